@@ -40,6 +40,8 @@ pub struct InsertInboundShipmentLine {
     pub sell_price_per_pack: f64,
     pub expiry_date: Option<NaiveDate>,
     pub number_of_packs: u32,
+    /// Pack variant of the item `number_of_packs` is counted in, converted to packs of the line
+    pub pack_variant_id: Option<String>,
     pub total_before_tax: f64,
     pub total_after_tax: f64,
     pub tax: Option<f64>,
@@ -56,6 +58,8 @@ pub struct UpdateInboundShipmentLine {
     pub sell_price_per_pack: Option<f64>,
     pub expiry_date: Option<NaiveDate>,
     pub number_of_packs: Option<u32>,
+    /// Pack variant of the item `number_of_packs` is counted in, converted to packs of the line
+    pub pack_variant_id: Option<String>,
 }
 
 pub struct DeleteInboundShipmentLine {
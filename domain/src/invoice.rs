@@ -15,6 +15,16 @@ pub enum InvoiceType {
     OutboundShipment,
     InboundShipment,
     InventoryAdjustment,
+    /// Stock sent back to a supplier, e.g. damaged or recalled stock
+    SupplierReturn,
+    /// Unused stock returned by a customer
+    CustomerReturn,
+    /// Packs of a stock line repacked into another pack size
+    Repack,
+    /// Stock dispensed to a patient
+    Prescription,
+    /// Stock moved to another location, part of a stock line is split off into a new stock line
+    LocationMovement,
 }
 
 #[derive(PartialEq, Debug)]
@@ -35,6 +45,8 @@ pub struct Invoice {
     pub delivered_datetime: Option<NaiveDateTime>,
     pub verified_datetime: Option<NaiveDateTime>,
     pub color: Option<String>,
+    /// Prescriber of a prescription
+    pub prescriber_id: Option<String>,
 }
 #[derive(Clone)]
 pub struct InvoiceFilter {
@@ -79,6 +91,16 @@ impl InvoiceFilter {
         self
     }
 
+    pub fn name_id(mut self, filter: EqualFilter<String>) -> Self {
+        self.name_id = Some(filter);
+        self
+    }
+
+    pub fn store_id(mut self, filter: EqualFilter<String>) -> Self {
+        self.store_id = Some(filter);
+        self
+    }
+
     pub fn r#type(mut self, filter: EqualFilter<InvoiceType>) -> Self {
         self.r#type = Some(filter);
         self
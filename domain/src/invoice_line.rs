@@ -26,6 +26,9 @@ pub struct InvoiceLine {
     pub batch: Option<String>,
     pub expiry_date: Option<NaiveDate>,
     pub note: Option<String>,
+    pub return_reason_id: Option<String>,
+    /// How to take the dispensed stock of a prescription line
+    pub directions: Option<String>,
 }
 
 pub type InvoiceLineSort = Sort<()>;
@@ -1,5 +1,22 @@
 use super::Sort;
 
+/// Essential medicines (VEN) classification
+#[derive(PartialEq, Debug, Clone)]
+pub enum VenCategory {
+    Vital,
+    Essential,
+    NonEssential,
+}
+
+/// Class of the item by its share of the consumption value of a store, A items make up most of the
+/// consumption value
+#[derive(PartialEq, Debug, Clone)]
+pub enum AbcClass {
+    A,
+    B,
+    C,
+}
+
 #[derive(PartialEq, Debug, Clone)]
 pub struct Item {
     pub id: String,
@@ -8,6 +25,15 @@ pub struct Item {
     // Is visible is from master list join
     pub is_visible: bool,
     pub unit_name: Option<String>,
+    // Properties with local overrides applied
+    pub category_id: Option<String>,
+    pub strength: Option<String>,
+    pub dosage_form: Option<String>,
+    pub ven_category: Option<VenCategory>,
+    pub is_controlled: bool,
+    pub storage_requirement: Option<String>,
+    /// ABC class in the store of the query, `None` if the store hasn't been classified
+    pub abc_class: Option<AbcClass>,
 }
 
 pub enum ItemSortField {
@@ -7,6 +7,7 @@ pub mod master_list;
 pub mod master_list_line;
 pub mod name;
 pub mod outbound_shipment;
+pub mod patient;
 pub mod shipment_tax_update;
 pub mod stock_line;
 
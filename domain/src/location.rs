@@ -6,6 +6,12 @@ pub struct Location {
     pub name: String,
     pub code: String,
     pub on_hold: bool,
+    /// Enclosing location, e.g. the room of a shelf
+    pub parent_id: Option<String>,
+    /// Type set on the location itself, see the location service for the inherited type
+    pub location_type_id: Option<String>,
+    /// Capacity in litres
+    pub volume: Option<f64>,
 }
 #[derive(Clone, PartialEq, Debug)]
 pub struct LocationFilter {
@@ -13,6 +19,7 @@ pub struct LocationFilter {
     pub name: Option<EqualFilter<String>>,
     pub code: Option<EqualFilter<String>>,
     pub store_id: Option<EqualFilter<String>>,
+    pub parent_id: Option<EqualFilter<String>>,
 }
 
 impl LocationFilter {
@@ -22,6 +29,7 @@ impl LocationFilter {
             name: None,
             code: None,
             store_id: None,
+            parent_id: None,
         }
     }
 
@@ -44,6 +52,11 @@ impl LocationFilter {
         self.store_id = Some(filter);
         self
     }
+
+    pub fn parent_id(mut self, filter: EqualFilter<String>) -> Self {
+        self.parent_id = Some(filter);
+        self
+    }
 }
 #[derive(PartialEq, Debug)]
 pub enum LocationSortField {
@@ -58,13 +71,20 @@ pub struct InsertLocation {
     pub code: String,
     pub name: Option<String>,
     pub on_hold: Option<bool>,
+    pub parent_id: Option<String>,
+    pub location_type_id: Option<String>,
+    pub volume: Option<f64>,
 }
 
+/// Omitted fields are left unchanged, an existing parent, type or volume can't be cleared
 pub struct UpdateLocation {
     pub id: String,
     pub code: Option<String>,
     pub name: Option<String>,
     pub on_hold: Option<bool>,
+    pub parent_id: Option<String>,
+    pub location_type_id: Option<String>,
+    pub volume: Option<f64>,
 }
 
 pub struct DeleteLocation {
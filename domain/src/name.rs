@@ -1,20 +1,47 @@
 use super::{EqualFilter, SimpleStringFilter, Sort};
 
+#[derive(PartialEq, Debug, Clone)]
+pub enum NameType {
+    Facility,
+    Patient,
+    Store,
+    Other,
+}
+
 #[derive(PartialEq, Debug, Clone)]
 pub struct Name {
     pub id: String,
     pub name: String,
     pub code: String,
+    pub r#type: NameType,
     pub is_customer: bool,
     pub is_supplier: bool,
+    pub is_manufacturer: bool,
+    pub is_donor: bool,
+    pub phone: Option<String>,
+    pub email: Option<String>,
+    pub website: Option<String>,
+    pub address1: Option<String>,
+    pub address2: Option<String>,
+    pub country: Option<String>,
+    pub comment: Option<String>,
+    /// Store the name was created in, names can only be edited in their home store
+    pub home_store_id: Option<String>,
+    /// Names of the tags (categories) of the name, ordered by tag name
+    pub tags: Vec<String>,
 }
 #[derive(Clone)]
 pub struct NameFilter {
     pub id: Option<EqualFilter<String>>,
     pub name: Option<SimpleStringFilter>,
     pub code: Option<SimpleStringFilter>,
+    pub r#type: Option<EqualFilter<NameType>>,
     pub is_customer: Option<bool>,
     pub is_supplier: Option<bool>,
+    pub is_manufacturer: Option<bool>,
+    pub is_donor: Option<bool>,
+    /// Matches names with any of the given tag names
+    pub tag: Option<EqualFilter<String>>,
 }
 
 pub enum NameSortField {
@@ -30,8 +57,12 @@ impl NameFilter {
             id: None,
             name: None,
             code: None,
+            r#type: None,
             is_customer: None,
             is_supplier: None,
+            is_manufacturer: None,
+            is_donor: None,
+            tag: None,
         }
     }
 
@@ -45,6 +76,16 @@ impl NameFilter {
         self
     }
 
+    pub fn r#type(mut self, filter: EqualFilter<NameType>) -> Self {
+        self.r#type = Some(filter);
+        self
+    }
+
+    pub fn tag(mut self, filter: EqualFilter<String>) -> Self {
+        self.tag = Some(filter);
+        self
+    }
+
     pub fn match_is_supplier(mut self, value: bool) -> Self {
         self.is_supplier = Some(value);
         self
@@ -34,6 +34,8 @@ pub struct InsertOutboundShipmentLine {
     pub item_id: String,
     pub stock_line_id: String,
     pub number_of_packs: u32,
+    /// Pack variant of the item `number_of_packs` is counted in, converted to packs of the stock line
+    pub pack_variant_id: Option<String>,
     pub total_before_tax: f64,
     pub total_after_tax: f64,
     pub tax: Option<f64>,
@@ -45,6 +47,8 @@ pub struct UpdateOutboundShipmentLine {
     pub item_id: Option<String>,
     pub stock_line_id: Option<String>,
     pub number_of_packs: Option<u32>,
+    /// Pack variant of the item `number_of_packs` is counted in, converted to packs of the stock line
+    pub pack_variant_id: Option<String>,
     pub total_before_tax: Option<f64>,
     pub total_after_tax: Option<f64>,
     pub tax: Option<ShipmentTaxUpdate>,
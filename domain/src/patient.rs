@@ -0,0 +1,63 @@
+use chrono::NaiveDate;
+
+use super::{DateFilter, EqualFilter, SimpleStringFilter, Sort};
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct Patient {
+    pub id: String,
+    /// Full name, e.g. "Doe, Jane"
+    pub name: String,
+    pub code: String,
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    pub date_of_birth: Option<NaiveDate>,
+    pub phone: Option<String>,
+    /// Store the patient was registered in, patients can only be edited in their home store
+    pub home_store_id: Option<String>,
+}
+
+#[derive(Clone, Debug)]
+pub struct PatientFilter {
+    pub id: Option<EqualFilter<String>>,
+    pub name: Option<SimpleStringFilter>,
+    pub code: Option<SimpleStringFilter>,
+    pub first_name: Option<SimpleStringFilter>,
+    pub last_name: Option<SimpleStringFilter>,
+    pub date_of_birth: Option<DateFilter>,
+    pub phone: Option<SimpleStringFilter>,
+    pub home_store_id: Option<EqualFilter<String>>,
+}
+
+impl PatientFilter {
+    pub fn new() -> PatientFilter {
+        PatientFilter {
+            id: None,
+            name: None,
+            code: None,
+            first_name: None,
+            last_name: None,
+            date_of_birth: None,
+            phone: None,
+            home_store_id: None,
+        }
+    }
+
+    pub fn id(mut self, filter: EqualFilter<String>) -> Self {
+        self.id = Some(filter);
+        self
+    }
+
+    pub fn code(mut self, filter: SimpleStringFilter) -> Self {
+        self.code = Some(filter);
+        self
+    }
+}
+
+#[derive(PartialEq, Debug)]
+pub enum PatientSortField {
+    Name,
+    Code,
+    DateOfBirth,
+}
+
+pub type PatientSort = Sort<PatientSortField>;
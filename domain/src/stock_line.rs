@@ -21,11 +21,13 @@ pub struct StockLine {
     pub on_hold: bool,
     pub note: Option<String>,
 }
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct StockLineFilter {
     pub id: Option<EqualFilter<String>>,
     pub item_id: Option<EqualFilter<String>>,
+    pub store_id: Option<EqualFilter<String>>,
     pub location_id: Option<EqualFilter<String>>,
+    pub batch: Option<EqualFilter<String>>,
     pub expiry_date: Option<DateFilter>,
 }
 
@@ -34,7 +36,9 @@ impl StockLineFilter {
         StockLineFilter {
             id: None,
             item_id: None,
+            store_id: None,
             location_id: None,
+            batch: None,
             expiry_date: None,
         }
     }
@@ -49,11 +53,21 @@ impl StockLineFilter {
         self
     }
 
+    pub fn store_id(mut self, filter: EqualFilter<String>) -> Self {
+        self.store_id = Some(filter);
+        self
+    }
+
     pub fn location_id(mut self, filter: EqualFilter<String>) -> Self {
         self.location_id = Some(filter);
         self
     }
 
+    pub fn batch(mut self, filter: EqualFilter<String>) -> Self {
+        self.batch = Some(filter);
+        self
+    }
+
     pub fn expiry_date(mut self, filter: DateFilter) -> Self {
         self.expiry_date = Some(filter);
         self
@@ -0,0 +1,414 @@
+use async_graphql::{InputType, Pos};
+use domain::{
+    invoice::{Invoice, InvoiceFilter},
+    item::Item,
+    location::{Location, LocationFilter},
+    name::{Name, NameFilter},
+    stock_line::{StockLine, StockLineFilter},
+    EqualFilter, Pagination,
+};
+use repository::{
+    InvoiceQueryRepository, ItemFilter, ItemQueryRepository, LocationRepository, MasterList,
+    MasterListRepository, NameQueryRepository, StockLineRepository, StorageConnection,
+};
+use serde::Serialize;
+
+use crate::schema::{
+    queries::{
+        ItemFilterInput, ItemSortInput, MasterListFilterInput, MasterListSortInput,
+        NameFilterInput, NameSortInput,
+    },
+    types::{
+        convert_sort, InvoiceFilterInput, InvoiceNodeStatus, InvoiceNodeType, InvoiceSortInput,
+        LocationFilterInput, LocationSortInput, StockLineFilterInput,
+    },
+};
+
+use super::{
+    writer::{ExportTable, ExportValue},
+    ExportError,
+};
+
+/// List queries that can be exported, identified by the name of the GraphQL query
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ExportList {
+    Invoices,
+    Items,
+    Names,
+    Locations,
+    MasterLists,
+    StockLines,
+}
+
+impl ExportList {
+    pub fn from_name(name: &str) -> Option<ExportList> {
+        let list = match name {
+            "invoices" => ExportList::Invoices,
+            "items" => ExportList::Items,
+            "names" => ExportList::Names,
+            "locations" => ExportList::Locations,
+            "masterLists" => ExportList::MasterLists,
+            "stockLines" => ExportList::StockLines,
+            _ => return None,
+        };
+        Some(list)
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ExportList::Invoices => "invoices",
+            ExportList::Items => "items",
+            ExportList::Names => "names",
+            ExportList::Locations => "locations",
+            ExportList::MasterLists => "masterLists",
+            ExportList::StockLines => "stockLines",
+        }
+    }
+}
+
+/// Raw filter and sort inputs, in the JSON format of the GraphQL query variables
+#[derive(Clone)]
+pub struct ExportListQuery {
+    pub store_id: String,
+    pub filter: Option<serde_json::Value>,
+    pub sort: Option<serde_json::Value>,
+    /// Defaults to all columns
+    pub columns: Option<Vec<String>>,
+}
+
+struct ExportColumn<T> {
+    key: &'static str,
+    value: fn(&T) -> ExportValue,
+}
+
+/// Queries a page of rows of the list, the page isn't limited by the maximum page size of the
+/// GraphQL list queries
+pub fn query_export_table(
+    connection: &StorageConnection,
+    list: ExportList,
+    query: ExportListQuery,
+    pagination: Pagination,
+) -> Result<ExportTable, ExportError> {
+    let ExportListQuery {
+        store_id,
+        filter,
+        sort,
+        columns,
+    } = query;
+
+    match list {
+        ExportList::Invoices => {
+            let filter = parse_input::<InvoiceFilterInput>(filter)?.map(InvoiceFilter::from);
+            let sort = convert_sort(parse_input::<Vec<InvoiceSortInput>>(sort)?);
+            let rows = InvoiceQueryRepository::new(connection).query(pagination, filter, sort)?;
+            to_table(rows, invoice_columns(), columns)
+        }
+        ExportList::Items => {
+            let filter = parse_input::<ItemFilterInput>(filter)?.map(ItemFilter::from);
+            let sort = convert_sort(parse_input::<Vec<ItemSortInput>>(sort)?);
+            let rows =
+                ItemQueryRepository::new(connection).query(&store_id, pagination, filter, sort)?;
+            to_table(rows, item_columns(), columns)
+        }
+        ExportList::Names => {
+            let filter = parse_input::<NameFilterInput>(filter)?.map(NameFilter::from);
+            let sort = convert_sort(parse_input::<Vec<NameSortInput>>(sort)?);
+            let rows = NameQueryRepository::new(connection).query(pagination, filter, sort)?;
+            to_table(rows, name_columns(), columns)
+        }
+        ExportList::Locations => {
+            let filter = parse_input::<LocationFilterInput>(filter)?.map(LocationFilter::from);
+            let sort = convert_sort(parse_input::<Vec<LocationSortInput>>(sort)?);
+            let rows = LocationRepository::new(connection).query(pagination, filter, sort)?;
+            to_table(rows, location_columns(), columns)
+        }
+        ExportList::MasterLists => {
+            let filter =
+                parse_input::<MasterListFilterInput>(filter)?.map(MasterListFilterInput::to_domain);
+            // Only the first sort input is evaluated, same as for the masterLists query
+            let sort = parse_input::<Vec<MasterListSortInput>>(sort)?
+                .and_then(|mut sort| sort.pop())
+                .map(MasterListSortInput::to_domain);
+            let rows = MasterListRepository::new(connection).query(pagination, filter, sort)?;
+            to_table(rows, master_list_columns(), columns)
+        }
+        ExportList::StockLines => {
+            let filter = parse_input::<StockLineFilterInput>(filter)?
+                .map(StockLineFilter::from)
+                .unwrap_or_else(StockLineFilter::new)
+                .store_id(EqualFilter::equal_to(&store_id));
+            let rows =
+                StockLineRepository::new(connection).query(pagination, Some(filter), None)?;
+            to_table(rows, stock_line_columns(), columns)
+        }
+    }
+}
+
+/// Parses a GraphQL input from its JSON representation
+fn parse_input<T: InputType>(value: Option<serde_json::Value>) -> Result<Option<T>, ExportError> {
+    let value = match value {
+        None | Some(serde_json::Value::Null) => return Ok(None),
+        Some(value) => async_graphql::Value::from_json(value)
+            .map_err(|error| ExportError::BadUserInput(error.to_string()))?,
+    };
+    T::parse(Some(value))
+        .map(Some)
+        .map_err(|error| ExportError::BadUserInput(error.into_server_error(Pos::default()).message))
+}
+
+fn to_table<T>(
+    rows: Vec<T>,
+    all_columns: Vec<ExportColumn<T>>,
+    selected: Option<Vec<String>>,
+) -> Result<ExportTable, ExportError> {
+    let columns = match selected {
+        None => all_columns,
+        Some(selected) => selected
+            .iter()
+            .map(|key| {
+                all_columns
+                    .iter()
+                    .find(|column| column.key == key)
+                    .map(|column| ExportColumn {
+                        key: column.key,
+                        value: column.value,
+                    })
+                    .ok_or_else(|| ExportError::BadUserInput(format!("Unknown column: {}", key)))
+            })
+            .collect::<Result<Vec<ExportColumn<T>>, ExportError>>()?,
+    };
+
+    Ok(ExportTable {
+        columns: columns.iter().map(|column| column.key).collect(),
+        rows: rows
+            .iter()
+            .map(|row| columns.iter().map(|column| (column.value)(row)).collect())
+            .collect(),
+    })
+}
+
+/// Exports enums the same way as they are returned by the GraphQL API
+fn enum_value<T: Serialize>(value: T) -> ExportValue {
+    match serde_json::to_value(value) {
+        Ok(serde_json::Value::String(value)) => ExportValue::Text(value),
+        _ => ExportValue::Empty,
+    }
+}
+
+fn invoice_columns() -> Vec<ExportColumn<Invoice>> {
+    vec![
+        ExportColumn {
+            key: "id",
+            value: |invoice| invoice.id.clone().into(),
+        },
+        ExportColumn {
+            key: "invoiceNumber",
+            value: |invoice| invoice.invoice_number.into(),
+        },
+        ExportColumn {
+            key: "type",
+            value: |invoice| enum_value(InvoiceNodeType::from(invoice.r#type.clone())),
+        },
+        ExportColumn {
+            key: "status",
+            value: |invoice| enum_value(InvoiceNodeStatus::from(invoice.status.clone())),
+        },
+        ExportColumn {
+            key: "otherPartyId",
+            value: |invoice| invoice.other_party_id.clone().into(),
+        },
+        ExportColumn {
+            key: "otherPartyName",
+            value: |invoice| invoice.other_party_name.clone().into(),
+        },
+        ExportColumn {
+            key: "onHold",
+            value: |invoice| invoice.on_hold.into(),
+        },
+        ExportColumn {
+            key: "theirReference",
+            value: |invoice| invoice.their_reference.clone().into(),
+        },
+        ExportColumn {
+            key: "comment",
+            value: |invoice| invoice.comment.clone().into(),
+        },
+        ExportColumn {
+            key: "createdDatetime",
+            value: |invoice| invoice.created_datetime.into(),
+        },
+        ExportColumn {
+            key: "allocatedDatetime",
+            value: |invoice| invoice.allocated_datetime.into(),
+        },
+        ExportColumn {
+            key: "pickedDatetime",
+            value: |invoice| invoice.picked_datetime.into(),
+        },
+        ExportColumn {
+            key: "shippedDatetime",
+            value: |invoice| invoice.shipped_datetime.into(),
+        },
+        ExportColumn {
+            key: "deliveredDatetime",
+            value: |invoice| invoice.delivered_datetime.into(),
+        },
+        ExportColumn {
+            key: "verifiedDatetime",
+            value: |invoice| invoice.verified_datetime.into(),
+        },
+        ExportColumn {
+            key: "color",
+            value: |invoice| invoice.color.clone().into(),
+        },
+    ]
+}
+
+fn item_columns() -> Vec<ExportColumn<Item>> {
+    vec![
+        ExportColumn {
+            key: "id",
+            value: |item| item.id.clone().into(),
+        },
+        ExportColumn {
+            key: "code",
+            value: |item| item.code.clone().into(),
+        },
+        ExportColumn {
+            key: "name",
+            value: |item| item.name.clone().into(),
+        },
+        ExportColumn {
+            key: "unitName",
+            value: |item| item.unit_name.clone().into(),
+        },
+        ExportColumn {
+            key: "isVisible",
+            value: |item| item.is_visible.into(),
+        },
+    ]
+}
+
+fn name_columns() -> Vec<ExportColumn<Name>> {
+    vec![
+        ExportColumn {
+            key: "id",
+            value: |name| name.id.clone().into(),
+        },
+        ExportColumn {
+            key: "code",
+            value: |name| name.code.clone().into(),
+        },
+        ExportColumn {
+            key: "name",
+            value: |name| name.name.clone().into(),
+        },
+        ExportColumn {
+            key: "isCustomer",
+            value: |name| name.is_customer.into(),
+        },
+        ExportColumn {
+            key: "isSupplier",
+            value: |name| name.is_supplier.into(),
+        },
+    ]
+}
+
+fn location_columns() -> Vec<ExportColumn<Location>> {
+    vec![
+        ExportColumn {
+            key: "id",
+            value: |location| location.id.clone().into(),
+        },
+        ExportColumn {
+            key: "code",
+            value: |location| location.code.clone().into(),
+        },
+        ExportColumn {
+            key: "name",
+            value: |location| location.name.clone().into(),
+        },
+        ExportColumn {
+            key: "onHold",
+            value: |location| location.on_hold.into(),
+        },
+    ]
+}
+
+fn master_list_columns() -> Vec<ExportColumn<MasterList>> {
+    vec![
+        ExportColumn {
+            key: "id",
+            value: |master_list| master_list.id.clone().into(),
+        },
+        ExportColumn {
+            key: "code",
+            value: |master_list| master_list.code.clone().into(),
+        },
+        ExportColumn {
+            key: "name",
+            value: |master_list| master_list.name.clone().into(),
+        },
+        ExportColumn {
+            key: "description",
+            value: |master_list| master_list.description.clone().into(),
+        },
+    ]
+}
+
+fn stock_line_columns() -> Vec<ExportColumn<StockLine>> {
+    vec![
+        ExportColumn {
+            key: "id",
+            value: |stock_line| stock_line.id.clone().into(),
+        },
+        ExportColumn {
+            key: "itemId",
+            value: |stock_line| stock_line.item_id.clone().into(),
+        },
+        ExportColumn {
+            key: "batch",
+            value: |stock_line| stock_line.batch.clone().into(),
+        },
+        ExportColumn {
+            key: "expiryDate",
+            value: |stock_line| stock_line.expiry_date.into(),
+        },
+        ExportColumn {
+            key: "locationId",
+            value: |stock_line| stock_line.location_id.clone().into(),
+        },
+        ExportColumn {
+            key: "locationName",
+            value: |stock_line| stock_line.location_name.clone().into(),
+        },
+        ExportColumn {
+            key: "packSize",
+            value: |stock_line| stock_line.pack_size.into(),
+        },
+        ExportColumn {
+            key: "availableNumberOfPacks",
+            value: |stock_line| stock_line.available_number_of_packs.into(),
+        },
+        ExportColumn {
+            key: "totalNumberOfPacks",
+            value: |stock_line| stock_line.total_number_of_packs.into(),
+        },
+        ExportColumn {
+            key: "costPricePerPack",
+            value: |stock_line| stock_line.cost_price_per_pack.into(),
+        },
+        ExportColumn {
+            key: "sellPricePerPack",
+            value: |stock_line| stock_line.sell_price_per_pack.into(),
+        },
+        ExportColumn {
+            key: "onHold",
+            value: |stock_line| stock_line.on_hold.into(),
+        },
+        ExportColumn {
+            key: "note",
+            value: |stock_line| stock_line.note.clone().into(),
+        },
+    ]
+}
@@ -0,0 +1,220 @@
+use actix_web::{
+    http::{header::CONTENT_DISPOSITION, StatusCode},
+    web::{Bytes, Data, Json, Path},
+    HttpRequest, HttpResponse, ResponseError,
+};
+use domain::Pagination;
+use repository::{ReadTransaction, RepositoryError};
+use serde::Deserialize;
+use service::{
+    auth_data::AuthData,
+    current_store_id,
+    permission_validation::{
+        Resource, ResourceAccessRequest, ValidationDeniedKind, ValidationError,
+    },
+    service_provider::ServiceProvider,
+};
+use thiserror::Error;
+
+use crate::auth_data_from_request;
+
+use self::{
+    lists::{query_export_table, ExportList, ExportListQuery},
+    writer::{csv_chunk, xlsx, ExportFormatter},
+};
+
+pub mod lists;
+pub mod writer;
+
+/// Number of rows queried and encoded per chunk of the CSV response
+const CSV_CHUNK_ROWS: usize = 500;
+
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    #[default]
+    Csv,
+    Xlsx,
+}
+
+/// Body of an export request, `filter` and `sort` take the same inputs as the GraphQL list query
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportRequest {
+    pub store_id: Option<String>,
+    pub filter: Option<serde_json::Value>,
+    pub sort: Option<serde_json::Value>,
+    /// Column keys in export order, defaults to all columns
+    pub columns: Option<Vec<String>>,
+    #[serde(default)]
+    pub format: ExportFormat,
+    /// strftime pattern, defaults to `%Y-%m-%d`
+    pub date_format: Option<String>,
+    /// strftime pattern, defaults to `%Y-%m-%d %H:%M:%S`
+    pub datetime_format: Option<String>,
+    /// Timezone of exported datetimes, defaults to the store preference
+    pub timezone_offset: Option<i32>,
+}
+
+#[derive(Debug, Error)]
+pub enum ExportError {
+    #[error("Unknown list: {0}")]
+    UnknownList(String),
+    #[error("Bad user input: {0}")]
+    BadUserInput(String),
+    #[error("Unauthenticated: {0}")]
+    Unauthenticated(String),
+    #[error("Forbidden: {0}")]
+    Forbidden(String),
+    #[error("Internal error: {0}")]
+    InternalError(String),
+}
+
+impl ResponseError for ExportError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            ExportError::UnknownList(_) => StatusCode::NOT_FOUND,
+            ExportError::BadUserInput(_) => StatusCode::BAD_REQUEST,
+            ExportError::Unauthenticated(_) => StatusCode::UNAUTHORIZED,
+            ExportError::Forbidden(_) => StatusCode::FORBIDDEN,
+            ExportError::InternalError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl From<RepositoryError> for ExportError {
+    fn from(error: RepositoryError) -> Self {
+        ExportError::InternalError(format!("{:?}", error))
+    }
+}
+
+impl From<ValidationError> for ExportError {
+    fn from(error: ValidationError) -> Self {
+        match error {
+            ValidationError::Denied(kind) => match kind {
+                ValidationDeniedKind::NotAuthenticated(_) => {
+                    ExportError::Unauthenticated(format!("{:?}", kind))
+                }
+                ValidationDeniedKind::InsufficientPermission(_) => {
+                    ExportError::Forbidden(format!("{:?}", kind))
+                }
+            },
+            ValidationError::InternalError(error) => ExportError::InternalError(error),
+        }
+    }
+}
+
+/// Exports the complete result of a list query, e.g. `POST /export/invoices`
+pub async fn export(
+    list: Path<String>,
+    request: Json<ExportRequest>,
+    http_req: HttpRequest,
+    service_provider: Data<ServiceProvider>,
+    auth_data: Data<AuthData>,
+) -> Result<HttpResponse, ExportError> {
+    let list = ExportList::from_name(&list).ok_or(ExportError::UnknownList(list.to_string()))?;
+    let ExportRequest {
+        store_id,
+        filter,
+        sort,
+        columns,
+        format,
+        date_format,
+        datetime_format,
+        timezone_offset,
+    } = request.into_inner();
+
+    let service_ctx = service_provider.context()?;
+    // TODO remove and make store_id parameter required
+    let store_id = match store_id {
+        Some(store_id) => store_id,
+        None => current_store_id(&service_ctx.connection)?,
+    };
+    service_provider.validation_service.validate(
+        &service_ctx,
+        &auth_data,
+        &auth_data_from_request(&http_req).auth_token,
+        &ResourceAccessRequest {
+            resource: Resource::ExportList,
+            store_id: Some(store_id.clone()),
+        },
+    )?;
+
+    let preferences = service_provider
+        .preference_service
+        .get_store_preferences(&service_ctx, &store_id)?;
+    let formatter = ExportFormatter::new(
+        date_format,
+        datetime_format,
+        timezone_offset.or(preferences.timezone_offset),
+    )?;
+    let query = ExportListQuery {
+        store_id,
+        filter,
+        sort,
+        columns,
+    };
+
+    let response = match format {
+        // the workbook is built in memory, all rows are queried at once
+        ExportFormat::Xlsx => {
+            let table =
+                query_export_table(&service_ctx.connection, list, query, Pagination::all())?;
+            HttpResponse::Ok()
+                .content_type("application/vnd.openxmlformats-officedocument.spreadsheetml.sheet")
+                .header(
+                    CONTENT_DISPOSITION,
+                    format!("attachment; filename=\"{}.xlsx\"", list.name()),
+                )
+                .body(xlsx(&table, &formatter)?)
+        }
+        // rows are queried page by page while the response is streamed, the first page is
+        // queried upfront so invalid inputs are reported with an error status. All pages are read
+        // in one transaction, the transaction ends when the stream is dropped.
+        ExportFormat::Csv => {
+            let transaction = ReadTransaction::begin(service_ctx.connection)?;
+            let page = |offset: usize| Pagination {
+                offset: offset as u32,
+                limit: CSV_CHUNK_ROWS as u32,
+            };
+            let first_page =
+                query_export_table(transaction.connection(), list, query.clone(), page(0))?;
+            let mut header = Some(first_page.columns);
+            let mut rows = Some(first_page.rows);
+            let mut offset = 0;
+            let mut is_last_page = false;
+            let chunks = std::iter::from_fn(move || {
+                if is_last_page {
+                    return None;
+                }
+                let page_rows = match rows.take() {
+                    Some(page_rows) => page_rows,
+                    None => match query_export_table(
+                        transaction.connection(),
+                        list,
+                        query.clone(),
+                        page(offset),
+                    ) {
+                        Ok(table) => table.rows,
+                        Err(error) => {
+                            is_last_page = true;
+                            return Some(Err(error));
+                        }
+                    },
+                };
+                is_last_page = page_rows.len() < CSV_CHUNK_ROWS;
+                offset += page_rows.len();
+                let chunk = csv_chunk(header.take().as_deref(), &page_rows, &formatter);
+                Some(chunk.map(Bytes::from))
+            });
+            HttpResponse::Ok()
+                .content_type("text/csv; charset=utf-8")
+                .header(
+                    CONTENT_DISPOSITION,
+                    format!("attachment; filename=\"{}.csv\"", list.name()),
+                )
+                .streaming(futures::stream::iter(chunks))
+        }
+    };
+    Ok(response)
+}
@@ -0,0 +1,191 @@
+use chrono::{
+    format::{Item, StrftimeItems},
+    DateTime, FixedOffset, NaiveDate, NaiveDateTime, Utc,
+};
+use simple_excel_writer::{CellValue, Row, Workbook};
+use util::timezone::offset_to_timezone;
+
+use super::ExportError;
+
+const DEFAULT_DATE_FORMAT: &str = "%Y-%m-%d";
+const DEFAULT_DATETIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ExportValue {
+    Text(String),
+    Integer(i64),
+    Number(f64),
+    Boolean(bool),
+    Date(NaiveDate),
+    /// UTC datetime, exported in the requested timezone
+    Datetime(NaiveDateTime),
+    Empty,
+}
+
+impl From<String> for ExportValue {
+    fn from(value: String) -> Self {
+        ExportValue::Text(value)
+    }
+}
+
+impl From<i64> for ExportValue {
+    fn from(value: i64) -> Self {
+        ExportValue::Integer(value)
+    }
+}
+
+impl From<i32> for ExportValue {
+    fn from(value: i32) -> Self {
+        ExportValue::Integer(value as i64)
+    }
+}
+
+impl From<f64> for ExportValue {
+    fn from(value: f64) -> Self {
+        ExportValue::Number(value)
+    }
+}
+
+impl From<bool> for ExportValue {
+    fn from(value: bool) -> Self {
+        ExportValue::Boolean(value)
+    }
+}
+
+impl From<NaiveDate> for ExportValue {
+    fn from(value: NaiveDate) -> Self {
+        ExportValue::Date(value)
+    }
+}
+
+impl From<NaiveDateTime> for ExportValue {
+    fn from(value: NaiveDateTime) -> Self {
+        ExportValue::Datetime(value)
+    }
+}
+
+impl<T: Into<ExportValue>> From<Option<T>> for ExportValue {
+    fn from(value: Option<T>) -> Self {
+        value.map(T::into).unwrap_or(ExportValue::Empty)
+    }
+}
+
+/// Selected columns and their values, rows are in the order of the query
+pub struct ExportTable {
+    pub columns: Vec<&'static str>,
+    pub rows: Vec<Vec<ExportValue>>,
+}
+
+/// Formats dates and datetimes as requested by the user
+pub struct ExportFormatter {
+    date_format: String,
+    datetime_format: String,
+    timezone: FixedOffset,
+}
+
+impl ExportFormatter {
+    /// Formats are strftime patterns, e.g. `%d/%m/%Y`
+    pub fn new(
+        date_format: Option<String>,
+        datetime_format: Option<String>,
+        timezone_offset: Option<i32>,
+    ) -> Result<Self, ExportError> {
+        let date_format = validate_format(date_format, DEFAULT_DATE_FORMAT)?;
+        let datetime_format = validate_format(datetime_format, DEFAULT_DATETIME_FORMAT)?;
+        let timezone = offset_to_timezone(&timezone_offset).ok_or(ExportError::BadUserInput(
+            "Invalid timezone offset".to_string(),
+        ))?;
+        Ok(ExportFormatter {
+            date_format,
+            datetime_format,
+            timezone,
+        })
+    }
+
+    pub fn format(&self, value: &ExportValue) -> String {
+        match value {
+            ExportValue::Text(value) => value.clone(),
+            ExportValue::Integer(value) => value.to_string(),
+            ExportValue::Number(value) => value.to_string(),
+            ExportValue::Boolean(value) => value.to_string(),
+            ExportValue::Date(value) => value.format(&self.date_format).to_string(),
+            ExportValue::Datetime(value) => DateTime::<Utc>::from_utc(*value, Utc)
+                .with_timezone(&self.timezone)
+                .format(&self.datetime_format)
+                .to_string(),
+            ExportValue::Empty => String::new(),
+        }
+    }
+}
+
+fn validate_format(format: Option<String>, default: &str) -> Result<String, ExportError> {
+    let format = format.unwrap_or_else(|| default.to_string());
+    if StrftimeItems::new(&format).any(|item| item == Item::Error) {
+        return Err(ExportError::BadUserInput(format!(
+            "Invalid date format: {}",
+            format
+        )));
+    }
+    Ok(format)
+}
+
+/// Encodes rows as CSV, the header is only written for the first chunk
+pub fn csv_chunk(
+    header: Option<&[&'static str]>,
+    rows: &[Vec<ExportValue>],
+    formatter: &ExportFormatter,
+) -> Result<Vec<u8>, ExportError> {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    if let Some(header) = header {
+        writer.write_record(header)?;
+    }
+    for row in rows {
+        writer.write_record(row.iter().map(|value| formatter.format(value)))?;
+    }
+    writer
+        .into_inner()
+        .map_err(|error| ExportError::InternalError(error.to_string()))
+}
+
+pub fn xlsx(table: &ExportTable, formatter: &ExportFormatter) -> Result<Vec<u8>, ExportError> {
+    let mut workbook = Workbook::create_in_memory();
+    let mut sheet = workbook.create_sheet("Export");
+    workbook.write_sheet(&mut sheet, |sheet_writer| {
+        let mut header = Row::new();
+        for column in &table.columns {
+            header.add_cell(CellValue::String(column.to_string()));
+        }
+        sheet_writer.append_row(header)?;
+        for values in &table.rows {
+            let mut row = Row::new();
+            for value in values {
+                row.add_cell(match value {
+                    ExportValue::Integer(value) => CellValue::Number(*value as f64),
+                    ExportValue::Number(value) => CellValue::Number(*value),
+                    ExportValue::Boolean(value) => CellValue::Bool(*value),
+                    ExportValue::Empty => CellValue::Blank(1),
+                    // Not using the string conversion of the writer, it turns strings that start
+                    // with = into formulas
+                    value => CellValue::String(formatter.format(value)),
+                });
+            }
+            sheet_writer.append_row(row)?;
+        }
+        Ok(())
+    })?;
+    workbook.close()?.ok_or(ExportError::InternalError(
+        "Workbook wasn't created in memory".to_string(),
+    ))
+}
+
+impl From<csv::Error> for ExportError {
+    fn from(error: csv::Error) -> Self {
+        ExportError::InternalError(error.to_string())
+    }
+}
+
+impl From<std::io::Error> for ExportError {
+    fn from(error: std::io::Error) -> Self {
+        ExportError::InternalError(error.to_string())
+    }
+}
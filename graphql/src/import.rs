@@ -0,0 +1,212 @@
+use actix_web::{
+    http::StatusCode,
+    web::{Bytes, Data, Query},
+    HttpRequest, HttpResponse, ResponseError,
+};
+use repository::RepositoryError;
+use serde::{Deserialize, Serialize};
+use service::{
+    auth_data::AuthData,
+    current_store_id,
+    permission_validation::{
+        Resource, ResourceAccessRequest, ValidationDeniedKind, ValidationError,
+    },
+    service_provider::ServiceProvider,
+    stock_import::{
+        opening_stock::ImportOpeningStock, ImportOpeningStockError, ImportRowError,
+        ImportRowErrorKind,
+    },
+};
+use thiserror::Error;
+
+use crate::auth_data_from_request;
+
+/// Maximum size of an uploaded file
+pub const MAX_IMPORT_SIZE: usize = 10 * 1024 * 1024;
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportOpeningStockQuery {
+    pub store_id: Option<String>,
+    /// Comment of the created inventory adjustment
+    pub comment: Option<String>,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportOpeningStockResponse {
+    pub invoice_id: String,
+    pub invoice_number: i64,
+    pub number_of_lines: usize,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RowErrorKindResponse {
+    pub code: &'static str,
+    /// Offending value or column, if any
+    pub value: Option<String>,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RowErrorResponse {
+    pub line_number: usize,
+    pub errors: Vec<RowErrorKindResponse>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ImportErrorBody<'a> {
+    message: String,
+    row_errors: &'a [RowErrorResponse],
+}
+
+#[derive(Debug, Error)]
+pub enum ImportError {
+    #[error("Bad user input: {0}")]
+    BadUserInput(String),
+    #[error("File has invalid rows")]
+    InvalidRows(Vec<RowErrorResponse>),
+    #[error("Unauthenticated: {0}")]
+    Unauthenticated(String),
+    #[error("Forbidden: {0}")]
+    Forbidden(String),
+    #[error("Internal error: {0}")]
+    InternalError(String),
+}
+
+impl ResponseError for ImportError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            ImportError::BadUserInput(_) | ImportError::InvalidRows(_) => StatusCode::BAD_REQUEST,
+            ImportError::Unauthenticated(_) => StatusCode::UNAUTHORIZED,
+            ImportError::Forbidden(_) => StatusCode::FORBIDDEN,
+            ImportError::InternalError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        let row_errors = match self {
+            ImportError::InvalidRows(row_errors) => &row_errors[..],
+            _ => &[],
+        };
+        HttpResponse::build(self.status_code()).json(ImportErrorBody {
+            message: self.to_string(),
+            row_errors,
+        })
+    }
+}
+
+impl From<RepositoryError> for ImportError {
+    fn from(error: RepositoryError) -> Self {
+        ImportError::InternalError(format!("{:?}", error))
+    }
+}
+
+impl From<ValidationError> for ImportError {
+    fn from(error: ValidationError) -> Self {
+        match error {
+            ValidationError::Denied(kind) => match kind {
+                ValidationDeniedKind::NotAuthenticated(_) => {
+                    ImportError::Unauthenticated(format!("{:?}", kind))
+                }
+                ValidationDeniedKind::InsufficientPermission(_) => {
+                    ImportError::Forbidden(format!("{:?}", kind))
+                }
+            },
+            ValidationError::InternalError(error) => ImportError::InternalError(error),
+        }
+    }
+}
+
+impl From<ImportOpeningStockError> for ImportError {
+    fn from(error: ImportOpeningStockError) -> Self {
+        match error {
+            ImportOpeningStockError::DatabaseError(error) => error.into(),
+            ImportOpeningStockError::InternalError(error) => ImportError::InternalError(error),
+            ImportOpeningStockError::InvalidCsv(error) => {
+                ImportError::BadUserInput(format!("Invalid CSV: {}", error))
+            }
+            ImportOpeningStockError::MissingColumn(column) => {
+                ImportError::BadUserInput(format!("Missing column: {}", column))
+            }
+            ImportOpeningStockError::NoRows => {
+                ImportError::BadUserInput("File has no rows".to_string())
+            }
+            ImportOpeningStockError::RowErrors(row_errors) => {
+                ImportError::InvalidRows(row_errors.into_iter().map(row_error_response).collect())
+            }
+        }
+    }
+}
+
+fn row_error_response(row_error: ImportRowError) -> RowErrorResponse {
+    use ImportRowErrorKind::*;
+    RowErrorResponse {
+        line_number: row_error.line_number,
+        errors: row_error
+            .errors
+            .into_iter()
+            .map(|kind| {
+                let (code, value) = match kind {
+                    InvalidRow(error) => ("InvalidRow", Some(error)),
+                    MissingItemCode => ("MissingItemCode", None),
+                    ItemDoesNotExist(code) => ("ItemDoesNotExist", Some(code)),
+                    InvalidExpiryDate(value) => ("InvalidExpiryDate", Some(value)),
+                    PackSizeBelowOne => ("PackSizeBelowOne", None),
+                    NumberOfPacksBelowOne => ("NumberOfPacksBelowOne", None),
+                    InvalidPrice(column) => ("InvalidPrice", Some(column)),
+                    LocationDoesNotExist(code) => ("LocationDoesNotExist", Some(code)),
+                };
+                RowErrorKindResponse { code, value }
+            })
+            .collect(),
+    }
+}
+
+/// Imports opening stock from the CSV file in the request body, e.g.
+/// `POST /import/openingStock?storeId=store_a`
+pub async fn import_opening_stock(
+    query: Query<ImportOpeningStockQuery>,
+    body: Bytes,
+    http_req: HttpRequest,
+    service_provider: Data<ServiceProvider>,
+    auth_data: Data<AuthData>,
+) -> Result<HttpResponse, ImportError> {
+    let ImportOpeningStockQuery { store_id, comment } = query.into_inner();
+    let csv = String::from_utf8(body.to_vec())
+        .map_err(|_| ImportError::BadUserInput("File is not UTF-8 encoded".to_string()))?;
+
+    let service_ctx = service_provider.context()?;
+    // TODO remove and make store_id parameter required
+    let store_id = match store_id {
+        Some(store_id) => store_id,
+        None => current_store_id(&service_ctx.connection)?,
+    };
+    service_provider.validation_service.validate(
+        &service_ctx,
+        &auth_data,
+        &auth_data_from_request(&http_req).auth_token,
+        &ResourceAccessRequest {
+            resource: Resource::ImportOpeningStock,
+            store_id: Some(store_id.clone()),
+        },
+    )?;
+
+    let result = service_provider.stock_import_service.import_opening_stock(
+        &service_ctx,
+        &store_id,
+        ImportOpeningStock {
+            // Spreadsheet applications tend to start the file with a byte order mark
+            csv: csv.trim_start_matches('\u{feff}').to_string(),
+            comment,
+        },
+    )?;
+
+    Ok(HttpResponse::Ok().json(ImportOpeningStockResponse {
+        invoice_id: result.invoice.id,
+        invoice_number: result.invoice.invoice_number,
+        number_of_lines: result.stock_lines.len(),
+    }))
+}
@@ -1,6 +1,11 @@
+pub mod export;
+pub mod import;
 pub mod loader;
+pub mod report;
+pub mod rest;
 pub mod schema;
 pub mod standard_graphql_error;
+pub mod temperature_log;
 
 use actix_web::cookie::Cookie;
 use actix_web::HttpRequest;
@@ -63,6 +68,42 @@ pub fn config(
     auth_data: Data<AuthData>,
 ) -> impl FnOnce(&mut actix_web::web::ServiceConfig) {
     |cfg| {
+        cfg.service(
+            actix_web::web::scope("/export")
+                .app_data(service_provider.clone())
+                .app_data(auth_data.clone())
+                .route("/{list}", actix_web::web::post().to(export::export)),
+        );
+        cfg.service(
+            actix_web::web::scope("/import")
+                .app_data(service_provider.clone())
+                .app_data(auth_data.clone())
+                .app_data(actix_web::web::PayloadConfig::new(import::MAX_IMPORT_SIZE))
+                .route(
+                    "/openingStock",
+                    actix_web::web::post().to(import::import_opening_stock),
+                )
+                .route(
+                    "/temperatureLog",
+                    actix_web::web::post().to(temperature_log::import_temperature_log),
+                ),
+        );
+        cfg.service(
+            actix_web::web::scope("/temperatureLog")
+                .app_data(service_provider.clone())
+                .app_data(auth_data.clone())
+                .route(
+                    "",
+                    actix_web::web::post().to(temperature_log::insert_temperature_log),
+                ),
+        );
+        cfg.service(
+            actix_web::web::scope("/reports")
+                .app_data(service_provider.clone())
+                .app_data(auth_data.clone())
+                .route("/{report}/{id}", actix_web::web::get().to(report::report)),
+        );
+        rest::config(service_provider.clone(), auth_data.clone())(cfg);
         let schema = build_schema()
             .data(connection_manager)
             .data(loader_registry)
@@ -2,9 +2,14 @@ use domain::item::Item;
 use domain::{EqualFilter, Pagination};
 use repository::{ItemFilter, ItemQueryRepository};
 use repository::{RepositoryError, StorageConnectionManager};
+use service::{
+    current_store_id,
+    stock_on_hand::item_summary::{get_item_stock_summaries, ItemStockSummary},
+};
 
 use async_graphql::dataloader::*;
 use async_graphql::*;
+use chrono::Utc;
 use std::collections::HashMap;
 
 pub struct ItemLoader {
@@ -18,9 +23,12 @@ impl Loader<String> for ItemLoader {
 
     async fn load(&self, keys: &[String]) -> Result<HashMap<String, Self::Value>, Self::Error> {
         let connection = self.connection_manager.connection()?;
+        // TODO use store from session
+        let store_id = current_store_id(&connection)?;
         let repo = ItemQueryRepository::new(&connection);
         let result = repo
             .query(
+                &store_id,
                 Pagination {
                     limit: keys.len() as u32,
                     offset: 0,
@@ -38,3 +46,29 @@ impl Loader<String> for ItemLoader {
         Ok(result)
     }
 }
+
+pub struct ItemStockSummaryLoader {
+    pub connection_manager: StorageConnectionManager,
+}
+
+#[async_trait::async_trait]
+impl Loader<String> for ItemStockSummaryLoader {
+    type Value = ItemStockSummary;
+    type Error = RepositoryError;
+
+    async fn load(&self, item_ids: &[String]) -> Result<HashMap<String, Self::Value>, Self::Error> {
+        let connection = self.connection_manager.connection()?;
+        // TODO use store from session
+        let store_id = current_store_id(&connection)?;
+        let result = get_item_stock_summaries(
+            &connection,
+            &store_id,
+            item_ids.to_vec(),
+            Utc::now().naive_utc(),
+        )?
+        .into_iter()
+        .map(|summary| (summary.item_id.clone(), summary))
+        .collect();
+        Ok(result)
+    }
+}
@@ -1,8 +1,8 @@
 use anymap::{any::Any, Map};
 
 use crate::loader::{
-    InvoiceLineLoader, InvoiceLoader, ItemLoader, RequisitionLineLoader, RequisitionLoader,
-    StoreLoader, UserAccountLoader,
+    InvoiceLineLoader, InvoiceLoader, ItemLoader, ItemStockSummaryLoader, RequisitionLineLoader,
+    RequisitionLoader, StoreLoader, UserAccountLoader,
 };
 
 use repository::StorageConnectionManager;
@@ -11,8 +11,8 @@ use async_graphql::dataloader::DataLoader;
 
 use super::{
     name::NameByIdLoader, InvoiceLineQueryLoader, InvoiceStatsLoader, LocationByIdLoader,
-    MasterListLineByMasterListId, StockLineByIdLoader, StockLineByItemIdLoader,
-    StockLineByLocationIdLoader,
+    MasterListLineByMasterListId, PackVariantByItemIdLoader, StockLineByIdLoader,
+    StockLineByItemIdLoader, StockLineByLocationIdLoader,
 };
 
 pub type LoaderMap = Map<AnyLoader>;
@@ -38,6 +38,10 @@ pub async fn get_loaders(connection_manager: &StorageConnectionManager) -> Loade
         connection_manager: connection_manager.clone(),
     });
 
+    let item_stock_summary_loader = DataLoader::new(ItemStockSummaryLoader {
+        connection_manager: connection_manager.clone(),
+    });
+
     let requisition_loader = DataLoader::new(RequisitionLoader {
         connection_manager: connection_manager.clone(),
     });
@@ -94,7 +98,12 @@ pub async fn get_loaders(connection_manager: &StorageConnectionManager) -> Loade
         connection_manager: connection_manager.clone(),
     });
 
+    let pack_variant_by_item_id_loader = DataLoader::new(PackVariantByItemIdLoader {
+        connection_manager: connection_manager.clone(),
+    });
+
     loaders.insert(item_loader);
+    loaders.insert(item_stock_summary_loader);
     loaders.insert(requisition_loader);
     loaders.insert(requisition_line_loader);
     loaders.insert(name_by_id_loader);
@@ -109,6 +118,7 @@ pub async fn get_loaders(connection_manager: &StorageConnectionManager) -> Loade
     loaders.insert(user_account_loader);
     loaders.insert(location_by_id_loader);
     loaders.insert(master_list_line_by_master_list_id);
+    loaders.insert(pack_variant_by_item_id_loader);
 
     loaders
 }
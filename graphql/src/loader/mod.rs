@@ -6,6 +6,7 @@ mod loader_registry;
 mod location;
 mod master_list_line;
 mod name;
+mod pack_variant;
 mod requisition;
 mod requisition_line;
 mod stock_line;
@@ -15,11 +16,12 @@ mod user_account;
 pub use invoice::{InvoiceLoader, InvoiceStatsLoader};
 pub use invoice_line::InvoiceLineLoader;
 pub use invoice_line_query::InvoiceLineQueryLoader;
-pub use item::ItemLoader;
+pub use item::{ItemLoader, ItemStockSummaryLoader};
 pub use loader_registry::{get_loaders, LoaderMap, LoaderRegistry};
 pub use location::{LocationByIdLoader, LocationRowByIdLoader};
 pub use master_list_line::MasterListLineByMasterListId;
 pub use name::NameByIdLoader;
+pub use pack_variant::PackVariantByItemIdLoader;
 pub use requisition::RequisitionLoader;
 pub use requisition_line::RequisitionLineLoader;
 pub use stock_line::{StockLineByIdLoader, StockLineByItemIdLoader, StockLineByLocationIdLoader};
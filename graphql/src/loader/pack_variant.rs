@@ -0,0 +1,30 @@
+use repository::schema::PackVariantRow;
+use repository::{PackVariantRowRepository, RepositoryError, StorageConnectionManager};
+
+use async_graphql::dataloader::*;
+use async_graphql::*;
+use std::collections::HashMap;
+
+pub struct PackVariantByItemIdLoader {
+    pub connection_manager: StorageConnectionManager,
+}
+
+#[async_trait::async_trait]
+impl Loader<String> for PackVariantByItemIdLoader {
+    type Value = Vec<PackVariantRow>;
+    type Error = RepositoryError;
+
+    async fn load(&self, item_ids: &[String]) -> Result<HashMap<String, Self::Value>, Self::Error> {
+        let connection = self.connection_manager.connection()?;
+        let repo = PackVariantRowRepository::new(&connection);
+
+        let mut result_map = HashMap::new();
+        for pack_variant in repo.find_many_by_item_ids(item_ids)? {
+            result_map
+                .entry(pack_variant.item_id.clone())
+                .or_insert(Vec::new())
+                .push(pack_variant);
+        }
+        Ok(result_map)
+    }
+}
@@ -0,0 +1,171 @@
+use actix_web::{
+    http::{header::CONTENT_DISPOSITION, StatusCode},
+    web::{Data, Path, Query},
+    HttpRequest, HttpResponse, ResponseError,
+};
+use repository::RepositoryError;
+use serde::Deserialize;
+use service::{
+    auth_data::AuthData,
+    current_store_id,
+    permission_validation::{
+        Resource, ResourceAccessRequest, ValidationDeniedKind, ValidationError,
+    },
+    report::{RenderReportError, ReportFormat},
+    service_provider::ServiceProvider,
+};
+use thiserror::Error;
+
+use crate::auth_data_from_request;
+
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ReportFormatQuery {
+    Html,
+    Pdf,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportQuery {
+    pub store_id: Option<String>,
+    /// Defaults to html
+    pub format: Option<ReportFormatQuery>,
+}
+
+#[derive(Debug, Error)]
+pub enum ReportError {
+    #[error("Not found: {0}")]
+    NotFound(String),
+    #[error("Bad user input: {0}")]
+    BadUserInput(String),
+    #[error("Unauthenticated: {0}")]
+    Unauthenticated(String),
+    #[error("Forbidden: {0}")]
+    Forbidden(String),
+    #[error("Internal error: {0}")]
+    InternalError(String),
+}
+
+impl ResponseError for ReportError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            ReportError::NotFound(_) => StatusCode::NOT_FOUND,
+            ReportError::BadUserInput(_) => StatusCode::BAD_REQUEST,
+            ReportError::Unauthenticated(_) => StatusCode::UNAUTHORIZED,
+            ReportError::Forbidden(_) => StatusCode::FORBIDDEN,
+            ReportError::InternalError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl From<RepositoryError> for ReportError {
+    fn from(error: RepositoryError) -> Self {
+        ReportError::InternalError(format!("{:?}", error))
+    }
+}
+
+impl From<ValidationError> for ReportError {
+    fn from(error: ValidationError) -> Self {
+        match error {
+            ValidationError::Denied(kind) => match kind {
+                ValidationDeniedKind::NotAuthenticated(_) => {
+                    ReportError::Unauthenticated(format!("{:?}", kind))
+                }
+                ValidationDeniedKind::InsufficientPermission(_) => {
+                    ReportError::Forbidden(format!("{:?}", kind))
+                }
+            },
+            ValidationError::InternalError(error) => ReportError::InternalError(error),
+        }
+    }
+}
+
+impl From<RenderReportError> for ReportError {
+    fn from(error: RenderReportError) -> Self {
+        match error {
+            RenderReportError::DatabaseError(error) => error.into(),
+            RenderReportError::ReportDoesNotExist => {
+                ReportError::NotFound("Report does not exist".to_string())
+            }
+            RenderReportError::RecordDoesNotExist => {
+                ReportError::NotFound("Record does not exist".to_string())
+            }
+            RenderReportError::WrongRecordType => {
+                ReportError::BadUserInput("Report is not for this type of record".to_string())
+            }
+            RenderReportError::TemplateError(error) => {
+                ReportError::InternalError(format!("Template error: {}", error))
+            }
+        }
+    }
+}
+
+/// Renders a report for a record, e.g. `GET /reports/packing_list/{invoice_id}?format=pdf`
+pub async fn report(
+    path: Path<(String, String)>,
+    query: Query<ReportQuery>,
+    http_req: HttpRequest,
+    service_provider: Data<ServiceProvider>,
+    auth_data: Data<AuthData>,
+) -> Result<HttpResponse, ReportError> {
+    let (report_id, record_id) = path.into_inner();
+    let ReportQuery { store_id, format } = query.into_inner();
+
+    let service_ctx = service_provider.context()?;
+    // TODO remove and make store_id parameter required
+    let store_id = match store_id {
+        Some(store_id) => store_id,
+        None => current_store_id(&service_ctx.connection)?,
+    };
+    service_provider.validation_service.validate(
+        &service_ctx,
+        &auth_data,
+        &auth_data_from_request(&http_req).auth_token,
+        &ResourceAccessRequest {
+            resource: Resource::RenderReport,
+            store_id: Some(store_id.clone()),
+        },
+    )?;
+
+    let format = match format {
+        Some(ReportFormatQuery::Pdf) => ReportFormat::Pdf,
+        Some(ReportFormatQuery::Html) | None => ReportFormat::Html,
+    };
+    let body = service_provider.report_service.render_report(
+        &service_ctx,
+        &store_id,
+        &report_id,
+        &record_id,
+        format,
+    )?;
+
+    let response = match format {
+        ReportFormat::Html => HttpResponse::Ok()
+            .content_type("text/html; charset=utf-8")
+            .body(body),
+        ReportFormat::Pdf => HttpResponse::Ok()
+            .content_type("application/pdf")
+            .header(
+                CONTENT_DISPOSITION,
+                format!(
+                    "inline; filename=\"{}.pdf\"",
+                    pdf_filename(&report_id, &record_id)
+                ),
+            )
+            .body(body),
+    };
+    Ok(response)
+}
+
+/// Report and record ids come from the path, characters that could break out of the
+/// Content-Disposition header value are replaced
+fn pdf_filename(report_id: &str, record_id: &str) -> String {
+    format!("{}_{}", report_id, record_id)
+        .chars()
+        .map(|c| match c {
+            'A'..='Z' | 'a'..='z' | '0'..='9' | '_' | '-' => c,
+            _ => '_',
+        })
+        .collect()
+}
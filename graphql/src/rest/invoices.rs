@@ -0,0 +1,190 @@
+use actix_web::{
+    web::{Data, Json, Path, Query},
+    HttpRequest,
+};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use domain::{
+    invoice::{Invoice, InvoiceFilter},
+    invoice_line::InvoiceLine,
+    EqualFilter, PaginationOption,
+};
+use repository::{InvoiceLineFilter, InvoiceLineRepository};
+use schemars::JsonSchema;
+use serde::Serialize;
+use service::{
+    auth_data::AuthData, invoice::get_invoices, permission_validation::Resource,
+    service_provider::ServiceProvider,
+};
+
+use crate::schema::types::{
+    convert_sort, InvoiceFilterInput, InvoiceLineNodeType, InvoiceNodeStatus, InvoiceNodeType,
+    InvoiceSortInput,
+};
+
+use super::{validate_request, ApiList, ListQuery, RestError, StoreQuery};
+
+#[derive(Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiInvoice {
+    pub id: String,
+    pub invoice_number: i64,
+    pub r#type: InvoiceNodeType,
+    pub status: InvoiceNodeStatus,
+    pub other_party_id: String,
+    pub other_party_name: String,
+    pub on_hold: bool,
+    pub their_reference: Option<String>,
+    pub comment: Option<String>,
+    pub color: Option<String>,
+    pub created_datetime: DateTime<Utc>,
+    pub allocated_datetime: Option<DateTime<Utc>>,
+    pub picked_datetime: Option<DateTime<Utc>>,
+    pub shipped_datetime: Option<DateTime<Utc>>,
+    pub delivered_datetime: Option<DateTime<Utc>>,
+    pub verified_datetime: Option<DateTime<Utc>>,
+}
+
+#[derive(Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiInvoiceLine {
+    pub id: String,
+    pub r#type: InvoiceLineNodeType,
+    pub item_id: String,
+    pub item_code: String,
+    pub item_name: String,
+    pub stock_line_id: Option<String>,
+    pub location_id: Option<String>,
+    pub location_name: Option<String>,
+    pub batch: Option<String>,
+    pub expiry_date: Option<NaiveDate>,
+    pub pack_size: i32,
+    pub number_of_packs: i32,
+    pub cost_price_per_pack: f64,
+    pub sell_price_per_pack: f64,
+    pub note: Option<String>,
+}
+
+#[derive(Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiInvoiceWithLines {
+    #[serde(flatten)]
+    pub invoice: ApiInvoice,
+    pub lines: Vec<ApiInvoiceLine>,
+}
+
+fn to_utc(datetime: NaiveDateTime) -> DateTime<Utc> {
+    DateTime::<Utc>::from_utc(datetime, Utc)
+}
+
+impl From<Invoice> for ApiInvoice {
+    fn from(invoice: Invoice) -> Self {
+        ApiInvoice {
+            id: invoice.id,
+            invoice_number: invoice.invoice_number,
+            r#type: InvoiceNodeType::from(invoice.r#type),
+            status: InvoiceNodeStatus::from(invoice.status),
+            other_party_id: invoice.other_party_id,
+            other_party_name: invoice.other_party_name,
+            on_hold: invoice.on_hold,
+            their_reference: invoice.their_reference,
+            comment: invoice.comment,
+            color: invoice.color,
+            created_datetime: to_utc(invoice.created_datetime),
+            allocated_datetime: invoice.allocated_datetime.map(to_utc),
+            picked_datetime: invoice.picked_datetime.map(to_utc),
+            shipped_datetime: invoice.shipped_datetime.map(to_utc),
+            delivered_datetime: invoice.delivered_datetime.map(to_utc),
+            verified_datetime: invoice.verified_datetime.map(to_utc),
+        }
+    }
+}
+
+impl From<InvoiceLine> for ApiInvoiceLine {
+    fn from(line: InvoiceLine) -> Self {
+        ApiInvoiceLine {
+            id: line.id,
+            r#type: InvoiceLineNodeType::from_domain(&line.r#type),
+            item_id: line.item_id,
+            item_code: line.item_code,
+            item_name: line.item_name,
+            stock_line_id: line.stock_line_id,
+            location_id: line.location_id,
+            location_name: line.location_name,
+            batch: line.batch,
+            expiry_date: line.expiry_date,
+            pack_size: line.pack_size,
+            number_of_packs: line.number_of_packs,
+            cost_price_per_pack: line.cost_price_per_pack,
+            sell_price_per_pack: line.sell_price_per_pack,
+            note: line.note,
+        }
+    }
+}
+
+/// `GET /api/v1/invoices`
+pub async fn list_invoices(
+    query: Query<ListQuery>,
+    http_req: HttpRequest,
+    service_provider: Data<ServiceProvider>,
+    auth_data: Data<AuthData>,
+) -> Result<Json<ApiList<ApiInvoice>>, RestError> {
+    let (_, store_id) = validate_request(
+        &service_provider,
+        &auth_data,
+        &http_req,
+        query.store_id.clone(),
+        Resource::QueryInvoices,
+    )?;
+
+    let mut filter = query
+        .filter::<InvoiceFilterInput>()?
+        .map(InvoiceFilter::from)
+        .unwrap_or_else(InvoiceFilter::new);
+    filter.store_id = Some(EqualFilter::equal_to(&store_id));
+    let invoices = get_invoices(
+        &service_provider.connection_manager,
+        query.page(),
+        Some(filter),
+        convert_sort(query.sort::<InvoiceSortInput>()?),
+    )?;
+    Ok(Json(ApiList::from_list(invoices, ApiInvoice::from)))
+}
+
+/// `GET /api/v1/invoices/{id}`
+pub async fn get_invoice(
+    id: Path<String>,
+    query: Query<StoreQuery>,
+    http_req: HttpRequest,
+    service_provider: Data<ServiceProvider>,
+    auth_data: Data<AuthData>,
+) -> Result<Json<ApiInvoiceWithLines>, RestError> {
+    let (service_ctx, store_id) = validate_request(
+        &service_provider,
+        &auth_data,
+        &http_req,
+        query.into_inner().store_id,
+        Resource::QueryInvoices,
+    )?;
+
+    let mut filter = InvoiceFilter::new().id(EqualFilter::equal_to(&id));
+    filter.store_id = Some(EqualFilter::equal_to(&store_id));
+    let invoice = get_invoices(
+        &service_provider.connection_manager,
+        Some(PaginationOption {
+            limit: Some(1),
+            offset: None,
+        }),
+        Some(filter),
+        None,
+    )?
+    .rows
+    .pop()
+    .ok_or_else(|| RestError::NotFound(id.to_string()))?;
+    let lines = InvoiceLineRepository::new(&service_ctx.connection)
+        .query_by_filter(InvoiceLineFilter::new().invoice_id(EqualFilter::equal_to(&id)))?;
+
+    Ok(Json(ApiInvoiceWithLines {
+        invoice: ApiInvoice::from(invoice),
+        lines: lines.into_iter().map(ApiInvoiceLine::from).collect(),
+    }))
+}
@@ -0,0 +1,99 @@
+use actix_web::{
+    web::{Data, Json, Path, Query},
+    HttpRequest,
+};
+use domain::{item::Item, EqualFilter, PaginationOption};
+use repository::ItemFilter;
+use schemars::JsonSchema;
+use serde::Serialize;
+use service::{
+    auth_data::AuthData, item::get_items, permission_validation::Resource,
+    service_provider::ServiceProvider,
+};
+
+use crate::schema::{
+    queries::{ItemFilterInput, ItemSortInput},
+    types::convert_sort,
+};
+
+use super::{validate_request, ApiList, ListQuery, RestError, StoreQuery};
+
+#[derive(Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiItem {
+    pub id: String,
+    pub code: String,
+    pub name: String,
+    pub unit_name: Option<String>,
+    /// Item is visible in the store
+    pub is_visible: bool,
+}
+
+impl From<Item> for ApiItem {
+    fn from(item: Item) -> Self {
+        ApiItem {
+            id: item.id,
+            code: item.code,
+            name: item.name,
+            unit_name: item.unit_name,
+            is_visible: item.is_visible,
+        }
+    }
+}
+
+/// `GET /api/v1/items`
+pub async fn list_items(
+    query: Query<ListQuery>,
+    http_req: HttpRequest,
+    service_provider: Data<ServiceProvider>,
+    auth_data: Data<AuthData>,
+) -> Result<Json<ApiList<ApiItem>>, RestError> {
+    let (_, store_id) = validate_request(
+        &service_provider,
+        &auth_data,
+        &http_req,
+        query.store_id.clone(),
+        Resource::QueryItems,
+    )?;
+
+    let items = get_items(
+        &service_provider.connection_manager,
+        &store_id,
+        query.page(),
+        query.filter::<ItemFilterInput>()?.map(ItemFilter::from),
+        convert_sort(query.sort::<ItemSortInput>()?),
+    )?;
+    Ok(Json(ApiList::from_list(items, ApiItem::from)))
+}
+
+/// `GET /api/v1/items/{id}`
+pub async fn get_item(
+    id: Path<String>,
+    query: Query<StoreQuery>,
+    http_req: HttpRequest,
+    service_provider: Data<ServiceProvider>,
+    auth_data: Data<AuthData>,
+) -> Result<Json<ApiItem>, RestError> {
+    let (_, store_id) = validate_request(
+        &service_provider,
+        &auth_data,
+        &http_req,
+        query.into_inner().store_id,
+        Resource::QueryItems,
+    )?;
+
+    let item = get_items(
+        &service_provider.connection_manager,
+        &store_id,
+        Some(PaginationOption {
+            limit: Some(1),
+            offset: None,
+        }),
+        Some(ItemFilter::new().id(EqualFilter::equal_to(&id))),
+        None,
+    )?
+    .rows
+    .pop()
+    .ok_or_else(|| RestError::NotFound(id.to_string()))?;
+    Ok(Json(ApiItem::from(item)))
+}
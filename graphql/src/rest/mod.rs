@@ -0,0 +1,359 @@
+use actix_web::{
+    http::StatusCode,
+    web::{self, Data},
+    HttpRequest, HttpResponse, ResponseError, Route,
+};
+use async_graphql::{InputType, Pos};
+use domain::PaginationOption;
+use repository::RepositoryError;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::{Deserialize, Serialize};
+use service::{
+    auth_data::AuthData,
+    current_store_id,
+    permission_validation::{
+        Resource, ResourceAccessRequest, ValidationDeniedKind, ValidationError,
+    },
+    service_provider::{ServiceContext, ServiceProvider},
+    ListError, ListResult, SingleRecordError,
+};
+use thiserror::Error;
+
+use crate::auth_data_from_request;
+
+pub mod invoices;
+pub mod items;
+pub mod names;
+pub mod openapi;
+pub mod stock_lines;
+pub mod stock_takes;
+
+/// Base path of the current version of the REST API
+pub const API_V1_PATH: &str = "/api/v1";
+
+/// Query parameters of a list endpoint, `filter` and `sort` take the JSON encoded inputs of the
+/// GraphQL list query
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ListQuery {
+    pub store_id: Option<String>,
+    /// Max number of returned items
+    pub first: Option<u32>,
+    /// First returned item is at the `offset` position in the full list
+    pub offset: Option<u32>,
+    pub filter: Option<String>,
+    pub sort: Option<String>,
+}
+
+impl ListQuery {
+    pub fn page(&self) -> Option<PaginationOption> {
+        if self.first.is_none() && self.offset.is_none() {
+            return None;
+        }
+        Some(PaginationOption {
+            limit: self.first,
+            offset: self.offset,
+        })
+    }
+
+    pub fn filter<T: InputType>(&self) -> Result<Option<T>, RestError> {
+        parse_input(&self.filter)
+    }
+
+    /// Only the first sort input is evaluated, same as for the GraphQL list queries
+    pub fn sort<T: InputType>(&self) -> Result<Option<Vec<T>>, RestError> {
+        parse_input(&self.sort)
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct StoreQuery {
+    pub store_id: Option<String>,
+}
+
+#[derive(Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiList<T> {
+    pub total_count: u32,
+    pub nodes: Vec<T>,
+}
+
+impl<T> ApiList<T> {
+    pub fn from_list<R>(list: ListResult<R>, f: impl Fn(R) -> T) -> ApiList<T> {
+        ApiList {
+            total_count: list.count,
+            nodes: list.rows.into_iter().map(f).collect(),
+        }
+    }
+}
+
+#[derive(Serialize, JsonSchema)]
+pub struct ApiErrorBody {
+    pub message: String,
+}
+
+#[derive(Debug, Error)]
+pub enum RestError {
+    #[error("Not found: {0}")]
+    NotFound(String),
+    #[error("Bad user input: {0}")]
+    BadUserInput(String),
+    #[error("Unauthenticated: {0}")]
+    Unauthenticated(String),
+    #[error("Forbidden: {0}")]
+    Forbidden(String),
+    #[error("Internal error: {0}")]
+    InternalError(String),
+}
+
+impl ResponseError for RestError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            RestError::NotFound(_) => StatusCode::NOT_FOUND,
+            RestError::BadUserInput(_) => StatusCode::BAD_REQUEST,
+            RestError::Unauthenticated(_) => StatusCode::UNAUTHORIZED,
+            RestError::Forbidden(_) => StatusCode::FORBIDDEN,
+            RestError::InternalError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code()).json(ApiErrorBody {
+            message: self.to_string(),
+        })
+    }
+}
+
+impl From<RepositoryError> for RestError {
+    fn from(error: RepositoryError) -> Self {
+        RestError::InternalError(format!("{:?}", error))
+    }
+}
+
+impl From<ValidationError> for RestError {
+    fn from(error: ValidationError) -> Self {
+        match error {
+            ValidationError::Denied(kind) => match kind {
+                ValidationDeniedKind::NotAuthenticated(_) => {
+                    RestError::Unauthenticated(format!("{:?}", kind))
+                }
+                ValidationDeniedKind::InsufficientPermission(_) => {
+                    RestError::Forbidden(format!("{:?}", kind))
+                }
+            },
+            ValidationError::InternalError(error) => RestError::InternalError(error),
+        }
+    }
+}
+
+impl From<ListError> for RestError {
+    fn from(error: ListError) -> Self {
+        match error {
+            ListError::DatabaseError(error) => error.into(),
+            ListError::LimitBelowMin(min) => {
+                RestError::BadUserInput(format!("first must be at least {}", min))
+            }
+            ListError::LimitAboveMax(max) => {
+                RestError::BadUserInput(format!("first must be at most {}", max))
+            }
+        }
+    }
+}
+
+impl From<SingleRecordError> for RestError {
+    fn from(error: SingleRecordError) -> Self {
+        match error {
+            SingleRecordError::DatabaseError(error) => error.into(),
+            SingleRecordError::NotFound(id) => RestError::NotFound(id),
+        }
+    }
+}
+
+/// Parses a JSON encoded GraphQL input
+fn parse_input<T: InputType>(value: &Option<String>) -> Result<Option<T>, RestError> {
+    let value = match value {
+        None => return Ok(None),
+        Some(value) => serde_json::from_str::<serde_json::Value>(value)
+            .map_err(|error| RestError::BadUserInput(error.to_string()))?,
+    };
+    if value.is_null() {
+        return Ok(None);
+    }
+    let value = async_graphql::Value::from_json(value)
+        .map_err(|error| RestError::BadUserInput(error.to_string()))?;
+    T::parse(Some(value))
+        .map(Some)
+        .map_err(|error| RestError::BadUserInput(error.into_server_error(Pos::default()).message))
+}
+
+/// Checks the JWT of the request has access to the resource in the store, returns the service
+/// context and the store id
+pub fn validate_request(
+    service_provider: &ServiceProvider,
+    auth_data: &AuthData,
+    http_req: &HttpRequest,
+    store_id: Option<String>,
+    resource: Resource,
+) -> Result<(ServiceContext, String), RestError> {
+    let service_ctx = service_provider.context()?;
+    // TODO remove and make store_id parameter required
+    let store_id = match store_id {
+        Some(store_id) => store_id,
+        None => current_store_id(&service_ctx.connection)?,
+    };
+    service_provider.validation_service.validate(
+        &service_ctx,
+        auth_data,
+        &auth_data_from_request(http_req).auth_token,
+        &ResourceAccessRequest {
+            resource,
+            store_id: Some(store_id.clone()),
+        },
+    )?;
+    Ok((service_ctx, store_id))
+}
+
+/// Endpoint of the REST API, used for routing and for the OpenAPI document
+pub struct ApiEndpoint {
+    /// Path relative to [API_V1_PATH], path parameters are in braces, e.g. `/items/{id}`
+    pub path: &'static str,
+    pub operation_id: &'static str,
+    pub tag: &'static str,
+    pub summary: &'static str,
+    /// Names of the GraphQL filter and sort inputs of a list endpoint
+    pub list: Option<ApiListInputs>,
+    pub response: fn(&mut SchemaGenerator) -> Schema,
+    pub route: fn() -> Route,
+}
+
+pub struct ApiListInputs {
+    pub filter: &'static str,
+    pub sort: Option<&'static str>,
+}
+
+pub fn endpoints() -> Vec<ApiEndpoint> {
+    vec![
+        ApiEndpoint {
+            path: "/items",
+            operation_id: "listItems",
+            tag: "items",
+            summary: "Items visible in the store",
+            list: Some(ApiListInputs {
+                filter: "ItemFilterInput",
+                sort: Some("ItemSortInput"),
+            }),
+            response: |gen| gen.subschema_for::<ApiList<items::ApiItem>>(),
+            route: || web::get().to(items::list_items),
+        },
+        ApiEndpoint {
+            path: "/items/{id}",
+            operation_id: "getItem",
+            tag: "items",
+            summary: "Item by id",
+            list: None,
+            response: |gen| gen.subschema_for::<items::ApiItem>(),
+            route: || web::get().to(items::get_item),
+        },
+        ApiEndpoint {
+            path: "/names",
+            operation_id: "listNames",
+            tag: "names",
+            summary: "Customers, suppliers and stores",
+            list: Some(ApiListInputs {
+                filter: "NameFilterInput",
+                sort: Some("NameSortInput"),
+            }),
+            response: |gen| gen.subschema_for::<ApiList<names::ApiName>>(),
+            route: || web::get().to(names::list_names),
+        },
+        ApiEndpoint {
+            path: "/names/{id}",
+            operation_id: "getName",
+            tag: "names",
+            summary: "Name by id",
+            list: None,
+            response: |gen| gen.subschema_for::<names::ApiName>(),
+            route: || web::get().to(names::get_name),
+        },
+        ApiEndpoint {
+            path: "/stock-lines",
+            operation_id: "listStockLines",
+            tag: "stock lines",
+            summary: "Stock lines of the store",
+            list: Some(ApiListInputs {
+                filter: "StockLineFilterInput",
+                sort: None,
+            }),
+            response: |gen| gen.subschema_for::<ApiList<stock_lines::ApiStockLine>>(),
+            route: || web::get().to(stock_lines::list_stock_lines),
+        },
+        ApiEndpoint {
+            path: "/stock-lines/{id}",
+            operation_id: "getStockLine",
+            tag: "stock lines",
+            summary: "Stock line by id",
+            list: None,
+            response: |gen| gen.subschema_for::<stock_lines::ApiStockLine>(),
+            route: || web::get().to(stock_lines::get_stock_line),
+        },
+        ApiEndpoint {
+            path: "/invoices",
+            operation_id: "listInvoices",
+            tag: "invoices",
+            summary: "Invoices of the store, without lines",
+            list: Some(ApiListInputs {
+                filter: "InvoiceFilterInput",
+                sort: Some("InvoiceSortInput"),
+            }),
+            response: |gen| gen.subschema_for::<ApiList<invoices::ApiInvoice>>(),
+            route: || web::get().to(invoices::list_invoices),
+        },
+        ApiEndpoint {
+            path: "/invoices/{id}",
+            operation_id: "getInvoice",
+            tag: "invoices",
+            summary: "Invoice by id, with lines",
+            list: None,
+            response: |gen| gen.subschema_for::<invoices::ApiInvoiceWithLines>(),
+            route: || web::get().to(invoices::get_invoice),
+        },
+        ApiEndpoint {
+            path: "/stock-takes",
+            operation_id: "listStockTakes",
+            tag: "stock takes",
+            summary: "Stock takes of the store, without lines",
+            list: Some(ApiListInputs {
+                filter: "StockTakeFilterInput",
+                sort: Some("StockTakeSortInput"),
+            }),
+            response: |gen| gen.subschema_for::<ApiList<stock_takes::ApiStockTake>>(),
+            route: || web::get().to(stock_takes::list_stock_takes),
+        },
+        ApiEndpoint {
+            path: "/stock-takes/{id}",
+            operation_id: "getStockTake",
+            tag: "stock takes",
+            summary: "Stock take by id, with lines",
+            list: None,
+            response: |gen| gen.subschema_for::<stock_takes::ApiStockTakeWithLines>(),
+            route: || web::get().to(stock_takes::get_stock_take),
+        },
+    ]
+}
+
+pub fn config(
+    service_provider: Data<ServiceProvider>,
+    auth_data: Data<AuthData>,
+) -> impl FnOnce(&mut web::ServiceConfig) {
+    |cfg| {
+        let scope = endpoints().into_iter().fold(
+            web::scope(API_V1_PATH)
+                .app_data(service_provider)
+                .app_data(auth_data)
+                .route("/openapi.json", web::get().to(openapi::openapi)),
+            |scope, endpoint| scope.route(endpoint.path, (endpoint.route)()),
+        );
+        cfg.service(scope);
+    }
+}
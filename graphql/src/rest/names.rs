@@ -0,0 +1,98 @@
+use actix_web::{
+    web::{Data, Json, Path, Query},
+    HttpRequest,
+};
+use domain::{
+    name::{Name, NameFilter},
+    EqualFilter, PaginationOption,
+};
+use schemars::JsonSchema;
+use serde::Serialize;
+use service::{
+    auth_data::AuthData, name::get_names, permission_validation::Resource,
+    service_provider::ServiceProvider,
+};
+
+use crate::schema::{
+    queries::{NameFilterInput, NameSortInput},
+    types::convert_sort,
+};
+
+use super::{validate_request, ApiList, ListQuery, RestError, StoreQuery};
+
+#[derive(Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiName {
+    pub id: String,
+    pub code: String,
+    pub name: String,
+    pub is_customer: bool,
+    pub is_supplier: bool,
+}
+
+impl From<Name> for ApiName {
+    fn from(name: Name) -> Self {
+        ApiName {
+            id: name.id,
+            code: name.code,
+            name: name.name,
+            is_customer: name.is_customer,
+            is_supplier: name.is_supplier,
+        }
+    }
+}
+
+/// `GET /api/v1/names`
+pub async fn list_names(
+    query: Query<ListQuery>,
+    http_req: HttpRequest,
+    service_provider: Data<ServiceProvider>,
+    auth_data: Data<AuthData>,
+) -> Result<Json<ApiList<ApiName>>, RestError> {
+    validate_request(
+        &service_provider,
+        &auth_data,
+        &http_req,
+        query.store_id.clone(),
+        Resource::QueryNames,
+    )?;
+
+    let names = get_names(
+        &service_provider.connection_manager,
+        query.page(),
+        query.filter::<NameFilterInput>()?.map(NameFilter::from),
+        convert_sort(query.sort::<NameSortInput>()?),
+    )?;
+    Ok(Json(ApiList::from_list(names, ApiName::from)))
+}
+
+/// `GET /api/v1/names/{id}`
+pub async fn get_name(
+    id: Path<String>,
+    query: Query<StoreQuery>,
+    http_req: HttpRequest,
+    service_provider: Data<ServiceProvider>,
+    auth_data: Data<AuthData>,
+) -> Result<Json<ApiName>, RestError> {
+    validate_request(
+        &service_provider,
+        &auth_data,
+        &http_req,
+        query.into_inner().store_id,
+        Resource::QueryNames,
+    )?;
+
+    let name = get_names(
+        &service_provider.connection_manager,
+        Some(PaginationOption {
+            limit: Some(1),
+            offset: None,
+        }),
+        Some(NameFilter::new().id(EqualFilter::equal_to(&id))),
+        None,
+    )?
+    .rows
+    .pop()
+    .ok_or_else(|| RestError::NotFound(id.to_string()))?;
+    Ok(Json(ApiName::from(name)))
+}
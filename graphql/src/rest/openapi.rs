@@ -0,0 +1,149 @@
+use actix_web::HttpResponse;
+use domain::DEFAULT_LIMIT;
+use schemars::gen::SchemaSettings;
+use serde_json::{json, Map, Value};
+
+use super::{endpoints, ApiErrorBody, API_V1_PATH};
+
+fn parameter(
+    name: &str,
+    location: &str,
+    required: bool,
+    schema: Value,
+    description: &str,
+) -> Value {
+    json!({
+        "name": name,
+        "in": location,
+        "required": required,
+        "schema": schema,
+        "description": description,
+    })
+}
+
+/// OpenAPI 3 document of the REST API, generated from the [endpoints] and their response types
+pub fn openapi_document() -> Value {
+    let mut gen = SchemaSettings::openapi3().into_generator();
+    let error_schema =
+        serde_json::to_value(gen.subschema_for::<ApiErrorBody>()).unwrap_or_default();
+
+    let mut paths = Map::new();
+    for endpoint in endpoints() {
+        let mut parameters: Vec<Value> = endpoint
+            .path
+            .split('/')
+            .filter_map(|segment| segment.strip_prefix('{')?.strip_suffix('}'))
+            .map(|name| parameter(name, "path", true, json!({ "type": "string" }), ""))
+            .collect();
+        parameters.push(parameter(
+            "storeId",
+            "query",
+            false,
+            json!({ "type": "string" }),
+            "Defaults to the store of the server",
+        ));
+
+        let mut responses = Map::new();
+        responses.insert(
+            "200".to_string(),
+            json!({
+                "description": "Success",
+                "content": { "application/json": { "schema": (endpoint.response)(&mut gen) } },
+            }),
+        );
+        if let Some(list) = &endpoint.list {
+            parameters.push(parameter(
+                "first",
+                "query",
+                false,
+                json!({ "type": "integer", "minimum": 1 }),
+                &format!(
+                    "Max number of returned items, defaults to {}",
+                    DEFAULT_LIMIT
+                ),
+            ));
+            parameters.push(parameter(
+                "offset",
+                "query",
+                false,
+                json!({ "type": "integer", "minimum": 0 }),
+                "First returned item is at the `offset` position in the full list",
+            ));
+            parameters.push(parameter(
+                "filter",
+                "query",
+                false,
+                json!({ "type": "string" }),
+                &format!("JSON encoded `{}` of the GraphQL API", list.filter),
+            ));
+            if let Some(sort) = list.sort {
+                parameters.push(parameter(
+                    "sort",
+                    "query",
+                    false,
+                    json!({ "type": "string" }),
+                    &format!(
+                        "JSON encoded list of `{}` of the GraphQL API, only the first is evaluated",
+                        sort
+                    ),
+                ));
+            }
+            responses.insert(
+                "400".to_string(),
+                error_response("Invalid filter, sort or pagination", &error_schema),
+            );
+        } else {
+            responses.insert(
+                "404".to_string(),
+                error_response("Record doesn't exist in the store", &error_schema),
+            );
+        }
+        responses.insert(
+            "401".to_string(),
+            error_response("Missing or invalid JWT", &error_schema),
+        );
+        responses.insert(
+            "403".to_string(),
+            error_response("User has no access to the store", &error_schema),
+        );
+
+        paths.insert(
+            endpoint.path.to_string(),
+            json!({
+                "get": {
+                    "operationId": endpoint.operation_id,
+                    "tags": [endpoint.tag],
+                    "summary": endpoint.summary,
+                    "parameters": parameters,
+                    "responses": responses,
+                }
+            }),
+        );
+    }
+
+    json!({
+        "openapi": "3.0.3",
+        "info": { "title": "omSupply REST API", "version": "1" },
+        "servers": [{ "url": API_V1_PATH }],
+        "security": [{ "bearerAuth": [] }],
+        "paths": paths,
+        "components": {
+            "schemas": gen.definitions(),
+            "securitySchemes": {
+                "bearerAuth": { "type": "http", "scheme": "bearer", "bearerFormat": "JWT" }
+            },
+        },
+    })
+}
+
+fn error_response(description: &str, error_schema: &Value) -> Value {
+    json!({
+        "description": description,
+        "content": { "application/json": { "schema": error_schema } },
+    })
+}
+
+/// `GET /api/v1/openapi.json`
+pub async fn openapi() -> HttpResponse {
+    HttpResponse::Ok().json(openapi_document())
+}
@@ -0,0 +1,118 @@
+use actix_web::{
+    web::{Data, Json, Path, Query},
+    HttpRequest,
+};
+use chrono::NaiveDate;
+use domain::{
+    stock_line::{StockLine, StockLineFilter},
+    EqualFilter,
+};
+use schemars::JsonSchema;
+use serde::Serialize;
+use service::{
+    auth_data::AuthData, permission_validation::Resource, service_provider::ServiceProvider,
+    stock_line::get_stock_lines,
+};
+
+use crate::schema::types::StockLineFilterInput;
+
+use super::{validate_request, ApiList, ListQuery, RestError, StoreQuery};
+
+#[derive(Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiStockLine {
+    pub id: String,
+    pub item_id: String,
+    pub store_id: String,
+    pub location_id: Option<String>,
+    pub location_name: Option<String>,
+    pub batch: Option<String>,
+    pub expiry_date: Option<NaiveDate>,
+    pub pack_size: i32,
+    pub cost_price_per_pack: f64,
+    pub sell_price_per_pack: f64,
+    pub available_number_of_packs: i32,
+    pub total_number_of_packs: i32,
+    pub on_hold: bool,
+    pub note: Option<String>,
+}
+
+impl From<StockLine> for ApiStockLine {
+    fn from(stock_line: StockLine) -> Self {
+        ApiStockLine {
+            id: stock_line.id,
+            item_id: stock_line.item_id,
+            store_id: stock_line.store_id,
+            location_id: stock_line.location_id,
+            location_name: stock_line.location_name,
+            batch: stock_line.batch,
+            expiry_date: stock_line.expiry_date,
+            pack_size: stock_line.pack_size,
+            cost_price_per_pack: stock_line.cost_price_per_pack,
+            sell_price_per_pack: stock_line.sell_price_per_pack,
+            available_number_of_packs: stock_line.available_number_of_packs,
+            total_number_of_packs: stock_line.total_number_of_packs,
+            on_hold: stock_line.on_hold,
+            note: stock_line.note,
+        }
+    }
+}
+
+/// `GET /api/v1/stock-lines`
+pub async fn list_stock_lines(
+    query: Query<ListQuery>,
+    http_req: HttpRequest,
+    service_provider: Data<ServiceProvider>,
+    auth_data: Data<AuthData>,
+) -> Result<Json<ApiList<ApiStockLine>>, RestError> {
+    let (_, store_id) = validate_request(
+        &service_provider,
+        &auth_data,
+        &http_req,
+        query.store_id.clone(),
+        Resource::QueryStockLines,
+    )?;
+
+    let filter = query
+        .filter::<StockLineFilterInput>()?
+        .map(StockLineFilter::from)
+        .unwrap_or_else(StockLineFilter::new)
+        .store_id(EqualFilter::equal_to(&store_id));
+    let stock_lines = get_stock_lines(
+        &service_provider.connection_manager,
+        query.page(),
+        Some(filter),
+    )?;
+    Ok(Json(ApiList::from_list(stock_lines, ApiStockLine::from)))
+}
+
+/// `GET /api/v1/stock-lines/{id}`
+pub async fn get_stock_line(
+    id: Path<String>,
+    query: Query<StoreQuery>,
+    http_req: HttpRequest,
+    service_provider: Data<ServiceProvider>,
+    auth_data: Data<AuthData>,
+) -> Result<Json<ApiStockLine>, RestError> {
+    let (_, store_id) = validate_request(
+        &service_provider,
+        &auth_data,
+        &http_req,
+        query.into_inner().store_id,
+        Resource::QueryStockLines,
+    )?;
+
+    let stock_line = get_stock_lines(
+        &service_provider.connection_manager,
+        None,
+        Some(
+            StockLineFilter::new()
+                .id(EqualFilter::equal_to(&id))
+                .store_id(EqualFilter::equal_to(&store_id)),
+        ),
+    )?
+    .rows
+    .pop()
+    .ok_or_else(|| RestError::NotFound(id.to_string()))?;
+    Ok(Json(ApiStockLine::from(stock_line)))
+}
@@ -0,0 +1,160 @@
+use actix_web::{
+    web::{Data, Json, Path, Query},
+    HttpRequest,
+};
+use chrono::{DateTime, NaiveDate, Utc};
+use domain::EqualFilter;
+use repository::{
+    StockTake, StockTakeFilter, StockTakeLine, StockTakeLineFilter, StockTakeLineRepository,
+};
+use schemars::JsonSchema;
+use serde::Serialize;
+use service::{
+    auth_data::AuthData, permission_validation::Resource, service_provider::ServiceProvider,
+};
+
+use crate::schema::{
+    mutations::stock_take::{
+        from_domain, StockTakeFilterInput, StockTakeNodeStatus, StockTakeSortInput,
+    },
+    types::convert_sort,
+};
+
+use super::{validate_request, ApiList, ListQuery, RestError, StoreQuery};
+
+#[derive(Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiStockTake {
+    pub id: String,
+    pub store_id: String,
+    pub status: StockTakeNodeStatus,
+    pub description: Option<String>,
+    pub comment: Option<String>,
+    pub created_datetime: DateTime<Utc>,
+    pub finalised_datetime: Option<DateTime<Utc>>,
+    /// Inventory adjustment created when the stock take was finalised
+    pub inventory_adjustment_id: Option<String>,
+}
+
+#[derive(Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiStockTakeLine {
+    pub id: String,
+    pub item_id: String,
+    /// Missing if the line creates a new stock line
+    pub stock_line_id: Option<String>,
+    pub location_id: Option<String>,
+    pub batch: Option<String>,
+    pub expiry_date: Option<NaiveDate>,
+    pub pack_size: Option<i32>,
+    pub snapshot_number_of_packs: i32,
+    pub counted_number_of_packs: Option<i32>,
+    pub cost_price_per_pack: Option<f64>,
+    pub sell_price_per_pack: Option<f64>,
+    pub comment: Option<String>,
+    pub note: Option<String>,
+}
+
+#[derive(Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiStockTakeWithLines {
+    #[serde(flatten)]
+    pub stock_take: ApiStockTake,
+    pub lines: Vec<ApiStockTakeLine>,
+}
+
+impl From<StockTake> for ApiStockTake {
+    fn from(stock_take: StockTake) -> Self {
+        ApiStockTake {
+            id: stock_take.id,
+            store_id: stock_take.store_id,
+            status: from_domain(&stock_take.status),
+            description: stock_take.description,
+            comment: stock_take.comment,
+            created_datetime: DateTime::<Utc>::from_utc(stock_take.created_datetime, Utc),
+            finalised_datetime: stock_take
+                .finalised_datetime
+                .map(|datetime| DateTime::<Utc>::from_utc(datetime, Utc)),
+            inventory_adjustment_id: stock_take.inventory_adjustment_id,
+        }
+    }
+}
+
+impl From<StockTakeLine> for ApiStockTakeLine {
+    fn from(StockTakeLine { line, .. }: StockTakeLine) -> Self {
+        ApiStockTakeLine {
+            id: line.id,
+            item_id: line.item_id,
+            stock_line_id: line.stock_line_id,
+            location_id: line.location_id,
+            batch: line.batch,
+            expiry_date: line.expiry_date,
+            pack_size: line.pack_size,
+            snapshot_number_of_packs: line.snapshot_number_of_packs,
+            counted_number_of_packs: line.counted_number_of_packs,
+            cost_price_per_pack: line.cost_price_per_pack,
+            sell_price_per_pack: line.sell_price_per_pack,
+            comment: line.comment,
+            note: line.note,
+        }
+    }
+}
+
+/// `GET /api/v1/stock-takes`
+pub async fn list_stock_takes(
+    query: Query<ListQuery>,
+    http_req: HttpRequest,
+    service_provider: Data<ServiceProvider>,
+    auth_data: Data<AuthData>,
+) -> Result<Json<ApiList<ApiStockTake>>, RestError> {
+    let (service_ctx, store_id) = validate_request(
+        &service_provider,
+        &auth_data,
+        &http_req,
+        query.store_id.clone(),
+        Resource::QueryStockTakes,
+    )?;
+
+    let filter = query
+        .filter::<StockTakeFilterInput>()?
+        .map(StockTakeFilter::from)
+        .unwrap_or_else(StockTakeFilter::new)
+        .store_id(EqualFilter::equal_to(&store_id));
+    let stock_takes = service_provider.stock_take_service.get_stock_takes(
+        &service_ctx,
+        query.page(),
+        Some(filter),
+        convert_sort(query.sort::<StockTakeSortInput>()?),
+    )?;
+    Ok(Json(ApiList::from_list(stock_takes, ApiStockTake::from)))
+}
+
+/// `GET /api/v1/stock-takes/{id}`
+pub async fn get_stock_take(
+    id: Path<String>,
+    query: Query<StoreQuery>,
+    http_req: HttpRequest,
+    service_provider: Data<ServiceProvider>,
+    auth_data: Data<AuthData>,
+) -> Result<Json<ApiStockTakeWithLines>, RestError> {
+    let (service_ctx, store_id) = validate_request(
+        &service_provider,
+        &auth_data,
+        &http_req,
+        query.into_inner().store_id,
+        Resource::QueryStockTakes,
+    )?;
+
+    let stock_take = service_provider
+        .stock_take_service
+        .get_stock_take(&service_ctx, id.to_string())?
+        .filter(|stock_take| stock_take.store_id == store_id)
+        .ok_or_else(|| RestError::NotFound(id.to_string()))?;
+    let lines = StockTakeLineRepository::new(&service_ctx.connection)
+        .query_by_filter(StockTakeLineFilter::new().stock_take_id(EqualFilter::equal_to(&id)))?;
+
+    Ok(Json(ApiStockTakeWithLines {
+        stock_take: ApiStockTake::from(stock_take),
+        lines: lines.into_iter().map(ApiStockTakeLine::from).collect(),
+    }))
+}
@@ -0,0 +1,146 @@
+use async_graphql::*;
+use service::{
+    barcode::{
+        delete::DeleteBarcodeError,
+        insert::{InsertBarcode, InsertBarcodeError},
+        update::{UpdateBarcode, UpdateBarcodeError},
+    },
+    permission_validation::{Resource, ResourceAccessRequest},
+};
+
+use crate::{
+    schema::queries::barcode::BarcodeNode,
+    standard_graphql_error::{validate_auth, StandardGraphqlError},
+    ContextExt,
+};
+
+#[derive(InputObject)]
+pub struct InsertBarcodeInput {
+    pub id: String,
+    pub value: String,
+    pub item_id: String,
+    pub manufacturer_id: Option<String>,
+    pub pack_size: Option<u32>,
+}
+
+/// Omitted fields are left unchanged
+#[derive(InputObject)]
+pub struct UpdateBarcodeInput {
+    pub id: String,
+    pub value: Option<String>,
+    pub item_id: Option<String>,
+    pub manufacturer_id: Option<String>,
+    pub pack_size: Option<u32>,
+}
+
+fn validate_barcode_auth(ctx: &Context<'_>, store_id: &str) -> Result<()> {
+    validate_auth(
+        ctx,
+        &ResourceAccessRequest {
+            resource: Resource::MutateBarcode,
+            store_id: Some(store_id.to_string()),
+        },
+    )?;
+    Ok(())
+}
+
+pub fn insert_barcode(
+    ctx: &Context<'_>,
+    store_id: &str,
+    input: InsertBarcodeInput,
+) -> Result<BarcodeNode> {
+    validate_barcode_auth(ctx, store_id)?;
+
+    let service_provider = ctx.service_provider();
+    let service_ctx = service_provider.context()?;
+    let input = InsertBarcode {
+        id: input.id,
+        value: input.value,
+        item_id: input.item_id,
+        manufacturer_id: input.manufacturer_id,
+        pack_size: input.pack_size.map(|pack_size| pack_size as i32),
+    };
+    match service_provider
+        .barcode_service
+        .insert_barcode(&service_ctx, input)
+    {
+        Ok(barcode) => Ok(BarcodeNode { barcode }),
+        Err(err) => {
+            let formatted_error = format!("{:#?}", err);
+            let graphql_error = match err {
+                InsertBarcodeError::DatabaseError(err) => err.into(),
+                InsertBarcodeError::BarcodeAlreadyExists
+                | InsertBarcodeError::EmptyValue
+                | InsertBarcodeError::BarcodeWithValueAlreadyExists
+                | InsertBarcodeError::ItemDoesNotExist
+                | InsertBarcodeError::ManufacturerDoesNotExist
+                | InsertBarcodeError::PackSizeBelowOne => {
+                    StandardGraphqlError::BadUserInput(formatted_error)
+                }
+            };
+            Err(graphql_error.extend())
+        }
+    }
+}
+
+pub fn update_barcode(
+    ctx: &Context<'_>,
+    store_id: &str,
+    input: UpdateBarcodeInput,
+) -> Result<BarcodeNode> {
+    validate_barcode_auth(ctx, store_id)?;
+
+    let service_provider = ctx.service_provider();
+    let service_ctx = service_provider.context()?;
+    let input = UpdateBarcode {
+        id: input.id,
+        value: input.value,
+        item_id: input.item_id,
+        manufacturer_id: input.manufacturer_id,
+        pack_size: input.pack_size.map(|pack_size| pack_size as i32),
+    };
+    match service_provider
+        .barcode_service
+        .update_barcode(&service_ctx, input)
+    {
+        Ok(barcode) => Ok(BarcodeNode { barcode }),
+        Err(err) => {
+            let formatted_error = format!("{:#?}", err);
+            let graphql_error = match err {
+                UpdateBarcodeError::DatabaseError(err) => err.into(),
+                UpdateBarcodeError::BarcodeDoesNotExist
+                | UpdateBarcodeError::EmptyValue
+                | UpdateBarcodeError::BarcodeWithValueAlreadyExists
+                | UpdateBarcodeError::ItemDoesNotExist
+                | UpdateBarcodeError::ManufacturerDoesNotExist
+                | UpdateBarcodeError::PackSizeBelowOne => {
+                    StandardGraphqlError::BadUserInput(formatted_error)
+                }
+            };
+            Err(graphql_error.extend())
+        }
+    }
+}
+
+pub fn delete_barcode(ctx: &Context<'_>, store_id: &str, id: &str) -> Result<String> {
+    validate_barcode_auth(ctx, store_id)?;
+
+    let service_provider = ctx.service_provider();
+    let service_ctx = service_provider.context()?;
+    match service_provider
+        .barcode_service
+        .delete_barcode(&service_ctx, id)
+    {
+        Ok(id) => Ok(id),
+        Err(err) => {
+            let formatted_error = format!("{:#?}", err);
+            let graphql_error = match err {
+                DeleteBarcodeError::DatabaseError(err) => err.into(),
+                DeleteBarcodeError::BarcodeDoesNotExist => {
+                    StandardGraphqlError::BadUserInput(formatted_error)
+                }
+            };
+            Err(graphql_error.extend())
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use async_graphql::*;
+use service::{
+    batch_recall::recall::RecallBatch,
+    permission_validation::{Resource, ResourceAccessRequest},
+};
+
+use crate::{
+    schema::queries::batch_recall::{
+        map_batch_recall_error, BatchRecallNode, BatchRecallSelectionInput,
+    },
+    standard_graphql_error::validate_auth,
+    ContextExt,
+};
+
+#[derive(InputObject)]
+pub struct RecallBatchInput {
+    /// Recall reference of the manufacturer or regulator
+    pub reference: String,
+    pub selection: BatchRecallSelectionInput,
+}
+
+pub fn recall_batch(
+    ctx: &Context<'_>,
+    store_id: &str,
+    input: RecallBatchInput,
+) -> Result<BatchRecallNode> {
+    validate_auth(
+        ctx,
+        &ResourceAccessRequest {
+            resource: Resource::RecallBatch,
+            store_id: Some(store_id.to_string()),
+        },
+    )?;
+
+    let service_provider = ctx.service_provider();
+    let service_ctx = service_provider.context()?;
+    match service_provider.batch_recall_service.recall_batch(
+        &service_ctx,
+        store_id,
+        RecallBatch {
+            reference: input.reference,
+            selection: input.selection.into(),
+        },
+    ) {
+        Ok(recall) => Ok(BatchRecallNode { recall }),
+        Err(err) => Err(map_batch_recall_error(err)),
+    }
+}
@@ -7,6 +7,7 @@ use crate::schema::mutations::{
     MutationWithId,
 };
 use repository::StorageConnectionManager;
+use service::plugin::PluginHost;
 
 use super::{
     delete::{DeleteInboundShipmentInput, DeleteInboundShipmentResponse},
@@ -33,6 +34,7 @@ pub struct BatchInboundShipmentResponse {
 
 pub fn get_batch_inbound_shipment_response(
     connection_manager: &StorageConnectionManager,
+    plugins: &PluginHost,
     insert_inbound_shipments: Option<Vec<InsertInboundShipmentInput>>,
     insert_inbound_shipment_lines: Option<Vec<InsertInboundShipmentLineInput>>,
     update_inbound_shipment_lines: Option<Vec<UpdateInboundShipmentLineInput>>,
@@ -58,7 +60,8 @@ pub fn get_batch_inbound_shipment_response(
     }
 
     if let Some(inputs) = insert_inbound_shipment_lines {
-        let (has_errors, responses) = do_insert_inbound_shipment_lines(connection_manager, inputs);
+        let (has_errors, responses) =
+            do_insert_inbound_shipment_lines(connection_manager, plugins, inputs);
         result.insert_inbound_shipment_lines = Some(responses);
         if has_errors {
             return result;
@@ -66,7 +69,8 @@ pub fn get_batch_inbound_shipment_response(
     }
 
     if let Some(inputs) = update_inbound_shipment_lines {
-        let (has_errors, responses) = do_update_inbound_shipment_lines(connection_manager, inputs);
+        let (has_errors, responses) =
+            do_update_inbound_shipment_lines(connection_manager, plugins, inputs);
         result.update_inbound_shipment_lines = Some(responses);
         if has_errors {
             return result;
@@ -82,7 +86,8 @@ pub fn get_batch_inbound_shipment_response(
     }
 
     if let Some(inputs) = update_inbound_shipments {
-        let (has_errors, responses) = do_update_inbound_shipments(connection_manager, inputs);
+        let (has_errors, responses) =
+            do_update_inbound_shipments(connection_manager, plugins, inputs);
         result.update_inbound_shipments = Some(responses);
         if has_errors {
             return result;
@@ -124,6 +129,7 @@ pub fn do_insert_inbound_shipments(
 
 pub fn do_update_inbound_shipments(
     connection: &StorageConnectionManager,
+    plugins: &PluginHost,
     inputs: Vec<UpdateInboundShipmentInput>,
 ) -> (bool, Vec<MutationWithId<UpdateInboundShipmentResponse>>) {
     let mut responses = Vec::new();
@@ -131,7 +137,7 @@ pub fn do_update_inbound_shipments(
         let id = input.id.clone();
         responses.push(MutationWithId {
             id,
-            response: get_update_inbound_shipment_response(connection, input),
+            response: get_update_inbound_shipment_response(connection, plugins, input),
         });
     }
     let has_errors = responses.iter().any(|mutation_with_id| {
@@ -168,6 +174,7 @@ pub fn do_delete_inbound_shipments(
 
 pub fn do_insert_inbound_shipment_lines(
     connection: &StorageConnectionManager,
+    plugins: &PluginHost,
     inputs: Vec<InsertInboundShipmentLineInput>,
 ) -> (bool, Vec<MutationWithId<InsertInboundShipmentLineResponse>>) {
     let mut responses = Vec::new();
@@ -175,7 +182,7 @@ pub fn do_insert_inbound_shipment_lines(
         let id = input.id.clone();
         responses.push(MutationWithId {
             id,
-            response: get_insert_inbound_shipment_line_response(connection, input),
+            response: get_insert_inbound_shipment_line_response(connection, plugins, input),
         });
     }
     let has_errors = responses.iter().any(|mutation_with_id| {
@@ -190,6 +197,7 @@ pub fn do_insert_inbound_shipment_lines(
 
 pub fn do_update_inbound_shipment_lines(
     connection: &StorageConnectionManager,
+    plugins: &PluginHost,
     inputs: Vec<UpdateInboundShipmentLineInput>,
 ) -> (bool, Vec<MutationWithId<UpdateInboundShipmentLineResponse>>) {
     let mut responses = Vec::new();
@@ -197,7 +205,7 @@ pub fn do_update_inbound_shipment_lines(
         let id = input.id.clone();
         responses.push(MutationWithId {
             id,
-            response: get_update_inbound_shipment_line_response(connection, input),
+            response: get_update_inbound_shipment_line_response(connection, plugins, input),
         });
     }
     let has_errors = responses.iter().any(|mutation_with_id| {
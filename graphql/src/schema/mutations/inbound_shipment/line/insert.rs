@@ -3,8 +3,9 @@ use chrono::NaiveDate;
 
 use crate::schema::{
     mutations::{
-        CannotEditInvoice, ForeignKey, ForeignKeyError, InvoiceDoesNotBelongToCurrentStore,
-        NotAnInboundShipment, RecordAlreadyExist,
+        CannotConvertPackVariant, CannotEditInvoice, ForeignKey, ForeignKeyError,
+        InvoiceDoesNotBelongToCurrentStore, LocationNotSuitableForItem, NotAnInboundShipment,
+        RecordAlreadyExist, RejectedByPlugin,
     },
     types::{
         get_invoice_line_response, DatabaseError, ErrorWrapper, InvoiceLineNode,
@@ -14,6 +15,7 @@ use crate::schema::{
 use domain::inbound_shipment::InsertInboundShipmentLine;
 use repository::StorageConnectionManager;
 use service::invoice_line::{insert_inbound_shipment_line, InsertInboundShipmentLineError};
+use service::plugin::PluginHost;
 
 #[derive(InputObject)]
 pub struct InsertInboundShipmentLineInput {
@@ -27,6 +29,8 @@ pub struct InsertInboundShipmentLineInput {
     pub sell_price_per_pack: f64,
     pub expiry_date: Option<NaiveDate>,
     pub number_of_packs: u32,
+    /// Pack variant of the item the number of packs is counted in, converted to packs of the line
+    pub pack_variant_id: Option<String>,
     pub total_before_tax: f64,
     pub total_after_tax: f64,
     pub tax: Option<f64>,
@@ -41,6 +45,7 @@ pub enum InsertInboundShipmentLineResponse {
 
 pub fn get_insert_inbound_shipment_line_response(
     connection_manager: &StorageConnectionManager,
+    plugins: &PluginHost,
     input: InsertInboundShipmentLineInput,
 ) -> InsertInboundShipmentLineResponse {
     use InsertInboundShipmentLineResponse::*;
@@ -52,7 +57,7 @@ pub fn get_insert_inbound_shipment_line_response(
             })
         }
     };
-    match insert_inbound_shipment_line(&connection, input.into()) {
+    match insert_inbound_shipment_line(&connection, plugins, input.into()) {
         Ok(id) => match get_invoice_line_response(connection_manager, id) {
             InvoiceLineResponse::Response(node) => Response(node),
             InvoiceLineResponse::Error(err) => NodeError(err),
@@ -68,9 +73,12 @@ pub enum InsertInboundShipmentLineErrorInterface {
     ForeignKeyError(ForeignKeyError),
     RecordAlreadyExist(RecordAlreadyExist),
     RangeError(RangeError),
+    CannotConvertPackVariant(CannotConvertPackVariant),
+    LocationNotSuitableForItem(LocationNotSuitableForItem),
     CannotEditInvoice(CannotEditInvoice),
     NotAnInboundShipment(NotAnInboundShipment),
     InvoiceDoesNotBelongToCurrentStore(InvoiceDoesNotBelongToCurrentStore),
+    RejectedByPlugin(RejectedByPlugin),
 }
 
 impl From<InsertInboundShipmentLineInput> for InsertInboundShipmentLine {
@@ -86,6 +94,7 @@ impl From<InsertInboundShipmentLineInput> for InsertInboundShipmentLine {
             sell_price_per_pack,
             cost_price_per_pack,
             number_of_packs,
+            pack_variant_id,
             total_before_tax,
             total_after_tax,
             tax,
@@ -102,6 +111,7 @@ impl From<InsertInboundShipmentLineInput> for InsertInboundShipmentLine {
             sell_price_per_pack,
             cost_price_per_pack,
             number_of_packs,
+            pack_variant_id,
             total_before_tax,
             total_after_tax,
             tax,
@@ -134,6 +144,12 @@ impl From<InsertInboundShipmentLineError> for InsertInboundShipmentLineResponse
             InsertInboundShipmentLineError::ItemNotFound => {
                 OutError::ForeignKeyError(ForeignKeyError(ForeignKey::ItemId))
             }
+            InsertInboundShipmentLineError::PackVariantDoesNotExist => {
+                OutError::ForeignKeyError(ForeignKeyError(ForeignKey::PackVariantId))
+            }
+            InsertInboundShipmentLineError::CannotConvertPackVariant => {
+                OutError::CannotConvertPackVariant(CannotConvertPackVariant {})
+            }
             InsertInboundShipmentLineError::NumberOfPacksBelowOne => {
                 OutError::RangeError(RangeError {
                     field: RangeField::NumberOfPacks,
@@ -147,6 +163,12 @@ impl From<InsertInboundShipmentLineError> for InsertInboundShipmentLineResponse
             InsertInboundShipmentLineError::LocationDoesNotExist => {
                 OutError::ForeignKeyError(ForeignKeyError(ForeignKey::LocationId))
             }
+            InsertInboundShipmentLineError::LocationNotSuitableForItem => {
+                OutError::LocationNotSuitableForItem(LocationNotSuitableForItem {})
+            }
+            InsertInboundShipmentLineError::RejectedByPlugin(rejection) => {
+                OutError::RejectedByPlugin(RejectedByPlugin(rejection))
+            }
         };
 
         InsertInboundShipmentLineResponse::Error(ErrorWrapper { error })
@@ -3,8 +3,9 @@ use chrono::NaiveDate;
 
 use crate::schema::{
     mutations::{
-        CannotEditInvoice, ForeignKey, ForeignKeyError, InvoiceDoesNotBelongToCurrentStore,
-        NotAnInboundShipment,
+        CannotConvertPackVariant, CannotEditInvoice, ForeignKey, ForeignKeyError,
+        InvoiceDoesNotBelongToCurrentStore, LocationNotSuitableForItem, NotAnInboundShipment,
+        RejectedByPlugin,
     },
     types::{
         get_invoice_line_response, DatabaseError, ErrorWrapper, InvoiceLineNode,
@@ -14,6 +15,7 @@ use crate::schema::{
 use domain::inbound_shipment::UpdateInboundShipmentLine;
 use repository::StorageConnectionManager;
 use service::invoice_line::{update_inbound_shipment_line, UpdateInboundShipmentLineError};
+use service::plugin::PluginHost;
 
 use super::{BatchIsReserved, InvoiceLineBelongsToAnotherInvoice};
 
@@ -29,6 +31,8 @@ pub struct UpdateInboundShipmentLineInput {
     pub sell_price_per_pack: Option<f64>,
     pub expiry_date: Option<NaiveDate>,
     pub number_of_packs: Option<u32>,
+    /// Pack variant of the item the number of packs is counted in, converted to packs of the line
+    pub pack_variant_id: Option<String>,
 }
 
 #[derive(Union)]
@@ -40,10 +44,11 @@ pub enum UpdateInboundShipmentLineResponse {
 
 pub fn get_update_inbound_shipment_line_response(
     connection_manager: &StorageConnectionManager,
+    plugins: &PluginHost,
     input: UpdateInboundShipmentLineInput,
 ) -> UpdateInboundShipmentLineResponse {
     use UpdateInboundShipmentLineResponse::*;
-    match update_inbound_shipment_line(connection_manager, input.into()) {
+    match update_inbound_shipment_line(connection_manager, plugins, input.into()) {
         Ok(id) => match get_invoice_line_response(connection_manager, id) {
             InvoiceLineResponse::Response(node) => Response(node),
             InvoiceLineResponse::Error(err) => NodeError(err),
@@ -64,6 +69,9 @@ pub enum UpdateInboundShipmentLineErrorInterface {
     NotAnInboundShipment(NotAnInboundShipment),
     BatchIsReserved(BatchIsReserved),
     RangeError(RangeError),
+    CannotConvertPackVariant(CannotConvertPackVariant),
+    LocationNotSuitableForItem(LocationNotSuitableForItem),
+    RejectedByPlugin(RejectedByPlugin),
 }
 
 impl From<UpdateInboundShipmentLineInput> for UpdateInboundShipmentLine {
@@ -79,6 +87,7 @@ impl From<UpdateInboundShipmentLineInput> for UpdateInboundShipmentLine {
             sell_price_per_pack,
             cost_price_per_pack,
             number_of_packs,
+            pack_variant_id,
         }: UpdateInboundShipmentLineInput,
     ) -> Self {
         UpdateInboundShipmentLine {
@@ -92,6 +101,7 @@ impl From<UpdateInboundShipmentLineInput> for UpdateInboundShipmentLine {
             sell_price_per_pack,
             cost_price_per_pack,
             number_of_packs,
+            pack_variant_id,
         }
     }
 }
@@ -121,6 +131,12 @@ impl From<UpdateInboundShipmentLineError> for UpdateInboundShipmentLineResponse
             UpdateInboundShipmentLineError::ItemNotFound => {
                 OutError::ForeignKeyError(ForeignKeyError(ForeignKey::ItemId))
             }
+            UpdateInboundShipmentLineError::PackVariantDoesNotExist => {
+                OutError::ForeignKeyError(ForeignKeyError(ForeignKey::PackVariantId))
+            }
+            UpdateInboundShipmentLineError::CannotConvertPackVariant => {
+                OutError::CannotConvertPackVariant(CannotConvertPackVariant {})
+            }
             UpdateInboundShipmentLineError::NumberOfPacksBelowOne => {
                 OutError::RangeError(RangeError {
                     field: RangeField::NumberOfPacks,
@@ -142,6 +158,12 @@ impl From<UpdateInboundShipmentLineError> for UpdateInboundShipmentLineResponse
             UpdateInboundShipmentLineError::LocationDoesNotExist => {
                 OutError::ForeignKeyError(ForeignKeyError(ForeignKey::LocationId))
             }
+            UpdateInboundShipmentLineError::LocationNotSuitableForItem => {
+                OutError::LocationNotSuitableForItem(LocationNotSuitableForItem {})
+            }
+            UpdateInboundShipmentLineError::RejectedByPlugin(rejection) => {
+                OutError::RejectedByPlugin(RejectedByPlugin(rejection))
+            }
         };
 
         UpdateInboundShipmentLineResponse::Error(ErrorWrapper { error })
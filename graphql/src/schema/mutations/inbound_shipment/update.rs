@@ -4,7 +4,7 @@ use crate::schema::{
     mutations::{
         outbound_shipment::CannotChangeStatusOfInvoiceOnHold, CannotEditInvoice,
         CannotReverseInvoiceStatus, ForeignKey, ForeignKeyError,
-        InvoiceDoesNotBelongToCurrentStore, NotAnInboundShipment,
+        InvoiceDoesNotBelongToCurrentStore, NotAnInboundShipment, RejectedByPlugin,
     },
     types::{
         get_invoice_response, DatabaseError, ErrorWrapper, InvoiceNode, InvoiceResponse, NameNode,
@@ -14,6 +14,7 @@ use crate::schema::{
 use domain::inbound_shipment::{UpdateInboundShipment, UpdateInboundShipmentStatus};
 use repository::StorageConnectionManager;
 use service::invoice::{update_inbound_shipment, UpdateInboundShipmentError};
+use service::plugin::PluginHost;
 
 use super::OtherPartyNotASupplier;
 
@@ -53,6 +54,7 @@ pub enum UpdateInboundShipmentResponse {
 
 pub fn get_update_inbound_shipment_response(
     connection_manager: &StorageConnectionManager,
+    plugins: &PluginHost,
     input: UpdateInboundShipmentInput,
 ) -> UpdateInboundShipmentResponse {
     use UpdateInboundShipmentResponse::*;
@@ -64,7 +66,7 @@ pub fn get_update_inbound_shipment_response(
             })
         }
     };
-    match update_inbound_shipment(&connection, input.into()) {
+    match update_inbound_shipment(&connection, plugins, input.into()) {
         Ok(id) => match get_invoice_response(connection_manager, id) {
             InvoiceResponse::Response(node) => Response(node),
             InvoiceResponse::Error(err) => NodeError(err),
@@ -85,6 +87,7 @@ pub enum UpdateInboundShipmentErrorInterface {
     InvoiceDoesNotBelongToCurrentStore(InvoiceDoesNotBelongToCurrentStore),
     CannotReverseInvoiceStatus(CannotReverseInvoiceStatus),
     CannotChangeStatusOfInvoiceOnHold(CannotChangeStatusOfInvoiceOnHold),
+    RejectedByPlugin(RejectedByPlugin),
 }
 
 impl From<UpdateInboundShipmentInput> for UpdateInboundShipment {
@@ -142,6 +145,9 @@ impl From<UpdateInboundShipmentError> for UpdateInboundShipmentResponse {
             UpdateInboundShipmentError::CannotChangeStatusOfInvoiceOnHold => {
                 OutError::CannotChangeStatusOfInvoiceOnHold(CannotChangeStatusOfInvoiceOnHold {})
             }
+            UpdateInboundShipmentError::RejectedByPlugin(rejection) => {
+                OutError::RejectedByPlugin(RejectedByPlugin(rejection))
+            }
         };
 
         UpdateInboundShipmentResponse::Error(ErrorWrapper { error })
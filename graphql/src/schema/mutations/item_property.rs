@@ -0,0 +1,149 @@
+use async_graphql::*;
+use chrono::{DateTime, Utc};
+use domain::item::VenCategory;
+use service::{
+    abc_classification::calculate::{CalculateAbcClassification, CalculateAbcClassificationError},
+    item_property::update::{UpdateItemProperties, UpdateItemPropertiesError},
+    permission_validation::{Resource, ResourceAccessRequest},
+};
+
+use crate::{
+    schema::{
+        queries::item_property::{ItemAbcClassNode, ItemPropertiesNode},
+        types::VenCategoryNode,
+    },
+    standard_graphql_error::{validate_auth, StandardGraphqlError},
+    ContextExt,
+};
+
+/// Omitted fields are left unchanged
+#[derive(InputObject)]
+pub struct UpdateItemPropertiesInput {
+    pub item_id: String,
+    pub category_id: Option<String>,
+    pub strength: Option<String>,
+    pub dosage_form: Option<String>,
+    pub ven_category: Option<VenCategoryNode>,
+    pub is_controlled: Option<bool>,
+    pub storage_requirement: Option<String>,
+}
+
+#[derive(InputObject)]
+pub struct CalculateAbcClassificationInput {
+    pub from_datetime: DateTime<Utc>,
+    pub to_datetime: DateTime<Utc>,
+    /// Share of the consumption value making up class A, defaults to 80
+    pub class_a_percentage: Option<f64>,
+    /// Share of the consumption value making up class A and B, defaults to 95
+    pub class_b_percentage: Option<f64>,
+}
+
+fn validate_item_properties_auth(ctx: &Context<'_>, store_id: &str) -> Result<()> {
+    validate_auth(
+        ctx,
+        &ResourceAccessRequest {
+            resource: Resource::MutateItemProperties,
+            store_id: Some(store_id.to_string()),
+        },
+    )?;
+    Ok(())
+}
+
+fn map_update_error(err: UpdateItemPropertiesError) -> Error {
+    let formatted_error = format!("{:#?}", err);
+    let graphql_error = match err {
+        UpdateItemPropertiesError::DatabaseError(err) => err.into(),
+        UpdateItemPropertiesError::ItemDoesNotExist
+        | UpdateItemPropertiesError::CategoryDoesNotExist => {
+            StandardGraphqlError::BadUserInput(formatted_error)
+        }
+    };
+    graphql_error.extend()
+}
+
+pub fn update_item_properties(
+    ctx: &Context<'_>,
+    store_id: &str,
+    input: UpdateItemPropertiesInput,
+) -> Result<ItemPropertiesNode> {
+    validate_item_properties_auth(ctx, store_id)?;
+
+    let service_provider = ctx.service_provider();
+    let service_ctx = service_provider.context()?;
+    let input = UpdateItemProperties {
+        item_id: input.item_id,
+        category_id: input.category_id,
+        strength: input.strength,
+        dosage_form: input.dosage_form,
+        ven_category: input
+            .ven_category
+            .map(|category| VenCategory::from(category).into()),
+        is_controlled: input.is_controlled,
+        storage_requirement: input.storage_requirement,
+    };
+    service_provider
+        .item_property_service
+        .update_item_properties(&service_ctx, input)
+        .map(|properties| ItemPropertiesNode { properties })
+        .map_err(map_update_error)
+}
+
+pub fn reset_item_properties(
+    ctx: &Context<'_>,
+    store_id: &str,
+    item_id: &str,
+) -> Result<ItemPropertiesNode> {
+    validate_item_properties_auth(ctx, store_id)?;
+
+    let service_provider = ctx.service_provider();
+    let service_ctx = service_provider.context()?;
+    service_provider
+        .item_property_service
+        .reset_item_properties(&service_ctx, item_id)
+        .map(|properties| ItemPropertiesNode { properties })
+        .map_err(map_update_error)
+}
+
+pub fn calculate_abc_classification(
+    ctx: &Context<'_>,
+    store_id: &str,
+    input: CalculateAbcClassificationInput,
+) -> Result<Vec<ItemAbcClassNode>> {
+    validate_auth(
+        ctx,
+        &ResourceAccessRequest {
+            resource: Resource::CalculateAbcClassification,
+            store_id: Some(store_id.to_string()),
+        },
+    )?;
+
+    let service_provider = ctx.service_provider();
+    let service_ctx = service_provider.context()?;
+    let input = CalculateAbcClassification {
+        from_datetime: input.from_datetime.naive_utc(),
+        to_datetime: input.to_datetime.naive_utc(),
+        class_a_percentage: input.class_a_percentage,
+        class_b_percentage: input.class_b_percentage,
+    };
+    match service_provider
+        .abc_classification_service
+        .calculate_abc_classification(&service_ctx, store_id, input)
+    {
+        Ok(rows) => Ok(rows
+            .into_iter()
+            .map(|abc_class| ItemAbcClassNode { abc_class })
+            .collect()),
+        Err(err) => {
+            let formatted_error = format!("{:#?}", err);
+            let graphql_error = match err {
+                CalculateAbcClassificationError::DatabaseError(err) => err.into(),
+                CalculateAbcClassificationError::StoreDoesNotExist
+                | CalculateAbcClassificationError::InvalidDateRange
+                | CalculateAbcClassificationError::InvalidPercentage => {
+                    StandardGraphqlError::BadUserInput(formatted_error)
+                }
+            };
+            Err(graphql_error.extend())
+        }
+    }
+}
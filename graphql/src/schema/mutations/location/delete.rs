@@ -8,7 +8,7 @@ use service::location::delete::{
 use crate::{
     schema::{
         mutations::{error::DatabaseError, DeleteResponse, RecordBelongsToAnotherStore},
-        types::{Connector, InvoiceLineNode, RecordNotFound, StockLineNode},
+        types::{Connector, InvoiceLineNode, LocationNode, RecordNotFound, StockLineNode},
     },
     ContextExt,
 };
@@ -63,6 +63,7 @@ pub enum DeleteLocationErrorInterface {
 pub struct LocationInUse {
     stock_lines: Connector<StockLineNode>,
     invoice_lines: Connector<InvoiceLineNode>,
+    sub_locations: Vec<LocationNode>,
 }
 
 #[Object]
@@ -78,6 +79,11 @@ impl LocationInUse {
     pub async fn invoice_lines(&self) -> &Connector<InvoiceLineNode> {
         &self.invoice_lines
     }
+
+    /// Locations with this location as parent
+    pub async fn sub_locations(&self) -> &Vec<LocationNode> {
+        &self.sub_locations
+    }
 }
 
 impl From<RepositoryError> for DeleteLocationError {
@@ -95,9 +101,11 @@ impl From<InError> for DeleteLocationError {
             InError::LocationInUse(ServiceLocationInUse {
                 stock_lines,
                 invoice_lines,
+                sub_locations,
             }) => OutError::LocationInUse(LocationInUse {
                 stock_lines: stock_lines.into(),
                 invoice_lines: invoice_lines.into(),
+                sub_locations: sub_locations.into_iter().map(LocationNode::from).collect(),
             }),
             InError::LocationDoesNotBelongToCurrentStore => {
                 OutError::RecordBelongsToAnotherStore(RecordBelongsToAnotherStore {})
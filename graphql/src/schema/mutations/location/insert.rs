@@ -6,7 +6,8 @@ use service::location::insert::InsertLocationError as InError;
 use crate::{
     schema::{
         mutations::{
-            error::DatabaseError, RecordAlreadyExist, UniqueValueKey, UniqueValueViolation,
+            error::DatabaseError, ForeignKey, ForeignKeyError, RecordAlreadyExist, UniqueValueKey,
+            UniqueValueViolation,
         },
         types::{InternalError, LocationNode},
     },
@@ -35,6 +36,9 @@ pub struct InsertLocationInput {
     pub code: String,
     pub name: Option<String>,
     pub on_hold: Option<bool>,
+    pub parent_id: Option<String>,
+    pub location_type_id: Option<String>,
+    pub volume: Option<f64>,
 }
 
 impl From<InsertLocationInput> for InsertLocation {
@@ -44,6 +48,9 @@ impl From<InsertLocationInput> for InsertLocation {
             code,
             name,
             on_hold,
+            parent_id,
+            location_type_id,
+            volume,
         }: InsertLocationInput,
     ) -> Self {
         InsertLocation {
@@ -51,6 +58,9 @@ impl From<InsertLocationInput> for InsertLocation {
             code,
             name,
             on_hold,
+            parent_id,
+            location_type_id,
+            volume,
         }
     }
 }
@@ -70,6 +80,7 @@ pub enum InsertLocationResponse {
 pub enum InsertLocationErrorInterface {
     LocationAlreadyExists(RecordAlreadyExist),
     UniqueValueViolation(UniqueValueViolation),
+    ForeignKeyError(ForeignKeyError),
     InternalError(InternalError),
     DatabaseError(DatabaseError),
 }
@@ -94,6 +105,12 @@ impl From<InError> for InsertLocationError {
             InError::CreatedRecordDoesNotExist => OutError::InternalError(InternalError(
                 "Could not find record after creation".to_owned(),
             )),
+            InError::ParentLocationDoesNotExist => {
+                OutError::ForeignKeyError(ForeignKeyError(ForeignKey::ParentLocationId))
+            }
+            InError::LocationTypeDoesNotExist => {
+                OutError::ForeignKeyError(ForeignKeyError(ForeignKey::LocationTypeId))
+            }
             InError::DatabaseError(error) => OutError::DatabaseError(DatabaseError(error)),
         };
         InsertLocationError { error }
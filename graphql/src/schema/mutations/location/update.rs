@@ -6,7 +6,8 @@ use service::location::update::UpdateLocationError as InError;
 use crate::{
     schema::{
         mutations::{
-            error::DatabaseError, RecordBelongsToAnotherStore, UniqueValueKey, UniqueValueViolation,
+            error::DatabaseError, ForeignKey, ForeignKeyError, ParentLocationIsSubLocation,
+            RecordBelongsToAnotherStore, UniqueValueKey, UniqueValueViolation,
         },
         types::{InternalError, LocationNode, RecordNotFound},
     },
@@ -35,6 +36,9 @@ pub struct UpdateLocationInput {
     pub code: Option<String>,
     pub name: Option<String>,
     pub on_hold: Option<bool>,
+    pub parent_id: Option<String>,
+    pub location_type_id: Option<String>,
+    pub volume: Option<f64>,
 }
 
 impl From<UpdateLocationInput> for UpdateLocation {
@@ -44,6 +48,9 @@ impl From<UpdateLocationInput> for UpdateLocation {
             code,
             name,
             on_hold,
+            parent_id,
+            location_type_id,
+            volume,
         }: UpdateLocationInput,
     ) -> Self {
         UpdateLocation {
@@ -51,6 +58,9 @@ impl From<UpdateLocationInput> for UpdateLocation {
             code,
             name,
             on_hold,
+            parent_id,
+            location_type_id,
+            volume,
         }
     }
 }
@@ -71,7 +81,9 @@ pub enum UpdateLocationResponse {
 pub enum UpdateLocationErrorInterface {
     LocationNotFound(RecordNotFound),
     UniqueValueViolation(UniqueValueViolation),
+    ForeignKeyError(ForeignKeyError),
     RecordBelongsToAnotherStore(RecordBelongsToAnotherStore),
+    ParentLocationIsSubLocation(ParentLocationIsSubLocation),
     InternalError(InternalError),
     DatabaseError(DatabaseError),
 }
@@ -97,6 +109,15 @@ impl From<InError> for UpdateLocationError {
             InError::UpdatedRecordDoesNotExist => OutError::InternalError(InternalError(
                 "Could not find record after updating".to_owned(),
             )),
+            InError::ParentLocationDoesNotExist => {
+                OutError::ForeignKeyError(ForeignKeyError(ForeignKey::ParentLocationId))
+            }
+            InError::ParentLocationIsSubLocation => {
+                OutError::ParentLocationIsSubLocation(ParentLocationIsSubLocation {})
+            }
+            InError::LocationTypeDoesNotExist => {
+                OutError::ForeignKeyError(ForeignKeyError(ForeignKey::LocationTypeId))
+            }
             InError::DatabaseError(error) => OutError::DatabaseError(DatabaseError(error)),
         };
         UpdateLocationError { error }
@@ -0,0 +1,94 @@
+use async_graphql::*;
+use service::{
+    location_movement::{
+        move_stock::{MoveStock, MoveStockError as ServiceError},
+        MovedStock,
+    },
+    permission_validation::{Resource, ResourceAccessRequest},
+};
+
+use crate::{
+    schema::{queries::location_movement::LocationMovementNode, types::StockLineNode},
+    standard_graphql_error::{validate_auth, StandardGraphqlError},
+    ContextExt,
+};
+
+#[derive(InputObject)]
+pub struct MoveStockInput {
+    /// Id of the new location movement
+    pub id: String,
+    pub stock_line_id: String,
+    /// Moving fewer packs than the stock line holds splits them off into a new stock line
+    pub number_of_packs: u32,
+    pub location_id: String,
+}
+
+#[derive(SimpleObject)]
+pub struct MoveStockNode {
+    pub movement: LocationMovementNode,
+    /// Stock line now holding the moved packs
+    pub stock_line: StockLineNode,
+}
+
+impl From<MovedStock> for MoveStockNode {
+    fn from(
+        MovedStock {
+            movement,
+            stock_line,
+        }: MovedStock,
+    ) -> Self {
+        MoveStockNode {
+            movement: LocationMovementNode::from(movement),
+            stock_line: StockLineNode::from(stock_line),
+        }
+    }
+}
+
+pub fn move_stock(
+    ctx: &Context<'_>,
+    store_id: &str,
+    input: MoveStockInput,
+) -> Result<MoveStockNode> {
+    validate_auth(
+        ctx,
+        &ResourceAccessRequest {
+            resource: Resource::MoveStock,
+            store_id: Some(store_id.to_string()),
+        },
+    )?;
+
+    let service_provider = ctx.service_provider();
+    let service_ctx = service_provider.context()?;
+    match service_provider.location_movement_service.move_stock(
+        &service_ctx,
+        store_id,
+        MoveStock {
+            id: input.id,
+            stock_line_id: input.stock_line_id,
+            number_of_packs: input.number_of_packs,
+            location_id: input.location_id,
+        },
+    ) {
+        Ok(moved) => Ok(MoveStockNode::from(moved)),
+        Err(err) => Err(map_error(err)),
+    }
+}
+
+fn map_error(err: ServiceError) -> Error {
+    let formatted_error = format!("{:#?}", err);
+    let graphql_error = match err {
+        ServiceError::DatabaseError(err) => err.into(),
+        ServiceError::InternalError(err) => StandardGraphqlError::InternalError(err),
+        ServiceError::MovementAlreadyExists
+        | ServiceError::StockLineDoesNotExist
+        | ServiceError::NumberOfPacksBelowOne
+        | ServiceError::ReductionBelowZero
+        | ServiceError::LocationDoesNotExist
+        | ServiceError::LocationIsOnHold
+        | ServiceError::LocationUnchanged
+        | ServiceError::LocationNotSuitableForItem => {
+            StandardGraphqlError::BadUserInput(formatted_error)
+        }
+    };
+    graphql_error.extend()
+}
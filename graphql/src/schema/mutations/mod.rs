@@ -1,18 +1,63 @@
 mod error;
 
+pub mod barcode;
+pub mod batch_recall;
 pub mod inbound_shipment;
+pub mod item_property;
 pub mod location;
+pub mod location_movement;
+pub mod name;
 pub mod outbound_shipment;
+pub mod pack_variant;
+pub mod patient;
+pub mod prescription;
+pub mod repack;
+pub mod returns;
+pub mod stock_line;
 pub mod stock_take;
+pub mod store_preference;
 pub mod tax_update_input;
+pub mod temperature_log;
 pub mod user_register;
 
 use self::{
+    barcode::{
+        delete_barcode, insert_barcode, update_barcode, InsertBarcodeInput, UpdateBarcodeInput,
+    },
+    batch_recall::{recall_batch, RecallBatchInput},
+    item_property::{
+        calculate_abc_classification, reset_item_properties, update_item_properties,
+        CalculateAbcClassificationInput, UpdateItemPropertiesInput,
+    },
     location::{
         delete_location, insert_location, update_location, DeleteLocationInput,
         DeleteLocationResponse, InsertLocationInput, InsertLocationResponse, UpdateLocationInput,
         UpdateLocationResponse,
     },
+    location_movement::{move_stock, MoveStockInput, MoveStockNode},
+    name::{insert_name, update_name, InsertNameInput, UpdateNameInput},
+    pack_variant::{
+        delete_pack_variant, insert_pack_variant, update_pack_variant, InsertPackVariantInput,
+        UpdatePackVariantInput,
+    },
+    patient::{
+        insert_patient, insert_prescriber, update_patient, InsertPatientInput,
+        InsertPrescriberInput, UpdatePatientInput,
+    },
+    prescription::{
+        delete_prescription, delete_prescription_line, insert_prescription,
+        insert_prescription_line, update_prescription, update_prescription_line,
+        InsertPrescriptionInput, InsertPrescriptionLineInput, UpdatePrescriptionInput,
+        UpdatePrescriptionLineInput,
+    },
+    repack::{repack, RepackInput, RepackNode},
+    returns::{
+        delete_return, delete_return_line, insert_return, insert_return_line,
+        update_customer_return, update_return_line, update_supplier_return, InsertReturnInput,
+        InsertReturnLineInput, UpdateCustomerReturnInput, UpdateReturnLineInput,
+        UpdateSupplierReturnInput,
+    },
+    stock_line::{hold_stock_lines, HoldStockLinesInput},
     stock_take::{
         delete::{delete_stock_take, DeleteStockTakeInput, DeleteStockTakeResponse},
         insert::{insert_stock_take, InsertStockTakeInput, InsertStockTakeResponse},
@@ -29,14 +74,31 @@ use self::{
         },
         update::{update_stock_take, UpdateStockTakeInput, UpdateStockTakeResponse},
     },
+    store_preference::{update_store_preferences, UpdateStorePreferencesInput},
+    temperature_log::{
+        insert_temperature_breach_config, update_temperature_breach_config,
+        InsertTemperatureBreachConfigInput, UpdateTemperatureBreachConfigInput,
+    },
 };
 
-use super::types::{get_invoice_response, Connector, InvoiceLineNode, InvoiceResponse};
+use super::queries::{
+    barcode::BarcodeNode,
+    batch_recall::BatchRecallNode,
+    item_property::{ItemAbcClassNode, ItemPropertiesNode},
+    pack_variant::PackVariantNode,
+    patient::{PatientNode, PrescriberNode},
+    store_preference::StorePreferenceNode,
+    temperature_log::TemperatureBreachConfigNode,
+};
+use super::types::{
+    get_invoice_response, Connector, InvoiceLineNode, InvoiceNode, InvoiceResponse, NameNode,
+    StockLineNode,
+};
 use crate::ContextExt;
 use async_graphql::*;
 use inbound_shipment::*;
 use outbound_shipment::*;
-use service::current_store_id;
+use service::{current_store_id, plugin::PluginRejection, returns::ReturnType};
 pub use user_register::*;
 
 pub struct Mutations;
@@ -90,7 +152,11 @@ impl Mutations {
         input: UpdateOutboundShipmentInput,
     ) -> UpdateOutboundShipmentResponse {
         let connection_manager = ctx.get_connection_manager();
-        get_update_outbound_shipment_response(connection_manager, input)
+        get_update_outbound_shipment_response(
+            connection_manager,
+            &ctx.service_provider().plugins,
+            input,
+        )
     }
 
     async fn delete_outbound_shipment(
@@ -108,7 +174,11 @@ impl Mutations {
         input: InsertOutboundShipmentLineInput,
     ) -> InsertOutboundShipmentLineResponse {
         let connection_manager = ctx.get_connection_manager();
-        get_insert_outbound_shipment_line_response(connection_manager, input)
+        get_insert_outbound_shipment_line_response(
+            connection_manager,
+            &ctx.service_provider().plugins,
+            input,
+        )
     }
 
     async fn update_outbound_shipment_line(
@@ -117,7 +187,11 @@ impl Mutations {
         input: UpdateOutboundShipmentLineInput,
     ) -> UpdateOutboundShipmentLineResponse {
         let connection_manager = ctx.get_connection_manager();
-        get_update_outbound_shipment_line_response(connection_manager, input)
+        get_update_outbound_shipment_line_response(
+            connection_manager,
+            &ctx.service_provider().plugins,
+            input,
+        )
     }
 
     async fn delete_outbound_shipment_line(
@@ -195,7 +269,11 @@ impl Mutations {
         input: UpdateInboundShipmentInput,
     ) -> UpdateInboundShipmentResponse {
         let connection_manager = ctx.get_connection_manager();
-        get_update_inbound_shipment_response(connection_manager, input)
+        get_update_inbound_shipment_response(
+            connection_manager,
+            &ctx.service_provider().plugins,
+            input,
+        )
     }
 
     async fn delete_inbound_shipment(
@@ -213,7 +291,11 @@ impl Mutations {
         input: InsertInboundShipmentLineInput,
     ) -> InsertInboundShipmentLineResponse {
         let connection_manager = ctx.get_connection_manager();
-        get_insert_inbound_shipment_line_response(connection_manager, input)
+        get_insert_inbound_shipment_line_response(
+            connection_manager,
+            &ctx.service_provider().plugins,
+            input,
+        )
     }
 
     async fn update_inbound_shipment_line(
@@ -222,7 +304,11 @@ impl Mutations {
         input: UpdateInboundShipmentLineInput,
     ) -> UpdateInboundShipmentLineResponse {
         let connection_manager = ctx.get_connection_manager();
-        get_update_inbound_shipment_line_response(connection_manager, input)
+        get_update_inbound_shipment_line_response(
+            connection_manager,
+            &ctx.service_provider().plugins,
+            input,
+        )
     }
 
     async fn delete_inbound_shipment_line(
@@ -248,6 +334,7 @@ impl Mutations {
 
         get_batch_inbound_shipment_response(
             connection_manager,
+            &ctx.service_provider().plugins,
             insert_inbound_shipments,
             insert_inbound_shipment_lines,
             update_inbound_shipment_lines,
@@ -274,6 +361,7 @@ impl Mutations {
 
         get_batch_outbound_shipment_response(
             connection_manager,
+            &ctx.service_provider().plugins,
             insert_outbound_shipments,
             insert_outbound_shipment_lines,
             update_outbound_shipment_lines,
@@ -363,6 +451,541 @@ impl Mutations {
         )?);
         delete_stock_take_line(ctx, &store_id, &input)
     }
+
+    /// Overrides store preferences locally
+    async fn update_store_preferences(
+        &self,
+        ctx: &Context<'_>,
+        store_id: Option<String>,
+        input: UpdateStorePreferencesInput,
+    ) -> Result<StorePreferenceNode> {
+        // TODO remove and make store_id parameter required
+        let store_id = store_id.unwrap_or(current_store_id(
+            &ctx.get_connection_manager().connection()?,
+        )?);
+        update_store_preferences(ctx, &store_id, input)
+    }
+
+    /// Puts stock lines on hold and optionally moves them to a quarantine location
+    async fn hold_stock_lines(
+        &self,
+        ctx: &Context<'_>,
+        store_id: Option<String>,
+        input: HoldStockLinesInput,
+    ) -> Result<Vec<StockLineNode>> {
+        // TODO remove and make store_id parameter required
+        let store_id = store_id.unwrap_or(current_store_id(
+            &ctx.get_connection_manager().connection()?,
+        )?);
+        hold_stock_lines(ctx, &store_id, input)
+    }
+
+    /// Puts the remaining stock of a recalled batch on hold and records the recall reference
+    async fn recall_batch(
+        &self,
+        ctx: &Context<'_>,
+        store_id: Option<String>,
+        input: RecallBatchInput,
+    ) -> Result<BatchRecallNode> {
+        // TODO remove and make store_id parameter required
+        let store_id = store_id.unwrap_or(current_store_id(
+            &ctx.get_connection_manager().connection()?,
+        )?);
+        recall_batch(ctx, &store_id, input)
+    }
+
+    async fn insert_barcode(
+        &self,
+        ctx: &Context<'_>,
+        store_id: Option<String>,
+        input: InsertBarcodeInput,
+    ) -> Result<BarcodeNode> {
+        // TODO remove and make store_id parameter required
+        let store_id = store_id.unwrap_or(current_store_id(
+            &ctx.get_connection_manager().connection()?,
+        )?);
+        insert_barcode(ctx, &store_id, input)
+    }
+
+    async fn update_barcode(
+        &self,
+        ctx: &Context<'_>,
+        store_id: Option<String>,
+        input: UpdateBarcodeInput,
+    ) -> Result<BarcodeNode> {
+        // TODO remove and make store_id parameter required
+        let store_id = store_id.unwrap_or(current_store_id(
+            &ctx.get_connection_manager().connection()?,
+        )?);
+        update_barcode(ctx, &store_id, input)
+    }
+
+    /// Returns the id of the deleted barcode
+    async fn delete_barcode(
+        &self,
+        ctx: &Context<'_>,
+        store_id: Option<String>,
+        id: String,
+    ) -> Result<String> {
+        // TODO remove and make store_id parameter required
+        let store_id = store_id.unwrap_or(current_store_id(
+            &ctx.get_connection_manager().connection()?,
+        )?);
+        delete_barcode(ctx, &store_id, &id)
+    }
+
+    async fn insert_pack_variant(
+        &self,
+        ctx: &Context<'_>,
+        store_id: Option<String>,
+        input: InsertPackVariantInput,
+    ) -> Result<PackVariantNode> {
+        // TODO remove and make store_id parameter required
+        let store_id = store_id.unwrap_or(current_store_id(
+            &ctx.get_connection_manager().connection()?,
+        )?);
+        insert_pack_variant(ctx, &store_id, input)
+    }
+
+    async fn update_pack_variant(
+        &self,
+        ctx: &Context<'_>,
+        store_id: Option<String>,
+        input: UpdatePackVariantInput,
+    ) -> Result<PackVariantNode> {
+        // TODO remove and make store_id parameter required
+        let store_id = store_id.unwrap_or(current_store_id(
+            &ctx.get_connection_manager().connection()?,
+        )?);
+        update_pack_variant(ctx, &store_id, input)
+    }
+
+    /// Returns the id of the deleted pack variant, lines with its pack size are kept
+    async fn delete_pack_variant(
+        &self,
+        ctx: &Context<'_>,
+        store_id: Option<String>,
+        id: String,
+    ) -> Result<String> {
+        // TODO remove and make store_id parameter required
+        let store_id = store_id.unwrap_or(current_store_id(
+            &ctx.get_connection_manager().connection()?,
+        )?);
+        delete_pack_variant(ctx, &store_id, &id)
+    }
+
+    /// Overrides the synced properties of an item locally
+    async fn update_item_properties(
+        &self,
+        ctx: &Context<'_>,
+        store_id: Option<String>,
+        input: UpdateItemPropertiesInput,
+    ) -> Result<ItemPropertiesNode> {
+        // TODO remove and make store_id parameter required
+        let store_id = store_id.unwrap_or(current_store_id(
+            &ctx.get_connection_manager().connection()?,
+        )?);
+        update_item_properties(ctx, &store_id, input)
+    }
+
+    /// Removes the local overrides, the synced properties of the item apply again
+    async fn reset_item_properties(
+        &self,
+        ctx: &Context<'_>,
+        store_id: Option<String>,
+        item_id: String,
+    ) -> Result<ItemPropertiesNode> {
+        // TODO remove and make store_id parameter required
+        let store_id = store_id.unwrap_or(current_store_id(
+            &ctx.get_connection_manager().connection()?,
+        )?);
+        reset_item_properties(ctx, &store_id, &item_id)
+    }
+
+    /// Classifies the items of the store by consumption value and replaces the stored
+    /// classification
+    async fn calculate_abc_classification(
+        &self,
+        ctx: &Context<'_>,
+        store_id: Option<String>,
+        input: CalculateAbcClassificationInput,
+    ) -> Result<Vec<ItemAbcClassNode>> {
+        // TODO remove and make store_id parameter required
+        let store_id = store_id.unwrap_or(current_store_id(
+            &ctx.get_connection_manager().connection()?,
+        )?);
+        calculate_abc_classification(ctx, &store_id, input)
+    }
+
+    async fn insert_supplier_return(
+        &self,
+        ctx: &Context<'_>,
+        store_id: Option<String>,
+        input: InsertReturnInput,
+    ) -> Result<InvoiceNode> {
+        // TODO remove and make store_id parameter required
+        let store_id = store_id.unwrap_or(current_store_id(
+            &ctx.get_connection_manager().connection()?,
+        )?);
+        insert_return(ctx, &store_id, ReturnType::Supplier, input)
+    }
+
+    /// Stock leaves the store when the return is picked
+    async fn update_supplier_return(
+        &self,
+        ctx: &Context<'_>,
+        store_id: Option<String>,
+        input: UpdateSupplierReturnInput,
+    ) -> Result<InvoiceNode> {
+        // TODO remove and make store_id parameter required
+        let store_id = store_id.unwrap_or(current_store_id(
+            &ctx.get_connection_manager().connection()?,
+        )?);
+        update_supplier_return(ctx, &store_id, input)
+    }
+
+    /// Returns the id of the deleted return, only returns without lines can be deleted
+    async fn delete_supplier_return(
+        &self,
+        ctx: &Context<'_>,
+        store_id: Option<String>,
+        id: String,
+    ) -> Result<String> {
+        // TODO remove and make store_id parameter required
+        let store_id = store_id.unwrap_or(current_store_id(
+            &ctx.get_connection_manager().connection()?,
+        )?);
+        delete_return(ctx, &store_id, ReturnType::Supplier, &id)
+    }
+
+    async fn insert_supplier_return_line(
+        &self,
+        ctx: &Context<'_>,
+        store_id: Option<String>,
+        input: InsertReturnLineInput,
+    ) -> Result<InvoiceLineNode> {
+        // TODO remove and make store_id parameter required
+        let store_id = store_id.unwrap_or(current_store_id(
+            &ctx.get_connection_manager().connection()?,
+        )?);
+        insert_return_line(ctx, &store_id, ReturnType::Supplier, input)
+    }
+
+    async fn update_supplier_return_line(
+        &self,
+        ctx: &Context<'_>,
+        store_id: Option<String>,
+        input: UpdateReturnLineInput,
+    ) -> Result<InvoiceLineNode> {
+        // TODO remove and make store_id parameter required
+        let store_id = store_id.unwrap_or(current_store_id(
+            &ctx.get_connection_manager().connection()?,
+        )?);
+        update_return_line(ctx, &store_id, ReturnType::Supplier, input)
+    }
+
+    /// Returns the id of the deleted line
+    async fn delete_supplier_return_line(
+        &self,
+        ctx: &Context<'_>,
+        store_id: Option<String>,
+        id: String,
+    ) -> Result<String> {
+        // TODO remove and make store_id parameter required
+        let store_id = store_id.unwrap_or(current_store_id(
+            &ctx.get_connection_manager().connection()?,
+        )?);
+        delete_return_line(ctx, &store_id, ReturnType::Supplier, &id)
+    }
+
+    async fn insert_customer_return(
+        &self,
+        ctx: &Context<'_>,
+        store_id: Option<String>,
+        input: InsertReturnInput,
+    ) -> Result<InvoiceNode> {
+        // TODO remove and make store_id parameter required
+        let store_id = store_id.unwrap_or(current_store_id(
+            &ctx.get_connection_manager().connection()?,
+        )?);
+        insert_return(ctx, &store_id, ReturnType::Customer, input)
+    }
+
+    /// Stock enters the store when the return is delivered
+    async fn update_customer_return(
+        &self,
+        ctx: &Context<'_>,
+        store_id: Option<String>,
+        input: UpdateCustomerReturnInput,
+    ) -> Result<InvoiceNode> {
+        // TODO remove and make store_id parameter required
+        let store_id = store_id.unwrap_or(current_store_id(
+            &ctx.get_connection_manager().connection()?,
+        )?);
+        update_customer_return(ctx, &store_id, input)
+    }
+
+    /// Returns the id of the deleted return, only returns without lines can be deleted
+    async fn delete_customer_return(
+        &self,
+        ctx: &Context<'_>,
+        store_id: Option<String>,
+        id: String,
+    ) -> Result<String> {
+        // TODO remove and make store_id parameter required
+        let store_id = store_id.unwrap_or(current_store_id(
+            &ctx.get_connection_manager().connection()?,
+        )?);
+        delete_return(ctx, &store_id, ReturnType::Customer, &id)
+    }
+
+    async fn insert_customer_return_line(
+        &self,
+        ctx: &Context<'_>,
+        store_id: Option<String>,
+        input: InsertReturnLineInput,
+    ) -> Result<InvoiceLineNode> {
+        // TODO remove and make store_id parameter required
+        let store_id = store_id.unwrap_or(current_store_id(
+            &ctx.get_connection_manager().connection()?,
+        )?);
+        insert_return_line(ctx, &store_id, ReturnType::Customer, input)
+    }
+
+    async fn update_customer_return_line(
+        &self,
+        ctx: &Context<'_>,
+        store_id: Option<String>,
+        input: UpdateReturnLineInput,
+    ) -> Result<InvoiceLineNode> {
+        // TODO remove and make store_id parameter required
+        let store_id = store_id.unwrap_or(current_store_id(
+            &ctx.get_connection_manager().connection()?,
+        )?);
+        update_return_line(ctx, &store_id, ReturnType::Customer, input)
+    }
+
+    /// Returns the id of the deleted line
+    async fn delete_customer_return_line(
+        &self,
+        ctx: &Context<'_>,
+        store_id: Option<String>,
+        id: String,
+    ) -> Result<String> {
+        // TODO remove and make store_id parameter required
+        let store_id = store_id.unwrap_or(current_store_id(
+            &ctx.get_connection_manager().connection()?,
+        )?);
+        delete_return_line(ctx, &store_id, ReturnType::Customer, &id)
+    }
+
+    /// Takes packs from a stock line and repacks them into a stock line of the same batch with a
+    /// different pack size, recorded as a verified repack invoice
+    async fn repack(
+        &self,
+        ctx: &Context<'_>,
+        store_id: Option<String>,
+        input: RepackInput,
+    ) -> Result<RepackNode> {
+        // TODO remove and make store_id parameter required
+        let store_id = store_id.unwrap_or(current_store_id(
+            &ctx.get_connection_manager().connection()?,
+        )?);
+        repack(ctx, &store_id, input)
+    }
+
+    /// Moves packs of a stock line to another location, splitting the stock line if only some of
+    /// its packs are moved. Each move is recorded in the location movement log.
+    async fn move_stock(
+        &self,
+        ctx: &Context<'_>,
+        store_id: Option<String>,
+        input: MoveStockInput,
+    ) -> Result<MoveStockNode> {
+        // TODO remove and make store_id parameter required
+        let store_id = store_id.unwrap_or(current_store_id(
+            &ctx.get_connection_manager().connection()?,
+        )?);
+        move_stock(ctx, &store_id, input)
+    }
+
+    /// Breaches of a location are recalculated with the configs of its location type whenever
+    /// readings are ingested
+    async fn insert_temperature_breach_config(
+        &self,
+        ctx: &Context<'_>,
+        store_id: Option<String>,
+        input: InsertTemperatureBreachConfigInput,
+    ) -> Result<TemperatureBreachConfigNode> {
+        // TODO remove and make store_id parameter required
+        let store_id = store_id.unwrap_or(current_store_id(
+            &ctx.get_connection_manager().connection()?,
+        )?);
+        insert_temperature_breach_config(ctx, &store_id, input)
+    }
+
+    async fn update_temperature_breach_config(
+        &self,
+        ctx: &Context<'_>,
+        store_id: Option<String>,
+        input: UpdateTemperatureBreachConfigInput,
+    ) -> Result<TemperatureBreachConfigNode> {
+        // TODO remove and make store_id parameter required
+        let store_id = store_id.unwrap_or(current_store_id(
+            &ctx.get_connection_manager().connection()?,
+        )?);
+        update_temperature_breach_config(ctx, &store_id, input)
+    }
+
+    /// The store becomes the home store of the name, changes are queued for upload to central
+    async fn insert_name(
+        &self,
+        ctx: &Context<'_>,
+        store_id: Option<String>,
+        input: InsertNameInput,
+    ) -> Result<NameNode> {
+        // TODO remove and make store_id parameter required
+        let store_id = store_id.unwrap_or(current_store_id(
+            &ctx.get_connection_manager().connection()?,
+        )?);
+        insert_name(ctx, &store_id, input)
+    }
+
+    /// Names can only be edited in their home store
+    async fn update_name(
+        &self,
+        ctx: &Context<'_>,
+        store_id: Option<String>,
+        input: UpdateNameInput,
+    ) -> Result<NameNode> {
+        // TODO remove and make store_id parameter required
+        let store_id = store_id.unwrap_or(current_store_id(
+            &ctx.get_connection_manager().connection()?,
+        )?);
+        update_name(ctx, &store_id, input)
+    }
+
+    /// The store becomes the home store of the patient
+    async fn insert_patient(
+        &self,
+        ctx: &Context<'_>,
+        store_id: Option<String>,
+        input: InsertPatientInput,
+    ) -> Result<PatientNode> {
+        // TODO remove and make store_id parameter required
+        let store_id = store_id.unwrap_or(current_store_id(
+            &ctx.get_connection_manager().connection()?,
+        )?);
+        insert_patient(ctx, &store_id, input)
+    }
+
+    /// Patients can only be edited in their home store
+    async fn update_patient(
+        &self,
+        ctx: &Context<'_>,
+        store_id: Option<String>,
+        input: UpdatePatientInput,
+    ) -> Result<PatientNode> {
+        // TODO remove and make store_id parameter required
+        let store_id = store_id.unwrap_or(current_store_id(
+            &ctx.get_connection_manager().connection()?,
+        )?);
+        update_patient(ctx, &store_id, input)
+    }
+
+    async fn insert_prescriber(
+        &self,
+        ctx: &Context<'_>,
+        store_id: Option<String>,
+        input: InsertPrescriberInput,
+    ) -> Result<PrescriberNode> {
+        // TODO remove and make store_id parameter required
+        let store_id = store_id.unwrap_or(current_store_id(
+            &ctx.get_connection_manager().connection()?,
+        )?);
+        insert_prescriber(ctx, &store_id, input)
+    }
+
+    async fn insert_prescription(
+        &self,
+        ctx: &Context<'_>,
+        store_id: Option<String>,
+        input: InsertPrescriptionInput,
+    ) -> Result<InvoiceNode> {
+        // TODO remove and make store_id parameter required
+        let store_id = store_id.unwrap_or(current_store_id(
+            &ctx.get_connection_manager().connection()?,
+        )?);
+        insert_prescription(ctx, &store_id, input)
+    }
+
+    /// Stock is dispensed when the prescription is picked
+    async fn update_prescription(
+        &self,
+        ctx: &Context<'_>,
+        store_id: Option<String>,
+        input: UpdatePrescriptionInput,
+    ) -> Result<InvoiceNode> {
+        // TODO remove and make store_id parameter required
+        let store_id = store_id.unwrap_or(current_store_id(
+            &ctx.get_connection_manager().connection()?,
+        )?);
+        update_prescription(ctx, &store_id, input)
+    }
+
+    /// Returns the id of the deleted prescription, only prescriptions without lines can be deleted
+    async fn delete_prescription(
+        &self,
+        ctx: &Context<'_>,
+        store_id: Option<String>,
+        id: String,
+    ) -> Result<String> {
+        // TODO remove and make store_id parameter required
+        let store_id = store_id.unwrap_or(current_store_id(
+            &ctx.get_connection_manager().connection()?,
+        )?);
+        delete_prescription(ctx, &store_id, &id)
+    }
+
+    async fn insert_prescription_line(
+        &self,
+        ctx: &Context<'_>,
+        store_id: Option<String>,
+        input: InsertPrescriptionLineInput,
+    ) -> Result<InvoiceLineNode> {
+        // TODO remove and make store_id parameter required
+        let store_id = store_id.unwrap_or(current_store_id(
+            &ctx.get_connection_manager().connection()?,
+        )?);
+        insert_prescription_line(ctx, &store_id, input)
+    }
+
+    async fn update_prescription_line(
+        &self,
+        ctx: &Context<'_>,
+        store_id: Option<String>,
+        input: UpdatePrescriptionLineInput,
+    ) -> Result<InvoiceLineNode> {
+        // TODO remove and make store_id parameter required
+        let store_id = store_id.unwrap_or(current_store_id(
+            &ctx.get_connection_manager().connection()?,
+        )?);
+        update_prescription_line(ctx, &store_id, input)
+    }
+
+    /// Returns the id of the deleted line
+    async fn delete_prescription_line(
+        &self,
+        ctx: &Context<'_>,
+        store_id: Option<String>,
+        id: String,
+    ) -> Result<String> {
+        // TODO remove and make store_id parameter required
+        let store_id = store_id.unwrap_or(current_store_id(
+            &ctx.get_connection_manager().connection()?,
+        )?);
+        delete_prescription_line(ctx, &store_id, &id)
+    }
 }
 
 // Common Mutation Errors
@@ -374,6 +997,9 @@ pub enum ForeignKey {
     InvoiceId,
     StockLineId,
     LocationId,
+    PackVariantId,
+    ParentLocationId,
+    LocationTypeId,
 }
 
 pub struct ForeignKeyError(ForeignKey);
@@ -430,6 +1056,22 @@ impl RecordBelongsToAnotherStore {
     }
 }
 
+pub struct ParentLocationIsSubLocation;
+#[Object]
+impl ParentLocationIsSubLocation {
+    pub async fn description(&self) -> &'static str {
+        "Parent location is the location itself or one of its sub locations"
+    }
+}
+
+pub struct LocationNotSuitableForItem;
+#[Object]
+impl LocationNotSuitableForItem {
+    pub async fn description(&self) -> &'static str {
+        "Location type is not suitable for storing the item"
+    }
+}
+
 pub struct CannotEditInvoice;
 #[Object]
 impl CannotEditInvoice {
@@ -504,6 +1146,27 @@ impl InvoiceLineBelongsToAnotherInvoice {
     }
 }
 
+pub struct CannotConvertPackVariant;
+#[Object]
+impl CannotConvertPackVariant {
+    pub async fn description(&self) -> &'static str {
+        "Packs of the pack variant don't make up a whole number of packs of the line"
+    }
+}
+
+pub struct RejectedByPlugin(pub PluginRejection);
+#[Object]
+impl RejectedByPlugin {
+    /// Message of the plugin
+    pub async fn description(&self) -> &str {
+        &self.0.message
+    }
+
+    pub async fn plugin(&self) -> &str {
+        &self.0.plugin
+    }
+}
+
 #[derive(SimpleObject)]
 #[graphql(concrete(
     name = "InsertInboundShipmentResponseWithId",
@@ -0,0 +1,161 @@
+use async_graphql::*;
+use domain::name::NameType;
+use service::{
+    name::{
+        insert::{InsertName, InsertNameError},
+        update::{UpdateName, UpdateNameError},
+    },
+    permission_validation::{Resource, ResourceAccessRequest},
+};
+
+use crate::{
+    schema::types::{NameNode, NameNodeType},
+    standard_graphql_error::{validate_auth, StandardGraphqlError},
+    ContextExt,
+};
+
+#[derive(InputObject)]
+pub struct InsertNameInput {
+    pub id: String,
+    pub code: String,
+    pub name: String,
+    /// Store names can't be created locally
+    pub r#type: NameNodeType,
+    pub is_customer: bool,
+    pub is_supplier: bool,
+    pub is_manufacturer: Option<bool>,
+    pub is_donor: Option<bool>,
+    pub phone: Option<String>,
+    pub email: Option<String>,
+    pub website: Option<String>,
+    pub address1: Option<String>,
+    pub address2: Option<String>,
+    pub country: Option<String>,
+    pub comment: Option<String>,
+    /// Tag (category) names, missing tags are created
+    pub tags: Option<Vec<String>>,
+}
+
+/// Omitted fields are left unchanged
+#[derive(InputObject)]
+pub struct UpdateNameInput {
+    pub id: String,
+    pub code: Option<String>,
+    pub name: Option<String>,
+    pub is_customer: Option<bool>,
+    pub is_supplier: Option<bool>,
+    pub is_manufacturer: Option<bool>,
+    pub is_donor: Option<bool>,
+    pub phone: Option<String>,
+    pub email: Option<String>,
+    pub website: Option<String>,
+    pub address1: Option<String>,
+    pub address2: Option<String>,
+    pub country: Option<String>,
+    pub comment: Option<String>,
+    /// Replaces all tags of the name
+    pub tags: Option<Vec<String>>,
+}
+
+fn validate_name_auth(ctx: &Context<'_>, store_id: &str) -> Result<()> {
+    validate_auth(
+        ctx,
+        &ResourceAccessRequest {
+            resource: Resource::MutateName,
+            store_id: Some(store_id.to_string()),
+        },
+    )?;
+    Ok(())
+}
+
+fn map_insert_error(err: InsertNameError) -> Error {
+    let formatted_error = format!("{:#?}", err);
+    let graphql_error = match err {
+        InsertNameError::DatabaseError(err) => err.into(),
+        InsertNameError::InternalError(err) => StandardGraphqlError::InternalError(err),
+        InsertNameError::NameAlreadyExists
+        | InsertNameError::NameWithCodeAlreadyExists
+        | InsertNameError::NameIsEmpty
+        | InsertNameError::CannotCreateStoreName
+        | InsertNameError::InvalidStore => StandardGraphqlError::BadUserInput(formatted_error),
+    };
+    graphql_error.extend()
+}
+
+fn map_update_error(err: UpdateNameError) -> Error {
+    let formatted_error = format!("{:#?}", err);
+    let graphql_error = match err {
+        UpdateNameError::DatabaseError(err) => err.into(),
+        UpdateNameError::InternalError(err) => StandardGraphqlError::InternalError(err),
+        UpdateNameError::NameDoesNotExist
+        | UpdateNameError::NotNameHomeStore
+        | UpdateNameError::NameWithCodeAlreadyExists
+        | UpdateNameError::NameIsEmpty => StandardGraphqlError::BadUserInput(formatted_error),
+    };
+    graphql_error.extend()
+}
+
+pub fn insert_name(ctx: &Context<'_>, store_id: &str, input: InsertNameInput) -> Result<NameNode> {
+    validate_name_auth(ctx, store_id)?;
+
+    let service_provider = ctx.service_provider();
+    let service_ctx = service_provider.context()?;
+    service_provider
+        .name_service
+        .insert_name(
+            &service_ctx,
+            store_id,
+            InsertName {
+                id: input.id,
+                code: input.code,
+                name: input.name,
+                r#type: NameType::from(input.r#type),
+                is_customer: input.is_customer,
+                is_supplier: input.is_supplier,
+                is_manufacturer: input.is_manufacturer.unwrap_or(false),
+                is_donor: input.is_donor.unwrap_or(false),
+                phone: input.phone,
+                email: input.email,
+                website: input.website,
+                address1: input.address1,
+                address2: input.address2,
+                country: input.country,
+                comment: input.comment,
+                tags: input.tags.unwrap_or_default(),
+            },
+        )
+        .map(NameNode::from)
+        .map_err(map_insert_error)
+}
+
+pub fn update_name(ctx: &Context<'_>, store_id: &str, input: UpdateNameInput) -> Result<NameNode> {
+    validate_name_auth(ctx, store_id)?;
+
+    let service_provider = ctx.service_provider();
+    let service_ctx = service_provider.context()?;
+    service_provider
+        .name_service
+        .update_name(
+            &service_ctx,
+            store_id,
+            UpdateName {
+                id: input.id,
+                code: input.code,
+                name: input.name,
+                is_customer: input.is_customer,
+                is_supplier: input.is_supplier,
+                is_manufacturer: input.is_manufacturer,
+                is_donor: input.is_donor,
+                phone: input.phone,
+                email: input.email,
+                website: input.website,
+                address1: input.address1,
+                address2: input.address2,
+                country: input.country,
+                comment: input.comment,
+                tags: input.tags,
+            },
+        )
+        .map(NameNode::from)
+        .map_err(map_update_error)
+}
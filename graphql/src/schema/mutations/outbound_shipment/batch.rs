@@ -7,6 +7,7 @@ use crate::schema::mutations::{
     MutationWithId,
 };
 use repository::StorageConnectionManager;
+use service::plugin::PluginHost;
 
 use super::{
     delete::DeleteOutboundShipmentResponse,
@@ -45,6 +46,7 @@ pub struct BatchOutboundShipmentResponse {
 
 pub fn get_batch_outbound_shipment_response(
     connection_manager: &StorageConnectionManager,
+    plugins: &PluginHost,
     insert_outbound_shipments: Option<Vec<InsertOutboundShipmentInput>>,
     insert_outbound_shipment_lines: Option<Vec<InsertOutboundShipmentLineInput>>,
     update_outbound_shipment_lines: Option<Vec<UpdateOutboundShipmentLineInput>>,
@@ -76,7 +78,8 @@ pub fn get_batch_outbound_shipment_response(
     }
 
     if let Some(inputs) = insert_outbound_shipment_lines {
-        let (has_errors, responses) = do_insert_outbound_shipment_lines(connection_manager, inputs);
+        let (has_errors, responses) =
+            do_insert_outbound_shipment_lines(connection_manager, plugins, inputs);
         result.insert_outbound_shipment_lines = Some(responses);
         if has_errors {
             return result;
@@ -84,7 +87,8 @@ pub fn get_batch_outbound_shipment_response(
     }
 
     if let Some(inputs) = update_outbound_shipment_lines {
-        let (has_errors, responses) = do_update_outbound_shipment_lines(connection_manager, inputs);
+        let (has_errors, responses) =
+            do_update_outbound_shipment_lines(connection_manager, plugins, inputs);
         result.update_outbound_shipment_lines = Some(responses);
         if has_errors {
             return result;
@@ -127,7 +131,8 @@ pub fn get_batch_outbound_shipment_response(
     }
 
     if let Some(inputs) = update_outbound_shipments {
-        let (has_errors, responses) = do_update_outbound_shipments(connection_manager, inputs);
+        let (has_errors, responses) =
+            do_update_outbound_shipments(connection_manager, plugins, inputs);
         result.update_outbound_shipments = Some(responses);
         if has_errors {
             return result;
@@ -169,6 +174,7 @@ pub fn do_insert_outbound_shipments(
 
 pub fn do_update_outbound_shipments(
     connection: &StorageConnectionManager,
+    plugins: &PluginHost,
     inputs: Vec<UpdateOutboundShipmentInput>,
 ) -> (bool, Vec<MutationWithId<UpdateOutboundShipmentResponse>>) {
     let mut responses = Vec::new();
@@ -176,7 +182,7 @@ pub fn do_update_outbound_shipments(
         let id = input.id.clone();
         responses.push(MutationWithId {
             id,
-            response: get_update_outbound_shipment_response(connection, input),
+            response: get_update_outbound_shipment_response(connection, plugins, input),
         });
     }
     let has_errors = responses.iter().any(|mutation_with_id| {
@@ -213,6 +219,7 @@ pub fn do_delete_outbound_shipments(
 
 pub fn do_insert_outbound_shipment_lines(
     connection: &StorageConnectionManager,
+    plugins: &PluginHost,
     inputs: Vec<InsertOutboundShipmentLineInput>,
 ) -> (
     bool,
@@ -223,7 +230,7 @@ pub fn do_insert_outbound_shipment_lines(
         let id = input.id.clone();
         responses.push(MutationWithId {
             id,
-            response: get_insert_outbound_shipment_line_response(connection, input),
+            response: get_insert_outbound_shipment_line_response(connection, plugins, input),
         });
     }
     let has_errors = responses.iter().any(|mutation_with_id| {
@@ -238,6 +245,7 @@ pub fn do_insert_outbound_shipment_lines(
 
 pub fn do_update_outbound_shipment_lines(
     connection: &StorageConnectionManager,
+    plugins: &PluginHost,
     inputs: Vec<UpdateOutboundShipmentLineInput>,
 ) -> (
     bool,
@@ -248,7 +256,7 @@ pub fn do_update_outbound_shipment_lines(
         let id = input.id.clone();
         responses.push(MutationWithId {
             id,
-            response: get_update_outbound_shipment_line_response(connection, input),
+            response: get_update_outbound_shipment_line_response(connection, plugins, input),
         });
     }
     let has_errors = responses.iter().any(|mutation_with_id| {
@@ -3,8 +3,9 @@ use async_graphql::*;
 use crate::schema::{
     mutations::{
         outbound_shipment::{LocationIsOnHold, NotEnoughStockForReduction, StockLineIsOnHold},
-        CannotEditInvoice, ForeignKey, ForeignKeyError, InvoiceDoesNotBelongToCurrentStore,
-        NotAnOutboundShipment, RecordAlreadyExist,
+        CannotConvertPackVariant, CannotEditInvoice, ForeignKey, ForeignKeyError,
+        InvoiceDoesNotBelongToCurrentStore, NotAnOutboundShipment, RecordAlreadyExist,
+        RejectedByPlugin,
     },
     types::{
         get_invoice_line_response, DatabaseError, ErrorWrapper, InvoiceLineNode,
@@ -14,6 +15,7 @@ use crate::schema::{
 use domain::outbound_shipment::InsertOutboundShipmentLine;
 use repository::StorageConnectionManager;
 use service::invoice_line::{insert_outbound_shipment_line, InsertOutboundShipmentLineError};
+use service::plugin::PluginHost;
 
 use super::{
     ItemDoesNotMatchStockLine, LocationNotFound, StockLineAlreadyExistsInInvoice,
@@ -27,6 +29,8 @@ pub struct InsertOutboundShipmentLineInput {
     pub item_id: String,
     pub stock_line_id: String,
     pub number_of_packs: u32,
+    /// Pack variant of the item the number of packs is counted in, converted to packs of the stock line
+    pub pack_variant_id: Option<String>,
     pub total_before_tax: f64,
     pub total_after_tax: f64,
     pub tax: Option<f64>,
@@ -41,6 +45,7 @@ pub enum InsertOutboundShipmentLineResponse {
 
 pub fn get_insert_outbound_shipment_line_response(
     connection_manager: &StorageConnectionManager,
+    plugins: &PluginHost,
     input: InsertOutboundShipmentLineInput,
 ) -> InsertOutboundShipmentLineResponse {
     use InsertOutboundShipmentLineResponse::*;
@@ -52,7 +57,7 @@ pub fn get_insert_outbound_shipment_line_response(
             })
         }
     };
-    match insert_outbound_shipment_line(&connection, input.into()) {
+    match insert_outbound_shipment_line(&connection, plugins, input.into()) {
         Ok(id) => match get_invoice_line_response(connection_manager, id) {
             InvoiceLineResponse::Response(node) => Response(node),
             InvoiceLineResponse::Error(err) => NodeError(err),
@@ -68,6 +73,7 @@ pub enum InsertOutboundShipmentLineErrorInterface {
     ForeignKeyError(ForeignKeyError),
     RecordAlreadyExist(RecordAlreadyExist),
     RangeError(RangeError),
+    CannotConvertPackVariant(CannotConvertPackVariant),
     CannotEditInvoice(CannotEditInvoice),
     NotAnOutboundShipment(NotAnOutboundShipment),
     StockLineDoesNotBelongToCurrentStore(StockLineDoesNotBelongToCurrentStore),
@@ -78,6 +84,7 @@ pub enum InsertOutboundShipmentLineErrorInterface {
     LocationIsOnHold(LocationIsOnHold),
     LocationNotFound(LocationNotFound),
     StockLineIsOnHold(StockLineIsOnHold),
+    RejectedByPlugin(RejectedByPlugin),
 }
 
 impl From<InsertOutboundShipmentLineInput> for InsertOutboundShipmentLine {
@@ -88,6 +95,7 @@ impl From<InsertOutboundShipmentLineInput> for InsertOutboundShipmentLine {
             item_id,
             stock_line_id,
             number_of_packs,
+            pack_variant_id,
             total_before_tax,
             total_after_tax,
             tax,
@@ -99,6 +107,7 @@ impl From<InsertOutboundShipmentLineInput> for InsertOutboundShipmentLine {
             item_id,
             stock_line_id,
             number_of_packs,
+            pack_variant_id,
             total_before_tax,
             total_after_tax,
             tax,
@@ -131,6 +140,12 @@ impl From<InsertOutboundShipmentLineError> for InsertOutboundShipmentLineRespons
             InsertOutboundShipmentLineError::ItemNotFound => {
                 OutError::ForeignKeyError(ForeignKeyError(ForeignKey::ItemId))
             }
+            InsertOutboundShipmentLineError::PackVariantDoesNotExist => {
+                OutError::ForeignKeyError(ForeignKeyError(ForeignKey::PackVariantId))
+            }
+            InsertOutboundShipmentLineError::CannotConvertPackVariant => {
+                OutError::CannotConvertPackVariant(CannotConvertPackVariant {})
+            }
             InsertOutboundShipmentLineError::NumberOfPacksBelowOne => {
                 OutError::RangeError(RangeError {
                     field: RangeField::NumberOfPacks,
@@ -161,6 +176,9 @@ impl From<InsertOutboundShipmentLineError> for InsertOutboundShipmentLineRespons
             InsertOutboundShipmentLineError::LocationNotFound => {
                 OutError::LocationNotFound(LocationNotFound {})
             }
+            InsertOutboundShipmentLineError::RejectedByPlugin(rejection) => {
+                OutError::RejectedByPlugin(RejectedByPlugin(rejection))
+            }
         };
         InsertOutboundShipmentLineResponse::Error(ErrorWrapper { error })
     }
@@ -2,9 +2,9 @@ use async_graphql::*;
 
 use crate::schema::{
     mutations::{
-        tax_update_input::TaxUpdate, CannotEditInvoice, ForeignKey, ForeignKeyError,
-        InvoiceDoesNotBelongToCurrentStore, InvoiceLineBelongsToAnotherInvoice,
-        NotAnOutboundShipment,
+        tax_update_input::TaxUpdate, CannotConvertPackVariant, CannotEditInvoice, ForeignKey,
+        ForeignKeyError, InvoiceDoesNotBelongToCurrentStore, InvoiceLineBelongsToAnotherInvoice,
+        NotAnOutboundShipment, RejectedByPlugin,
     },
     types::{
         get_invoice_line_response, DatabaseError, ErrorWrapper, InvoiceLineNode,
@@ -16,6 +16,7 @@ use domain::{
 };
 use repository::StorageConnectionManager;
 use service::invoice_line::{update_outbound_shipment_line, UpdateOutboundShipmentLineError};
+use service::plugin::PluginHost;
 
 use super::{
     ItemDoesNotMatchStockLine, LineDoesNotReferenceStockLine, LocationIsOnHold, LocationNotFound,
@@ -30,6 +31,8 @@ pub struct UpdateOutboundShipmentLineInput {
     item_id: Option<String>,
     stock_line_id: Option<String>,
     number_of_packs: Option<u32>,
+    /// Pack variant of the item the number of packs is counted in, converted to packs of the stock line
+    pack_variant_id: Option<String>,
     total_before_tax: Option<f64>,
     total_after_tax: Option<f64>,
     tax: Option<TaxUpdate>,
@@ -37,10 +40,11 @@ pub struct UpdateOutboundShipmentLineInput {
 
 pub fn get_update_outbound_shipment_line_response(
     connection_manager: &StorageConnectionManager,
+    plugins: &PluginHost,
     input: UpdateOutboundShipmentLineInput,
 ) -> UpdateOutboundShipmentLineResponse {
     use UpdateOutboundShipmentLineResponse::*;
-    match update_outbound_shipment_line(connection_manager, input.into()) {
+    match update_outbound_shipment_line(connection_manager, plugins, input.into()) {
         Ok(id) => match get_invoice_line_response(connection_manager, id) {
             InvoiceLineResponse::Response(node) => Response(node),
             InvoiceLineResponse::Error(err) => NodeError(err),
@@ -73,8 +77,10 @@ pub enum UpdateOutboundShipmentLineErrorInterface {
     LocationIsOnHold(LocationIsOnHold),
     LocationNotFound(LocationNotFound),
     RangeError(RangeError),
+    CannotConvertPackVariant(CannotConvertPackVariant),
     StockLineIsOnHold(StockLineIsOnHold),
     NotEnoughStockForReduction(NotEnoughStockForReduction),
+    RejectedByPlugin(RejectedByPlugin),
 }
 
 impl From<UpdateOutboundShipmentLineInput> for UpdateOutboundShipmentLine {
@@ -85,6 +91,7 @@ impl From<UpdateOutboundShipmentLineInput> for UpdateOutboundShipmentLine {
             item_id,
             stock_line_id,
             number_of_packs,
+            pack_variant_id,
             total_before_tax,
             total_after_tax,
             tax,
@@ -96,6 +103,7 @@ impl From<UpdateOutboundShipmentLineInput> for UpdateOutboundShipmentLine {
             item_id,
             stock_line_id,
             number_of_packs,
+            pack_variant_id,
             total_before_tax,
             total_after_tax,
             tax: tax.map(|tax| ShipmentTaxUpdate {
@@ -127,6 +135,12 @@ impl From<UpdateOutboundShipmentLineError> for UpdateOutboundShipmentLineRespons
             UpdateOutboundShipmentLineError::ItemNotFound => {
                 OutError::ForeignKeyError(ForeignKeyError(ForeignKey::ItemId))
             }
+            UpdateOutboundShipmentLineError::PackVariantDoesNotExist => {
+                OutError::ForeignKeyError(ForeignKeyError(ForeignKey::PackVariantId))
+            }
+            UpdateOutboundShipmentLineError::CannotConvertPackVariant => {
+                OutError::CannotConvertPackVariant(CannotConvertPackVariant {})
+            }
             UpdateOutboundShipmentLineError::NumberOfPacksBelowOne => {
                 OutError::RangeError(RangeError {
                     field: RangeField::NumberOfPacks,
@@ -169,6 +183,9 @@ impl From<UpdateOutboundShipmentLineError> for UpdateOutboundShipmentLineRespons
             UpdateOutboundShipmentLineError::LocationNotFound => {
                 OutError::LocationNotFound(LocationNotFound {})
             }
+            UpdateOutboundShipmentLineError::RejectedByPlugin(rejection) => {
+                OutError::RejectedByPlugin(RejectedByPlugin(rejection))
+            }
         };
 
         UpdateOutboundShipmentLineResponse::Error(ErrorWrapper { error })
@@ -5,7 +5,7 @@ use crate::schema::{
             CannotChangeStatusOfInvoiceOnHold, InvoiceLineHasNoStockLineError,
             NotAnOutboundShipmentError,
         },
-        CannotReverseInvoiceStatus, ForeignKey, ForeignKeyError,
+        CannotReverseInvoiceStatus, ForeignKey, ForeignKeyError, RejectedByPlugin,
     },
     types::{
         get_invoice_response, Connector, ErrorWrapper, InvoiceLineNode, InvoiceNode,
@@ -15,6 +15,7 @@ use crate::schema::{
 use domain::outbound_shipment::{UpdateOutboundShipment, UpdateOutboundShipmentStatus};
 use repository::StorageConnectionManager;
 use service::invoice::{update_outbound_shipment, UpdateOutboundShipmentError};
+use service::plugin::PluginHost;
 
 use super::{
     CanOnlyEditInvoicesInLoggedInStoreError, InvoiceIsNotEditable,
@@ -81,6 +82,7 @@ pub enum UpdateOutboundShipmentResponse {
 
 pub fn get_update_outbound_shipment_response(
     connection_manager: &StorageConnectionManager,
+    plugins: &PluginHost,
     input: UpdateOutboundShipmentInput,
 ) -> UpdateOutboundShipmentResponse {
     use UpdateOutboundShipmentResponse::*;
@@ -92,7 +94,7 @@ pub fn get_update_outbound_shipment_response(
             })
         }
     };
-    match update_outbound_shipment(&connection, input.into()) {
+    match update_outbound_shipment(&connection, plugins, input.into()) {
         Ok(id) => match get_invoice_response(connection_manager, id) {
             InvoiceResponse::Response(node) => Response(node),
             InvoiceResponse::Error(err) => NodeError(err),
@@ -117,6 +119,7 @@ pub enum UpdateOutboundShipmentErrorInterface {
     DatabaseError(DatabaseError),
     InvalidInvoiceLine(InvoiceLineHasNoStockLineError),
     CanOnlyChangeToAllocatedWhenNoUnallocatedLines(CanOnlyChangeToAllocatedWhenNoUnallocatedLines),
+    RejectedByPlugin(RejectedByPlugin),
 }
 
 impl From<UpdateOutboundShipmentError> for UpdateOutboundShipmentResponse {
@@ -158,6 +161,9 @@ impl From<UpdateOutboundShipmentError> for UpdateOutboundShipmentResponse {
                     CanOnlyChangeToAllocatedWhenNoUnallocatedLines(lines.into()),
                 )
             }
+            UpdateOutboundShipmentError::RejectedByPlugin(rejection) => {
+                OutError::RejectedByPlugin(RejectedByPlugin(rejection))
+            }
         };
 
         UpdateOutboundShipmentResponse::Error(ErrorWrapper { error })
@@ -0,0 +1,153 @@
+use async_graphql::*;
+use service::{
+    pack_variant::{
+        delete::DeletePackVariantError,
+        insert::{InsertPackVariant, InsertPackVariantError},
+        update::{UpdatePackVariant, UpdatePackVariantError},
+    },
+    permission_validation::{Resource, ResourceAccessRequest},
+};
+
+use crate::{
+    schema::queries::pack_variant::PackVariantNode,
+    standard_graphql_error::{validate_auth, StandardGraphqlError},
+    ContextExt,
+};
+
+#[derive(InputObject)]
+pub struct InsertPackVariantInput {
+    pub id: String,
+    pub item_id: String,
+    pub name: String,
+    pub pack_size: u32,
+    pub unit_id: Option<String>,
+    pub volume_per_pack: Option<f64>,
+    pub weight_per_pack: Option<f64>,
+}
+
+/// Omitted fields are left unchanged
+#[derive(InputObject)]
+pub struct UpdatePackVariantInput {
+    pub id: String,
+    pub name: Option<String>,
+    pub pack_size: Option<u32>,
+    pub unit_id: Option<String>,
+    pub volume_per_pack: Option<f64>,
+    pub weight_per_pack: Option<f64>,
+}
+
+fn validate_pack_variant_auth(ctx: &Context<'_>, store_id: &str) -> Result<()> {
+    validate_auth(
+        ctx,
+        &ResourceAccessRequest {
+            resource: Resource::MutatePackVariant,
+            store_id: Some(store_id.to_string()),
+        },
+    )?;
+    Ok(())
+}
+
+pub fn insert_pack_variant(
+    ctx: &Context<'_>,
+    store_id: &str,
+    input: InsertPackVariantInput,
+) -> Result<PackVariantNode> {
+    validate_pack_variant_auth(ctx, store_id)?;
+
+    let service_provider = ctx.service_provider();
+    let service_ctx = service_provider.context()?;
+    let input = InsertPackVariant {
+        id: input.id,
+        item_id: input.item_id,
+        name: input.name,
+        pack_size: input.pack_size as i32,
+        unit_id: input.unit_id,
+        volume_per_pack: input.volume_per_pack,
+        weight_per_pack: input.weight_per_pack,
+    };
+    match service_provider
+        .pack_variant_service
+        .insert_pack_variant(&service_ctx, input)
+    {
+        Ok(pack_variant) => Ok(PackVariantNode::from(pack_variant)),
+        Err(err) => {
+            let formatted_error = format!("{:#?}", err);
+            let graphql_error = match err {
+                InsertPackVariantError::DatabaseError(err) => err.into(),
+                InsertPackVariantError::PackVariantAlreadyExists
+                | InsertPackVariantError::ItemDoesNotExist
+                | InsertPackVariantError::EmptyName
+                | InsertPackVariantError::PackSizeBelowOne
+                | InsertPackVariantError::PackVariantWithPackSizeAlreadyExists
+                | InsertPackVariantError::UnitDoesNotExist
+                | InsertPackVariantError::NegativeVolumeOrWeight => {
+                    StandardGraphqlError::BadUserInput(formatted_error)
+                }
+            };
+            Err(graphql_error.extend())
+        }
+    }
+}
+
+pub fn update_pack_variant(
+    ctx: &Context<'_>,
+    store_id: &str,
+    input: UpdatePackVariantInput,
+) -> Result<PackVariantNode> {
+    validate_pack_variant_auth(ctx, store_id)?;
+
+    let service_provider = ctx.service_provider();
+    let service_ctx = service_provider.context()?;
+    let input = UpdatePackVariant {
+        id: input.id,
+        name: input.name,
+        pack_size: input.pack_size.map(|pack_size| pack_size as i32),
+        unit_id: input.unit_id,
+        volume_per_pack: input.volume_per_pack,
+        weight_per_pack: input.weight_per_pack,
+    };
+    match service_provider
+        .pack_variant_service
+        .update_pack_variant(&service_ctx, input)
+    {
+        Ok(pack_variant) => Ok(PackVariantNode::from(pack_variant)),
+        Err(err) => {
+            let formatted_error = format!("{:#?}", err);
+            let graphql_error = match err {
+                UpdatePackVariantError::DatabaseError(err) => err.into(),
+                UpdatePackVariantError::PackVariantDoesNotExist
+                | UpdatePackVariantError::EmptyName
+                | UpdatePackVariantError::PackSizeBelowOne
+                | UpdatePackVariantError::PackVariantWithPackSizeAlreadyExists
+                | UpdatePackVariantError::UnitDoesNotExist
+                | UpdatePackVariantError::NegativeVolumeOrWeight => {
+                    StandardGraphqlError::BadUserInput(formatted_error)
+                }
+            };
+            Err(graphql_error.extend())
+        }
+    }
+}
+
+pub fn delete_pack_variant(ctx: &Context<'_>, store_id: &str, id: &str) -> Result<String> {
+    validate_pack_variant_auth(ctx, store_id)?;
+
+    let service_provider = ctx.service_provider();
+    let service_ctx = service_provider.context()?;
+    match service_provider
+        .pack_variant_service
+        .delete_pack_variant(&service_ctx, id)
+    {
+        Ok(id) => Ok(id),
+        Err(err) => {
+            let formatted_error = format!("{:#?}", err);
+            let graphql_error = match err {
+                DeletePackVariantError::DatabaseError(err) => err.into(),
+                DeletePackVariantError::PackVariantDoesNotExist => {
+                    StandardGraphqlError::BadUserInput(formatted_error)
+                }
+            };
+            Err(graphql_error.extend())
+        }
+    }
+}
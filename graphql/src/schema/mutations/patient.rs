@@ -0,0 +1,180 @@
+use async_graphql::*;
+use chrono::NaiveDate;
+use service::{
+    patient::{
+        insert::{InsertPatient, InsertPatientError},
+        prescriber::{InsertPrescriber, InsertPrescriberError},
+        update::{UpdatePatient, UpdatePatientError},
+    },
+    permission_validation::{Resource, ResourceAccessRequest},
+};
+
+use crate::{
+    schema::queries::{PatientNode, PrescriberNode},
+    standard_graphql_error::{validate_auth, StandardGraphqlError},
+    ContextExt,
+};
+
+#[derive(InputObject)]
+pub struct InsertPatientInput {
+    pub id: String,
+    pub code: String,
+    pub first_name: Option<String>,
+    pub last_name: String,
+    pub date_of_birth: Option<NaiveDate>,
+    pub phone: Option<String>,
+}
+
+/// Omitted fields are left unchanged
+#[derive(InputObject)]
+pub struct UpdatePatientInput {
+    pub id: String,
+    pub code: Option<String>,
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    pub date_of_birth: Option<NaiveDate>,
+    pub phone: Option<String>,
+}
+
+#[derive(InputObject)]
+pub struct InsertPrescriberInput {
+    pub id: String,
+    pub code: String,
+    pub first_name: Option<String>,
+    pub last_name: String,
+    /// Registration with the medical council
+    pub registration_code: Option<String>,
+}
+
+fn validate_patient_auth(ctx: &Context<'_>, store_id: &str, resource: Resource) -> Result<()> {
+    validate_auth(
+        ctx,
+        &ResourceAccessRequest {
+            resource,
+            store_id: Some(store_id.to_string()),
+        },
+    )?;
+    Ok(())
+}
+
+fn map_insert_error(err: InsertPatientError) -> Error {
+    let formatted_error = format!("{:#?}", err);
+    let graphql_error = match err {
+        InsertPatientError::DatabaseError(err) => err.into(),
+        InsertPatientError::InternalError(err) => StandardGraphqlError::InternalError(err),
+        InsertPatientError::PatientAlreadyExists
+        | InsertPatientError::PatientWithCodeAlreadyExists
+        | InsertPatientError::LastNameIsEmpty
+        | InsertPatientError::InvalidStore => StandardGraphqlError::BadUserInput(formatted_error),
+    };
+    graphql_error.extend()
+}
+
+fn map_update_error(err: UpdatePatientError) -> Error {
+    let formatted_error = format!("{:#?}", err);
+    let graphql_error = match err {
+        UpdatePatientError::DatabaseError(err) => err.into(),
+        UpdatePatientError::InternalError(err) => StandardGraphqlError::InternalError(err),
+        UpdatePatientError::PatientDoesNotExist
+        | UpdatePatientError::NotPatientHomeStore
+        | UpdatePatientError::PatientWithCodeAlreadyExists
+        | UpdatePatientError::LastNameIsEmpty => {
+            StandardGraphqlError::BadUserInput(formatted_error)
+        }
+    };
+    graphql_error.extend()
+}
+
+fn map_insert_prescriber_error(err: InsertPrescriberError) -> Error {
+    let formatted_error = format!("{:#?}", err);
+    let graphql_error = match err {
+        InsertPrescriberError::DatabaseError(err) => err.into(),
+        InsertPrescriberError::InternalError(err) => StandardGraphqlError::InternalError(err),
+        InsertPrescriberError::PrescriberAlreadyExists
+        | InsertPrescriberError::LastNameIsEmpty
+        | InsertPrescriberError::InvalidStore => {
+            StandardGraphqlError::BadUserInput(formatted_error)
+        }
+    };
+    graphql_error.extend()
+}
+
+pub fn insert_patient(
+    ctx: &Context<'_>,
+    store_id: &str,
+    input: InsertPatientInput,
+) -> Result<PatientNode> {
+    validate_patient_auth(ctx, store_id, Resource::MutatePatient)?;
+
+    let service_provider = ctx.service_provider();
+    let service_ctx = service_provider.context()?;
+    service_provider
+        .patient_service
+        .insert_patient(
+            &service_ctx,
+            store_id,
+            InsertPatient {
+                id: input.id,
+                code: input.code,
+                first_name: input.first_name,
+                last_name: input.last_name,
+                date_of_birth: input.date_of_birth,
+                phone: input.phone,
+            },
+        )
+        .map(PatientNode::from)
+        .map_err(map_insert_error)
+}
+
+pub fn update_patient(
+    ctx: &Context<'_>,
+    store_id: &str,
+    input: UpdatePatientInput,
+) -> Result<PatientNode> {
+    validate_patient_auth(ctx, store_id, Resource::MutatePatient)?;
+
+    let service_provider = ctx.service_provider();
+    let service_ctx = service_provider.context()?;
+    service_provider
+        .patient_service
+        .update_patient(
+            &service_ctx,
+            store_id,
+            UpdatePatient {
+                id: input.id,
+                code: input.code,
+                first_name: input.first_name,
+                last_name: input.last_name,
+                date_of_birth: input.date_of_birth,
+                phone: input.phone,
+            },
+        )
+        .map(PatientNode::from)
+        .map_err(map_update_error)
+}
+
+pub fn insert_prescriber(
+    ctx: &Context<'_>,
+    store_id: &str,
+    input: InsertPrescriberInput,
+) -> Result<PrescriberNode> {
+    validate_patient_auth(ctx, store_id, Resource::MutatePrescriber)?;
+
+    let service_provider = ctx.service_provider();
+    let service_ctx = service_provider.context()?;
+    service_provider
+        .patient_service
+        .insert_prescriber(
+            &service_ctx,
+            store_id,
+            InsertPrescriber {
+                id: input.id,
+                code: input.code,
+                first_name: input.first_name,
+                last_name: input.last_name,
+                registration_code: input.registration_code,
+            },
+        )
+        .map(PrescriberNode::from)
+        .map_err(map_insert_prescriber_error)
+}
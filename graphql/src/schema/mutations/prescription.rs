@@ -0,0 +1,307 @@
+use async_graphql::*;
+use service::{
+    permission_validation::{Resource, ResourceAccessRequest},
+    prescription::{
+        delete::DeletePrescriptionError,
+        delete_line::DeletePrescriptionLineError,
+        insert::{InsertPrescription, InsertPrescriptionError},
+        insert_line::{InsertPrescriptionLine, InsertPrescriptionLineError},
+        update::{PrescriptionStatus, UpdatePrescription, UpdatePrescriptionError},
+        update_line::{UpdatePrescriptionLine, UpdatePrescriptionLineError},
+    },
+};
+
+use crate::{
+    schema::types::{InvoiceLineNode, InvoiceNode},
+    standard_graphql_error::{validate_auth, StandardGraphqlError},
+    ContextExt,
+};
+
+#[derive(InputObject)]
+pub struct InsertPrescriptionInput {
+    pub id: String,
+    pub patient_id: String,
+    pub prescriber_id: Option<String>,
+    pub their_reference: Option<String>,
+    pub comment: Option<String>,
+}
+
+#[derive(Enum, Copy, Clone, PartialEq, Eq)]
+pub enum UpdatePrescriptionStatusInput {
+    Picked,
+    Verified,
+}
+
+/// Omitted fields are left unchanged
+#[derive(InputObject)]
+pub struct UpdatePrescriptionInput {
+    pub id: String,
+    pub status: Option<UpdatePrescriptionStatusInput>,
+    pub prescriber_id: Option<String>,
+    pub comment: Option<String>,
+    pub their_reference: Option<String>,
+}
+
+#[derive(InputObject)]
+pub struct InsertPrescriptionLineInput {
+    pub id: String,
+    pub invoice_id: String,
+    /// Stock line the packs are dispensed from
+    pub stock_line_id: String,
+    pub number_of_packs: u32,
+    /// Dosage directions for the patient, printed on the label
+    pub directions: Option<String>,
+    pub note: Option<String>,
+}
+
+/// Omitted fields are left unchanged
+#[derive(InputObject)]
+pub struct UpdatePrescriptionLineInput {
+    pub id: String,
+    pub number_of_packs: Option<u32>,
+    pub directions: Option<String>,
+    pub note: Option<String>,
+}
+
+fn validate_prescription_auth(ctx: &Context<'_>, store_id: &str) -> Result<()> {
+    validate_auth(
+        ctx,
+        &ResourceAccessRequest {
+            resource: Resource::MutatePrescription,
+            store_id: Some(store_id.to_string()),
+        },
+    )?;
+    Ok(())
+}
+
+fn map_insert_error(err: InsertPrescriptionError) -> Error {
+    let formatted_error = format!("{:#?}", err);
+    let graphql_error = match err {
+        InsertPrescriptionError::DatabaseError(err) => err.into(),
+        InsertPrescriptionError::InternalError(err) => StandardGraphqlError::InternalError(err),
+        InsertPrescriptionError::InvoiceAlreadyExists
+        | InsertPrescriptionError::InvalidStore
+        | InsertPrescriptionError::PatientDoesNotExist
+        | InsertPrescriptionError::PrescriberDoesNotExist => {
+            StandardGraphqlError::BadUserInput(formatted_error)
+        }
+    };
+    graphql_error.extend()
+}
+
+fn map_update_error(err: UpdatePrescriptionError) -> Error {
+    let formatted_error = format!("{:#?}", err);
+    let graphql_error = match err {
+        UpdatePrescriptionError::DatabaseError(err) => err.into(),
+        UpdatePrescriptionError::InternalError(err) => StandardGraphqlError::InternalError(err),
+        UpdatePrescriptionError::InvoiceDoesNotExist
+        | UpdatePrescriptionError::NotAPrescription
+        | UpdatePrescriptionError::NotThisStoreInvoice
+        | UpdatePrescriptionError::CannotEditPrescription
+        | UpdatePrescriptionError::CannotReverseInvoiceStatus
+        | UpdatePrescriptionError::PrescriberDoesNotExist
+        | UpdatePrescriptionError::RejectedByPlugin(_) => {
+            StandardGraphqlError::BadUserInput(formatted_error)
+        }
+    };
+    graphql_error.extend()
+}
+
+fn map_delete_error(err: DeletePrescriptionError) -> Error {
+    let formatted_error = format!("{:#?}", err);
+    let graphql_error = match err {
+        DeletePrescriptionError::DatabaseError(err) => err.into(),
+        DeletePrescriptionError::InvoiceDoesNotExist
+        | DeletePrescriptionError::NotAPrescription
+        | DeletePrescriptionError::NotThisStoreInvoice
+        | DeletePrescriptionError::CannotEditPrescription
+        | DeletePrescriptionError::InvoiceLinesExist => {
+            StandardGraphqlError::BadUserInput(formatted_error)
+        }
+    };
+    graphql_error.extend()
+}
+
+fn map_insert_line_error(err: InsertPrescriptionLineError) -> Error {
+    let formatted_error = format!("{:#?}", err);
+    let graphql_error = match err {
+        InsertPrescriptionLineError::DatabaseError(err) => err.into(),
+        InsertPrescriptionLineError::InternalError(err) => StandardGraphqlError::InternalError(err),
+        InsertPrescriptionLineError::LineAlreadyExists
+        | InsertPrescriptionLineError::InvoiceDoesNotExist
+        | InsertPrescriptionLineError::NotAPrescription
+        | InsertPrescriptionLineError::NotThisStoreInvoice
+        | InsertPrescriptionLineError::CannotEditPrescription
+        | InsertPrescriptionLineError::StockLineDoesNotExist
+        | InsertPrescriptionLineError::StockLineAlreadyExistsInInvoice(_)
+        | InsertPrescriptionLineError::NumberOfPacksBelowOne
+        | InsertPrescriptionLineError::BatchIsOnHold
+        | InsertPrescriptionLineError::LocationIsOnHold
+        | InsertPrescriptionLineError::LocationNotFound
+        | InsertPrescriptionLineError::ReductionBelowZero
+        | InsertPrescriptionLineError::RejectedByPlugin(_) => {
+            StandardGraphqlError::BadUserInput(formatted_error)
+        }
+    };
+    graphql_error.extend()
+}
+
+fn map_update_line_error(err: UpdatePrescriptionLineError) -> Error {
+    let formatted_error = format!("{:#?}", err);
+    let graphql_error = match err {
+        UpdatePrescriptionLineError::DatabaseError(err) => err.into(),
+        UpdatePrescriptionLineError::InternalError(err) => StandardGraphqlError::InternalError(err),
+        UpdatePrescriptionLineError::LineDoesNotExist
+        | UpdatePrescriptionLineError::InvoiceDoesNotExist
+        | UpdatePrescriptionLineError::NotAPrescription
+        | UpdatePrescriptionLineError::NotThisStoreInvoice
+        | UpdatePrescriptionLineError::CannotEditPrescription
+        | UpdatePrescriptionLineError::NumberOfPacksBelowOne
+        | UpdatePrescriptionLineError::ReductionBelowZero
+        | UpdatePrescriptionLineError::RejectedByPlugin(_) => {
+            StandardGraphqlError::BadUserInput(formatted_error)
+        }
+    };
+    graphql_error.extend()
+}
+
+fn map_delete_line_error(err: DeletePrescriptionLineError) -> Error {
+    let formatted_error = format!("{:#?}", err);
+    let graphql_error = match err {
+        DeletePrescriptionLineError::DatabaseError(err) => err.into(),
+        DeletePrescriptionLineError::LineDoesNotExist
+        | DeletePrescriptionLineError::InvoiceDoesNotExist
+        | DeletePrescriptionLineError::NotAPrescription
+        | DeletePrescriptionLineError::NotThisStoreInvoice
+        | DeletePrescriptionLineError::CannotEditPrescription => {
+            StandardGraphqlError::BadUserInput(formatted_error)
+        }
+    };
+    graphql_error.extend()
+}
+
+pub fn insert_prescription(
+    ctx: &Context<'_>,
+    store_id: &str,
+    input: InsertPrescriptionInput,
+) -> Result<InvoiceNode> {
+    validate_prescription_auth(ctx, store_id)?;
+
+    let service_provider = ctx.service_provider();
+    let service_ctx = service_provider.context()?;
+    service_provider
+        .prescription_service
+        .insert_prescription(
+            &service_ctx,
+            store_id,
+            InsertPrescription {
+                id: input.id,
+                patient_id: input.patient_id,
+                prescriber_id: input.prescriber_id,
+                their_reference: input.their_reference,
+                comment: input.comment,
+            },
+        )
+        .map(InvoiceNode::from)
+        .map_err(map_insert_error)
+}
+
+pub fn update_prescription(
+    ctx: &Context<'_>,
+    store_id: &str,
+    input: UpdatePrescriptionInput,
+) -> Result<InvoiceNode> {
+    validate_prescription_auth(ctx, store_id)?;
+
+    let service_provider = ctx.service_provider();
+    let service_ctx = service_provider.context()?;
+    let input = UpdatePrescription {
+        id: input.id,
+        status: input.status.map(|status| match status {
+            UpdatePrescriptionStatusInput::Picked => PrescriptionStatus::Picked,
+            UpdatePrescriptionStatusInput::Verified => PrescriptionStatus::Verified,
+        }),
+        prescriber_id: input.prescriber_id,
+        comment: input.comment,
+        their_reference: input.their_reference,
+    };
+    service_provider
+        .prescription_service
+        .update_prescription(&service_ctx, store_id, input)
+        .map(InvoiceNode::from)
+        .map_err(map_update_error)
+}
+
+pub fn delete_prescription(ctx: &Context<'_>, store_id: &str, id: &str) -> Result<String> {
+    validate_prescription_auth(ctx, store_id)?;
+
+    let service_provider = ctx.service_provider();
+    let service_ctx = service_provider.context()?;
+    service_provider
+        .prescription_service
+        .delete_prescription(&service_ctx, store_id, id)
+        .map_err(map_delete_error)
+}
+
+pub fn insert_prescription_line(
+    ctx: &Context<'_>,
+    store_id: &str,
+    input: InsertPrescriptionLineInput,
+) -> Result<InvoiceLineNode> {
+    validate_prescription_auth(ctx, store_id)?;
+
+    let service_provider = ctx.service_provider();
+    let service_ctx = service_provider.context()?;
+    service_provider
+        .prescription_service
+        .insert_prescription_line(
+            &service_ctx,
+            store_id,
+            InsertPrescriptionLine {
+                id: input.id,
+                invoice_id: input.invoice_id,
+                stock_line_id: input.stock_line_id,
+                number_of_packs: input.number_of_packs,
+                directions: input.directions,
+                note: input.note,
+            },
+        )
+        .map(InvoiceLineNode::from)
+        .map_err(map_insert_line_error)
+}
+
+pub fn update_prescription_line(
+    ctx: &Context<'_>,
+    store_id: &str,
+    input: UpdatePrescriptionLineInput,
+) -> Result<InvoiceLineNode> {
+    validate_prescription_auth(ctx, store_id)?;
+
+    let service_provider = ctx.service_provider();
+    let service_ctx = service_provider.context()?;
+    service_provider
+        .prescription_service
+        .update_prescription_line(
+            &service_ctx,
+            store_id,
+            UpdatePrescriptionLine {
+                id: input.id,
+                number_of_packs: input.number_of_packs,
+                directions: input.directions,
+                note: input.note,
+            },
+        )
+        .map(InvoiceLineNode::from)
+        .map_err(map_update_line_error)
+}
+
+pub fn delete_prescription_line(ctx: &Context<'_>, store_id: &str, id: &str) -> Result<String> {
+    validate_prescription_auth(ctx, store_id)?;
+
+    let service_provider = ctx.service_provider();
+    let service_ctx = service_provider.context()?;
+    service_provider
+        .prescription_service
+        .delete_prescription_line(&service_ctx, store_id, id)
+        .map_err(map_delete_line_error)
+}
@@ -0,0 +1,97 @@
+use async_graphql::*;
+use service::{
+    permission_validation::{Resource, ResourceAccessRequest},
+    repack::{
+        insert::{InsertRepack, InsertRepackError as ServiceError},
+        Repack,
+    },
+};
+
+use crate::{
+    schema::types::{InvoiceNode, StockLineNode},
+    standard_graphql_error::{validate_auth, StandardGraphqlError},
+    ContextExt,
+};
+
+#[derive(InputObject)]
+pub struct RepackInput {
+    /// Id of the new repack invoice
+    pub id: String,
+    pub stock_line_id: String,
+    /// Number of packs taken from the stock line
+    pub number_of_packs: u32,
+    pub new_pack_size: u32,
+    /// Defaults to the location of the stock line
+    pub new_location_id: Option<String>,
+}
+
+#[derive(SimpleObject)]
+pub struct RepackNode {
+    /// Verified repack invoice recording the movement
+    pub invoice: InvoiceNode,
+    pub stock_line_from: StockLineNode,
+    pub stock_line_to: StockLineNode,
+}
+
+impl From<Repack> for RepackNode {
+    fn from(
+        Repack {
+            invoice,
+            stock_line_from,
+            stock_line_to,
+        }: Repack,
+    ) -> Self {
+        RepackNode {
+            invoice: InvoiceNode::from(invoice),
+            stock_line_from: StockLineNode::from(stock_line_from),
+            stock_line_to: StockLineNode::from(stock_line_to),
+        }
+    }
+}
+
+pub fn repack(ctx: &Context<'_>, store_id: &str, input: RepackInput) -> Result<RepackNode> {
+    validate_auth(
+        ctx,
+        &ResourceAccessRequest {
+            resource: Resource::RepackStock,
+            store_id: Some(store_id.to_string()),
+        },
+    )?;
+
+    let service_provider = ctx.service_provider();
+    let service_ctx = service_provider.context()?;
+    match service_provider.repack_service.insert_repack(
+        &service_ctx,
+        store_id,
+        InsertRepack {
+            id: input.id,
+            stock_line_id: input.stock_line_id,
+            number_of_packs: input.number_of_packs,
+            new_pack_size: input.new_pack_size,
+            new_location_id: input.new_location_id,
+        },
+    ) {
+        Ok(repack) => Ok(RepackNode::from(repack)),
+        Err(err) => Err(map_error(err)),
+    }
+}
+
+fn map_error(err: ServiceError) -> Error {
+    let formatted_error = format!("{:#?}", err);
+    let graphql_error = match err {
+        ServiceError::DatabaseError(err) => err.into(),
+        ServiceError::InternalError(err) => StandardGraphqlError::InternalError(err),
+        ServiceError::RepackAlreadyExists
+        | ServiceError::InvalidStore
+        | ServiceError::StockLineDoesNotExist
+        | ServiceError::StockLineIsOnHold
+        | ServiceError::NumberOfPacksBelowOne
+        | ServiceError::ReductionBelowZero
+        | ServiceError::PackSizeBelowOne
+        | ServiceError::PackSizeUnchanged
+        | ServiceError::CannotSplitIntoWholePacks
+        | ServiceError::LocationDoesNotExist
+        | ServiceError::LocationIsOnHold => StandardGraphqlError::BadUserInput(formatted_error),
+    };
+    graphql_error.extend()
+}
@@ -0,0 +1,381 @@
+use async_graphql::*;
+use service::{
+    permission_validation::{Resource, ResourceAccessRequest},
+    returns::{
+        delete::DeleteReturnError,
+        delete_line::DeleteReturnLineError,
+        insert::{InsertReturn, InsertReturnError},
+        insert_line::{InsertReturnLine, InsertReturnLineError},
+        update::{
+            CustomerReturnStatus, SupplierReturnStatus, UpdateCustomerReturn, UpdateReturnError,
+            UpdateSupplierReturn,
+        },
+        update_line::{UpdateReturnLine, UpdateReturnLineError},
+        ReturnType,
+    },
+};
+
+use crate::{
+    schema::types::{InvoiceLineNode, InvoiceNode},
+    standard_graphql_error::{validate_auth, StandardGraphqlError},
+    ContextExt,
+};
+
+#[derive(InputObject)]
+pub struct InsertReturnInput {
+    pub id: String,
+    pub other_party_id: String,
+    pub their_reference: Option<String>,
+    pub comment: Option<String>,
+    pub color: Option<String>,
+}
+
+#[derive(Enum, Copy, Clone, PartialEq, Eq)]
+pub enum UpdateSupplierReturnStatusInput {
+    Picked,
+    Shipped,
+}
+
+#[derive(Enum, Copy, Clone, PartialEq, Eq)]
+pub enum UpdateCustomerReturnStatusInput {
+    Delivered,
+    Verified,
+}
+
+/// Omitted fields are left unchanged
+#[derive(InputObject)]
+pub struct UpdateSupplierReturnInput {
+    pub id: String,
+    pub status: Option<UpdateSupplierReturnStatusInput>,
+    pub on_hold: Option<bool>,
+    pub comment: Option<String>,
+    pub their_reference: Option<String>,
+    pub color: Option<String>,
+}
+
+/// Omitted fields are left unchanged
+#[derive(InputObject)]
+pub struct UpdateCustomerReturnInput {
+    pub id: String,
+    pub status: Option<UpdateCustomerReturnStatusInput>,
+    pub on_hold: Option<bool>,
+    pub comment: Option<String>,
+    pub their_reference: Option<String>,
+    pub color: Option<String>,
+}
+
+#[derive(InputObject)]
+pub struct InsertReturnLineInput {
+    pub id: String,
+    pub invoice_id: String,
+    /// Stock line the packs are returned from (supplier return) or to (customer return)
+    pub stock_line_id: String,
+    pub number_of_packs: u32,
+    pub reason_id: Option<String>,
+    pub note: Option<String>,
+}
+
+/// Omitted fields are left unchanged
+#[derive(InputObject)]
+pub struct UpdateReturnLineInput {
+    pub id: String,
+    pub number_of_packs: Option<u32>,
+    pub reason_id: Option<String>,
+    pub note: Option<String>,
+}
+
+fn validate_return_auth(ctx: &Context<'_>, store_id: &str, r#type: ReturnType) -> Result<()> {
+    let resource = match r#type {
+        ReturnType::Supplier => Resource::MutateSupplierReturn,
+        ReturnType::Customer => Resource::MutateCustomerReturn,
+    };
+    validate_auth(
+        ctx,
+        &ResourceAccessRequest {
+            resource,
+            store_id: Some(store_id.to_string()),
+        },
+    )?;
+    Ok(())
+}
+
+fn map_insert_error(err: InsertReturnError) -> Error {
+    let formatted_error = format!("{:#?}", err);
+    let graphql_error = match err {
+        InsertReturnError::DatabaseError(err) => err.into(),
+        InsertReturnError::InternalError(err) => StandardGraphqlError::InternalError(err),
+        InsertReturnError::InvoiceAlreadyExists
+        | InsertReturnError::InvalidStore
+        | InsertReturnError::OtherPartyDoesNotExist
+        | InsertReturnError::OtherPartyNotASupplier(_)
+        | InsertReturnError::OtherPartyNotACustomer(_) => {
+            StandardGraphqlError::BadUserInput(formatted_error)
+        }
+    };
+    graphql_error.extend()
+}
+
+fn map_update_error(err: UpdateReturnError) -> Error {
+    let formatted_error = format!("{:#?}", err);
+    let graphql_error = match err {
+        UpdateReturnError::DatabaseError(err) => err.into(),
+        UpdateReturnError::InternalError(err) => StandardGraphqlError::InternalError(err),
+        UpdateReturnError::InvoiceDoesNotExist
+        | UpdateReturnError::NotASupplierReturn
+        | UpdateReturnError::NotACustomerReturn
+        | UpdateReturnError::NotThisStoreInvoice
+        | UpdateReturnError::CannotEditReturn
+        | UpdateReturnError::CannotReverseInvoiceStatus
+        | UpdateReturnError::CannotChangeStatusOfInvoiceOnHold
+        | UpdateReturnError::RejectedByPlugin(_) => {
+            StandardGraphqlError::BadUserInput(formatted_error)
+        }
+    };
+    graphql_error.extend()
+}
+
+fn map_delete_error(err: DeleteReturnError) -> Error {
+    let formatted_error = format!("{:#?}", err);
+    let graphql_error = match err {
+        DeleteReturnError::DatabaseError(err) => err.into(),
+        DeleteReturnError::InvoiceDoesNotExist
+        | DeleteReturnError::NotASupplierReturn
+        | DeleteReturnError::NotACustomerReturn
+        | DeleteReturnError::NotThisStoreInvoice
+        | DeleteReturnError::CannotEditReturn
+        | DeleteReturnError::InvoiceLinesExist => {
+            StandardGraphqlError::BadUserInput(formatted_error)
+        }
+    };
+    graphql_error.extend()
+}
+
+fn map_insert_line_error(err: InsertReturnLineError) -> Error {
+    let formatted_error = format!("{:#?}", err);
+    let graphql_error = match err {
+        InsertReturnLineError::DatabaseError(err) => err.into(),
+        InsertReturnLineError::InternalError(err) => StandardGraphqlError::InternalError(err),
+        InsertReturnLineError::LineAlreadyExists
+        | InsertReturnLineError::InvoiceDoesNotExist
+        | InsertReturnLineError::NotASupplierReturn
+        | InsertReturnLineError::NotACustomerReturn
+        | InsertReturnLineError::NotThisStoreInvoice
+        | InsertReturnLineError::CannotEditReturn
+        | InsertReturnLineError::StockLineDoesNotExist
+        | InsertReturnLineError::NumberOfPacksBelowOne
+        | InsertReturnLineError::ReductionBelowZero
+        | InsertReturnLineError::ReasonDoesNotExist
+        | InsertReturnLineError::NotAReturnReason
+        | InsertReturnLineError::RejectedByPlugin(_) => {
+            StandardGraphqlError::BadUserInput(formatted_error)
+        }
+    };
+    graphql_error.extend()
+}
+
+fn map_update_line_error(err: UpdateReturnLineError) -> Error {
+    let formatted_error = format!("{:#?}", err);
+    let graphql_error = match err {
+        UpdateReturnLineError::DatabaseError(err) => err.into(),
+        UpdateReturnLineError::InternalError(err) => StandardGraphqlError::InternalError(err),
+        UpdateReturnLineError::LineDoesNotExist
+        | UpdateReturnLineError::InvoiceDoesNotExist
+        | UpdateReturnLineError::NotASupplierReturn
+        | UpdateReturnLineError::NotACustomerReturn
+        | UpdateReturnLineError::NotThisStoreInvoice
+        | UpdateReturnLineError::CannotEditReturn
+        | UpdateReturnLineError::NumberOfPacksBelowOne
+        | UpdateReturnLineError::ReductionBelowZero
+        | UpdateReturnLineError::ReasonDoesNotExist
+        | UpdateReturnLineError::NotAReturnReason
+        | UpdateReturnLineError::RejectedByPlugin(_) => {
+            StandardGraphqlError::BadUserInput(formatted_error)
+        }
+    };
+    graphql_error.extend()
+}
+
+fn map_delete_line_error(err: DeleteReturnLineError) -> Error {
+    let formatted_error = format!("{:#?}", err);
+    let graphql_error = match err {
+        DeleteReturnLineError::DatabaseError(err) => err.into(),
+        DeleteReturnLineError::LineDoesNotExist
+        | DeleteReturnLineError::InvoiceDoesNotExist
+        | DeleteReturnLineError::NotASupplierReturn
+        | DeleteReturnLineError::NotACustomerReturn
+        | DeleteReturnLineError::NotThisStoreInvoice
+        | DeleteReturnLineError::CannotEditReturn => {
+            StandardGraphqlError::BadUserInput(formatted_error)
+        }
+    };
+    graphql_error.extend()
+}
+
+pub fn insert_return(
+    ctx: &Context<'_>,
+    store_id: &str,
+    r#type: ReturnType,
+    input: InsertReturnInput,
+) -> Result<InvoiceNode> {
+    validate_return_auth(ctx, store_id, r#type)?;
+
+    let service_provider = ctx.service_provider();
+    let service_ctx = service_provider.context()?;
+    let service = &service_provider.return_service;
+    let input = InsertReturn {
+        id: input.id,
+        other_party_id: input.other_party_id,
+        their_reference: input.their_reference,
+        comment: input.comment,
+        color: input.color,
+    };
+    let result = match r#type {
+        ReturnType::Supplier => service.insert_supplier_return(&service_ctx, store_id, input),
+        ReturnType::Customer => service.insert_customer_return(&service_ctx, store_id, input),
+    };
+    result.map(InvoiceNode::from).map_err(map_insert_error)
+}
+
+pub fn update_supplier_return(
+    ctx: &Context<'_>,
+    store_id: &str,
+    input: UpdateSupplierReturnInput,
+) -> Result<InvoiceNode> {
+    validate_return_auth(ctx, store_id, ReturnType::Supplier)?;
+
+    let service_provider = ctx.service_provider();
+    let service_ctx = service_provider.context()?;
+    let input = UpdateSupplierReturn {
+        id: input.id,
+        status: input.status.map(|status| match status {
+            UpdateSupplierReturnStatusInput::Picked => SupplierReturnStatus::Picked,
+            UpdateSupplierReturnStatusInput::Shipped => SupplierReturnStatus::Shipped,
+        }),
+        on_hold: input.on_hold,
+        comment: input.comment,
+        their_reference: input.their_reference,
+        color: input.color,
+    };
+    service_provider
+        .return_service
+        .update_supplier_return(&service_ctx, store_id, input)
+        .map(InvoiceNode::from)
+        .map_err(map_update_error)
+}
+
+pub fn update_customer_return(
+    ctx: &Context<'_>,
+    store_id: &str,
+    input: UpdateCustomerReturnInput,
+) -> Result<InvoiceNode> {
+    validate_return_auth(ctx, store_id, ReturnType::Customer)?;
+
+    let service_provider = ctx.service_provider();
+    let service_ctx = service_provider.context()?;
+    let input = UpdateCustomerReturn {
+        id: input.id,
+        status: input.status.map(|status| match status {
+            UpdateCustomerReturnStatusInput::Delivered => CustomerReturnStatus::Delivered,
+            UpdateCustomerReturnStatusInput::Verified => CustomerReturnStatus::Verified,
+        }),
+        on_hold: input.on_hold,
+        comment: input.comment,
+        their_reference: input.their_reference,
+        color: input.color,
+    };
+    service_provider
+        .return_service
+        .update_customer_return(&service_ctx, store_id, input)
+        .map(InvoiceNode::from)
+        .map_err(map_update_error)
+}
+
+pub fn delete_return(
+    ctx: &Context<'_>,
+    store_id: &str,
+    r#type: ReturnType,
+    id: &str,
+) -> Result<String> {
+    validate_return_auth(ctx, store_id, r#type)?;
+
+    let service_provider = ctx.service_provider();
+    let service_ctx = service_provider.context()?;
+    let service = &service_provider.return_service;
+    let result = match r#type {
+        ReturnType::Supplier => service.delete_supplier_return(&service_ctx, store_id, id),
+        ReturnType::Customer => service.delete_customer_return(&service_ctx, store_id, id),
+    };
+    result.map_err(map_delete_error)
+}
+
+pub fn insert_return_line(
+    ctx: &Context<'_>,
+    store_id: &str,
+    r#type: ReturnType,
+    input: InsertReturnLineInput,
+) -> Result<InvoiceLineNode> {
+    validate_return_auth(ctx, store_id, r#type)?;
+
+    let service_provider = ctx.service_provider();
+    let service_ctx = service_provider.context()?;
+    let service = &service_provider.return_service;
+    let input = InsertReturnLine {
+        id: input.id,
+        invoice_id: input.invoice_id,
+        stock_line_id: input.stock_line_id,
+        number_of_packs: input.number_of_packs,
+        reason_id: input.reason_id,
+        note: input.note,
+    };
+    let result = match r#type {
+        ReturnType::Supplier => service.insert_supplier_return_line(&service_ctx, store_id, input),
+        ReturnType::Customer => service.insert_customer_return_line(&service_ctx, store_id, input),
+    };
+    result
+        .map(InvoiceLineNode::from)
+        .map_err(map_insert_line_error)
+}
+
+pub fn update_return_line(
+    ctx: &Context<'_>,
+    store_id: &str,
+    r#type: ReturnType,
+    input: UpdateReturnLineInput,
+) -> Result<InvoiceLineNode> {
+    validate_return_auth(ctx, store_id, r#type)?;
+
+    let service_provider = ctx.service_provider();
+    let service_ctx = service_provider.context()?;
+    let service = &service_provider.return_service;
+    let input = UpdateReturnLine {
+        id: input.id,
+        number_of_packs: input.number_of_packs,
+        reason_id: input.reason_id,
+        note: input.note,
+    };
+    let result = match r#type {
+        ReturnType::Supplier => service.update_supplier_return_line(&service_ctx, store_id, input),
+        ReturnType::Customer => service.update_customer_return_line(&service_ctx, store_id, input),
+    };
+    result
+        .map(InvoiceLineNode::from)
+        .map_err(map_update_line_error)
+}
+
+pub fn delete_return_line(
+    ctx: &Context<'_>,
+    store_id: &str,
+    r#type: ReturnType,
+    id: &str,
+) -> Result<String> {
+    validate_return_auth(ctx, store_id, r#type)?;
+
+    let service_provider = ctx.service_provider();
+    let service_ctx = service_provider.context()?;
+    let service = &service_provider.return_service;
+    let result = match r#type {
+        ReturnType::Supplier => service.delete_supplier_return_line(&service_ctx, store_id, id),
+        ReturnType::Customer => service.delete_customer_return_line(&service_ctx, store_id, id),
+    };
+    result.map_err(map_delete_line_error)
+}
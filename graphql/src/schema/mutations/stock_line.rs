@@ -0,0 +1,72 @@
+use async_graphql::*;
+use service::{
+    permission_validation::{Resource, ResourceAccessRequest},
+    stock_expiry::hold::{HoldStockLines, HoldStockLinesError as ServiceError},
+};
+
+use crate::{
+    schema::types::StockLineNode,
+    standard_graphql_error::{validate_auth, StandardGraphqlError},
+    ContextExt,
+};
+
+#[derive(InputObject)]
+pub struct HoldStockLinesInput {
+    pub stock_line_ids: Vec<String>,
+    /// Defaults to true
+    pub on_hold: Option<bool>,
+    /// Quarantine location to move the stock lines to
+    pub location_id: Option<String>,
+}
+
+pub fn hold_stock_lines(
+    ctx: &Context<'_>,
+    store_id: &str,
+    input: HoldStockLinesInput,
+) -> Result<Vec<StockLineNode>> {
+    validate_auth(
+        ctx,
+        &ResourceAccessRequest {
+            resource: Resource::HoldStockLines,
+            store_id: Some(store_id.to_string()),
+        },
+    )?;
+
+    let service_provider = ctx.service_provider();
+    let service_ctx = service_provider.context()?;
+    match service_provider.stock_expiry_service.hold_stock_lines(
+        &service_ctx,
+        store_id,
+        HoldStockLines {
+            stock_line_ids: input.stock_line_ids,
+            on_hold: input.on_hold.unwrap_or(true),
+            location_id: input.location_id,
+        },
+    ) {
+        Ok(stock_lines) => Ok(stock_lines.into_iter().map(StockLineNode::from).collect()),
+        Err(err) => Err(map_error(err)),
+    }
+}
+
+fn map_error(err: ServiceError) -> async_graphql::Error {
+    let formatted_error = format!("{:#?}", err);
+    let graphql_error = match err {
+        ServiceError::DatabaseError(err) => err.into(),
+        ServiceError::InternalError(err) => StandardGraphqlError::InternalError(err),
+        ServiceError::NoStockLines => StandardGraphqlError::BadUserInput(formatted_error),
+        ServiceError::StockLineDoesNotExist(_) => {
+            StandardGraphqlError::BadUserInput(formatted_error)
+        }
+        ServiceError::StockLineDoesNotBelongToCurrentStore(_) => {
+            StandardGraphqlError::BadUserInput(formatted_error)
+        }
+        ServiceError::LocationDoesNotExist => StandardGraphqlError::BadUserInput(formatted_error),
+        ServiceError::LocationDoesNotBelongToCurrentStore => {
+            StandardGraphqlError::BadUserInput(formatted_error)
+        }
+        ServiceError::LocationNotSuitableForItem(_) => {
+            StandardGraphqlError::BadUserInput(formatted_error)
+        }
+    };
+    graphql_error.extend()
+}
@@ -1,7 +1,12 @@
 use chrono::NaiveDateTime;
-use repository::{schema::StockTakeStatus, StockTake};
+use repository::{schema::StockTakeStatus, StockTake, StockTakeFilter};
+use schemars::JsonSchema;
+use serde::Serialize;
 
 use async_graphql::*;
+use domain::{DatetimeFilter, EqualFilter};
+
+use crate::schema::types::{DatetimeFilterInput, EqualFilterInput, SortInput};
 
 pub mod delete;
 pub mod insert;
@@ -12,7 +17,8 @@ pub struct StockTakeNode {
     pub stock_take: StockTake,
 }
 
-#[derive(Enum, Copy, Clone, PartialEq, Eq, Debug)]
+#[derive(Enum, Copy, Clone, PartialEq, Eq, Debug, Serialize, JsonSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum StockTakeNodeStatus {
     New,
     Finalized,
@@ -27,7 +33,36 @@ impl StockTakeNodeStatus {
     }
 }
 
-fn from_domain(status: &StockTakeStatus) -> StockTakeNodeStatus {
+#[derive(Enum, Copy, Clone, PartialEq, Eq)]
+#[graphql(remote = "repository::StockTakeSortField")]
+#[graphql(rename_items = "camelCase")]
+pub enum StockTakeSortFieldInput {
+    Status,
+    CreatedDatetime,
+    FinalisedDatetime,
+}
+pub type StockTakeSortInput = SortInput<StockTakeSortFieldInput>;
+
+#[derive(InputObject, Clone)]
+pub struct StockTakeFilterInput {
+    pub status: Option<EqualFilterInput<StockTakeNodeStatus>>,
+    pub created_datetime: Option<DatetimeFilterInput>,
+    pub finalised_datetime: Option<DatetimeFilterInput>,
+}
+
+impl From<StockTakeFilterInput> for StockTakeFilter {
+    fn from(f: StockTakeFilterInput) -> Self {
+        StockTakeFilter {
+            id: None,
+            store_id: None,
+            status: f.status.map(EqualFilter::from),
+            created_datetime: f.created_datetime.map(DatetimeFilter::from),
+            finalised_datetime: f.finalised_datetime.map(DatetimeFilter::from),
+        }
+    }
+}
+
+pub fn from_domain(status: &StockTakeStatus) -> StockTakeNodeStatus {
     match status {
         StockTakeStatus::New => StockTakeNodeStatus::New,
         StockTakeStatus::Finalized => StockTakeNodeStatus::Finalized,
@@ -1,5 +1,5 @@
 use crate::{
-    schema::types::StockTakeLineNode,
+    schema::{mutations::RejectedByPlugin, types::StockTakeLineNode},
     standard_graphql_error::{validate_auth, StandardGraphqlError},
     ContextExt,
 };
@@ -50,10 +50,32 @@ impl SnapshotCountCurrentCountMismatch {
     }
 }
 
+pub struct AdjustmentReasonRequired(Vec<StockTakeLine>);
+#[Object]
+impl AdjustmentReasonRequired {
+    pub async fn description(&self) -> &'static str {
+        "Adjusted lines require a comment with the reason for the adjustment"
+    }
+
+    pub async fn lines(&self) -> StockTakeLineConnector {
+        let lines: Vec<StockTakeLineNode> = self
+            .0
+            .iter()
+            .map(|line| StockTakeLineNode { line: line.clone() })
+            .collect();
+        StockTakeLineConnector {
+            total_count: usize_to_u32(lines.len()),
+            nodes: lines,
+        }
+    }
+}
+
 #[derive(Interface)]
 #[graphql(field(name = "description", type = "String"))]
 pub enum UpdateStockTakeErrorInterface {
     SnapshotCountCurrentCountMismatch(SnapshotCountCurrentCountMismatch),
+    AdjustmentReasonRequired(AdjustmentReasonRequired),
+    RejectedByPlugin(RejectedByPlugin),
 }
 
 #[derive(SimpleObject)]
@@ -103,6 +125,16 @@ fn map_error(err: ServiceError) -> Result<UpdateStockTakeErrorInterface> {
                 ),
             )
         }
+        ServiceError::AdjustmentReasonRequired(lines) => {
+            return Ok(UpdateStockTakeErrorInterface::AdjustmentReasonRequired(
+                AdjustmentReasonRequired(lines),
+            ))
+        }
+        ServiceError::RejectedByPlugin(rejection) => {
+            return Ok(UpdateStockTakeErrorInterface::RejectedByPlugin(
+                RejectedByPlugin(rejection),
+            ))
+        }
 
         // standard gql errors:
         ServiceError::DatabaseError(err) => err.into(),
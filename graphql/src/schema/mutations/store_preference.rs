@@ -0,0 +1,87 @@
+use async_graphql::*;
+use service::{
+    permission_validation::{Resource, ResourceAccessRequest},
+    preference::update::{UpdateStorePreferences, UpdateStorePreferencesError as ServiceError},
+};
+
+use crate::{
+    schema::queries::store_preference::StorePreferenceNode,
+    standard_graphql_error::{validate_auth, StandardGraphqlError},
+    ContextExt,
+};
+
+/// Preferences to override locally, omitted fields are left unchanged
+#[derive(InputObject)]
+pub struct UpdateStorePreferencesInput {
+    pub pack_to_one: Option<bool>,
+    pub months_overstock: Option<f64>,
+    pub months_understock: Option<f64>,
+    pub months_items_expire: Option<f64>,
+    pub allow_negative_stock: Option<bool>,
+    /// Timezone offset in hours
+    pub timezone_offset: Option<i32>,
+    pub months_of_stock_target: Option<f64>,
+    pub requires_reason_on_adjustment: Option<bool>,
+    pub expiring_soon_days: Option<i32>,
+}
+
+pub fn update_store_preferences(
+    ctx: &Context<'_>,
+    store_id: &str,
+    input: UpdateStorePreferencesInput,
+) -> Result<StorePreferenceNode> {
+    validate_auth(
+        ctx,
+        &ResourceAccessRequest {
+            resource: Resource::UpdateStorePreferences,
+            store_id: Some(store_id.to_string()),
+        },
+    )?;
+
+    let service_provider = ctx.service_provider();
+    let service_ctx = service_provider.context()?;
+    match service_provider
+        .preference_service
+        .update_store_preferences(&service_ctx, store_id, to_domain(input))
+    {
+        Ok(preferences) => Ok(StorePreferenceNode { preferences }),
+        Err(err) => Err(map_error(err)),
+    }
+}
+
+fn map_error(err: ServiceError) -> async_graphql::Error {
+    let formatted_error = format!("{:#?}", err);
+    let graphql_error = match err {
+        ServiceError::DatabaseError(err) => err.into(),
+        ServiceError::StoreDoesNotExist => StandardGraphqlError::BadUserInput(formatted_error),
+        ServiceError::InvalidTimezoneOffset => StandardGraphqlError::BadUserInput(formatted_error),
+        ServiceError::NegativeValue => StandardGraphqlError::BadUserInput(formatted_error),
+    };
+    graphql_error.extend()
+}
+
+fn to_domain(
+    UpdateStorePreferencesInput {
+        pack_to_one,
+        months_overstock,
+        months_understock,
+        months_items_expire,
+        allow_negative_stock,
+        timezone_offset,
+        months_of_stock_target,
+        requires_reason_on_adjustment,
+        expiring_soon_days,
+    }: UpdateStorePreferencesInput,
+) -> UpdateStorePreferences {
+    UpdateStorePreferences {
+        pack_to_one,
+        months_overstock,
+        months_understock,
+        months_items_expire,
+        allow_negative_stock,
+        timezone_offset,
+        months_of_stock_target,
+        requires_reason_on_adjustment,
+        expiring_soon_days,
+    }
+}
@@ -0,0 +1,131 @@
+use async_graphql::*;
+use service::{
+    permission_validation::{Resource, ResourceAccessRequest},
+    temperature_log::breach_config::{
+        InsertTemperatureBreachConfig, InsertTemperatureBreachConfigError,
+        UpdateTemperatureBreachConfig, UpdateTemperatureBreachConfigError,
+    },
+};
+
+use crate::{
+    schema::queries::temperature_log::TemperatureBreachConfigNode,
+    standard_graphql_error::{validate_auth, StandardGraphqlError},
+    ContextExt,
+};
+
+#[derive(InputObject)]
+pub struct InsertTemperatureBreachConfigInput {
+    pub id: String,
+    pub location_type_id: String,
+    pub description: String,
+    /// Readings below the minimum are out of range, at least one of minimum and maximum is
+    /// required
+    pub minimum_temperature: Option<f64>,
+    /// Readings above the maximum are out of range
+    pub maximum_temperature: Option<f64>,
+    /// Readings must be out of range for at least this long to count as a breach
+    pub duration_minutes: u32,
+    /// Defaults to true
+    pub is_active: Option<bool>,
+}
+
+#[derive(InputObject)]
+pub struct UpdateTemperatureBreachConfigInput {
+    pub id: String,
+    pub description: Option<String>,
+    pub minimum_temperature: Option<f64>,
+    pub maximum_temperature: Option<f64>,
+    pub duration_minutes: Option<u32>,
+    pub is_active: Option<bool>,
+}
+
+fn validate_mutation_auth(ctx: &Context<'_>, store_id: &str) -> Result<()> {
+    validate_auth(
+        ctx,
+        &ResourceAccessRequest {
+            resource: Resource::MutateTemperatureBreachConfigs,
+            store_id: Some(store_id.to_string()),
+        },
+    )?;
+    Ok(())
+}
+
+pub fn insert_temperature_breach_config(
+    ctx: &Context<'_>,
+    store_id: &str,
+    input: InsertTemperatureBreachConfigInput,
+) -> Result<TemperatureBreachConfigNode> {
+    validate_mutation_auth(ctx, store_id)?;
+
+    let service_provider = ctx.service_provider();
+    let service_ctx = service_provider.context()?;
+    match service_provider
+        .temperature_log_service
+        .insert_temperature_breach_config(
+            &service_ctx,
+            InsertTemperatureBreachConfig {
+                id: input.id,
+                location_type_id: input.location_type_id,
+                description: input.description,
+                minimum_temperature: input.minimum_temperature,
+                maximum_temperature: input.maximum_temperature,
+                duration_minutes: input.duration_minutes,
+                is_active: input.is_active,
+            },
+        ) {
+        Ok(config) => Ok(TemperatureBreachConfigNode::from(config)),
+        Err(err) => Err(map_insert_error(err)),
+    }
+}
+
+pub fn update_temperature_breach_config(
+    ctx: &Context<'_>,
+    store_id: &str,
+    input: UpdateTemperatureBreachConfigInput,
+) -> Result<TemperatureBreachConfigNode> {
+    validate_mutation_auth(ctx, store_id)?;
+
+    let service_provider = ctx.service_provider();
+    let service_ctx = service_provider.context()?;
+    match service_provider
+        .temperature_log_service
+        .update_temperature_breach_config(
+            &service_ctx,
+            UpdateTemperatureBreachConfig {
+                id: input.id,
+                description: input.description,
+                minimum_temperature: input.minimum_temperature,
+                maximum_temperature: input.maximum_temperature,
+                duration_minutes: input.duration_minutes,
+                is_active: input.is_active,
+            },
+        ) {
+        Ok(config) => Ok(TemperatureBreachConfigNode::from(config)),
+        Err(err) => Err(map_update_error(err)),
+    }
+}
+
+fn map_insert_error(err: InsertTemperatureBreachConfigError) -> Error {
+    use InsertTemperatureBreachConfigError as ServiceError;
+    let formatted_error = format!("{:#?}", err);
+    let graphql_error = match err {
+        ServiceError::DatabaseError(err) => err.into(),
+        ServiceError::ConfigAlreadyExists
+        | ServiceError::LocationTypeDoesNotExist
+        | ServiceError::NoTemperatureRange
+        | ServiceError::MinimumAboveMaximum => StandardGraphqlError::BadUserInput(formatted_error),
+    };
+    graphql_error.extend()
+}
+
+fn map_update_error(err: UpdateTemperatureBreachConfigError) -> Error {
+    use UpdateTemperatureBreachConfigError as ServiceError;
+    let formatted_error = format!("{:#?}", err);
+    let graphql_error = match err {
+        ServiceError::DatabaseError(err) => err.into(),
+        ServiceError::ConfigDoesNotExist | ServiceError::MinimumAboveMaximum => {
+            StandardGraphqlError::BadUserInput(formatted_error)
+        }
+    };
+    graphql_error.extend()
+}
@@ -0,0 +1,156 @@
+use async_graphql::*;
+use chrono::NaiveDate;
+use repository::schema::BarcodeRow;
+use service::{
+    barcode::{
+        gs1::Gs1Error,
+        scan::{ScanBarcodeError, ScannedBarcode},
+    },
+    permission_validation::{Resource, ResourceAccessRequest},
+};
+
+use crate::{
+    schema::types::ItemNode,
+    standard_graphql_error::{validate_auth, StandardGraphqlError},
+    ContextExt,
+};
+
+pub struct BarcodeNode {
+    pub barcode: BarcodeRow,
+}
+
+#[Object]
+impl BarcodeNode {
+    pub async fn id(&self) -> &str {
+        &self.barcode.id
+    }
+
+    /// Scanned value, e.g. a GTIN
+    pub async fn value(&self) -> &str {
+        &self.barcode.value
+    }
+
+    pub async fn item_id(&self) -> &str {
+        &self.barcode.item_id
+    }
+
+    /// Name id of the manufacturer
+    pub async fn manufacturer_id(&self) -> &Option<String> {
+        &self.barcode.manufacturer_id
+    }
+
+    /// Pack size the barcode is printed on
+    pub async fn pack_size(&self) -> Option<u32> {
+        self.barcode.pack_size.map(|pack_size| pack_size as u32)
+    }
+}
+
+/// Fields map to the inputs of inbound shipment and stock take lines
+pub struct ScannedBarcodeNode {
+    scanned: ScannedBarcode,
+}
+
+#[Object]
+impl ScannedBarcodeNode {
+    /// Scanned value with surrounding whitespace removed
+    pub async fn value(&self) -> &str {
+        &self.scanned.value
+    }
+
+    /// GTIN-14 of the scanned GTIN or GS1 element string
+    pub async fn gtin(&self) -> &Option<String> {
+        &self.scanned.gtin
+    }
+
+    /// Matching barcode, null if the barcode is unknown
+    pub async fn barcode(&self) -> Option<BarcodeNode> {
+        self.scanned
+            .barcode
+            .clone()
+            .map(|barcode| BarcodeNode { barcode })
+    }
+
+    /// Item of the matching barcode or the item with the scanned value as code
+    pub async fn item(&self) -> Option<ItemNode> {
+        self.scanned.item.clone().map(ItemNode::from)
+    }
+
+    pub async fn item_id(&self) -> Option<&str> {
+        self.scanned.item.as_ref().map(|item| item.id.as_str())
+    }
+
+    pub async fn pack_size(&self) -> Option<u32> {
+        self.scanned.pack_size().map(|pack_size| pack_size as u32)
+    }
+
+    pub async fn batch(&self) -> &Option<String> {
+        &self.scanned.batch
+    }
+
+    pub async fn expiry_date(&self) -> &Option<NaiveDate> {
+        &self.scanned.expiry_date
+    }
+
+    pub async fn serial_number(&self) -> &Option<String> {
+        &self.scanned.serial
+    }
+}
+
+pub fn scan_barcode(ctx: &Context<'_>, store_id: &str, value: &str) -> Result<ScannedBarcodeNode> {
+    validate_auth(
+        ctx,
+        &ResourceAccessRequest {
+            resource: Resource::ScanBarcode,
+            store_id: Some(store_id.to_string()),
+        },
+    )?;
+
+    let service_provider = ctx.service_provider();
+    let service_ctx = service_provider.context()?;
+    match service_provider
+        .barcode_service
+        .scan_barcode(&service_ctx, store_id, value)
+    {
+        Ok(scanned) => Ok(ScannedBarcodeNode { scanned }),
+        Err(err) => Err(map_error(err)),
+    }
+}
+
+pub fn barcodes(ctx: &Context<'_>, store_id: &str, item_id: &str) -> Result<Vec<BarcodeNode>> {
+    validate_auth(
+        ctx,
+        &ResourceAccessRequest {
+            resource: Resource::QueryItems,
+            store_id: Some(store_id.to_string()),
+        },
+    )?;
+
+    let service_provider = ctx.service_provider();
+    let service_ctx = service_provider.context()?;
+    let barcodes = service_provider
+        .barcode_service
+        .get_item_barcodes(&service_ctx, item_id)
+        .map_err(|err| StandardGraphqlError::from(err).extend())?;
+    Ok(barcodes
+        .into_iter()
+        .map(|barcode| BarcodeNode { barcode })
+        .collect())
+}
+
+fn map_error(err: ScanBarcodeError) -> async_graphql::Error {
+    let graphql_error = match err {
+        ScanBarcodeError::DatabaseError(err) => err.into(),
+        ScanBarcodeError::EmptyBarcode => {
+            StandardGraphqlError::BadUserInput("Barcode is empty".to_string())
+        }
+        ScanBarcodeError::InvalidGs1(err) => StandardGraphqlError::BadUserInput(match err {
+            Gs1Error::UnknownApplicationIdentifier(ai) => {
+                format!("Unsupported GS1 application identifier {}", ai)
+            }
+            Gs1Error::InvalidLength(ai) => format!("Invalid length of GS1 field ({})", ai),
+            Gs1Error::InvalidDate(ai) => format!("Invalid date in GS1 field ({})", ai),
+            Gs1Error::InvalidCheckDigit(ai) => format!("Invalid check digit in GS1 field ({})", ai),
+        }),
+    };
+    graphql_error.extend()
+}
@@ -0,0 +1,260 @@
+use async_graphql::*;
+use chrono::{DateTime, Utc};
+use domain::invoice::InvoiceType;
+use repository::schema::{BatchRecallRow, StockMovementRow};
+use service::{
+    batch_recall::{
+        trace::{BatchRecallCustomer, BatchRecallSelection, BatchRecallTrace},
+        BatchRecallError,
+    },
+    permission_validation::{Resource, ResourceAccessRequest},
+};
+
+use crate::{
+    schema::types::{InvoiceNodeType, StockLineNode},
+    standard_graphql_error::{validate_auth, StandardGraphqlError},
+    ContextExt,
+};
+
+/// Either item and batch or stock line ids must be provided
+#[derive(InputObject, Clone)]
+pub struct BatchRecallSelectionInput {
+    pub item_id: Option<String>,
+    pub batch: Option<String>,
+    pub stock_line_ids: Option<Vec<String>>,
+}
+
+impl From<BatchRecallSelectionInput> for BatchRecallSelection {
+    fn from(
+        BatchRecallSelectionInput {
+            item_id,
+            batch,
+            stock_line_ids,
+        }: BatchRecallSelectionInput,
+    ) -> Self {
+        BatchRecallSelection {
+            item_id,
+            batch,
+            stock_line_ids,
+        }
+    }
+}
+
+pub struct BatchRecallNode {
+    pub recall: BatchRecallRow,
+}
+
+#[Object]
+impl BatchRecallNode {
+    pub async fn id(&self) -> &str {
+        &self.recall.id
+    }
+
+    pub async fn reference(&self) -> &str {
+        &self.recall.reference
+    }
+
+    pub async fn item_id(&self) -> &Option<String> {
+        &self.recall.item_id
+    }
+
+    pub async fn batch(&self) -> &Option<String> {
+        &self.recall.batch
+    }
+
+    pub async fn created_datetime(&self) -> DateTime<Utc> {
+        DateTime::<Utc>::from_utc(self.recall.created_datetime, Utc)
+    }
+}
+
+pub struct BatchRecallMovementNode {
+    movement: StockMovementRow,
+}
+
+#[Object]
+impl BatchRecallMovementNode {
+    /// Id of the invoice line
+    pub async fn id(&self) -> &str {
+        &self.movement.id
+    }
+
+    pub async fn datetime(&self) -> DateTime<Utc> {
+        DateTime::<Utc>::from_utc(self.movement.datetime, Utc)
+    }
+
+    pub async fn invoice_id(&self) -> &str {
+        &self.movement.invoice_id
+    }
+
+    pub async fn invoice_type(&self) -> InvoiceNodeType {
+        InvoiceType::from(self.movement.invoice_type.clone()).into()
+    }
+
+    pub async fn invoice_number(&self) -> i64 {
+        self.movement.invoice_number
+    }
+
+    pub async fn other_party_id(&self) -> &str {
+        &self.movement.other_party_id
+    }
+
+    pub async fn other_party_name(&self) -> &str {
+        &self.movement.other_party_name
+    }
+
+    pub async fn stock_line_id(&self) -> &Option<String> {
+        &self.movement.stock_line_id
+    }
+
+    /// Number of units, negative if stock left the store
+    pub async fn units(&self) -> i64 {
+        self.movement.number_of_packs as i64 * self.movement.pack_size as i64
+    }
+}
+
+pub struct BatchRecallCustomerNode {
+    customer: BatchRecallCustomer,
+}
+
+#[Object]
+impl BatchRecallCustomerNode {
+    pub async fn name_id(&self) -> &str {
+        &self.customer.name_id
+    }
+
+    pub async fn name(&self) -> &str {
+        &self.customer.name
+    }
+
+    /// Units shipped to the customer
+    pub async fn units(&self) -> i64 {
+        self.customer.units
+    }
+
+    pub async fn number_of_shipments(&self) -> i64 {
+        self.customer.number_of_shipments
+    }
+
+    pub async fn first_shipped_datetime(&self) -> DateTime<Utc> {
+        DateTime::<Utc>::from_utc(self.customer.first_shipped_datetime, Utc)
+    }
+
+    pub async fn last_shipped_datetime(&self) -> DateTime<Utc> {
+        DateTime::<Utc>::from_utc(self.customer.last_shipped_datetime, Utc)
+    }
+}
+
+pub struct BatchRecallTraceNode {
+    trace: BatchRecallTrace,
+}
+
+fn movement_nodes(movements: &[StockMovementRow]) -> Vec<BatchRecallMovementNode> {
+    movements
+        .iter()
+        .cloned()
+        .map(|movement| BatchRecallMovementNode { movement })
+        .collect()
+}
+
+#[Object]
+impl BatchRecallTraceNode {
+    /// Local stock lines of the batch
+    pub async fn stock_lines(&self) -> Vec<StockLineNode> {
+        self.trace
+            .stock_lines
+            .iter()
+            .cloned()
+            .map(StockLineNode::from)
+            .collect()
+    }
+
+    /// Inbound shipment lines that received the batch
+    pub async fn receipts(&self) -> Vec<BatchRecallMovementNode> {
+        movement_nodes(&self.trace.receipts)
+    }
+
+    /// Outbound shipment lines that issued the batch
+    pub async fn issues(&self) -> Vec<BatchRecallMovementNode> {
+        movement_nodes(&self.trace.issues)
+    }
+
+    pub async fn adjustments(&self) -> Vec<BatchRecallMovementNode> {
+        movement_nodes(&self.trace.adjustments)
+    }
+
+    /// Supplier and customer returns of the batch
+    pub async fn returns(&self) -> Vec<BatchRecallMovementNode> {
+        movement_nodes(&self.trace.returns)
+    }
+
+    /// Customers that received the batch
+    pub async fn customers(&self) -> Vec<BatchRecallCustomerNode> {
+        self.trace
+            .customers
+            .iter()
+            .cloned()
+            .map(|customer| BatchRecallCustomerNode { customer })
+            .collect()
+    }
+
+    pub async fn units_received(&self) -> i64 {
+        self.trace.units_received
+    }
+
+    pub async fn units_issued(&self) -> i64 {
+        self.trace.units_issued
+    }
+
+    pub async fn units_on_hand(&self) -> i64 {
+        self.trace.units_on_hand
+    }
+
+    /// Recalls recorded for the stock lines of the batch
+    pub async fn recalls(&self) -> Vec<BatchRecallNode> {
+        self.trace
+            .recalls
+            .iter()
+            .cloned()
+            .map(|recall| BatchRecallNode { recall })
+            .collect()
+    }
+}
+
+pub fn batch_recall_trace(
+    ctx: &Context<'_>,
+    store_id: &str,
+    selection: BatchRecallSelectionInput,
+) -> Result<BatchRecallTraceNode> {
+    validate_auth(
+        ctx,
+        &ResourceAccessRequest {
+            resource: Resource::QueryBatchRecallTrace,
+            store_id: Some(store_id.to_string()),
+        },
+    )?;
+
+    let service_provider = ctx.service_provider();
+    let service_ctx = service_provider.context()?;
+    match service_provider
+        .batch_recall_service
+        .get_batch_recall_trace(&service_ctx, store_id, selection.into())
+    {
+        Ok(trace) => Ok(BatchRecallTraceNode { trace }),
+        Err(err) => Err(map_batch_recall_error(err)),
+    }
+}
+
+pub fn map_batch_recall_error(err: BatchRecallError) -> async_graphql::Error {
+    let formatted_error = format!("{:#?}", err);
+    let graphql_error = match err {
+        BatchRecallError::DatabaseError(err) => err.into(),
+        BatchRecallError::MissingBatchOrStockLines => {
+            StandardGraphqlError::BadUserInput(formatted_error)
+        }
+        BatchRecallError::StockLineDoesNotExist(_) => {
+            StandardGraphqlError::BadUserInput(formatted_error)
+        }
+        BatchRecallError::EmptyReference => StandardGraphqlError::BadUserInput(formatted_error),
+    };
+    graphql_error.extend()
+}
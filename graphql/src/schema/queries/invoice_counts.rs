@@ -4,7 +4,10 @@ use domain::invoice::{InvoiceStatus, InvoiceType};
 use service::dashboard::invoice_count::{CountTimeRange, InvoiceCountError};
 use util::timezone::offset_to_timezone;
 
-use crate::{standard_graphql_error::StandardGraphqlError, ContextExt};
+use crate::{
+    schema::queries::store_preference::current_store_preferences,
+    standard_graphql_error::StandardGraphqlError, ContextExt,
+};
 
 fn do_invoice_count(
     ctx: &Context<'_>,
@@ -132,7 +135,11 @@ impl InvoiceCounts {
     }
 }
 
-pub fn invoice_counts(timezone_offset: Option<i32>) -> Result<InvoiceCounts> {
+pub fn invoice_counts(ctx: &Context<'_>, timezone_offset: Option<i32>) -> Result<InvoiceCounts> {
+    let timezone_offset = match timezone_offset {
+        Some(timezone_offset) => Some(timezone_offset),
+        None => current_store_preferences(ctx)?.timezone_offset,
+    };
     let timezone_offset = offset_to_timezone(&timezone_offset).ok_or(
         StandardGraphqlError::BadUserInput("Invalid timezone offset".to_string()),
     )?;
@@ -1,14 +1,15 @@
 use async_graphql::*;
-use domain::{PaginationOption, SimpleStringFilter};
+use domain::{EqualFilter, PaginationOption, SimpleStringFilter};
 use repository::ItemFilter;
 use service::item::get_items;
 
 use crate::{
     schema::types::{
         sort_filter_types::{
-            convert_sort, EqualFilterBoolInput, SimpleStringFilterInput, SortInput,
+            convert_sort, EqualFilterBoolInput, EqualFilterInput, EqualFilterStringInput,
+            SimpleStringFilterInput, SortInput,
         },
-        ConnectorError, ItemNode, PaginationInput,
+        AbcClassNode, ConnectorError, ItemNode, PaginationInput, VenCategoryNode,
     },
     ContextExt,
 };
@@ -27,6 +28,14 @@ pub struct ItemFilterInput {
     pub name: Option<SimpleStringFilterInput>,
     pub code: Option<SimpleStringFilterInput>,
     pub is_visible: Option<EqualFilterBoolInput>,
+    /// Items of the category or any of its sub categories
+    pub category_id: Option<EqualFilterStringInput>,
+    pub strength: Option<SimpleStringFilterInput>,
+    pub dosage_form: Option<SimpleStringFilterInput>,
+    pub ven_category: Option<EqualFilterInput<VenCategoryNode>>,
+    pub is_controlled: Option<EqualFilterBoolInput>,
+    /// ABC class in the store of the query
+    pub abc_class: Option<EqualFilterInput<AbcClassNode>>,
 }
 
 impl From<ItemFilterInput> for ItemFilter {
@@ -37,6 +46,12 @@ impl From<ItemFilterInput> for ItemFilter {
             code: f.code.map(SimpleStringFilter::from),
             is_visible: f.is_visible.and_then(|filter| filter.equal_to),
             r#type: None,
+            category_id: f.category_id.map(EqualFilter::from),
+            strength: f.strength.map(SimpleStringFilter::from),
+            dosage_form: f.dosage_form.map(SimpleStringFilter::from),
+            ven_category: f.ven_category.map(EqualFilter::from),
+            is_controlled: f.is_controlled.and_then(|filter| filter.equal_to),
+            abc_class: f.abc_class.map(EqualFilter::from),
         }
     }
 }
@@ -55,6 +70,7 @@ pub enum ItemsResponse {
 
 pub fn items(
     ctx: &Context<'_>,
+    store_id: &str,
     page: Option<PaginationInput>,
     filter: Option<ItemFilterInput>,
     sort: Option<Vec<ItemSortInput>>,
@@ -62,6 +78,7 @@ pub fn items(
     let connection_manager = ctx.get_connection_manager();
     match get_items(
         connection_manager,
+        store_id,
         page.map(PaginationOption::from),
         filter.map(ItemFilter::from),
         convert_sort(sort),
@@ -0,0 +1,136 @@
+use async_graphql::*;
+use chrono::{DateTime, Utc};
+use domain::item::{AbcClass, VenCategory};
+use repository::schema::{ItemAbcClassRow, ItemCategoryRow, ItemPropertyRow};
+use service::permission_validation::{Resource, ResourceAccessRequest};
+
+use crate::{
+    schema::types::{AbcClassNode, VenCategoryNode},
+    standard_graphql_error::{validate_auth, StandardGraphqlError},
+    ContextExt,
+};
+
+pub struct ItemCategoryNode {
+    pub category: ItemCategoryRow,
+}
+
+#[Object]
+impl ItemCategoryNode {
+    pub async fn id(&self) -> &str {
+        &self.category.id
+    }
+
+    pub async fn name(&self) -> &str {
+        &self.category.name
+    }
+
+    /// Null for top level categories
+    pub async fn parent_id(&self) -> &Option<String> {
+        &self.category.parent_id
+    }
+}
+
+/// Properties of an item with the local overrides applied
+pub struct ItemPropertiesNode {
+    pub properties: ItemPropertyRow,
+}
+
+#[Object]
+impl ItemPropertiesNode {
+    pub async fn item_id(&self) -> &str {
+        &self.properties.item_id
+    }
+
+    pub async fn category_id(&self) -> &Option<String> {
+        &self.properties.category_id
+    }
+
+    pub async fn strength(&self) -> &Option<String> {
+        &self.properties.strength
+    }
+
+    pub async fn dosage_form(&self) -> &Option<String> {
+        &self.properties.dosage_form
+    }
+
+    pub async fn ven_category(&self) -> Option<VenCategoryNode> {
+        self.properties
+            .ven_category
+            .clone()
+            .map(|category| VenCategoryNode::from(VenCategory::from(category)))
+    }
+
+    pub async fn is_controlled(&self) -> bool {
+        self.properties.is_controlled
+    }
+
+    pub async fn storage_requirement(&self) -> &Option<String> {
+        &self.properties.storage_requirement
+    }
+}
+
+pub struct ItemAbcClassNode {
+    pub abc_class: ItemAbcClassRow,
+}
+
+#[Object]
+impl ItemAbcClassNode {
+    pub async fn item_id(&self) -> &str {
+        &self.abc_class.item_id
+    }
+
+    pub async fn class(&self) -> AbcClassNode {
+        AbcClassNode::from(AbcClass::from(self.abc_class.class.clone()))
+    }
+
+    /// Cost value of the stock issued in the period of the classification
+    pub async fn consumption_value(&self) -> f64 {
+        self.abc_class.consumption_value
+    }
+
+    pub async fn calculated_datetime(&self) -> DateTime<Utc> {
+        DateTime::<Utc>::from_utc(self.abc_class.calculated_datetime, Utc)
+    }
+}
+
+pub fn item_categories(ctx: &Context<'_>, store_id: &str) -> Result<Vec<ItemCategoryNode>> {
+    validate_auth(
+        ctx,
+        &ResourceAccessRequest {
+            resource: Resource::QueryItems,
+            store_id: Some(store_id.to_string()),
+        },
+    )?;
+
+    let service_provider = ctx.service_provider();
+    let service_ctx = service_provider.context()?;
+    let categories = service_provider
+        .item_property_service
+        .get_item_categories(&service_ctx)
+        .map_err(|err| StandardGraphqlError::from(err).extend())?;
+    Ok(categories
+        .into_iter()
+        .map(|category| ItemCategoryNode { category })
+        .collect())
+}
+
+pub fn abc_classification(ctx: &Context<'_>, store_id: &str) -> Result<Vec<ItemAbcClassNode>> {
+    validate_auth(
+        ctx,
+        &ResourceAccessRequest {
+            resource: Resource::QueryAbcClassification,
+            store_id: Some(store_id.to_string()),
+        },
+    )?;
+
+    let service_provider = ctx.service_provider();
+    let service_ctx = service_provider.context()?;
+    let rows = service_provider
+        .abc_classification_service
+        .get_abc_classification(&service_ctx, store_id)
+        .map_err(|err| StandardGraphqlError::from(err).extend())?;
+    Ok(rows
+        .into_iter()
+        .map(|abc_class| ItemAbcClassNode { abc_class })
+        .collect())
+}
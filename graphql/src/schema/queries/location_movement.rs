@@ -0,0 +1,91 @@
+use async_graphql::*;
+use chrono::{DateTime, Utc};
+use repository::schema::LocationMovementRow;
+use service::permission_validation::{Resource, ResourceAccessRequest};
+
+use crate::{
+    standard_graphql_error::{validate_auth, StandardGraphqlError},
+    ContextExt,
+};
+
+pub struct LocationMovementNode {
+    pub movement: LocationMovementRow,
+}
+
+#[Object]
+impl LocationMovementNode {
+    pub async fn id(&self) -> &str {
+        &self.movement.id
+    }
+
+    /// Stock line holding the moved packs
+    pub async fn stock_line_id(&self) -> &str {
+        &self.movement.stock_line_id
+    }
+
+    /// Set when the packs were split off another stock line
+    pub async fn split_from_stock_line_id(&self) -> &Option<String> {
+        &self.movement.split_from_stock_line_id
+    }
+
+    pub async fn from_location_id(&self) -> &Option<String> {
+        &self.movement.from_location_id
+    }
+
+    pub async fn to_location_id(&self) -> &str {
+        &self.movement.to_location_id
+    }
+
+    pub async fn number_of_packs(&self) -> i32 {
+        self.movement.number_of_packs
+    }
+
+    pub async fn moved_datetime(&self) -> DateTime<Utc> {
+        DateTime::<Utc>::from_utc(self.movement.moved_datetime, Utc)
+    }
+}
+
+impl From<LocationMovementRow> for LocationMovementNode {
+    fn from(movement: LocationMovementRow) -> Self {
+        LocationMovementNode { movement }
+    }
+}
+
+/// Either the stock line or the location must be provided
+pub fn location_movements(
+    ctx: &Context<'_>,
+    store_id: &str,
+    stock_line_id: Option<String>,
+    location_id: Option<String>,
+) -> Result<Vec<LocationMovementNode>> {
+    validate_auth(
+        ctx,
+        &ResourceAccessRequest {
+            resource: Resource::QueryLocationMovements,
+            store_id: Some(store_id.to_string()),
+        },
+    )?;
+
+    let service_provider = ctx.service_provider();
+    let service_ctx = service_provider.context()?;
+    let service = &service_provider.location_movement_service;
+    let movements = match (stock_line_id, location_id) {
+        (Some(stock_line_id), None) => {
+            service.get_stock_line_movements(&service_ctx, store_id, &stock_line_id)
+        }
+        (None, Some(location_id)) => {
+            service.get_location_movements(&service_ctx, store_id, &location_id)
+        }
+        _ => {
+            return Err(StandardGraphqlError::BadUserInput(
+                "Either stockLineId or locationId must be provided".to_string(),
+            )
+            .extend())
+        }
+    }
+    .map_err(|err| StandardGraphqlError::from(err).extend())?;
+    Ok(movements
+        .into_iter()
+        .map(LocationMovementNode::from)
+        .collect())
+}
@@ -1,9 +1,10 @@
 use crate::ContextExt;
 use domain::location::LocationFilter;
 use domain::{invoice::InvoiceFilter, PaginationOption};
-use service::invoice::get_invoices;
+use service::{current_store_id, invoice::get_invoices};
 
-use async_graphql::{Context, Object, Result};
+use async_graphql::{Context, Json, Object, Result};
+use chrono::{DateTime, Utc};
 
 use super::types::*;
 pub struct Queries;
@@ -26,8 +27,40 @@ pub mod item;
 pub use self::item::*;
 pub mod stock_counts;
 pub use self::stock_counts::*;
+pub mod stock_on_hand;
+pub use self::stock_on_hand::*;
+pub mod stock_movement;
+pub use self::stock_movement::*;
+pub mod stock_expiry;
+pub use self::stock_expiry::*;
+pub mod batch_recall;
+pub use self::batch_recall::*;
+pub mod stock_valuation;
+pub use self::stock_valuation::*;
+pub mod barcode;
+pub use self::barcode::*;
+pub mod pack_variant;
+pub use self::pack_variant::*;
+pub mod item_property;
+pub use self::item_property::*;
+pub mod plugin;
+pub use self::plugin::*;
+pub mod report;
+pub use self::report::*;
+pub mod return_reason;
+pub use self::return_reason::*;
+pub mod location_movement;
+pub use self::location_movement::*;
+pub mod patient;
+pub mod temperature_log;
+pub use self::patient::*;
+pub mod prescription;
+pub use self::prescription::*;
 use self::store::{stores, StoreFilterInput, StoresResponse};
+pub use self::temperature_log::*;
 pub mod store;
+pub mod store_preference;
+use self::store_preference::{store_preferences, StorePreferenceNode};
 
 #[Object]
 impl Queries {
@@ -73,6 +106,19 @@ impl Queries {
         names(ctx, page, filter, sort)
     }
 
+    /// Name tags (categories) ordered by name
+    pub async fn name_tags(
+        &self,
+        ctx: &Context<'_>,
+        store_id: Option<String>,
+    ) -> Result<Vec<NameTagNode>> {
+        // TODO remove and make store_id parameter required
+        let store_id = store_id.unwrap_or(current_store_id(
+            &ctx.get_connection_manager().connection()?,
+        )?);
+        name_tags(ctx, &store_id)
+    }
+
     pub async fn stores(
         &self,
         ctx: &Context<'_>,
@@ -124,12 +170,18 @@ impl Queries {
     pub async fn items(
         &self,
         ctx: &Context<'_>,
+        store_id: Option<String>,
         #[graphql(desc = "Pagination option (first and offset)")] page: Option<PaginationInput>,
         #[graphql(desc = "Filter option")] filter: Option<ItemFilterInput>,
         #[graphql(desc = "Sort options (only first sort input is evaluated for this endpoint)")]
         sort: Option<Vec<ItemSortInput>>,
-    ) -> ItemsResponse {
-        items(ctx, page, filter, sort)
+    ) -> Result<ItemsResponse> {
+        // TODO remove and make store_id parameter required
+        let store_id = match store_id {
+            Some(store_id) => store_id,
+            None => current_store_id(&ctx.get_connection_manager().connection()?)?,
+        };
+        Ok(items(ctx, &store_id, page, filter, sort))
     }
 
     pub async fn invoice(
@@ -163,16 +215,406 @@ impl Queries {
 
     pub async fn invoice_counts(
         &self,
-        #[graphql(desc = "Timezone offset")] timezone_offset: Option<i32>,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Timezone offset, defaults to the store preference")]
+        timezone_offset: Option<i32>,
     ) -> Result<InvoiceCounts> {
-        invoice_counts(timezone_offset)
+        invoice_counts(ctx, timezone_offset)
     }
 
     pub async fn stock_counts(
         &self,
-        #[graphql(desc = "Timezone offset")] timezone_offset: Option<i32>,
-        #[graphql(desc = "Expiring soon threshold")] days_till_expired: Option<i32>,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Timezone offset, defaults to the store preference")]
+        timezone_offset: Option<i32>,
+        #[graphql(desc = "Expiring soon threshold, defaults to the store preference")]
+        days_till_expired: Option<i32>,
     ) -> Result<StockCounts> {
-        stock_counts(timezone_offset, days_till_expired)
+        stock_counts(ctx, timezone_offset, days_till_expired)
+    }
+
+    pub async fn store_preferences(
+        &self,
+        ctx: &Context<'_>,
+        store_id: Option<String>,
+    ) -> Result<StorePreferenceNode> {
+        // TODO remove and make store_id parameter required
+        let store_id = store_id.unwrap_or(current_store_id(
+            &ctx.get_connection_manager().connection()?,
+        )?);
+        store_preferences(ctx, &store_id)
+    }
+
+    /// Stock on hand of the store aggregated per item
+    pub async fn stock_on_hand(
+        &self,
+        ctx: &Context<'_>,
+        store_id: Option<String>,
+        #[graphql(desc = "Aggregate per item and location instead of per item")]
+        by_location: Option<bool>,
+        #[graphql(desc = "Pagination option (first and offset)")] page: Option<PaginationInput>,
+        #[graphql(desc = "Filter option")] filter: Option<StockOnHandFilterInput>,
+        #[graphql(desc = "Sort options (only first sort input is evaluated for this endpoint)")]
+        sort: Option<Vec<StockOnHandSortInput>>,
+    ) -> Result<StockOnHandConnector> {
+        // TODO remove and make store_id parameter required
+        let store_id = store_id.unwrap_or(current_store_id(
+            &ctx.get_connection_manager().connection()?,
+        )?);
+        stock_on_hand(
+            ctx,
+            &store_id,
+            by_location.unwrap_or(false),
+            page,
+            filter,
+            sort,
+        )
+    }
+
+    /// Stock on hand of the store as it was at the given time
+    pub async fn historical_stock_on_hand(
+        &self,
+        ctx: &Context<'_>,
+        store_id: Option<String>,
+        datetime: DateTime<Utc>,
+        #[graphql(desc = "Defaults to ITEM")] group_by: Option<HistoricalStockOnHandGroupByInput>,
+        #[graphql(desc = "Filter option")] filter: Option<HistoricalStockOnHandFilterInput>,
+    ) -> Result<HistoricalStockOnHandConnector> {
+        // TODO remove and make store_id parameter required
+        let store_id = store_id.unwrap_or(current_store_id(
+            &ctx.get_connection_manager().connection()?,
+        )?);
+        historical_stock_on_hand(
+            ctx,
+            &store_id,
+            datetime,
+            group_by.unwrap_or(HistoricalStockOnHandGroupByInput::Item),
+            filter,
+        )
+    }
+
+    /// Expired and short dated stock grouped by item and location
+    pub async fn stock_expiry_report(
+        &self,
+        ctx: &Context<'_>,
+        store_id: Option<String>,
+        #[graphql(desc = "Defaults to the store preference")] timezone_offset: Option<i32>,
+        #[graphql(desc = "Defaults to the store preference")] days_till_expired: Option<i32>,
+        #[graphql(desc = "Filter option")] filter: Option<StockExpiryReportFilterInput>,
+    ) -> Result<StockExpiryReportConnector> {
+        // TODO remove and make store_id parameter required
+        let store_id = store_id.unwrap_or(current_store_id(
+            &ctx.get_connection_manager().connection()?,
+        )?);
+        stock_expiry_report(ctx, &store_id, timezone_offset, days_till_expired, filter)
+    }
+
+    /// Receipts, issues, customers and remaining stock of a batch
+    pub async fn batch_recall_trace(
+        &self,
+        ctx: &Context<'_>,
+        store_id: Option<String>,
+        selection: BatchRecallSelectionInput,
+    ) -> Result<BatchRecallTraceNode> {
+        // TODO remove and make store_id parameter required
+        let store_id = store_id.unwrap_or(current_store_id(
+            &ctx.get_connection_manager().connection()?,
+        )?);
+        batch_recall_trace(ctx, &store_id, selection)
+    }
+
+    /// Value of the stock on hand at cost, grouped by item or location
+    pub async fn stock_valuation(
+        &self,
+        ctx: &Context<'_>,
+        store_id: Option<String>,
+        #[graphql(desc = "Defaults to ITEM")] group_by: Option<StockValuationGroupByInput>,
+    ) -> Result<StockValuationConnector> {
+        // TODO remove and make store_id parameter required
+        let store_id = store_id.unwrap_or(current_store_id(
+            &ctx.get_connection_manager().connection()?,
+        )?);
+        stock_valuation(
+            ctx,
+            &store_id,
+            group_by.unwrap_or(StockValuationGroupByInput::Item),
+        )
+    }
+
+    /// Printable reports, render them with the `/reports/{id}/{recordId}` route
+    pub async fn reports(
+        &self,
+        ctx: &Context<'_>,
+        store_id: Option<String>,
+        #[graphql(desc = "Only reports for this type of record")] context: Option<
+            ReportContextType,
+        >,
+    ) -> Result<ReportConnector> {
+        // TODO remove and make store_id parameter required
+        let store_id = store_id.unwrap_or(current_store_id(
+            &ctx.get_connection_manager().connection()?,
+        )?);
+        reports(ctx, &store_id, context)
+    }
+
+    /// Looks up a scanned barcode or GS1 element string. The result prefills inbound shipment and
+    /// stock take lines.
+    pub async fn scan_barcode(
+        &self,
+        ctx: &Context<'_>,
+        store_id: Option<String>,
+        #[graphql(desc = "Scanned value, GS1 group separators are sent as ASCII 29")] value: String,
+    ) -> Result<ScannedBarcodeNode> {
+        // TODO remove and make store_id parameter required
+        let store_id = store_id.unwrap_or(current_store_id(
+            &ctx.get_connection_manager().connection()?,
+        )?);
+        scan_barcode(ctx, &store_id, &value)
+    }
+
+    /// Barcodes of an item
+    pub async fn barcodes(
+        &self,
+        ctx: &Context<'_>,
+        store_id: Option<String>,
+        item_id: String,
+    ) -> Result<Vec<BarcodeNode>> {
+        // TODO remove and make store_id parameter required
+        let store_id = store_id.unwrap_or(current_store_id(
+            &ctx.get_connection_manager().connection()?,
+        )?);
+        barcodes(ctx, &store_id, &item_id)
+    }
+
+    /// Pack variants of an item ordered by pack size
+    pub async fn pack_variants(
+        &self,
+        ctx: &Context<'_>,
+        store_id: Option<String>,
+        item_id: String,
+    ) -> Result<Vec<PackVariantNode>> {
+        // TODO remove and make store_id parameter required
+        let store_id = store_id.unwrap_or(current_store_id(
+            &ctx.get_connection_manager().connection()?,
+        )?);
+        pack_variants(ctx, &store_id, &item_id)
+    }
+
+    /// Item categories ordered by name
+    pub async fn item_categories(
+        &self,
+        ctx: &Context<'_>,
+        store_id: Option<String>,
+    ) -> Result<Vec<ItemCategoryNode>> {
+        // TODO remove and make store_id parameter required
+        let store_id = store_id.unwrap_or(current_store_id(
+            &ctx.get_connection_manager().connection()?,
+        )?);
+        item_categories(ctx, &store_id)
+    }
+
+    /// Last calculated ABC classification of the store, ordered by consumption value (descending)
+    pub async fn abc_classification(
+        &self,
+        ctx: &Context<'_>,
+        store_id: Option<String>,
+    ) -> Result<Vec<ItemAbcClassNode>> {
+        // TODO remove and make store_id parameter required
+        let store_id = store_id.unwrap_or(current_store_id(
+            &ctx.get_connection_manager().connection()?,
+        )?);
+        abc_classification(ctx, &store_id)
+    }
+
+    /// Active reasons for supplier and customer return lines, ordered by reason
+    pub async fn return_reasons(
+        &self,
+        ctx: &Context<'_>,
+        store_id: Option<String>,
+    ) -> Result<Vec<ReturnReasonNode>> {
+        // TODO remove and make store_id parameter required
+        let store_id = store_id.unwrap_or(current_store_id(
+            &ctx.get_connection_manager().connection()?,
+        )?);
+        return_reasons(ctx, &store_id)
+    }
+
+    /// Moves of a stock line (including packs split off it) or moves into and out of a location,
+    /// oldest first. Either stockLineId or locationId must be provided.
+    pub async fn location_movements(
+        &self,
+        ctx: &Context<'_>,
+        store_id: Option<String>,
+        stock_line_id: Option<String>,
+        location_id: Option<String>,
+    ) -> Result<Vec<LocationMovementNode>> {
+        // TODO remove and make store_id parameter required
+        let store_id = store_id.unwrap_or(current_store_id(
+            &ctx.get_connection_manager().connection()?,
+        )?);
+        location_movements(ctx, &store_id, stock_line_id, location_id)
+    }
+
+    /// Temperature readings of a location, oldest first
+    pub async fn temperature_logs(
+        &self,
+        ctx: &Context<'_>,
+        store_id: Option<String>,
+        location_id: String,
+        from_datetime: Option<DateTime<Utc>>,
+        to_datetime: Option<DateTime<Utc>>,
+    ) -> Result<Vec<TemperatureLogNode>> {
+        // TODO remove and make store_id parameter required
+        let store_id = store_id.unwrap_or(current_store_id(
+            &ctx.get_connection_manager().connection()?,
+        )?);
+        temperature_logs(ctx, &store_id, &location_id, from_datetime, to_datetime)
+    }
+
+    /// Temperature breaches of the store, most recent first
+    pub async fn temperature_breaches(
+        &self,
+        ctx: &Context<'_>,
+        store_id: Option<String>,
+        location_id: Option<String>,
+        #[graphql(desc = "Only breaches that haven't ended yet", default = false)]
+        current_only: bool,
+    ) -> Result<Vec<TemperatureBreachNode>> {
+        // TODO remove and make store_id parameter required
+        let store_id = store_id.unwrap_or(current_store_id(
+            &ctx.get_connection_manager().connection()?,
+        )?);
+        temperature_breaches(ctx, &store_id, location_id, current_only)
+    }
+
+    pub async fn temperature_breach_counts(
+        &self,
+        ctx: &Context<'_>,
+        store_id: Option<String>,
+        #[graphql(desc = "Period of the recent breach count, defaults to 7 days")]
+        recent_days: Option<u32>,
+    ) -> Result<TemperatureBreachCountsNode> {
+        // TODO remove and make store_id parameter required
+        let store_id = store_id.unwrap_or(current_store_id(
+            &ctx.get_connection_manager().connection()?,
+        )?);
+        temperature_breach_counts(ctx, &store_id, recent_days)
+    }
+
+    pub async fn temperature_breach_configs(
+        &self,
+        ctx: &Context<'_>,
+        store_id: Option<String>,
+        location_type_id: Option<String>,
+    ) -> Result<Vec<TemperatureBreachConfigNode>> {
+        // TODO remove and make store_id parameter required
+        let store_id = store_id.unwrap_or(current_store_id(
+            &ctx.get_connection_manager().connection()?,
+        )?);
+        temperature_breach_configs(ctx, &store_id, location_type_id)
+    }
+
+    /// Runs the report hook of a plugin, the result is the JSON output of the plugin
+    pub async fn plugin_report(
+        &self,
+        ctx: &Context<'_>,
+        store_id: Option<String>,
+        #[graphql(desc = "File name of the plugin without the .wasm extension")] plugin: String,
+        #[graphql(desc = "Passed to the plugin")] arguments: Option<Json<serde_json::Value>>,
+    ) -> Result<Json<serde_json::Value>> {
+        // TODO remove and make store_id parameter required
+        let store_id = store_id.unwrap_or(current_store_id(
+            &ctx.get_connection_manager().connection()?,
+        )?);
+        plugin_report(ctx, &store_id, &plugin, arguments)
+    }
+
+    /// Cost of the stock issued by outbound shipments and prescriptions in the period
+    pub async fn cost_of_goods_issued(
+        &self,
+        ctx: &Context<'_>,
+        store_id: Option<String>,
+        from_datetime: DateTime<Utc>,
+        to_datetime: DateTime<Utc>,
+        method: CostingMethodInput,
+        #[graphql(desc = "Filter option")] filter: Option<CostOfGoodsIssuedFilterInput>,
+    ) -> Result<CostOfGoodsIssuedNode> {
+        // TODO remove and make store_id parameter required
+        let store_id = store_id.unwrap_or(current_store_id(
+            &ctx.get_connection_manager().connection()?,
+        )?);
+        cost_of_goods_issued(ctx, &store_id, from_datetime, to_datetime, method, filter)
+    }
+
+    /// Stock card of an item, batch or location with a running balance
+    pub async fn stock_movements(
+        &self,
+        ctx: &Context<'_>,
+        store_id: Option<String>,
+        filter: StockMovementFilterInput,
+    ) -> Result<StockLedgerNode> {
+        // TODO remove and make store_id parameter required
+        let store_id = store_id.unwrap_or(current_store_id(
+            &ctx.get_connection_manager().connection()?,
+        )?);
+        stock_movements(ctx, &store_id, filter)
+    }
+
+    /// Patients of all stores, patients can only be edited in their home store
+    pub async fn patients(
+        &self,
+        ctx: &Context<'_>,
+        store_id: Option<String>,
+        #[graphql(desc = "Pagination option (first and offset)")] page: Option<PaginationInput>,
+        #[graphql(desc = "Filter option")] filter: Option<PatientFilterInput>,
+        #[graphql(desc = "Sort options (only first sort input is evaluated for this endpoint)")]
+        sort: Option<Vec<PatientSortInput>>,
+    ) -> Result<PatientConnector> {
+        // TODO remove and make store_id parameter required
+        let store_id = store_id.unwrap_or(current_store_id(
+            &ctx.get_connection_manager().connection()?,
+        )?);
+        patients(ctx, &store_id, page, filter, sort)
+    }
+
+    /// Prescribers ordered by last and first name
+    pub async fn prescribers(
+        &self,
+        ctx: &Context<'_>,
+        store_id: Option<String>,
+    ) -> Result<Vec<PrescriberNode>> {
+        // TODO remove and make store_id parameter required
+        let store_id = store_id.unwrap_or(current_store_id(
+            &ctx.get_connection_manager().connection()?,
+        )?);
+        prescribers(ctx, &store_id)
+    }
+
+    /// Picked and verified prescriptions of the patient from all stores, latest first
+    pub async fn patient_dispensing_history(
+        &self,
+        ctx: &Context<'_>,
+        store_id: Option<String>,
+        patient_id: String,
+    ) -> Result<Vec<InvoiceNode>> {
+        // TODO remove and make store_id parameter required
+        let store_id = store_id.unwrap_or(current_store_id(
+            &ctx.get_connection_manager().connection()?,
+        )?);
+        patient_dispensing_history(ctx, &store_id, &patient_id)
+    }
+
+    /// Label data of each line of a prescription, ordered by item name
+    pub async fn prescription_labels(
+        &self,
+        ctx: &Context<'_>,
+        store_id: Option<String>,
+        invoice_id: String,
+    ) -> Result<Vec<PrescriptionLabelNode>> {
+        // TODO remove and make store_id parameter required
+        let store_id = store_id.unwrap_or(current_store_id(
+            &ctx.get_connection_manager().connection()?,
+        )?);
+        prescription_labels(ctx, &store_id, &invoice_id)
     }
 }
@@ -1,13 +1,23 @@
 use async_graphql::*;
 use domain::PaginationOption;
-use domain::{name::NameFilter, SimpleStringFilter};
-use service::name::get_names;
+use domain::{name::NameFilter, EqualFilter, SimpleStringFilter};
+use repository::schema::NameTagRow;
+use service::{
+    name::get_names,
+    permission_validation::{Resource, ResourceAccessRequest},
+};
 
 use crate::schema::types::sort_filter_types::convert_sort;
-use crate::schema::types::{name::NameNode, PaginationInput};
+use crate::schema::types::{
+    name::{NameNode, NameNodeType},
+    PaginationInput,
+};
+use crate::standard_graphql_error::{validate_auth, StandardGraphqlError};
 use crate::ContextExt;
 
-use super::{ConnectorError, SimpleStringFilterInput, SortInput};
+use super::{
+    ConnectorError, EqualFilterInput, EqualFilterStringInput, SimpleStringFilterInput, SortInput,
+};
 
 #[derive(Enum, Copy, Clone, PartialEq, Eq)]
 #[graphql(remote = "domain::name::NameSortField")]
@@ -28,6 +38,11 @@ pub struct NameFilterInput {
     pub is_customer: Option<bool>,
     /// Filter by supplier property
     pub is_supplier: Option<bool>,
+    pub r#type: Option<EqualFilterInput<NameNodeType>>,
+    pub is_manufacturer: Option<bool>,
+    pub is_donor: Option<bool>,
+    /// Filter by tag (category) name
+    pub tag: Option<EqualFilterStringInput>,
 }
 
 impl From<NameFilterInput> for NameFilter {
@@ -36,8 +51,12 @@ impl From<NameFilterInput> for NameFilter {
             id: None,
             name: f.name.map(SimpleStringFilter::from),
             code: f.code.map(SimpleStringFilter::from),
+            r#type: f.r#type.map(EqualFilter::from),
             is_customer: f.is_customer,
             is_supplier: f.is_supplier,
+            is_manufacturer: f.is_manufacturer,
+            is_donor: f.is_donor,
+            tag: f.tag.map(EqualFilter::from),
         }
     }
 }
@@ -74,3 +93,36 @@ pub fn names(
         Err(error) => NamesResponse::Error(error.into()),
     }
 }
+
+pub struct NameTagNode {
+    pub tag: NameTagRow,
+}
+
+#[Object]
+impl NameTagNode {
+    pub async fn id(&self) -> &str {
+        &self.tag.id
+    }
+
+    pub async fn name(&self) -> &str {
+        &self.tag.name
+    }
+}
+
+pub fn name_tags(ctx: &Context<'_>, store_id: &str) -> Result<Vec<NameTagNode>> {
+    validate_auth(
+        ctx,
+        &ResourceAccessRequest {
+            resource: Resource::QueryNames,
+            store_id: Some(store_id.to_string()),
+        },
+    )?;
+
+    let service_provider = ctx.service_provider();
+    let service_ctx = service_provider.context()?;
+    let tags = service_provider
+        .name_service
+        .get_name_tags(&service_ctx)
+        .map_err(|err| StandardGraphqlError::from(err).extend())?;
+    Ok(tags.into_iter().map(|tag| NameTagNode { tag }).collect())
+}
@@ -0,0 +1,106 @@
+use async_graphql::dataloader::DataLoader;
+use async_graphql::*;
+use repository::schema::PackVariantRow;
+use service::permission_validation::{Resource, ResourceAccessRequest};
+
+use crate::{
+    loader::PackVariantByItemIdLoader,
+    standard_graphql_error::{validate_auth, StandardGraphqlError},
+    ContextExt,
+};
+
+pub struct PackVariantNode {
+    pub pack_variant: PackVariantRow,
+}
+
+#[Object]
+impl PackVariantNode {
+    pub async fn id(&self) -> &str {
+        &self.pack_variant.id
+    }
+
+    pub async fn item_id(&self) -> &str {
+        &self.pack_variant.item_id
+    }
+
+    pub async fn name(&self) -> &str {
+        &self.pack_variant.name
+    }
+
+    /// Number of units of the item in one pack
+    pub async fn pack_size(&self) -> u32 {
+        self.pack_variant.pack_size as u32
+    }
+
+    /// Unit of the pack itself, e.g. bottle or box
+    pub async fn unit_id(&self) -> &Option<String> {
+        &self.pack_variant.unit_id
+    }
+
+    /// Volume of one pack in litres
+    pub async fn volume_per_pack(&self) -> Option<f64> {
+        self.pack_variant.volume_per_pack
+    }
+
+    /// Weight of one pack in kilograms
+    pub async fn weight_per_pack(&self) -> Option<f64> {
+        self.pack_variant.weight_per_pack
+    }
+}
+
+impl From<PackVariantRow> for PackVariantNode {
+    fn from(pack_variant: PackVariantRow) -> Self {
+        PackVariantNode { pack_variant }
+    }
+}
+
+pub fn pack_variants(
+    ctx: &Context<'_>,
+    store_id: &str,
+    item_id: &str,
+) -> Result<Vec<PackVariantNode>> {
+    validate_auth(
+        ctx,
+        &ResourceAccessRequest {
+            resource: Resource::QueryItems,
+            store_id: Some(store_id.to_string()),
+        },
+    )?;
+
+    let service_provider = ctx.service_provider();
+    let service_ctx = service_provider.context()?;
+    let pack_variants = service_provider
+        .pack_variant_service
+        .get_item_pack_variants(&service_ctx, item_id)
+        .map_err(|err| StandardGraphqlError::from(err).extend())?;
+    Ok(pack_variants
+        .into_iter()
+        .map(PackVariantNode::from)
+        .collect())
+}
+
+/// Pack variants of the item, ordered by pack size
+pub async fn load_pack_variants(ctx: &Context<'_>, item_id: &str) -> Result<Vec<PackVariantNode>> {
+    let loader = ctx.get_loader::<DataLoader<PackVariantByItemIdLoader>>();
+    let pack_variants = loader
+        .load_one(item_id.to_string())
+        .await
+        .map_err(|error| StandardGraphqlError::from(error).extend())?
+        .unwrap_or_default();
+    Ok(pack_variants
+        .into_iter()
+        .map(PackVariantNode::from)
+        .collect())
+}
+
+/// Pack variant of the item with the pack size of a line
+pub async fn load_pack_variant(
+    ctx: &Context<'_>,
+    item_id: &str,
+    pack_size: i32,
+) -> Result<Option<PackVariantNode>> {
+    Ok(load_pack_variants(ctx, item_id)
+        .await?
+        .into_iter()
+        .find(|node| node.pack_variant.pack_size == pack_size))
+}
@@ -0,0 +1,206 @@
+use async_graphql::*;
+use chrono::NaiveDate;
+use domain::{
+    patient::{Patient, PatientFilter},
+    DateFilter, EqualFilter, PaginationOption, SimpleStringFilter,
+};
+use repository::schema::PrescriberRow;
+use service::{
+    permission_validation::{Resource, ResourceAccessRequest},
+    ListError,
+};
+
+use crate::{
+    schema::types::{
+        sort_filter_types::convert_sort, DateFilterInput, EqualFilterStringInput, PaginationInput,
+        SimpleStringFilterInput, SortInput,
+    },
+    standard_graphql_error::{validate_auth, StandardGraphqlError},
+    ContextExt,
+};
+
+#[derive(Enum, Copy, Clone, PartialEq, Eq)]
+#[graphql(remote = "domain::patient::PatientSortField")]
+#[graphql(rename_items = "camelCase")]
+pub enum PatientSortFieldInput {
+    Name,
+    Code,
+    DateOfBirth,
+}
+pub type PatientSortInput = SortInput<PatientSortFieldInput>;
+
+#[derive(InputObject, Clone)]
+pub struct PatientFilterInput {
+    pub id: Option<EqualFilterStringInput>,
+    /// Filter by full name, e.g. "Doe, Jane"
+    pub name: Option<SimpleStringFilterInput>,
+    pub code: Option<SimpleStringFilterInput>,
+    pub first_name: Option<SimpleStringFilterInput>,
+    pub last_name: Option<SimpleStringFilterInput>,
+    pub date_of_birth: Option<DateFilterInput>,
+    pub phone: Option<SimpleStringFilterInput>,
+    pub home_store_id: Option<EqualFilterStringInput>,
+}
+
+impl From<PatientFilterInput> for PatientFilter {
+    fn from(f: PatientFilterInput) -> Self {
+        PatientFilter {
+            id: f.id.map(EqualFilter::from),
+            name: f.name.map(SimpleStringFilter::from),
+            code: f.code.map(SimpleStringFilter::from),
+            first_name: f.first_name.map(SimpleStringFilter::from),
+            last_name: f.last_name.map(SimpleStringFilter::from),
+            date_of_birth: f.date_of_birth.map(DateFilter::from),
+            phone: f.phone.map(SimpleStringFilter::from),
+            home_store_id: f.home_store_id.map(EqualFilter::from),
+        }
+    }
+}
+
+pub struct PatientNode {
+    pub patient: Patient,
+}
+
+#[Object]
+impl PatientNode {
+    pub async fn id(&self) -> &str {
+        &self.patient.id
+    }
+
+    /// Full name, e.g. "Doe, Jane"
+    pub async fn name(&self) -> &str {
+        &self.patient.name
+    }
+
+    pub async fn code(&self) -> &str {
+        &self.patient.code
+    }
+
+    pub async fn first_name(&self) -> &Option<String> {
+        &self.patient.first_name
+    }
+
+    pub async fn last_name(&self) -> &Option<String> {
+        &self.patient.last_name
+    }
+
+    pub async fn date_of_birth(&self) -> &Option<NaiveDate> {
+        &self.patient.date_of_birth
+    }
+
+    pub async fn phone(&self) -> &Option<String> {
+        &self.patient.phone
+    }
+
+    /// Store the patient was registered in, patients can only be edited in their home store
+    pub async fn home_store_id(&self) -> &Option<String> {
+        &self.patient.home_store_id
+    }
+}
+
+impl From<Patient> for PatientNode {
+    fn from(patient: Patient) -> Self {
+        PatientNode { patient }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct PatientConnector {
+    total_count: u32,
+    nodes: Vec<PatientNode>,
+}
+
+pub struct PrescriberNode {
+    pub prescriber: PrescriberRow,
+}
+
+#[Object]
+impl PrescriberNode {
+    pub async fn id(&self) -> &str {
+        &self.prescriber.id
+    }
+
+    pub async fn code(&self) -> &str {
+        &self.prescriber.code
+    }
+
+    pub async fn first_name(&self) -> &Option<String> {
+        &self.prescriber.first_name
+    }
+
+    pub async fn last_name(&self) -> &str {
+        &self.prescriber.last_name
+    }
+
+    /// Registration with the medical council
+    pub async fn registration_code(&self) -> &Option<String> {
+        &self.prescriber.registration_code
+    }
+}
+
+impl From<PrescriberRow> for PrescriberNode {
+    fn from(prescriber: PrescriberRow) -> Self {
+        PrescriberNode { prescriber }
+    }
+}
+
+fn map_list_error(err: ListError) -> Error {
+    let formatted_error = format!("{:#?}", err);
+    let graphql_error = match err {
+        ListError::DatabaseError(err) => err.into(),
+        ListError::LimitBelowMin(_) | ListError::LimitAboveMax(_) => {
+            StandardGraphqlError::BadUserInput(formatted_error)
+        }
+    };
+    graphql_error.extend()
+}
+
+pub fn patients(
+    ctx: &Context<'_>,
+    store_id: &str,
+    page: Option<PaginationInput>,
+    filter: Option<PatientFilterInput>,
+    sort: Option<Vec<PatientSortInput>>,
+) -> Result<PatientConnector> {
+    validate_auth(
+        ctx,
+        &ResourceAccessRequest {
+            resource: Resource::QueryPatients,
+            store_id: Some(store_id.to_string()),
+        },
+    )?;
+
+    let service_provider = ctx.service_provider();
+    let service_ctx = service_provider.context()?;
+    let patients = service_provider
+        .patient_service
+        .get_patients(
+            &service_ctx,
+            page.map(PaginationOption::from),
+            filter.map(PatientFilter::from),
+            convert_sort(sort),
+        )
+        .map_err(map_list_error)?;
+    Ok(PatientConnector {
+        total_count: patients.count,
+        nodes: patients.rows.into_iter().map(PatientNode::from).collect(),
+    })
+}
+
+pub fn prescribers(ctx: &Context<'_>, store_id: &str) -> Result<Vec<PrescriberNode>> {
+    validate_auth(
+        ctx,
+        &ResourceAccessRequest {
+            resource: Resource::QueryPatients,
+            store_id: Some(store_id.to_string()),
+        },
+    )?;
+
+    let service_provider = ctx.service_provider();
+    let service_ctx = service_provider.context()?;
+    let prescribers = service_provider
+        .patient_service
+        .get_prescribers(&service_ctx)
+        .map_err(|err| StandardGraphqlError::from(err).extend())?;
+    Ok(prescribers.into_iter().map(PrescriberNode::from).collect())
+}
@@ -0,0 +1,49 @@
+use async_graphql::*;
+use service::{
+    permission_validation::{Resource, ResourceAccessRequest},
+    plugin::PluginError,
+};
+
+use crate::{
+    standard_graphql_error::{validate_auth, StandardGraphqlError},
+    ContextExt,
+};
+
+pub fn plugin_report(
+    ctx: &Context<'_>,
+    store_id: &str,
+    plugin: &str,
+    arguments: Option<Json<serde_json::Value>>,
+) -> Result<Json<serde_json::Value>> {
+    validate_auth(
+        ctx,
+        &ResourceAccessRequest {
+            resource: Resource::RunPluginReport,
+            store_id: Some(store_id.to_string()),
+        },
+    )?;
+
+    let service_provider = ctx.service_provider();
+    let service_ctx = service_provider.context()?;
+    let arguments = arguments.map(|arguments| arguments.0).unwrap_or_default();
+    match service_ctx
+        .plugins
+        .report(&service_ctx.connection, store_id, plugin, arguments)
+    {
+        Ok(result) => Ok(Json(result)),
+        Err(error) => {
+            let formatted_error = format!("{:?}", error);
+            let graphql_error = match error {
+                PluginError::PluginDoesNotExist(_) | PluginError::HookNotImplemented { .. } => {
+                    StandardGraphqlError::BadUserInput(formatted_error)
+                }
+                PluginError::IoError(_)
+                | PluginError::InvalidModule { .. }
+                | PluginError::ExecutionError { .. } => {
+                    StandardGraphqlError::InternalError(formatted_error)
+                }
+            };
+            Err(graphql_error.extend())
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use async_graphql::*;
+use chrono::{NaiveDate, NaiveDateTime};
+use service::{
+    permission_validation::{Resource, ResourceAccessRequest},
+    prescription::label::{PrescriptionLabel, PrescriptionLabelError},
+};
+
+use crate::{
+    schema::types::InvoiceNode,
+    standard_graphql_error::{validate_auth, StandardGraphqlError},
+    ContextExt,
+};
+
+pub struct PrescriptionLabelNode {
+    pub label: PrescriptionLabel,
+}
+
+#[Object]
+impl PrescriptionLabelNode {
+    pub async fn invoice_line_id(&self) -> &str {
+        &self.label.invoice_line_id
+    }
+
+    pub async fn store_name(&self) -> &str {
+        &self.label.store_name
+    }
+
+    pub async fn patient_name(&self) -> &str {
+        &self.label.patient_name
+    }
+
+    pub async fn patient_date_of_birth(&self) -> &Option<NaiveDate> {
+        &self.label.patient_date_of_birth
+    }
+
+    pub async fn prescriber_name(&self) -> &Option<String> {
+        &self.label.prescriber_name
+    }
+
+    pub async fn prescription_number(&self) -> i64 {
+        self.label.prescription_number
+    }
+
+    /// Picked datetime of the prescription, created datetime if not picked yet
+    pub async fn dispensed_datetime(&self) -> NaiveDateTime {
+        self.label.dispensed_datetime
+    }
+
+    pub async fn item_name(&self) -> &str {
+        &self.label.item_name
+    }
+
+    pub async fn item_code(&self) -> &str {
+        &self.label.item_code
+    }
+
+    /// Number of packs times the pack size
+    pub async fn number_of_units(&self) -> i64 {
+        self.label.number_of_units
+    }
+
+    pub async fn directions(&self) -> &Option<String> {
+        &self.label.directions
+    }
+
+    pub async fn batch(&self) -> &Option<String> {
+        &self.label.batch
+    }
+
+    pub async fn expiry_date(&self) -> &Option<NaiveDate> {
+        &self.label.expiry_date
+    }
+}
+
+pub fn patient_dispensing_history(
+    ctx: &Context<'_>,
+    store_id: &str,
+    patient_id: &str,
+) -> Result<Vec<InvoiceNode>> {
+    validate_auth(
+        ctx,
+        &ResourceAccessRequest {
+            resource: Resource::QueryPatients,
+            store_id: Some(store_id.to_string()),
+        },
+    )?;
+
+    let service_provider = ctx.service_provider();
+    let service_ctx = service_provider.context()?;
+    let prescriptions = service_provider
+        .prescription_service
+        .get_patient_dispensing_history(&service_ctx, patient_id)
+        .map_err(|err| StandardGraphqlError::from(err).extend())?;
+    Ok(prescriptions.into_iter().map(InvoiceNode::from).collect())
+}
+
+pub fn prescription_labels(
+    ctx: &Context<'_>,
+    store_id: &str,
+    invoice_id: &str,
+) -> Result<Vec<PrescriptionLabelNode>> {
+    validate_auth(
+        ctx,
+        &ResourceAccessRequest {
+            resource: Resource::QueryInvoices,
+            store_id: Some(store_id.to_string()),
+        },
+    )?;
+
+    let service_provider = ctx.service_provider();
+    let service_ctx = service_provider.context()?;
+    let labels = service_provider
+        .prescription_service
+        .get_prescription_labels(&service_ctx, store_id, invoice_id)
+        .map_err(|err| {
+            let formatted_error = format!("{:#?}", err);
+            let graphql_error = match err {
+                PrescriptionLabelError::DatabaseError(err) => err.into(),
+                PrescriptionLabelError::InvoiceDoesNotExist
+                | PrescriptionLabelError::NotAPrescription
+                | PrescriptionLabelError::NotThisStoreInvoice => {
+                    StandardGraphqlError::BadUserInput(formatted_error)
+                }
+            };
+            graphql_error.extend()
+        })?;
+    Ok(labels
+        .into_iter()
+        .map(|label| PrescriptionLabelNode { label })
+        .collect())
+}
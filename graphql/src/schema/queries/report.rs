@@ -0,0 +1,81 @@
+use async_graphql::*;
+use repository::schema::{ReportContext, ReportRow};
+use service::permission_validation::{Resource, ResourceAccessRequest};
+
+use crate::{standard_graphql_error::validate_auth, ContextExt};
+
+#[derive(Enum, Copy, Clone, PartialEq, Eq)]
+pub enum ReportContextType {
+    OutboundShipment,
+    InboundShipment,
+    StockTake,
+}
+
+impl ReportContextType {
+    pub fn to_domain(self) -> ReportContext {
+        match self {
+            ReportContextType::OutboundShipment => ReportContext::OutboundShipment,
+            ReportContextType::InboundShipment => ReportContext::InboundShipment,
+            ReportContextType::StockTake => ReportContext::StockTake,
+        }
+    }
+
+    pub fn from_domain(context: &ReportContext) -> ReportContextType {
+        match context {
+            ReportContext::OutboundShipment => ReportContextType::OutboundShipment,
+            ReportContext::InboundShipment => ReportContextType::InboundShipment,
+            ReportContext::StockTake => ReportContextType::StockTake,
+        }
+    }
+}
+
+pub struct ReportNode {
+    row: ReportRow,
+}
+
+#[Object]
+impl ReportNode {
+    /// Template of the `/reports/{id}/{recordId}` route
+    pub async fn id(&self) -> &str {
+        &self.row.id
+    }
+
+    pub async fn name(&self) -> &str {
+        &self.row.name
+    }
+
+    pub async fn context(&self) -> ReportContextType {
+        ReportContextType::from_domain(&self.row.context)
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct ReportConnector {
+    total_count: u32,
+    nodes: Vec<ReportNode>,
+}
+
+pub fn reports(
+    ctx: &Context<'_>,
+    store_id: &str,
+    context: Option<ReportContextType>,
+) -> Result<ReportConnector> {
+    validate_auth(
+        ctx,
+        &ResourceAccessRequest {
+            resource: Resource::QueryReports,
+            store_id: Some(store_id.to_string()),
+        },
+    )?;
+
+    let service_provider = ctx.service_provider();
+    let service_ctx = service_provider.context()?;
+    let rows = service_provider
+        .report_service
+        .get_reports(&service_ctx, context.map(ReportContextType::to_domain))?;
+
+    Ok(ReportConnector {
+        total_count: rows.len() as u32,
+        nodes: rows.into_iter().map(|row| ReportNode { row }).collect(),
+    })
+}
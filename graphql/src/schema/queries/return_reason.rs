@@ -0,0 +1,44 @@
+use async_graphql::*;
+use repository::schema::ReasonRow;
+use service::permission_validation::{Resource, ResourceAccessRequest};
+
+use crate::{
+    standard_graphql_error::{validate_auth, StandardGraphqlError},
+    ContextExt,
+};
+
+pub struct ReturnReasonNode {
+    pub reason: ReasonRow,
+}
+
+#[Object]
+impl ReturnReasonNode {
+    pub async fn id(&self) -> &str {
+        &self.reason.id
+    }
+
+    pub async fn reason(&self) -> &str {
+        &self.reason.reason
+    }
+}
+
+pub fn return_reasons(ctx: &Context<'_>, store_id: &str) -> Result<Vec<ReturnReasonNode>> {
+    validate_auth(
+        ctx,
+        &ResourceAccessRequest {
+            resource: Resource::QueryInvoices,
+            store_id: Some(store_id.to_string()),
+        },
+    )?;
+
+    let service_provider = ctx.service_provider();
+    let service_ctx = service_provider.context()?;
+    let reasons = service_provider
+        .return_service
+        .get_return_reasons(&service_ctx)
+        .map_err(|err| StandardGraphqlError::from(err).extend())?;
+    Ok(reasons
+        .into_iter()
+        .map(|reason| ReturnReasonNode { reason })
+        .collect())
+}
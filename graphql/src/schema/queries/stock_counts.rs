@@ -2,11 +2,14 @@ use async_graphql::*;
 use chrono::{Duration, FixedOffset, Utc};
 use util::timezone::offset_to_timezone;
 
-use crate::{standard_graphql_error::StandardGraphqlError, ContextExt};
+use crate::{
+    schema::queries::store_preference::current_store_preferences,
+    standard_graphql_error::StandardGraphqlError, ContextExt,
+};
 
 pub struct StockCounts {
     timezone_offset: FixedOffset,
-    days_till_expired: Option<i32>,
+    days_till_expired: i32,
 }
 
 #[Object]
@@ -26,20 +29,23 @@ impl StockCounts {
         let service_provider = ctx.service_provider();
         let service_ctx = service_provider.context()?;
         let service = &service_provider.stock_expiry_count_service;
-        let days_till_expired = self.days_till_expired.unwrap_or(7);
         let date = Utc::now()
             .with_timezone(&self.timezone_offset)
             .date()
             .naive_utc()
-            + Duration::days(days_till_expired as i64);
+            + Duration::days(self.days_till_expired as i64);
         Ok(service.count_expired_stock(&service_ctx, date)?)
     }
 }
 
 pub fn stock_counts(
+    ctx: &Context<'_>,
     timezone_offset: Option<i32>,
     days_till_expired: Option<i32>,
 ) -> Result<StockCounts> {
+    let preferences = current_store_preferences(ctx)?;
+    let timezone_offset = timezone_offset.or(preferences.timezone_offset);
+    let days_till_expired = days_till_expired.unwrap_or(preferences.expiring_soon_days);
     let timezone_offset = offset_to_timezone(&timezone_offset).ok_or(
         StandardGraphqlError::BadUserInput("Invalid timezone offset".to_string()),
     )?;
@@ -0,0 +1,151 @@
+use async_graphql::{dataloader::DataLoader, *};
+use chrono::{NaiveDate, Utc};
+use domain::EqualFilter;
+use service::{
+    permission_validation::{Resource, ResourceAccessRequest},
+    stock_expiry::report::{StockExpiryReportInput, StockExpiryReportRow},
+};
+use util::timezone::offset_to_timezone;
+
+use crate::{
+    loader::ItemLoader,
+    schema::types::{sort_filter_types::EqualFilterStringInput, ItemNode, StockLineNode},
+    standard_graphql_error::{validate_auth, StandardGraphqlError},
+    ContextExt,
+};
+
+#[derive(InputObject, Clone)]
+pub struct StockExpiryReportFilterInput {
+    pub item_id: Option<EqualFilterStringInput>,
+    /// Only include items of the master list
+    pub master_list_id: Option<String>,
+}
+
+pub struct StockExpiryReportNode {
+    row: StockExpiryReportRow,
+}
+
+#[Object]
+impl StockExpiryReportNode {
+    pub async fn item_id(&self) -> &str {
+        &self.row.item_id
+    }
+
+    pub async fn item(&self, ctx: &Context<'_>) -> Result<ItemNode> {
+        let loader = ctx.get_loader::<DataLoader<ItemLoader>>();
+        let item = loader.load_one(self.row.item_id.clone()).await?.ok_or(
+            StandardGraphqlError::InternalError(format!(
+                "Cannot find item_id {}",
+                self.row.item_id
+            ))
+            .extend(),
+        )?;
+        Ok(ItemNode::from(item))
+    }
+
+    pub async fn location_id(&self) -> &Option<String> {
+        &self.row.location_id
+    }
+
+    pub async fn location_name(&self) -> &Option<String> {
+        &self.row.location_name
+    }
+
+    pub async fn earliest_expiry_date(&self) -> NaiveDate {
+        self.row.earliest_expiry_date
+    }
+
+    pub async fn total_number_of_packs(&self) -> i64 {
+        self.row.total_number_of_packs
+    }
+
+    /// Number of units (number of packs * pack size)
+    pub async fn total_units(&self) -> i64 {
+        self.row.total_units
+    }
+
+    /// Units that are already expired
+    pub async fn expired_units(&self) -> i64 {
+        self.row.expired_units
+    }
+
+    /// Value of the stock at cost price
+    pub async fn cost_value(&self) -> f64 {
+        self.row.total_cost_value
+    }
+
+    /// Value of the stock at sell price
+    pub async fn sell_value(&self) -> f64 {
+        self.row.total_sell_value
+    }
+
+    /// Batches of the group, earliest expiry first
+    pub async fn stock_lines(&self) -> Vec<StockLineNode> {
+        self.row
+            .stock_lines
+            .iter()
+            .cloned()
+            .map(StockLineNode::from)
+            .collect()
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct StockExpiryReportConnector {
+    total_count: u32,
+    nodes: Vec<StockExpiryReportNode>,
+}
+
+pub fn stock_expiry_report(
+    ctx: &Context<'_>,
+    store_id: &str,
+    timezone_offset: Option<i32>,
+    days_till_expired: Option<i32>,
+    filter: Option<StockExpiryReportFilterInput>,
+) -> Result<StockExpiryReportConnector> {
+    validate_auth(
+        ctx,
+        &ResourceAccessRequest {
+            resource: Resource::QueryStockExpiryReport,
+            store_id: Some(store_id.to_string()),
+        },
+    )?;
+
+    let service_provider = ctx.service_provider();
+    let service_ctx = service_provider.context()?;
+    let preferences = service_provider
+        .preference_service
+        .get_store_preferences(&service_ctx, store_id)?;
+    let timezone_offset = offset_to_timezone(&timezone_offset.or(preferences.timezone_offset))
+        .ok_or(StandardGraphqlError::BadUserInput(
+            "Invalid timezone offset".to_string(),
+        ))?;
+    let (item_id, master_list_id) = match filter {
+        Some(filter) => (filter.item_id.map(EqualFilter::from), filter.master_list_id),
+        None => (None, None),
+    };
+
+    let rows = service_provider
+        .stock_expiry_service
+        .get_stock_expiry_report(
+            &service_ctx,
+            store_id,
+            StockExpiryReportInput {
+                today: Utc::now()
+                    .with_timezone(&timezone_offset)
+                    .date()
+                    .naive_local(),
+                days_till_expired: days_till_expired.unwrap_or(preferences.expiring_soon_days),
+                master_list_id,
+                item_id,
+            },
+        )?;
+
+    Ok(StockExpiryReportConnector {
+        total_count: rows.len() as u32,
+        nodes: rows
+            .into_iter()
+            .map(|row| StockExpiryReportNode { row })
+            .collect(),
+    })
+}
@@ -0,0 +1,170 @@
+use async_graphql::*;
+use chrono::{DateTime, Utc};
+use domain::invoice::InvoiceType;
+use service::{
+    permission_validation::{Resource, ResourceAccessRequest},
+    stock_movement::{StockLedger, StockLedgerError, StockLedgerInput, StockLedgerLine},
+};
+
+use crate::{
+    schema::types::InvoiceNodeType,
+    standard_graphql_error::{validate_auth, StandardGraphqlError},
+    ContextExt,
+};
+
+/// At least one of item, stock line or location must be provided
+#[derive(InputObject, Clone)]
+pub struct StockMovementFilterInput {
+    pub item_id: Option<String>,
+    /// Stock movements of a single batch
+    pub stock_line_id: Option<String>,
+    pub location_id: Option<String>,
+    pub from_datetime: Option<DateTime<Utc>>,
+    pub to_datetime: Option<DateTime<Utc>>,
+}
+
+pub struct StockMovementNode {
+    line: StockLedgerLine,
+}
+
+#[Object]
+impl StockMovementNode {
+    /// Id of the invoice line
+    pub async fn id(&self) -> &str {
+        &self.line.movement.id
+    }
+
+    /// Time the stock changed, e.g. when an outbound shipment was picked
+    pub async fn datetime(&self) -> DateTime<Utc> {
+        DateTime::<Utc>::from_utc(self.line.movement.datetime, Utc)
+    }
+
+    pub async fn invoice_id(&self) -> &str {
+        &self.line.movement.invoice_id
+    }
+
+    pub async fn invoice_type(&self) -> InvoiceNodeType {
+        InvoiceType::from(self.line.movement.invoice_type.clone()).into()
+    }
+
+    pub async fn invoice_number(&self) -> i64 {
+        self.line.movement.invoice_number
+    }
+
+    pub async fn other_party_id(&self) -> &str {
+        &self.line.movement.other_party_id
+    }
+
+    pub async fn other_party_name(&self) -> &str {
+        &self.line.movement.other_party_name
+    }
+
+    pub async fn item_id(&self) -> &str {
+        &self.line.movement.item_id
+    }
+
+    pub async fn stock_line_id(&self) -> &Option<String> {
+        &self.line.movement.stock_line_id
+    }
+
+    pub async fn batch(&self) -> &Option<String> {
+        &self.line.movement.batch
+    }
+
+    pub async fn location_id(&self) -> &Option<String> {
+        &self.line.movement.location_id
+    }
+
+    pub async fn pack_size(&self) -> i32 {
+        self.line.movement.pack_size
+    }
+
+    /// Number of packs, negative if stock left the store
+    pub async fn number_of_packs(&self) -> i32 {
+        self.line.movement.number_of_packs
+    }
+
+    /// Units received
+    pub async fn quantity_in(&self) -> i64 {
+        self.line.quantity_in
+    }
+
+    /// Units issued
+    pub async fn quantity_out(&self) -> i64 {
+        self.line.quantity_out
+    }
+
+    /// Units on hand after this movement
+    pub async fn balance(&self) -> i64 {
+        self.line.balance
+    }
+}
+
+pub struct StockLedgerNode {
+    ledger: StockLedger,
+}
+
+#[Object]
+impl StockLedgerNode {
+    /// Units on hand before the first movement
+    pub async fn opening_balance(&self) -> i64 {
+        self.ledger.opening_balance
+    }
+
+    pub async fn closing_balance(&self) -> i64 {
+        self.ledger.closing_balance
+    }
+
+    /// Movements in chronological order
+    pub async fn nodes(&self) -> Vec<StockMovementNode> {
+        self.ledger
+            .lines
+            .iter()
+            .cloned()
+            .map(|line| StockMovementNode { line })
+            .collect()
+    }
+}
+
+pub fn stock_movements(
+    ctx: &Context<'_>,
+    store_id: &str,
+    filter: StockMovementFilterInput,
+) -> Result<StockLedgerNode> {
+    validate_auth(
+        ctx,
+        &ResourceAccessRequest {
+            resource: Resource::QueryStockMovements,
+            store_id: Some(store_id.to_string()),
+        },
+    )?;
+
+    let service_provider = ctx.service_provider();
+    let service_ctx = service_provider.context()?;
+    match service_provider.stock_movement_service.get_stock_ledger(
+        &service_ctx,
+        store_id,
+        StockLedgerInput {
+            item_id: filter.item_id,
+            stock_line_id: filter.stock_line_id,
+            location_id: filter.location_id,
+            from_datetime: filter.from_datetime.map(|datetime| datetime.naive_utc()),
+            to_datetime: filter.to_datetime.map(|datetime| datetime.naive_utc()),
+        },
+    ) {
+        Ok(ledger) => Ok(StockLedgerNode { ledger }),
+        Err(err) => Err(map_error(err)),
+    }
+}
+
+fn map_error(err: StockLedgerError) -> async_graphql::Error {
+    let formatted_error = format!("{:#?}", err);
+    let graphql_error = match err {
+        StockLedgerError::DatabaseError(err) => err.into(),
+        StockLedgerError::MissingItemBatchOrLocation => {
+            StandardGraphqlError::BadUserInput(formatted_error)
+        }
+        StockLedgerError::InvalidDateRange => StandardGraphqlError::BadUserInput(formatted_error),
+    };
+    graphql_error.extend()
+}
@@ -0,0 +1,281 @@
+use async_graphql::*;
+use chrono::{DateTime, NaiveDate, Utc};
+use domain::{DateFilter, EqualFilter, PaginationOption, SimpleStringFilter};
+use repository::{StockOnHand, StockOnHandFilter, StockOnHandSortField};
+use service::{
+    permission_validation::{Resource, ResourceAccessRequest},
+    stock_on_hand::historical::{HistoricalStockOnHand, HistoricalStockOnHandGroupBy},
+};
+
+use crate::{
+    schema::types::{
+        sort_filter_types::{
+            convert_sort, DateFilterInput, EqualFilterStringInput, SimpleStringFilterInput,
+            SortInput,
+        },
+        PaginationInput,
+    },
+    standard_graphql_error::{list_error_to_gql_err, validate_auth},
+    ContextExt,
+};
+
+#[derive(Enum, Copy, Clone, PartialEq, Eq)]
+#[graphql(rename_items = "camelCase")]
+pub enum StockOnHandSortFieldInput {
+    ItemName,
+    ItemCode,
+    TotalNumberOfPacks,
+    TotalUnits,
+    AvailableUnits,
+    EarliestExpiryDate,
+    StockValue,
+}
+
+impl From<StockOnHandSortFieldInput> for StockOnHandSortField {
+    fn from(field: StockOnHandSortFieldInput) -> Self {
+        match field {
+            StockOnHandSortFieldInput::ItemName => StockOnHandSortField::ItemName,
+            StockOnHandSortFieldInput::ItemCode => StockOnHandSortField::ItemCode,
+            StockOnHandSortFieldInput::TotalNumberOfPacks => {
+                StockOnHandSortField::TotalNumberOfPacks
+            }
+            StockOnHandSortFieldInput::TotalUnits => StockOnHandSortField::TotalUnits,
+            StockOnHandSortFieldInput::AvailableUnits => StockOnHandSortField::AvailableUnits,
+            StockOnHandSortFieldInput::EarliestExpiryDate => {
+                StockOnHandSortField::EarliestExpiryDate
+            }
+            StockOnHandSortFieldInput::StockValue => StockOnHandSortField::TotalCostValue,
+        }
+    }
+}
+
+pub type StockOnHandSortInput = SortInput<StockOnHandSortFieldInput>;
+
+#[derive(InputObject, Clone)]
+pub struct StockOnHandFilterInput {
+    pub item_id: Option<EqualFilterStringInput>,
+    /// Only applied if stock on hand is grouped by location
+    pub location_id: Option<EqualFilterStringInput>,
+    pub item_name: Option<SimpleStringFilterInput>,
+    pub item_code: Option<SimpleStringFilterInput>,
+    pub earliest_expiry_date: Option<DateFilterInput>,
+}
+
+impl From<StockOnHandFilterInput> for StockOnHandFilter {
+    fn from(f: StockOnHandFilterInput) -> Self {
+        StockOnHandFilter {
+            store_id: None,
+            item_id: f.item_id.map(EqualFilter::from),
+            location_id: f.location_id.map(EqualFilter::from),
+            item_name: f.item_name.map(SimpleStringFilter::from),
+            item_code: f.item_code.map(SimpleStringFilter::from),
+            earliest_expiry_date: f.earliest_expiry_date.map(DateFilter::from),
+        }
+    }
+}
+
+pub struct StockOnHandNode {
+    stock_on_hand: StockOnHand,
+}
+
+#[Object]
+impl StockOnHandNode {
+    pub async fn store_id(&self) -> &str {
+        &self.stock_on_hand.store_id
+    }
+
+    pub async fn item_id(&self) -> &str {
+        &self.stock_on_hand.item_id
+    }
+
+    pub async fn item_name(&self) -> &str {
+        &self.stock_on_hand.item_name
+    }
+
+    pub async fn item_code(&self) -> &str {
+        &self.stock_on_hand.item_code
+    }
+
+    /// Only set if stock on hand is grouped by location
+    pub async fn location_id(&self) -> &Option<String> {
+        &self.stock_on_hand.location_id
+    }
+
+    pub async fn total_number_of_packs(&self) -> i64 {
+        self.stock_on_hand.total_number_of_packs
+    }
+
+    pub async fn available_number_of_packs(&self) -> i64 {
+        self.stock_on_hand.available_number_of_packs
+    }
+
+    /// Stock on hand in units (number of packs * pack size)
+    pub async fn total_units(&self) -> i64 {
+        self.stock_on_hand.total_units
+    }
+
+    pub async fn available_units(&self) -> i64 {
+        self.stock_on_hand.available_units
+    }
+
+    pub async fn number_of_batches(&self) -> i64 {
+        self.stock_on_hand.number_of_batches
+    }
+
+    pub async fn earliest_expiry_date(&self) -> &Option<NaiveDate> {
+        &self.stock_on_hand.earliest_expiry_date
+    }
+
+    /// Value of the stock on hand at cost price
+    pub async fn stock_value(&self) -> f64 {
+        self.stock_on_hand.total_cost_value
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct StockOnHandConnector {
+    total_count: u32,
+    nodes: Vec<StockOnHandNode>,
+}
+
+pub fn stock_on_hand(
+    ctx: &Context<'_>,
+    store_id: &str,
+    by_location: bool,
+    page: Option<PaginationInput>,
+    filter: Option<StockOnHandFilterInput>,
+    sort: Option<Vec<StockOnHandSortInput>>,
+) -> Result<StockOnHandConnector> {
+    validate_auth(
+        ctx,
+        &ResourceAccessRequest {
+            resource: Resource::QueryStockOnHand,
+            store_id: Some(store_id.to_string()),
+        },
+    )?;
+
+    let service_provider = ctx.service_provider();
+    let service_ctx = service_provider.context()?;
+    let result = service_provider
+        .stock_on_hand_service
+        .get_stock_on_hand(
+            &service_ctx,
+            store_id,
+            by_location,
+            page.map(PaginationOption::from),
+            filter.map(StockOnHandFilter::from),
+            convert_sort(sort),
+        )
+        .map_err(list_error_to_gql_err)?;
+
+    Ok(StockOnHandConnector {
+        total_count: result.count,
+        nodes: result
+            .rows
+            .into_iter()
+            .map(|stock_on_hand| StockOnHandNode { stock_on_hand })
+            .collect(),
+    })
+}
+
+#[derive(Enum, Copy, Clone, PartialEq, Eq)]
+#[graphql(rename_items = "camelCase")]
+pub enum HistoricalStockOnHandGroupByInput {
+    Item,
+    /// Per stock line
+    Batch,
+    Location,
+}
+
+impl From<HistoricalStockOnHandGroupByInput> for HistoricalStockOnHandGroupBy {
+    fn from(group_by: HistoricalStockOnHandGroupByInput) -> Self {
+        match group_by {
+            HistoricalStockOnHandGroupByInput::Item => HistoricalStockOnHandGroupBy::Item,
+            HistoricalStockOnHandGroupByInput::Batch => HistoricalStockOnHandGroupBy::Batch,
+            HistoricalStockOnHandGroupByInput::Location => HistoricalStockOnHandGroupBy::Location,
+        }
+    }
+}
+
+#[derive(InputObject, Clone)]
+pub struct HistoricalStockOnHandFilterInput {
+    pub item_id: Option<EqualFilterStringInput>,
+}
+
+pub struct HistoricalStockOnHandNode {
+    stock_on_hand: HistoricalStockOnHand,
+}
+
+#[Object]
+impl HistoricalStockOnHandNode {
+    pub async fn item_id(&self) -> &str {
+        &self.stock_on_hand.item_id
+    }
+
+    /// Only set if grouped by batch
+    pub async fn stock_line_id(&self) -> &Option<String> {
+        &self.stock_on_hand.stock_line_id
+    }
+
+    /// Only set if grouped by batch
+    pub async fn batch(&self) -> &Option<String> {
+        &self.stock_on_hand.batch
+    }
+
+    /// Only set if grouped by batch
+    pub async fn expiry_date(&self) -> &Option<NaiveDate> {
+        &self.stock_on_hand.expiry_date
+    }
+
+    /// Current location of the stock, only set if grouped by batch or location
+    pub async fn location_id(&self) -> &Option<String> {
+        &self.stock_on_hand.location_id
+    }
+
+    /// Stock on hand in units (number of packs * pack size)
+    pub async fn total_units(&self) -> i64 {
+        self.stock_on_hand.total_units
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct HistoricalStockOnHandConnector {
+    total_count: u32,
+    nodes: Vec<HistoricalStockOnHandNode>,
+}
+
+pub fn historical_stock_on_hand(
+    ctx: &Context<'_>,
+    store_id: &str,
+    datetime: DateTime<Utc>,
+    group_by: HistoricalStockOnHandGroupByInput,
+    filter: Option<HistoricalStockOnHandFilterInput>,
+) -> Result<HistoricalStockOnHandConnector> {
+    validate_auth(
+        ctx,
+        &ResourceAccessRequest {
+            resource: Resource::QueryStockOnHand,
+            store_id: Some(store_id.to_string()),
+        },
+    )?;
+
+    let service_provider = ctx.service_provider();
+    let service_ctx = service_provider.context()?;
+    let rows = service_provider
+        .stock_on_hand_service
+        .get_historical_stock_on_hand(
+            &service_ctx,
+            store_id,
+            datetime.naive_utc(),
+            group_by.into(),
+            filter.and_then(|filter| filter.item_id.map(EqualFilter::from)),
+        )?;
+
+    Ok(HistoricalStockOnHandConnector {
+        total_count: rows.len() as u32,
+        nodes: rows
+            .into_iter()
+            .map(|stock_on_hand| HistoricalStockOnHandNode { stock_on_hand })
+            .collect(),
+    })
+}
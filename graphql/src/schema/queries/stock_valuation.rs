@@ -0,0 +1,217 @@
+use async_graphql::*;
+use chrono::{DateTime, Utc};
+use domain::EqualFilter;
+use service::{
+    permission_validation::{Resource, ResourceAccessRequest},
+    stock_valuation::{
+        cost_of_goods::{
+            CostOfGoodsIssued, CostOfGoodsIssuedError, CostOfGoodsIssuedInput, CostingMethod,
+            ItemCostOfGoodsIssued,
+        },
+        valuation::{StockValuation, StockValuationGroupBy, StockValuationReport},
+    },
+};
+
+use crate::{
+    schema::types::sort_filter_types::EqualFilterStringInput,
+    standard_graphql_error::{validate_auth, StandardGraphqlError},
+    ContextExt,
+};
+
+#[derive(Enum, Copy, Clone, PartialEq, Eq)]
+#[graphql(rename_items = "camelCase")]
+pub enum StockValuationGroupByInput {
+    Item,
+    Location,
+}
+
+impl From<StockValuationGroupByInput> for StockValuationGroupBy {
+    fn from(group_by: StockValuationGroupByInput) -> Self {
+        match group_by {
+            StockValuationGroupByInput::Item => StockValuationGroupBy::Item,
+            StockValuationGroupByInput::Location => StockValuationGroupBy::Location,
+        }
+    }
+}
+
+pub struct StockValuationNode {
+    valuation: StockValuation,
+}
+
+#[Object]
+impl StockValuationNode {
+    /// Only set if grouped by item
+    pub async fn item_id(&self) -> &Option<String> {
+        &self.valuation.item_id
+    }
+
+    /// Only set if grouped by location, null for stock without a location
+    pub async fn location_id(&self) -> &Option<String> {
+        &self.valuation.location_id
+    }
+
+    /// Stock on hand in units (number of packs * pack size)
+    pub async fn total_units(&self) -> i64 {
+        self.valuation.total_units
+    }
+
+    /// Stock on hand at the cost price of each batch
+    pub async fn total_cost_value(&self) -> f64 {
+        self.valuation.total_cost_value
+    }
+}
+
+pub struct StockValuationConnector {
+    report: StockValuationReport,
+}
+
+#[Object]
+impl StockValuationConnector {
+    pub async fn total_count(&self) -> u32 {
+        self.report.rows.len() as u32
+    }
+
+    pub async fn total_cost_value(&self) -> f64 {
+        self.report.total_cost_value
+    }
+
+    pub async fn nodes(&self) -> Vec<StockValuationNode> {
+        self.report
+            .rows
+            .iter()
+            .cloned()
+            .map(|valuation| StockValuationNode { valuation })
+            .collect()
+    }
+}
+
+pub fn stock_valuation(
+    ctx: &Context<'_>,
+    store_id: &str,
+    group_by: StockValuationGroupByInput,
+) -> Result<StockValuationConnector> {
+    validate_auth(
+        ctx,
+        &ResourceAccessRequest {
+            resource: Resource::QueryStockValuation,
+            store_id: Some(store_id.to_string()),
+        },
+    )?;
+
+    let service_provider = ctx.service_provider();
+    let service_ctx = service_provider.context()?;
+    let report = service_provider
+        .stock_valuation_service
+        .get_stock_valuation(&service_ctx, store_id, group_by.into())?;
+
+    Ok(StockValuationConnector { report })
+}
+
+#[derive(Enum, Copy, Clone, PartialEq, Eq)]
+#[graphql(rename_items = "camelCase")]
+pub enum CostingMethodInput {
+    /// Cost price of the issued batch
+    BatchActualCost,
+    /// First in, first out
+    Fifo,
+    WeightedAverage,
+}
+
+impl From<CostingMethodInput> for CostingMethod {
+    fn from(method: CostingMethodInput) -> Self {
+        match method {
+            CostingMethodInput::BatchActualCost => CostingMethod::BatchActualCost,
+            CostingMethodInput::Fifo => CostingMethod::Fifo,
+            CostingMethodInput::WeightedAverage => CostingMethod::WeightedAverage,
+        }
+    }
+}
+
+#[derive(InputObject, Clone)]
+pub struct CostOfGoodsIssuedFilterInput {
+    pub item_id: Option<EqualFilterStringInput>,
+}
+
+pub struct ItemCostOfGoodsIssuedNode {
+    item: ItemCostOfGoodsIssued,
+}
+
+#[Object]
+impl ItemCostOfGoodsIssuedNode {
+    pub async fn item_id(&self) -> &str {
+        &self.item.item_id
+    }
+
+    /// Units issued in the period (number of packs * pack size)
+    pub async fn units_issued(&self) -> i64 {
+        self.item.units_issued
+    }
+
+    pub async fn cost(&self) -> f64 {
+        self.item.cost
+    }
+}
+
+pub struct CostOfGoodsIssuedNode {
+    cost_of_goods: CostOfGoodsIssued,
+}
+
+#[Object]
+impl CostOfGoodsIssuedNode {
+    pub async fn total_cost(&self) -> f64 {
+        self.cost_of_goods.total_cost
+    }
+
+    pub async fn items(&self) -> Vec<ItemCostOfGoodsIssuedNode> {
+        self.cost_of_goods
+            .items
+            .iter()
+            .cloned()
+            .map(|item| ItemCostOfGoodsIssuedNode { item })
+            .collect()
+    }
+}
+
+pub fn cost_of_goods_issued(
+    ctx: &Context<'_>,
+    store_id: &str,
+    from_datetime: DateTime<Utc>,
+    to_datetime: DateTime<Utc>,
+    method: CostingMethodInput,
+    filter: Option<CostOfGoodsIssuedFilterInput>,
+) -> Result<CostOfGoodsIssuedNode> {
+    validate_auth(
+        ctx,
+        &ResourceAccessRequest {
+            resource: Resource::QueryStockValuation,
+            store_id: Some(store_id.to_string()),
+        },
+    )?;
+
+    let service_provider = ctx.service_provider();
+    let service_ctx = service_provider.context()?;
+    match service_provider
+        .stock_valuation_service
+        .get_cost_of_goods_issued(
+            &service_ctx,
+            store_id,
+            CostOfGoodsIssuedInput {
+                from_datetime: from_datetime.naive_utc(),
+                to_datetime: to_datetime.naive_utc(),
+                method: method.into(),
+                item_id: filter.and_then(|filter| filter.item_id.map(EqualFilter::from)),
+            },
+        ) {
+        Ok(cost_of_goods) => Ok(CostOfGoodsIssuedNode { cost_of_goods }),
+        Err(err) => {
+            let formatted_error = format!("{:#?}", err);
+            let graphql_error = match err {
+                CostOfGoodsIssuedError::DatabaseError(err) => err.into(),
+                CostOfGoodsIssuedError::InvalidDateRange => {
+                    StandardGraphqlError::BadUserInput(formatted_error)
+                }
+            };
+            Err(graphql_error.extend())
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use async_graphql::*;
+use service::{
+    current_store_id,
+    permission_validation::{Resource, ResourceAccessRequest},
+    preference::StorePreferences,
+};
+
+use crate::{standard_graphql_error::validate_auth, ContextExt};
+
+pub struct StorePreferenceNode {
+    pub preferences: StorePreferences,
+}
+
+#[Object]
+impl StorePreferenceNode {
+    pub async fn store_id(&self) -> &str {
+        &self.preferences.store_id
+    }
+
+    /// Received stock is stored with a pack size of one
+    pub async fn pack_to_one(&self) -> bool {
+        self.preferences.pack_to_one
+    }
+
+    pub async fn months_overstock(&self) -> f64 {
+        self.preferences.months_overstock
+    }
+
+    pub async fn months_understock(&self) -> f64 {
+        self.preferences.months_understock
+    }
+
+    pub async fn months_items_expire(&self) -> f64 {
+        self.preferences.months_items_expire
+    }
+
+    /// Outbound shipments can allocate more than the available stock
+    pub async fn allow_negative_stock(&self) -> bool {
+        self.preferences.allow_negative_stock
+    }
+
+    /// Default timezone offset in hours, null if the timezone of the server is used
+    pub async fn timezone_offset(&self) -> Option<i32> {
+        self.preferences.timezone_offset
+    }
+
+    pub async fn months_of_stock_target(&self) -> f64 {
+        self.preferences.months_of_stock_target
+    }
+
+    /// Adjusted stock take lines require a comment before the stock take can be finalised
+    pub async fn requires_reason_on_adjustment(&self) -> bool {
+        self.preferences.requires_reason_on_adjustment
+    }
+
+    /// Stock expiring within this number of days is counted as expiring soon
+    pub async fn expiring_soon_days(&self) -> i32 {
+        self.preferences.expiring_soon_days
+    }
+}
+
+pub fn store_preferences(ctx: &Context<'_>, store_id: &str) -> Result<StorePreferenceNode> {
+    validate_auth(
+        ctx,
+        &ResourceAccessRequest {
+            resource: Resource::QueryStorePreferences,
+            store_id: Some(store_id.to_string()),
+        },
+    )?;
+
+    let service_provider = ctx.service_provider();
+    let service_ctx = service_provider.context()?;
+    let preferences = service_provider
+        .preference_service
+        .get_store_preferences(&service_ctx, store_id)?;
+    Ok(StorePreferenceNode { preferences })
+}
+
+/// Preferences of the current store, used to default request parameters
+pub fn current_store_preferences(ctx: &Context<'_>) -> Result<StorePreferences> {
+    let service_provider = ctx.service_provider();
+    let service_ctx = service_provider.context()?;
+    let store_id = current_store_id(&service_ctx.connection)?;
+    Ok(service_provider
+        .preference_service
+        .get_store_preferences(&service_ctx, &store_id)?)
+}
@@ -0,0 +1,286 @@
+use async_graphql::*;
+use chrono::{DateTime, Duration, Utc};
+use repository::schema::{TemperatureBreachConfigRow, TemperatureBreachRow, TemperatureLogRow};
+use service::{
+    permission_validation::{Resource, ResourceAccessRequest},
+    SingleRecordError,
+};
+
+use crate::{
+    schema::types::{LocationNode, StockLineNode},
+    standard_graphql_error::{validate_auth, StandardGraphqlError},
+    ContextExt,
+};
+
+/// Default period of the recent breach count
+const RECENT_BREACH_DAYS: i64 = 7;
+
+pub struct TemperatureLogNode {
+    pub log: TemperatureLogRow,
+}
+
+#[Object]
+impl TemperatureLogNode {
+    pub async fn id(&self) -> &str {
+        &self.log.id
+    }
+
+    pub async fn location_id(&self) -> &str {
+        &self.log.location_id
+    }
+
+    /// Degrees Celsius
+    pub async fn temperature(&self) -> f64 {
+        self.log.temperature
+    }
+
+    pub async fn datetime(&self) -> DateTime<Utc> {
+        DateTime::<Utc>::from_utc(self.log.datetime, Utc)
+    }
+}
+
+impl From<TemperatureLogRow> for TemperatureLogNode {
+    fn from(log: TemperatureLogRow) -> Self {
+        TemperatureLogNode { log }
+    }
+}
+
+pub struct TemperatureBreachConfigNode {
+    pub config: TemperatureBreachConfigRow,
+}
+
+#[Object]
+impl TemperatureBreachConfigNode {
+    pub async fn id(&self) -> &str {
+        &self.config.id
+    }
+
+    pub async fn location_type_id(&self) -> &str {
+        &self.config.location_type_id
+    }
+
+    pub async fn description(&self) -> &str {
+        &self.config.description
+    }
+
+    /// Readings below the minimum are out of range
+    pub async fn minimum_temperature(&self) -> Option<f64> {
+        self.config.minimum_temperature
+    }
+
+    /// Readings above the maximum are out of range
+    pub async fn maximum_temperature(&self) -> Option<f64> {
+        self.config.maximum_temperature
+    }
+
+    /// Readings must be out of range for at least this long to count as a breach
+    pub async fn duration_minutes(&self) -> i32 {
+        self.config.duration_minutes
+    }
+
+    pub async fn is_active(&self) -> bool {
+        self.config.is_active
+    }
+}
+
+impl From<TemperatureBreachConfigRow> for TemperatureBreachConfigNode {
+    fn from(config: TemperatureBreachConfigRow) -> Self {
+        TemperatureBreachConfigNode { config }
+    }
+}
+
+pub struct TemperatureBreachNode {
+    pub breach: TemperatureBreachRow,
+}
+
+#[Object]
+impl TemperatureBreachNode {
+    pub async fn id(&self) -> &str {
+        &self.breach.id
+    }
+
+    pub async fn location_id(&self) -> &str {
+        &self.breach.location_id
+    }
+
+    pub async fn location(&self, ctx: &Context<'_>) -> Result<Option<LocationNode>> {
+        let service_provider = ctx.service_provider();
+        let service_ctx = service_provider.context()?;
+        match service_provider
+            .location_service
+            .get_location(&service_ctx, self.breach.location_id.clone())
+        {
+            Ok(location) => Ok(Some(LocationNode::from(location))),
+            // the location has been deleted
+            Err(SingleRecordError::NotFound(_)) => Ok(None),
+            Err(SingleRecordError::DatabaseError(error)) => {
+                Err(StandardGraphqlError::from(error).extend())
+            }
+        }
+    }
+
+    pub async fn temperature_breach_config_id(&self) -> &str {
+        &self.breach.temperature_breach_config_id
+    }
+
+    pub async fn temperature_breach_config(
+        &self,
+        ctx: &Context<'_>,
+    ) -> Result<TemperatureBreachConfigNode> {
+        let service_provider = ctx.service_provider();
+        let service_ctx = service_provider.context()?;
+        let config = service_provider
+            .temperature_log_service
+            .get_temperature_breach_config(&service_ctx, &self.breach.temperature_breach_config_id)
+            .map_err(|error| match error {
+                SingleRecordError::DatabaseError(error) => StandardGraphqlError::from(error),
+                SingleRecordError::NotFound(id) => StandardGraphqlError::InternalError(format!(
+                    "Missing temperature breach config {}",
+                    id
+                )),
+            })
+            .map_err(|error| error.extend())?;
+        Ok(TemperatureBreachConfigNode::from(config))
+    }
+
+    /// First out of range reading
+    pub async fn start_datetime(&self) -> DateTime<Utc> {
+        DateTime::<Utc>::from_utc(self.breach.start_datetime, Utc)
+    }
+
+    /// First reading back in range, null while the breach is ongoing
+    pub async fn end_datetime(&self) -> Option<DateTime<Utc>> {
+        self.breach
+            .end_datetime
+            .map(|datetime| DateTime::<Utc>::from_utc(datetime, Utc))
+    }
+
+    /// Until the end or, for ongoing breaches, until the last reading
+    pub async fn duration_minutes(&self) -> i32 {
+        self.breach.duration_minutes
+    }
+
+    pub async fn is_current(&self) -> bool {
+        self.breach.end_datetime.is_none()
+    }
+
+    /// Stock lines that were in the location while the breach lasted
+    pub async fn stock_lines(&self, ctx: &Context<'_>) -> Result<Vec<StockLineNode>> {
+        let service_provider = ctx.service_provider();
+        let service_ctx = service_provider.context()?;
+        let stock_lines = service_provider
+            .temperature_log_service
+            .get_temperature_breach_stock_lines(&service_ctx, &self.breach)
+            .map_err(|error| StandardGraphqlError::from(error).extend())?;
+        Ok(stock_lines.into_iter().map(StockLineNode::from).collect())
+    }
+}
+
+impl From<TemperatureBreachRow> for TemperatureBreachNode {
+    fn from(breach: TemperatureBreachRow) -> Self {
+        TemperatureBreachNode { breach }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct TemperatureBreachCountsNode {
+    /// Breaches that haven't ended yet
+    pub current: i64,
+    /// Breaches that started in the recent days
+    pub recent: i64,
+}
+
+fn validate_query_auth(ctx: &Context<'_>, store_id: &str) -> Result<()> {
+    validate_auth(
+        ctx,
+        &ResourceAccessRequest {
+            resource: Resource::QueryTemperatureLogs,
+            store_id: Some(store_id.to_string()),
+        },
+    )?;
+    Ok(())
+}
+
+pub fn temperature_logs(
+    ctx: &Context<'_>,
+    store_id: &str,
+    location_id: &str,
+    from_datetime: Option<DateTime<Utc>>,
+    to_datetime: Option<DateTime<Utc>>,
+) -> Result<Vec<TemperatureLogNode>> {
+    validate_query_auth(ctx, store_id)?;
+
+    let service_provider = ctx.service_provider();
+    let service_ctx = service_provider.context()?;
+    let logs = service_provider
+        .temperature_log_service
+        .get_temperature_logs(
+            &service_ctx,
+            store_id,
+            location_id,
+            from_datetime.map(|datetime| datetime.naive_utc()),
+            to_datetime.map(|datetime| datetime.naive_utc()),
+        )
+        .map_err(|error| StandardGraphqlError::from(error).extend())?;
+    Ok(logs.into_iter().map(TemperatureLogNode::from).collect())
+}
+
+pub fn temperature_breaches(
+    ctx: &Context<'_>,
+    store_id: &str,
+    location_id: Option<String>,
+    current_only: bool,
+) -> Result<Vec<TemperatureBreachNode>> {
+    validate_query_auth(ctx, store_id)?;
+
+    let service_provider = ctx.service_provider();
+    let service_ctx = service_provider.context()?;
+    let breaches = service_provider
+        .temperature_log_service
+        .get_temperature_breaches(&service_ctx, store_id, location_id.as_deref(), current_only)
+        .map_err(|error| StandardGraphqlError::from(error).extend())?;
+    Ok(breaches
+        .into_iter()
+        .map(TemperatureBreachNode::from)
+        .collect())
+}
+
+pub fn temperature_breach_counts(
+    ctx: &Context<'_>,
+    store_id: &str,
+    recent_days: Option<u32>,
+) -> Result<TemperatureBreachCountsNode> {
+    validate_query_auth(ctx, store_id)?;
+
+    let service_provider = ctx.service_provider();
+    let service_ctx = service_provider.context()?;
+    let since = Utc::now().naive_utc()
+        - Duration::days(recent_days.map(i64::from).unwrap_or(RECENT_BREACH_DAYS));
+    let counts = service_provider
+        .temperature_log_service
+        .count_temperature_breaches(&service_ctx, store_id, since)
+        .map_err(|error| StandardGraphqlError::from(error).extend())?;
+    Ok(TemperatureBreachCountsNode {
+        current: counts.current,
+        recent: counts.recent,
+    })
+}
+
+pub fn temperature_breach_configs(
+    ctx: &Context<'_>,
+    store_id: &str,
+    location_type_id: Option<String>,
+) -> Result<Vec<TemperatureBreachConfigNode>> {
+    validate_query_auth(ctx, store_id)?;
+
+    let service_provider = ctx.service_provider();
+    let service_ctx = service_provider.context()?;
+    let configs = service_provider
+        .temperature_log_service
+        .get_temperature_breach_configs(&service_ctx, location_type_id.as_deref())
+        .map_err(|error| StandardGraphqlError::from(error).extend())?;
+    Ok(configs
+        .into_iter()
+        .map(TemperatureBreachConfigNode::from)
+        .collect())
+}
@@ -4,6 +4,7 @@ use super::{
 };
 use crate::{
     loader::{ItemLoader, LocationByIdLoader, StockLineByIdLoader},
+    schema::queries::pack_variant::{load_pack_variant, PackVariantNode},
     ContextExt,
 };
 use async_graphql::*;
@@ -11,10 +12,11 @@ use chrono::NaiveDate;
 use dataloader::DataLoader;
 use domain::invoice_line::{InvoiceLine, InvoiceLineType};
 use repository::StorageConnectionManager;
+use schemars::JsonSchema;
 use serde::Serialize;
-use service::invoice_line::get_invoice_line;
+use service::{invoice_line::get_invoice_line, pack_variant::number_of_units};
 
-#[derive(Enum, Copy, Clone, PartialEq, Eq, Debug, Serialize)]
+#[derive(Enum, Copy, Clone, PartialEq, Eq, Debug, Serialize, JsonSchema)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")] // only needed to be comparable in tests
 pub enum InvoiceLineNodeType {
     StockIn,
@@ -77,6 +79,17 @@ impl InvoiceLineNode {
     pub async fn number_of_packs(&self) -> i32 {
         self.invoice_line.number_of_packs
     }
+    /// Number of units (number of packs * pack size)
+    pub async fn number_of_units(&self) -> i64 {
+        number_of_units(
+            self.invoice_line.number_of_packs,
+            self.invoice_line.pack_size,
+        )
+    }
+    /// Pack variant of the item with the pack size of the line
+    pub async fn pack_variant(&self, ctx: &Context<'_>) -> Result<Option<PackVariantNode>> {
+        load_pack_variant(ctx, &self.invoice_line.item_id, self.invoice_line.pack_size).await
+    }
     pub async fn cost_price_per_pack(&self) -> f64 {
         self.invoice_line.cost_price_per_pack
     }
@@ -92,6 +105,14 @@ impl InvoiceLineNode {
     pub async fn note(&self) -> &Option<String> {
         &self.invoice_line.note
     }
+    /// Reason of a return line
+    pub async fn return_reason_id(&self) -> &Option<String> {
+        &self.invoice_line.return_reason_id
+    }
+    /// Dosage directions of a prescription line
+    pub async fn directions(&self) -> &Option<String> {
+        &self.invoice_line.directions
+    }
     pub async fn location_name(&self) -> &Option<String> {
         &self.invoice_line.location_name
     }
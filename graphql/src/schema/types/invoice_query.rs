@@ -10,6 +10,7 @@ use domain::{
     DatetimeFilter, EqualFilter, SimpleStringFilter,
 };
 use repository::{schema::InvoiceStatsRow, StorageConnectionManager};
+use schemars::JsonSchema;
 use serde::Serialize;
 use service::invoice::get_invoice;
 
@@ -76,16 +77,21 @@ impl From<InvoiceFilterInput> for InvoiceFilter {
     }
 }
 
-#[derive(Enum, Copy, Clone, PartialEq, Eq, Debug, Serialize)]
+#[derive(Enum, Copy, Clone, PartialEq, Eq, Debug, Serialize, JsonSchema)]
 #[graphql(remote = "domain::invoice::InvoiceType")]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum InvoiceNodeType {
     OutboundShipment,
     InboundShipment,
     InventoryAdjustment,
+    SupplierReturn,
+    CustomerReturn,
+    Repack,
+    Prescription,
+    LocationMovement,
 }
 
-#[derive(Enum, Copy, Clone, PartialEq, Eq, Debug, Serialize)]
+#[derive(Enum, Copy, Clone, PartialEq, Eq, Debug, Serialize, JsonSchema)]
 #[graphql(remote = "domain::invoice::InvoiceStatus")]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")] // only needed to be comparable in tests
 pub enum InvoiceNodeStatus {
@@ -93,6 +99,7 @@ pub enum InvoiceNodeStatus {
     /// updated when items are added to the invoice.
     /// Inbound Shipment: No stock changes in this status, only manually entered
     /// inbound Shipments have new status
+    /// Returns and Prescription: Lines can only be added, changed and deleted in this status
     New,
     /// General description: Outbound Shipment is ready for picking (all unallocated lines need to be fullfilled)
     /// Outbound Shipment: Invoice can only be turned to allocated status when
@@ -105,20 +112,26 @@ pub enum InvoiceNodeStatus {
     /// Inbound Shipment: For inter store stock transfers an inbound Shipment
     /// is created when corresponding outbound Shipment is picked and ready for
     /// Shipment, inbound Shipment is not editable in this status
+    /// Supplier Return: total_number_of_packs of the returned stock lines get updated
+    /// Prescription: Stock is dispensed, total_number_of_packs of the stock lines get updated
     Picked,
     /// General description: Outbound Shipment is sent out for delivery
     /// Outbound Shipment: Becomes not editable
     /// Inbound Shipment: For inter store stock transfers an inbound Shipment
     /// becomes editable when this status is set as a result of corresponding
     /// outbound Shipment being chagned to shipped (this is similar to New status)
+    /// Supplier Return: Becomes not editable
     Shipped,
     /// General description: Inbound Shipment was received
     /// Outbound Shipment: Status is updated based on corresponding inbound Shipment
     /// Inbound Shipment: Stock is introduced and can be issued
+    /// Customer Return: Returned stock is added back to its stock lines
     Delivered,
     /// General description: Received inbound Shipment was counted and verified
     /// Outbound Shipment: Status is updated based on corresponding inbound Shipment
     /// Inbound Shipment: Becomes not editable
+    /// Customer Return: Becomes not editable
+    /// Prescription: Becomes not editable
     Verified,
 }
 
@@ -202,6 +215,11 @@ impl InvoiceNode {
         &self.invoice.color
     }
 
+    /// Prescriber of a prescription
+    pub async fn prescriber_id(&self) -> &Option<String> {
+        &self.invoice.prescriber_id
+    }
+
     pub async fn lines(&self, ctx: &Context<'_>) -> InvoiceLinesResponse {
         let loader = ctx.get_loader::<DataLoader<InvoiceLineQueryLoader>>();
         match loader.load_one(self.invoice.id.to_string()).await {
@@ -1,9 +1,32 @@
-use crate::{loader::StockLineByItemIdLoader, ContextExt};
+use crate::{
+    loader::{ItemStockSummaryLoader, StockLineByItemIdLoader},
+    standard_graphql_error::StandardGraphqlError,
+    ContextExt,
+};
 use async_graphql::dataloader::DataLoader;
 use async_graphql::*;
+use chrono::NaiveDate;
 use domain::item::Item;
+use service::stock_on_hand::item_summary::ItemStockSummary;
 
 use super::{InternalError, StockLinesResponse};
+use crate::schema::queries::pack_variant::{load_pack_variants, PackVariantNode};
+
+#[derive(Enum, Copy, Clone, PartialEq, Eq)]
+#[graphql(remote = "domain::item::VenCategory")]
+pub enum VenCategoryNode {
+    Vital,
+    Essential,
+    NonEssential,
+}
+
+#[derive(Enum, Copy, Clone, PartialEq, Eq)]
+#[graphql(remote = "domain::item::AbcClass")]
+pub enum AbcClassNode {
+    A,
+    B,
+    C,
+}
 
 #[derive(PartialEq, Debug)]
 pub struct ItemNode {
@@ -32,6 +55,35 @@ impl ItemNode {
         &self.item.unit_name
     }
 
+    pub async fn category_id(&self) -> &Option<String> {
+        &self.item.category_id
+    }
+
+    pub async fn strength(&self) -> &Option<String> {
+        &self.item.strength
+    }
+
+    pub async fn dosage_form(&self) -> &Option<String> {
+        &self.item.dosage_form
+    }
+
+    pub async fn ven_category(&self) -> Option<VenCategoryNode> {
+        self.item.ven_category.clone().map(VenCategoryNode::from)
+    }
+
+    pub async fn is_controlled(&self) -> bool {
+        self.item.is_controlled
+    }
+
+    pub async fn storage_requirement(&self) -> &Option<String> {
+        &self.item.storage_requirement
+    }
+
+    /// ABC class of the item in the current store, null if it hasn't been classified
+    pub async fn abc_class(&self) -> Option<AbcClassNode> {
+        self.item.abc_class.clone().map(AbcClassNode::from)
+    }
+
     async fn available_batches(&self, ctx: &Context<'_>) -> StockLinesResponse {
         let loader = ctx.get_loader::<DataLoader<StockLineByItemIdLoader>>();
         match loader.load_one(self.item.id.to_string()).await {
@@ -41,6 +93,71 @@ impl ItemNode {
             Err(error) => StockLinesResponse::Error(error.into()),
         }
     }
+
+    /// Packs the item comes in, ordered by pack size
+    pub async fn pack_variants(&self, ctx: &Context<'_>) -> Result<Vec<PackVariantNode>> {
+        load_pack_variants(ctx, &self.item.id).await
+    }
+
+    pub async fn total_number_of_packs(&self, ctx: &Context<'_>) -> Result<i64> {
+        Ok(self.stock_summary(ctx).await?.total_number_of_packs)
+    }
+
+    pub async fn available_number_of_packs(&self, ctx: &Context<'_>) -> Result<i64> {
+        Ok(self.stock_summary(ctx).await?.available_number_of_packs)
+    }
+
+    /// Stock on hand in units (number of packs * pack size)
+    pub async fn total_units(&self, ctx: &Context<'_>) -> Result<i64> {
+        Ok(self.stock_summary(ctx).await?.total_units)
+    }
+
+    pub async fn available_units(&self, ctx: &Context<'_>) -> Result<i64> {
+        Ok(self.stock_summary(ctx).await?.available_units)
+    }
+
+    /// Number of batches with stock on hand
+    pub async fn number_of_batches(&self, ctx: &Context<'_>) -> Result<i64> {
+        Ok(self.stock_summary(ctx).await?.number_of_batches)
+    }
+
+    pub async fn earliest_expiry_date(&self, ctx: &Context<'_>) -> Result<Option<NaiveDate>> {
+        Ok(self.stock_summary(ctx).await?.earliest_expiry_date)
+    }
+
+    /// Value of the stock on hand at cost price
+    pub async fn stock_value(&self, ctx: &Context<'_>) -> Result<f64> {
+        Ok(self.stock_summary(ctx).await?.total_cost_value)
+    }
+
+    /// Average units shipped or dispensed per month over the last three months
+    pub async fn average_monthly_consumption(&self, ctx: &Context<'_>) -> Result<f64> {
+        Ok(self.stock_summary(ctx).await?.average_monthly_consumption)
+    }
+
+    /// Months the stock on hand lasts at the average monthly consumption, null if there was no
+    /// consumption
+    pub async fn months_of_stock(&self, ctx: &Context<'_>) -> Result<Option<f64>> {
+        Ok(self.stock_summary(ctx).await?.months_of_stock)
+    }
+}
+
+impl ItemNode {
+    /// Stock figures of the item in the current store
+    async fn stock_summary(&self, ctx: &Context<'_>) -> Result<ItemStockSummary> {
+        let loader = ctx.get_loader::<DataLoader<ItemStockSummaryLoader>>();
+        loader
+            .load_one(self.item.id.clone())
+            .await
+            .map_err(|error| StandardGraphqlError::from(error).extend())?
+            .ok_or(
+                StandardGraphqlError::InternalError(format!(
+                    "Missing stock summary for item {}",
+                    self.item.id
+                ))
+                .extend(),
+            )
+    }
 }
 
 #[derive(Union)]
@@ -5,7 +5,12 @@ use domain::{
     EqualFilter,
 };
 
-use crate::{loader::StockLineByLocationIdLoader, ContextExt};
+use repository::schema::LocationTypeRow;
+use service::SingleRecordError;
+
+use crate::{
+    loader::StockLineByLocationIdLoader, standard_graphql_error::StandardGraphqlError, ContextExt,
+};
 
 use super::{
     Connector, ConnectorError, EqualFilterStringInput, NodeError, SortInput, StockLinesResponse,
@@ -25,6 +30,7 @@ pub struct LocationFilterInput {
     pub name: Option<EqualFilterStringInput>,
     pub code: Option<EqualFilterStringInput>,
     pub id: Option<EqualFilterStringInput>,
+    pub parent_id: Option<EqualFilterStringInput>,
 }
 
 impl From<LocationFilterInput> for LocationFilter {
@@ -34,6 +40,7 @@ impl From<LocationFilterInput> for LocationFilter {
             code: f.code.map(EqualFilter::from),
             id: f.id.map(EqualFilter::from),
             store_id: None,
+            parent_id: f.parent_id.map(EqualFilter::from),
         }
     }
 }
@@ -61,6 +68,53 @@ impl LocationNode {
         self.location.on_hold
     }
 
+    pub async fn parent_id(&self) -> &Option<String> {
+        &self.location.parent_id
+    }
+
+    /// Type set on the location itself, see locationType for the effective type
+    pub async fn location_type_id(&self) -> &Option<String> {
+        &self.location.location_type_id
+    }
+
+    /// Storage capacity of the location
+    pub async fn volume(&self) -> Option<f64> {
+        self.location.volume
+    }
+
+    /// Type of the location, inherited from the nearest parent location if not set
+    pub async fn location_type(&self, ctx: &Context<'_>) -> Result<Option<LocationTypeNode>> {
+        let service_provider = ctx.service_provider();
+        let service_ctx = service_provider.context()?;
+        let location_type = service_provider
+            .location_service
+            .get_location_type(&service_ctx, &self.location.id)
+            .map_err(map_single_record_error)?;
+        Ok(location_type.map(LocationTypeNode::from))
+    }
+
+    /// Parent locations and the location itself, starting with the outermost location
+    pub async fn path(&self, ctx: &Context<'_>) -> Result<Vec<LocationNode>> {
+        let service_provider = ctx.service_provider();
+        let service_ctx = service_provider.context()?;
+        let path = service_provider
+            .location_service
+            .get_location_path(&service_ctx, &self.location.id)
+            .map_err(map_single_record_error)?;
+        Ok(path.into_iter().map(LocationNode::from).collect())
+    }
+
+    /// All locations nested in the location, at any depth
+    pub async fn sub_locations(&self, ctx: &Context<'_>) -> Result<Vec<LocationNode>> {
+        let service_provider = ctx.service_provider();
+        let service_ctx = service_provider.context()?;
+        let sub_locations = service_provider
+            .location_service
+            .get_sub_locations(&service_ctx, &self.location.id)
+            .map_err(map_single_record_error)?;
+        Ok(sub_locations.into_iter().map(LocationNode::from).collect())
+    }
+
     pub async fn stock(&self, ctx: &Context<'_>) -> StockLinesResponse {
         let loader = ctx.get_loader::<DataLoader<StockLineByLocationIdLoader>>();
         match loader.load_one(self.location.id.clone()).await {
@@ -72,6 +126,46 @@ impl LocationNode {
     }
 }
 
+fn map_single_record_error(error: SingleRecordError) -> Error {
+    match error {
+        SingleRecordError::DatabaseError(error) => StandardGraphqlError::from(error),
+        SingleRecordError::NotFound(id) => {
+            StandardGraphqlError::InternalError(format!("Missing location {}", id))
+        }
+    }
+    .extend()
+}
+
+#[derive(PartialEq, Debug)]
+pub struct LocationTypeNode {
+    pub location_type: LocationTypeRow,
+}
+
+#[Object]
+impl LocationTypeNode {
+    pub async fn id(&self) -> &str {
+        &self.location_type.id
+    }
+
+    pub async fn name(&self) -> &str {
+        &self.location_type.name
+    }
+
+    pub async fn min_temperature(&self) -> Option<f64> {
+        self.location_type.min_temperature
+    }
+
+    pub async fn max_temperature(&self) -> Option<f64> {
+        self.location_type.max_temperature
+    }
+}
+
+impl From<LocationTypeRow> for LocationTypeNode {
+    fn from(location_type: LocationTypeRow) -> Self {
+        LocationTypeNode { location_type }
+    }
+}
+
 #[derive(Union)]
 pub enum LocationsResponse {
     Error(ConnectorError),
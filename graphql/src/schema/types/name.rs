@@ -4,6 +4,16 @@ use async_graphql::*;
 
 use super::NodeError;
 
+#[derive(Enum, Copy, Clone, PartialEq, Eq)]
+#[graphql(remote = "domain::name::NameType")]
+pub enum NameNodeType {
+    /// Customers and suppliers, e.g. clinics and wholesalers
+    Facility,
+    Patient,
+    Store,
+    Other,
+}
+
 #[Object]
 impl NameNode {
     pub async fn id(&self) -> &str {
@@ -25,6 +35,56 @@ impl NameNode {
     pub async fn is_supplier(&self) -> bool {
         self.name.is_supplier
     }
+
+    pub async fn r#type(&self) -> NameNodeType {
+        NameNodeType::from(self.name.r#type.clone())
+    }
+
+    pub async fn is_manufacturer(&self) -> bool {
+        self.name.is_manufacturer
+    }
+
+    pub async fn is_donor(&self) -> bool {
+        self.name.is_donor
+    }
+
+    pub async fn phone(&self) -> &Option<String> {
+        &self.name.phone
+    }
+
+    pub async fn email(&self) -> &Option<String> {
+        &self.name.email
+    }
+
+    pub async fn website(&self) -> &Option<String> {
+        &self.name.website
+    }
+
+    pub async fn address1(&self) -> &Option<String> {
+        &self.name.address1
+    }
+
+    pub async fn address2(&self) -> &Option<String> {
+        &self.name.address2
+    }
+
+    pub async fn country(&self) -> &Option<String> {
+        &self.name.country
+    }
+
+    pub async fn comment(&self) -> &Option<String> {
+        &self.name.comment
+    }
+
+    /// Store the name was created in, names can only be edited in their home store
+    pub async fn home_store_id(&self) -> &Option<String> {
+        &self.name.home_store_id
+    }
+
+    /// Names of the tags (categories) of the name
+    pub async fn tags(&self) -> &Vec<String> {
+        &self.name.tags
+    }
 }
 
 #[derive(Union)]
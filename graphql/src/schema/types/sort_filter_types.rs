@@ -1,20 +1,34 @@
-use crate::schema::queries::{ItemSortFieldInput, NameSortFieldInput};
+use crate::schema::{
+    mutations::stock_take::{StockTakeNodeStatus, StockTakeSortFieldInput},
+    queries::{
+        ItemSortFieldInput, NameSortFieldInput, PatientSortFieldInput, StockOnHandSortFieldInput,
+    },
+};
 
-use super::{InvoiceNodeStatus, InvoiceNodeType, InvoiceSortFieldInput, LocationSortFieldInput};
+use super::{
+    AbcClassNode, InvoiceNodeStatus, InvoiceNodeType, InvoiceSortFieldInput,
+    LocationSortFieldInput, NameNodeType, VenCategoryNode,
+};
 
 use domain::{
     invoice::{InvoiceStatus, InvoiceType},
-    DatetimeFilter, EqualFilter, SimpleStringFilter, Sort,
+    item::{AbcClass, VenCategory},
+    name::NameType,
+    DateFilter, DatetimeFilter, EqualFilter, SimpleStringFilter, Sort,
 };
 
 use async_graphql::{InputObject, InputType};
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
+use repository::schema::StockTakeStatus;
 
 #[derive(InputObject)]
 #[graphql(concrete(name = "InvoiceSortInput", params(InvoiceSortFieldInput)))]
 #[graphql(concrete(name = "ItemSortInput", params(ItemSortFieldInput)))]
 #[graphql(concrete(name = "NameSortInput", params(NameSortFieldInput)))]
+#[graphql(concrete(name = "StockOnHandSortInput", params(StockOnHandSortFieldInput)))]
 #[graphql(concrete(name = "LocationSortInput", params(LocationSortFieldInput)))]
+#[graphql(concrete(name = "StockTakeSortInput", params(StockTakeSortFieldInput)))]
+#[graphql(concrete(name = "PatientSortInput", params(PatientSortFieldInput)))]
 pub struct SortInput<T: InputType> {
     /// Sort query result by `key`
     pub key: T,
@@ -75,6 +89,10 @@ impl From<SimpleStringFilterInput> for SimpleStringFilter {
 #[graphql(concrete(name = "EqualFilterBigNumberInput", params(i64)))]
 #[graphql(concrete(name = "EqualFilterInvoiceTypeInput", params(InvoiceNodeType)))]
 #[graphql(concrete(name = "EqualFilterInvoiceStatusInput", params(InvoiceNodeStatus)))]
+#[graphql(concrete(name = "EqualFilterStockTakeStatusInput", params(StockTakeNodeStatus)))]
+#[graphql(concrete(name = "EqualFilterVenCategoryInput", params(VenCategoryNode)))]
+#[graphql(concrete(name = "EqualFilterAbcClassInput", params(AbcClassNode)))]
+#[graphql(concrete(name = "EqualFilterNameTypeInput", params(NameNodeType)))]
 pub struct EqualFilterInput<T: InputType> {
     pub equal_to: Option<T>,
     pub equal_any: Option<Vec<T>>,
@@ -137,6 +155,76 @@ impl From<EqualFilterInput<InvoiceNodeStatus>> for EqualFilter<InvoiceStatus> {
     }
 }
 
+impl From<EqualFilterInput<StockTakeNodeStatus>> for EqualFilter<StockTakeStatus> {
+    fn from(
+        EqualFilterInput {
+            equal_to,
+            equal_any,
+            not_equal_to,
+        }: EqualFilterInput<StockTakeNodeStatus>,
+    ) -> Self {
+        EqualFilter {
+            equal_to: equal_to.map(|status| status.to_domain()),
+            equal_any: equal_any.map(|statuses| {
+                statuses
+                    .into_iter()
+                    .map(|status| status.to_domain())
+                    .collect()
+            }),
+            not_equal_to: not_equal_to.map(|status| status.to_domain()),
+        }
+    }
+}
+
+impl From<EqualFilterInput<VenCategoryNode>> for EqualFilter<VenCategory> {
+    fn from(
+        EqualFilterInput {
+            equal_to,
+            equal_any,
+            not_equal_to,
+        }: EqualFilterInput<VenCategoryNode>,
+    ) -> Self {
+        EqualFilter {
+            equal_to: equal_to.map(VenCategory::from),
+            equal_any: equal_any
+                .map(|categories| categories.into_iter().map(VenCategory::from).collect()),
+            not_equal_to: not_equal_to.map(VenCategory::from),
+        }
+    }
+}
+
+impl From<EqualFilterInput<NameNodeType>> for EqualFilter<NameType> {
+    fn from(
+        EqualFilterInput {
+            equal_to,
+            equal_any,
+            not_equal_to,
+        }: EqualFilterInput<NameNodeType>,
+    ) -> Self {
+        EqualFilter {
+            equal_to: equal_to.map(NameType::from),
+            equal_any: equal_any.map(|types| types.into_iter().map(NameType::from).collect()),
+            not_equal_to: not_equal_to.map(NameType::from),
+        }
+    }
+}
+
+impl From<EqualFilterInput<AbcClassNode>> for EqualFilter<AbcClass> {
+    fn from(
+        EqualFilterInput {
+            equal_to,
+            equal_any,
+            not_equal_to,
+        }: EqualFilterInput<AbcClassNode>,
+    ) -> Self {
+        EqualFilter {
+            equal_to: equal_to.map(AbcClass::from),
+            equal_any: equal_any.map(|classes| classes.into_iter().map(AbcClass::from).collect()),
+            not_equal_to: not_equal_to.map(AbcClass::from),
+        }
+    }
+}
+
 // Datetime filter
 
 #[derive(InputObject, Clone)]
@@ -155,3 +243,22 @@ impl From<DatetimeFilterInput> for DatetimeFilter {
         }
     }
 }
+
+// Date filter
+
+#[derive(InputObject, Clone)]
+pub struct DateFilterInput {
+    pub equal_to: Option<NaiveDate>,
+    pub before_or_equal_to: Option<NaiveDate>,
+    pub after_or_equal_to: Option<NaiveDate>,
+}
+
+impl From<DateFilterInput> for DateFilter {
+    fn from(f: DateFilterInput) -> Self {
+        DateFilter {
+            equal_to: f.equal_to,
+            before_or_equal_to: f.before_or_equal_to,
+            after_or_equal_to: f.after_or_equal_to,
+        }
+    }
+}
@@ -2,13 +2,41 @@ use async_graphql::dataloader::DataLoader;
 use async_graphql::*;
 use chrono::NaiveDate;
 
-use domain::stock_line::StockLine;
+use domain::stock_line::{StockLine, StockLineFilter};
+use domain::{DateFilter, EqualFilter};
 use repository::StorageConnectionManager;
-use service::stock_line::get_stock_line;
+use service::{pack_variant::number_of_units, stock_line::get_stock_line};
 
-use crate::{loader::LocationByIdLoader, ContextExt};
+use crate::{
+    loader::LocationByIdLoader,
+    schema::queries::pack_variant::{load_pack_variant, PackVariantNode},
+    ContextExt,
+};
 
-use super::{Connector, ConnectorError, LocationResponse, NodeError};
+use super::{
+    Connector, ConnectorError, DateFilterInput, EqualFilterStringInput, LocationResponse, NodeError,
+};
+
+#[derive(InputObject, Clone)]
+pub struct StockLineFilterInput {
+    pub item_id: Option<EqualFilterStringInput>,
+    pub location_id: Option<EqualFilterStringInput>,
+    pub batch: Option<EqualFilterStringInput>,
+    pub expiry_date: Option<DateFilterInput>,
+}
+
+impl From<StockLineFilterInput> for StockLineFilter {
+    fn from(f: StockLineFilterInput) -> Self {
+        StockLineFilter {
+            id: None,
+            item_id: f.item_id.map(EqualFilter::from),
+            store_id: None,
+            location_id: f.location_id.map(EqualFilter::from),
+            batch: f.batch.map(EqualFilter::from),
+            expiry_date: f.expiry_date.map(DateFilter::from),
+        }
+    }
+}
 
 pub struct StockLineNode {
     pub stock_line: StockLine,
@@ -43,6 +71,24 @@ impl StockLineNode {
     pub async fn total_number_of_packs(&self) -> i32 {
         self.stock_line.total_number_of_packs
     }
+    /// Available number of packs * pack size
+    pub async fn available_units(&self) -> i64 {
+        number_of_units(
+            self.stock_line.available_number_of_packs,
+            self.stock_line.pack_size,
+        )
+    }
+    /// Total number of packs * pack size
+    pub async fn total_units(&self) -> i64 {
+        number_of_units(
+            self.stock_line.total_number_of_packs,
+            self.stock_line.pack_size,
+        )
+    }
+    /// Pack variant of the item with the pack size of the stock line
+    pub async fn pack_variant(&self, ctx: &Context<'_>) -> Result<Option<PackVariantNode>> {
+        load_pack_variant(ctx, &self.stock_line.item_id, self.stock_line.pack_size).await
+    }
     pub async fn expiry_date(&self) -> &Option<NaiveDate> {
         &self.stock_line.expiry_date
     }
@@ -0,0 +1,230 @@
+use actix_web::{
+    web::{Bytes, Data, Json, Query},
+    HttpRequest, HttpResponse,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use service::{
+    auth_data::AuthData,
+    current_store_id,
+    permission_validation::{Resource, ResourceAccessRequest},
+    service_provider::{ServiceContext, ServiceProvider},
+    temperature_log::{
+        import::{
+            ImportTemperatureLogs, ImportTemperatureLogsError, TemperatureLogRowError,
+            TemperatureLogRowErrorKind,
+        },
+        insert::{InsertTemperatureLog, InsertTemperatureLogsError},
+        IngestedTemperatureLogs,
+    },
+};
+
+use crate::{
+    auth_data_from_request,
+    import::{ImportError, RowErrorKindResponse, RowErrorResponse},
+};
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportTemperatureLogQuery {
+    pub store_id: Option<String>,
+    /// Location of all readings, required unless the file has a location_code column
+    pub location_id: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TemperatureLogQuery {
+    pub store_id: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TemperatureReading {
+    pub location_id: String,
+    /// Degrees Celsius
+    pub temperature: f64,
+    /// RFC 3339, e.g. `2021-06-01T08:00:00Z`
+    pub datetime: DateTime<Utc>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TemperatureLogRequest {
+    pub readings: Vec<TemperatureReading>,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TemperatureLogResponse {
+    pub number_of_logs: usize,
+    /// Readings skipped because the location already has a reading at the same time
+    pub number_of_duplicates: usize,
+    /// Breaches of the locations of the readings, including ended breaches
+    pub number_of_breaches: usize,
+    pub number_of_current_breaches: usize,
+}
+
+impl From<IngestedTemperatureLogs> for TemperatureLogResponse {
+    fn from(result: IngestedTemperatureLogs) -> Self {
+        TemperatureLogResponse {
+            number_of_logs: result.logs.len(),
+            number_of_duplicates: result.number_of_duplicates,
+            number_of_breaches: result.breaches.len(),
+            number_of_current_breaches: result
+                .breaches
+                .iter()
+                .filter(|breach| breach.end_datetime.is_none())
+                .count(),
+        }
+    }
+}
+
+impl From<ImportTemperatureLogsError> for ImportError {
+    fn from(error: ImportTemperatureLogsError) -> Self {
+        match error {
+            ImportTemperatureLogsError::DatabaseError(error) => error.into(),
+            ImportTemperatureLogsError::MissingHeader => ImportError::BadUserInput(
+                "No header with a temperature and a date time column".to_string(),
+            ),
+            ImportTemperatureLogsError::MissingLocation => ImportError::BadUserInput(
+                "Either locationId or a location_code column is required".to_string(),
+            ),
+            ImportTemperatureLogsError::LocationDoesNotExist => {
+                ImportError::BadUserInput("Location does not exist".to_string())
+            }
+            ImportTemperatureLogsError::NoRows => {
+                ImportError::BadUserInput("File has no rows".to_string())
+            }
+            ImportTemperatureLogsError::RowErrors(row_errors) => {
+                ImportError::InvalidRows(row_errors.into_iter().map(row_error_response).collect())
+            }
+        }
+    }
+}
+
+impl From<InsertTemperatureLogsError> for ImportError {
+    fn from(error: InsertTemperatureLogsError) -> Self {
+        match error {
+            InsertTemperatureLogsError::DatabaseError(error) => error.into(),
+            InsertTemperatureLogsError::NoReadings => {
+                ImportError::BadUserInput("No readings".to_string())
+            }
+            InsertTemperatureLogsError::LocationDoesNotExist(id) => {
+                ImportError::BadUserInput(format!("Location does not exist: {}", id))
+            }
+        }
+    }
+}
+
+fn row_error_response(row_error: TemperatureLogRowError) -> RowErrorResponse {
+    use TemperatureLogRowErrorKind::*;
+    RowErrorResponse {
+        line_number: row_error.line_number,
+        errors: row_error
+            .errors
+            .into_iter()
+            .map(|kind| {
+                let (code, value) = match kind {
+                    InvalidRow(error) => ("InvalidRow", error),
+                    InvalidDatetime(value) => ("InvalidDatetime", value),
+                    InvalidTemperature(value) => ("InvalidTemperature", value),
+                    LocationDoesNotExist(code) => ("LocationDoesNotExist", code),
+                };
+                RowErrorKindResponse {
+                    code,
+                    value: Some(value),
+                }
+            })
+            .collect(),
+    }
+}
+
+/// Checks the request has access to ingest readings in the store, returns the service context and
+/// the store id
+fn validate_request(
+    service_provider: &ServiceProvider,
+    auth_data: &AuthData,
+    http_req: &HttpRequest,
+    store_id: Option<String>,
+) -> Result<(ServiceContext, String), ImportError> {
+    let service_ctx = service_provider.context()?;
+    // TODO remove and make store_id parameter required
+    let store_id = match store_id {
+        Some(store_id) => store_id,
+        None => current_store_id(&service_ctx.connection)?,
+    };
+    service_provider.validation_service.validate(
+        &service_ctx,
+        auth_data,
+        &auth_data_from_request(http_req).auth_token,
+        &ResourceAccessRequest {
+            resource: Resource::IngestTemperatureLogs,
+            store_id: Some(store_id.clone()),
+        },
+    )?;
+    Ok((service_ctx, store_id))
+}
+
+/// Imports the CSV export of a data logger in the request body, e.g.
+/// `POST /import/temperatureLog?storeId=store_a&locationId=fridge_1`
+pub async fn import_temperature_log(
+    query: Query<ImportTemperatureLogQuery>,
+    body: Bytes,
+    http_req: HttpRequest,
+    service_provider: Data<ServiceProvider>,
+    auth_data: Data<AuthData>,
+) -> Result<HttpResponse, ImportError> {
+    let ImportTemperatureLogQuery {
+        store_id,
+        location_id,
+    } = query.into_inner();
+    let csv = String::from_utf8(body.to_vec())
+        .map_err(|_| ImportError::BadUserInput("File is not UTF-8 encoded".to_string()))?;
+    let (service_ctx, store_id) =
+        validate_request(&service_provider, &auth_data, &http_req, store_id)?;
+
+    let result = service_provider
+        .temperature_log_service
+        .import_temperature_logs(
+            &service_ctx,
+            &store_id,
+            ImportTemperatureLogs {
+                location_id,
+                csv: csv.trim_start_matches('\u{feff}').to_string(),
+            },
+        )?;
+    Ok(HttpResponse::Ok().json(TemperatureLogResponse::from(result)))
+}
+
+/// Logs readings pushed by local sensors, e.g. `POST /temperatureLog?storeId=store_a` with
+/// `{ "readings": [{ "locationId": "fridge_1", "temperature": 4.5, "datetime": "2021-06-01T08:00:00Z" }] }`
+pub async fn insert_temperature_log(
+    query: Query<TemperatureLogQuery>,
+    body: Json<TemperatureLogRequest>,
+    http_req: HttpRequest,
+    service_provider: Data<ServiceProvider>,
+    auth_data: Data<AuthData>,
+) -> Result<HttpResponse, ImportError> {
+    let (service_ctx, store_id) = validate_request(
+        &service_provider,
+        &auth_data,
+        &http_req,
+        query.into_inner().store_id,
+    )?;
+
+    let readings = body
+        .into_inner()
+        .readings
+        .into_iter()
+        .map(|reading| InsertTemperatureLog {
+            location_id: reading.location_id,
+            temperature: reading.temperature,
+            datetime: reading.datetime.naive_utc(),
+        })
+        .collect();
+    let result = service_provider
+        .temperature_log_service
+        .insert_temperature_logs(&service_ctx, &store_id, readings)?;
+    Ok(HttpResponse::Ok().json(TemperatureLogResponse::from(result)))
+}
@@ -0,0 +1,8 @@
+-- Create currency table.
+
+CREATE TABLE currency (
+    id TEXT NOT NULL PRIMARY KEY,
+    code TEXT NOT NULL,
+    rate DOUBLE PRECISION NOT NULL,
+    is_home_currency BOOLEAN NOT NULL
+)
@@ -0,0 +1,4 @@
+-- Drop reason table.
+
+DROP TABLE IF EXISTS reason;
+DROP TYPE IF EXISTS reason_row_type;
\ No newline at end of file
@@ -0,0 +1,14 @@
+-- Create reason table.
+
+CREATE TYPE reason_row_type AS ENUM (
+    'POSITIVE_INVENTORY_ADJUSTMENT',
+    'NEGATIVE_INVENTORY_ADJUSTMENT',
+    'RETURN_REASON'
+);
+
+CREATE TABLE reason (
+    id TEXT NOT NULL PRIMARY KEY,
+    type reason_row_type NOT NULL,
+    reason TEXT NOT NULL,
+    is_active BOOLEAN NOT NULL
+)
@@ -0,0 +1,11 @@
+-- Create item_store_join table.
+
+CREATE TABLE item_store_join (
+    id TEXT NOT NULL PRIMARY KEY,
+    item_id TEXT NOT NULL REFERENCES item(id),
+    store_id TEXT NOT NULL REFERENCES store(id),
+    default_sell_price_per_pack DOUBLE PRECISION NOT NULL,
+    margin DOUBLE PRECISION NOT NULL,
+    ignore_for_orders BOOLEAN NOT NULL,
+    inactive BOOLEAN NOT NULL
+)
@@ -0,0 +1,17 @@
+-- Create store_preference_override table.
+-- Holds preferences which have been changed locally, null columns fall back to the synced
+-- store_preference or the default value.
+
+CREATE TABLE store_preference_override (
+    id TEXT NOT NULL PRIMARY KEY,
+    store_id TEXT NOT NULL UNIQUE REFERENCES store(id),
+    pack_to_one BOOLEAN,
+    months_overstock DOUBLE PRECISION,
+    months_understock DOUBLE PRECISION,
+    months_items_expire DOUBLE PRECISION,
+    allow_negative_stock BOOLEAN,
+    timezone_offset INTEGER,
+    months_of_stock_target DOUBLE PRECISION,
+    requires_reason_on_adjustment BOOLEAN,
+    expiring_soon_days INTEGER
+)
@@ -0,0 +1,29 @@
+DROP VIEW IF EXISTS item_is_visible CASCADE;
+
+-- Items visible in a store, i.e. items on a master list which is joined to the name of the store
+-- or items which are active in the store (item_store_join). Items which are inactive in a store are
+-- never visible in that store.
+CREATE VIEW item_is_visible_in_store AS
+SELECT
+    master_list_line.item_id,
+    store.id AS store_id
+FROM
+    master_list_line
+    JOIN master_list_name_join ON master_list_line.master_list_id = master_list_name_join.master_list_id
+    JOIN store ON master_list_name_join.name_id = store.name_id
+UNION
+SELECT
+    item_id,
+    store_id
+FROM
+    item_store_join
+WHERE
+    NOT inactive
+EXCEPT
+SELECT
+    item_id,
+    store_id
+FROM
+    item_store_join
+WHERE
+    inactive
@@ -0,0 +1,2 @@
+DROP VIEW stock_on_hand_by_location CASCADE;
+DROP VIEW stock_on_hand CASCADE;
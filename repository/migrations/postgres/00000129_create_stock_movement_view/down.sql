@@ -0,0 +1 @@
+DROP VIEW stock_movement CASCADE;
@@ -0,0 +1,3 @@
+DROP INDEX index_stock_line_store_id;
+DROP INDEX index_invoice_line_invoice_id;
+DROP INDEX index_invoice_store_id;
@@ -0,0 +1,44 @@
+DROP VIEW stock_movement CASCADE;
+
+CREATE VIEW stock_movement AS
+SELECT
+    *
+FROM (
+    SELECT
+        invoice_line.id,
+        invoice.store_id,
+        invoice_line.item_id,
+        invoice_line.stock_line_id,
+        invoice_line.location_id,
+        invoice_line.batch,
+        invoice_line.invoice_id,
+        invoice.type AS invoice_type,
+        invoice.invoice_number,
+        invoice.name_id AS other_party_id,
+        name.name AS other_party_name,
+        CASE
+            WHEN invoice.type = 'OUTBOUND_SHIPMENT'
+                AND invoice.status IN ('PICKED', 'SHIPPED', 'DELIVERED', 'VERIFIED')
+                THEN COALESCE(invoice.picked_datetime, invoice.shipped_datetime)
+            WHEN invoice.type = 'INBOUND_SHIPMENT'
+                AND invoice.status IN ('DELIVERED', 'VERIFIED')
+                THEN COALESCE(invoice.delivered_datetime, invoice.verified_datetime)
+            WHEN invoice.type = 'INVENTORY_ADJUSTMENT'
+                AND invoice.status = 'VERIFIED'
+                THEN invoice.verified_datetime
+        END AS datetime,
+        invoice_line.pack_size,
+        -- negative for stock leaving the store
+        CASE
+            WHEN invoice_line.type = 'STOCK_OUT' THEN -invoice_line.number_of_packs
+            ELSE invoice_line.number_of_packs
+        END AS number_of_packs
+    FROM
+        invoice_line
+        JOIN invoice ON invoice_line.invoice_id = invoice.id
+        JOIN name ON invoice.name_id = name.id
+    WHERE
+        invoice_line.type IN ('STOCK_IN', 'STOCK_OUT')
+) AS movement
+WHERE
+    movement.datetime IS NOT NULL;
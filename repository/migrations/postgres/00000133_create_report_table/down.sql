@@ -0,0 +1,4 @@
+-- Drop report table.
+
+DROP TABLE IF EXISTS report;
+DROP TYPE IF EXISTS report_context;
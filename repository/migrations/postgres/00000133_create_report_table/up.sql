@@ -0,0 +1,16 @@
+-- Create report table.
+-- Holds report templates synced from central or added locally, they override the built-in
+-- templates with the same id.
+
+CREATE TYPE report_context AS ENUM (
+    'OUTBOUND_SHIPMENT',
+    'INBOUND_SHIPMENT',
+    'STOCK_TAKE'
+);
+
+CREATE TABLE report (
+    id TEXT NOT NULL PRIMARY KEY,
+    name TEXT NOT NULL,
+    context report_context NOT NULL,
+    template TEXT NOT NULL
+);
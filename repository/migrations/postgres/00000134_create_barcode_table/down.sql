@@ -0,0 +1,3 @@
+-- Drop barcode table.
+
+DROP TABLE IF EXISTS barcode;
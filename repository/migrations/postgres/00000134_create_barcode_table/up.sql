@@ -0,0 +1,13 @@
+-- Create barcode table.
+-- Links barcode values, e.g. GTINs, to items and optionally to a pack size and manufacturer.
+
+CREATE TABLE barcode (
+    id TEXT NOT NULL PRIMARY KEY,
+    value TEXT NOT NULL,
+    item_id TEXT NOT NULL REFERENCES item(id),
+    manufacturer_id TEXT REFERENCES name(id),
+    pack_size INTEGER
+);
+
+CREATE INDEX index_barcode_value ON barcode (value);
+CREATE INDEX index_barcode_item_id ON barcode (item_id);
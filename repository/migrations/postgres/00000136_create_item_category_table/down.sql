@@ -0,0 +1,3 @@
+-- Drop item_category table.
+
+DROP TABLE IF EXISTS item_category;
@@ -0,0 +1,11 @@
+-- Create item_category table.
+-- Categories form a hierarchy, e.g. a therapeutic class with its sub classes. The parent isn't a
+-- foreign key since categories are synced in no particular order.
+
+CREATE TABLE item_category (
+    id TEXT NOT NULL PRIMARY KEY,
+    name TEXT NOT NULL,
+    parent_id TEXT
+);
+
+CREATE INDEX index_item_category_parent_id ON item_category (parent_id);
@@ -0,0 +1,12 @@
+-- Remove item properties.
+
+DROP VIEW IF EXISTS item_property;
+DROP TABLE IF EXISTS item_property_override;
+
+ALTER TABLE item
+    DROP COLUMN category_id,
+    DROP COLUMN strength,
+    DROP COLUMN dosage_form,
+    DROP COLUMN ven_category,
+    DROP COLUMN is_controlled,
+    DROP COLUMN storage_requirement;
@@ -0,0 +1,39 @@
+-- Add item properties.
+-- Properties are synced from central with the item, item_property_override holds properties
+-- changed locally. The item_property view resolves the properties to use, an override wins over the
+-- synced value.
+
+ALTER TABLE item
+    ADD COLUMN category_id TEXT REFERENCES item_category(id),
+    ADD COLUMN strength TEXT,
+    ADD COLUMN dosage_form TEXT,
+    ADD COLUMN ven_category TEXT,
+    ADD COLUMN is_controlled BOOLEAN NOT NULL DEFAULT false,
+    ADD COLUMN storage_requirement TEXT;
+
+CREATE TABLE item_property_override (
+    id TEXT NOT NULL PRIMARY KEY,
+    item_id TEXT NOT NULL UNIQUE REFERENCES item(id),
+    category_id TEXT REFERENCES item_category(id),
+    strength TEXT,
+    dosage_form TEXT,
+    ven_category TEXT,
+    is_controlled BOOLEAN,
+    storage_requirement TEXT
+);
+
+CREATE VIEW item_property AS
+SELECT
+    item.id AS item_id,
+    COALESCE(item_property_override.category_id, item.category_id) AS category_id,
+    COALESCE(item_property_override.strength, item.strength) AS strength,
+    COALESCE(item_property_override.dosage_form, item.dosage_form) AS dosage_form,
+    COALESCE(item_property_override.ven_category, item.ven_category) AS ven_category,
+    COALESCE(item_property_override.is_controlled, item.is_controlled) AS is_controlled,
+    COALESCE(
+        item_property_override.storage_requirement,
+        item.storage_requirement
+    ) AS storage_requirement
+FROM
+    item
+    LEFT JOIN item_property_override ON item_property_override.item_id = item.id;
@@ -0,0 +1,3 @@
+-- Drop item_abc_class table.
+
+DROP TABLE IF EXISTS item_abc_class;
@@ -0,0 +1,2 @@
+-- Postgres can't remove enum values, only the column is dropped
+ALTER TABLE invoice_line DROP COLUMN return_reason_id;
@@ -0,0 +1,8 @@
+ALTER TYPE invoice_type ADD VALUE 'SUPPLIER_RETURN';
+ALTER TYPE invoice_type ADD VALUE 'CUSTOMER_RETURN';
+
+ALTER TYPE number_type ADD VALUE 'SUPPLIER_RETURN';
+ALTER TYPE number_type ADD VALUE 'CUSTOMER_RETURN';
+
+-- Reason of return lines, references a reason of type RETURN_REASON
+ALTER TABLE invoice_line ADD COLUMN return_reason_id TEXT REFERENCES reason(id);
@@ -0,0 +1 @@
+-- Postgres can't remove enum values
@@ -0,0 +1,3 @@
+ALTER TYPE invoice_type ADD VALUE 'REPACK';
+
+ALTER TYPE number_type ADD VALUE 'REPACK';
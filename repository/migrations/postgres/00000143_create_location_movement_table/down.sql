@@ -0,0 +1,3 @@
+-- Drop location_movement table.
+
+DROP TABLE IF EXISTS location_movement;
@@ -0,0 +1,7 @@
+-- Remove location hierarchy, location types and capacity.
+
+ALTER TABLE item_store_join DROP COLUMN restricted_location_type_id;
+
+ALTER TABLE location DROP COLUMN volume;
+ALTER TABLE location DROP COLUMN location_type_id;
+ALTER TABLE location DROP COLUMN parent_id;
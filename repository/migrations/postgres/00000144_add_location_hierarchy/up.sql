@@ -0,0 +1,11 @@
+-- Add location hierarchy, location types and capacity.
+-- Locations form a tree per store (e.g. building, room, shelf, bin). A location without a type
+-- uses the type of its nearest typed ancestor. Items restricted to a location type in a store can
+-- only be put into suitable locations.
+
+ALTER TABLE location ADD COLUMN parent_id TEXT REFERENCES location(id);
+ALTER TABLE location ADD COLUMN location_type_id TEXT REFERENCES location_type(id);
+-- capacity in litres
+ALTER TABLE location ADD COLUMN volume DOUBLE PRECISION;
+
+ALTER TABLE item_store_join ADD COLUMN restricted_location_type_id TEXT REFERENCES location_type(id);
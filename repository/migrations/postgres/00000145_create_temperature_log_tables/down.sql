@@ -0,0 +1,5 @@
+-- Drop temperature_log, temperature_breach_config and temperature_breach tables.
+
+DROP TABLE IF EXISTS temperature_breach;
+DROP TABLE IF EXISTS temperature_breach_config;
+DROP TABLE IF EXISTS temperature_log;
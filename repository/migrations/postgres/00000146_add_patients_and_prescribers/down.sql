@@ -0,0 +1,14 @@
+-- Remove patients and prescribers.
+
+DROP TABLE prescriber;
+
+DROP INDEX index_name_type;
+
+ALTER TABLE name DROP COLUMN home_store_id;
+ALTER TABLE name DROP COLUMN phone;
+ALTER TABLE name DROP COLUMN date_of_birth;
+ALTER TABLE name DROP COLUMN last_name;
+ALTER TABLE name DROP COLUMN first_name;
+ALTER TABLE name DROP COLUMN type;
+
+DROP TYPE name_row_type;
@@ -0,0 +1,4 @@
+-- Postgres can't remove enum values, only the columns are dropped
+ALTER TABLE invoice_line DROP COLUMN directions;
+
+ALTER TABLE invoice DROP COLUMN prescriber_id;
@@ -0,0 +1,10 @@
+-- Add the prescription invoice type for dispensing stock to patients.
+ALTER TYPE invoice_type ADD VALUE 'PRESCRIPTION';
+
+ALTER TYPE number_type ADD VALUE 'PRESCRIPTION';
+
+-- Prescriber of a prescription
+ALTER TABLE invoice ADD COLUMN prescriber_id TEXT REFERENCES prescriber(id);
+
+-- How to take the dispensed stock, printed on the label of prescription lines
+ALTER TABLE invoice_line ADD COLUMN directions TEXT;
@@ -0,0 +1,17 @@
+-- Remove contact details, type flags and tags of names.
+-- Values can't be removed from the sync_out_table_name type, they are kept.
+
+ALTER TABLE sync_out ALTER COLUMN site_id SET NOT NULL;
+ALTER TABLE sync_out ALTER COLUMN created_at TYPE DATE;
+
+DROP TABLE name_tag_join;
+DROP TABLE name_tag;
+
+ALTER TABLE name DROP COLUMN comment;
+ALTER TABLE name DROP COLUMN country;
+ALTER TABLE name DROP COLUMN address2;
+ALTER TABLE name DROP COLUMN address1;
+ALTER TABLE name DROP COLUMN website;
+ALTER TABLE name DROP COLUMN email;
+ALTER TABLE name DROP COLUMN is_donor;
+ALTER TABLE name DROP COLUMN is_manufacturer;
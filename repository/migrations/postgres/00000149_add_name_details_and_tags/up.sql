@@ -0,0 +1,34 @@
+-- Add contact details, type flags and tags of names.
+-- Names can be created locally, e.g. a new outreach clinic. Like patients they can only be edited
+-- in their home store, the store they were created in. Local names are queued in sync_out for
+-- upload to central.
+
+ALTER TABLE name ADD COLUMN is_manufacturer BOOLEAN NOT NULL DEFAULT false;
+ALTER TABLE name ADD COLUMN is_donor BOOLEAN NOT NULL DEFAULT false;
+ALTER TABLE name ADD COLUMN email TEXT;
+ALTER TABLE name ADD COLUMN website TEXT;
+ALTER TABLE name ADD COLUMN address1 TEXT;
+ALTER TABLE name ADD COLUMN address2 TEXT;
+ALTER TABLE name ADD COLUMN country TEXT;
+ALTER TABLE name ADD COLUMN comment TEXT;
+
+-- Tags (categories) of names, e.g. "Health centre" or "District A"
+CREATE TABLE name_tag (
+    id TEXT NOT NULL PRIMARY KEY,
+    name TEXT NOT NULL UNIQUE
+);
+
+CREATE TABLE name_tag_join (
+    id TEXT NOT NULL PRIMARY KEY,
+    name_id TEXT NOT NULL REFERENCES name(id),
+    name_tag_id TEXT NOT NULL REFERENCES name_tag(id)
+);
+
+CREATE INDEX index_name_tag_join_name_id ON name_tag_join (name_id);
+
+-- The created_at date didn't order records created on the same day. Records are uploaded to
+-- central, site_id is only needed for records pushed to other sites.
+ALTER TABLE sync_out ALTER COLUMN created_at TYPE TIMESTAMP;
+ALTER TABLE sync_out ALTER COLUMN site_id DROP NOT NULL;
+ALTER TYPE sync_out_table_name ADD VALUE 'name_tag';
+ALTER TYPE sync_out_table_name ADD VALUE 'name_tag_join';
@@ -0,0 +1,7 @@
+-- Add the location movement invoice type. Stock moved to another location is recorded as a
+-- location movement invoice with a stock out line in the old and a stock in line in the new
+-- location. Moving part of a stock line splits the packs off into a new stock line, the stock in
+-- line is for the new stock line.
+ALTER TYPE invoice_type ADD VALUE 'LOCATION_MOVEMENT';
+
+ALTER TYPE number_type ADD VALUE 'LOCATION_MOVEMENT';
@@ -0,0 +1,3 @@
+-- Drop currency table.
+
+DROP TABLE IF EXISTS currency;
\ No newline at end of file
@@ -0,0 +1,3 @@
+-- Drop location_type table.
+
+DROP TABLE IF EXISTS location_type;
\ No newline at end of file
@@ -0,0 +1,8 @@
+-- Create location_type table.
+
+CREATE TABLE location_type (
+    id TEXT NOT NULL PRIMARY KEY,
+    name TEXT NOT NULL,
+    min_temperature REAL,
+    max_temperature REAL
+)
@@ -0,0 +1,3 @@
+-- Drop store_preference table.
+
+DROP TABLE IF EXISTS store_preference;
\ No newline at end of file
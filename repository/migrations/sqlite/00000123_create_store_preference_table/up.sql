@@ -0,0 +1,10 @@
+-- Create store_preference table.
+
+CREATE TABLE store_preference (
+    id TEXT NOT NULL PRIMARY KEY,
+    store_id TEXT NOT NULL REFERENCES store(id),
+    pack_to_one BOOLEAN NOT NULL,
+    months_overstock REAL NOT NULL,
+    months_understock REAL NOT NULL,
+    months_items_expire REAL NOT NULL
+)
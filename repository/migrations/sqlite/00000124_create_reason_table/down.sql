@@ -0,0 +1,3 @@
+-- Drop reason table.
+
+DROP TABLE IF EXISTS reason;
\ No newline at end of file
@@ -0,0 +1,8 @@
+-- Create reason table.
+
+CREATE TABLE reason (
+    id TEXT NOT NULL PRIMARY KEY,
+    type TEXT CHECK (type IN ('POSITIVE_INVENTORY_ADJUSTMENT', 'NEGATIVE_INVENTORY_ADJUSTMENT', 'RETURN_REASON')) NOT NULL,
+    reason TEXT NOT NULL,
+    is_active BOOLEAN NOT NULL
+)
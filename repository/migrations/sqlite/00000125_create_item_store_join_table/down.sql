@@ -0,0 +1,3 @@
+-- Drop item_store_join table.
+
+DROP TABLE IF EXISTS item_store_join;
\ No newline at end of file
@@ -0,0 +1,3 @@
+-- Drop store_preference_override table.
+
+DROP TABLE IF EXISTS store_preference_override;
\ No newline at end of file
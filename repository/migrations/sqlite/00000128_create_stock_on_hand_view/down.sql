@@ -0,0 +1,2 @@
+DROP VIEW stock_on_hand_by_location;
+DROP VIEW stock_on_hand;
@@ -0,0 +1,41 @@
+-- Current stock per item in a store, only counting batches that still hold stock
+CREATE VIEW stock_on_hand AS
+SELECT
+    stock_line.store_id,
+    stock_line.item_id,
+    SUM(stock_line.total_number_of_packs) AS total_number_of_packs,
+    SUM(stock_line.available_number_of_packs) AS available_number_of_packs,
+    SUM(stock_line.total_number_of_packs * stock_line.pack_size) AS total_units,
+    SUM(stock_line.available_number_of_packs * stock_line.pack_size) AS available_units,
+    COUNT(*) AS number_of_batches,
+    MIN(stock_line.expiry_date) AS earliest_expiry_date,
+    SUM(stock_line.total_number_of_packs * stock_line.cost_price_per_pack) AS total_cost_value
+FROM
+    stock_line
+WHERE
+    stock_line.total_number_of_packs > 0
+GROUP BY
+    stock_line.store_id,
+    stock_line.item_id;
+
+-- Same as stock_on_hand but split by location (location_id is null for unassigned stock)
+CREATE VIEW stock_on_hand_by_location AS
+SELECT
+    stock_line.store_id,
+    stock_line.item_id,
+    stock_line.location_id,
+    SUM(stock_line.total_number_of_packs) AS total_number_of_packs,
+    SUM(stock_line.available_number_of_packs) AS available_number_of_packs,
+    SUM(stock_line.total_number_of_packs * stock_line.pack_size) AS total_units,
+    SUM(stock_line.available_number_of_packs * stock_line.pack_size) AS available_units,
+    COUNT(*) AS number_of_batches,
+    MIN(stock_line.expiry_date) AS earliest_expiry_date,
+    SUM(stock_line.total_number_of_packs * stock_line.cost_price_per_pack) AS total_cost_value
+FROM
+    stock_line
+WHERE
+    stock_line.total_number_of_packs > 0
+GROUP BY
+    stock_line.store_id,
+    stock_line.item_id,
+    stock_line.location_id;
@@ -0,0 +1,4 @@
+-- Used when rebuilding stock on hand from the stock_movement view
+CREATE INDEX index_invoice_store_id ON invoice (store_id);
+CREATE INDEX index_invoice_line_invoice_id ON invoice_line (invoice_id);
+CREATE INDEX index_stock_line_store_id ON stock_line (store_id);
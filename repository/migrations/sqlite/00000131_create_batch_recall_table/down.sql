@@ -0,0 +1,4 @@
+-- Drop batch_recall tables.
+
+DROP TABLE IF EXISTS batch_recall_line;
+DROP TABLE IF EXISTS batch_recall;
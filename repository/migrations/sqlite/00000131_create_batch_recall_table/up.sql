@@ -0,0 +1,19 @@
+-- Create batch_recall table.
+-- Records a manufacturer recall and the local stock lines that were put on hold for it.
+
+CREATE TABLE batch_recall (
+    id TEXT NOT NULL PRIMARY KEY,
+    store_id TEXT NOT NULL REFERENCES store(id),
+    reference TEXT NOT NULL,
+    item_id TEXT REFERENCES item(id),
+    batch TEXT,
+    created_datetime TIMESTAMP NOT NULL
+);
+
+CREATE TABLE batch_recall_line (
+    id TEXT NOT NULL PRIMARY KEY,
+    batch_recall_id TEXT NOT NULL REFERENCES batch_recall(id),
+    stock_line_id TEXT NOT NULL REFERENCES stock_line(id),
+    -- packs on hand when the stock line was put on hold
+    number_of_packs INTEGER NOT NULL
+);
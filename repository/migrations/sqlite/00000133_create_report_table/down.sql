@@ -0,0 +1,3 @@
+-- Drop report table.
+
+DROP TABLE IF EXISTS report;
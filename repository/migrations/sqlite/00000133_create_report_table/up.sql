@@ -0,0 +1,10 @@
+-- Create report table.
+-- Holds report templates synced from central or added locally, they override the built-in
+-- templates with the same id.
+
+CREATE TABLE report (
+    id TEXT NOT NULL PRIMARY KEY,
+    name TEXT NOT NULL,
+    context TEXT CHECK (context IN ('OUTBOUND_SHIPMENT', 'INBOUND_SHIPMENT', 'STOCK_TAKE')) NOT NULL,
+    template TEXT NOT NULL
+);
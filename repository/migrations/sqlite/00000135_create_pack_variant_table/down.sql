@@ -0,0 +1,3 @@
+-- Drop pack_variant table.
+
+DROP TABLE IF EXISTS pack_variant;
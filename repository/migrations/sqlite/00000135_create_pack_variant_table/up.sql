@@ -0,0 +1,15 @@
+-- Create pack_variant table.
+-- Packs an item comes in, e.g. a bottle of 100 or a box of 10 blisters, with the pack size in
+-- units of the item.
+
+CREATE TABLE pack_variant (
+    id TEXT NOT NULL PRIMARY KEY,
+    item_id TEXT NOT NULL REFERENCES item(id),
+    name TEXT NOT NULL,
+    pack_size INTEGER NOT NULL,
+    unit_id TEXT REFERENCES unit(id),
+    volume_per_pack REAL,
+    weight_per_pack REAL
+);
+
+CREATE UNIQUE INDEX index_pack_variant_item_id_pack_size ON pack_variant (item_id, pack_size);
@@ -0,0 +1,11 @@
+-- Remove item properties.
+
+DROP VIEW IF EXISTS item_property;
+DROP TABLE IF EXISTS item_property_override;
+
+ALTER TABLE item DROP COLUMN category_id;
+ALTER TABLE item DROP COLUMN strength;
+ALTER TABLE item DROP COLUMN dosage_form;
+ALTER TABLE item DROP COLUMN ven_category;
+ALTER TABLE item DROP COLUMN is_controlled;
+ALTER TABLE item DROP COLUMN storage_requirement;
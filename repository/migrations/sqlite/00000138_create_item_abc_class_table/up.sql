@@ -0,0 +1,13 @@
+-- Create item_abc_class table.
+-- ABC classification of the items of a store by their consumption value, recalculated as a whole.
+
+CREATE TABLE item_abc_class (
+    id TEXT NOT NULL PRIMARY KEY,
+    store_id TEXT NOT NULL REFERENCES store(id),
+    item_id TEXT NOT NULL REFERENCES item(id),
+    class TEXT NOT NULL,
+    consumption_value REAL NOT NULL,
+    calculated_datetime TIMESTAMP NOT NULL
+);
+
+CREATE UNIQUE INDEX index_item_abc_class_store_id_item_id ON item_abc_class (store_id, item_id);
@@ -0,0 +1,3 @@
+-- The invoice and number tables keep the return types, rows of these types would violate the
+-- previous CHECK constraints
+ALTER TABLE invoice_line DROP COLUMN return_reason_id;
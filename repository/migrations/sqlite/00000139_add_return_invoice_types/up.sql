@@ -0,0 +1,88 @@
+-- SQLite can't change CHECK constraints, the invoice and number tables are rebuilt with the new
+-- types. The stock_movement view references the invoice table and has to be dropped while the
+-- table is replaced.
+DROP VIEW stock_movement;
+
+CREATE TABLE invoice_new (
+    id text NOT NULL PRIMARY KEY,
+    -- For outbound shipments, the id of the receiving customer.
+    -- For inbound shipments, the id of the sending supplier.
+    name_id text NOT NULL REFERENCES name(id),
+    -- For outbound shipments, the id of the issuing store.
+    -- For inbound shipments, the id of the receiving store.
+    store_id text NOT NULL REFERENCES store (id),
+    invoice_number integer NOT NULL,
+    type TEXT CHECK (type IN ('OUTBOUND_SHIPMENT', 'INBOUND_SHIPMENT', 'INVENTORY_ADJUSTMENT', 'SUPPLIER_RETURN', 'CUSTOMER_RETURN')) NOT NULL,
+    status text CHECK (status IN ('NEW','ALLOCATED', 'PICKED', 'SHIPPED',  'DELIVERED', 'VERIFIED')) NOT NULL,
+    on_hold boolean NOT NULL,
+    comment text,
+    their_reference text,
+    created_datetime text NOT NULL,
+    allocated_datetime text,
+    picked_datetime text,
+    shipped_datetime text,
+    delivered_datetime text,
+    verified_datetime text,
+    color text);
+INSERT INTO invoice_new SELECT * FROM invoice;
+DROP TABLE invoice;
+ALTER TABLE invoice_new RENAME TO invoice;
+CREATE INDEX index_invoice_store_id ON invoice (store_id);
+
+CREATE TABLE number_new (
+    id TEXT NOT NULL PRIMARY KEY,
+    -- current counter value
+    value BIGINT NOT NULL,
+    store_id TEXT NOT NULL REFERENCES store(id),
+    type TEXT CHECK (type IN ('INBOUND_SHIPMENT', 'OUTBOUND_SHIPMENT', 'INVENTORY_ADJUSTMENT', 'SUPPLIER_RETURN', 'CUSTOMER_RETURN')) NOT NULL
+);
+INSERT INTO number_new SELECT * FROM number;
+DROP TABLE number;
+ALTER TABLE number_new RENAME TO number;
+
+-- Reason of return lines, references a reason of type RETURN_REASON
+ALTER TABLE invoice_line ADD COLUMN return_reason_id TEXT REFERENCES reason(id);
+
+CREATE VIEW stock_movement AS
+SELECT
+    *
+FROM (
+    SELECT
+        invoice_line.id,
+        invoice.store_id,
+        invoice_line.item_id,
+        invoice_line.stock_line_id,
+        invoice_line.location_id,
+        invoice_line.batch,
+        invoice_line.invoice_id,
+        invoice.type AS invoice_type,
+        invoice.invoice_number,
+        invoice.name_id AS other_party_id,
+        name.name AS other_party_name,
+        CASE
+            WHEN invoice.type = 'OUTBOUND_SHIPMENT'
+                AND invoice.status IN ('PICKED', 'SHIPPED', 'DELIVERED', 'VERIFIED')
+                THEN COALESCE(invoice.picked_datetime, invoice.shipped_datetime)
+            WHEN invoice.type = 'INBOUND_SHIPMENT'
+                AND invoice.status IN ('DELIVERED', 'VERIFIED')
+                THEN COALESCE(invoice.delivered_datetime, invoice.verified_datetime)
+            WHEN invoice.type = 'INVENTORY_ADJUSTMENT'
+                AND invoice.status = 'VERIFIED'
+                THEN invoice.verified_datetime
+        END AS datetime,
+        invoice_line.pack_size,
+        invoice_line.cost_price_per_pack,
+        -- negative for stock leaving the store
+        CASE
+            WHEN invoice_line.type = 'STOCK_OUT' THEN -invoice_line.number_of_packs
+            ELSE invoice_line.number_of_packs
+        END AS number_of_packs
+    FROM
+        invoice_line
+        JOIN invoice ON invoice_line.invoice_id = invoice.id
+        JOIN name ON invoice.name_id = name.id
+    WHERE
+        invoice_line.type IN ('STOCK_IN', 'STOCK_OUT')
+) AS movement
+WHERE
+    movement.datetime IS NOT NULL;
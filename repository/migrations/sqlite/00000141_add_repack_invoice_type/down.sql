@@ -0,0 +1,2 @@
+-- The invoice and number tables keep the repack type, rows of this type would violate the
+-- previous CHECK constraints
@@ -0,0 +1,16 @@
+-- Create location_movement table.
+-- Log of stock moved between locations. Locations aren't foreign keys so the log is kept when a
+-- location is deleted.
+
+CREATE TABLE location_movement (
+    id TEXT NOT NULL PRIMARY KEY,
+    store_id TEXT NOT NULL REFERENCES store(id),
+    -- stock line holding the moved packs
+    stock_line_id TEXT NOT NULL REFERENCES stock_line(id),
+    -- set when the packs were split off another stock line
+    split_from_stock_line_id TEXT REFERENCES stock_line(id),
+    from_location_id TEXT,
+    to_location_id TEXT NOT NULL,
+    number_of_packs INTEGER NOT NULL,
+    moved_datetime TIMESTAMP NOT NULL
+);
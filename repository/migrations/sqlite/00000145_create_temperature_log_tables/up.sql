@@ -0,0 +1,40 @@
+-- Create temperature_log, temperature_breach_config and temperature_breach tables.
+-- Cold chain monitoring: readings of data loggers and sensors are logged per location, breaches
+-- of the rules configured for the location type are recorded when readings are ingested.
+-- Locations aren't foreign keys so the logs are kept when a location is deleted.
+
+CREATE TABLE temperature_log (
+    id TEXT NOT NULL PRIMARY KEY,
+    store_id TEXT NOT NULL REFERENCES store(id),
+    location_id TEXT NOT NULL,
+    -- degrees Celsius
+    temperature REAL NOT NULL,
+    datetime TIMESTAMP NOT NULL
+);
+
+CREATE INDEX index_temperature_log_location_id_datetime ON temperature_log (location_id, datetime);
+
+CREATE TABLE temperature_breach_config (
+    id TEXT NOT NULL PRIMARY KEY,
+    location_type_id TEXT NOT NULL REFERENCES location_type(id),
+    description TEXT NOT NULL,
+    -- readings below the minimum or above the maximum are out of range
+    minimum_temperature REAL,
+    maximum_temperature REAL,
+    -- readings must be out of range for at least this long to count as a breach
+    duration_minutes INTEGER NOT NULL,
+    is_active BOOLEAN NOT NULL
+);
+
+CREATE TABLE temperature_breach (
+    id TEXT NOT NULL PRIMARY KEY,
+    store_id TEXT NOT NULL REFERENCES store(id),
+    location_id TEXT NOT NULL,
+    temperature_breach_config_id TEXT NOT NULL REFERENCES temperature_breach_config(id),
+    -- first out of range reading
+    start_datetime TIMESTAMP NOT NULL,
+    -- first reading back in range, null while the breach is ongoing
+    end_datetime TIMESTAMP,
+    -- until the end or, for ongoing breaches, until the last reading
+    duration_minutes INTEGER NOT NULL
+);
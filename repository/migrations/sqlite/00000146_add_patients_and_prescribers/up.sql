@@ -0,0 +1,24 @@
+-- Add patients and prescribers.
+-- Patients are names of type PATIENT. Like other patient data they can only be edited in their
+-- home store, see the sync docs. The home store isn't a foreign key as patients can be synced
+-- before their home store.
+
+ALTER TABLE name ADD COLUMN type TEXT CHECK (type IN ('FACILITY', 'PATIENT', 'STORE', 'OTHER')) NOT NULL DEFAULT 'FACILITY';
+ALTER TABLE name ADD COLUMN first_name TEXT;
+ALTER TABLE name ADD COLUMN last_name TEXT;
+ALTER TABLE name ADD COLUMN date_of_birth TEXT;
+ALTER TABLE name ADD COLUMN phone TEXT;
+ALTER TABLE name ADD COLUMN home_store_id TEXT;
+
+CREATE INDEX index_name_type ON name (type);
+
+-- Clinicians writing prescriptions, only editable in their home store
+CREATE TABLE prescriber (
+    id TEXT NOT NULL PRIMARY KEY,
+    code TEXT NOT NULL,
+    first_name TEXT,
+    last_name TEXT NOT NULL,
+    -- e.g. the number of the medical council registration
+    registration_code TEXT,
+    home_store_id TEXT
+);
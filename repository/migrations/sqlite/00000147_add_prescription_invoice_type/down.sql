@@ -0,0 +1,3 @@
+-- The invoice and number tables keep the prescription type and the prescriber, rows of this type
+-- would violate the previous CHECK constraints
+ALTER TABLE invoice_line DROP COLUMN directions;
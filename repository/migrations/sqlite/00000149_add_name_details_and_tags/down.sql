@@ -0,0 +1,36 @@
+-- Remove contact details, type flags and tags of names.
+
+CREATE TABLE sync_out_old (
+    id TEXT NOT NULL PRIMARY KEY,
+    created_at TEXT NOT NULL,
+    table_name TEXT CHECK(table_name IN (
+        'requisition',
+        'requisition_line',
+        'item',
+        'stock_line',
+        'invoice',
+        'invoice_line',
+        'name',
+        'store')) NOT NULL,
+    record_id TEXT NOT NULL,
+    store_id TEXT NOT NULL,
+    site_id INTEGER NOT NULL,
+    action TEXT CHECK(action IN ('insert', 'update', 'delete', 'patch')) NOT NULL,
+    FOREIGN KEY(store_id) REFERENCES store(id)
+);
+INSERT INTO sync_out_old SELECT * FROM sync_out
+    WHERE table_name NOT IN ('name_tag', 'name_tag_join') AND site_id IS NOT NULL;
+DROP TABLE sync_out;
+ALTER TABLE sync_out_old RENAME TO sync_out;
+
+DROP TABLE name_tag_join;
+DROP TABLE name_tag;
+
+ALTER TABLE name DROP COLUMN comment;
+ALTER TABLE name DROP COLUMN country;
+ALTER TABLE name DROP COLUMN address2;
+ALTER TABLE name DROP COLUMN address1;
+ALTER TABLE name DROP COLUMN website;
+ALTER TABLE name DROP COLUMN email;
+ALTER TABLE name DROP COLUMN is_donor;
+ALTER TABLE name DROP COLUMN is_manufacturer;
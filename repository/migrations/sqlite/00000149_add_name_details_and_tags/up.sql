@@ -0,0 +1,53 @@
+-- Add contact details, type flags and tags of names.
+-- Names can be created locally, e.g. a new outreach clinic. Like patients they can only be edited
+-- in their home store, the store they were created in. Local names are queued in sync_out for
+-- upload to central.
+
+ALTER TABLE name ADD COLUMN is_manufacturer BOOLEAN NOT NULL DEFAULT false;
+ALTER TABLE name ADD COLUMN is_donor BOOLEAN NOT NULL DEFAULT false;
+ALTER TABLE name ADD COLUMN email TEXT;
+ALTER TABLE name ADD COLUMN website TEXT;
+ALTER TABLE name ADD COLUMN address1 TEXT;
+ALTER TABLE name ADD COLUMN address2 TEXT;
+ALTER TABLE name ADD COLUMN country TEXT;
+ALTER TABLE name ADD COLUMN comment TEXT;
+
+-- Tags (categories) of names, e.g. "Health centre" or "District A"
+CREATE TABLE name_tag (
+    id TEXT NOT NULL PRIMARY KEY,
+    name TEXT NOT NULL UNIQUE
+);
+
+CREATE TABLE name_tag_join (
+    id TEXT NOT NULL PRIMARY KEY,
+    name_id TEXT NOT NULL REFERENCES name(id),
+    name_tag_id TEXT NOT NULL REFERENCES name_tag(id)
+);
+
+CREATE INDEX index_name_tag_join_name_id ON name_tag_join (name_id);
+
+-- SQLite can't change CHECK constraints, sync_out is rebuilt with the name tag tables. Records
+-- are uploaded to central, site_id is only needed for records pushed to other sites.
+CREATE TABLE sync_out_new (
+    id TEXT NOT NULL PRIMARY KEY,
+    created_at TEXT NOT NULL,
+    table_name TEXT CHECK(table_name IN (
+        'requisition',
+        'requisition_line',
+        'item',
+        'stock_line',
+        'invoice',
+        'invoice_line',
+        'name',
+        'store',
+        'name_tag',
+        'name_tag_join')) NOT NULL,
+    record_id TEXT NOT NULL,
+    store_id TEXT NOT NULL,
+    site_id INTEGER,
+    action TEXT CHECK(action IN ('insert', 'update', 'delete', 'patch')) NOT NULL,
+    FOREIGN KEY(store_id) REFERENCES store(id)
+);
+INSERT INTO sync_out_new SELECT * FROM sync_out;
+DROP TABLE sync_out;
+ALTER TABLE sync_out_new RENAME TO sync_out;
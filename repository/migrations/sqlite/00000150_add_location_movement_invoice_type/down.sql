@@ -0,0 +1,2 @@
+-- The invoice and number tables keep the location movement type, rows of this type would violate
+-- the previous CHECK constraints
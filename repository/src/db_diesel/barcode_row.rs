@@ -0,0 +1,70 @@
+use super::StorageConnection;
+
+use crate::repository_error::RepositoryError;
+use crate::schema::diesel_schema::barcode::dsl as barcode_dsl;
+use crate::schema::BarcodeRow;
+
+use diesel::prelude::*;
+
+pub struct BarcodeRowRepository<'a> {
+    connection: &'a StorageConnection,
+}
+
+impl<'a> BarcodeRowRepository<'a> {
+    pub fn new(connection: &'a StorageConnection) -> Self {
+        BarcodeRowRepository { connection }
+    }
+
+    #[cfg(feature = "postgres")]
+    pub fn upsert_one(&self, row: &BarcodeRow) -> Result<(), RepositoryError> {
+        diesel::insert_into(barcode_dsl::barcode)
+            .values(row)
+            .on_conflict(barcode_dsl::id)
+            .do_update()
+            .set(row)
+            .execute(&self.connection.connection)?;
+        Ok(())
+    }
+
+    #[cfg(not(feature = "postgres"))]
+    pub fn upsert_one(&self, row: &BarcodeRow) -> Result<(), RepositoryError> {
+        diesel::replace_into(barcode_dsl::barcode)
+            .values(row)
+            .execute(&self.connection.connection)?;
+        Ok(())
+    }
+
+    pub fn find_one_by_id(&self, id: &str) -> Result<Option<BarcodeRow>, RepositoryError> {
+        let result = barcode_dsl::barcode
+            .filter(barcode_dsl::id.eq(id))
+            .first(&self.connection.connection)
+            .optional()?;
+        Ok(result)
+    }
+
+    /// Barcodes matching any of the values, ordered by id
+    pub fn find_many_by_values(
+        &self,
+        values: &[String],
+    ) -> Result<Vec<BarcodeRow>, RepositoryError> {
+        let result = barcode_dsl::barcode
+            .filter(barcode_dsl::value.eq_any(values))
+            .order(barcode_dsl::id.asc())
+            .load(&self.connection.connection)?;
+        Ok(result)
+    }
+
+    pub fn find_many_by_item_id(&self, item_id: &str) -> Result<Vec<BarcodeRow>, RepositoryError> {
+        let result = barcode_dsl::barcode
+            .filter(barcode_dsl::item_id.eq(item_id))
+            .order(barcode_dsl::value.asc())
+            .load(&self.connection.connection)?;
+        Ok(result)
+    }
+
+    pub fn delete(&self, id: &str) -> Result<(), RepositoryError> {
+        diesel::delete(barcode_dsl::barcode.filter(barcode_dsl::id.eq(id)))
+            .execute(&self.connection.connection)?;
+        Ok(())
+    }
+}
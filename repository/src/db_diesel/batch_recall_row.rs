@@ -0,0 +1,102 @@
+use super::StorageConnection;
+
+use crate::repository_error::RepositoryError;
+use crate::schema::diesel_schema::{
+    batch_recall::dsl as batch_recall_dsl, batch_recall_line::dsl as batch_recall_line_dsl,
+};
+use crate::schema::{BatchRecallLineRow, BatchRecallRow};
+
+use diesel::prelude::*;
+
+pub struct BatchRecallRowRepository<'a> {
+    connection: &'a StorageConnection,
+}
+
+impl<'a> BatchRecallRowRepository<'a> {
+    pub fn new(connection: &'a StorageConnection) -> Self {
+        BatchRecallRowRepository { connection }
+    }
+
+    #[cfg(feature = "postgres")]
+    pub fn upsert_one(&self, row: &BatchRecallRow) -> Result<(), RepositoryError> {
+        diesel::insert_into(batch_recall_dsl::batch_recall)
+            .values(row)
+            .on_conflict(batch_recall_dsl::id)
+            .do_update()
+            .set(row)
+            .execute(&self.connection.connection)?;
+        Ok(())
+    }
+
+    #[cfg(not(feature = "postgres"))]
+    pub fn upsert_one(&self, row: &BatchRecallRow) -> Result<(), RepositoryError> {
+        diesel::replace_into(batch_recall_dsl::batch_recall)
+            .values(row)
+            .execute(&self.connection.connection)?;
+        Ok(())
+    }
+
+    pub fn find_one_by_id(&self, id: &str) -> Result<Option<BatchRecallRow>, RepositoryError> {
+        let result = batch_recall_dsl::batch_recall
+            .filter(batch_recall_dsl::id.eq(id))
+            .first(&self.connection.connection)
+            .optional()?;
+        Ok(result)
+    }
+
+    /// Recalls of the store that include any of the stock lines, oldest first
+    pub fn find_many_by_stock_line_ids(
+        &self,
+        store_id: &str,
+        stock_line_ids: &[String],
+    ) -> Result<Vec<BatchRecallRow>, RepositoryError> {
+        let recall_ids = batch_recall_line_dsl::batch_recall_line
+            .filter(batch_recall_line_dsl::stock_line_id.eq_any(stock_line_ids))
+            .select(batch_recall_line_dsl::batch_recall_id);
+        let result = batch_recall_dsl::batch_recall
+            .filter(batch_recall_dsl::store_id.eq(store_id))
+            .filter(batch_recall_dsl::id.eq_any(recall_ids))
+            .order(batch_recall_dsl::created_datetime.asc())
+            .load(&self.connection.connection)?;
+        Ok(result)
+    }
+}
+
+pub struct BatchRecallLineRowRepository<'a> {
+    connection: &'a StorageConnection,
+}
+
+impl<'a> BatchRecallLineRowRepository<'a> {
+    pub fn new(connection: &'a StorageConnection) -> Self {
+        BatchRecallLineRowRepository { connection }
+    }
+
+    #[cfg(feature = "postgres")]
+    pub fn upsert_one(&self, row: &BatchRecallLineRow) -> Result<(), RepositoryError> {
+        diesel::insert_into(batch_recall_line_dsl::batch_recall_line)
+            .values(row)
+            .on_conflict(batch_recall_line_dsl::id)
+            .do_update()
+            .set(row)
+            .execute(&self.connection.connection)?;
+        Ok(())
+    }
+
+    #[cfg(not(feature = "postgres"))]
+    pub fn upsert_one(&self, row: &BatchRecallLineRow) -> Result<(), RepositoryError> {
+        diesel::replace_into(batch_recall_line_dsl::batch_recall_line)
+            .values(row)
+            .execute(&self.connection.connection)?;
+        Ok(())
+    }
+
+    pub fn find_many_by_batch_recall_id(
+        &self,
+        batch_recall_id: &str,
+    ) -> Result<Vec<BatchRecallLineRow>, RepositoryError> {
+        let result = batch_recall_line_dsl::batch_recall_line
+            .filter(batch_recall_line_dsl::batch_recall_id.eq(batch_recall_id))
+            .load(&self.connection.connection)?;
+        Ok(result)
+    }
+}
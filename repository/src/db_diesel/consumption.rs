@@ -0,0 +1,120 @@
+use chrono::NaiveDateTime;
+
+use crate::{
+    schema::{
+        diesel_schema::{invoice::dsl as invoice_dsl, invoice_line::dsl as invoice_line_dsl},
+        InvoiceLineRowType, InvoiceRowType,
+    },
+    RepositoryError, StorageConnection,
+};
+
+use diesel::{
+    dsl::sql,
+    prelude::*,
+    sql_types::{BigInt, Double, Nullable},
+};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ItemConsumption {
+    pub item_id: String,
+    /// Number of units (packs * pack size)
+    pub quantity: i64,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ItemConsumptionValue {
+    pub item_id: String,
+    /// Cost value of the issued stock (packs * cost price per pack)
+    pub value: f64,
+}
+
+/// Stock issued to customers and patients, i.e. outbound shipment lines of shipped invoices and
+/// prescription lines of dispensed (picked) prescriptions
+pub struct ConsumptionRepository<'a> {
+    connection: &'a StorageConnection,
+}
+
+impl<'a> ConsumptionRepository<'a> {
+    pub fn new(connection: &'a StorageConnection) -> Self {
+        ConsumptionRepository { connection }
+    }
+
+    /// Total units issued per item from the store between `from` and `to` (inclusive).
+    /// Items without any consumption in the period are not returned.
+    pub fn query_by_item(
+        &self,
+        store_id: &str,
+        item_ids: Vec<String>,
+        from: NaiveDateTime,
+        to: NaiveDateTime,
+    ) -> Result<Vec<ItemConsumption>, RepositoryError> {
+        let result = invoice_line_dsl::invoice_line
+            .inner_join(invoice_dsl::invoice)
+            .filter(invoice_dsl::store_id.eq(store_id))
+            .filter(
+                invoice_dsl::type_
+                    .eq(InvoiceRowType::OutboundShipment)
+                    .and(invoice_dsl::shipped_datetime.between(from, to))
+                    .or(invoice_dsl::type_
+                        .eq(InvoiceRowType::Prescription)
+                        .and(invoice_dsl::picked_datetime.between(from, to))),
+            )
+            .filter(invoice_line_dsl::type_.eq(InvoiceLineRowType::StockOut))
+            .filter(invoice_line_dsl::item_id.eq_any(item_ids))
+            .group_by(invoice_line_dsl::item_id)
+            .select((
+                invoice_line_dsl::item_id,
+                // diesel doesn't allow mixing aggregate and non aggregate expressions in a select
+                sql::<Nullable<BigInt>>(
+                    "SUM(invoice_line.number_of_packs * invoice_line.pack_size)",
+                ),
+            ))
+            .load::<(String, Option<i64>)>(&self.connection.connection)?;
+
+        Ok(result
+            .into_iter()
+            .map(|(item_id, quantity)| ItemConsumption {
+                item_id,
+                quantity: quantity.unwrap_or(0),
+            })
+            .collect())
+    }
+
+    /// Cost value of the stock issued per item from the store between `from` and `to`
+    /// (inclusive). Items without any consumption in the period are not returned.
+    pub fn query_value_by_item(
+        &self,
+        store_id: &str,
+        from: NaiveDateTime,
+        to: NaiveDateTime,
+    ) -> Result<Vec<ItemConsumptionValue>, RepositoryError> {
+        let result = invoice_line_dsl::invoice_line
+            .inner_join(invoice_dsl::invoice)
+            .filter(invoice_dsl::store_id.eq(store_id))
+            .filter(
+                invoice_dsl::type_
+                    .eq(InvoiceRowType::OutboundShipment)
+                    .and(invoice_dsl::shipped_datetime.between(from, to))
+                    .or(invoice_dsl::type_
+                        .eq(InvoiceRowType::Prescription)
+                        .and(invoice_dsl::picked_datetime.between(from, to))),
+            )
+            .filter(invoice_line_dsl::type_.eq(InvoiceLineRowType::StockOut))
+            .group_by(invoice_line_dsl::item_id)
+            .select((
+                invoice_line_dsl::item_id,
+                sql::<Nullable<Double>>(
+                    "SUM(invoice_line.number_of_packs * invoice_line.cost_price_per_pack)",
+                ),
+            ))
+            .load::<(String, Option<f64>)>(&self.connection.connection)?;
+
+        Ok(result
+            .into_iter()
+            .map(|(item_id, value)| ItemConsumptionValue {
+                item_id,
+                value: value.unwrap_or(0.0),
+            })
+            .collect())
+    }
+}
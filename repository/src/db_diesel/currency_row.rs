@@ -0,0 +1,44 @@
+use super::StorageConnection;
+
+use crate::repository_error::RepositoryError;
+use crate::schema::diesel_schema::currency::dsl as currency_dsl;
+use crate::schema::CurrencyRow;
+
+use diesel::prelude::*;
+
+pub struct CurrencyRowRepository<'a> {
+    connection: &'a StorageConnection,
+}
+
+impl<'a> CurrencyRowRepository<'a> {
+    pub fn new(connection: &'a StorageConnection) -> Self {
+        CurrencyRowRepository { connection }
+    }
+
+    #[cfg(feature = "postgres")]
+    pub fn upsert_one(&self, row: &CurrencyRow) -> Result<(), RepositoryError> {
+        diesel::insert_into(currency_dsl::currency)
+            .values(row)
+            .on_conflict(currency_dsl::id)
+            .do_update()
+            .set(row)
+            .execute(&self.connection.connection)?;
+        Ok(())
+    }
+
+    #[cfg(not(feature = "postgres"))]
+    pub fn upsert_one(&self, row: &CurrencyRow) -> Result<(), RepositoryError> {
+        diesel::replace_into(currency_dsl::currency)
+            .values(row)
+            .execute(&self.connection.connection)?;
+        Ok(())
+    }
+
+    pub fn find_one_by_id(&self, id: &str) -> Result<Option<CurrencyRow>, RepositoryError> {
+        let result = currency_dsl::currency
+            .filter(currency_dsl::id.eq(id))
+            .first(&self.connection.connection)
+            .optional()?;
+        Ok(result)
+    }
+}
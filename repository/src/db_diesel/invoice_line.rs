@@ -153,6 +153,8 @@ fn to_domain((invoice_line, location_row_option): InvoiceLineJoin) -> InvoiceLin
         batch: invoice_line.batch,
         expiry_date: invoice_line.expiry_date,
         note: invoice_line.note,
+        return_reason_id: invoice_line.return_reason_id,
+        directions: invoice_line.directions,
         location_name: location_row_option.map(|location_row| location_row.name),
     }
 }
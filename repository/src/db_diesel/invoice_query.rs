@@ -44,6 +44,11 @@ impl From<InvoiceRowType> for InvoiceType {
             InvoiceRowType::OutboundShipment => OutboundShipment,
             InvoiceRowType::InboundShipment => InboundShipment,
             InvoiceRowType::InventoryAdjustment => InventoryAdjustment,
+            InvoiceRowType::SupplierReturn => SupplierReturn,
+            InvoiceRowType::CustomerReturn => CustomerReturn,
+            InvoiceRowType::Repack => Repack,
+            InvoiceRowType::Prescription => Prescription,
+            InvoiceRowType::LocationMovement => LocationMovement,
         }
     }
 }
@@ -69,6 +74,11 @@ impl From<InvoiceType> for InvoiceRowType {
             InvoiceType::OutboundShipment => OutboundShipment,
             InvoiceType::InboundShipment => InboundShipment,
             InvoiceType::InventoryAdjustment => InventoryAdjustment,
+            InvoiceType::SupplierReturn => SupplierReturn,
+            InvoiceType::CustomerReturn => CustomerReturn,
+            InvoiceType::Repack => Repack,
+            InvoiceType::Prescription => Prescription,
+            InvoiceType::LocationMovement => LocationMovement,
         }
     }
 }
@@ -140,9 +150,8 @@ impl<'a> InvoiceQueryRepository<'a> {
                     apply_sort_no_case!(query, sort, invoice_dsl::comment);
                 }
             }
-        } else {
-            query = query.order(invoice_dsl::id.asc())
         }
+        query = query.then_order_by(invoice_dsl::id.asc());
 
         let result = query
             .offset(pagination.offset as i64)
@@ -179,6 +188,7 @@ fn to_domain((invoice_row, name_row, _store_row): InvoiceQueryJoin) -> Invoice {
         delivered_datetime: invoice_row.delivered_datetime,
         verified_datetime: invoice_row.verified_datetime,
         color: invoice_row.color,
+        prescriber_id: invoice_row.prescriber_id,
     }
 }
 
@@ -204,12 +214,22 @@ pub fn create_filtered_query<'a>(filter: Option<InvoiceFilter>) -> BoxedInvoiceQ
                 let eq = InvoiceRowType::from(eq.clone());
                 query = query.filter(invoice_dsl::type_.eq(eq));
             }
+            if let Some(eq_any) = value.equal_any {
+                let eq_any: Vec<InvoiceRowType> =
+                    eq_any.into_iter().map(InvoiceRowType::from).collect();
+                query = query.filter(invoice_dsl::type_.eq_any(eq_any));
+            }
         }
         if let Some(value) = f.status {
             if let Some(eq) = value.equal_to {
                 let eq = InvoiceRowStatus::from(eq.clone());
                 query = query.filter(invoice_dsl::status.eq(eq));
             }
+            if let Some(eq_any) = value.equal_any {
+                let eq_any: Vec<InvoiceRowStatus> =
+                    eq_any.into_iter().map(InvoiceRowStatus::from).collect();
+                query = query.filter(invoice_dsl::status.eq_any(eq_any));
+            }
         }
 
         apply_date_time_filter!(query, f.created_datetime, invoice_dsl::created_datetime);
@@ -0,0 +1,59 @@
+use super::StorageConnection;
+
+use crate::repository_error::RepositoryError;
+use crate::schema::diesel_schema::item_abc_class::dsl as item_abc_class_dsl;
+use crate::schema::ItemAbcClassRow;
+
+use diesel::prelude::*;
+
+pub struct ItemAbcClassRowRepository<'a> {
+    connection: &'a StorageConnection,
+}
+
+impl<'a> ItemAbcClassRowRepository<'a> {
+    pub fn new(connection: &'a StorageConnection) -> Self {
+        ItemAbcClassRowRepository { connection }
+    }
+
+    #[cfg(feature = "postgres")]
+    pub fn upsert_one(&self, row: &ItemAbcClassRow) -> Result<(), RepositoryError> {
+        diesel::insert_into(item_abc_class_dsl::item_abc_class)
+            .values(row)
+            .on_conflict(item_abc_class_dsl::id)
+            .do_update()
+            .set(row)
+            .execute(&self.connection.connection)?;
+        Ok(())
+    }
+
+    #[cfg(not(feature = "postgres"))]
+    pub fn upsert_one(&self, row: &ItemAbcClassRow) -> Result<(), RepositoryError> {
+        diesel::replace_into(item_abc_class_dsl::item_abc_class)
+            .values(row)
+            .execute(&self.connection.connection)?;
+        Ok(())
+    }
+
+    /// Classes of the items of a store ordered by consumption value, highest first
+    pub fn find_many_by_store_id(
+        &self,
+        store_id: &str,
+    ) -> Result<Vec<ItemAbcClassRow>, RepositoryError> {
+        let result = item_abc_class_dsl::item_abc_class
+            .filter(item_abc_class_dsl::store_id.eq(store_id))
+            .order((
+                item_abc_class_dsl::consumption_value.desc(),
+                item_abc_class_dsl::item_id.asc(),
+            ))
+            .load(&self.connection.connection)?;
+        Ok(result)
+    }
+
+    pub fn delete_by_store_id(&self, store_id: &str) -> Result<(), RepositoryError> {
+        diesel::delete(
+            item_abc_class_dsl::item_abc_class.filter(item_abc_class_dsl::store_id.eq(store_id)),
+        )
+        .execute(&self.connection.connection)?;
+        Ok(())
+    }
+}
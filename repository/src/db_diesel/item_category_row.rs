@@ -0,0 +1,52 @@
+use super::StorageConnection;
+
+use crate::repository_error::RepositoryError;
+use crate::schema::diesel_schema::item_category::dsl as item_category_dsl;
+use crate::schema::ItemCategoryRow;
+
+use diesel::prelude::*;
+
+pub struct ItemCategoryRowRepository<'a> {
+    connection: &'a StorageConnection,
+}
+
+impl<'a> ItemCategoryRowRepository<'a> {
+    pub fn new(connection: &'a StorageConnection) -> Self {
+        ItemCategoryRowRepository { connection }
+    }
+
+    #[cfg(feature = "postgres")]
+    pub fn upsert_one(&self, row: &ItemCategoryRow) -> Result<(), RepositoryError> {
+        diesel::insert_into(item_category_dsl::item_category)
+            .values(row)
+            .on_conflict(item_category_dsl::id)
+            .do_update()
+            .set(row)
+            .execute(&self.connection.connection)?;
+        Ok(())
+    }
+
+    #[cfg(not(feature = "postgres"))]
+    pub fn upsert_one(&self, row: &ItemCategoryRow) -> Result<(), RepositoryError> {
+        diesel::replace_into(item_category_dsl::item_category)
+            .values(row)
+            .execute(&self.connection.connection)?;
+        Ok(())
+    }
+
+    pub fn find_one_by_id(&self, id: &str) -> Result<Option<ItemCategoryRow>, RepositoryError> {
+        let result = item_category_dsl::item_category
+            .filter(item_category_dsl::id.eq(id))
+            .first(&self.connection.connection)
+            .optional()?;
+        Ok(result)
+    }
+
+    /// All categories ordered by name
+    pub fn find_all(&self) -> Result<Vec<ItemCategoryRow>, RepositoryError> {
+        let result = item_category_dsl::item_category
+            .order(item_category_dsl::name.asc())
+            .load(&self.connection.connection)?;
+        Ok(result)
+    }
+}
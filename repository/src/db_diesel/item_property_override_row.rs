@@ -0,0 +1,81 @@
+use super::StorageConnection;
+
+use crate::repository_error::RepositoryError;
+use crate::schema::diesel_schema::{
+    item_property::dsl as item_property_dsl,
+    item_property_override::dsl as item_property_override_dsl,
+};
+use crate::schema::{ItemPropertyOverrideRow, ItemPropertyRow};
+
+use diesel::prelude::*;
+
+pub struct ItemPropertyOverrideRowRepository<'a> {
+    connection: &'a StorageConnection,
+}
+
+impl<'a> ItemPropertyOverrideRowRepository<'a> {
+    pub fn new(connection: &'a StorageConnection) -> Self {
+        ItemPropertyOverrideRowRepository { connection }
+    }
+
+    #[cfg(feature = "postgres")]
+    pub fn upsert_one(&self, row: &ItemPropertyOverrideRow) -> Result<(), RepositoryError> {
+        diesel::insert_into(item_property_override_dsl::item_property_override)
+            .values(row)
+            .on_conflict(item_property_override_dsl::id)
+            .do_update()
+            .set(row)
+            .execute(&self.connection.connection)?;
+        Ok(())
+    }
+
+    #[cfg(not(feature = "postgres"))]
+    pub fn upsert_one(&self, row: &ItemPropertyOverrideRow) -> Result<(), RepositoryError> {
+        diesel::replace_into(item_property_override_dsl::item_property_override)
+            .values(row)
+            .execute(&self.connection.connection)?;
+        Ok(())
+    }
+
+    pub fn find_one_by_item_id(
+        &self,
+        item_id: &str,
+    ) -> Result<Option<ItemPropertyOverrideRow>, RepositoryError> {
+        let result = item_property_override_dsl::item_property_override
+            .filter(item_property_override_dsl::item_id.eq(item_id))
+            .first(&self.connection.connection)
+            .optional()?;
+        Ok(result)
+    }
+
+    pub fn delete_by_item_id(&self, item_id: &str) -> Result<(), RepositoryError> {
+        diesel::delete(
+            item_property_override_dsl::item_property_override
+                .filter(item_property_override_dsl::item_id.eq(item_id)),
+        )
+        .execute(&self.connection.connection)?;
+        Ok(())
+    }
+}
+
+/// Reads the item_property view
+pub struct ItemPropertyRepository<'a> {
+    connection: &'a StorageConnection,
+}
+
+impl<'a> ItemPropertyRepository<'a> {
+    pub fn new(connection: &'a StorageConnection) -> Self {
+        ItemPropertyRepository { connection }
+    }
+
+    pub fn find_one_by_item_id(
+        &self,
+        item_id: &str,
+    ) -> Result<Option<ItemPropertyRow>, RepositoryError> {
+        let result = item_property_dsl::item_property
+            .filter(item_property_dsl::item_id.eq(item_id))
+            .first(&self.connection.connection)
+            .optional()?;
+        Ok(result)
+    }
+}
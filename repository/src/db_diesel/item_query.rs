@@ -1,17 +1,20 @@
-use super::{DBType, StorageConnection};
+use super::{DBType, ItemCategoryRowRepository, StorageConnection};
 use crate::{
     diesel_macros::{apply_equal_filter, apply_simple_string_filter, apply_sort_no_case},
     repository_error::RepositoryError,
     schema::{
         diesel_schema::{
-            item, item::dsl as item_dsl, item_is_visible,
-            item_is_visible::dsl as item_is_visible_dsl, unit, unit::dsl as unit_dsl,
+            item, item::dsl as item_dsl, item_abc_class, item_abc_class::dsl as item_abc_class_dsl,
+            item_is_visible_in_store,
+            item_is_visible_in_store::dsl as item_is_visible_in_store_dsl, item_property,
+            item_property::dsl as item_property_dsl, unit, unit::dsl as unit_dsl,
         },
-        ItemIsVisibleRow, ItemRow, ItemRowType, UnitRow,
+        ItemAbcClassRow, ItemAbcClassRowClass, ItemIsVisibleInStoreRow, ItemPropertyRow, ItemRow,
+        ItemRowType, ItemRowVenCategory, UnitRow,
     },
 };
 use domain::{
-    item::{Item, ItemSort, ItemSortField},
+    item::{AbcClass, Item, ItemSort, ItemSortField, VenCategory},
     EqualFilter, Pagination, SimpleStringFilter,
 };
 
@@ -21,8 +24,15 @@ pub struct ItemFilter {
     pub name: Option<SimpleStringFilter>,
     pub code: Option<SimpleStringFilter>,
     pub r#type: Option<EqualFilter<ItemRowType>>,
-    /// If true it only returns ItemAndMasterList that have a name join row
+    /// If true it only returns items which are visible in the store of the query
     pub is_visible: Option<bool>,
+    pub category_id: Option<EqualFilter<String>>,
+    pub strength: Option<SimpleStringFilter>,
+    pub dosage_form: Option<SimpleStringFilter>,
+    pub ven_category: Option<EqualFilter<VenCategory>>,
+    pub is_controlled: Option<bool>,
+    /// ABC class in the store of the query
+    pub abc_class: Option<EqualFilter<AbcClass>>,
 }
 
 impl ItemFilter {
@@ -33,6 +43,12 @@ impl ItemFilter {
             code: None,
             r#type: None,
             is_visible: None,
+            category_id: None,
+            strength: None,
+            dosage_form: None,
+            ven_category: None,
+            is_controlled: None,
+            abc_class: None,
         }
     }
 
@@ -60,14 +76,81 @@ impl ItemFilter {
         self.is_visible = Some(value);
         self
     }
+
+    pub fn category_id(mut self, filter: EqualFilter<String>) -> Self {
+        self.category_id = Some(filter);
+        self
+    }
+
+    pub fn ven_category(mut self, filter: EqualFilter<VenCategory>) -> Self {
+        self.ven_category = Some(filter);
+        self
+    }
+
+    pub fn match_is_controlled(mut self, value: bool) -> Self {
+        self.is_controlled = Some(value);
+        self
+    }
+
+    pub fn abc_class(mut self, filter: EqualFilter<AbcClass>) -> Self {
+        self.abc_class = Some(filter);
+        self
+    }
+}
+
+impl From<ItemRowVenCategory> for VenCategory {
+    fn from(category: ItemRowVenCategory) -> Self {
+        match category {
+            ItemRowVenCategory::Vital => VenCategory::Vital,
+            ItemRowVenCategory::Essential => VenCategory::Essential,
+            ItemRowVenCategory::NonEssential => VenCategory::NonEssential,
+        }
+    }
+}
+
+impl From<VenCategory> for ItemRowVenCategory {
+    fn from(category: VenCategory) -> Self {
+        match category {
+            VenCategory::Vital => ItemRowVenCategory::Vital,
+            VenCategory::Essential => ItemRowVenCategory::Essential,
+            VenCategory::NonEssential => ItemRowVenCategory::NonEssential,
+        }
+    }
+}
+
+impl From<ItemAbcClassRowClass> for AbcClass {
+    fn from(class: ItemAbcClassRowClass) -> Self {
+        match class {
+            ItemAbcClassRowClass::A => AbcClass::A,
+            ItemAbcClassRowClass::B => AbcClass::B,
+            ItemAbcClassRowClass::C => AbcClass::C,
+        }
+    }
+}
+
+impl From<AbcClass> for ItemAbcClassRowClass {
+    fn from(class: AbcClass) -> Self {
+        match class {
+            AbcClass::A => ItemAbcClassRowClass::A,
+            AbcClass::B => ItemAbcClassRowClass::B,
+            AbcClass::C => ItemAbcClassRowClass::C,
+        }
+    }
 }
 
 use diesel::{
-    dsl::{InnerJoin, IntoBoxed, LeftJoin},
+    dsl::{And, Eq, InnerJoin, IntoBoxed, LeftJoin},
     prelude::*,
+    query_source::joins::OnClauseWrapper,
 };
 
-type ItemAndMasterList = (ItemRow, ItemIsVisibleRow, Option<UnitRow>);
+type ItemAndMasterList = (
+    ItemRow,
+    Option<ItemIsVisibleInStoreRow>,
+    Option<UnitRow>,
+    ItemPropertyRow,
+    Option<ItemAbcClassRow>,
+);
 
 pub struct ItemQueryRepository<'a> {
     connection: &'a StorageConnection,
@@ -78,24 +161,38 @@ impl<'a> ItemQueryRepository<'a> {
         ItemQueryRepository { connection }
     }
 
-    pub fn count(&self, filter: Option<ItemFilter>) -> Result<i64, RepositoryError> {
-        // TODO (beyond M1), check that store_id matches current store
-        let query = create_filtered_query(filter);
+    /// # Arguments
+    /// * store_id the store for which the visibility of items is evaluated
+    pub fn count(
+        &self,
+        store_id: &str,
+        filter: Option<ItemFilter>,
+    ) -> Result<i64, RepositoryError> {
+        let filter = self.include_sub_categories(filter)?;
+        let query = create_filtered_query(store_id, filter);
 
         Ok(query.count().get_result(&self.connection.connection)?)
     }
 
-    pub fn query_by_filter(&self, filter: ItemFilter) -> Result<Vec<Item>, RepositoryError> {
-        self.query(Pagination::new(), Some(filter), None)
+    pub fn query_by_filter(
+        &self,
+        store_id: &str,
+        filter: ItemFilter,
+    ) -> Result<Vec<Item>, RepositoryError> {
+        self.query(store_id, Pagination::new(), Some(filter), None)
     }
 
+    /// # Arguments
+    /// * store_id the store for which the visibility of items is evaluated
     pub fn query(
         &self,
+        store_id: &str,
         pagination: Pagination,
         filter: Option<ItemFilter>,
         sort: Option<ItemSort>,
     ) -> Result<Vec<Item>, RepositoryError> {
-        let mut query = create_filtered_query(filter);
+        let filter = self.include_sub_categories(filter)?;
+        let mut query = create_filtered_query(store_id, filter);
 
         if let Some(sort) = sort {
             match sort.key {
@@ -106,9 +203,8 @@ impl<'a> ItemQueryRepository<'a> {
                     apply_sort_no_case!(query, sort, item_dsl::code);
                 }
             }
-        } else {
-            query = query.order(item_dsl::id.asc())
         }
+        query = query.then_order_by(item_dsl::id.asc());
 
         let result = query
             .offset(pagination.offset as i64)
@@ -117,29 +213,130 @@ impl<'a> ItemQueryRepository<'a> {
 
         Ok(result.into_iter().map(to_domain).collect())
     }
+
+    /// Items of a category are matched by the category and all its sub categories
+    fn include_sub_categories(
+        &self,
+        filter: Option<ItemFilter>,
+    ) -> Result<Option<ItemFilter>, RepositoryError> {
+        let mut filter = match filter {
+            Some(filter) => filter,
+            None => return Ok(None),
+        };
+        let category_filter = match filter.category_id.take() {
+            Some(category_filter) => category_filter,
+            None => return Ok(Some(filter)),
+        };
+
+        let mut category_ids: Vec<String> = category_filter
+            .equal_to
+            .iter()
+            .chain(category_filter.equal_any.iter().flatten())
+            .cloned()
+            .collect();
+        if category_ids.is_empty() {
+            filter.category_id = Some(category_filter);
+            return Ok(Some(filter));
+        }
+
+        let categories = ItemCategoryRowRepository::new(self.connection).find_all()?;
+        // categories are added while iterating, the contains check also guards against cycles
+        let mut index = 0;
+        while index < category_ids.len() {
+            for category in &categories {
+                if category.parent_id.as_ref() == Some(&category_ids[index])
+                    && !category_ids.contains(&category.id)
+                {
+                    category_ids.push(category.id.clone());
+                }
+            }
+            index += 1;
+        }
+
+        filter.category_id = Some(EqualFilter {
+            equal_to: None,
+            not_equal_to: category_filter.not_equal_to,
+            equal_any: Some(category_ids),
+        });
+        Ok(Some(filter))
+    }
 }
 
-fn to_domain((item_row, item_is_visible_row, unit_row_option): ItemAndMasterList) -> Item {
+fn to_domain(
+    (item_row, item_is_visible_row, unit_row_option, property_row, abc_class_row): ItemAndMasterList,
+) -> Item {
     Item {
         id: item_row.id,
         name: item_row.name,
         code: item_row.code,
-        is_visible: item_is_visible_row.is_visible,
+        is_visible: item_is_visible_row.is_some(),
         unit_name: unit_row_option.map(|unit| unit.name),
+        category_id: property_row.category_id,
+        strength: property_row.strength,
+        dosage_form: property_row.dosage_form,
+        ven_category: property_row.ven_category.map(VenCategory::from),
+        is_controlled: property_row.is_controlled,
+        storage_requirement: property_row.storage_requirement,
+        abc_class: abc_class_row.map(|row| AbcClass::from(row.class)),
+    }
+}
+
+/// Converts a filter on domain values to a filter on the values stored in the DB
+fn to_row_filter<T, R: From<T>>(filter: EqualFilter<T>) -> EqualFilter<R> {
+    EqualFilter {
+        equal_to: filter.equal_to.map(R::from),
+        not_equal_to: filter.not_equal_to.map(R::from),
+        equal_any: filter
+            .equal_any
+            .map(|values| values.into_iter().map(R::from).collect()),
     }
 }
 
+type ItemIsVisibleInStoreOn = And<
+    Eq<item_is_visible_in_store::item_id, item::id>,
+    Eq<item_is_visible_in_store::store_id, String>,
+>;
+
+type ItemAbcClassOn =
+    And<Eq<item_abc_class::item_id, item::id>, Eq<item_abc_class::store_id, String>>;
+
 type BoxedItemQuery = IntoBoxed<
     'static,
-    LeftJoin<InnerJoin<item::table, item_is_visible::table>, unit::table>,
+    LeftJoin<
+        InnerJoin<
+            LeftJoin<
+                LeftJoin<
+                    item::table,
+                    OnClauseWrapper<item_is_visible_in_store::table, ItemIsVisibleInStoreOn>,
+                >,
+                unit::table,
+            >,
+            item_property::table,
+        >,
+        OnClauseWrapper<item_abc_class::table, ItemAbcClassOn>,
+    >,
     DBType,
 >;
 
-pub fn create_filtered_query(filter: Option<ItemFilter>) -> BoxedItemQuery {
-    // Join master_list_line
+pub fn create_filtered_query(store_id: &str, filter: Option<ItemFilter>) -> BoxedItemQuery {
+    // Join the visibility of the item in the requested store
     let mut query = item_dsl::item
-        .inner_join(item_is_visible_dsl::item_is_visible)
+        .left_join(
+            item_is_visible_in_store_dsl::item_is_visible_in_store.on(
+                item_is_visible_in_store_dsl::item_id
+                    .eq(item_dsl::id)
+                    .and(item_is_visible_in_store_dsl::store_id.eq(store_id.to_string())),
+            ),
+        )
         .left_join(unit_dsl::unit)
+        // Properties with the local overrides applied
+        .inner_join(item_property_dsl::item_property)
+        // ABC class of the item in the requested store
+        .left_join(
+            item_abc_class_dsl::item_abc_class.on(item_abc_class_dsl::item_id
+                .eq(item_dsl::id)
+                .and(item_abc_class_dsl::store_id.eq(store_id.to_string()))),
+        )
         .into_boxed();
 
     if let Some(f) = filter {
@@ -147,9 +344,27 @@ pub fn create_filtered_query(filter: Option<ItemFilter>) -> BoxedItemQuery {
         apply_simple_string_filter!(query, f.code, item_dsl::code);
         apply_simple_string_filter!(query, f.name, item_dsl::name);
         apply_equal_filter!(query, f.r#type, item_dsl::type_);
+        apply_equal_filter!(query, f.category_id, item_property_dsl::category_id);
+        apply_simple_string_filter!(query, f.strength, item_property_dsl::strength);
+        apply_simple_string_filter!(query, f.dosage_form, item_property_dsl::dosage_form);
+        apply_equal_filter!(
+            query,
+            f.ven_category.map(to_row_filter::<_, ItemRowVenCategory>),
+            item_property_dsl::ven_category
+        );
+        apply_equal_filter!(
+            query,
+            f.abc_class.map(to_row_filter::<_, ItemAbcClassRowClass>),
+            item_abc_class_dsl::class
+        );
+        if let Some(is_controlled) = f.is_controlled {
+            query = query.filter(item_property_dsl::is_controlled.eq(is_controlled));
+        }
 
-        if let Some(is_visible) = f.is_visible {
-            query = query.filter(item_is_visible::is_visible.eq(is_visible));
+        match f.is_visible {
+            Some(true) => query = query.filter(item_is_visible_in_store_dsl::item_id.is_not_null()),
+            Some(false) => query = query.filter(item_is_visible_in_store_dsl::item_id.is_null()),
+            None => {}
         }
     }
     query
@@ -165,16 +380,24 @@ mod tests {
         test_db,
         {
             db_diesel::{
+                ItemAbcClassRowRepository, ItemCategoryRowRepository,
+                ItemPropertyOverrideRowRepository, ItemStoreJoinRowRepository,
                 MasterListLineRowRepository, MasterListNameJoinRepository, MasterListRowRepository,
+                StoreRowRepository,
+            },
+            mock::{mock_item_a, mock_item_b, mock_store_a, mock_store_b, MockDataInserts},
+            schema::{
+                ItemAbcClassRow, ItemAbcClassRowClass, ItemCategoryRow, ItemPropertyOverrideRow,
+                ItemRow, ItemRowVenCategory, ItemStoreJoinRow, MasterListLineRow,
+                MasterListNameJoinRow, MasterListRow, NameRow, NameRowType, StoreRow,
             },
-            mock::MockDataInserts,
-            schema::{ItemRow, MasterListLineRow, MasterListNameJoinRow, MasterListRow, NameRow},
             ItemQueryRepository, ItemRepository, NameRepository,
         },
     };
+    use chrono::NaiveDate;
     use domain::{
-        item::{Item, ItemSort, ItemSortField},
-        EqualFilter, Pagination, DEFAULT_LIMIT,
+        item::{AbcClass, Item, ItemSort, ItemSortField, VenCategory},
+        EqualFilter, Pagination, SimpleStringFilter, DEFAULT_LIMIT,
     };
 
     impl PartialEq<ItemRow> for Item {
@@ -193,6 +416,12 @@ mod tests {
                 code: format!("code{}", index),
                 unit_id: None,
                 r#type: ItemRowType::Stock,
+                category_id: None,
+                strength: None,
+                dosage_form: None,
+                ven_category: None,
+                is_controlled: false,
+                storage_requirement: None,
             });
         }
         rows
@@ -217,14 +446,14 @@ mod tests {
         // Test
         // .count()
         assert_eq!(
-            usize::try_from(item_query_repository.count(None).unwrap()).unwrap(),
+            usize::try_from(item_query_repository.count("", None).unwrap()).unwrap(),
             rows.len()
         );
 
         // .query, no pagenation (default)
         assert_eq!(
             item_query_repository
-                .query(Pagination::new(), None, None)
+                .query("", Pagination::new(), None, None)
                 .unwrap()
                 .len(),
             default_page_size
@@ -233,6 +462,7 @@ mod tests {
         // .query, pagenation (offset 10)
         let result = item_query_repository
             .query(
+                "",
                 Pagination {
                     offset: 10,
                     limit: DEFAULT_LIMIT,
@@ -251,6 +481,7 @@ mod tests {
         // .query, pagenation (first 10)
         let result = item_query_repository
             .query(
+                "",
                 Pagination {
                     offset: 0,
                     limit: 10,
@@ -265,6 +496,7 @@ mod tests {
         // .query, pagenation (offset 150, first 90) <- more then records in table
         let result = item_query_repository
             .query(
+                "",
                 Pagination {
                     offset: 150,
                     limit: 90,
@@ -293,6 +525,7 @@ mod tests {
         // test any id filter:
         let results = item_query_repository
             .query(
+                &mock_store_a().id,
                 Pagination::new(),
                 Some(ItemFilter {
                     id: Some(EqualFilter::equal_any(vec![
@@ -304,6 +537,7 @@ mod tests {
                     // query invisible rows
                     is_visible: Some(false),
                     r#type: None,
+                    ..ItemFilter::new()
                 }),
                 None,
             )
@@ -333,6 +567,12 @@ mod tests {
                 code: "name1".to_owned(),
                 unit_id: None,
                 r#type: ItemRowType::Stock,
+                category_id: None,
+                strength: None,
+                dosage_form: None,
+                ven_category: None,
+                is_controlled: false,
+                storage_requirement: None,
             },
             ItemRow {
                 id: "item2".to_owned(),
@@ -340,6 +580,12 @@ mod tests {
                 code: "name2".to_owned(),
                 unit_id: None,
                 r#type: ItemRowType::Stock,
+                category_id: None,
+                strength: None,
+                dosage_form: None,
+                ven_category: None,
+                is_controlled: false,
+                storage_requirement: None,
             },
             ItemRow {
                 id: "item3".to_owned(),
@@ -347,6 +593,12 @@ mod tests {
                 code: "name3".to_owned(),
                 unit_id: None,
                 r#type: ItemRowType::Stock,
+                category_id: None,
+                strength: None,
+                dosage_form: None,
+                ven_category: None,
+                is_controlled: false,
+                storage_requirement: None,
             },
             ItemRow {
                 id: "item4".to_owned(),
@@ -354,6 +606,12 @@ mod tests {
                 code: "name4".to_owned(),
                 unit_id: None,
                 r#type: ItemRowType::Stock,
+                category_id: None,
+                strength: None,
+                dosage_form: None,
+                ven_category: None,
+                is_controlled: false,
+                storage_requirement: None,
             },
             ItemRow {
                 id: "item5".to_owned(),
@@ -361,6 +619,12 @@ mod tests {
                 code: "name5".to_owned(),
                 unit_id: None,
                 r#type: ItemRowType::Stock,
+                category_id: None,
+                strength: None,
+                dosage_form: None,
+                ven_category: None,
+                is_controlled: false,
+                storage_requirement: None,
             },
         ];
 
@@ -408,6 +672,20 @@ mod tests {
             code: "".to_owned(),
             is_supplier: true,
             is_customer: true,
+            r#type: NameRowType::Facility,
+            first_name: None,
+            last_name: None,
+            date_of_birth: None,
+            phone: None,
+            home_store_id: None,
+            is_manufacturer: false,
+            is_donor: false,
+            email: None,
+            website: None,
+            address1: None,
+            address2: None,
+            country: None,
+            comment: None,
         };
 
         let master_list_name_join_1 = MasterListNameJoinRow {
@@ -422,6 +700,40 @@ mod tests {
             master_list_id: "master_list2".to_owned(),
         };
 
+        let name_row_2 = NameRow {
+            id: "name2".to_owned(),
+            name: "".to_owned(),
+            code: "".to_owned(),
+            is_supplier: true,
+            is_customer: true,
+            r#type: NameRowType::Facility,
+            first_name: None,
+            last_name: None,
+            date_of_birth: None,
+            phone: None,
+            home_store_id: None,
+            is_manufacturer: false,
+            is_donor: false,
+            email: None,
+            website: None,
+            address1: None,
+            address2: None,
+            country: None,
+            comment: None,
+        };
+
+        let store_row = StoreRow {
+            id: "store1".to_owned(),
+            name_id: "name1".to_owned(),
+            code: "".to_owned(),
+        };
+
+        let store_row_2 = StoreRow {
+            id: "store2".to_owned(),
+            name_id: "name2".to_owned(),
+            code: "".to_owned(),
+        };
+
         for row in item_rows.iter() {
             ItemRepository::new(&storage_connection)
                 .upsert_one(&row)
@@ -443,11 +755,20 @@ mod tests {
         NameRepository::new(&storage_connection)
             .upsert_one(&name_row)
             .unwrap();
+        NameRepository::new(&storage_connection)
+            .upsert_one(&name_row_2)
+            .unwrap();
+        StoreRowRepository::new(&storage_connection)
+            .upsert_one(&store_row)
+            .unwrap();
+        StoreRowRepository::new(&storage_connection)
+            .upsert_one(&store_row_2)
+            .unwrap();
         // Test
 
         // Before adding any joins
         let results0 = item_query_repository
-            .query(Pagination::new(), None, None)
+            .query("store1", Pagination::new(), None, None)
             .unwrap();
 
         assert_eq!(results0, item_rows);
@@ -457,7 +778,7 @@ mod tests {
             .upsert_one(&master_list_name_join_1)
             .unwrap();
         let results = item_query_repository
-            .query(Pagination::new(), None, None)
+            .query("store1", Pagination::new(), None, None)
             .unwrap();
         assert!(results[0].is_visible);
         assert!(results[1].is_visible);
@@ -467,14 +788,21 @@ mod tests {
             .upsert_one(&master_list_name_join_2)
             .unwrap();
         let results = item_query_repository
-            .query(Pagination::new(), None, None)
+            .query("store1", Pagination::new(), None, None)
             .unwrap();
         assert!(results[2].is_visible);
         assert!(results[3].is_visible);
 
+        // Items are not visible in a store without master lists
+        let results = item_query_repository
+            .query("store2", Pagination::new(), None, None)
+            .unwrap();
+        assert!(results.iter().all(|item| !item.is_visible));
+
         // test is_visible filter:
         let results = item_query_repository
             .query(
+                "store1",
                 Pagination::new(),
                 Some(ItemFilter {
                     id: None,
@@ -483,6 +811,7 @@ mod tests {
                     // query invisible rows
                     is_visible: Some(false),
                     r#type: None,
+                    ..ItemFilter::new()
                 }),
                 None,
             )
@@ -491,6 +820,7 @@ mod tests {
         // get visible rows
         let results = item_query_repository
             .query(
+                "store1",
                 Pagination::new(),
                 Some(ItemFilter {
                     id: None,
@@ -499,11 +829,208 @@ mod tests {
                     // query invisible rows
                     is_visible: Some(true),
                     r#type: None,
+                    ..ItemFilter::new()
                 }),
                 None,
             )
             .unwrap();
         assert_eq!(results.len(), 4);
+
+        // Active item store join makes item5 visible, inactive join hides item1
+        for row in vec![
+            ItemStoreJoinRow {
+                id: "item_store_join1".to_owned(),
+                item_id: "item5".to_owned(),
+                store_id: "store1".to_owned(),
+                default_sell_price_per_pack: 0.0,
+                margin: 0.0,
+                ignore_for_orders: false,
+                inactive: false,
+                restricted_location_type_id: None,
+            },
+            ItemStoreJoinRow {
+                id: "item_store_join2".to_owned(),
+                item_id: "item1".to_owned(),
+                store_id: "store1".to_owned(),
+                default_sell_price_per_pack: 0.0,
+                margin: 0.0,
+                ignore_for_orders: false,
+                inactive: true,
+                restricted_location_type_id: None,
+            },
+        ] {
+            ItemStoreJoinRowRepository::new(&storage_connection)
+                .upsert_one(&row)
+                .unwrap();
+        }
+        let results = item_query_repository
+            .query("store1", Pagination::new(), None, None)
+            .unwrap();
+        let visible: Vec<&str> = results
+            .iter()
+            .filter(|item| item.is_visible)
+            .map(|item| item.id.as_str())
+            .collect();
+        assert_eq!(visible, vec!["item2", "item3", "item4", "item5"]);
+        assert_eq!(
+            item_query_repository
+                .count("store1", Some(ItemFilter::new().match_is_visible(true)))
+                .unwrap(),
+            4
+        );
+
+        // joins of other stores don't change the visibility
+        let results = item_query_repository
+            .query("store2", Pagination::new(), None, None)
+            .unwrap();
+        assert!(results.iter().all(|item| !item.is_visible));
+    }
+
+    #[actix_rt::test]
+    async fn test_item_query_properties() {
+        let (_, connection, _, _) =
+            test_db::setup_all("test_item_query_properties", MockDataInserts::all()).await;
+        let repo = ItemQueryRepository::new(&connection);
+
+        let category_repo = ItemCategoryRowRepository::new(&connection);
+        for (id, parent_id) in [("medicines", None), ("antibiotics", Some("medicines"))] {
+            category_repo
+                .upsert_one(&ItemCategoryRow {
+                    id: id.to_string(),
+                    name: id.to_string(),
+                    parent_id: parent_id.map(str::to_string),
+                })
+                .unwrap();
+        }
+        ItemRepository::new(&connection)
+            .upsert_one(&ItemRow {
+                category_id: Some("antibiotics".to_string()),
+                strength: Some("250mg".to_string()),
+                ven_category: Some(ItemRowVenCategory::Vital),
+                ..mock_item_a()
+            })
+            .unwrap();
+        ItemRepository::new(&connection)
+            .upsert_one(&ItemRow {
+                category_id: Some("medicines".to_string()),
+                ven_category: Some(ItemRowVenCategory::Essential),
+                ..mock_item_b()
+            })
+            .unwrap();
+        // local override of item b
+        ItemPropertyOverrideRowRepository::new(&connection)
+            .upsert_one(&ItemPropertyOverrideRow {
+                id: "override_b".to_string(),
+                item_id: mock_item_b().id,
+                category_id: None,
+                strength: None,
+                dosage_form: Some("Tablet".to_string()),
+                ven_category: Some(ItemRowVenCategory::NonEssential),
+                is_controlled: Some(true),
+                storage_requirement: Some("Below 25 C".to_string()),
+            })
+            .unwrap();
+        ItemAbcClassRowRepository::new(&connection)
+            .upsert_one(&ItemAbcClassRow {
+                id: "abc_a".to_string(),
+                store_id: mock_store_a().id,
+                item_id: mock_item_a().id,
+                class: ItemAbcClassRowClass::A,
+                consumption_value: 100.0,
+                calculated_datetime: NaiveDate::from_ymd(2021, 1, 1).and_hms(0, 0, 0),
+            })
+            .unwrap();
+
+        let item_ids = |store_id: &str, filter: ItemFilter| -> Vec<String> {
+            repo.query(store_id, Pagination::new(), Some(filter), None)
+                .unwrap()
+                .into_iter()
+                .map(|item| item.id)
+                .collect()
+        };
+        let store_id = mock_store_a().id;
+
+        // resolved properties
+        let item_b = repo
+            .query_by_filter(
+                &store_id,
+                ItemFilter::new().id(EqualFilter::equal_to("item_b")),
+            )
+            .unwrap()
+            .pop()
+            .unwrap();
+        assert_eq!(item_b.category_id, Some("medicines".to_string()));
+        assert_eq!(item_b.dosage_form, Some("Tablet".to_string()));
+        assert_eq!(item_b.ven_category, Some(VenCategory::NonEssential));
+        assert!(item_b.is_controlled);
+        assert_eq!(item_b.storage_requirement, Some("Below 25 C".to_string()));
+        assert_eq!(item_b.abc_class, None);
+
+        // category filter includes sub categories
+        assert_eq!(
+            item_ids(
+                &store_id,
+                ItemFilter::new().category_id(EqualFilter::equal_to("medicines"))
+            ),
+            vec!["item_a", "item_b"]
+        );
+        assert_eq!(
+            item_ids(
+                &store_id,
+                ItemFilter::new().category_id(EqualFilter::equal_to("antibiotics"))
+            ),
+            vec!["item_a"]
+        );
+        assert_eq!(
+            repo.count(
+                &store_id,
+                Some(ItemFilter::new().category_id(EqualFilter::equal_to("medicines")))
+            )
+            .unwrap(),
+            2
+        );
+
+        // overridden VEN category
+        assert_eq!(
+            item_ids(
+                &store_id,
+                ItemFilter::new().ven_category(EqualFilter {
+                    equal_to: None,
+                    not_equal_to: None,
+                    equal_any: Some(vec![VenCategory::Essential, VenCategory::NonEssential]),
+                })
+            ),
+            vec!["item_b"]
+        );
+
+        assert_eq!(
+            item_ids(&store_id, ItemFilter::new().match_is_controlled(true)),
+            vec!["item_b"]
+        );
+        assert_eq!(
+            item_ids(
+                &store_id,
+                ItemFilter {
+                    strength: Some(SimpleStringFilter::like("250")),
+                    ..ItemFilter::new()
+                }
+            ),
+            vec!["item_a"]
+        );
+
+        // ABC class is per store
+        let class_a = || {
+            ItemFilter::new().abc_class(EqualFilter {
+                equal_to: Some(AbcClass::A),
+                not_equal_to: None,
+                equal_any: None,
+            })
+        };
+        assert_eq!(item_ids(&store_id, class_a()), vec!["item_a"]);
+        assert_eq!(
+            item_ids(&mock_store_b().id, class_a()),
+            Vec::<String>::new()
+        );
     }
 
     #[actix_rt::test]
@@ -512,10 +1039,11 @@ mod tests {
             test_db::setup_all("test_item_query_sort", MockDataInserts::all()).await;
         let repo = ItemQueryRepository::new(&connection);
 
-        let mut items = repo.query(Pagination::new(), None, None).unwrap();
+        let mut items = repo.query("", Pagination::new(), None, None).unwrap();
 
         let sorted = repo
             .query(
+                "",
                 Pagination::new(),
                 None,
                 Some(ItemSort {
@@ -536,6 +1064,7 @@ mod tests {
 
         let sorted = repo
             .query(
+                "",
                 Pagination::new(),
                 None,
                 Some(ItemSort {
@@ -0,0 +1,67 @@
+use super::StorageConnection;
+
+use crate::repository_error::RepositoryError;
+use crate::schema::diesel_schema::item_store_join::dsl as item_store_join_dsl;
+use crate::schema::ItemStoreJoinRow;
+
+use diesel::prelude::*;
+
+pub struct ItemStoreJoinRowRepository<'a> {
+    connection: &'a StorageConnection,
+}
+
+impl<'a> ItemStoreJoinRowRepository<'a> {
+    pub fn new(connection: &'a StorageConnection) -> Self {
+        ItemStoreJoinRowRepository { connection }
+    }
+
+    #[cfg(feature = "postgres")]
+    pub fn upsert_one(&self, row: &ItemStoreJoinRow) -> Result<(), RepositoryError> {
+        diesel::insert_into(item_store_join_dsl::item_store_join)
+            .values(row)
+            .on_conflict(item_store_join_dsl::id)
+            .do_update()
+            .set(row)
+            .execute(&self.connection.connection)?;
+        Ok(())
+    }
+
+    #[cfg(not(feature = "postgres"))]
+    pub fn upsert_one(&self, row: &ItemStoreJoinRow) -> Result<(), RepositoryError> {
+        diesel::replace_into(item_store_join_dsl::item_store_join)
+            .values(row)
+            .execute(&self.connection.connection)?;
+        Ok(())
+    }
+
+    pub fn find_one_by_id(&self, id: &str) -> Result<Option<ItemStoreJoinRow>, RepositoryError> {
+        let result = item_store_join_dsl::item_store_join
+            .filter(item_store_join_dsl::id.eq(id))
+            .first(&self.connection.connection)
+            .optional()?;
+        Ok(result)
+    }
+
+    pub fn find_one_by_item_and_store_id(
+        &self,
+        item_id: &str,
+        store_id: &str,
+    ) -> Result<Option<ItemStoreJoinRow>, RepositoryError> {
+        let result = item_store_join_dsl::item_store_join
+            .filter(item_store_join_dsl::item_id.eq(item_id))
+            .filter(item_store_join_dsl::store_id.eq(store_id))
+            .first(&self.connection.connection)
+            .optional()?;
+        Ok(result)
+    }
+
+    pub fn find_many_by_store_id(
+        &self,
+        store_id: &str,
+    ) -> Result<Vec<ItemStoreJoinRow>, RepositoryError> {
+        let result = item_store_join_dsl::item_store_join
+            .filter(item_store_join_dsl::store_id.eq(store_id))
+            .load(&self.connection.connection)?;
+        Ok(result)
+    }
+}
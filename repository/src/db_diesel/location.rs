@@ -50,9 +50,8 @@ impl<'a> LocationRepository<'a> {
                     apply_sort_no_case!(query, sort, location_dsl::code)
                 }
             }
-        } else {
-            query = query.order(location_dsl::id.asc())
         }
+        query = query.then_order_by(location_dsl::id.asc());
 
         let result = query
             .offset(pagination.offset as i64)
@@ -73,6 +72,7 @@ fn create_filtered_query(filter: Option<LocationFilter>) -> BoxedLocationQuery {
         apply_equal_filter!(query, filter.name, location_dsl::name);
         apply_equal_filter!(query, filter.code, location_dsl::code);
         apply_equal_filter!(query, filter.store_id, location_dsl::store_id);
+        apply_equal_filter!(query, filter.parent_id, location_dsl::parent_id);
     }
 
     query
@@ -85,6 +85,9 @@ pub fn to_domain(
         code,
         on_hold,
         store_id: _,
+        parent_id,
+        location_type_id,
+        volume,
     }: LocationRow,
 ) -> Location {
     Location {
@@ -92,5 +95,8 @@ pub fn to_domain(
         name,
         code,
         on_hold,
+        parent_id,
+        location_type_id,
+        volume,
     }
 }
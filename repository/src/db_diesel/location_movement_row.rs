@@ -0,0 +1,80 @@
+use super::StorageConnection;
+
+use crate::repository_error::RepositoryError;
+use crate::schema::diesel_schema::location_movement::dsl as location_movement_dsl;
+use crate::schema::LocationMovementRow;
+
+use diesel::prelude::*;
+
+pub struct LocationMovementRowRepository<'a> {
+    connection: &'a StorageConnection,
+}
+
+impl<'a> LocationMovementRowRepository<'a> {
+    pub fn new(connection: &'a StorageConnection) -> Self {
+        LocationMovementRowRepository { connection }
+    }
+
+    #[cfg(feature = "postgres")]
+    pub fn upsert_one(&self, row: &LocationMovementRow) -> Result<(), RepositoryError> {
+        diesel::insert_into(location_movement_dsl::location_movement)
+            .values(row)
+            .on_conflict(location_movement_dsl::id)
+            .do_update()
+            .set(row)
+            .execute(&self.connection.connection)?;
+        Ok(())
+    }
+
+    #[cfg(not(feature = "postgres"))]
+    pub fn upsert_one(&self, row: &LocationMovementRow) -> Result<(), RepositoryError> {
+        diesel::replace_into(location_movement_dsl::location_movement)
+            .values(row)
+            .execute(&self.connection.connection)?;
+        Ok(())
+    }
+
+    pub fn find_one_by_id(&self, id: &str) -> Result<Option<LocationMovementRow>, RepositoryError> {
+        let result = location_movement_dsl::location_movement
+            .filter(location_movement_dsl::id.eq(id))
+            .first(&self.connection.connection)
+            .optional()?;
+        Ok(result)
+    }
+
+    /// Moves of the stock line, including packs split off it, oldest first
+    pub fn find_many_by_stock_line_id(
+        &self,
+        store_id: &str,
+        stock_line_id: &str,
+    ) -> Result<Vec<LocationMovementRow>, RepositoryError> {
+        let result = location_movement_dsl::location_movement
+            .filter(location_movement_dsl::store_id.eq(store_id))
+            .filter(
+                location_movement_dsl::stock_line_id
+                    .eq(stock_line_id)
+                    .or(location_movement_dsl::split_from_stock_line_id.eq(stock_line_id)),
+            )
+            .order(location_movement_dsl::moved_datetime.asc())
+            .load(&self.connection.connection)?;
+        Ok(result)
+    }
+
+    /// Moves into or out of the location, oldest first
+    pub fn find_many_by_location_id(
+        &self,
+        store_id: &str,
+        location_id: &str,
+    ) -> Result<Vec<LocationMovementRow>, RepositoryError> {
+        let result = location_movement_dsl::location_movement
+            .filter(location_movement_dsl::store_id.eq(store_id))
+            .filter(
+                location_movement_dsl::from_location_id
+                    .eq(location_id)
+                    .or(location_movement_dsl::to_location_id.eq(location_id)),
+            )
+            .order(location_movement_dsl::moved_datetime.asc())
+            .load(&self.connection.connection)?;
+        Ok(result)
+    }
+}
@@ -51,6 +51,15 @@ impl<'a> LocationRowRepository<'a> {
             .load(&self.connection.connection)?)
     }
 
+    pub fn find_many_by_store_id(
+        &self,
+        store_id: &str,
+    ) -> Result<Vec<LocationRow>, RepositoryError> {
+        Ok(location_dsl::location
+            .filter(location_dsl::store_id.eq(store_id))
+            .load(&self.connection.connection)?)
+    }
+
     pub fn delete(&self, id: &str) -> Result<(), RepositoryError> {
         diesel::delete(location_dsl::location.filter(location_dsl::id.eq(id)))
             .execute(&self.connection.connection)?;
@@ -0,0 +1,44 @@
+use super::StorageConnection;
+
+use crate::repository_error::RepositoryError;
+use crate::schema::diesel_schema::location_type::dsl as location_type_dsl;
+use crate::schema::LocationTypeRow;
+
+use diesel::prelude::*;
+
+pub struct LocationTypeRowRepository<'a> {
+    connection: &'a StorageConnection,
+}
+
+impl<'a> LocationTypeRowRepository<'a> {
+    pub fn new(connection: &'a StorageConnection) -> Self {
+        LocationTypeRowRepository { connection }
+    }
+
+    #[cfg(feature = "postgres")]
+    pub fn upsert_one(&self, row: &LocationTypeRow) -> Result<(), RepositoryError> {
+        diesel::insert_into(location_type_dsl::location_type)
+            .values(row)
+            .on_conflict(location_type_dsl::id)
+            .do_update()
+            .set(row)
+            .execute(&self.connection.connection)?;
+        Ok(())
+    }
+
+    #[cfg(not(feature = "postgres"))]
+    pub fn upsert_one(&self, row: &LocationTypeRow) -> Result<(), RepositoryError> {
+        diesel::replace_into(location_type_dsl::location_type)
+            .values(row)
+            .execute(&self.connection.connection)?;
+        Ok(())
+    }
+
+    pub fn find_one_by_id(&self, id: &str) -> Result<Option<LocationTypeRow>, RepositoryError> {
+        let result = location_type_dsl::location_type
+            .filter(location_type_dsl::id.eq(id))
+            .first(&self.connection.connection)
+            .optional()?;
+        Ok(result)
+    }
+}
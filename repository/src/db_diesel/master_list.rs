@@ -64,9 +64,8 @@ impl<'a> MasterListRepository<'a> {
                     apply_sort_no_case!(query, sort, master_list_dsl::description);
                 }
             }
-        } else {
-            query = query.order(master_list_dsl::id.asc())
         }
+        query = query.then_order_by(master_list_dsl::id.asc());
 
         let result = query
             .offset(pagination.offset as i64)
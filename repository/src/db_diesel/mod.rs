@@ -1,15 +1,25 @@
 use crate::repository_error::RepositoryError;
 
+mod barcode_row;
+mod batch_recall_row;
 mod central_sync_buffer;
 mod central_sync_cursor;
+mod consumption;
+mod currency_row;
 mod invoice;
 mod invoice_line;
 mod invoice_line_row;
 mod invoice_query;
 mod item;
+mod item_abc_class_row;
+mod item_category_row;
+mod item_property_override_row;
 mod item_query;
+mod item_store_join_row;
 mod location;
+mod location_movement_row;
 mod location_row;
+mod location_type_row;
 mod master_list;
 mod master_list_line;
 mod master_list_line_row;
@@ -18,31 +28,55 @@ mod master_list_row;
 mod name;
 mod name_query;
 mod name_store_join;
+mod name_tag_row;
 mod number_row;
+mod pack_variant_row;
+mod patient;
+mod prescriber_row;
+mod reason_row;
+mod report_row;
 mod requisition;
 mod requisition_line;
 mod stock_line;
 mod stock_line_row;
+mod stock_movement;
+mod stock_on_hand;
 mod stock_take;
 mod stock_take_line;
 mod stock_take_line_row;
 mod stock_take_row;
 mod storage_connection;
 mod store;
+mod store_preference_override_row;
+mod store_preference_row;
 mod store_row;
+mod sync_out_row;
+mod temperature_breach_config_row;
+mod temperature_breach_row;
+mod temperature_log_row;
 mod unit_row;
 mod user_account;
 
+pub use barcode_row::BarcodeRowRepository;
+pub use batch_recall_row::{BatchRecallLineRowRepository, BatchRecallRowRepository};
 pub use central_sync_buffer::CentralSyncBufferRepository;
 pub use central_sync_cursor::CentralSyncCursorRepository;
+pub use consumption::{ConsumptionRepository, ItemConsumption, ItemConsumptionValue};
+pub use currency_row::CurrencyRowRepository;
 pub use invoice::{InvoiceRepository, OutboundShipmentRepository};
 pub use invoice_line::{InvoiceLineFilter, InvoiceLineRepository};
 pub use invoice_line_row::InvoiceLineRowRepository;
 pub use invoice_query::InvoiceQueryRepository;
 pub use item::ItemRepository;
+pub use item_abc_class_row::ItemAbcClassRowRepository;
+pub use item_category_row::ItemCategoryRowRepository;
+pub use item_property_override_row::{ItemPropertyOverrideRowRepository, ItemPropertyRepository};
 pub use item_query::{ItemFilter, ItemQueryRepository};
+pub use item_store_join_row::ItemStoreJoinRowRepository;
 pub use location::{to_domain as location_to_domain, LocationRepository};
+pub use location_movement_row::LocationMovementRowRepository;
 pub use location_row::LocationRowRepository;
+pub use location_type_row::LocationTypeRowRepository;
 pub use master_list::{MasterList, MasterListRepository};
 pub use master_list_line::{MasterListLine, MasterListLineRepository};
 pub use master_list_line_row::MasterListLineRowRepository;
@@ -51,18 +85,34 @@ pub use master_list_row::MasterListRowRepository;
 pub use name::NameRepository;
 pub use name_query::NameQueryRepository;
 pub use name_store_join::NameStoreJoinRepository;
+pub use name_tag_row::{NameTagJoinRowRepository, NameTagRowRepository};
 pub use number_row::NumberRowRepository;
+pub use pack_variant_row::PackVariantRowRepository;
+pub use patient::{to_domain as patient_to_domain, PatientRepository};
+pub use prescriber_row::PrescriberRowRepository;
+pub use reason_row::ReasonRowRepository;
+pub use report_row::ReportRowRepository;
 pub use requisition::RequisitionRepository;
 pub use requisition_line::RequisitionLineRepository;
 pub use stock_line::{to_domain as stock_line_to_domain, StockLineRepository};
 pub use stock_line_row::StockLineRowRepository;
+pub use stock_movement::*;
+pub use stock_on_hand::*;
 pub use stock_take::*;
 pub use stock_take_line::*;
 pub use stock_take_line_row::*;
 pub use stock_take_row::*;
-pub use storage_connection::{StorageConnection, StorageConnectionManager, TransactionError};
+pub use storage_connection::{
+    ReadTransaction, StorageConnection, StorageConnectionManager, TransactionError,
+};
 pub use store::*;
+pub use store_preference_override_row::StorePreferenceOverrideRowRepository;
+pub use store_preference_row::StorePreferenceRowRepository;
 pub use store_row::StoreRowRepository;
+pub use sync_out_row::SyncOutRowRepository;
+pub use temperature_breach_config_row::TemperatureBreachConfigRowRepository;
+pub use temperature_breach_row::TemperatureBreachRowRepository;
+pub use temperature_log_row::TemperatureLogRowRepository;
 pub use unit_row::UnitRowRepository;
 pub use user_account::UserAccountRepository;
 
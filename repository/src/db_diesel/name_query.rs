@@ -1,17 +1,18 @@
-use super::{DBType, StorageConnection};
+use super::{DBType, NameTagJoinRowRepository, StorageConnection};
 use crate::{
     diesel_macros::{apply_equal_filter, apply_simple_string_filter, apply_sort_no_case},
     repository_error::RepositoryError,
     schema::{
         diesel_schema::{
             name, name::dsl as name_dsl, name_store_join,
-            name_store_join::dsl as name_store_join_dsl,
+            name_store_join::dsl as name_store_join_dsl, name_tag::dsl as name_tag_dsl,
+            name_tag_join::dsl as name_tag_join_dsl,
         },
-        NameRow, NameStoreJoinRow,
+        NameRow, NameRowType, NameStoreJoinRow,
     },
 };
 use domain::{
-    name::{Name, NameFilter, NameSort, NameSortField},
+    name::{Name, NameFilter, NameSort, NameSortField, NameType},
     Pagination,
 };
 
@@ -22,6 +23,30 @@ use diesel::{
 
 type NameAndNameStoreJoin = (NameRow, Option<NameStoreJoinRow>);
 
+impl From<NameRowType> for NameType {
+    fn from(r#type: NameRowType) -> Self {
+        use NameType::*;
+        match r#type {
+            NameRowType::Facility => Facility,
+            NameRowType::Patient => Patient,
+            NameRowType::Store => Store,
+            NameRowType::Other => Other,
+        }
+    }
+}
+
+impl From<NameType> for NameRowType {
+    fn from(r#type: NameType) -> Self {
+        use NameRowType::*;
+        match r#type {
+            NameType::Facility => Facility,
+            NameType::Patient => Patient,
+            NameType::Store => Store,
+            NameType::Other => Other,
+        }
+    }
+}
+
 pub struct NameQueryRepository<'a> {
     connection: &'a StorageConnection,
 }
@@ -60,16 +85,25 @@ impl<'a> NameQueryRepository<'a> {
                     apply_sort_no_case!(query, sort, name_dsl::code);
                 }
             }
-        } else {
-            query = query.order(name_dsl::id.asc())
         }
+        query = query.then_order_by(name_dsl::id.asc());
 
         let result = query
             .offset(pagination.offset as i64)
             .limit(pagination.limit as i64)
             .load::<NameAndNameStoreJoin>(&self.connection.connection)?;
 
-        Ok(result.into_iter().map(to_domain).collect())
+        let mut names: Vec<Name> = result.into_iter().map(to_domain).collect();
+
+        let ids: Vec<String> = names.iter().map(|name| name.id.clone()).collect();
+        let tags = NameTagJoinRowRepository::new(self.connection).find_many_by_name_id(&ids)?;
+        for (tag_join, tag) in tags {
+            if let Some(name) = names.iter_mut().find(|name| name.id == tag_join.name_id) {
+                name.tags.push(tag.name);
+            }
+        }
+
+        Ok(names)
     }
 }
 
@@ -86,8 +120,20 @@ fn to_domain((name_row, name_store_join_row_option): NameAndNameStoreJoin) -> Na
         id: name_row.id,
         name: name_row.name,
         code: name_row.code,
+        r#type: NameType::from(name_row.r#type),
         is_customer,
         is_supplier,
+        is_manufacturer: name_row.is_manufacturer,
+        is_donor: name_row.is_donor,
+        phone: name_row.phone,
+        email: name_row.email,
+        website: name_row.website,
+        address1: name_row.address1,
+        address2: name_row.address2,
+        country: name_row.country,
+        comment: name_row.comment,
+        home_store_id: name_row.home_store_id,
+        tags: Vec::new(),
     }
 }
 
@@ -109,6 +155,35 @@ pub fn create_filtered_query(filter: Option<NameFilter>) -> BoxedNameQuery {
         if let Some(is_supplier) = f.is_supplier {
             query = query.filter(name_store_join_dsl::name_is_supplier.eq(is_supplier));
         }
+        if let Some(is_manufacturer) = f.is_manufacturer {
+            query = query.filter(name_dsl::is_manufacturer.eq(is_manufacturer));
+        }
+        if let Some(is_donor) = f.is_donor {
+            query = query.filter(name_dsl::is_donor.eq(is_donor));
+        }
+
+        if let Some(value) = f.r#type {
+            if let Some(eq) = value.equal_to {
+                let eq = NameRowType::from(eq);
+                query = query.filter(name_dsl::type_.eq(eq));
+            }
+            if let Some(eq_any) = value.equal_any {
+                let eq_any: Vec<NameRowType> = eq_any.into_iter().map(NameRowType::from).collect();
+                query = query.filter(name_dsl::type_.eq_any(eq_any));
+            }
+        }
+
+        if let Some(tag) = f.tag {
+            let mut tag_names = tag.equal_any.unwrap_or_default();
+            if let Some(eq) = tag.equal_to {
+                tag_names.push(eq);
+            }
+            let names_with_tag = name_tag_join_dsl::name_tag_join
+                .inner_join(name_tag_dsl::name_tag)
+                .filter(name_tag_dsl::name.eq_any(tag_names))
+                .select(name_tag_join_dsl::name_id);
+            query = query.filter(name_dsl::id.eq_any(names_with_tag));
+        }
     }
 
     query
@@ -121,11 +196,11 @@ mod tests {
         {
             db_diesel::{NameQueryRepository, NameRepository},
             mock::MockDataInserts,
-            schema::NameRow,
+            schema::{NameRow, NameRowType},
         },
     };
     use domain::{
-        name::{Name, NameSort, NameSortField},
+        name::{Name, NameSort, NameSortField, NameType},
         Pagination, DEFAULT_LIMIT,
     };
     use std::convert::TryFrom;
@@ -140,14 +215,40 @@ mod tests {
                 code: format!("code{}", index),
                 is_customer: true,
                 is_supplier: true,
+                r#type: NameRowType::Facility,
+                first_name: None,
+                last_name: None,
+                date_of_birth: None,
+                phone: None,
+                home_store_id: None,
+                is_manufacturer: false,
+                is_donor: false,
+                email: None,
+                website: None,
+                address1: None,
+                address2: None,
+                country: None,
+                comment: None,
             });
 
             queries.push(Name {
                 id: format!("id{:05}", index),
                 name: format!("name{}", index),
                 code: format!("code{}", index),
+                r#type: NameType::Facility,
                 is_customer: false,
                 is_supplier: false,
+                is_manufacturer: false,
+                is_donor: false,
+                phone: None,
+                email: None,
+                website: None,
+                address1: None,
+                address2: None,
+                country: None,
+                comment: None,
+                home_store_id: None,
+                tags: Vec::new(),
             });
         }
         (rows, queries)
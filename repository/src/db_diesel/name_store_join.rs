@@ -58,6 +58,19 @@ impl<'a> NameStoreJoinRepository<'a> {
         Ok(())
     }
 
+    pub fn find_one_by_name_and_store_id(
+        &self,
+        name_id: &str,
+        store_id: &str,
+    ) -> Result<Option<NameStoreJoinRow>, RepositoryError> {
+        let result = name_store_join_dsl::name_store_join
+            .filter(name_store_join_dsl::name_id.eq(name_id))
+            .filter(name_store_join_dsl::store_id.eq(store_id))
+            .first(&self.connection.connection)
+            .optional()?;
+        Ok(result)
+    }
+
     #[cfg(feature = "postgres")]
     pub fn upsert_one(&self, row: &NameStoreJoinRow) -> Result<(), RepositoryError> {
         diesel::insert_into(name_store_join_dsl::name_store_join)
@@ -0,0 +1,118 @@
+use super::StorageConnection;
+
+use crate::repository_error::RepositoryError;
+use crate::schema::diesel_schema::{
+    name_tag::dsl as name_tag_dsl, name_tag_join::dsl as name_tag_join_dsl,
+};
+use crate::schema::{NameTagJoinRow, NameTagRow};
+
+use diesel::prelude::*;
+
+pub struct NameTagRowRepository<'a> {
+    connection: &'a StorageConnection,
+}
+
+impl<'a> NameTagRowRepository<'a> {
+    pub fn new(connection: &'a StorageConnection) -> Self {
+        NameTagRowRepository { connection }
+    }
+
+    #[cfg(feature = "postgres")]
+    pub fn upsert_one(&self, row: &NameTagRow) -> Result<(), RepositoryError> {
+        diesel::insert_into(name_tag_dsl::name_tag)
+            .values(row)
+            .on_conflict(name_tag_dsl::id)
+            .do_update()
+            .set(row)
+            .execute(&self.connection.connection)?;
+        Ok(())
+    }
+
+    #[cfg(not(feature = "postgres"))]
+    pub fn upsert_one(&self, row: &NameTagRow) -> Result<(), RepositoryError> {
+        diesel::replace_into(name_tag_dsl::name_tag)
+            .values(row)
+            .execute(&self.connection.connection)?;
+        Ok(())
+    }
+
+    pub fn find_one_by_id(&self, id: &str) -> Result<Option<NameTagRow>, RepositoryError> {
+        let result = name_tag_dsl::name_tag
+            .filter(name_tag_dsl::id.eq(id))
+            .first(&self.connection.connection)
+            .optional()?;
+        Ok(result)
+    }
+
+    pub fn find_one_by_name(&self, name: &str) -> Result<Option<NameTagRow>, RepositoryError> {
+        let result = name_tag_dsl::name_tag
+            .filter(name_tag_dsl::name.eq(name))
+            .first(&self.connection.connection)
+            .optional()?;
+        Ok(result)
+    }
+
+    /// Ordered by name
+    pub fn find_all(&self) -> Result<Vec<NameTagRow>, RepositoryError> {
+        let result = name_tag_dsl::name_tag
+            .order(name_tag_dsl::name.asc())
+            .load(&self.connection.connection)?;
+        Ok(result)
+    }
+}
+
+pub struct NameTagJoinRowRepository<'a> {
+    connection: &'a StorageConnection,
+}
+
+impl<'a> NameTagJoinRowRepository<'a> {
+    pub fn new(connection: &'a StorageConnection) -> Self {
+        NameTagJoinRowRepository { connection }
+    }
+
+    #[cfg(feature = "postgres")]
+    pub fn upsert_one(&self, row: &NameTagJoinRow) -> Result<(), RepositoryError> {
+        diesel::insert_into(name_tag_join_dsl::name_tag_join)
+            .values(row)
+            .on_conflict(name_tag_join_dsl::id)
+            .do_update()
+            .set(row)
+            .execute(&self.connection.connection)?;
+        Ok(())
+    }
+
+    #[cfg(not(feature = "postgres"))]
+    pub fn upsert_one(&self, row: &NameTagJoinRow) -> Result<(), RepositoryError> {
+        diesel::replace_into(name_tag_join_dsl::name_tag_join)
+            .values(row)
+            .execute(&self.connection.connection)?;
+        Ok(())
+    }
+
+    pub fn find_one_by_id(&self, id: &str) -> Result<Option<NameTagJoinRow>, RepositoryError> {
+        let result = name_tag_join_dsl::name_tag_join
+            .filter(name_tag_join_dsl::id.eq(id))
+            .first(&self.connection.connection)
+            .optional()?;
+        Ok(result)
+    }
+
+    /// Joins of the names with their tags
+    pub fn find_many_by_name_id(
+        &self,
+        name_ids: &[String],
+    ) -> Result<Vec<(NameTagJoinRow, NameTagRow)>, RepositoryError> {
+        let result = name_tag_join_dsl::name_tag_join
+            .inner_join(name_tag_dsl::name_tag)
+            .filter(name_tag_join_dsl::name_id.eq_any(name_ids))
+            .order(name_tag_dsl::name.asc())
+            .load(&self.connection.connection)?;
+        Ok(result)
+    }
+
+    pub fn delete(&self, id: &str) -> Result<(), RepositoryError> {
+        diesel::delete(name_tag_join_dsl::name_tag_join.filter(name_tag_join_dsl::id.eq(id)))
+            .execute(&self.connection.connection)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,78 @@
+use super::StorageConnection;
+
+use crate::repository_error::RepositoryError;
+use crate::schema::diesel_schema::pack_variant::dsl as pack_variant_dsl;
+use crate::schema::PackVariantRow;
+
+use diesel::prelude::*;
+
+pub struct PackVariantRowRepository<'a> {
+    connection: &'a StorageConnection,
+}
+
+impl<'a> PackVariantRowRepository<'a> {
+    pub fn new(connection: &'a StorageConnection) -> Self {
+        PackVariantRowRepository { connection }
+    }
+
+    #[cfg(feature = "postgres")]
+    pub fn upsert_one(&self, row: &PackVariantRow) -> Result<(), RepositoryError> {
+        diesel::insert_into(pack_variant_dsl::pack_variant)
+            .values(row)
+            .on_conflict(pack_variant_dsl::id)
+            .do_update()
+            .set(row)
+            .execute(&self.connection.connection)?;
+        Ok(())
+    }
+
+    #[cfg(not(feature = "postgres"))]
+    pub fn upsert_one(&self, row: &PackVariantRow) -> Result<(), RepositoryError> {
+        diesel::replace_into(pack_variant_dsl::pack_variant)
+            .values(row)
+            .execute(&self.connection.connection)?;
+        Ok(())
+    }
+
+    pub fn find_one_by_id(&self, id: &str) -> Result<Option<PackVariantRow>, RepositoryError> {
+        let result = pack_variant_dsl::pack_variant
+            .filter(pack_variant_dsl::id.eq(id))
+            .first(&self.connection.connection)
+            .optional()?;
+        Ok(result)
+    }
+
+    pub fn find_one_by_item_id_and_pack_size(
+        &self,
+        item_id: &str,
+        pack_size: i32,
+    ) -> Result<Option<PackVariantRow>, RepositoryError> {
+        let result = pack_variant_dsl::pack_variant
+            .filter(pack_variant_dsl::item_id.eq(item_id))
+            .filter(pack_variant_dsl::pack_size.eq(pack_size))
+            .first(&self.connection.connection)
+            .optional()?;
+        Ok(result)
+    }
+
+    /// Pack variants of the items, ordered by item id and pack size
+    pub fn find_many_by_item_ids(
+        &self,
+        item_ids: &[String],
+    ) -> Result<Vec<PackVariantRow>, RepositoryError> {
+        let result = pack_variant_dsl::pack_variant
+            .filter(pack_variant_dsl::item_id.eq_any(item_ids))
+            .order((
+                pack_variant_dsl::item_id.asc(),
+                pack_variant_dsl::pack_size.asc(),
+            ))
+            .load(&self.connection.connection)?;
+        Ok(result)
+    }
+
+    pub fn delete(&self, id: &str) -> Result<(), RepositoryError> {
+        diesel::delete(pack_variant_dsl::pack_variant.filter(pack_variant_dsl::id.eq(id)))
+            .execute(&self.connection.connection)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,122 @@
+use super::StorageConnection;
+use crate::diesel_macros::{
+    apply_date_time_filter, apply_equal_filter, apply_simple_string_filter, apply_sort,
+    apply_sort_no_case,
+};
+use crate::repository_error::RepositoryError;
+use crate::schema::diesel_schema::{name, name::dsl as name_dsl};
+use crate::schema::{NameRow, NameRowType};
+use crate::DBType;
+
+use diesel::prelude::*;
+use domain::patient::{Patient, PatientFilter, PatientSort, PatientSortField};
+use domain::Pagination;
+
+/// Names of type patient
+pub struct PatientRepository<'a> {
+    connection: &'a StorageConnection,
+}
+
+impl<'a> PatientRepository<'a> {
+    pub fn new(connection: &'a StorageConnection) -> Self {
+        PatientRepository { connection }
+    }
+
+    pub fn count(&self, filter: Option<PatientFilter>) -> Result<i64, RepositoryError> {
+        let query = create_filtered_query(filter);
+
+        Ok(query.count().get_result(&self.connection.connection)?)
+    }
+
+    pub fn query_by_filter(&self, filter: PatientFilter) -> Result<Vec<Patient>, RepositoryError> {
+        self.query(Pagination::new(), Some(filter), None)
+    }
+
+    pub fn query(
+        &self,
+        pagination: Pagination,
+        filter: Option<PatientFilter>,
+        sort: Option<PatientSort>,
+    ) -> Result<Vec<Patient>, RepositoryError> {
+        let mut query = create_filtered_query(filter);
+
+        if let Some(sort) = sort {
+            match sort.key {
+                PatientSortField::Name => {
+                    apply_sort_no_case!(query, sort, name_dsl::name_)
+                }
+                PatientSortField::Code => {
+                    apply_sort_no_case!(query, sort, name_dsl::code)
+                }
+                PatientSortField::DateOfBirth => {
+                    apply_sort!(query, sort, name_dsl::date_of_birth)
+                }
+            }
+        } else {
+            query = query.order(name_dsl::id.asc())
+        }
+
+        let result = query
+            .offset(pagination.offset as i64)
+            .limit(pagination.limit as i64)
+            .load::<NameRow>(&self.connection.connection)?;
+
+        Ok(result.into_iter().map(to_domain).collect())
+    }
+}
+
+type BoxedPatientQuery = name::BoxedQuery<'static, DBType>;
+
+fn create_filtered_query(filter: Option<PatientFilter>) -> BoxedPatientQuery {
+    let mut query = name::table
+        .filter(name_dsl::type_.eq(NameRowType::Patient))
+        .into_boxed();
+
+    if let Some(filter) = filter {
+        apply_equal_filter!(query, filter.id, name_dsl::id);
+        apply_simple_string_filter!(query, filter.name, name_dsl::name_);
+        apply_simple_string_filter!(query, filter.code, name_dsl::code);
+        apply_simple_string_filter!(query, filter.first_name, name_dsl::first_name);
+        apply_simple_string_filter!(query, filter.last_name, name_dsl::last_name);
+        apply_date_time_filter!(query, filter.date_of_birth, name_dsl::date_of_birth);
+        apply_simple_string_filter!(query, filter.phone, name_dsl::phone);
+        apply_equal_filter!(query, filter.home_store_id, name_dsl::home_store_id);
+    }
+
+    query
+}
+
+pub fn to_domain(
+    NameRow {
+        id,
+        name,
+        code,
+        is_customer: _,
+        is_supplier: _,
+        r#type: _,
+        first_name,
+        last_name,
+        date_of_birth,
+        phone,
+        home_store_id,
+        is_manufacturer: _,
+        is_donor: _,
+        email: _,
+        website: _,
+        address1: _,
+        address2: _,
+        country: _,
+        comment: _,
+    }: NameRow,
+) -> Patient {
+    Patient {
+        id,
+        name,
+        code,
+        first_name,
+        last_name,
+        date_of_birth,
+        phone,
+        home_store_id,
+    }
+}
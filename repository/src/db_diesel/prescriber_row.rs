@@ -0,0 +1,62 @@
+use super::StorageConnection;
+
+use crate::repository_error::RepositoryError;
+use crate::schema::diesel_schema::prescriber::dsl as prescriber_dsl;
+use crate::schema::PrescriberRow;
+
+use diesel::prelude::*;
+
+pub struct PrescriberRowRepository<'a> {
+    connection: &'a StorageConnection,
+}
+
+impl<'a> PrescriberRowRepository<'a> {
+    pub fn new(connection: &'a StorageConnection) -> Self {
+        PrescriberRowRepository { connection }
+    }
+
+    #[cfg(feature = "postgres")]
+    pub fn upsert_one(&self, row: &PrescriberRow) -> Result<(), RepositoryError> {
+        diesel::insert_into(prescriber_dsl::prescriber)
+            .values(row)
+            .on_conflict(prescriber_dsl::id)
+            .do_update()
+            .set(row)
+            .execute(&self.connection.connection)?;
+        Ok(())
+    }
+
+    #[cfg(not(feature = "postgres"))]
+    pub fn upsert_one(&self, row: &PrescriberRow) -> Result<(), RepositoryError> {
+        diesel::replace_into(prescriber_dsl::prescriber)
+            .values(row)
+            .execute(&self.connection.connection)?;
+        Ok(())
+    }
+
+    pub fn find_one_by_id(&self, id: &str) -> Result<Option<PrescriberRow>, RepositoryError> {
+        let result = prescriber_dsl::prescriber
+            .filter(prescriber_dsl::id.eq(id))
+            .first(&self.connection.connection)
+            .optional()?;
+        Ok(result)
+    }
+
+    pub fn find_many_by_id(&self, ids: &[String]) -> Result<Vec<PrescriberRow>, RepositoryError> {
+        let result = prescriber_dsl::prescriber
+            .filter(prescriber_dsl::id.eq_any(ids))
+            .load(&self.connection.connection)?;
+        Ok(result)
+    }
+
+    /// Ordered by last and first name
+    pub fn find_all(&self) -> Result<Vec<PrescriberRow>, RepositoryError> {
+        let result = prescriber_dsl::prescriber
+            .order((
+                prescriber_dsl::last_name.asc(),
+                prescriber_dsl::first_name.asc(),
+            ))
+            .load(&self.connection.connection)?;
+        Ok(result)
+    }
+}
@@ -0,0 +1,56 @@
+use super::StorageConnection;
+
+use crate::repository_error::RepositoryError;
+use crate::schema::diesel_schema::reason::dsl as reason_dsl;
+use crate::schema::{ReasonRow, ReasonRowType};
+
+use diesel::prelude::*;
+
+pub struct ReasonRowRepository<'a> {
+    connection: &'a StorageConnection,
+}
+
+impl<'a> ReasonRowRepository<'a> {
+    pub fn new(connection: &'a StorageConnection) -> Self {
+        ReasonRowRepository { connection }
+    }
+
+    #[cfg(feature = "postgres")]
+    pub fn upsert_one(&self, row: &ReasonRow) -> Result<(), RepositoryError> {
+        diesel::insert_into(reason_dsl::reason)
+            .values(row)
+            .on_conflict(reason_dsl::id)
+            .do_update()
+            .set(row)
+            .execute(&self.connection.connection)?;
+        Ok(())
+    }
+
+    #[cfg(not(feature = "postgres"))]
+    pub fn upsert_one(&self, row: &ReasonRow) -> Result<(), RepositoryError> {
+        diesel::replace_into(reason_dsl::reason)
+            .values(row)
+            .execute(&self.connection.connection)?;
+        Ok(())
+    }
+
+    pub fn find_one_by_id(&self, id: &str) -> Result<Option<ReasonRow>, RepositoryError> {
+        let result = reason_dsl::reason
+            .filter(reason_dsl::id.eq(id))
+            .first(&self.connection.connection)
+            .optional()?;
+        Ok(result)
+    }
+
+    pub fn find_active_by_type(
+        &self,
+        r#type: ReasonRowType,
+    ) -> Result<Vec<ReasonRow>, RepositoryError> {
+        let result = reason_dsl::reason
+            .filter(reason_dsl::type_.eq(r#type))
+            .filter(reason_dsl::is_active.eq(true))
+            .order(reason_dsl::reason_.asc())
+            .load(&self.connection.connection)?;
+        Ok(result)
+    }
+}
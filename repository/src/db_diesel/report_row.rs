@@ -0,0 +1,65 @@
+use super::StorageConnection;
+
+use crate::repository_error::RepositoryError;
+use crate::schema::diesel_schema::report::dsl as report_dsl;
+use crate::schema::{ReportContext, ReportRow};
+
+use diesel::prelude::*;
+
+pub struct ReportRowRepository<'a> {
+    connection: &'a StorageConnection,
+}
+
+impl<'a> ReportRowRepository<'a> {
+    pub fn new(connection: &'a StorageConnection) -> Self {
+        ReportRowRepository { connection }
+    }
+
+    #[cfg(feature = "postgres")]
+    pub fn upsert_one(&self, row: &ReportRow) -> Result<(), RepositoryError> {
+        diesel::insert_into(report_dsl::report)
+            .values(row)
+            .on_conflict(report_dsl::id)
+            .do_update()
+            .set(row)
+            .execute(&self.connection.connection)?;
+        Ok(())
+    }
+
+    #[cfg(not(feature = "postgres"))]
+    pub fn upsert_one(&self, row: &ReportRow) -> Result<(), RepositoryError> {
+        diesel::replace_into(report_dsl::report)
+            .values(row)
+            .execute(&self.connection.connection)?;
+        Ok(())
+    }
+
+    pub fn delete(&self, id: &str) -> Result<(), RepositoryError> {
+        diesel::delete(report_dsl::report.filter(report_dsl::id.eq(id)))
+            .execute(&self.connection.connection)?;
+        Ok(())
+    }
+
+    pub fn find_one_by_id(&self, id: &str) -> Result<Option<ReportRow>, RepositoryError> {
+        let result = report_dsl::report
+            .filter(report_dsl::id.eq(id))
+            .first(&self.connection.connection)
+            .optional()?;
+        Ok(result)
+    }
+
+    /// Reports ordered by name, all contexts if `context` is None
+    pub fn find_many_by_context(
+        &self,
+        context: Option<ReportContext>,
+    ) -> Result<Vec<ReportRow>, RepositoryError> {
+        let mut query = report_dsl::report.into_boxed();
+        if let Some(context) = context {
+            query = query.filter(report_dsl::context.eq(context));
+        }
+        let result = query
+            .order(report_dsl::name.asc())
+            .load(&self.connection.connection)?;
+        Ok(result)
+    }
+}
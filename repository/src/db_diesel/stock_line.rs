@@ -52,6 +52,7 @@ impl<'a> StockLineRepository<'a> {
         let query = create_filtered_query(filter);
 
         let result = query
+            .order(stock_line_dsl::id.asc())
             .offset(pagination.offset as i64)
             .limit(pagination.limit as i64)
             .load::<StockLineJoin>(&self.connection.connection)?;
@@ -70,7 +71,9 @@ fn create_filtered_query(filter: Option<StockLineFilter>) -> BoxedStockLineQuery
     if let Some(f) = filter {
         apply_equal_filter!(query, f.id, stock_line_dsl::id);
         apply_equal_filter!(query, f.item_id, stock_line_dsl::item_id);
+        apply_equal_filter!(query, f.store_id, stock_line_dsl::store_id);
         apply_equal_filter!(query, f.location_id, stock_line_dsl::location_id);
+        apply_equal_filter!(query, f.batch, stock_line_dsl::batch);
         apply_date_time_filter!(query, f.expiry_date, stock_line_dsl::expiry_date);
     }
 
@@ -54,4 +54,16 @@ impl<'a> StockLineRowRepository<'a> {
             .load::<StockLineRow>(&self.connection.connection)
             .map_err(RepositoryError::from)
     }
+
+    pub fn find_many_by_item_id(
+        &self,
+        store_id: &str,
+        item_id: &str,
+    ) -> Result<Vec<StockLineRow>, RepositoryError> {
+        stock_line_dsl::stock_line
+            .filter(stock_line_dsl::store_id.eq(store_id))
+            .filter(stock_line_dsl::item_id.eq(item_id))
+            .load::<StockLineRow>(&self.connection.connection)
+            .map_err(RepositoryError::from)
+    }
 }
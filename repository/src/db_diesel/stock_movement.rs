@@ -0,0 +1,169 @@
+use chrono::NaiveDateTime;
+use domain::{DatetimeFilter, EqualFilter};
+
+use crate::{
+    diesel_macros::{apply_date_time_filter, apply_equal_filter},
+    schema::{
+        diesel_schema::stock_movement::{self, dsl as stock_movement_dsl},
+        StockMovementRow,
+    },
+    DBType, RepositoryError, StorageConnection,
+};
+
+use diesel::{
+    dsl::{sql, IntoBoxed},
+    prelude::*,
+    sql_types::{BigInt, Nullable},
+};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct StockLineMovementTotal {
+    pub item_id: String,
+    pub stock_line_id: Option<String>,
+    /// Sum of the movements in units (number of packs * pack size)
+    pub units: i64,
+}
+
+#[derive(Clone, Debug)]
+pub struct StockMovementFilter {
+    pub store_id: Option<EqualFilter<String>>,
+    pub item_id: Option<EqualFilter<String>>,
+    pub stock_line_id: Option<EqualFilter<String>>,
+    pub location_id: Option<EqualFilter<String>>,
+    pub batch: Option<EqualFilter<String>>,
+    pub datetime: Option<DatetimeFilter>,
+}
+
+impl StockMovementFilter {
+    pub fn new() -> StockMovementFilter {
+        StockMovementFilter {
+            store_id: None,
+            item_id: None,
+            stock_line_id: None,
+            location_id: None,
+            batch: None,
+            datetime: None,
+        }
+    }
+
+    pub fn store_id(mut self, filter: EqualFilter<String>) -> Self {
+        self.store_id = Some(filter);
+        self
+    }
+
+    pub fn item_id(mut self, filter: EqualFilter<String>) -> Self {
+        self.item_id = Some(filter);
+        self
+    }
+
+    pub fn stock_line_id(mut self, filter: EqualFilter<String>) -> Self {
+        self.stock_line_id = Some(filter);
+        self
+    }
+
+    pub fn location_id(mut self, filter: EqualFilter<String>) -> Self {
+        self.location_id = Some(filter);
+        self
+    }
+
+    pub fn batch(mut self, filter: EqualFilter<String>) -> Self {
+        self.batch = Some(filter);
+        self
+    }
+
+    pub fn datetime(mut self, filter: DatetimeFilter) -> Self {
+        self.datetime = Some(filter);
+        self
+    }
+}
+
+type BoxedStockMovementQuery = IntoBoxed<'static, stock_movement::table, DBType>;
+
+fn create_filtered_query(filter: Option<StockMovementFilter>) -> BoxedStockMovementQuery {
+    let mut query = stock_movement_dsl::stock_movement.into_boxed();
+
+    if let Some(f) = filter {
+        apply_equal_filter!(query, f.store_id, stock_movement_dsl::store_id);
+        apply_equal_filter!(query, f.item_id, stock_movement_dsl::item_id);
+        apply_equal_filter!(query, f.stock_line_id, stock_movement_dsl::stock_line_id);
+        apply_equal_filter!(query, f.location_id, stock_movement_dsl::location_id);
+        apply_equal_filter!(query, f.batch, stock_movement_dsl::batch);
+        apply_date_time_filter!(query, f.datetime, stock_movement_dsl::datetime);
+    }
+
+    query
+}
+
+/// Invoice lines that changed stock on hand, see the stock_movement view
+pub struct StockMovementRepository<'a> {
+    connection: &'a StorageConnection,
+}
+
+impl<'a> StockMovementRepository<'a> {
+    pub fn new(connection: &'a StorageConnection) -> Self {
+        StockMovementRepository { connection }
+    }
+
+    /// Returns the movements in chronological order
+    pub fn query_by_filter(
+        &self,
+        filter: StockMovementFilter,
+    ) -> Result<Vec<StockMovementRow>, RepositoryError> {
+        let result = create_filtered_query(Some(filter))
+            .order((
+                stock_movement_dsl::datetime.asc(),
+                stock_movement_dsl::id.asc(),
+            ))
+            .load::<StockMovementRow>(&self.connection.connection)?;
+        Ok(result)
+    }
+
+    /// Sum of the movements in units (number of packs * pack size)
+    pub fn sum_units(&self, filter: StockMovementFilter) -> Result<i64, RepositoryError> {
+        let result = create_filtered_query(Some(filter))
+            .select(diesel::dsl::sum(
+                stock_movement_dsl::number_of_packs * stock_movement_dsl::pack_size,
+            ))
+            .first::<Option<i64>>(&self.connection.connection)?;
+        Ok(result.unwrap_or(0))
+    }
+
+    /// Sum of the movements in units per item and stock line that happened after `datetime`
+    /// (exclusive), i.e. the movements to reverse to get the stock at `datetime`
+    pub fn sum_units_by_stock_line_after(
+        &self,
+        store_id: &str,
+        item_id: Option<EqualFilter<String>>,
+        datetime: NaiveDateTime,
+    ) -> Result<Vec<StockLineMovementTotal>, RepositoryError> {
+        let mut query = stock_movement_dsl::stock_movement
+            .filter(stock_movement_dsl::store_id.eq(store_id.to_string()))
+            .filter(stock_movement_dsl::datetime.gt(datetime))
+            .group_by((
+                stock_movement_dsl::item_id,
+                stock_movement_dsl::stock_line_id,
+            ))
+            .select((
+                stock_movement_dsl::item_id,
+                stock_movement_dsl::stock_line_id,
+                // diesel doesn't allow mixing aggregate and non aggregate expressions in a select
+                sql::<Nullable<BigInt>>(
+                    "SUM(stock_movement.number_of_packs * stock_movement.pack_size)",
+                ),
+            ))
+            .into_boxed();
+        apply_equal_filter!(query, item_id, stock_movement_dsl::item_id);
+
+        let result =
+            query.load::<(String, Option<String>, Option<i64>)>(&self.connection.connection)?;
+
+        Ok(result
+            .into_iter()
+            .map(|(item_id, stock_line_id, units)| StockLineMovementTotal {
+                item_id,
+                stock_line_id,
+                units: units.unwrap_or(0),
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,401 @@
+use chrono::NaiveDate;
+use domain::{DateFilter, EqualFilter, Pagination, SimpleStringFilter, Sort};
+
+use crate::{
+    diesel_macros::{
+        apply_date_time_filter, apply_equal_filter, apply_simple_string_filter, apply_sort,
+        apply_sort_no_case,
+    },
+    schema::{
+        diesel_schema::{
+            item, item::dsl as item_dsl, stock_on_hand, stock_on_hand::dsl as stock_on_hand_dsl,
+            stock_on_hand_by_location, stock_on_hand_by_location::dsl as by_location_dsl,
+        },
+        ItemRow, StockOnHandByLocationRow, StockOnHandRow,
+    },
+    DBType, RepositoryError, StorageConnection,
+};
+
+use diesel::{
+    dsl::{InnerJoin, IntoBoxed},
+    prelude::*,
+};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct StockOnHand {
+    pub store_id: String,
+    pub item_id: String,
+    pub item_name: String,
+    pub item_code: String,
+    /// Only set when stock on hand is queried by location
+    pub location_id: Option<String>,
+    pub total_number_of_packs: i64,
+    pub available_number_of_packs: i64,
+    pub total_units: i64,
+    pub available_units: i64,
+    pub number_of_batches: i64,
+    pub earliest_expiry_date: Option<NaiveDate>,
+    pub total_cost_value: f64,
+}
+
+#[derive(Clone, Debug)]
+pub struct StockOnHandFilter {
+    pub store_id: Option<EqualFilter<String>>,
+    pub item_id: Option<EqualFilter<String>>,
+    /// Only applied when stock on hand is queried by location
+    pub location_id: Option<EqualFilter<String>>,
+    pub item_name: Option<SimpleStringFilter>,
+    pub item_code: Option<SimpleStringFilter>,
+    pub earliest_expiry_date: Option<DateFilter>,
+}
+
+impl StockOnHandFilter {
+    pub fn new() -> StockOnHandFilter {
+        StockOnHandFilter {
+            store_id: None,
+            item_id: None,
+            location_id: None,
+            item_name: None,
+            item_code: None,
+            earliest_expiry_date: None,
+        }
+    }
+
+    pub fn store_id(mut self, filter: EqualFilter<String>) -> Self {
+        self.store_id = Some(filter);
+        self
+    }
+
+    pub fn item_id(mut self, filter: EqualFilter<String>) -> Self {
+        self.item_id = Some(filter);
+        self
+    }
+
+    pub fn location_id(mut self, filter: EqualFilter<String>) -> Self {
+        self.location_id = Some(filter);
+        self
+    }
+
+    pub fn item_name(mut self, filter: SimpleStringFilter) -> Self {
+        self.item_name = Some(filter);
+        self
+    }
+
+    pub fn item_code(mut self, filter: SimpleStringFilter) -> Self {
+        self.item_code = Some(filter);
+        self
+    }
+
+    pub fn earliest_expiry_date(mut self, filter: DateFilter) -> Self {
+        self.earliest_expiry_date = Some(filter);
+        self
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum StockOnHandSortField {
+    ItemName,
+    ItemCode,
+    TotalNumberOfPacks,
+    TotalUnits,
+    AvailableUnits,
+    EarliestExpiryDate,
+    TotalCostValue,
+}
+
+pub type StockOnHandSort = Sort<StockOnHandSortField>;
+
+type StockOnHandJoin = (StockOnHandRow, ItemRow);
+type StockOnHandByLocationJoin = (StockOnHandByLocationRow, ItemRow);
+
+type BoxedStockOnHandQuery =
+    IntoBoxed<'static, InnerJoin<stock_on_hand::table, item::table>, DBType>;
+type BoxedStockOnHandByLocationQuery =
+    IntoBoxed<'static, InnerJoin<stock_on_hand_by_location::table, item::table>, DBType>;
+
+/// Both stock on hand views share the same aggregate columns, the filter and sort are applied
+/// to whichever view `$dsl` refers to
+macro_rules! apply_stock_on_hand_filter {
+    ($query:ident, $filter:ident, $dsl:ident) => {{
+        apply_equal_filter!($query, $filter.store_id, $dsl::store_id);
+        apply_equal_filter!($query, $filter.item_id, $dsl::item_id);
+        apply_simple_string_filter!($query, $filter.item_name, item_dsl::name);
+        apply_simple_string_filter!($query, $filter.item_code, item_dsl::code);
+        apply_date_time_filter!(
+            $query,
+            $filter.earliest_expiry_date,
+            $dsl::earliest_expiry_date
+        );
+    }};
+}
+
+macro_rules! apply_stock_on_hand_sort {
+    ($query:ident, $sort:ident, $dsl:ident) => {{
+        if let Some(sort) = $sort {
+            match sort.key {
+                StockOnHandSortField::ItemName => apply_sort_no_case!($query, sort, item_dsl::name),
+                StockOnHandSortField::ItemCode => apply_sort_no_case!($query, sort, item_dsl::code),
+                StockOnHandSortField::TotalNumberOfPacks => {
+                    apply_sort!($query, sort, $dsl::total_number_of_packs)
+                }
+                StockOnHandSortField::TotalUnits => apply_sort!($query, sort, $dsl::total_units),
+                StockOnHandSortField::AvailableUnits => {
+                    apply_sort!($query, sort, $dsl::available_units)
+                }
+                StockOnHandSortField::EarliestExpiryDate => {
+                    apply_sort!($query, sort, $dsl::earliest_expiry_date)
+                }
+                StockOnHandSortField::TotalCostValue => {
+                    apply_sort!($query, sort, $dsl::total_cost_value)
+                }
+            }
+        } else {
+            $query = $query.order(item_dsl::name.asc())
+        }
+    }};
+}
+
+fn create_filtered_query(filter: Option<StockOnHandFilter>) -> BoxedStockOnHandQuery {
+    let mut query = stock_on_hand_dsl::stock_on_hand
+        .inner_join(item_dsl::item)
+        .into_boxed();
+
+    if let Some(f) = filter {
+        apply_stock_on_hand_filter!(query, f, stock_on_hand_dsl);
+    }
+
+    query
+}
+
+fn create_filtered_by_location_query(
+    filter: Option<StockOnHandFilter>,
+) -> BoxedStockOnHandByLocationQuery {
+    let mut query = by_location_dsl::stock_on_hand_by_location
+        .inner_join(item_dsl::item)
+        .into_boxed();
+
+    if let Some(f) = filter {
+        apply_equal_filter!(query, f.location_id, by_location_dsl::location_id);
+        apply_stock_on_hand_filter!(query, f, by_location_dsl);
+    }
+
+    query
+}
+
+/// Stock on hand aggregated from stock lines, either per item or per item and location
+pub struct StockOnHandRepository<'a> {
+    connection: &'a StorageConnection,
+}
+
+impl<'a> StockOnHandRepository<'a> {
+    pub fn new(connection: &'a StorageConnection) -> Self {
+        StockOnHandRepository { connection }
+    }
+
+    pub fn count(&self, filter: Option<StockOnHandFilter>) -> Result<i64, RepositoryError> {
+        let query = create_filtered_query(filter);
+        Ok(query.count().get_result(&self.connection.connection)?)
+    }
+
+    pub fn query_by_filter(
+        &self,
+        filter: StockOnHandFilter,
+    ) -> Result<Vec<StockOnHand>, RepositoryError> {
+        self.query(Pagination::all(), Some(filter), None)
+    }
+
+    pub fn query(
+        &self,
+        pagination: Pagination,
+        filter: Option<StockOnHandFilter>,
+        sort: Option<StockOnHandSort>,
+    ) -> Result<Vec<StockOnHand>, RepositoryError> {
+        let mut query = create_filtered_query(filter);
+        apply_stock_on_hand_sort!(query, sort, stock_on_hand_dsl);
+
+        let result = query
+            .offset(pagination.offset as i64)
+            .limit(pagination.limit as i64)
+            .load::<StockOnHandJoin>(&self.connection.connection)?;
+
+        Ok(result.into_iter().map(to_domain).collect())
+    }
+
+    pub fn count_by_location(
+        &self,
+        filter: Option<StockOnHandFilter>,
+    ) -> Result<i64, RepositoryError> {
+        let query = create_filtered_by_location_query(filter);
+        Ok(query.count().get_result(&self.connection.connection)?)
+    }
+
+    pub fn query_by_location(
+        &self,
+        pagination: Pagination,
+        filter: Option<StockOnHandFilter>,
+        sort: Option<StockOnHandSort>,
+    ) -> Result<Vec<StockOnHand>, RepositoryError> {
+        let mut query = create_filtered_by_location_query(filter);
+        apply_stock_on_hand_sort!(query, sort, by_location_dsl);
+
+        let result = query
+            .offset(pagination.offset as i64)
+            .limit(pagination.limit as i64)
+            .load::<StockOnHandByLocationJoin>(&self.connection.connection)?;
+
+        Ok(result.into_iter().map(by_location_to_domain).collect())
+    }
+}
+
+fn to_domain((row, item_row): StockOnHandJoin) -> StockOnHand {
+    StockOnHand {
+        store_id: row.store_id,
+        item_id: row.item_id,
+        item_name: item_row.name,
+        item_code: item_row.code,
+        location_id: None,
+        total_number_of_packs: row.total_number_of_packs,
+        available_number_of_packs: row.available_number_of_packs,
+        total_units: row.total_units,
+        available_units: row.available_units,
+        number_of_batches: row.number_of_batches,
+        earliest_expiry_date: row.earliest_expiry_date,
+        total_cost_value: row.total_cost_value,
+    }
+}
+
+fn by_location_to_domain((row, item_row): StockOnHandByLocationJoin) -> StockOnHand {
+    StockOnHand {
+        store_id: row.store_id,
+        item_id: row.item_id,
+        item_name: item_row.name,
+        item_code: item_row.code,
+        location_id: row.location_id,
+        total_number_of_packs: row.total_number_of_packs,
+        available_number_of_packs: row.available_number_of_packs,
+        total_units: row.total_units,
+        available_units: row.available_units,
+        number_of_batches: row.number_of_batches,
+        earliest_expiry_date: row.earliest_expiry_date,
+        total_cost_value: row.total_cost_value,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use domain::{EqualFilter, Pagination, Sort};
+
+    use crate::{mock::MockDataInserts, schema::StockLineRow, test_db, StockLineRowRepository};
+
+    use super::{StockOnHandFilter, StockOnHandRepository, StockOnHandSortField};
+
+    fn stock_line(
+        id: &str,
+        location_id: Option<&str>,
+        pack_size: i32,
+        total_number_of_packs: i32,
+        expiry_date: Option<NaiveDate>,
+    ) -> StockLineRow {
+        StockLineRow {
+            id: id.to_owned(),
+            item_id: "item_query_test2".to_owned(),
+            store_id: "store_b".to_owned(),
+            location_id: location_id.map(str::to_owned),
+            batch: None,
+            pack_size,
+            cost_price_per_pack: 2.5,
+            sell_price_per_pack: 3.0,
+            available_number_of_packs: total_number_of_packs - 1,
+            total_number_of_packs,
+            expiry_date,
+            on_hold: false,
+            note: None,
+        }
+    }
+
+    #[actix_rt::test]
+    async fn test_stock_on_hand_repository() {
+        let (_, connection, _, _) =
+            test_db::setup_all("test_stock_on_hand_repository", MockDataInserts::all()).await;
+
+        let stock_line_repo = StockLineRowRepository::new(&connection);
+        for row in vec![
+            stock_line(
+                "soh_line_a",
+                None,
+                1,
+                10,
+                NaiveDate::from_ymd_opt(2022, 3, 1),
+            ),
+            stock_line(
+                "soh_line_b",
+                Some("location_in_another_store"),
+                10,
+                4,
+                NaiveDate::from_ymd_opt(2021, 12, 24),
+            ),
+            stock_line("soh_line_c", Some("location_in_another_store"), 5, 2, None),
+            // batches without stock are ignored
+            stock_line(
+                "soh_line_d",
+                None,
+                1,
+                0,
+                NaiveDate::from_ymd_opt(2020, 1, 1),
+            ),
+        ] {
+            stock_line_repo.upsert_one(&row).unwrap();
+        }
+
+        let repo = StockOnHandRepository::new(&connection);
+        let filter = StockOnHandFilter::new()
+            .store_id(EqualFilter::equal_to("store_b"))
+            .item_id(EqualFilter::equal_to("item_query_test2"));
+
+        // per item
+        let result = repo.query_by_filter(filter.clone()).unwrap();
+        assert_eq!(result.len(), 1);
+        let stock_on_hand = &result[0];
+        assert_eq!(stock_on_hand.item_code, "code_item_query_test2");
+        assert_eq!(stock_on_hand.location_id, None);
+        assert_eq!(stock_on_hand.total_number_of_packs, 16);
+        assert_eq!(stock_on_hand.available_number_of_packs, 13);
+        assert_eq!(stock_on_hand.total_units, 10 + 40 + 10);
+        assert_eq!(stock_on_hand.available_units, 9 + 30 + 5);
+        assert_eq!(stock_on_hand.number_of_batches, 3);
+        assert_eq!(
+            stock_on_hand.earliest_expiry_date,
+            NaiveDate::from_ymd_opt(2021, 12, 24)
+        );
+        assert_eq!(stock_on_hand.total_cost_value, 16.0 * 2.5);
+
+        // per location
+        let result = repo
+            .query_by_location(
+                Pagination::new(),
+                Some(filter.clone()),
+                Some(Sort {
+                    key: StockOnHandSortField::TotalUnits,
+                    desc: Some(true),
+                }),
+            )
+            .unwrap();
+        assert_eq!(result.len(), 2);
+        assert_eq!(
+            result[0].location_id,
+            Some("location_in_another_store".to_owned())
+        );
+        assert_eq!(result[0].total_units, 50);
+        assert_eq!(result[0].number_of_batches, 2);
+        assert_eq!(result[1].location_id, None);
+        assert_eq!(result[1].total_units, 10);
+
+        let count = repo
+            .count_by_location(Some(
+                filter.location_id(EqualFilter::equal_to("location_in_another_store")),
+            ))
+            .unwrap();
+        assert_eq!(count, 1);
+    }
+}
@@ -5,7 +5,7 @@ use super::{get_connection, DBBackendConnection, DBConnection};
 use crate::repository_error::RepositoryError;
 
 use diesel::{
-    connection::TransactionManager,
+    connection::{SimpleConnection, TransactionManager},
     r2d2::{ConnectionManager, Pool},
     Connection,
 };
@@ -189,6 +189,46 @@ impl StorageConnection {
     }
 }
 
+#[cfg(feature = "postgres")]
+const BEGIN_READ_TRANSACTION: &str = "BEGIN TRANSACTION ISOLATION LEVEL REPEATABLE READ";
+#[cfg(not(feature = "postgres"))]
+const BEGIN_READ_TRANSACTION: &str = "BEGIN";
+
+/// Transaction which stays open until it is dropped, all reads see the same snapshot of the
+/// database. Used to read a result page by page, e.g. while streaming it in a response.
+///
+/// Nothing is committed, the transaction is rolled back when dropped.
+pub struct ReadTransaction {
+    connection: StorageConnection,
+}
+
+impl ReadTransaction {
+    pub fn begin(connection: StorageConnection) -> Result<ReadTransaction, RepositoryError> {
+        connection
+            .connection
+            .batch_execute(BEGIN_READ_TRANSACTION)?;
+        // transactions started while reading reuse the read transaction
+        connection
+            .transaction_level
+            .set(connection.transaction_level.get() + 1);
+        Ok(ReadTransaction { connection })
+    }
+
+    pub fn connection(&self) -> &StorageConnection {
+        &self.connection
+    }
+}
+
+impl Drop for ReadTransaction {
+    fn drop(&mut self) {
+        let level = self.connection.transaction_level.get();
+        self.connection.transaction_level.set(level - 1);
+        if let Err(err) = self.connection.connection.batch_execute("ROLLBACK") {
+            error!("Failed to end read tx: {:?}", err);
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct StorageConnectionManager {
     pool: Pool<ConnectionManager<DBBackendConnection>>,
@@ -209,7 +249,10 @@ impl StorageConnectionManager {
 
 #[cfg(test)]
 mod connection_manager_tests {
-    use crate::{get_storage_connection_manager, test_db, RepositoryError, TransactionError};
+    use crate::{
+        get_storage_connection_manager, schema::UnitRow, test_db, ReadTransaction, RepositoryError,
+        TransactionError, UnitRowRepository,
+    };
 
     #[actix_rt::test]
     async fn test_nested_tx() {
@@ -254,4 +297,39 @@ mod connection_manager_tests {
             );
         assert_eq!(connection.transaction_level.get(), 0);
     }
+
+    #[actix_rt::test]
+    async fn test_read_tx() {
+        let settings = test_db::get_test_db_settings("omsupply-read-tx");
+        test_db::setup(&settings).await;
+        let connection_manager = get_storage_connection_manager(&settings);
+
+        let transaction = ReadTransaction::begin(connection_manager.connection().unwrap()).unwrap();
+        let connection = transaction.connection();
+        assert_eq!(connection.transaction_level.get(), 1);
+        // reuses the read tx
+        connection
+            .transaction_sync(|con| {
+                assert_eq!(con.transaction_level.get(), 1);
+                UnitRowRepository::new(con).upsert_one(&UnitRow {
+                    id: "unit".to_string(),
+                    name: "unit".to_string(),
+                    description: None,
+                    index: 0,
+                })
+            })
+            .unwrap();
+        assert!(UnitRowRepository::new(connection)
+            .find_one_by_id_option("unit")
+            .unwrap()
+            .is_some());
+        drop(transaction);
+
+        // the read tx is rolled back
+        let connection = connection_manager.connection().unwrap();
+        assert!(UnitRowRepository::new(&connection)
+            .find_one_by_id_option("unit")
+            .unwrap()
+            .is_none());
+    }
 }
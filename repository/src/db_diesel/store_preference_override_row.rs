@@ -0,0 +1,58 @@
+use super::StorageConnection;
+
+use crate::repository_error::RepositoryError;
+use crate::schema::diesel_schema::store_preference_override::dsl as store_preference_override_dsl;
+use crate::schema::StorePreferenceOverrideRow;
+
+use diesel::prelude::*;
+
+pub struct StorePreferenceOverrideRowRepository<'a> {
+    connection: &'a StorageConnection,
+}
+
+impl<'a> StorePreferenceOverrideRowRepository<'a> {
+    pub fn new(connection: &'a StorageConnection) -> Self {
+        StorePreferenceOverrideRowRepository { connection }
+    }
+
+    #[cfg(feature = "postgres")]
+    pub fn upsert_one(&self, row: &StorePreferenceOverrideRow) -> Result<(), RepositoryError> {
+        diesel::insert_into(store_preference_override_dsl::store_preference_override)
+            .values(row)
+            .on_conflict(store_preference_override_dsl::id)
+            .do_update()
+            .set(row)
+            .execute(&self.connection.connection)?;
+        Ok(())
+    }
+
+    #[cfg(not(feature = "postgres"))]
+    pub fn upsert_one(&self, row: &StorePreferenceOverrideRow) -> Result<(), RepositoryError> {
+        diesel::replace_into(store_preference_override_dsl::store_preference_override)
+            .values(row)
+            .execute(&self.connection.connection)?;
+        Ok(())
+    }
+
+    pub fn find_one_by_id(
+        &self,
+        id: &str,
+    ) -> Result<Option<StorePreferenceOverrideRow>, RepositoryError> {
+        let result = store_preference_override_dsl::store_preference_override
+            .filter(store_preference_override_dsl::id.eq(id))
+            .first(&self.connection.connection)
+            .optional()?;
+        Ok(result)
+    }
+
+    pub fn find_one_by_store_id(
+        &self,
+        store_id: &str,
+    ) -> Result<Option<StorePreferenceOverrideRow>, RepositoryError> {
+        let result = store_preference_override_dsl::store_preference_override
+            .filter(store_preference_override_dsl::store_id.eq(store_id))
+            .first(&self.connection.connection)
+            .optional()?;
+        Ok(result)
+    }
+}
@@ -0,0 +1,55 @@
+use super::StorageConnection;
+
+use crate::repository_error::RepositoryError;
+use crate::schema::diesel_schema::store_preference::dsl as store_preference_dsl;
+use crate::schema::StorePreferenceRow;
+
+use diesel::prelude::*;
+
+pub struct StorePreferenceRowRepository<'a> {
+    connection: &'a StorageConnection,
+}
+
+impl<'a> StorePreferenceRowRepository<'a> {
+    pub fn new(connection: &'a StorageConnection) -> Self {
+        StorePreferenceRowRepository { connection }
+    }
+
+    #[cfg(feature = "postgres")]
+    pub fn upsert_one(&self, row: &StorePreferenceRow) -> Result<(), RepositoryError> {
+        diesel::insert_into(store_preference_dsl::store_preference)
+            .values(row)
+            .on_conflict(store_preference_dsl::id)
+            .do_update()
+            .set(row)
+            .execute(&self.connection.connection)?;
+        Ok(())
+    }
+
+    #[cfg(not(feature = "postgres"))]
+    pub fn upsert_one(&self, row: &StorePreferenceRow) -> Result<(), RepositoryError> {
+        diesel::replace_into(store_preference_dsl::store_preference)
+            .values(row)
+            .execute(&self.connection.connection)?;
+        Ok(())
+    }
+
+    pub fn find_one_by_id(&self, id: &str) -> Result<Option<StorePreferenceRow>, RepositoryError> {
+        let result = store_preference_dsl::store_preference
+            .filter(store_preference_dsl::id.eq(id))
+            .first(&self.connection.connection)
+            .optional()?;
+        Ok(result)
+    }
+
+    pub fn find_one_by_store_id(
+        &self,
+        store_id: &str,
+    ) -> Result<Option<StorePreferenceRow>, RepositoryError> {
+        let result = store_preference_dsl::store_preference
+            .filter(store_preference_dsl::store_id.eq(store_id))
+            .first(&self.connection.connection)
+            .optional()?;
+        Ok(result)
+    }
+}
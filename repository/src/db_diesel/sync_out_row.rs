@@ -0,0 +1,38 @@
+use super::StorageConnection;
+
+use crate::repository_error::RepositoryError;
+use crate::schema::diesel_schema::sync_out::dsl as sync_out_dsl;
+use crate::schema::SyncOutRow;
+
+use diesel::prelude::*;
+
+pub struct SyncOutRowRepository<'a> {
+    connection: &'a StorageConnection,
+}
+
+impl<'a> SyncOutRowRepository<'a> {
+    pub fn new(connection: &'a StorageConnection) -> Self {
+        SyncOutRowRepository { connection }
+    }
+
+    pub fn insert_one(&self, row: &SyncOutRow) -> Result<(), RepositoryError> {
+        diesel::insert_into(sync_out_dsl::sync_out)
+            .values(row)
+            .execute(&self.connection.connection)?;
+        Ok(())
+    }
+
+    /// Queued records, oldest first
+    pub fn find_all(&self) -> Result<Vec<SyncOutRow>, RepositoryError> {
+        let result = sync_out_dsl::sync_out
+            .order((sync_out_dsl::created_at.asc(), sync_out_dsl::id.asc()))
+            .load(&self.connection.connection)?;
+        Ok(result)
+    }
+
+    pub fn delete(&self, id: &str) -> Result<(), RepositoryError> {
+        diesel::delete(sync_out_dsl::sync_out.filter(sync_out_dsl::id.eq(id)))
+            .execute(&self.connection.connection)?;
+        Ok(())
+    }
+}
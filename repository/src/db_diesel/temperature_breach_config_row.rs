@@ -0,0 +1,65 @@
+use super::StorageConnection;
+
+use crate::repository_error::RepositoryError;
+use crate::schema::diesel_schema::temperature_breach_config::dsl as temperature_breach_config_dsl;
+use crate::schema::TemperatureBreachConfigRow;
+
+use diesel::prelude::*;
+
+pub struct TemperatureBreachConfigRowRepository<'a> {
+    connection: &'a StorageConnection,
+}
+
+impl<'a> TemperatureBreachConfigRowRepository<'a> {
+    pub fn new(connection: &'a StorageConnection) -> Self {
+        TemperatureBreachConfigRowRepository { connection }
+    }
+
+    #[cfg(feature = "postgres")]
+    pub fn upsert_one(&self, row: &TemperatureBreachConfigRow) -> Result<(), RepositoryError> {
+        diesel::insert_into(temperature_breach_config_dsl::temperature_breach_config)
+            .values(row)
+            .on_conflict(temperature_breach_config_dsl::id)
+            .do_update()
+            .set(row)
+            .execute(&self.connection.connection)?;
+        Ok(())
+    }
+
+    #[cfg(not(feature = "postgres"))]
+    pub fn upsert_one(&self, row: &TemperatureBreachConfigRow) -> Result<(), RepositoryError> {
+        diesel::replace_into(temperature_breach_config_dsl::temperature_breach_config)
+            .values(row)
+            .execute(&self.connection.connection)?;
+        Ok(())
+    }
+
+    pub fn find_one_by_id(
+        &self,
+        id: &str,
+    ) -> Result<Option<TemperatureBreachConfigRow>, RepositoryError> {
+        let result = temperature_breach_config_dsl::temperature_breach_config
+            .filter(temperature_breach_config_dsl::id.eq(id))
+            .first(&self.connection.connection)
+            .optional()?;
+        Ok(result)
+    }
+
+    pub fn find_all(&self) -> Result<Vec<TemperatureBreachConfigRow>, RepositoryError> {
+        let result = temperature_breach_config_dsl::temperature_breach_config
+            .order(temperature_breach_config_dsl::description.asc())
+            .load(&self.connection.connection)?;
+        Ok(result)
+    }
+
+    pub fn find_many_by_location_type_id(
+        &self,
+        location_type_id: &str,
+    ) -> Result<Vec<TemperatureBreachConfigRow>, RepositoryError> {
+        let result = temperature_breach_config_dsl::temperature_breach_config
+            .filter(temperature_breach_config_dsl::location_type_id.eq(location_type_id))
+            .order(temperature_breach_config_dsl::description.asc())
+            .load(&self.connection.connection)?;
+        Ok(result)
+    }
+}
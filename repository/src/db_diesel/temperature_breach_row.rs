@@ -0,0 +1,84 @@
+use super::StorageConnection;
+
+use crate::repository_error::RepositoryError;
+use crate::schema::diesel_schema::temperature_breach::dsl as temperature_breach_dsl;
+use crate::schema::TemperatureBreachRow;
+
+use diesel::prelude::*;
+
+pub struct TemperatureBreachRowRepository<'a> {
+    connection: &'a StorageConnection,
+}
+
+impl<'a> TemperatureBreachRowRepository<'a> {
+    pub fn new(connection: &'a StorageConnection) -> Self {
+        TemperatureBreachRowRepository { connection }
+    }
+
+    #[cfg(feature = "postgres")]
+    pub fn upsert_one(&self, row: &TemperatureBreachRow) -> Result<(), RepositoryError> {
+        diesel::insert_into(temperature_breach_dsl::temperature_breach)
+            .values(row)
+            .on_conflict(temperature_breach_dsl::id)
+            .do_update()
+            .set(row)
+            .execute(&self.connection.connection)?;
+        Ok(())
+    }
+
+    #[cfg(not(feature = "postgres"))]
+    pub fn upsert_one(&self, row: &TemperatureBreachRow) -> Result<(), RepositoryError> {
+        diesel::replace_into(temperature_breach_dsl::temperature_breach)
+            .values(row)
+            .execute(&self.connection.connection)?;
+        Ok(())
+    }
+
+    pub fn find_one_by_id(
+        &self,
+        id: &str,
+    ) -> Result<Option<TemperatureBreachRow>, RepositoryError> {
+        let result = temperature_breach_dsl::temperature_breach
+            .filter(temperature_breach_dsl::id.eq(id))
+            .first(&self.connection.connection)
+            .optional()?;
+        Ok(result)
+    }
+
+    /// Breaches of the store, optionally only of one location or only ongoing breaches, most
+    /// recent first
+    pub fn find_many(
+        &self,
+        store_id: &str,
+        location_id: Option<&str>,
+        current_only: bool,
+    ) -> Result<Vec<TemperatureBreachRow>, RepositoryError> {
+        let mut query = temperature_breach_dsl::temperature_breach
+            .filter(temperature_breach_dsl::store_id.eq(store_id))
+            .into_boxed();
+        if let Some(location_id) = location_id {
+            query = query.filter(temperature_breach_dsl::location_id.eq(location_id));
+        }
+        if current_only {
+            query = query.filter(temperature_breach_dsl::end_datetime.is_null());
+        }
+        let result = query
+            .order(temperature_breach_dsl::start_datetime.desc())
+            .load(&self.connection.connection)?;
+        Ok(result)
+    }
+
+    pub fn delete_by_location_id(
+        &self,
+        store_id: &str,
+        location_id: &str,
+    ) -> Result<(), RepositoryError> {
+        diesel::delete(
+            temperature_breach_dsl::temperature_breach
+                .filter(temperature_breach_dsl::store_id.eq(store_id))
+                .filter(temperature_breach_dsl::location_id.eq(location_id)),
+        )
+        .execute(&self.connection.connection)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,70 @@
+use super::StorageConnection;
+
+use crate::repository_error::RepositoryError;
+use crate::schema::diesel_schema::temperature_log::dsl as temperature_log_dsl;
+use crate::schema::TemperatureLogRow;
+
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+pub struct TemperatureLogRowRepository<'a> {
+    connection: &'a StorageConnection,
+}
+
+impl<'a> TemperatureLogRowRepository<'a> {
+    pub fn new(connection: &'a StorageConnection) -> Self {
+        TemperatureLogRowRepository { connection }
+    }
+
+    #[cfg(feature = "postgres")]
+    pub fn upsert_one(&self, row: &TemperatureLogRow) -> Result<(), RepositoryError> {
+        diesel::insert_into(temperature_log_dsl::temperature_log)
+            .values(row)
+            .on_conflict(temperature_log_dsl::id)
+            .do_update()
+            .set(row)
+            .execute(&self.connection.connection)?;
+        Ok(())
+    }
+
+    #[cfg(not(feature = "postgres"))]
+    pub fn upsert_one(&self, row: &TemperatureLogRow) -> Result<(), RepositoryError> {
+        diesel::replace_into(temperature_log_dsl::temperature_log)
+            .values(row)
+            .execute(&self.connection.connection)?;
+        Ok(())
+    }
+
+    pub fn find_one_by_id(&self, id: &str) -> Result<Option<TemperatureLogRow>, RepositoryError> {
+        let result = temperature_log_dsl::temperature_log
+            .filter(temperature_log_dsl::id.eq(id))
+            .first(&self.connection.connection)
+            .optional()?;
+        Ok(result)
+    }
+
+    /// Readings of the location, optionally limited to a period (both ends inclusive), oldest
+    /// first
+    pub fn find_many_by_location_id(
+        &self,
+        store_id: &str,
+        location_id: &str,
+        from_datetime: Option<NaiveDateTime>,
+        to_datetime: Option<NaiveDateTime>,
+    ) -> Result<Vec<TemperatureLogRow>, RepositoryError> {
+        let mut query = temperature_log_dsl::temperature_log
+            .filter(temperature_log_dsl::store_id.eq(store_id))
+            .filter(temperature_log_dsl::location_id.eq(location_id))
+            .into_boxed();
+        if let Some(from_datetime) = from_datetime {
+            query = query.filter(temperature_log_dsl::datetime.ge(from_datetime));
+        }
+        if let Some(to_datetime) = to_datetime {
+            query = query.filter(temperature_log_dsl::datetime.le(to_datetime));
+        }
+        let result = query
+            .order(temperature_log_dsl::datetime.asc())
+            .load(&self.connection.connection)?;
+        Ok(result)
+    }
+}
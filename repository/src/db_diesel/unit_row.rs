@@ -37,4 +37,12 @@ impl<'a> UnitRowRepository<'a> {
             .first(&self.connection.connection)?;
         Ok(result)
     }
+
+    pub fn find_one_by_id_option(&self, unit_id: &str) -> Result<Option<UnitRow>, RepositoryError> {
+        let result = unit
+            .filter(id.eq(unit_id))
+            .first(&self.connection.connection)
+            .optional()?;
+        Ok(result)
+    }
 }
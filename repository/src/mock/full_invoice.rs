@@ -55,6 +55,7 @@ pub fn mock_full_draft_outbound_shipment_a() -> FullMockInvoice {
             picked_datetime: None,
             delivered_datetime: None,
             verified_datetime: None,
+            prescriber_id: None,
         },
         lines: vec![
             FullMockInvoiceLine {
@@ -77,6 +78,8 @@ pub fn mock_full_draft_outbound_shipment_a() -> FullMockInvoice {
                     r#type: InvoiceLineRowType::StockOut,
                     number_of_packs: 10,
                     note: None,
+                    return_reason_id: None,
+                    directions: None,
                 },
                 stock_line: StockLineRow {
                     id: stock_line_a_id,
@@ -114,6 +117,8 @@ pub fn mock_full_draft_outbound_shipment_a() -> FullMockInvoice {
                     r#type: InvoiceLineRowType::StockOut,
                     number_of_packs: 2,
                     note: None,
+                    return_reason_id: None,
+                    directions: None,
                 },
                 stock_line: StockLineRow {
                     id: stock_line_b_id,
@@ -156,6 +161,7 @@ pub fn mock_full_draft_inbound_shipment_on_hold() -> FullMockInvoice {
             shipped_datetime: None,
             delivered_datetime: None,
             verified_datetime: None,
+            prescriber_id: None,
         },
         lines: Vec::new(),
     }
@@ -182,6 +188,7 @@ pub fn mock_full_draft_outbound_shipment_on_hold() -> FullMockInvoice {
             shipped_datetime: None,
             delivered_datetime: None,
             verified_datetime: None,
+            prescriber_id: None,
         },
         lines: Vec::new(),
     }
@@ -20,6 +20,7 @@ pub fn mock_outbound_shipment_a() -> InvoiceRow {
         shipped_datetime: None,
         delivered_datetime: None,
         verified_datetime: None,
+        prescriber_id: None,
     }
 }
 
@@ -41,6 +42,7 @@ pub fn mock_outbound_shipment_b() -> InvoiceRow {
         allocated_datetime: Some(NaiveDate::from_ymd(1970, 1, 2).and_hms_milli(15, 30, 0, 0)),
         delivered_datetime: None,
         verified_datetime: None,
+        prescriber_id: None,
     }
 }
 
@@ -62,6 +64,7 @@ pub fn mock_outbound_shipment_c() -> InvoiceRow {
         shipped_datetime: None,
         delivered_datetime: None,
         verified_datetime: None,
+        prescriber_id: None,
     }
 }
 
@@ -83,6 +86,7 @@ pub fn mock_outbound_shipment_d() -> InvoiceRow {
         allocated_datetime: Some(NaiveDate::from_ymd(1970, 1, 2).and_hms_milli(15, 30, 0, 0)),
         delivered_datetime: None,
         verified_datetime: None,
+        prescriber_id: None,
     }
 }
 
@@ -105,6 +109,7 @@ pub fn mock_outbound_shipment_picked() -> InvoiceRow {
         shipped_datetime: None,
         delivered_datetime: None,
         verified_datetime: None,
+        prescriber_id: None,
     }
 }
 
@@ -126,6 +131,7 @@ pub fn mock_outbound_shipment_shipped() -> InvoiceRow {
         allocated_datetime: Some(NaiveDate::from_ymd(1970, 1, 5).and_hms_milli(15, 30, 0, 0)),
         delivered_datetime: None,
         verified_datetime: None,
+        prescriber_id: None,
     }
 }
 
@@ -148,6 +154,7 @@ pub fn mock_outbound_shipment_no_lines() -> InvoiceRow {
         shipped_datetime: None,
         delivered_datetime: None,
         verified_datetime: None,
+        prescriber_id: None,
     }
 }
 
@@ -169,6 +176,7 @@ pub fn mock_inbound_shipment_a() -> InvoiceRow {
         shipped_datetime: None,
         picked_datetime: None,
         verified_datetime: None,
+        prescriber_id: None,
     }
 }
 
@@ -190,6 +198,7 @@ pub fn mock_inbound_shipment_b() -> InvoiceRow {
         allocated_datetime: Some(NaiveDate::from_ymd(1970, 1, 4).and_hms_milli(21, 30, 0, 0)),
         delivered_datetime: Some(Utc::now().naive_utc()),
         verified_datetime: Some(Utc::now().naive_utc()),
+        prescriber_id: None,
     }
 }
 
@@ -211,6 +220,7 @@ pub fn mock_inbound_shipment_c() -> InvoiceRow {
         shipped_datetime: None,
         delivered_datetime: None,
         verified_datetime: None,
+        prescriber_id: None,
     }
 }
 
@@ -232,6 +242,7 @@ pub fn mock_inbound_shipment_d() -> InvoiceRow {
         shipped_datetime: None,
         picked_datetime: None,
         verified_datetime: None,
+        prescriber_id: None,
     }
 }
 
@@ -253,6 +264,7 @@ pub fn mock_empty_draft_inbound_shipment() -> InvoiceRow {
         shipped_datetime: None,
         delivered_datetime: None,
         verified_datetime: None,
+        prescriber_id: None,
     }
 }
 
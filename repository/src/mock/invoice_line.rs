@@ -22,6 +22,8 @@ pub fn mock_outbound_shipment_a_invoice_lines() -> Vec<InvoiceLineRow> {
         r#type: InvoiceLineRowType::StockOut,
         number_of_packs: 10,
         note: None,
+        return_reason_id: None,
+        directions: None,
     };
 
     let mock_outbound_shipment_a_invoice_line_b: InvoiceLineRow = InvoiceLineRow {
@@ -43,6 +45,8 @@ pub fn mock_outbound_shipment_a_invoice_lines() -> Vec<InvoiceLineRow> {
         r#type: InvoiceLineRowType::StockOut,
         number_of_packs: 4,
         note: None,
+        return_reason_id: None,
+        directions: None,
     };
 
     vec![
@@ -71,6 +75,8 @@ pub fn mock_outbound_shipment_b_invoice_lines() -> Vec<InvoiceLineRow> {
         r#type: InvoiceLineRowType::StockOut,
         number_of_packs: 3,
         note: None,
+        return_reason_id: None,
+        directions: None,
     };
 
     let mock_outbound_shipment_b_invoice_line_b: InvoiceLineRow = InvoiceLineRow {
@@ -92,6 +98,8 @@ pub fn mock_outbound_shipment_b_invoice_lines() -> Vec<InvoiceLineRow> {
         r#type: InvoiceLineRowType::StockOut,
         number_of_packs: 5,
         note: None,
+        return_reason_id: None,
+        directions: None,
     };
 
     vec![
@@ -120,6 +128,8 @@ pub fn mock_outbound_shipment_c_invoice_lines() -> Vec<InvoiceLineRow> {
         r#type: InvoiceLineRowType::StockOut,
         number_of_packs: 3,
         note: None,
+        return_reason_id: None,
+        directions: None,
     };
 
     let mock_outbound_shipment_c_invoice_line_b: InvoiceLineRow = InvoiceLineRow {
@@ -141,6 +151,8 @@ pub fn mock_outbound_shipment_c_invoice_lines() -> Vec<InvoiceLineRow> {
         r#type: InvoiceLineRowType::StockOut,
         number_of_packs: 1,
         note: None,
+        return_reason_id: None,
+        directions: None,
     };
 
     vec![
@@ -169,6 +181,8 @@ pub fn mock_outbound_shipment_d_invoice_lines() -> Vec<InvoiceLineRow> {
         r#type: InvoiceLineRowType::StockOut,
         number_of_packs: 2,
         note: None,
+        return_reason_id: None,
+        directions: None,
     };
 
     vec![mock_outbound_shipment_d_invoice_line_a]
@@ -194,6 +208,8 @@ pub fn mock_inbound_shipment_a_invoice_lines() -> Vec<InvoiceLineRow> {
         r#type: InvoiceLineRowType::StockIn,
         number_of_packs: 1,
         note: None,
+        return_reason_id: None,
+        directions: None,
     };
 
     let mock_inbound_shipment_a_invoice_line_b: InvoiceLineRow = InvoiceLineRow {
@@ -215,6 +231,8 @@ pub fn mock_inbound_shipment_a_invoice_lines() -> Vec<InvoiceLineRow> {
         r#type: InvoiceLineRowType::StockIn,
         number_of_packs: 1,
         note: None,
+        return_reason_id: None,
+        directions: None,
     };
 
     vec![
@@ -243,6 +261,8 @@ pub fn mock_inbound_shipment_b_invoice_lines() -> Vec<InvoiceLineRow> {
         r#type: InvoiceLineRowType::StockIn,
         number_of_packs: 1,
         note: None,
+        return_reason_id: None,
+        directions: None,
     };
 
     let mock_inbound_shipment_b_invoice_line_b: InvoiceLineRow = InvoiceLineRow {
@@ -264,6 +284,8 @@ pub fn mock_inbound_shipment_b_invoice_lines() -> Vec<InvoiceLineRow> {
         r#type: InvoiceLineRowType::StockIn,
         number_of_packs: 1,
         note: None,
+        return_reason_id: None,
+        directions: None,
     };
 
     vec![
@@ -292,6 +314,8 @@ pub fn mock_inbound_shipment_c_invoice_lines() -> Vec<InvoiceLineRow> {
         r#type: InvoiceLineRowType::StockIn,
         number_of_packs: 3,
         note: None,
+        return_reason_id: None,
+        directions: None,
     };
 
     let mock_inbound_shipment_c_invoice_line_b: InvoiceLineRow = InvoiceLineRow {
@@ -313,6 +337,8 @@ pub fn mock_inbound_shipment_c_invoice_lines() -> Vec<InvoiceLineRow> {
         r#type: InvoiceLineRowType::StockIn,
         number_of_packs: 2,
         note: None,
+        return_reason_id: None,
+        directions: None,
     };
 
     vec![
@@ -341,6 +367,8 @@ pub fn mock_inbound_shipment_d_invoice_lines() -> Vec<InvoiceLineRow> {
         r#type: InvoiceLineRowType::StockIn,
         number_of_packs: 7,
         note: None,
+        return_reason_id: None,
+        directions: None,
     };
 
     let mock_inbound_shipment_d_invoice_line_b: InvoiceLineRow = InvoiceLineRow {
@@ -362,6 +390,8 @@ pub fn mock_inbound_shipment_d_invoice_lines() -> Vec<InvoiceLineRow> {
         r#type: InvoiceLineRowType::StockIn,
         number_of_packs: 2,
         note: None,
+        return_reason_id: None,
+        directions: None,
     };
 
     vec![
@@ -7,6 +7,12 @@ pub fn mock_item_a() -> ItemRow {
         code: String::from("item_a_code"),
         unit_id: None,
         r#type: ItemType::Stock,
+        category_id: None,
+        strength: None,
+        dosage_form: None,
+        ven_category: None,
+        is_controlled: false,
+        storage_requirement: None,
     }
 }
 
@@ -17,6 +23,12 @@ pub fn mock_item_b() -> ItemRow {
         code: String::from("item_b_code"),
         unit_id: None,
         r#type: ItemType::Stock,
+        category_id: None,
+        strength: None,
+        dosage_form: None,
+        ven_category: None,
+        is_controlled: false,
+        storage_requirement: None,
     }
 }
 
@@ -27,6 +39,12 @@ pub fn mock_item_c() -> ItemRow {
         code: String::from("item_c_code"),
         unit_id: None,
         r#type: ItemType::Stock,
+        category_id: None,
+        strength: None,
+        dosage_form: None,
+        ven_category: None,
+        is_controlled: false,
+        storage_requirement: None,
     }
 }
 
@@ -37,6 +55,12 @@ pub fn item_query_test1() -> ItemRow {
         code: String::from("code_item_query_test1"),
         unit_id: None,
         r#type: ItemType::Stock,
+        category_id: None,
+        strength: None,
+        dosage_form: None,
+        ven_category: None,
+        is_controlled: false,
+        storage_requirement: None,
     }
 }
 
@@ -47,6 +71,12 @@ pub fn item_query_test2() -> ItemRow {
         code: String::from("code_item_query_test2"),
         unit_id: Some("item_query_test2".to_owned()),
         r#type: ItemType::Stock,
+        category_id: None,
+        strength: None,
+        dosage_form: None,
+        ven_category: None,
+        is_controlled: false,
+        storage_requirement: None,
     }
 }
 
@@ -57,6 +87,12 @@ pub fn mock_item_service_item() -> ItemRow {
         code: String::from("code_mock_item_service_item"),
         unit_id: None,
         r#type: ItemType::Service,
+        category_id: None,
+        strength: None,
+        dosage_form: None,
+        ven_category: None,
+        is_controlled: false,
+        storage_requirement: None,
     }
 }
 
@@ -8,6 +8,9 @@ pub fn mock_locations() -> Vec<LocationRow> {
             name: "name_location_1".to_owned(),
             on_hold: false,
             store_id: "store_a".to_string(),
+            parent_id: None,
+            location_type_id: None,
+            volume: None,
         },
         LocationRow {
             id: "location_on_hold".to_owned(),
@@ -15,6 +18,9 @@ pub fn mock_locations() -> Vec<LocationRow> {
             name: "name_location_on_hold".to_owned(),
             on_hold: true,
             store_id: "store_a".to_string(),
+            parent_id: None,
+            location_type_id: None,
+            volume: None,
         },
         // For case insensitive sort
         LocationRow {
@@ -23,6 +29,9 @@ pub fn mock_locations() -> Vec<LocationRow> {
             name: "name_LocAtIOn_2".to_owned(),
             on_hold: false,
             store_id: "store_a".to_string(),
+            parent_id: None,
+            location_type_id: None,
+            volume: None,
         },
         // Location in another store, for unique code check
         LocationRow {
@@ -31,6 +40,9 @@ pub fn mock_locations() -> Vec<LocationRow> {
             name: "store_b_location_name".to_owned(),
             on_hold: false,
             store_id: "store_b".to_string(),
+            parent_id: None,
+            location_type_id: None,
+            volume: None,
         },
     ]
 }
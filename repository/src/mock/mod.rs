@@ -29,7 +29,7 @@ pub use invoice::*;
 pub use invoice_line::*;
 pub use item::*;
 pub use location::mock_locations;
-pub use name::{mock_name_store_a, mock_name_store_b, mock_names};
+pub use name::{mock_name_a, mock_name_store_a, mock_name_store_b, mock_names, mock_patient_a};
 pub use name_store_join::mock_name_store_joins;
 pub use number::*;
 pub use requisition::mock_requisitions;
@@ -1,4 +1,6 @@
-use crate::schema::NameRow;
+use chrono::NaiveDate;
+
+use crate::schema::{NameRow, NameRowType};
 
 pub fn mock_name_store_a() -> NameRow {
     NameRow {
@@ -7,6 +9,20 @@ pub fn mock_name_store_a() -> NameRow {
         code: String::from("code"),
         is_customer: false,
         is_supplier: true,
+        r#type: NameRowType::Store,
+        first_name: None,
+        last_name: None,
+        date_of_birth: None,
+        phone: None,
+        home_store_id: None,
+        is_manufacturer: false,
+        is_donor: false,
+        email: None,
+        website: None,
+        address1: None,
+        address2: None,
+        country: None,
+        comment: None,
     }
 }
 
@@ -17,6 +33,20 @@ pub fn mock_name_store_b() -> NameRow {
         code: String::from("code"),
         is_customer: false,
         is_supplier: true,
+        r#type: NameRowType::Store,
+        first_name: None,
+        last_name: None,
+        date_of_birth: None,
+        phone: None,
+        home_store_id: None,
+        is_manufacturer: false,
+        is_donor: false,
+        email: None,
+        website: None,
+        address1: None,
+        address2: None,
+        country: None,
+        comment: None,
     }
 }
 
@@ -27,6 +57,20 @@ pub fn mock_name_store_c() -> NameRow {
         code: String::from("code"),
         is_customer: false,
         is_supplier: true,
+        r#type: NameRowType::Store,
+        first_name: None,
+        last_name: None,
+        date_of_birth: None,
+        phone: None,
+        home_store_id: None,
+        is_manufacturer: false,
+        is_donor: false,
+        email: None,
+        website: None,
+        address1: None,
+        address2: None,
+        country: None,
+        comment: None,
     }
 }
 
@@ -37,6 +81,20 @@ pub fn mock_name_a() -> NameRow {
         code: String::from("name_a"),
         is_customer: false,
         is_supplier: true,
+        r#type: NameRowType::Facility,
+        first_name: None,
+        last_name: None,
+        date_of_birth: None,
+        phone: None,
+        home_store_id: None,
+        is_manufacturer: false,
+        is_donor: false,
+        email: None,
+        website: None,
+        address1: None,
+        address2: None,
+        country: None,
+        comment: None,
     }
 }
 
@@ -48,6 +106,20 @@ pub fn mock_name_invad() -> NameRow {
         code: String::from("invad"),
         is_customer: false,
         is_supplier: false,
+        r#type: NameRowType::Other,
+        first_name: None,
+        last_name: None,
+        date_of_birth: None,
+        phone: None,
+        home_store_id: None,
+        is_manufacturer: false,
+        is_donor: false,
+        email: None,
+        website: None,
+        address1: None,
+        address2: None,
+        country: None,
+        comment: None,
     }
 }
 
@@ -58,6 +130,45 @@ pub fn mock_name_master_list_filter_test() -> NameRow {
         code: String::from("master_list_filter_test"),
         is_customer: false,
         is_supplier: true,
+        r#type: NameRowType::Facility,
+        first_name: None,
+        last_name: None,
+        date_of_birth: None,
+        phone: None,
+        home_store_id: None,
+        is_manufacturer: false,
+        is_donor: false,
+        email: None,
+        website: None,
+        address1: None,
+        address2: None,
+        country: None,
+        comment: None,
+    }
+}
+
+// Patient registered in store_a
+pub fn mock_patient_a() -> NameRow {
+    NameRow {
+        id: String::from("patient_a"),
+        name: String::from("Doe, Jane"),
+        code: String::from("P0001"),
+        is_customer: false,
+        is_supplier: false,
+        r#type: NameRowType::Patient,
+        first_name: Some(String::from("Jane")),
+        last_name: Some(String::from("Doe")),
+        date_of_birth: Some(NaiveDate::from_ymd(1985, 3, 14)),
+        phone: Some(String::from("0211234567")),
+        home_store_id: Some(String::from("store_a")),
+        is_manufacturer: false,
+        is_donor: false,
+        email: None,
+        website: None,
+        address1: None,
+        address2: None,
+        country: None,
+        comment: None,
     }
 }
 
@@ -69,5 +180,6 @@ pub fn mock_names() -> Vec<NameRow> {
         mock_name_a(),
         mock_name_invad(),
         mock_name_master_list_filter_test(),
+        mock_patient_a(),
     ]
 }
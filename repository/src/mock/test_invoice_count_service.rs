@@ -22,6 +22,7 @@ pub fn mock_inbound_shipment_invoice_count_service_a() -> InvoiceRow {
         delivered_datetime: None,
         verified_datetime: None,
         color: None,
+        prescriber_id: None,
     }
 }
 
@@ -43,6 +44,7 @@ pub fn mock_inbound_shipment_invoice_count_service_b() -> InvoiceRow {
         delivered_datetime: None,
         verified_datetime: None,
         color: None,
+        prescriber_id: None,
     }
 }
 
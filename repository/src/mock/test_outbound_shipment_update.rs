@@ -27,6 +27,8 @@ fn mock_outbound_shipment_line_no_stock_line() -> InvoiceLineRow {
         r#type: InvoiceLineRowType::StockOut,
         number_of_packs: 1,
         note: None,
+        return_reason_id: None,
+        directions: None,
     }
 }
 
@@ -37,6 +39,12 @@ fn mock_item_with_no_stock_line() -> ItemRow {
         code: String::from("code"),
         unit_id: None,
         r#type: ItemRowType::Stock,
+        category_id: None,
+        strength: None,
+        dosage_form: None,
+        ven_category: None,
+        is_controlled: false,
+        storage_requirement: None,
     }
 }
 
@@ -59,6 +67,7 @@ fn mock_outbound_shipment_invalid_stock_line() -> InvoiceRow {
         shipped_datetime: None,
         delivered_datetime: None,
         verified_datetime: None,
+        prescriber_id: None,
     }
 }
 
@@ -38,6 +38,7 @@ pub fn mock_new_invoice_with_unallocated_line() -> InvoiceRow {
         delivered_datetime: None,
         verified_datetime: None,
         color: None,
+        prescriber_id: None,
     }
 }
 
@@ -61,6 +62,8 @@ pub fn mock_unallocated_line() -> InvoiceLineRow {
         r#type: InvoiceLineRowType::UnallocatedStock,
         number_of_packs: 1,
         note: None,
+        return_reason_id: None,
+        directions: None,
     }
 }
 
@@ -84,6 +87,7 @@ pub fn mock_new_invoice_with_unallocated_line2() -> InvoiceRow {
         delivered_datetime: None,
         verified_datetime: None,
         color: None,
+        prescriber_id: None,
     }
 }
 
@@ -107,6 +111,8 @@ pub fn mock_unallocated_line2() -> InvoiceLineRow {
         r#type: InvoiceLineRowType::UnallocatedStock,
         number_of_packs: 1,
         note: None,
+        return_reason_id: None,
+        directions: None,
     }
 }
 
@@ -128,5 +134,6 @@ pub fn mock_allocated_invoice() -> InvoiceRow {
         delivered_datetime: None,
         verified_datetime: None,
         color: None,
+        prescriber_id: None,
     }
 }
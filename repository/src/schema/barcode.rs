@@ -0,0 +1,15 @@
+use super::diesel_schema::barcode;
+
+/// Barcode of an item, synced from central or created locally
+#[derive(Clone, Queryable, Insertable, AsChangeset, Debug, PartialEq)]
+#[table_name = "barcode"]
+pub struct BarcodeRow {
+    pub id: String,
+    /// Scanned value, e.g. a GTIN
+    pub value: String,
+    pub item_id: String,
+    /// Name id of the manufacturer
+    pub manufacturer_id: Option<String>,
+    /// Pack size the barcode is printed on
+    pub pack_size: Option<i32>,
+}
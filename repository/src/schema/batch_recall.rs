@@ -0,0 +1,27 @@
+use super::diesel_schema::{batch_recall, batch_recall_line};
+
+use chrono::NaiveDateTime;
+
+/// A manufacturer recall, identified by the item and batch or by the recalled stock lines
+#[derive(Clone, Queryable, Insertable, AsChangeset, Debug, PartialEq)]
+#[table_name = "batch_recall"]
+pub struct BatchRecallRow {
+    pub id: String,
+    pub store_id: String,
+    /// Recall reference of the manufacturer or regulator
+    pub reference: String,
+    pub item_id: Option<String>,
+    pub batch: Option<String>,
+    pub created_datetime: NaiveDateTime,
+}
+
+/// Stock line put on hold for a recall
+#[derive(Clone, Queryable, Insertable, AsChangeset, Debug, PartialEq)]
+#[table_name = "batch_recall_line"]
+pub struct BatchRecallLineRow {
+    pub id: String,
+    pub batch_recall_id: String,
+    pub stock_line_id: String,
+    /// Packs on hand when the stock line was put on hold
+    pub number_of_packs: i32,
+}
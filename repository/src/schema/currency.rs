@@ -0,0 +1,10 @@
+use super::diesel_schema::currency;
+
+#[derive(Clone, Queryable, Insertable, AsChangeset, Debug, PartialEq)]
+#[table_name = "currency"]
+pub struct CurrencyRow {
+    pub id: String,
+    pub code: String,
+    pub rate: f64,
+    pub is_home_currency: bool,
+}
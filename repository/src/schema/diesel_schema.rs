@@ -29,6 +29,9 @@ table! {
         code -> Text,
         on_hold -> Bool,
         store_id -> Text,
+        parent_id -> Nullable<Text>,
+        location_type_id -> Nullable<Text>,
+        volume -> Nullable<Double>,
     }
 }
 
@@ -39,6 +42,12 @@ table! {
         code -> Text,
         unit_id -> Nullable<Text>,
         #[sql_name = "type"] type_ -> crate::schema::item::ItemRowTypeMapping,
+        category_id -> Nullable<Text>,
+        strength -> Nullable<Text>,
+        dosage_form -> Nullable<Text>,
+        ven_category -> Nullable<crate::schema::item::ItemRowVenCategoryMapping>,
+        is_controlled -> Bool,
+        storage_requirement -> Nullable<Text>,
     }
 }
 
@@ -68,6 +77,46 @@ table! {
         code -> Text,
         is_customer -> Bool,
         is_supplier -> Bool,
+        #[sql_name = "type"] type_ -> crate::schema::name::NameRowTypeMapping,
+        first_name -> Nullable<Text>,
+        last_name -> Nullable<Text>,
+        date_of_birth -> Nullable<Date>,
+        phone -> Nullable<Text>,
+        home_store_id -> Nullable<Text>,
+        is_manufacturer -> Bool,
+        is_donor -> Bool,
+        email -> Nullable<Text>,
+        website -> Nullable<Text>,
+        address1 -> Nullable<Text>,
+        address2 -> Nullable<Text>,
+        country -> Nullable<Text>,
+        comment -> Nullable<Text>,
+    }
+}
+
+table! {
+    name_tag (id) {
+        id -> Text,
+        name -> Text,
+    }
+}
+
+table! {
+    name_tag_join (id) {
+        id -> Text,
+        name_id -> Text,
+        name_tag_id -> Text,
+    }
+}
+
+table! {
+    prescriber (id) {
+        id -> Text,
+        code -> Text,
+        first_name -> Nullable<Text>,
+        last_name -> Text,
+        registration_code -> Nullable<Text>,
+        home_store_id -> Nullable<Text>,
     }
 }
 
@@ -101,11 +150,11 @@ table! {
 table! {
     sync_out (id) {
         id -> Text,
-        created_at -> Date,
+        created_at -> Timestamp,
         table_name -> crate::schema::sync_out::SyncOutRowTableNameTypeMapping,
         record_id -> Text,
         store_id -> Text,
-        site_id -> Integer,
+        site_id -> Nullable<Integer>,
         action -> crate::schema::sync_out::SyncOutRowActionTypeMapping,
     }
 }
@@ -128,6 +177,7 @@ table! {
         delivered_datetime -> Nullable<Timestamp>,
         verified_datetime -> Nullable<Timestamp>,
         color -> Nullable<Text>,
+        prescriber_id -> Nullable<Text>,
     }
 }
 
@@ -151,6 +201,8 @@ table! {
         #[sql_name = "type"] type_ -> crate::schema::invoice_line::InvoiceLineRowTypeMapping,
         number_of_packs -> Integer,
         note -> Nullable<Text>,
+        return_reason_id -> Nullable<Text>,
+        directions -> Nullable<Text>,
     }
 }
 
@@ -166,6 +218,55 @@ table! {
     }
 }
 
+table! {
+    stock_on_hand (store_id, item_id) {
+        store_id -> Text,
+        item_id -> Text,
+        total_number_of_packs -> BigInt,
+        available_number_of_packs -> BigInt,
+        total_units -> BigInt,
+        available_units -> BigInt,
+        number_of_batches -> BigInt,
+        earliest_expiry_date -> Nullable<Date>,
+        total_cost_value -> Double,
+    }
+}
+
+table! {
+    stock_on_hand_by_location (store_id, item_id, location_id) {
+        store_id -> Text,
+        item_id -> Text,
+        location_id -> Nullable<Text>,
+        total_number_of_packs -> BigInt,
+        available_number_of_packs -> BigInt,
+        total_units -> BigInt,
+        available_units -> BigInt,
+        number_of_batches -> BigInt,
+        earliest_expiry_date -> Nullable<Date>,
+        total_cost_value -> Double,
+    }
+}
+
+table! {
+    stock_movement (id) {
+        id -> Text,
+        store_id -> Text,
+        item_id -> Text,
+        stock_line_id -> Nullable<Text>,
+        location_id -> Nullable<Text>,
+        batch -> Nullable<Text>,
+        invoice_id -> Text,
+        invoice_type -> crate::schema::invoice::InvoiceRowTypeMapping,
+        invoice_number -> BigInt,
+        other_party_id -> Text,
+        other_party_name -> Text,
+        datetime -> Timestamp,
+        pack_size -> Integer,
+        cost_price_per_pack -> Double,
+        number_of_packs -> Integer,
+    }
+}
+
 table! {
     user_account (id) {
         id -> Text,
@@ -211,9 +312,9 @@ table! {
 }
 
 table! {
-    item_is_visible (id) {
-        id -> Text,
-        is_visible -> Bool,
+    item_is_visible_in_store (item_id, store_id) {
+        item_id -> Text,
+        store_id -> Text,
     }
 }
 
@@ -260,6 +361,73 @@ table! {
     }
 }
 
+table! {
+    currency (id) {
+        id -> Text,
+        code -> Text,
+        rate -> Double,
+        is_home_currency -> Bool,
+    }
+}
+
+table! {
+    location_type (id) {
+        id -> Text,
+        name -> Text,
+        min_temperature -> Nullable<Double>,
+        max_temperature -> Nullable<Double>,
+    }
+}
+
+table! {
+    store_preference (id) {
+        id -> Text,
+        store_id -> Text,
+        pack_to_one -> Bool,
+        months_overstock -> Double,
+        months_understock -> Double,
+        months_items_expire -> Double,
+    }
+}
+
+table! {
+    store_preference_override (id) {
+        id -> Text,
+        store_id -> Text,
+        pack_to_one -> Nullable<Bool>,
+        months_overstock -> Nullable<Double>,
+        months_understock -> Nullable<Double>,
+        months_items_expire -> Nullable<Double>,
+        allow_negative_stock -> Nullable<Bool>,
+        timezone_offset -> Nullable<Integer>,
+        months_of_stock_target -> Nullable<Double>,
+        requires_reason_on_adjustment -> Nullable<Bool>,
+        expiring_soon_days -> Nullable<Integer>,
+    }
+}
+
+table! {
+    reason (id) {
+        id -> Text,
+        #[sql_name = "type"] type_ -> crate::schema::reason::ReasonRowTypeMapping,
+        #[sql_name = "reason"] reason_ -> Text,
+        is_active -> Bool,
+    }
+}
+
+table! {
+    item_store_join (id) {
+        id -> Text,
+        item_id -> Text,
+        store_id -> Text,
+        default_sell_price_per_pack -> Double,
+        margin -> Double,
+        ignore_for_orders -> Bool,
+        inactive -> Bool,
+        restricted_location_type_id -> Nullable<Text>,
+    }
+}
+
 joinable!(item -> unit (unit_id));
 joinable!(stock_line -> item (item_id));
 joinable!(stock_line -> store (store_id));
@@ -272,21 +440,187 @@ joinable!(store -> name (name_id));
 joinable!(sync_out -> store (store_id));
 joinable!(invoice -> name (name_id));
 joinable!(invoice -> store (store_id));
+joinable!(invoice -> prescriber (prescriber_id));
 joinable!(invoice_line -> item (item_id));
 joinable!(invoice_line -> stock_line (stock_line_id));
 joinable!(invoice_line -> invoice (invoice_id));
 joinable!(invoice_line -> location (location_id));
 joinable!(name_store_join -> store (store_id));
 joinable!(name_store_join -> name (name_id));
+joinable!(name_tag_join -> name (name_id));
+joinable!(name_tag_join -> name_tag (name_tag_id));
 joinable!(master_list_line -> master_list (master_list_id));
 joinable!(master_list_line -> item (item_id));
 joinable!(master_list_name_join -> master_list (master_list_id));
 joinable!(master_list_name_join -> name (name_id));
-joinable!(item_is_visible -> item (id));
 joinable!(location -> store (store_id));
 joinable!(stock_take_line -> location (location_id));
 joinable!(stock_take_line -> stock_take (stock_take_id));
 joinable!(stock_take_line -> stock_line (stock_line_id));
+joinable!(store_preference -> store (store_id));
+joinable!(store_preference_override -> store (store_id));
+table! {
+    batch_recall (id) {
+        id -> Text,
+        store_id -> Text,
+        reference -> Text,
+        item_id -> Nullable<Text>,
+        batch -> Nullable<Text>,
+        created_datetime -> Timestamp,
+    }
+}
+
+table! {
+    batch_recall_line (id) {
+        id -> Text,
+        batch_recall_id -> Text,
+        stock_line_id -> Text,
+        number_of_packs -> Integer,
+    }
+}
+
+table! {
+    location_movement (id) {
+        id -> Text,
+        store_id -> Text,
+        stock_line_id -> Text,
+        split_from_stock_line_id -> Nullable<Text>,
+        from_location_id -> Nullable<Text>,
+        to_location_id -> Text,
+        number_of_packs -> Integer,
+        moved_datetime -> Timestamp,
+    }
+}
+
+table! {
+    temperature_log (id) {
+        id -> Text,
+        store_id -> Text,
+        location_id -> Text,
+        temperature -> Double,
+        datetime -> Timestamp,
+    }
+}
+
+table! {
+    temperature_breach_config (id) {
+        id -> Text,
+        location_type_id -> Text,
+        description -> Text,
+        minimum_temperature -> Nullable<Double>,
+        maximum_temperature -> Nullable<Double>,
+        duration_minutes -> Integer,
+        is_active -> Bool,
+    }
+}
+
+table! {
+    temperature_breach (id) {
+        id -> Text,
+        store_id -> Text,
+        location_id -> Text,
+        temperature_breach_config_id -> Text,
+        start_datetime -> Timestamp,
+        end_datetime -> Nullable<Timestamp>,
+        duration_minutes -> Integer,
+    }
+}
+
+table! {
+    report (id) {
+        id -> Text,
+        name -> Text,
+        context -> crate::schema::report::ReportContextMapping,
+        template -> Text,
+    }
+}
+
+table! {
+    barcode (id) {
+        id -> Text,
+        value -> Text,
+        item_id -> Text,
+        manufacturer_id -> Nullable<Text>,
+        pack_size -> Nullable<Integer>,
+    }
+}
+
+table! {
+    pack_variant (id) {
+        id -> Text,
+        item_id -> Text,
+        name -> Text,
+        pack_size -> Integer,
+        unit_id -> Nullable<Text>,
+        volume_per_pack -> Nullable<Double>,
+        weight_per_pack -> Nullable<Double>,
+    }
+}
+
+table! {
+    item_category (id) {
+        id -> Text,
+        name -> Text,
+        parent_id -> Nullable<Text>,
+    }
+}
+
+table! {
+    item_property_override (id) {
+        id -> Text,
+        item_id -> Text,
+        category_id -> Nullable<Text>,
+        strength -> Nullable<Text>,
+        dosage_form -> Nullable<Text>,
+        ven_category -> Nullable<crate::schema::item::ItemRowVenCategoryMapping>,
+        is_controlled -> Nullable<Bool>,
+        storage_requirement -> Nullable<Text>,
+    }
+}
+
+table! {
+    item_property (item_id) {
+        item_id -> Text,
+        category_id -> Nullable<Text>,
+        strength -> Nullable<Text>,
+        dosage_form -> Nullable<Text>,
+        ven_category -> Nullable<crate::schema::item::ItemRowVenCategoryMapping>,
+        is_controlled -> Bool,
+        storage_requirement -> Nullable<Text>,
+    }
+}
+
+table! {
+    item_abc_class (id) {
+        id -> Text,
+        store_id -> Text,
+        item_id -> Text,
+        class -> crate::schema::item_abc_class::ItemAbcClassRowClassMapping,
+        consumption_value -> Double,
+        calculated_datetime -> Timestamp,
+    }
+}
+
+joinable!(item_store_join -> item (item_id));
+joinable!(item_store_join -> store (store_id));
+joinable!(stock_on_hand -> item (item_id));
+joinable!(stock_on_hand_by_location -> item (item_id));
+joinable!(batch_recall -> store (store_id));
+joinable!(batch_recall_line -> batch_recall (batch_recall_id));
+joinable!(batch_recall_line -> stock_line (stock_line_id));
+joinable!(location_movement -> store (store_id));
+joinable!(temperature_log -> store (store_id));
+joinable!(temperature_breach_config -> location_type (location_type_id));
+joinable!(temperature_breach -> store (store_id));
+joinable!(temperature_breach -> temperature_breach_config (temperature_breach_config_id));
+joinable!(barcode -> item (item_id));
+joinable!(pack_variant -> item (item_id));
+joinable!(pack_variant -> unit (unit_id));
+joinable!(item -> item_category (category_id));
+joinable!(item_property_override -> item (item_id));
+joinable!(item_property -> item (item_id));
+joinable!(item_abc_class -> item (item_id));
+joinable!(item_abc_class -> store (store_id));
 
 allow_tables_to_appear_in_same_query!(
     unit,
@@ -296,6 +630,7 @@ allow_tables_to_appear_in_same_query!(
     item,
     stock_line,
     name,
+    prescriber,
     requisition,
     requisition_line,
     store,
@@ -305,9 +640,33 @@ allow_tables_to_appear_in_same_query!(
     invoice_stats,
     user_account,
     name_store_join,
+    name_tag,
+    name_tag_join,
     master_list_line,
     master_list_name_join,
-    item_is_visible,
+    item_is_visible_in_store,
     stock_take,
     stock_take_line,
+    currency,
+    location_type,
+    store_preference,
+    store_preference_override,
+    reason,
+    item_store_join,
+    stock_on_hand,
+    stock_on_hand_by_location,
+    stock_movement,
+    batch_recall,
+    batch_recall_line,
+    location_movement,
+    temperature_log,
+    temperature_breach_config,
+    temperature_breach,
+    report,
+    barcode,
+    pack_variant,
+    item_category,
+    item_property_override,
+    item_property,
+    item_abc_class,
 );
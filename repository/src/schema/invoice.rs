@@ -8,6 +8,12 @@ pub enum InvoiceRowType {
     OutboundShipment,
     InboundShipment,
     InventoryAdjustment,
+    SupplierReturn,
+    CustomerReturn,
+    Repack,
+    Prescription,
+    /// Stock moved to another location, part of a stock line is split off into a new stock line
+    LocationMovement,
 }
 
 #[derive(DbEnum, Debug, Clone, PartialEq, Eq)]
@@ -41,4 +47,6 @@ pub struct InvoiceRow {
     pub delivered_datetime: Option<NaiveDateTime>,
     pub verified_datetime: Option<NaiveDateTime>,
     pub color: Option<String>,
+    /// Prescriber of a prescription
+    pub prescriber_id: Option<String>,
 }
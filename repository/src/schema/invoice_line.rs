@@ -49,4 +49,8 @@ pub struct InvoiceLineRow {
     pub r#type: InvoiceLineRowType,
     pub number_of_packs: i32,
     pub note: Option<String>,
+    /// Reason of return lines, a reason of type `ReturnReason`
+    pub return_reason_id: Option<String>,
+    /// How to take the dispensed stock of a prescription line
+    pub directions: Option<String>,
 }
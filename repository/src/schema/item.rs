@@ -9,6 +9,15 @@ pub enum ItemRowType {
     NonStock,
 }
 
+/// Essential medicines (VEN) classification
+#[derive(DbEnum, Debug, Clone, PartialEq, Eq)]
+#[DbValueStyle = "SCREAMING_SNAKE_CASE"]
+pub enum ItemRowVenCategory {
+    Vital,
+    Essential,
+    NonEssential,
+}
+
 #[derive(Clone, Insertable, Queryable, Debug, PartialEq, Eq, AsChangeset)]
 #[table_name = "item"]
 pub struct ItemRow {
@@ -18,4 +27,12 @@ pub struct ItemRow {
     pub unit_id: Option<String>,
     #[column_name = "type_"]
     pub r#type: ItemRowType,
+    // Properties as synced from central, see ItemPropertyRow for the properties with local
+    // overrides applied
+    pub category_id: Option<String>,
+    pub strength: Option<String>,
+    pub dosage_form: Option<String>,
+    pub ven_category: Option<ItemRowVenCategory>,
+    pub is_controlled: bool,
+    pub storage_requirement: Option<String>,
 }
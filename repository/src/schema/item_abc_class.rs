@@ -0,0 +1,23 @@
+use super::diesel_schema::item_abc_class;
+use chrono::NaiveDateTime;
+use diesel_derive_enum::DbEnum;
+
+#[derive(DbEnum, Debug, Clone, PartialEq, Eq)]
+#[DbValueStyle = "SCREAMING_SNAKE_CASE"]
+pub enum ItemAbcClassRowClass {
+    A,
+    B,
+    C,
+}
+
+#[derive(Clone, Queryable, Insertable, AsChangeset, Debug, PartialEq)]
+#[table_name = "item_abc_class"]
+pub struct ItemAbcClassRow {
+    pub id: String,
+    pub store_id: String,
+    pub item_id: String,
+    pub class: ItemAbcClassRowClass,
+    /// Cost of the stock issued in the period the class was calculated for
+    pub consumption_value: f64,
+    pub calculated_datetime: NaiveDateTime,
+}
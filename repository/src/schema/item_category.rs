@@ -0,0 +1,10 @@
+use super::diesel_schema::item_category;
+
+#[derive(Clone, Queryable, Insertable, AsChangeset, Debug, PartialEq, Eq)]
+#[table_name = "item_category"]
+pub struct ItemCategoryRow {
+    pub id: String,
+    pub name: String,
+    /// Category this category is a sub category of
+    pub parent_id: Option<String>,
+}
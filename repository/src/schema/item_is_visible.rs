@@ -1,5 +0,0 @@
-#[derive(Clone, Queryable, Debug, PartialEq, Eq)]
-pub struct ItemIsVisibleRow {
-    pub id: String,
-    pub is_visible: bool,
-}
@@ -0,0 +1,6 @@
+/// Item which is visible in a store, see the `item_is_visible_in_store` view
+#[derive(Clone, Queryable, Debug, PartialEq, Eq)]
+pub struct ItemIsVisibleInStoreRow {
+    pub item_id: String,
+    pub store_id: String,
+}
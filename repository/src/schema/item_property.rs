@@ -0,0 +1,28 @@
+use super::{diesel_schema::item_property_override, ItemRowVenCategory};
+
+/// Locally changed item properties, a `None` value means the property is not overridden.
+#[derive(Clone, Queryable, Insertable, AsChangeset, Debug, PartialEq, Eq)]
+#[table_name = "item_property_override"]
+pub struct ItemPropertyOverrideRow {
+    pub id: String,
+    pub item_id: String,
+    pub category_id: Option<String>,
+    pub strength: Option<String>,
+    pub dosage_form: Option<String>,
+    pub ven_category: Option<ItemRowVenCategory>,
+    pub is_controlled: Option<bool>,
+    pub storage_requirement: Option<String>,
+}
+
+/// Properties of an item from the item_property view, i.e. the synced properties of the item with
+/// the local overrides applied
+#[derive(Clone, Queryable, Debug, PartialEq, Eq)]
+pub struct ItemPropertyRow {
+    pub item_id: String,
+    pub category_id: Option<String>,
+    pub strength: Option<String>,
+    pub dosage_form: Option<String>,
+    pub ven_category: Option<ItemRowVenCategory>,
+    pub is_controlled: bool,
+    pub storage_requirement: Option<String>,
+}
@@ -0,0 +1,15 @@
+use super::diesel_schema::item_store_join;
+
+#[derive(Clone, Queryable, Insertable, AsChangeset, Debug, PartialEq)]
+#[table_name = "item_store_join"]
+pub struct ItemStoreJoinRow {
+    pub id: String,
+    pub item_id: String,
+    pub store_id: String,
+    pub default_sell_price_per_pack: f64,
+    pub margin: f64,
+    pub ignore_for_orders: bool,
+    pub inactive: bool,
+    /// Location type the item has to be stored in, e.g. a cold room
+    pub restricted_location_type_id: Option<String>,
+}
@@ -8,4 +8,10 @@ pub struct LocationRow {
     pub code: String,
     pub on_hold: bool,
     pub store_id: String,
+    /// Enclosing location, e.g. the room of a shelf
+    pub parent_id: Option<String>,
+    /// Inherited from the parent location when not set
+    pub location_type_id: Option<String>,
+    /// Capacity in litres
+    pub volume: Option<f64>,
 }
@@ -0,0 +1,19 @@
+use super::diesel_schema::location_movement;
+
+use chrono::NaiveDateTime;
+
+/// Packs of a stock line moved from one location to another
+#[derive(Clone, Queryable, Insertable, AsChangeset, Debug, PartialEq)]
+#[table_name = "location_movement"]
+pub struct LocationMovementRow {
+    pub id: String,
+    pub store_id: String,
+    /// Stock line holding the moved packs
+    pub stock_line_id: String,
+    /// Set when the packs were split off another stock line
+    pub split_from_stock_line_id: Option<String>,
+    pub from_location_id: Option<String>,
+    pub to_location_id: String,
+    pub number_of_packs: i32,
+    pub moved_datetime: NaiveDateTime,
+}
@@ -0,0 +1,10 @@
+use super::diesel_schema::location_type;
+
+#[derive(Clone, Queryable, Insertable, AsChangeset, Debug, PartialEq)]
+#[table_name = "location_type"]
+pub struct LocationTypeRow {
+    pub id: String,
+    pub name: String,
+    pub min_temperature: Option<f64>,
+    pub max_temperature: Option<f64>,
+}
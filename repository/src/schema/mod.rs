@@ -1,24 +1,43 @@
+mod barcode;
+mod batch_recall;
 mod central_sync_buffer;
 mod central_sync_cursor;
+mod currency;
 mod invoice;
 mod invoice_line;
 mod invoice_stats;
 mod item;
-mod item_is_visible;
+mod item_abc_class;
+mod item_category;
+mod item_is_visible_in_store;
+mod item_property;
+mod item_store_join;
 mod location;
+mod location_movement;
+mod location_type;
 mod master_list;
 mod master_list_line;
 mod master_list_name_join;
 mod name;
 mod name_store_join;
+mod name_tag;
 mod number;
+mod pack_variant;
+mod prescriber;
+mod reason;
+mod report;
 mod requisition;
 mod requisition_line;
 mod stock_line;
+mod stock_movement;
+mod stock_on_hand;
 mod stock_take;
 mod stock_take_line;
 mod store;
+mod store_preference;
+mod store_preference_override;
 mod sync_out;
+mod temperature_log;
 mod unit;
 mod user_account;
 
@@ -39,26 +58,45 @@ pub enum DatabaseRow {
     SyncOut(SyncOutRow),
 }
 
+pub use barcode::BarcodeRow;
+pub use batch_recall::{BatchRecallLineRow, BatchRecallRow};
 pub use central_sync_buffer::CentralSyncBufferRow;
 pub use central_sync_cursor::CentralSyncCursorRow;
+pub use currency::CurrencyRow;
 pub use invoice::{InvoiceRow, InvoiceRowStatus, InvoiceRowType};
 pub use invoice_line::{InvoiceLineRow, InvoiceLineRowType};
 pub use invoice_stats::InvoiceStatsRow;
-pub use item::{ItemRow, ItemRowType};
-pub use item_is_visible::ItemIsVisibleRow;
+pub use item::{ItemRow, ItemRowType, ItemRowVenCategory};
+pub use item_abc_class::{ItemAbcClassRow, ItemAbcClassRowClass};
+pub use item_category::ItemCategoryRow;
+pub use item_is_visible_in_store::ItemIsVisibleInStoreRow;
+pub use item_property::{ItemPropertyOverrideRow, ItemPropertyRow};
+pub use item_store_join::ItemStoreJoinRow;
 pub use location::LocationRow;
+pub use location_movement::LocationMovementRow;
+pub use location_type::LocationTypeRow;
 pub use master_list::MasterListRow;
 pub use master_list_line::MasterListLineRow;
 pub use master_list_name_join::MasterListNameJoinRow;
-pub use name::NameRow;
+pub use name::{NameRow, NameRowType};
 pub use name_store_join::NameStoreJoinRow;
+pub use name_tag::{NameTagJoinRow, NameTagRow};
 pub use number::{NumberRow, NumberRowType};
+pub use pack_variant::PackVariantRow;
+pub use prescriber::PrescriberRow;
+pub use reason::{ReasonRow, ReasonRowType};
+pub use report::{ReportContext, ReportRow};
 pub use requisition::{RequisitionRow, RequisitionRowType};
 pub use requisition_line::RequisitionLineRow;
 pub use stock_line::StockLineRow;
+pub use stock_movement::StockMovementRow;
+pub use stock_on_hand::{StockOnHandByLocationRow, StockOnHandRow};
 pub use stock_take::*;
 pub use stock_take_line::*;
 pub use store::StoreRow;
+pub use store_preference::StorePreferenceRow;
+pub use store_preference_override::StorePreferenceOverrideRow;
 pub use sync_out::{SyncOutRow, SyncOutRowActionType, SyncOutRowTableNameType};
+pub use temperature_log::{TemperatureBreachConfigRow, TemperatureBreachRow, TemperatureLogRow};
 pub use unit::UnitRow;
 pub use user_account::UserAccountRow;
@@ -1,4 +1,17 @@
 use super::diesel_schema::name;
+use chrono::NaiveDate;
+use diesel_derive_enum::DbEnum;
+
+#[derive(DbEnum, Debug, Clone, PartialEq, Eq)]
+#[DbValueStyle = "SCREAMING_SNAKE_CASE"]
+pub enum NameRowType {
+    /// Customers and suppliers, e.g. clinics and wholesalers
+    Facility,
+    /// Patient data, only editable in the home store of the patient
+    Patient,
+    Store,
+    Other,
+}
 
 #[derive(Clone, Queryable, Insertable, Debug, PartialEq, Eq, AsChangeset)]
 #[table_name = "name"]
@@ -9,4 +22,21 @@ pub struct NameRow {
     pub code: String,
     pub is_customer: bool,
     pub is_supplier: bool,
+    #[column_name = "type_"]
+    pub r#type: NameRowType,
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    pub date_of_birth: Option<NaiveDate>,
+    pub phone: Option<String>,
+    /// Store the name was created in, names can only be edited in their home store. Names created
+    /// in central (other than patients) have no home store and can't be edited locally.
+    pub home_store_id: Option<String>,
+    pub is_manufacturer: bool,
+    pub is_donor: bool,
+    pub email: Option<String>,
+    pub website: Option<String>,
+    pub address1: Option<String>,
+    pub address2: Option<String>,
+    pub country: Option<String>,
+    pub comment: Option<String>,
 }
@@ -0,0 +1,17 @@
+use super::diesel_schema::{name_tag, name_tag_join};
+
+/// Tag (category) of names, e.g. "Health centre" or "District A"
+#[derive(Clone, Queryable, Insertable, AsChangeset, Debug, PartialEq, Eq)]
+#[table_name = "name_tag"]
+pub struct NameTagRow {
+    pub id: String,
+    pub name: String,
+}
+
+#[derive(Clone, Queryable, Insertable, AsChangeset, Debug, PartialEq, Eq)]
+#[table_name = "name_tag_join"]
+pub struct NameTagJoinRow {
+    pub id: String,
+    pub name_id: String,
+    pub name_tag_id: String,
+}
@@ -8,6 +8,11 @@ pub enum NumberRowType {
     InboundShipment,
     OutboundShipment,
     InventoryAdjustment,
+    SupplierReturn,
+    CustomerReturn,
+    Repack,
+    Prescription,
+    LocationMovement,
 }
 
 #[derive(Clone, Insertable, Queryable, Debug, PartialEq, Eq, AsChangeset)]
@@ -0,0 +1,18 @@
+use super::diesel_schema::pack_variant;
+
+/// Pack an item comes in, e.g. a bottle of 100 or a box of 10 blisters
+#[derive(Clone, Queryable, Insertable, AsChangeset, Debug, PartialEq)]
+#[table_name = "pack_variant"]
+pub struct PackVariantRow {
+    pub id: String,
+    pub item_id: String,
+    pub name: String,
+    /// Number of units of the item in one pack, unique per item
+    pub pack_size: i32,
+    /// Unit of the pack itself, e.g. bottle or box
+    pub unit_id: Option<String>,
+    /// Volume of one pack in litres
+    pub volume_per_pack: Option<f64>,
+    /// Weight of one pack in kilograms
+    pub weight_per_pack: Option<f64>,
+}
@@ -0,0 +1,14 @@
+use super::diesel_schema::prescriber;
+
+/// Clinician writing prescriptions, only editable in the home store
+#[derive(Clone, Queryable, Insertable, AsChangeset, Debug, PartialEq, Eq)]
+#[table_name = "prescriber"]
+pub struct PrescriberRow {
+    pub id: String,
+    pub code: String,
+    pub first_name: Option<String>,
+    pub last_name: String,
+    /// e.g. the number of the medical council registration
+    pub registration_code: Option<String>,
+    pub home_store_id: Option<String>,
+}
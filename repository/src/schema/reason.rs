@@ -0,0 +1,21 @@
+use super::diesel_schema::reason;
+use diesel_derive_enum::DbEnum;
+
+#[derive(DbEnum, Debug, Clone, PartialEq, Eq)]
+#[DbValueStyle = "SCREAMING_SNAKE_CASE"]
+pub enum ReasonRowType {
+    PositiveInventoryAdjustment,
+    NegativeInventoryAdjustment,
+    ReturnReason,
+}
+
+#[derive(Clone, Queryable, Insertable, AsChangeset, Debug, PartialEq, Eq)]
+#[table_name = "reason"]
+pub struct ReasonRow {
+    pub id: String,
+    #[column_name = "type_"]
+    pub r#type: ReasonRowType,
+    #[column_name = "reason_"]
+    pub reason: String,
+    pub is_active: bool,
+}
@@ -0,0 +1,22 @@
+use super::diesel_schema::report;
+use diesel_derive_enum::DbEnum;
+
+/// Type of record a report is printed for
+#[derive(DbEnum, Debug, Clone, Copy, PartialEq, Eq)]
+#[DbValueStyle = "SCREAMING_SNAKE_CASE"]
+pub enum ReportContext {
+    OutboundShipment,
+    InboundShipment,
+    StockTake,
+}
+
+/// Report template, either synced from central or added locally
+#[derive(Clone, Queryable, Insertable, AsChangeset, Debug, PartialEq, Eq)]
+#[table_name = "report"]
+pub struct ReportRow {
+    pub id: String,
+    pub name: String,
+    pub context: ReportContext,
+    /// Tera (Jinja2 like) HTML template
+    pub template: String,
+}
@@ -0,0 +1,24 @@
+use super::InvoiceRowType;
+
+use chrono::NaiveDateTime;
+
+#[derive(Clone, Queryable, Debug, PartialEq)]
+pub struct StockMovementRow {
+    pub id: String,
+    pub store_id: String,
+    pub item_id: String,
+    pub stock_line_id: Option<String>,
+    pub location_id: Option<String>,
+    pub batch: Option<String>,
+    pub invoice_id: String,
+    pub invoice_type: InvoiceRowType,
+    pub invoice_number: i64,
+    pub other_party_id: String,
+    pub other_party_name: String,
+    /// Time the stock changed
+    pub datetime: NaiveDateTime,
+    pub pack_size: i32,
+    pub cost_price_per_pack: f64,
+    /// Negative if stock left the store
+    pub number_of_packs: i32,
+}
@@ -0,0 +1,28 @@
+use chrono::NaiveDate;
+
+#[derive(Clone, Queryable, Debug, PartialEq)]
+pub struct StockOnHandRow {
+    pub store_id: String,
+    pub item_id: String,
+    pub total_number_of_packs: i64,
+    pub available_number_of_packs: i64,
+    pub total_units: i64,
+    pub available_units: i64,
+    pub number_of_batches: i64,
+    pub earliest_expiry_date: Option<NaiveDate>,
+    pub total_cost_value: f64,
+}
+
+#[derive(Clone, Queryable, Debug, PartialEq)]
+pub struct StockOnHandByLocationRow {
+    pub store_id: String,
+    pub item_id: String,
+    pub location_id: Option<String>,
+    pub total_number_of_packs: i64,
+    pub available_number_of_packs: i64,
+    pub total_units: i64,
+    pub available_units: i64,
+    pub number_of_batches: i64,
+    pub earliest_expiry_date: Option<NaiveDate>,
+    pub total_cost_value: f64,
+}
@@ -0,0 +1,12 @@
+use super::diesel_schema::store_preference;
+
+#[derive(Clone, Queryable, Insertable, AsChangeset, Debug, PartialEq)]
+#[table_name = "store_preference"]
+pub struct StorePreferenceRow {
+    pub id: String,
+    pub store_id: String,
+    pub pack_to_one: bool,
+    pub months_overstock: f64,
+    pub months_understock: f64,
+    pub months_items_expire: f64,
+}
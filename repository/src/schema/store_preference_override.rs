@@ -0,0 +1,18 @@
+use super::diesel_schema::store_preference_override;
+
+/// Locally changed store preferences, a `None` value means the preference is not overridden.
+#[derive(Clone, Queryable, Insertable, AsChangeset, Debug, PartialEq)]
+#[table_name = "store_preference_override"]
+pub struct StorePreferenceOverrideRow {
+    pub id: String,
+    pub store_id: String,
+    pub pack_to_one: Option<bool>,
+    pub months_overstock: Option<f64>,
+    pub months_understock: Option<f64>,
+    pub months_items_expire: Option<f64>,
+    pub allow_negative_stock: Option<bool>,
+    pub timezone_offset: Option<i32>,
+    pub months_of_stock_target: Option<f64>,
+    pub requires_reason_on_adjustment: Option<bool>,
+    pub expiring_soon_days: Option<i32>,
+}
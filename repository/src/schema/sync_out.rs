@@ -1,4 +1,5 @@
 use super::diesel_schema::sync_out;
+use chrono::NaiveDateTime;
 use diesel_derive_enum::DbEnum;
 
 #[derive(DbEnum, Debug, Clone, PartialEq, Eq)]
@@ -11,6 +12,8 @@ pub enum SyncOutRowTableNameType {
     InvoiceLine,
     Name,
     Store,
+    NameTag,
+    NameTagJoin,
 }
 
 #[derive(DbEnum, Debug, Clone, PartialEq, Eq)]
@@ -21,14 +24,17 @@ pub enum SyncOutRowActionType {
     Patch,
 }
 
+/// Record queued for upload to central
 #[derive(Clone, Queryable, Insertable, Debug, PartialEq, Eq)]
 #[table_name = "sync_out"]
 pub struct SyncOutRow {
     pub id: String,
-    pub created_at: String,
+    pub created_at: NaiveDateTime,
     pub table_name: SyncOutRowTableNameType,
     pub record_id: String,
+    /// Store the record was changed in
     pub store_id: String,
-    pub site_id: i32,
+    /// Site the record is pushed to, none for records uploaded to central
+    pub site_id: Option<i32>,
     pub action: SyncOutRowActionType,
 }
@@ -0,0 +1,45 @@
+use super::diesel_schema::{temperature_breach, temperature_breach_config, temperature_log};
+
+use chrono::NaiveDateTime;
+
+/// Temperature reading of a data logger or sensor in a location
+#[derive(Clone, Queryable, Insertable, AsChangeset, Debug, PartialEq)]
+#[table_name = "temperature_log"]
+pub struct TemperatureLogRow {
+    pub id: String,
+    pub store_id: String,
+    pub location_id: String,
+    /// Degrees Celsius
+    pub temperature: f64,
+    pub datetime: NaiveDateTime,
+}
+
+/// Rule for locations of a location type, readings out of range for at least the duration are a
+/// breach
+#[derive(Clone, Queryable, Insertable, AsChangeset, Debug, PartialEq)]
+#[table_name = "temperature_breach_config"]
+pub struct TemperatureBreachConfigRow {
+    pub id: String,
+    pub location_type_id: String,
+    pub description: String,
+    pub minimum_temperature: Option<f64>,
+    pub maximum_temperature: Option<f64>,
+    pub duration_minutes: i32,
+    pub is_active: bool,
+}
+
+/// Period a location was out of the range of a breach config
+#[derive(Clone, Queryable, Insertable, AsChangeset, Debug, PartialEq)]
+#[table_name = "temperature_breach"]
+pub struct TemperatureBreachRow {
+    pub id: String,
+    pub store_id: String,
+    pub location_id: String,
+    pub temperature_breach_config_id: String,
+    /// First out of range reading
+    pub start_datetime: NaiveDateTime,
+    /// First reading back in range, None while the breach is ongoing
+    pub end_datetime: Option<NaiveDateTime>,
+    /// Until the end or, for ongoing breaches, until the last reading
+    pub duration_minutes: i32,
+}
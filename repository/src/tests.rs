@@ -12,6 +12,20 @@ mod repository_test {
                 code: "code1".to_string(),
                 is_customer: false,
                 is_supplier: false,
+                r#type: NameRowType::Facility,
+                first_name: None,
+                last_name: None,
+                date_of_birth: None,
+                phone: None,
+                home_store_id: None,
+                is_manufacturer: false,
+                is_donor: false,
+                email: None,
+                website: None,
+                address1: None,
+                address2: None,
+                country: None,
+                comment: None,
             }
         }
 
@@ -22,6 +36,20 @@ mod repository_test {
                 code: "code1".to_string(),
                 is_customer: false,
                 is_supplier: false,
+                r#type: NameRowType::Facility,
+                first_name: None,
+                last_name: None,
+                date_of_birth: None,
+                phone: None,
+                home_store_id: None,
+                is_manufacturer: false,
+                is_donor: false,
+                email: None,
+                website: None,
+                address1: None,
+                address2: None,
+                country: None,
+                comment: None,
             }
         }
 
@@ -32,6 +60,20 @@ mod repository_test {
                 code: "code2".to_string(),
                 is_customer: true,
                 is_supplier: false,
+                r#type: NameRowType::Facility,
+                first_name: None,
+                last_name: None,
+                date_of_birth: None,
+                phone: None,
+                home_store_id: None,
+                is_manufacturer: false,
+                is_donor: false,
+                email: None,
+                website: None,
+                address1: None,
+                address2: None,
+                country: None,
+                comment: None,
             }
         }
 
@@ -42,6 +84,20 @@ mod repository_test {
                 code: "a_umlaut_äÄ_code".to_string(),
                 is_customer: true,
                 is_supplier: false,
+                r#type: NameRowType::Facility,
+                first_name: None,
+                last_name: None,
+                date_of_birth: None,
+                phone: None,
+                home_store_id: None,
+                is_manufacturer: false,
+                is_donor: false,
+                email: None,
+                website: None,
+                address1: None,
+                address2: None,
+                country: None,
+                comment: None,
             }
         }
 
@@ -60,6 +116,12 @@ mod repository_test {
                 code: "code1".to_string(),
                 unit_id: None,
                 r#type: ItemRowType::Stock,
+                category_id: None,
+                strength: None,
+                dosage_form: None,
+                ven_category: None,
+                is_controlled: false,
+                storage_requirement: None,
             }
         }
 
@@ -70,6 +132,12 @@ mod repository_test {
                 code: "code2".to_string(),
                 unit_id: None,
                 r#type: ItemRowType::Stock,
+                category_id: None,
+                strength: None,
+                dosage_form: None,
+                ven_category: None,
+                is_controlled: false,
+                storage_requirement: None,
             }
         }
 
@@ -80,6 +148,12 @@ mod repository_test {
                 code: "item_service_code_1".to_string(),
                 unit_id: None,
                 r#type: ItemRowType::Service,
+                category_id: None,
+                strength: None,
+                dosage_form: None,
+                ven_category: None,
+                is_controlled: false,
+                storage_requirement: None,
             }
         }
 
@@ -210,6 +284,7 @@ mod repository_test {
                 shipped_datetime: None,
                 delivered_datetime: None,
                 verified_datetime: None,
+                prescriber_id: None,
             }
         }
 
@@ -231,6 +306,7 @@ mod repository_test {
                 shipped_datetime: None,
                 delivered_datetime: None,
                 verified_datetime: None,
+                prescriber_id: None,
             }
         }
 
@@ -254,6 +330,8 @@ mod repository_test {
                 number_of_packs: 1,
                 note: None,
                 location_id: None,
+                return_reason_id: None,
+                directions: None,
             }
         }
         pub fn invoice_line_2() -> InvoiceLineRow {
@@ -276,6 +354,8 @@ mod repository_test {
                 number_of_packs: 1,
                 note: None,
                 location_id: None,
+                return_reason_id: None,
+                directions: None,
             }
         }
 
@@ -299,6 +379,8 @@ mod repository_test {
                 number_of_packs: 1,
                 note: None,
                 location_id: None,
+                return_reason_id: None,
+                directions: None,
             }
         }
 
@@ -322,6 +404,8 @@ mod repository_test {
                 number_of_packs: 1,
                 note: None,
                 location_id: None,
+                return_reason_id: None,
+                directions: None,
             }
         }
 
@@ -425,8 +509,12 @@ mod repository_test {
                         like: None,
                     }),
                     code: None,
+                    r#type: None,
                     is_customer: None,
                     is_supplier: None,
+                    is_manufacturer: None,
+                    is_donor: None,
+                    tag: None,
                 }),
                 None,
             )
@@ -444,8 +532,12 @@ mod repository_test {
                         like: Some("me_".to_string()),
                     }),
                     code: None,
+                    r#type: None,
                     is_customer: None,
                     is_supplier: None,
+                    is_manufacturer: None,
+                    is_donor: None,
+                    tag: None,
                 }),
                 None,
             )
@@ -463,8 +555,12 @@ mod repository_test {
                         like: Some("mE_".to_string()),
                     }),
                     code: None,
+                    r#type: None,
                     is_customer: None,
                     is_supplier: None,
+                    is_manufacturer: None,
+                    is_donor: None,
+                    tag: None,
                 }),
                 None,
             )
@@ -484,8 +580,12 @@ mod repository_test {
                         like: Some("T_Ää_N".to_string()),
                     }),
                     code: None,
+                    r#type: None,
                     is_customer: None,
                     is_supplier: None,
+                    is_manufacturer: None,
+                    is_donor: None,
+                    tag: None,
                 }),
                 None,
             )
@@ -503,8 +603,12 @@ mod repository_test {
                         equal_to: Some("code1".to_string()),
                         like: None,
                     }),
+                    r#type: None,
                     is_customer: None,
                     is_supplier: None,
+                    is_manufacturer: None,
+                    is_donor: None,
+                    tag: None,
                 }),
                 None,
             )
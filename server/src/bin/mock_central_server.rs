@@ -0,0 +1,105 @@
+//! Mock central server implementing the `v5` sync API from a JSON fixture directory.
+//!
+//! Listens on the sync url of the app configuration and accepts the configured sync credentials,
+//! i.e. a remote server started with the same configuration syncs against it:
+//!
+//! cargo run --bin mock_central_server -- <fixture directory> [--batch-size N] [--interrupt-after N]
+//!
+//! See `load_fixtures` for the layout of the fixture directory.
+
+use log::info;
+use reqwest::Url;
+use server::{
+    configuration,
+    settings::Settings,
+    sync::{
+        mock_central::{config, load_fixtures, MockCentralState},
+        SyncCredentials,
+    },
+};
+
+use actix_web::{web::Data, App, HttpServer};
+use std::{env, path::PathBuf, sync::Mutex};
+
+const USAGE: &str =
+    "Usage: mock_central_server <fixture directory> [--batch-size N] [--interrupt-after N]";
+
+struct Arguments {
+    fixture_directory: PathBuf,
+    batch_size: Option<u32>,
+    interrupt_after: Option<u32>,
+}
+
+fn parse_arguments() -> Result<Arguments, String> {
+    let mut args = env::args().skip(1);
+    let mut fixture_directory = None;
+    let mut batch_size = None;
+    let mut interrupt_after = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--batch-size" => batch_size = Some(parse_number(args.next(), &arg)?),
+            "--interrupt-after" => interrupt_after = Some(parse_number(args.next(), &arg)?),
+            _ if fixture_directory.is_none() => fixture_directory = Some(PathBuf::from(arg)),
+            _ => return Err(format!("Unexpected argument {}", arg)),
+        }
+    }
+
+    Ok(Arguments {
+        fixture_directory: fixture_directory.ok_or("Missing fixture directory")?,
+        batch_size,
+        interrupt_after,
+    })
+}
+
+fn parse_number(value: Option<String>, arg: &str) -> Result<u32, String> {
+    value
+        .and_then(|value| value.parse().ok())
+        .ok_or_else(|| format!("Expected a number after {}", arg))
+}
+
+#[actix_web::main]
+async fn main() -> std::io::Result<()> {
+    env::set_var("RUST_LOG", "info");
+    env_logger::init();
+
+    let arguments = match parse_arguments() {
+        Ok(arguments) => arguments,
+        Err(error) => {
+            eprintln!("{}\n{}", error, USAGE);
+            std::process::exit(1);
+        }
+    };
+
+    let settings: Settings =
+        configuration::get_configuration().expect("Failed to parse configuration settings");
+
+    let fixtures =
+        load_fixtures(&arguments.fixture_directory).expect("Failed to load sync fixtures");
+    info!(
+        "Loaded {} central and {} remote records",
+        fixtures.central_records.len(),
+        fixtures.remote_records.len()
+    );
+
+    let credentials = SyncCredentials::new(&settings.sync.username, &settings.sync.password);
+    let mut state = MockCentralState::new(credentials, fixtures);
+    if let Some(batch_size) = arguments.batch_size {
+        state.batch_size = batch_size;
+    }
+    state.interrupt_after = arguments.interrupt_after;
+    let state = Data::new(Mutex::new(state));
+
+    let url = Url::parse(&settings.sync.url).expect("Failed to parse sync url");
+    let address = format!(
+        "{}:{}",
+        url.host_str().unwrap_or("localhost"),
+        url.port_or_known_default().unwrap_or(80)
+    );
+    info!("Mock central server listening on {}", address);
+
+    HttpServer::new(move || App::new().configure(config(state.clone())))
+        .bind(address)?
+        .run()
+        .await
+}
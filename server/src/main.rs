@@ -15,13 +15,17 @@ use graphql::{
 };
 use log::{error, warn};
 use repository::get_storage_connection_manager;
-use service::{auth_data::AuthData, service_provider::ServiceProvider, token_bucket::TokenBucket};
+use service::{
+    auth_data::AuthData, plugin::PluginHost, service_provider::ServiceProvider,
+    token_bucket::TokenBucket,
+};
 
 use actix_cors::Cors;
 use actix_web::{web::Data, App, HttpServer};
 use std::{
     env,
     net::TcpListener,
+    path::Path,
     sync::{Arc, Mutex, RwLock},
     time::Duration,
 };
@@ -50,7 +54,13 @@ async fn main() -> std::io::Result<()> {
     });
     let connection_manager = get_storage_connection_manager(&settings.database);
     let loaders: LoaderMap = get_loaders(&connection_manager).await;
-    let service_provider = ServiceProvider::new(connection_manager.clone());
+    let mut service_provider = ServiceProvider::new(connection_manager.clone());
+    if let Some(plugin_dir) = &settings.server.plugin_dir {
+        match PluginHost::load_directory(Path::new(plugin_dir)) {
+            Ok(plugins) => service_provider.plugins = Arc::new(plugins),
+            Err(error) => error!("Failed to load plugins: {:?}", error),
+        }
+    }
     let (mut sync_sender, mut sync_receiver): (SyncSenderActor, SyncReceiverActor) =
         sync::get_sync_actors();
 
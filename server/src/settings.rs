@@ -18,6 +18,9 @@ pub struct Settings {
 pub struct ServerSettings {
     pub host: String,
     pub port: u16,
+    /// Directory of the `*.wasm` plugins, no plugins are loaded if not set
+    #[serde(default)]
+    pub plugin_dir: Option<String>,
 }
 
 #[derive(serde::Deserialize)]
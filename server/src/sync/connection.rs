@@ -1,8 +1,8 @@
 use crate::{
     settings::SyncSettings,
     sync::{
-        CentralSyncBatch, RemoteSyncAcknowledgement, RemoteSyncBatch, RemoteSyncRecord,
-        SyncCredentials, SyncServer,
+        CentralSyncBatch, RemoteSyncAcknowledgement, RemoteSyncBatch, RemoteSyncPushRecord,
+        RemoteSyncRecord, SyncCredentials, SyncServer,
     },
 };
 
@@ -161,6 +161,24 @@ impl SyncConnection {
         Ok(())
     }
 
+    // Push records changed on the remote site to central.
+    pub async fn push_remote_records(
+        &self,
+        records: &[RemoteSyncPushRecord],
+    ) -> Result<(), SyncConnectionError> {
+        let url = self.server.queued_records_url();
+
+        self.client
+            .post(url)
+            .basic_auth(&self.credentials.username, Some(&self.credentials.password))
+            .body(serde_json::to_string(records).unwrap_or_default())
+            .send()
+            .await?
+            .error_for_status()?;
+
+        Ok(())
+    }
+
     // Pull batch of records from central sync log.
     pub async fn pull_central_records(
         &self,
@@ -207,8 +225,8 @@ mod tests {
     use crate::{
         settings::SyncSettings,
         sync::{
-            CentralSyncBatch, RemoteSyncAcknowledgement, RemoteSyncBatch, RemoteSyncRecord,
-            RemoteSyncRecordAction, RemoteSyncRecordData, SyncConnection,
+            CentralSyncBatch, RemoteSyncAcknowledgement, RemoteSyncBatch, RemoteSyncPushRecord,
+            RemoteSyncRecord, RemoteSyncRecordAction, RemoteSyncRecordData, SyncConnection,
         },
     };
     use repository::schema::CentralSyncBufferRow;
@@ -431,6 +449,76 @@ mod tests {
         assert!(acknowledge_result_without_auth.is_err());
     }
 
+    #[actix_rt::test]
+    async fn test_push_remote_records() {
+        let mock_server = MockServer::start();
+
+        let mock_username = "username".to_owned();
+        let mock_password = "password".to_owned();
+
+        let mock_sync_settings_with_auth = SyncSettings {
+            url: mock_server.url(""),
+            username: mock_username.clone(),
+            password: mock_password.clone(),
+            interval: 0,
+        };
+
+        let mock_sync_settings_without_auth = SyncSettings {
+            url: mock_server.url(""),
+            username: "".to_owned(),
+            password: "".to_owned(),
+            interval: 0,
+        };
+
+        let mock_authorization_header =
+	    "Basic dXNlcm5hbWU6NWU4ODQ4OThkYTI4MDQ3MTUxZDBlNTZmOGRjNjI5Mjc3MzYwM2QwZDZhYWJiZGQ2MmExMWVmNzIxZDE1NDJkOA=="
+	    .to_owned();
+
+        let push_records_path = "/sync/v5/queued_records".to_owned();
+
+        let mock_push_records_data = vec![
+            RemoteSyncPushRecord {
+                table_name: "name".to_owned(),
+                record_id: "record_a".to_owned(),
+                action: RemoteSyncRecordAction::Create,
+                data: serde_json::json!({ "ID": "record_a" }),
+            },
+            RemoteSyncPushRecord {
+                table_name: "name_tag_join".to_owned(),
+                record_id: "record_b".to_owned(),
+                action: RemoteSyncRecordAction::Delete,
+                data: serde_json::json!({ "ID": "record_b" }),
+            },
+        ];
+
+        mock_server.mock(|when, then| {
+            when.method(POST)
+                .header(AUTHORIZATION.to_string(), mock_authorization_header)
+                .path(push_records_path.clone())
+                .body(serde_json::to_string(&mock_push_records_data).unwrap());
+            then.status(200);
+        });
+
+        mock_server.mock(|when, then| {
+            when.method(POST).path(push_records_path.clone());
+            then.status(401);
+        });
+
+        let sync_connection_with_auth = SyncConnection::new(&mock_sync_settings_with_auth);
+        let push_result_with_auth = sync_connection_with_auth
+            .push_remote_records(&mock_push_records_data)
+            .await;
+
+        assert!(push_result_with_auth.is_ok());
+
+        let sync_connection_without_auth = SyncConnection::new(&mock_sync_settings_without_auth);
+        let push_result_without_auth = sync_connection_without_auth
+            .push_remote_records(&mock_push_records_data)
+            .await;
+
+        assert!(push_result_without_auth.is_err());
+    }
+
     #[actix_rt::test]
     async fn test_pull_central_records() {
         let mock_server = MockServer::start();
@@ -0,0 +1,120 @@
+use crate::sync::{
+    mock_central::{MockCentralError, MockRemoteRecord},
+    translation::TRANSLATION_RECORDS,
+    RemoteSyncRecordAction,
+};
+use repository::schema::CentralSyncBufferRow;
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+const CENTRAL_FIXTURE_DIRECTORY: &str = "central";
+const REMOTE_FIXTURE_DIRECTORY: &str = "remote";
+
+pub struct SyncFixtures {
+    pub central_records: Vec<CentralSyncBufferRow>,
+    pub remote_records: Vec<MockRemoteRecord>,
+}
+
+/// Loads sync records from a fixture directory.
+///
+/// The fixture directory contains a `central` and a `remote` directory (both optional). Every
+/// `<table_name>.json` file in these directories holds an array of legacy mSupply records of that
+/// table, e.g. `central/item.json`. Each record must have an `ID` field.
+///
+/// Central records are assigned consecutive cursors, starting at 1, in the order of
+/// `TRANSLATION_RECORDS` followed by all other tables in alphabetical order.
+pub fn load_fixtures(directory: &Path) -> Result<SyncFixtures, MockCentralError> {
+    let mut central_records = Vec::new();
+    for (table_name, path) in fixture_files(&directory.join(CENTRAL_FIXTURE_DIRECTORY))? {
+        for (record_id, data) in read_fixture_file(&path)? {
+            central_records.push(CentralSyncBufferRow {
+                id: central_records.len() as i32 + 1,
+                table_name: table_name.clone(),
+                record_id,
+                data: data.to_string(),
+            });
+        }
+    }
+
+    let mut remote_records = Vec::new();
+    for (table_name, path) in fixture_files(&directory.join(REMOTE_FIXTURE_DIRECTORY))? {
+        for (record_id, data) in read_fixture_file(&path)? {
+            remote_records.push(MockRemoteRecord {
+                table_name: table_name.clone(),
+                record_id,
+                action: RemoteSyncRecordAction::Create,
+                data,
+            });
+        }
+    }
+
+    Ok(SyncFixtures {
+        central_records,
+        remote_records,
+    })
+}
+
+/// Returns (table name, path) of all json files in the directory, sorted by table name with the
+/// translated tables first.
+fn fixture_files(directory: &Path) -> Result<Vec<(String, PathBuf)>, MockCentralError> {
+    if !directory.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let entries = fs::read_dir(directory).map_err(|source| MockCentralError::IoError {
+        path: directory.display().to_string(),
+        source,
+    })?;
+
+    let mut files = Vec::new();
+    for entry in entries {
+        let path = entry
+            .map_err(|source| MockCentralError::IoError {
+                path: directory.display().to_string(),
+                source,
+            })?
+            .path();
+        if path.extension().and_then(|extension| extension.to_str()) != Some("json") {
+            continue;
+        }
+        if let Some(table_name) = path.file_stem().and_then(|stem| stem.to_str()) {
+            files.push((table_name.to_owned(), path.clone()));
+        }
+    }
+
+    files.sort_by_key(|(table_name, _)| {
+        let position = TRANSLATION_RECORDS
+            .iter()
+            .position(|translated| translated == table_name)
+            .unwrap_or(TRANSLATION_RECORDS.len());
+        (position, table_name.clone())
+    });
+
+    Ok(files)
+}
+
+fn read_fixture_file(path: &Path) -> Result<Vec<(String, serde_json::Value)>, MockCentralError> {
+    let path_string = path.display().to_string();
+    let content = fs::read_to_string(path).map_err(|source| MockCentralError::IoError {
+        path: path_string.clone(),
+        source,
+    })?;
+    let records: Vec<serde_json::Value> =
+        serde_json::from_str(&content).map_err(|source| MockCentralError::ParseError {
+            path: path_string.clone(),
+            source,
+        })?;
+
+    records
+        .into_iter()
+        .map(|record| match record.get("ID").and_then(|id| id.as_str()) {
+            Some(id) => Ok((id.to_owned(), record)),
+            None => Err(MockCentralError::MissingIdError {
+                path: path_string.clone(),
+            }),
+        })
+        .collect()
+}
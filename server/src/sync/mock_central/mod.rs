@@ -0,0 +1,463 @@
+//! In-memory implementation of the `v5` central sync API.
+//!
+//! Used to run the `Synchroniser` end-to-end during local development (see the
+//! `mock_central_server` binary) and in integration tests.
+
+mod fixtures;
+mod routes;
+
+pub use fixtures::{load_fixtures, SyncFixtures};
+pub use routes::config;
+
+use crate::sync::{RemoteSyncRecordAction, SyncCredentials};
+use repository::schema::CentralSyncBufferRow;
+
+use serde::{Deserialize, Serialize};
+use std::sync::Mutex;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum MockCentralError {
+    #[error("Failed to read fixture file {path}")]
+    IoError {
+        path: String,
+        source: std::io::Error,
+    },
+    #[error("Failed to parse fixture file {path}")]
+    ParseError {
+        path: String,
+        source: serde_json::Error,
+    },
+    #[error("Fixture record in {path} has no ID")]
+    MissingIdError { path: String },
+}
+
+/// Record that belongs to a remote site, i.e. loaded from the remote fixtures or pushed by the
+/// remote site.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MockRemoteRecord {
+    #[serde(rename = "tableName")]
+    pub table_name: String,
+    #[serde(rename = "recordId")]
+    pub record_id: String,
+    pub action: RemoteSyncRecordAction,
+    pub data: serde_json::Value,
+}
+
+/// Entry in the remote sync queue, as served by `queued_records`.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MockQueuedRecord {
+    #[serde(rename = "syncID")]
+    pub sync_id: String,
+    #[serde(flatten)]
+    pub record: MockRemoteRecord,
+}
+
+pub struct MockCentralState {
+    pub credentials: SyncCredentials,
+    /// Central records ordered by cursor (`CentralSyncBufferRow::id`).
+    pub central_records: Vec<CentralSyncBufferRow>,
+    /// All records belonging to the remote site. The remote sync queue is rebuilt from these
+    /// records on initialisation.
+    pub remote_records: Vec<MockRemoteRecord>,
+    /// Records queued for the remote site which have not been acknowledged yet.
+    pub queue: Vec<MockQueuedRecord>,
+    /// Max number of records returned per request, independent of the requested limit.
+    pub batch_size: u32,
+    /// Number of `central_records` requests to serve before responding with an error. Used to
+    /// simulate a pull which is interrupted halfway through.
+    pub interrupt_after: Option<u32>,
+}
+
+pub type MockCentralStateData = Mutex<MockCentralState>;
+
+impl MockCentralState {
+    pub fn new(credentials: SyncCredentials, fixtures: SyncFixtures) -> MockCentralState {
+        let SyncFixtures {
+            mut central_records,
+            remote_records,
+        } = fixtures;
+        central_records.sort_by_key(|record| record.id);
+
+        MockCentralState {
+            credentials,
+            central_records,
+            remote_records,
+            queue: Vec::new(),
+            batch_size: u32::MAX,
+            interrupt_after: None,
+        }
+    }
+
+    /// One past the highest central record cursor, 1 if there are no central records.
+    pub fn max_cursor(&self) -> u32 {
+        self.central_records
+            .last()
+            .map_or(1, |record| record.id as u32 + 1)
+    }
+
+    /// Returns up to `limit` central records with a cursor greater than `cursor`.
+    pub fn central_records(&self, cursor: u32, limit: u32) -> Vec<CentralSyncBufferRow> {
+        let limit = limit.min(self.batch_size) as usize;
+        self.central_records
+            .iter()
+            .filter(|record| record.id as u32 > cursor)
+            .take(limit)
+            .cloned()
+            .collect()
+    }
+
+    /// Rebuilds the remote sync queue from all records belonging to the remote site.
+    pub fn initialise(&mut self) {
+        self.queue = self
+            .remote_records
+            .iter()
+            .map(|record| MockQueuedRecord {
+                sync_id: util::uuid::uuid(),
+                record: record.clone(),
+            })
+            .collect();
+    }
+
+    pub fn queued_records(&self, limit: u32) -> Vec<MockQueuedRecord> {
+        let limit = limit.min(self.batch_size) as usize;
+        self.queue.iter().take(limit).cloned().collect()
+    }
+
+    pub fn acknowledge(&mut self, sync_ids: &[String]) {
+        self.queue
+            .retain(|queued| !sync_ids.iter().any(|id| id == &queued.sync_id));
+    }
+
+    /// Stores records pushed by the remote site. Pushed records are not queued back to the remote
+    /// site until it is re-initialised.
+    pub fn push(&mut self, records: Vec<MockRemoteRecord>) {
+        for record in records {
+            self.remote_records.retain(|existing| {
+                existing.table_name != record.table_name || existing.record_id != record.record_id
+            });
+            if !matches!(record.action, RemoteSyncRecordAction::Delete) {
+                self.remote_records.push(record);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{config, MockCentralState, MockRemoteRecord, SyncFixtures};
+    use crate::{
+        settings::SyncSettings,
+        sync::{
+            translation::test_data::{
+                barcode::get_test_barcode_records, check_records_against_database,
+                currency::get_test_currency_records, extract_sync_buffer_rows,
+                item::get_test_item_records, item_category::get_test_item_category_records,
+                item_store_join::get_test_item_store_join_records,
+                location_type::get_test_location_type_records,
+                master_list::get_test_master_list_records,
+                master_list_line::get_test_master_list_line_records,
+                master_list_name_join::get_test_master_list_name_join_records,
+                name::get_test_name_records, reason::get_test_reason_records,
+                store::get_test_store_records, store_preference::get_test_store_preference_records,
+                unit::get_test_unit_records, TestSyncRecord,
+            },
+            RemoteSyncRecordAction, SyncConnection, SyncCredentials, Synchroniser,
+        },
+        test_utils::get_test_settings,
+    };
+    use actix_web::{web::Data, App, HttpServer};
+    use domain::name::NameType;
+    use repository::{
+        get_storage_connection_manager,
+        mock::{mock_store_a, MockDataInserts},
+        test_db, CentralSyncCursorRepository, SyncOutRowRepository,
+    };
+    use service::{
+        name::{insert::InsertName, update::UpdateName},
+        service_provider::ServiceProvider,
+    };
+    use std::{net::TcpListener, sync::Mutex};
+
+    fn get_test_records() -> Vec<TestSyncRecord> {
+        let mut records = Vec::new();
+        records.append(&mut get_test_name_records());
+        records.append(&mut get_test_unit_records());
+        records.append(&mut get_test_item_category_records());
+        records.append(&mut get_test_item_records());
+        records.append(&mut get_test_store_records());
+        records.append(&mut get_test_master_list_records());
+        records.append(&mut get_test_master_list_line_records());
+        records.append(&mut get_test_master_list_name_join_records());
+        records.append(&mut get_test_currency_records());
+        records.append(&mut get_test_location_type_records());
+        records.append(&mut get_test_store_preference_records());
+        records.append(&mut get_test_reason_records());
+        records.append(&mut get_test_item_store_join_records());
+        records.append(&mut get_test_barcode_records());
+        records
+    }
+
+    fn get_test_remote_record(id: &str) -> MockRemoteRecord {
+        MockRemoteRecord {
+            table_name: "transact".to_owned(),
+            record_id: id.to_owned(),
+            action: RemoteSyncRecordAction::Create,
+            data: serde_json::json!({ "ID": id }),
+        }
+    }
+
+    /// Starts a mock central server on a random port and returns its state and url.
+    fn start_mock_central(fixtures: SyncFixtures) -> (Data<Mutex<MockCentralState>>, String) {
+        let settings = get_test_settings("");
+        let credentials = SyncCredentials::new(&settings.sync.username, &settings.sync.password);
+        let state = Data::new(Mutex::new(MockCentralState::new(credentials, fixtures)));
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let server_state = state.clone();
+        HttpServer::new(move || App::new().configure(config(server_state.clone())))
+            .listen(listener)
+            .unwrap()
+            .run();
+
+        (state, url)
+    }
+
+    fn get_synchroniser(url: &str) -> Synchroniser {
+        let settings = get_test_settings("");
+        Synchroniser {
+            connection: SyncConnection::new(&SyncSettings {
+                url: url.to_owned(),
+                ..settings.sync
+            }),
+        }
+    }
+
+    #[actix_rt::test]
+    async fn test_mock_central_sync() {
+        let settings = get_test_settings("omsupply-database-mock-central-sync");
+        test_db::setup(&settings.database).await;
+        let connection_manager = get_storage_connection_manager(&settings.database);
+
+        let test_records = get_test_records();
+        let (_, url) = start_mock_central(SyncFixtures {
+            central_records: extract_sync_buffer_rows(&test_records),
+            remote_records: Vec::new(),
+        });
+
+        get_synchroniser(&url)
+            .sync(&connection_manager)
+            .await
+            .expect("Failed to sync against mock central server");
+
+        check_records_against_database(&connection_manager, test_records).await;
+
+        // re-initialise a fresh data file from the same central server
+        drop(connection_manager);
+        test_db::setup(&settings.database).await;
+        let connection_manager = get_storage_connection_manager(&settings.database);
+        get_synchroniser(&url)
+            .sync(&connection_manager)
+            .await
+            .expect("Failed to re-initialise against mock central server");
+
+        check_records_against_database(&connection_manager, get_test_records()).await;
+    }
+
+    #[actix_rt::test]
+    async fn test_mock_central_interrupted_pull() {
+        let settings = get_test_settings("omsupply-database-mock-central-interrupted-pull");
+        test_db::setup(&settings.database).await;
+        let connection_manager = get_storage_connection_manager(&settings.database);
+
+        let test_records = get_test_records();
+        let (state, url) = start_mock_central(SyncFixtures {
+            central_records: extract_sync_buffer_rows(&test_records),
+            remote_records: Vec::new(),
+        });
+        {
+            let mut state = state.lock().unwrap();
+            state.batch_size = 5;
+            state.interrupt_after = Some(2);
+        }
+
+        let mut synchroniser = get_synchroniser(&url);
+        assert!(synchroniser.sync(&connection_manager).await.is_err());
+
+        // cursor stays at the last record of the second batch
+        let connection = connection_manager.connection().unwrap();
+        let expected_cursor = state.lock().unwrap().central_records[9].id as u32;
+        assert_eq!(
+            CentralSyncCursorRepository::new(&connection)
+                .get_cursor()
+                .await
+                .unwrap(),
+            expected_cursor
+        );
+
+        state.lock().unwrap().interrupt_after = None;
+        synchroniser
+            .sync(&connection_manager)
+            .await
+            .expect("Failed to resume interrupted pull");
+
+        check_records_against_database(&connection_manager, test_records).await;
+    }
+
+    #[actix_rt::test]
+    async fn test_mock_central_remote_queue() {
+        let (state, url) = start_mock_central(SyncFixtures {
+            central_records: Vec::new(),
+            remote_records: vec![
+                get_test_remote_record("transact_a"),
+                get_test_remote_record("transact_b"),
+                get_test_remote_record("transact_c"),
+            ],
+        });
+        state.lock().unwrap().batch_size = 2;
+
+        let mut synchroniser = get_synchroniser(&url);
+        let records = synchroniser.pull_remote_records().await.unwrap();
+        let mut record_ids: Vec<String> = records.into_iter().map(|r| r.data.id).collect();
+        record_ids.sort();
+        assert_eq!(record_ids, vec!["transact_a", "transact_b", "transact_c"]);
+        assert!(state.lock().unwrap().queue.is_empty());
+
+        // pushed records come back after re-initialisation
+        let settings = get_test_settings("");
+        reqwest::Client::new()
+            .post(&format!("{}/sync/v5/queued_records", url))
+            .basic_auth(
+                &settings.sync.username,
+                Some(&util::hash::sha256(&settings.sync.password)),
+            )
+            .body(
+                serde_json::to_string(&vec![
+                    get_test_remote_record("transact_d"),
+                    MockRemoteRecord {
+                        action: RemoteSyncRecordAction::Delete,
+                        ..get_test_remote_record("transact_a")
+                    },
+                ])
+                .unwrap(),
+            )
+            .send()
+            .await
+            .unwrap()
+            .error_for_status()
+            .unwrap();
+
+        let records = synchroniser.pull_remote_records().await.unwrap();
+        let mut record_ids: Vec<String> = records.into_iter().map(|r| r.data.id).collect();
+        record_ids.sort();
+        assert_eq!(record_ids, vec!["transact_b", "transact_c", "transact_d"]);
+    }
+
+    #[actix_rt::test]
+    async fn test_mock_central_push() {
+        let (_, connection, connection_manager, _) = test_db::setup_all(
+            "omsupply-database-mock-central-push",
+            MockDataInserts::all(),
+        )
+        .await;
+        let service_provider = ServiceProvider::new(connection_manager.clone());
+        let context = service_provider.context().unwrap();
+        let service = &service_provider.name_service;
+        let store_id = &mock_store_a().id;
+
+        service
+            .insert_name(
+                &context,
+                store_id,
+                InsertName {
+                    id: "clinic".to_owned(),
+                    code: "C1".to_owned(),
+                    name: "Outreach clinic".to_owned(),
+                    r#type: NameType::Facility,
+                    is_customer: true,
+                    is_supplier: false,
+                    is_manufacturer: false,
+                    is_donor: false,
+                    phone: None,
+                    email: None,
+                    website: None,
+                    address1: None,
+                    address2: None,
+                    country: None,
+                    comment: None,
+                    tags: vec!["Clinic".to_owned(), "Outreach".to_owned()],
+                },
+            )
+            .unwrap();
+        // removes the Outreach tag join again
+        service
+            .update_name(
+                &context,
+                store_id,
+                UpdateName {
+                    id: "clinic".to_owned(),
+                    code: None,
+                    name: None,
+                    is_customer: None,
+                    is_supplier: None,
+                    is_manufacturer: None,
+                    is_donor: None,
+                    phone: None,
+                    email: None,
+                    website: None,
+                    address1: None,
+                    address2: None,
+                    country: None,
+                    comment: None,
+                    tags: Some(vec!["Clinic".to_owned()]),
+                },
+            )
+            .unwrap();
+
+        let (state, url) = start_mock_central(SyncFixtures {
+            central_records: Vec::new(),
+            remote_records: Vec::new(),
+        });
+        get_synchroniser(&url)
+            .push_remote_records(&connection_manager)
+            .await
+            .expect("Failed to push to mock central server");
+
+        let state = state.lock().unwrap();
+        let mut tables: Vec<&str> = state
+            .remote_records
+            .iter()
+            .map(|record| record.table_name.as_str())
+            .collect();
+        tables.sort();
+        assert_eq!(
+            tables,
+            vec!["name", "name_tag", "name_tag", "name_tag_join"]
+        );
+
+        let name = state
+            .remote_records
+            .iter()
+            .find(|record| record.table_name == "name")
+            .unwrap();
+        assert_eq!(name.record_id, "clinic");
+        assert_eq!(name.data["ID"], "clinic");
+        assert_eq!(name.data["code"], "C1");
+        assert_eq!(name.data["type"], "facility");
+        assert_eq!(name.data["supplying_store_id"], store_id.as_str());
+
+        let tag_join = state
+            .remote_records
+            .iter()
+            .find(|record| record.table_name == "name_tag_join")
+            .unwrap();
+        assert_eq!(tag_join.data["name_ID"], "clinic");
+
+        // pushed records are removed from the queue
+        assert!(SyncOutRowRepository::new(&connection)
+            .find_all()
+            .unwrap()
+            .is_empty());
+    }
+}
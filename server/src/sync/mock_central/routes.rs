@@ -0,0 +1,204 @@
+use crate::sync::{
+    mock_central::{MockCentralStateData, MockQueuedRecord, MockRemoteRecord},
+    server::{ACKNOWLEDGE_RECORDS, BASE_URL, CENTRAL_RECORDS, INITIALISE, QUEUED_RECORDS},
+    RemoteSyncAcknowledgement,
+};
+
+use actix_web::{
+    http::header::AUTHORIZATION,
+    web::{self, Bytes, Data, Query},
+    HttpRequest, HttpResponse,
+};
+use log::info;
+use repository::schema::CentralSyncBufferRow;
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize)]
+pub struct CentralRecordsQuery {
+    cursor: u32,
+    limit: u32,
+}
+
+#[derive(Deserialize)]
+pub struct QueuedRecordsQuery {
+    limit: u32,
+}
+
+/// Same as `CentralSyncBatch` but serving the record data as json object, like the central server.
+#[derive(Serialize)]
+struct MockCentralSyncBatch {
+    #[serde(rename = "maxCursor")]
+    max_cursor: u32,
+    data: Option<Vec<MockCentralRecord>>,
+}
+
+#[derive(Serialize)]
+struct MockCentralRecord {
+    #[serde(rename = "ID")]
+    id: i32,
+    #[serde(rename = "tableName")]
+    table_name: String,
+    #[serde(rename = "recordId")]
+    record_id: String,
+    data: serde_json::Value,
+}
+
+impl From<CentralSyncBufferRow> for MockCentralRecord {
+    fn from(row: CentralSyncBufferRow) -> Self {
+        MockCentralRecord {
+            id: row.id,
+            table_name: row.table_name,
+            record_id: row.record_id,
+            data: serde_json::from_str(&row.data).unwrap_or(serde_json::Value::Null),
+        }
+    }
+}
+
+/// Same as `RemoteSyncBatch` but serving the complete record data.
+#[derive(Serialize)]
+struct MockRemoteSyncBatch {
+    #[serde(rename = "queueLength")]
+    queue_length: u32,
+    data: Option<Vec<MockQueuedRecord>>,
+}
+
+pub fn config(state: Data<MockCentralStateData>) -> impl FnOnce(&mut web::ServiceConfig) {
+    |cfg| {
+        cfg.service(
+            web::scope(BASE_URL.trim_end_matches('/'))
+                .app_data(state)
+                .route(&format!("/{}", INITIALISE), web::post().to(initialise))
+                .route(
+                    &format!("/{}", QUEUED_RECORDS),
+                    web::get().to(queued_records),
+                )
+                .route(
+                    &format!("/{}", QUEUED_RECORDS),
+                    web::post().to(push_records),
+                )
+                .route(
+                    &format!("/{}", CENTRAL_RECORDS),
+                    web::get().to(central_records),
+                )
+                .route(
+                    &format!("/{}", ACKNOWLEDGE_RECORDS),
+                    web::post().to(acknowledge_records),
+                ),
+        );
+    }
+}
+
+fn is_authorised(request: &HttpRequest, state: &Data<MockCentralStateData>) -> bool {
+    let credentials = &state.lock().unwrap().credentials;
+    let expected = format!(
+        "Basic {}",
+        base64::encode(format!("{}:{}", credentials.username, credentials.password))
+    );
+    request
+        .headers()
+        .get(AUTHORIZATION)
+        .and_then(|header| header.to_str().ok())
+        == Some(expected.as_str())
+}
+
+async fn central_records(
+    request: HttpRequest,
+    state: Data<MockCentralStateData>,
+    query: Query<CentralRecordsQuery>,
+) -> HttpResponse {
+    if !is_authorised(&request, &state) {
+        return HttpResponse::Unauthorized().finish();
+    }
+
+    let mut state = state.lock().unwrap();
+    if let Some(interrupt_after) = state.interrupt_after {
+        if interrupt_after == 0 {
+            info!(
+                "Interrupting central records pull at cursor {}",
+                query.cursor
+            );
+            return HttpResponse::InternalServerError().finish();
+        }
+        state.interrupt_after = Some(interrupt_after - 1);
+    }
+
+    let records = state.central_records(query.cursor, query.limit);
+    info!(
+        "Serving {} central records from cursor {}",
+        records.len(),
+        query.cursor
+    );
+    HttpResponse::Ok().json(MockCentralSyncBatch {
+        max_cursor: state.max_cursor(),
+        data: Some(records.into_iter().map(MockCentralRecord::from).collect()),
+    })
+}
+
+async fn initialise(request: HttpRequest, state: Data<MockCentralStateData>) -> HttpResponse {
+    if !is_authorised(&request, &state) {
+        return HttpResponse::Unauthorized().finish();
+    }
+
+    let mut state = state.lock().unwrap();
+    state.initialise();
+    info!(
+        "Initialised remote sync queue with {} records",
+        state.queue.len()
+    );
+    HttpResponse::Ok().json(MockRemoteSyncBatch {
+        queue_length: state.queue.len() as u32,
+        data: None,
+    })
+}
+
+async fn queued_records(
+    request: HttpRequest,
+    state: Data<MockCentralStateData>,
+    query: Query<QueuedRecordsQuery>,
+) -> HttpResponse {
+    if !is_authorised(&request, &state) {
+        return HttpResponse::Unauthorized().finish();
+    }
+
+    let state = state.lock().unwrap();
+    HttpResponse::Ok().json(MockRemoteSyncBatch {
+        queue_length: state.queue.len() as u32,
+        data: Some(state.queued_records(query.limit)),
+    })
+}
+
+// The sync connection doesn't set a content type, parse the body manually.
+async fn acknowledge_records(
+    request: HttpRequest,
+    state: Data<MockCentralStateData>,
+    body: Bytes,
+) -> HttpResponse {
+    if !is_authorised(&request, &state) {
+        return HttpResponse::Unauthorized().finish();
+    }
+
+    let acknowledgement: RemoteSyncAcknowledgement = match serde_json::from_slice(&body) {
+        Ok(acknowledgement) => acknowledgement,
+        Err(_) => return HttpResponse::BadRequest().finish(),
+    };
+    state.lock().unwrap().acknowledge(&acknowledgement.sync_ids);
+    HttpResponse::Ok().finish()
+}
+
+async fn push_records(
+    request: HttpRequest,
+    state: Data<MockCentralStateData>,
+    body: Bytes,
+) -> HttpResponse {
+    if !is_authorised(&request, &state) {
+        return HttpResponse::Unauthorized().finish();
+    }
+
+    let records: Vec<MockRemoteRecord> = match serde_json::from_slice(&body) {
+        Ok(records) => records,
+        Err(_) => return HttpResponse::BadRequest().finish(),
+    };
+    info!("Received {} pushed records", records.len());
+    state.lock().unwrap().push(records);
+    HttpResponse::Ok().finish()
+}
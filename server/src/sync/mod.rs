@@ -2,6 +2,7 @@ mod actor;
 mod central;
 mod connection;
 mod credentials;
+pub mod mock_central;
 mod remote;
 mod server;
 mod synchroniser;
@@ -13,7 +14,7 @@ pub use central::CentralSyncBatch;
 pub use connection::{SyncConnection, SyncConnectionError};
 pub use credentials::SyncCredentials;
 pub use remote::{
-    RemoteSyncAcknowledgement, RemoteSyncBatch, RemoteSyncRecord, RemoteSyncRecordAction,
-    RemoteSyncRecordData,
+    RemoteSyncAcknowledgement, RemoteSyncBatch, RemoteSyncPushRecord, RemoteSyncRecord,
+    RemoteSyncRecordAction, RemoteSyncRecordData,
 };
 pub use synchroniser::{CentralSyncError, RemoteSyncError, SyncError, Synchroniser};
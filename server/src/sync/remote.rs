@@ -61,6 +61,17 @@ impl Display for RemoteSyncRecordData {
     }
 }
 
+/// Record changed on the remote site, pushed to central in its legacy format
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RemoteSyncPushRecord {
+    #[serde(rename = "tableName")]
+    pub table_name: String,
+    #[serde(rename = "recordId")]
+    pub record_id: String,
+    pub action: RemoteSyncRecordAction,
+    pub data: serde_json::Value,
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct RemoteSyncAcknowledgement {
     #[serde(rename = "syncIDs")]
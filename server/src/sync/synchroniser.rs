@@ -1,10 +1,14 @@
 use crate::sync::{
-    translation::{import_sync_records, SyncImportError, TRANSLATION_RECORDS},
+    translation::{
+        import_sync_records,
+        push::{is_pushed_table, translate_push_record},
+        SyncImportError, TRANSLATION_RECORDS,
+    },
     CentralSyncBatch, RemoteSyncBatch, RemoteSyncRecord, SyncConnection, SyncConnectionError,
 };
 use repository::{
     schema::CentralSyncBufferRow, CentralSyncBufferRepository, CentralSyncCursorRepository,
-    NameStoreJoinRepository, RepositoryError, StorageConnectionManager,
+    NameStoreJoinRepository, RepositoryError, StorageConnectionManager, SyncOutRowRepository,
 };
 
 use log::info;
@@ -163,6 +167,78 @@ impl Synchroniser {
         Ok(records)
     }
 
+    // Push records queued in sync_out to central, queued records are removed once pushed.
+    //
+    // Only name, name tag and name tag join records are pushed, other records stay queued.
+    pub async fn push_remote_records(
+        &mut self,
+        connection_manager: &StorageConnectionManager,
+    ) -> Result<(), RemoteSyncError> {
+        // Arbitrary batch size.
+        const BATCH_SIZE: usize = 500;
+
+        let repository_error = |msg: &'static str| {
+            move |source: RepositoryError| RemoteSyncError {
+                msg,
+                source: anyhow::Error::from(source),
+            }
+        };
+
+        let connection = connection_manager
+            .connection()
+            .map_err(repository_error("Failed to connect to DB"))?;
+        let sync_out_repository = SyncOutRowRepository::new(&connection);
+
+        let (queued, unsupported): (Vec<_>, Vec<_>) = sync_out_repository
+            .find_all()
+            .map_err(repository_error("Failed to get queued remote sync records"))?
+            .into_iter()
+            .partition(|row| is_pushed_table(&row.table_name));
+        if !unsupported.is_empty() {
+            info!(
+                "{} queued remote sync records are not pushed yet",
+                unsupported.len()
+            );
+        }
+        if queued.is_empty() {
+            info!("No remote sync records to push");
+            return Ok(());
+        }
+
+        for rows in queued.chunks(BATCH_SIZE) {
+            let mut records = Vec::new();
+            for row in rows {
+                if let Some(record) = translate_push_record(&connection, row)
+                    .map_err(repository_error("Failed to translate remote sync record"))?
+                {
+                    records.push(record);
+                }
+            }
+
+            if !records.is_empty() {
+                info!("Pushing {} remote sync records...", records.len());
+                self.connection
+                    .push_remote_records(&records)
+                    .await
+                    .map_err(|source| RemoteSyncError {
+                        msg: "Failed to push remote sync records",
+                        source: anyhow::Error::from(source),
+                    })?;
+                info!("Pushed {} remote sync records", records.len());
+            }
+
+            for row in rows {
+                sync_out_repository
+                    .delete(&row.id)
+                    .map_err(repository_error(
+                        "Failed to remove pushed remote sync records",
+                    ))?;
+            }
+        }
+
+        Ok(())
+    }
+
     async fn integrate_central_records(
         &self,
         connection_manager: &StorageConnectionManager,
@@ -233,6 +309,10 @@ impl Synchroniser {
         self.integrate_central_records(connection_manager).await?;
         info!("Successfully integrated central records");
 
+        info!("Pushing remote records...");
+        self.push_remote_records(connection_manager).await?;
+        info!("Successfully pushed remote records");
+
         // info!("Syncing remote records...");
         // let remote_records = self.pull_remote_records().await?;
         // info!("Successfully pulled remote records");
@@ -251,10 +331,13 @@ mod tests {
         sync::{
             translation::test_data::{
                 check_records_against_database, extract_sync_buffer_rows,
-                item::get_test_item_records, master_list::get_test_master_list_records,
+                item::get_test_item_records, item_store_join::get_test_item_store_join_records,
+                location_type::get_test_location_type_records,
+                master_list::get_test_master_list_records,
                 master_list_line::get_test_master_list_line_records,
                 master_list_name_join::get_test_master_list_name_join_records,
                 name::get_test_name_records, store::get_test_store_records,
+                store_preference::get_test_store_preference_records,
             },
             SyncConnection, Synchroniser,
         },
@@ -281,6 +364,9 @@ mod tests {
         test_records.append(&mut get_test_master_list_records());
         test_records.append(&mut get_test_master_list_name_join_records());
         test_records.append(&mut get_test_master_list_line_records());
+        test_records.append(&mut get_test_location_type_records());
+        test_records.append(&mut get_test_item_store_join_records());
+        test_records.append(&mut get_test_store_preference_records());
 
         let central_records: Vec<CentralSyncBufferRow> = extract_sync_buffer_rows(&test_records);
         let connection = connection_manager.connection().unwrap();
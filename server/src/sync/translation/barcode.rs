@@ -0,0 +1,75 @@
+use crate::sync::translation::{SyncTranslationError, TRANSLATION_RECORD_BARCODE};
+use repository::schema::{BarcodeRow, CentralSyncBufferRow};
+
+use serde::Deserialize;
+
+#[allow(non_snake_case)]
+#[derive(Deserialize)]
+pub struct LegacyBarcodeRow {
+    ID: String,
+    barcode: String,
+    itemID: String,
+    manufacturerID: String,
+    packSize: Option<i32>,
+}
+
+impl LegacyBarcodeRow {
+    pub fn try_translate(
+        sync_record: &CentralSyncBufferRow,
+    ) -> Result<Option<BarcodeRow>, SyncTranslationError> {
+        let table_name = TRANSLATION_RECORD_BARCODE;
+
+        if sync_record.table_name != table_name {
+            return Ok(None);
+        }
+
+        let data =
+            serde_json::from_str::<LegacyBarcodeRow>(&sync_record.data).map_err(|source| {
+                SyncTranslationError {
+                    table_name,
+                    source,
+                    record: sync_record.data.clone(),
+                }
+            })?;
+
+        let mut result = BarcodeRow {
+            id: data.ID,
+            value: data.barcode,
+            item_id: data.itemID,
+            manufacturer_id: None,
+            // a pack size of 0 means the barcode isn't specific to a pack size
+            pack_size: data.packSize.filter(|pack_size| *pack_size > 0),
+        };
+
+        if data.manufacturerID != "" {
+            result.manufacturer_id = Some(data.manufacturerID);
+        }
+
+        Ok(Some(result))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sync::translation::{
+        barcode::LegacyBarcodeRow,
+        test_data::{barcode::get_test_barcode_records, TestSyncDataRecord},
+    };
+
+    #[test]
+    fn test_barcode_translation() {
+        for record in get_test_barcode_records() {
+            match record.translated_record {
+                TestSyncDataRecord::Barcode(translated_record) => {
+                    assert_eq!(
+                        LegacyBarcodeRow::try_translate(&record.central_sync_buffer_row).unwrap(),
+                        translated_record,
+                        "{}",
+                        record.identifier
+                    )
+                }
+                _ => panic!("Testing wrong record type {:#?}", record.translated_record),
+            }
+        }
+    }
+}
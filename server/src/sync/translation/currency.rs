@@ -0,0 +1,66 @@
+use crate::sync::translation::{SyncTranslationError, TRANSLATION_RECORD_CURRENCY};
+use repository::schema::{CentralSyncBufferRow, CurrencyRow};
+
+use serde::Deserialize;
+
+#[allow(non_snake_case)]
+#[derive(Deserialize)]
+pub struct LegacyCurrencyRow {
+    ID: String,
+    currency: String,
+    rate: f64,
+    is_home_currency: bool,
+}
+
+impl LegacyCurrencyRow {
+    pub fn try_translate(
+        sync_record: &CentralSyncBufferRow,
+    ) -> Result<Option<CurrencyRow>, SyncTranslationError> {
+        let table_name = TRANSLATION_RECORD_CURRENCY;
+
+        if sync_record.table_name != table_name {
+            return Ok(None);
+        }
+
+        let data =
+            serde_json::from_str::<LegacyCurrencyRow>(&sync_record.data).map_err(|source| {
+                SyncTranslationError {
+                    table_name,
+                    source,
+                    record: sync_record.data.clone(),
+                }
+            })?;
+
+        Ok(Some(CurrencyRow {
+            id: data.ID,
+            code: data.currency,
+            rate: data.rate,
+            is_home_currency: data.is_home_currency,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sync::translation::{
+        currency::LegacyCurrencyRow,
+        test_data::{currency::get_test_currency_records, TestSyncDataRecord},
+    };
+
+    #[test]
+    fn test_currency_translation() {
+        for record in get_test_currency_records() {
+            match record.translated_record {
+                TestSyncDataRecord::Currency(translated_record) => {
+                    assert_eq!(
+                        LegacyCurrencyRow::try_translate(&record.central_sync_buffer_row).unwrap(),
+                        translated_record,
+                        "{}",
+                        record.identifier
+                    )
+                }
+                _ => panic!("Testing wrong record type {:#?}", record.translated_record),
+            }
+        }
+    }
+}
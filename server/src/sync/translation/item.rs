@@ -1,5 +1,5 @@
 use crate::sync::translation::{SyncTranslationError, TRANSLATION_RECORD_ITEM};
-use repository::schema::{CentralSyncBufferRow, ItemRow, ItemRowType};
+use repository::schema::{CentralSyncBufferRow, ItemRow, ItemRowType, ItemRowVenCategory};
 
 use serde::Deserialize;
 
@@ -19,6 +19,9 @@ pub struct LegacyItemRow {
     code: String,
     unit_ID: String,
     type_of: LegacyItemType,
+    category_ID: String,
+    strength: String,
+    VEN_category: String,
 }
 
 fn to_item_type(type_of: LegacyItemType) -> ItemRowType {
@@ -29,6 +32,15 @@ fn to_item_type(type_of: LegacyItemType) -> ItemRowType {
     }
 }
 
+fn to_ven_category(ven_category: &str) -> Option<ItemRowVenCategory> {
+    match ven_category {
+        "V" => Some(ItemRowVenCategory::Vital),
+        "E" => Some(ItemRowVenCategory::Essential),
+        "N" => Some(ItemRowVenCategory::NonEssential),
+        _ => None,
+    }
+}
+
 impl LegacyItemRow {
     pub fn try_translate(
         sync_record: &CentralSyncBufferRow,
@@ -52,11 +64,24 @@ impl LegacyItemRow {
             code: data.code,
             unit_id: None,
             r#type: to_item_type(data.type_of),
+            category_id: None,
+            strength: None,
+            ven_category: to_ven_category(&data.VEN_category),
+            // not part of legacy item records, can only be set as local overrides
+            dosage_form: None,
+            is_controlled: false,
+            storage_requirement: None,
         };
 
         if data.unit_ID != "" {
             result.unit_id = Some(data.unit_ID);
         }
+        if data.category_ID != "" {
+            result.category_id = Some(data.category_ID);
+        }
+        if data.strength != "" {
+            result.strength = Some(data.strength);
+        }
 
         Ok(Some(result))
     }
@@ -0,0 +1,71 @@
+use crate::sync::translation::{SyncTranslationError, TRANSLATION_RECORD_ITEM_CATEGORY};
+use repository::schema::{CentralSyncBufferRow, ItemCategoryRow};
+
+use serde::Deserialize;
+
+#[allow(non_snake_case)]
+#[derive(Deserialize)]
+pub struct LegacyItemCategoryRow {
+    ID: String,
+    Description: String,
+    parent_ID: String,
+}
+
+impl LegacyItemCategoryRow {
+    pub fn try_translate(
+        sync_record: &CentralSyncBufferRow,
+    ) -> Result<Option<ItemCategoryRow>, SyncTranslationError> {
+        let table_name = TRANSLATION_RECORD_ITEM_CATEGORY;
+
+        if sync_record.table_name != table_name {
+            return Ok(None);
+        }
+
+        let data =
+            serde_json::from_str::<LegacyItemCategoryRow>(&sync_record.data).map_err(|source| {
+                SyncTranslationError {
+                    table_name,
+                    source,
+                    record: sync_record.data.clone(),
+                }
+            })?;
+
+        let mut result = ItemCategoryRow {
+            id: data.ID,
+            name: data.Description,
+            parent_id: None,
+        };
+
+        if data.parent_ID != "" {
+            result.parent_id = Some(data.parent_ID);
+        }
+
+        Ok(Some(result))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sync::translation::{
+        item_category::LegacyItemCategoryRow,
+        test_data::{item_category::get_test_item_category_records, TestSyncDataRecord},
+    };
+
+    #[test]
+    fn test_item_category_translation() {
+        for record in get_test_item_category_records() {
+            match record.translated_record {
+                TestSyncDataRecord::ItemCategory(translated_record) => {
+                    assert_eq!(
+                        LegacyItemCategoryRow::try_translate(&record.central_sync_buffer_row)
+                            .unwrap(),
+                        translated_record,
+                        "{}",
+                        record.identifier
+                    )
+                }
+                _ => panic!("Testing wrong record type {:#?}", record.translated_record),
+            }
+        }
+    }
+}
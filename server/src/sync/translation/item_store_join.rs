@@ -0,0 +1,78 @@
+use crate::sync::translation::{SyncTranslationError, TRANSLATION_RECORD_ITEM_STORE_JOIN};
+use repository::schema::{CentralSyncBufferRow, ItemStoreJoinRow};
+
+use serde::Deserialize;
+
+#[allow(non_snake_case)]
+#[derive(Deserialize)]
+pub struct LegacyItemStoreJoinRow {
+    ID: String,
+    item_ID: String,
+    store_ID: String,
+    default_price: f64,
+    margin: f64,
+    ignore_for_orders: bool,
+    inactive: bool,
+    restricted_location_type_ID: String,
+}
+
+impl LegacyItemStoreJoinRow {
+    pub fn try_translate(
+        sync_record: &CentralSyncBufferRow,
+    ) -> Result<Option<ItemStoreJoinRow>, SyncTranslationError> {
+        let table_name = TRANSLATION_RECORD_ITEM_STORE_JOIN;
+
+        if sync_record.table_name != table_name {
+            return Ok(None);
+        }
+
+        let data = serde_json::from_str::<LegacyItemStoreJoinRow>(&sync_record.data).map_err(
+            |source| SyncTranslationError {
+                table_name,
+                source,
+                record: sync_record.data.clone(),
+            },
+        )?;
+
+        let mut result = ItemStoreJoinRow {
+            id: data.ID,
+            item_id: data.item_ID,
+            store_id: data.store_ID,
+            default_sell_price_per_pack: data.default_price,
+            margin: data.margin,
+            ignore_for_orders: data.ignore_for_orders,
+            inactive: data.inactive,
+            restricted_location_type_id: None,
+        };
+        if data.restricted_location_type_ID != "" {
+            result.restricted_location_type_id = Some(data.restricted_location_type_ID);
+        }
+        Ok(Some(result))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sync::translation::{
+        item_store_join::LegacyItemStoreJoinRow,
+        test_data::{item_store_join::get_test_item_store_join_records, TestSyncDataRecord},
+    };
+
+    #[test]
+    fn test_item_store_join_translation() {
+        for record in get_test_item_store_join_records() {
+            match record.translated_record {
+                TestSyncDataRecord::ItemStoreJoin(translated_record) => {
+                    assert_eq!(
+                        LegacyItemStoreJoinRow::try_translate(&record.central_sync_buffer_row)
+                            .unwrap(),
+                        translated_record,
+                        "{}",
+                        record.identifier
+                    )
+                }
+                _ => panic!("Testing wrong record type {:#?}", record.translated_record),
+            }
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use crate::sync::translation::{SyncTranslationError, TRANSLATION_RECORD_LOCATION_TYPE};
+use repository::schema::{CentralSyncBufferRow, LocationTypeRow};
+
+use serde::Deserialize;
+
+#[allow(non_snake_case)]
+#[derive(Deserialize)]
+pub struct LegacyLocationTypeRow {
+    ID: String,
+    Description: String,
+    Temperature_min: Option<f64>,
+    Temperature_max: Option<f64>,
+}
+
+impl LegacyLocationTypeRow {
+    pub fn try_translate(
+        sync_record: &CentralSyncBufferRow,
+    ) -> Result<Option<LocationTypeRow>, SyncTranslationError> {
+        let table_name = TRANSLATION_RECORD_LOCATION_TYPE;
+
+        if sync_record.table_name != table_name {
+            return Ok(None);
+        }
+
+        let data =
+            serde_json::from_str::<LegacyLocationTypeRow>(&sync_record.data).map_err(|source| {
+                SyncTranslationError {
+                    table_name,
+                    source,
+                    record: sync_record.data.clone(),
+                }
+            })?;
+
+        Ok(Some(LocationTypeRow {
+            id: data.ID,
+            name: data.Description,
+            min_temperature: data.Temperature_min,
+            max_temperature: data.Temperature_max,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sync::translation::{
+        location_type::LegacyLocationTypeRow,
+        test_data::{location_type::get_test_location_type_records, TestSyncDataRecord},
+    };
+
+    #[test]
+    fn test_location_type_translation() {
+        for record in get_test_location_type_records() {
+            match record.translated_record {
+                TestSyncDataRecord::LocationType(translated_record) => {
+                    assert_eq!(
+                        LegacyLocationTypeRow::try_translate(&record.central_sync_buffer_row)
+                            .unwrap(),
+                        translated_record,
+                        "{}",
+                        record.identifier
+                    )
+                }
+                _ => panic!("Testing wrong record type {:#?}", record.translated_record),
+            }
+        }
+    }
+}
@@ -1,8 +1,17 @@
+mod barcode;
+mod currency;
 mod item;
+mod item_category;
+mod item_store_join;
 mod list_master;
 mod list_master_line;
 mod list_master_name_join;
+mod location_type;
 mod name;
+mod name_tag;
+mod options;
+mod pref;
+pub mod push;
 mod store;
 pub mod test_data;
 mod unit;
@@ -10,18 +19,23 @@ mod unit;
 use crate::sync::translation::unit::LegacyUnitRow;
 use repository::{
     schema::{
-        CentralSyncBufferRow, ItemRow, MasterListLineRow, MasterListNameJoinRow, MasterListRow,
-        NameRow, StoreRow, UnitRow,
+        BarcodeRow, CentralSyncBufferRow, CurrencyRow, ItemCategoryRow, ItemRow, ItemStoreJoinRow,
+        LocationTypeRow, MasterListLineRow, MasterListNameJoinRow, MasterListRow, NameRow,
+        ReasonRow, StorePreferenceRow, StoreRow, UnitRow,
     },
-    ItemRepository, MasterListLineRowRepository, MasterListNameJoinRepository,
-    MasterListRowRepository, NameRepository, RepositoryError, StorageConnection,
-    StorageConnectionManager, StoreRowRepository, TransactionError, UnitRowRepository,
+    BarcodeRowRepository, CurrencyRowRepository, ItemCategoryRowRepository, ItemRepository,
+    ItemStoreJoinRowRepository, LocationTypeRowRepository, MasterListLineRowRepository,
+    MasterListNameJoinRepository, MasterListRowRepository, NameRepository, ReasonRowRepository,
+    RepositoryError, StorageConnection, StorageConnectionManager, StorePreferenceRowRepository,
+    StoreRowRepository, TransactionError, UnitRowRepository,
 };
 
 use self::{
-    item::LegacyItemRow, list_master::LegacyListMasterRow,
-    list_master_line::LegacyListMasterLineRow, list_master_name_join::LegacyListMasterNameJoinRow,
-    name::LegacyNameRow, store::LegacyStoreRow,
+    barcode::LegacyBarcodeRow, currency::LegacyCurrencyRow, item::LegacyItemRow,
+    item_category::LegacyItemCategoryRow, item_store_join::LegacyItemStoreJoinRow,
+    list_master::LegacyListMasterRow, list_master_line::LegacyListMasterLineRow,
+    list_master_name_join::LegacyListMasterNameJoinRow, location_type::LegacyLocationTypeRow,
+    name::LegacyNameRow, options::LegacyOptionsRow, pref::LegacyPrefRow, store::LegacyStoreRow,
 };
 
 use log::{info, warn};
@@ -67,6 +81,13 @@ enum IntegrationUpsertRecord {
     MasterList(MasterListRow),
     MasterListLine(MasterListLineRow),
     MasterListNameJoin(MasterListNameJoinRow),
+    Currency(CurrencyRow),
+    LocationType(LocationTypeRow),
+    StorePreference(StorePreferenceRow),
+    Reason(ReasonRow),
+    ItemStoreJoin(ItemStoreJoinRow),
+    Barcode(BarcodeRow),
+    ItemCategory(ItemCategoryRow),
 }
 
 #[derive(Debug)]
@@ -91,6 +112,11 @@ fn do_translation(
         return Ok(());
     }
 
+    if let Some(row) = LegacyItemCategoryRow::try_translate(sync_record)? {
+        records.upserts.push(ItemCategory(row));
+        return Ok(());
+    }
+
     if let Some(row) = LegacyItemRow::try_translate(sync_record)? {
         records.upserts.push(Item(row));
         return Ok(());
@@ -121,27 +147,75 @@ fn do_translation(
         return Ok(());
     }
 
+    if let Some(row) = LegacyCurrencyRow::try_translate(sync_record)? {
+        records.upserts.push(Currency(row));
+        return Ok(());
+    }
+
+    if let Some(row) = LegacyLocationTypeRow::try_translate(sync_record)? {
+        records.upserts.push(LocationType(row));
+        return Ok(());
+    }
+
+    if let Some(row) = LegacyPrefRow::try_translate(sync_record)? {
+        records.upserts.push(StorePreference(row));
+        return Ok(());
+    }
+
+    if let Some(row) = LegacyOptionsRow::try_translate(sync_record)? {
+        records.upserts.push(Reason(row));
+        return Ok(());
+    }
+
+    if let Some(row) = LegacyItemStoreJoinRow::try_translate(sync_record)? {
+        records.upserts.push(ItemStoreJoin(row));
+        return Ok(());
+    }
+
+    if let Some(row) = LegacyBarcodeRow::try_translate(sync_record)? {
+        records.upserts.push(Barcode(row));
+        return Ok(());
+    }
+
     Ok(()) // At this point we are either ignoring records or record_types
 }
 
 pub const TRANSLATION_RECORD_NAME: &str = "name";
 pub const TRANSLATION_RECORD_UNIT: &str = "unit";
+pub const TRANSLATION_RECORD_ITEM_CATEGORY: &str = "item_category";
 pub const TRANSLATION_RECORD_ITEM: &str = "item";
 pub const TRANSLATION_RECORD_STORE: &str = "store";
 pub const TRANSLATION_RECORD_LIST_MASTER: &str = "list_master";
 pub const TRANSLATION_RECORD_LIST_MASTER_LINE: &str = "list_master_line";
 pub const TRANSLATION_RECORD_LIST_MASTER_NAME_JOIN: &str = "list_master_name_join";
+pub const TRANSLATION_RECORD_CURRENCY: &str = "currency";
+pub const TRANSLATION_RECORD_LOCATION_TYPE: &str = "location_type";
+pub const TRANSLATION_RECORD_PREF: &str = "pref";
+pub const TRANSLATION_RECORD_OPTIONS: &str = "options";
+pub const TRANSLATION_RECORD_ITEM_STORE_JOIN: &str = "item_store_join";
+pub const TRANSLATION_RECORD_BARCODE: &str = "barcode";
+/// Only pushed to central
+pub const TRANSLATION_RECORD_NAME_TAG: &str = "name_tag";
+/// Only pushed to central
+pub const TRANSLATION_RECORD_NAME_TAG_JOIN: &str = "name_tag_join";
 
 /// Returns a list of records that can be translated. The list is topologically sorted, i.e. items
 /// at the beginning of the list don't rely on later items to be translated first.
 pub const TRANSLATION_RECORDS: &[&str] = &[
     TRANSLATION_RECORD_NAME,
     TRANSLATION_RECORD_UNIT,
+    TRANSLATION_RECORD_ITEM_CATEGORY,
     TRANSLATION_RECORD_ITEM,
     TRANSLATION_RECORD_STORE,
     TRANSLATION_RECORD_LIST_MASTER,
     TRANSLATION_RECORD_LIST_MASTER_LINE,
     TRANSLATION_RECORD_LIST_MASTER_NAME_JOIN,
+    TRANSLATION_RECORD_CURRENCY,
+    TRANSLATION_RECORD_LOCATION_TYPE,
+    TRANSLATION_RECORD_PREF,
+    TRANSLATION_RECORD_OPTIONS,
+    TRANSLATION_RECORD_ITEM_STORE_JOIN,
+    TRANSLATION_RECORD_BARCODE,
 ];
 
 /// Imports sync records and writes them to the DB
@@ -188,6 +262,25 @@ fn integrate_record(
         IntegrationUpsertRecord::MasterListNameJoin(record) => {
             MasterListNameJoinRepository::new(con).upsert_one(record)
         }
+        IntegrationUpsertRecord::Currency(record) => {
+            CurrencyRowRepository::new(con).upsert_one(record)
+        }
+        IntegrationUpsertRecord::LocationType(record) => {
+            LocationTypeRowRepository::new(con).upsert_one(record)
+        }
+        IntegrationUpsertRecord::StorePreference(record) => {
+            StorePreferenceRowRepository::new(con).upsert_one(record)
+        }
+        IntegrationUpsertRecord::Reason(record) => ReasonRowRepository::new(con).upsert_one(record),
+        IntegrationUpsertRecord::ItemStoreJoin(record) => {
+            ItemStoreJoinRowRepository::new(con).upsert_one(record)
+        }
+        IntegrationUpsertRecord::Barcode(record) => {
+            BarcodeRowRepository::new(con).upsert_one(record)
+        }
+        IntegrationUpsertRecord::ItemCategory(record) => {
+            ItemCategoryRowRepository::new(con).upsert_one(record)
+        }
     }
 }
 
@@ -237,12 +330,20 @@ mod tests {
     use repository::{get_storage_connection_manager, test_db};
 
     use super::test_data::{
-        check_records_against_database, extract_sync_buffer_rows,
+        barcode::get_test_barcode_records,
+        check_records_against_database,
+        currency::get_test_currency_records,
+        extract_sync_buffer_rows,
         item::{get_test_item_records, get_test_item_upsert_records},
+        item_category::get_test_item_category_records,
+        item_store_join::get_test_item_store_join_records,
+        location_type::get_test_location_type_records,
         master_list::{get_test_master_list_records, get_test_master_list_upsert_records},
         master_list_line::get_test_master_list_line_records,
         master_list_name_join::get_test_master_list_name_join_records,
         name::{get_test_name_records, get_test_name_upsert_records},
+        reason::get_test_reason_records,
+        store_preference::get_test_store_preference_records,
         unit::{get_test_unit_records, get_test_unit_upsert_records},
     };
 
@@ -258,10 +359,17 @@ mod tests {
         records.append(&mut get_test_name_records());
         records.append(&mut get_test_store_records());
         records.append(&mut get_test_unit_records());
+        records.append(&mut get_test_item_category_records());
         records.append(&mut get_test_item_records());
         records.append(&mut get_test_master_list_records());
         records.append(&mut get_test_master_list_line_records());
         records.append(&mut get_test_master_list_name_join_records());
+        records.append(&mut get_test_currency_records());
+        records.append(&mut get_test_location_type_records());
+        records.append(&mut get_test_store_preference_records());
+        records.append(&mut get_test_reason_records());
+        records.append(&mut get_test_item_store_join_records());
+        records.append(&mut get_test_barcode_records());
 
         import_sync_records(&connection_manager, &extract_sync_buffer_rows(&records))
             .await
@@ -281,6 +389,7 @@ mod tests {
         let mut init_records = Vec::new();
         init_records.append(&mut get_test_name_records());
         init_records.append(&mut get_test_unit_records());
+        init_records.append(&mut get_test_item_category_records());
         init_records.append(&mut get_test_item_records());
         init_records.append(&mut get_test_master_list_records());
         let mut upsert_records = Vec::new();
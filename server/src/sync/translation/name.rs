@@ -1,16 +1,59 @@
 use crate::sync::translation::{SyncTranslationError, TRANSLATION_RECORD_NAME};
-use repository::schema::{CentralSyncBufferRow, NameRow};
+use chrono::NaiveDate;
+use repository::schema::{CentralSyncBufferRow, NameRow, NameRowType};
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 #[allow(non_snake_case)]
-#[derive(Deserialize)]
+#[derive(Deserialize, Serialize)]
 pub struct LegacyNameRow {
     ID: String,
     name: String,
     code: String,
     customer: bool,
     supplier: bool,
+    #[serde(rename = "type")]
+    name_type: String,
+    first: String,
+    last: String,
+    date_of_birth: String,
+    phone: String,
+    /// Home store of a patient, patients are only editable in their home store
+    supplying_store_id: String,
+    manufacturer: bool,
+    donor: bool,
+    email: String,
+    url: String,
+    bill_address1: String,
+    bill_address2: String,
+    country: String,
+    comment: String,
+}
+
+fn to_name_type(name_type: &str) -> NameRowType {
+    match name_type {
+        "patient" => NameRowType::Patient,
+        "store" => NameRowType::Store,
+        "facility" => NameRowType::Facility,
+        _ => NameRowType::Other,
+    }
+}
+
+fn to_legacy_name_type(r#type: &NameRowType) -> &'static str {
+    match r#type {
+        NameRowType::Patient => "patient",
+        NameRowType::Store => "store",
+        NameRowType::Facility => "facility",
+        NameRowType::Other => "",
+    }
+}
+
+fn empty_str_as_option(value: String) -> Option<String> {
+    if value.is_empty() {
+        None
+    } else {
+        Some(value)
+    }
 }
 
 impl LegacyNameRow {
@@ -31,16 +74,67 @@ impl LegacyNameRow {
             }
         })?;
 
+        let r#type = to_name_type(&data.name_type);
+        // the home store only applies to patients, other names are visible in all stores
+        let home_store_id = match r#type {
+            NameRowType::Patient => empty_str_as_option(data.supplying_store_id),
+            _ => None,
+        };
         Ok(Some(NameRow {
             id: data.ID.to_string(),
             name: data.name.to_string(),
             code: data.code.to_string(),
             is_customer: data.customer,
             is_supplier: data.supplier,
+            r#type,
+            first_name: empty_str_as_option(data.first),
+            last_name: empty_str_as_option(data.last),
+            // "0000-00-00" if not set
+            date_of_birth: NaiveDate::parse_from_str(&data.date_of_birth, "%Y-%m-%d").ok(),
+            phone: empty_str_as_option(data.phone),
+            home_store_id,
+            is_manufacturer: data.manufacturer,
+            is_donor: data.donor,
+            email: empty_str_as_option(data.email),
+            website: empty_str_as_option(data.url),
+            address1: empty_str_as_option(data.bill_address1),
+            address2: empty_str_as_option(data.bill_address2),
+            country: empty_str_as_option(data.country),
+            comment: empty_str_as_option(data.comment),
         }))
     }
 }
 
+/// Legacy row of a name pushed to central
+impl From<NameRow> for LegacyNameRow {
+    fn from(row: NameRow) -> Self {
+        LegacyNameRow {
+            ID: row.id,
+            name: row.name,
+            code: row.code,
+            customer: row.is_customer,
+            supplier: row.is_supplier,
+            name_type: to_legacy_name_type(&row.r#type).to_string(),
+            first: row.first_name.unwrap_or_default(),
+            last: row.last_name.unwrap_or_default(),
+            date_of_birth: row
+                .date_of_birth
+                .map(|date| date.format("%Y-%m-%d").to_string())
+                .unwrap_or_else(|| "0000-00-00".to_string()),
+            phone: row.phone.unwrap_or_default(),
+            supplying_store_id: row.home_store_id.unwrap_or_default(),
+            manufacturer: row.is_manufacturer,
+            donor: row.is_donor,
+            email: row.email.unwrap_or_default(),
+            url: row.website.unwrap_or_default(),
+            bill_address1: row.address1.unwrap_or_default(),
+            bill_address2: row.address2.unwrap_or_default(),
+            country: row.country.unwrap_or_default(),
+            comment: row.comment.unwrap_or_default(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::sync::translation::{
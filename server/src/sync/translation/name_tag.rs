@@ -0,0 +1,37 @@
+use repository::schema::{NameTagJoinRow, NameTagRow};
+
+use serde::Serialize;
+
+#[allow(non_snake_case)]
+#[derive(Serialize)]
+pub struct LegacyNameTagRow {
+    ID: String,
+    description: String,
+}
+
+impl From<NameTagRow> for LegacyNameTagRow {
+    fn from(row: NameTagRow) -> Self {
+        LegacyNameTagRow {
+            ID: row.id,
+            description: row.name,
+        }
+    }
+}
+
+#[allow(non_snake_case)]
+#[derive(Serialize)]
+pub struct LegacyNameTagJoinRow {
+    ID: String,
+    name_ID: String,
+    name_tag_ID: String,
+}
+
+impl From<NameTagJoinRow> for LegacyNameTagJoinRow {
+    fn from(row: NameTagJoinRow) -> Self {
+        LegacyNameTagJoinRow {
+            ID: row.id,
+            name_ID: row.name_id,
+            name_tag_ID: row.name_tag_id,
+        }
+    }
+}
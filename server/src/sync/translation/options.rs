@@ -0,0 +1,75 @@
+use crate::sync::translation::{SyncTranslationError, TRANSLATION_RECORD_OPTIONS};
+use repository::schema::{CentralSyncBufferRow, ReasonRow, ReasonRowType};
+
+use serde::Deserialize;
+
+#[allow(non_snake_case)]
+#[derive(Deserialize)]
+pub struct LegacyOptionsRow {
+    ID: String,
+    title: String,
+    #[serde(rename = "type")]
+    type_of: String,
+    isActive: bool,
+}
+
+impl LegacyOptionsRow {
+    pub fn try_translate(
+        sync_record: &CentralSyncBufferRow,
+    ) -> Result<Option<ReasonRow>, SyncTranslationError> {
+        let table_name = TRANSLATION_RECORD_OPTIONS;
+
+        if sync_record.table_name != table_name {
+            return Ok(None);
+        }
+
+        let data =
+            serde_json::from_str::<LegacyOptionsRow>(&sync_record.data).map_err(|source| {
+                SyncTranslationError {
+                    table_name,
+                    source,
+                    record: sync_record.data.clone(),
+                }
+            })?;
+
+        // Options are also used for other lookups (e.g. vial wastage), only reasons are translated
+        let r#type = match &data.type_of[..] {
+            "positiveInventoryAdjustment" => ReasonRowType::PositiveInventoryAdjustment,
+            "negativeInventoryAdjustment" => ReasonRowType::NegativeInventoryAdjustment,
+            "returnReason" => ReasonRowType::ReturnReason,
+            _ => return Ok(None),
+        };
+
+        Ok(Some(ReasonRow {
+            id: data.ID,
+            r#type,
+            reason: data.title,
+            is_active: data.isActive,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sync::translation::{
+        options::LegacyOptionsRow,
+        test_data::{reason::get_test_reason_records, TestSyncDataRecord},
+    };
+
+    #[test]
+    fn test_options_translation() {
+        for record in get_test_reason_records() {
+            match record.translated_record {
+                TestSyncDataRecord::Reason(translated_record) => {
+                    assert_eq!(
+                        LegacyOptionsRow::try_translate(&record.central_sync_buffer_row).unwrap(),
+                        translated_record,
+                        "{}",
+                        record.identifier
+                    )
+                }
+                _ => panic!("Testing wrong record type {:#?}", record.translated_record),
+            }
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use crate::sync::translation::{SyncTranslationError, TRANSLATION_RECORD_PREF};
+use repository::schema::{CentralSyncBufferRow, StorePreferenceRow};
+
+use serde::Deserialize;
+
+/// Value of the `item` field for preference records holding store preferences.
+const STORE_PREFERENCES_ITEM: &str = "store_preferences";
+
+#[allow(non_snake_case)]
+#[derive(Deserialize)]
+pub struct LegacyPrefRow {
+    ID: String,
+    store_ID: String,
+    item: String,
+    data: serde_json::Value,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize)]
+pub struct LegacyStorePreferences {
+    #[serde(default)]
+    pack_to_one: bool,
+    #[serde(default)]
+    monthsOverstock: f64,
+    #[serde(default)]
+    monthsUnderstock: f64,
+    #[serde(default)]
+    monthsItemsExpire: f64,
+}
+
+impl LegacyPrefRow {
+    pub fn try_translate(
+        sync_record: &CentralSyncBufferRow,
+    ) -> Result<Option<StorePreferenceRow>, SyncTranslationError> {
+        let table_name = TRANSLATION_RECORD_PREF;
+
+        if sync_record.table_name != table_name {
+            return Ok(None);
+        }
+
+        let map_error = |source| SyncTranslationError {
+            table_name,
+            source,
+            record: sync_record.data.clone(),
+        };
+        let data = serde_json::from_str::<LegacyPrefRow>(&sync_record.data).map_err(map_error)?;
+
+        // Only store preferences are translated, other preferences are user or site specific
+        if data.item != STORE_PREFERENCES_ITEM || data.store_ID == "" {
+            return Ok(None);
+        }
+        let preferences =
+            serde_json::from_value::<LegacyStorePreferences>(data.data).map_err(map_error)?;
+
+        Ok(Some(StorePreferenceRow {
+            id: data.ID,
+            store_id: data.store_ID,
+            pack_to_one: preferences.pack_to_one,
+            months_overstock: preferences.monthsOverstock,
+            months_understock: preferences.monthsUnderstock,
+            months_items_expire: preferences.monthsItemsExpire,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sync::translation::{
+        pref::LegacyPrefRow,
+        test_data::{store_preference::get_test_store_preference_records, TestSyncDataRecord},
+    };
+
+    #[test]
+    fn test_pref_translation() {
+        for record in get_test_store_preference_records() {
+            match record.translated_record {
+                TestSyncDataRecord::StorePreference(translated_record) => {
+                    assert_eq!(
+                        LegacyPrefRow::try_translate(&record.central_sync_buffer_row).unwrap(),
+                        translated_record,
+                        "{}",
+                        record.identifier
+                    )
+                }
+                _ => panic!("Testing wrong record type {:#?}", record.translated_record),
+            }
+        }
+    }
+}
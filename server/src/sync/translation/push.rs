@@ -0,0 +1,93 @@
+use crate::sync::{
+    translation::{
+        name::LegacyNameRow,
+        name_tag::{LegacyNameTagJoinRow, LegacyNameTagRow},
+        TRANSLATION_RECORD_NAME, TRANSLATION_RECORD_NAME_TAG, TRANSLATION_RECORD_NAME_TAG_JOIN,
+    },
+    RemoteSyncPushRecord, RemoteSyncRecordAction,
+};
+use repository::{
+    schema::{SyncOutRow, SyncOutRowActionType, SyncOutRowTableNameType},
+    NameRepository, NameTagJoinRowRepository, NameTagRowRepository, RepositoryError,
+    StorageConnection,
+};
+
+use serde::Serialize;
+use serde_json::json;
+
+/// Tables of the sync out queue which can be pushed to central
+pub fn is_pushed_table(table_name: &SyncOutRowTableNameType) -> bool {
+    matches!(
+        table_name,
+        SyncOutRowTableNameType::Name
+            | SyncOutRowTableNameType::NameTag
+            | SyncOutRowTableNameType::NameTagJoin
+    )
+}
+
+/// Translates a queued record into its legacy format.
+///
+/// Returns None if the table is not pushed or if the record has been removed since it was queued,
+/// e.g. a name tag join that was replaced by a later update.
+pub fn translate_push_record(
+    connection: &StorageConnection,
+    row: &SyncOutRow,
+) -> Result<Option<RemoteSyncPushRecord>, RepositoryError> {
+    let table_name = match row.table_name {
+        SyncOutRowTableNameType::Name => TRANSLATION_RECORD_NAME,
+        SyncOutRowTableNameType::NameTag => TRANSLATION_RECORD_NAME_TAG,
+        SyncOutRowTableNameType::NameTagJoin => TRANSLATION_RECORD_NAME_TAG_JOIN,
+        _ => return Ok(None),
+    };
+
+    let (action, data) = match row.action {
+        SyncOutRowActionType::Insert => (RemoteSyncRecordAction::Create, None),
+        SyncOutRowActionType::Update | SyncOutRowActionType::Patch => {
+            (RemoteSyncRecordAction::Update, None)
+        }
+        SyncOutRowActionType::Delete => (
+            RemoteSyncRecordAction::Delete,
+            Some(json!({ "ID": row.record_id })),
+        ),
+    };
+
+    let data = match data {
+        Some(data) => data,
+        None => match legacy_record(connection, row)? {
+            Some(data) => data,
+            None => return Ok(None),
+        },
+    };
+
+    Ok(Some(RemoteSyncPushRecord {
+        table_name: table_name.to_string(),
+        record_id: row.record_id.clone(),
+        action,
+        data,
+    }))
+}
+
+fn legacy_record(
+    connection: &StorageConnection,
+    row: &SyncOutRow,
+) -> Result<Option<serde_json::Value>, RepositoryError> {
+    let data = match row.table_name {
+        SyncOutRowTableNameType::Name => NameRepository::new(connection)
+            .find_many_by_id(std::slice::from_ref(&row.record_id))?
+            .pop()
+            .map(|row| to_value(LegacyNameRow::from(row))),
+        SyncOutRowTableNameType::NameTag => NameTagRowRepository::new(connection)
+            .find_one_by_id(&row.record_id)?
+            .map(|row| to_value(LegacyNameTagRow::from(row))),
+        SyncOutRowTableNameType::NameTagJoin => NameTagJoinRowRepository::new(connection)
+            .find_one_by_id(&row.record_id)?
+            .map(|row| to_value(LegacyNameTagJoinRow::from(row))),
+        _ => None,
+    };
+    Ok(data)
+}
+
+fn to_value<T: Serialize>(row: T) -> serde_json::Value {
+    // legacy rows only contain strings, bools and numbers
+    serde_json::to_value(row).unwrap_or_default()
+}
@@ -0,0 +1,68 @@
+use crate::sync::translation::test_data::{TestSyncDataRecord, TestSyncRecord};
+use repository::schema::{BarcodeRow, CentralSyncBufferRow};
+
+const BARCODE_1: (&'static str, &'static str) = (
+    "C7A1B5E2D3F44A6B9C8D7E6F5A4B3C2D",
+    r#"{
+    "ID": "C7A1B5E2D3F44A6B9C8D7E6F5A4B3C2D",
+    "barcode": "09501101530003",
+    "itemID": "8F252B5884B74888AAB73A0D42C09E7F",
+    "manufacturerID": "1FB32324AF8049248D929CFB35F255BA",
+    "packSize": 10,
+    "variationID": "",
+    "parentID": ""
+}"#,
+);
+
+const BARCODE_2: (&'static str, &'static str) = (
+    "D8B2C6F3E4A54B7C0D9E8F7A6B5C4D3E",
+    r#"{
+    "ID": "D8B2C6F3E4A54B7C0D9E8F7A6B5C4D3E",
+    "barcode": "5012345678900",
+    "itemID": "8F252B5884B74888AAB73A0D42C09E7F",
+    "manufacturerID": "",
+    "packSize": 0,
+    "variationID": "",
+    "parentID": ""
+}"#,
+);
+
+#[allow(dead_code)]
+const RECORD_TYPE: &'static str = "barcode";
+#[allow(dead_code)]
+pub fn get_test_barcode_records() -> Vec<TestSyncRecord> {
+    vec![
+        TestSyncRecord {
+            translated_record: TestSyncDataRecord::Barcode(Some(BarcodeRow {
+                id: BARCODE_1.0.to_owned(),
+                value: "09501101530003".to_owned(),
+                item_id: "8F252B5884B74888AAB73A0D42C09E7F".to_owned(),
+                manufacturer_id: Some("1FB32324AF8049248D929CFB35F255BA".to_owned()),
+                pack_size: Some(10),
+            })),
+            identifier: "Barcode with manufacturer and pack size",
+            central_sync_buffer_row: CentralSyncBufferRow {
+                id: 1100,
+                table_name: RECORD_TYPE.to_owned(),
+                record_id: BARCODE_1.0.to_owned(),
+                data: BARCODE_1.1.to_owned(),
+            },
+        },
+        TestSyncRecord {
+            translated_record: TestSyncDataRecord::Barcode(Some(BarcodeRow {
+                id: BARCODE_2.0.to_owned(),
+                value: "5012345678900".to_owned(),
+                item_id: "8F252B5884B74888AAB73A0D42C09E7F".to_owned(),
+                manufacturer_id: None,
+                pack_size: None,
+            })),
+            identifier: "Barcode without manufacturer and pack size",
+            central_sync_buffer_row: CentralSyncBufferRow {
+                id: 1101,
+                table_name: RECORD_TYPE.to_owned(),
+                record_id: BARCODE_2.0.to_owned(),
+                data: BARCODE_2.1.to_owned(),
+            },
+        },
+    ]
+}
@@ -0,0 +1,62 @@
+use crate::sync::translation::test_data::{TestSyncDataRecord, TestSyncRecord};
+use repository::schema::{CentralSyncBufferRow, CurrencyRow};
+
+const CURRENCY_1: (&'static str, &'static str) = (
+    "8009D512AC0E4FD78625E3C8273B0171",
+    r#"{
+    "ID": "8009D512AC0E4FD78625E3C8273B0171",
+    "rate": 1,
+    "currency": "NZD",
+    "is_home_currency": true,
+    "date_updated": "0000-00-00"
+}"#,
+);
+
+const CURRENCY_2: (&'static str, &'static str) = (
+    "6A2E7D0E5C1B4F4B9C46E51CFEC2FCA1",
+    r#"{
+    "ID": "6A2E7D0E5C1B4F4B9C46E51CFEC2FCA1",
+    "rate": 0.67,
+    "currency": "USD",
+    "is_home_currency": false,
+    "date_updated": "2021-11-09"
+}"#,
+);
+
+#[allow(dead_code)]
+const RECORD_TYPE: &'static str = "currency";
+#[allow(dead_code)]
+pub fn get_test_currency_records() -> Vec<TestSyncRecord> {
+    vec![
+        TestSyncRecord {
+            translated_record: TestSyncDataRecord::Currency(Some(CurrencyRow {
+                id: CURRENCY_1.0.to_owned(),
+                code: "NZD".to_owned(),
+                rate: 1.0,
+                is_home_currency: true,
+            })),
+            identifier: "Home currency",
+            central_sync_buffer_row: CentralSyncBufferRow {
+                id: 600,
+                table_name: RECORD_TYPE.to_owned(),
+                record_id: CURRENCY_1.0.to_owned(),
+                data: CURRENCY_1.1.to_owned(),
+            },
+        },
+        TestSyncRecord {
+            translated_record: TestSyncDataRecord::Currency(Some(CurrencyRow {
+                id: CURRENCY_2.0.to_owned(),
+                code: "USD".to_owned(),
+                rate: 0.67,
+                is_home_currency: false,
+            })),
+            identifier: "Foreign currency",
+            central_sync_buffer_row: CentralSyncBufferRow {
+                id: 601,
+                table_name: RECORD_TYPE.to_owned(),
+                record_id: CURRENCY_2.0.to_owned(),
+                data: CURRENCY_2.1.to_owned(),
+            },
+        },
+    ]
+}
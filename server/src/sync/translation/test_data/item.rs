@@ -1,5 +1,5 @@
 use crate::sync::translation::test_data::{TestSyncDataRecord, TestSyncRecord};
-use repository::schema::{CentralSyncBufferRow, ItemRow, ItemRowType};
+use repository::schema::{CentralSyncBufferRow, ItemRow, ItemRowType, ItemRowVenCategory};
 
 const ITEM_1: (&'static str, &'static str) = (
     "8F252B5884B74888AAB73A0D42C09E7F",
@@ -121,7 +121,7 @@ const ITEM_1_UPSERT: (&'static str, &'static str) = (
     "interaction_group_ID": "",
     "spare_pack_to_one_on_receive": false,
     "cross_ref_item_ID": "",
-    "strength": "",
+    "strength": "500mg",
     "user_field_4": false,
     "user_field_6": "",
     "spare_internal_analysis": 0,
@@ -133,7 +133,7 @@ const ITEM_1_UPSERT: (&'static str, &'static str) = (
     "account_income_ID": "EF34ADD07C014AB8914E30CA2E3FEA8D",
     "unit_ID": "A02C91EB6C77400BA783C4CD7C565F29",
     "outer_pack_size": 0,
-    "category_ID": "",
+    "category_ID": "5E7F2B3C4D5E4F6A9B0C1D2E3F4A5B6C",
     "ABC_category": "",
     "warning_quantity": 0,
     "user_field_5": 0,
@@ -148,7 +148,7 @@ const ITEM_1_UPSERT: (&'static str, &'static str) = (
     "category2_ID": "",
     "category3_ID": "",
     "buy_price": 0,
-    "VEN_category": "",
+    "VEN_category": "E",
     "universalcodes_code": "",
     "universalcodes_name": "",
     "kit_data": null,
@@ -170,6 +170,12 @@ pub fn get_test_item_records() -> Vec<TestSyncRecord> {
             code: "NSI".to_owned(),
             unit_id: None,
             r#type: ItemRowType::NonStock,
+            category_id: None,
+            strength: None,
+            dosage_form: None,
+            ven_category: None,
+            is_controlled: false,
+            storage_requirement: None,
         })),
         identifier: "Non stock items",
         central_sync_buffer_row: CentralSyncBufferRow {
@@ -189,6 +195,12 @@ pub fn get_test_item_upsert_records() -> Vec<TestSyncRecord> {
             code: "NSI".to_owned(),
             unit_id: Some("A02C91EB6C77400BA783C4CD7C565F29".to_owned()),
             r#type: ItemRowType::Stock,
+            category_id: Some("5E7F2B3C4D5E4F6A9B0C1D2E3F4A5B6C".to_owned()),
+            strength: Some("500mg".to_owned()),
+            dosage_form: None,
+            ven_category: Some(ItemRowVenCategory::Essential),
+            is_controlled: false,
+            storage_requirement: None,
         })),
         identifier: "Non stock items 2",
         central_sync_buffer_row: CentralSyncBufferRow {
@@ -0,0 +1,58 @@
+use crate::sync::translation::test_data::{TestSyncDataRecord, TestSyncRecord};
+use repository::schema::{CentralSyncBufferRow, ItemCategoryRow};
+
+const ITEM_CATEGORY_1: (&'static str, &'static str) = (
+    "4D6E1A2B3C4D4E5F8A9B0C1D2E3F4A5B",
+    r#"{
+    "ID": "4D6E1A2B3C4D4E5F8A9B0C1D2E3F4A5B",
+    "Description": "Anti-infective medicines",
+    "parent_ID": "",
+    "sort_order": 1
+}"#,
+);
+
+const ITEM_CATEGORY_2: (&'static str, &'static str) = (
+    "5E7F2B3C4D5E4F6A9B0C1D2E3F4A5B6C",
+    r#"{
+    "ID": "5E7F2B3C4D5E4F6A9B0C1D2E3F4A5B6C",
+    "Description": "Antibacterials",
+    "parent_ID": "4D6E1A2B3C4D4E5F8A9B0C1D2E3F4A5B",
+    "sort_order": 2
+}"#,
+);
+
+#[allow(dead_code)]
+const RECORD_TYPE: &'static str = "item_category";
+#[allow(dead_code)]
+pub fn get_test_item_category_records() -> Vec<TestSyncRecord> {
+    vec![
+        TestSyncRecord {
+            translated_record: TestSyncDataRecord::ItemCategory(Some(ItemCategoryRow {
+                id: ITEM_CATEGORY_1.0.to_owned(),
+                name: "Anti-infective medicines".to_owned(),
+                parent_id: None,
+            })),
+            identifier: "Top level category",
+            central_sync_buffer_row: CentralSyncBufferRow {
+                id: 1200,
+                table_name: RECORD_TYPE.to_owned(),
+                record_id: ITEM_CATEGORY_1.0.to_owned(),
+                data: ITEM_CATEGORY_1.1.to_owned(),
+            },
+        },
+        TestSyncRecord {
+            translated_record: TestSyncDataRecord::ItemCategory(Some(ItemCategoryRow {
+                id: ITEM_CATEGORY_2.0.to_owned(),
+                name: "Antibacterials".to_owned(),
+                parent_id: Some("4D6E1A2B3C4D4E5F8A9B0C1D2E3F4A5B".to_owned()),
+            })),
+            identifier: "Sub category",
+            central_sync_buffer_row: CentralSyncBufferRow {
+                id: 1201,
+                table_name: RECORD_TYPE.to_owned(),
+                record_id: ITEM_CATEGORY_2.0.to_owned(),
+                data: ITEM_CATEGORY_2.1.to_owned(),
+            },
+        },
+    ]
+}
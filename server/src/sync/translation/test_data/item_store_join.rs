@@ -0,0 +1,80 @@
+use crate::sync::translation::test_data::{TestSyncDataRecord, TestSyncRecord};
+use repository::schema::{CentralSyncBufferRow, ItemStoreJoinRow};
+
+const ITEM_STORE_JOIN_1: (&'static str, &'static str) = (
+    "B5D1E2F3A4C54B6D8E9F0A1B2C3D4E5F",
+    r#"{
+    "ID": "B5D1E2F3A4C54B6D8E9F0A1B2C3D4E5F",
+    "item_ID": "8F252B5884B74888AAB73A0D42C09E7F",
+    "store_ID": "4E27CEB263354EB7B1B33CEA8F7884D8",
+    "default_location_ID": "",
+    "ignore_for_orders": false,
+    "margin": 10,
+    "default_price": 2.5,
+    "inactive": false,
+    "restricted_location_type_ID": "",
+    "pack_to_one": false
+}"#,
+);
+
+const ITEM_STORE_JOIN_2: (&'static str, &'static str) = (
+    "C6E2F3A4B5D64C7E9F0A1B2C3D4E5F60",
+    r#"{
+    "ID": "C6E2F3A4B5D64C7E9F0A1B2C3D4E5F60",
+    "item_ID": "8F252B5884B74888AAB73A0D42C09E7F",
+    "store_ID": "4E27CEB263354EB7B1B33CEA8F7884D8",
+    "default_location_ID": "",
+    "ignore_for_orders": false,
+    "margin": 0,
+    "default_price": 0,
+    "inactive": false,
+    "restricted_location_type_ID": "A3CE2E4BB4D54F6AA0F29A9B5C1D5B16",
+    "pack_to_one": false
+}"#,
+);
+
+#[allow(dead_code)]
+const RECORD_TYPE: &'static str = "item_store_join";
+#[allow(dead_code)]
+pub fn get_test_item_store_join_records() -> Vec<TestSyncRecord> {
+    vec![
+        TestSyncRecord {
+            translated_record: TestSyncDataRecord::ItemStoreJoin(Some(ItemStoreJoinRow {
+                id: ITEM_STORE_JOIN_1.0.to_owned(),
+                item_id: "8F252B5884B74888AAB73A0D42C09E7F".to_owned(),
+                store_id: "4E27CEB263354EB7B1B33CEA8F7884D8".to_owned(),
+                default_sell_price_per_pack: 2.5,
+                margin: 10.0,
+                ignore_for_orders: false,
+                inactive: false,
+                restricted_location_type_id: None,
+            })),
+            identifier: "Item store join",
+            central_sync_buffer_row: CentralSyncBufferRow {
+                id: 1000,
+                table_name: RECORD_TYPE.to_owned(),
+                record_id: ITEM_STORE_JOIN_1.0.to_owned(),
+                data: ITEM_STORE_JOIN_1.1.to_owned(),
+            },
+        },
+        TestSyncRecord {
+            translated_record: TestSyncDataRecord::ItemStoreJoin(Some(ItemStoreJoinRow {
+                id: ITEM_STORE_JOIN_2.0.to_owned(),
+                item_id: "8F252B5884B74888AAB73A0D42C09E7F".to_owned(),
+                store_id: "4E27CEB263354EB7B1B33CEA8F7884D8".to_owned(),
+                default_sell_price_per_pack: 0.0,
+                margin: 0.0,
+                ignore_for_orders: false,
+                inactive: false,
+                restricted_location_type_id: Some("A3CE2E4BB4D54F6AA0F29A9B5C1D5B16".to_owned()),
+            })),
+            identifier: "Item restricted to a location type",
+            central_sync_buffer_row: CentralSyncBufferRow {
+                id: 1001,
+                table_name: RECORD_TYPE.to_owned(),
+                record_id: ITEM_STORE_JOIN_2.0.to_owned(),
+                data: ITEM_STORE_JOIN_2.1.to_owned(),
+            },
+        },
+    ]
+}
@@ -0,0 +1,60 @@
+use crate::sync::translation::test_data::{TestSyncDataRecord, TestSyncRecord};
+use repository::schema::{CentralSyncBufferRow, LocationTypeRow};
+
+const LOCATION_TYPE_1: (&'static str, &'static str) = (
+    "A3CE2E4BB4D54F6AA0F29A9B5C1D5B16",
+    r#"{
+    "ID": "A3CE2E4BB4D54F6AA0F29A9B5C1D5B16",
+    "Description": "Cold room (2-8 C)",
+    "Temperature_min": 2,
+    "Temperature_max": 8
+}"#,
+);
+
+const LOCATION_TYPE_2: (&'static str, &'static str) = (
+    "E6A2D8F3B6DB4F0A9E0F8C0B3B9E6C44",
+    r#"{
+    "ID": "E6A2D8F3B6DB4F0A9E0F8C0B3B9E6C44",
+    "Description": "Controlled drug cabinet",
+    "Temperature_min": null,
+    "Temperature_max": null
+}"#,
+);
+
+#[allow(dead_code)]
+const RECORD_TYPE: &'static str = "location_type";
+#[allow(dead_code)]
+pub fn get_test_location_type_records() -> Vec<TestSyncRecord> {
+    vec![
+        TestSyncRecord {
+            translated_record: TestSyncDataRecord::LocationType(Some(LocationTypeRow {
+                id: LOCATION_TYPE_1.0.to_owned(),
+                name: "Cold room (2-8 C)".to_owned(),
+                min_temperature: Some(2.0),
+                max_temperature: Some(8.0),
+            })),
+            identifier: "Cold room",
+            central_sync_buffer_row: CentralSyncBufferRow {
+                id: 700,
+                table_name: RECORD_TYPE.to_owned(),
+                record_id: LOCATION_TYPE_1.0.to_owned(),
+                data: LOCATION_TYPE_1.1.to_owned(),
+            },
+        },
+        TestSyncRecord {
+            translated_record: TestSyncDataRecord::LocationType(Some(LocationTypeRow {
+                id: LOCATION_TYPE_2.0.to_owned(),
+                name: "Controlled drug cabinet".to_owned(),
+                min_temperature: None,
+                max_temperature: None,
+            })),
+            identifier: "Without temperature range",
+            central_sync_buffer_row: CentralSyncBufferRow {
+                id: 701,
+                table_name: RECORD_TYPE.to_owned(),
+                record_id: LOCATION_TYPE_2.0.to_owned(),
+                data: LOCATION_TYPE_2.1.to_owned(),
+            },
+        },
+    ]
+}
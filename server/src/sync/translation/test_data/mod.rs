@@ -1,19 +1,29 @@
+pub mod barcode;
+pub mod currency;
 pub mod item;
+pub mod item_category;
+pub mod item_store_join;
+pub mod location_type;
 pub mod master_list;
 pub mod master_list_line;
 pub mod master_list_name_join;
 pub mod name;
+pub mod reason;
 pub mod store;
+pub mod store_preference;
 pub mod unit;
 
 use repository::{
     schema::{
-        CentralSyncBufferRow, ItemRow, MasterListLineRow, MasterListNameJoinRow, MasterListRow,
-        NameRow, StoreRow, UnitRow,
+        BarcodeRow, CentralSyncBufferRow, CurrencyRow, ItemCategoryRow, ItemRow, ItemStoreJoinRow,
+        LocationTypeRow, MasterListLineRow, MasterListNameJoinRow, MasterListRow, NameRow,
+        ReasonRow, StorePreferenceRow, StoreRow, UnitRow,
     },
-    ItemRepository, MasterListLineRowRepository, MasterListNameJoinRepository,
-    MasterListRowRepository, NameRepository, RepositoryError, StorageConnectionManager,
-    StoreRowRepository, UnitRowRepository,
+    BarcodeRowRepository, CurrencyRowRepository, ItemCategoryRowRepository, ItemRepository,
+    ItemStoreJoinRowRepository, LocationTypeRowRepository, MasterListLineRowRepository,
+    MasterListNameJoinRepository, MasterListRowRepository, NameRepository, ReasonRowRepository,
+    RepositoryError, StorageConnectionManager, StorePreferenceRowRepository, StoreRowRepository,
+    UnitRowRepository,
 };
 
 #[allow(dead_code)]
@@ -26,6 +36,13 @@ pub enum TestSyncDataRecord {
     MasterList(Option<MasterListRow>),
     MasterListLine(Option<MasterListLineRow>),
     MasterListNameJoin(Option<MasterListNameJoinRow>),
+    Currency(Option<CurrencyRow>),
+    LocationType(Option<LocationTypeRow>),
+    StorePreference(Option<StorePreferenceRow>),
+    Reason(Option<ReasonRow>),
+    ItemStoreJoin(Option<ItemStoreJoinRow>),
+    Barcode(Option<BarcodeRow>),
+    ItemCategory(Option<ItemCategoryRow>),
 }
 #[allow(dead_code)]
 #[derive(Clone)]
@@ -127,6 +144,62 @@ pub async fn check_records_against_database(
                     from_option_to_db_result(comparison_record)
                 )
             }
+            TestSyncDataRecord::Currency(comparison_record) => {
+                assert_eq!(
+                    CurrencyRowRepository::new(&connection)
+                        .find_one_by_id(&record.central_sync_buffer_row.record_id)
+                        .unwrap(),
+                    comparison_record
+                )
+            }
+            TestSyncDataRecord::LocationType(comparison_record) => {
+                assert_eq!(
+                    LocationTypeRowRepository::new(&connection)
+                        .find_one_by_id(&record.central_sync_buffer_row.record_id)
+                        .unwrap(),
+                    comparison_record
+                )
+            }
+            TestSyncDataRecord::StorePreference(comparison_record) => {
+                assert_eq!(
+                    StorePreferenceRowRepository::new(&connection)
+                        .find_one_by_id(&record.central_sync_buffer_row.record_id)
+                        .unwrap(),
+                    comparison_record
+                )
+            }
+            TestSyncDataRecord::Reason(comparison_record) => {
+                assert_eq!(
+                    ReasonRowRepository::new(&connection)
+                        .find_one_by_id(&record.central_sync_buffer_row.record_id)
+                        .unwrap(),
+                    comparison_record
+                )
+            }
+            TestSyncDataRecord::ItemStoreJoin(comparison_record) => {
+                assert_eq!(
+                    ItemStoreJoinRowRepository::new(&connection)
+                        .find_one_by_id(&record.central_sync_buffer_row.record_id)
+                        .unwrap(),
+                    comparison_record
+                )
+            }
+            TestSyncDataRecord::Barcode(comparison_record) => {
+                assert_eq!(
+                    BarcodeRowRepository::new(&connection)
+                        .find_one_by_id(&record.central_sync_buffer_row.record_id)
+                        .unwrap(),
+                    comparison_record
+                )
+            }
+            TestSyncDataRecord::ItemCategory(comparison_record) => {
+                assert_eq!(
+                    ItemCategoryRowRepository::new(&connection)
+                        .find_one_by_id(&record.central_sync_buffer_row.record_id)
+                        .unwrap(),
+                    comparison_record
+                )
+            }
         }
     }
 }
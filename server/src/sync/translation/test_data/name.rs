@@ -1,5 +1,6 @@
 use crate::sync::translation::test_data::{TestSyncDataRecord, TestSyncRecord};
-use repository::schema::{CentralSyncBufferRow, NameRow};
+use chrono::NaiveDate;
+use repository::schema::{CentralSyncBufferRow, NameRow, NameRowType};
 
 const NAME_1: (&'static str, &'static str) = (
     "1FB32324AF8049248D929CFB35F255BA",
@@ -283,6 +284,100 @@ const NAME_3: (&'static str, &'static str) = (
 }"#,
 );
 
+const NAME_4: (&'static str, &'static str) = (
+    "6F7AB3E1D4C24C3B9A1E5F2D8C7B6A54",
+    r#"{
+    "ID": "6F7AB3E1D4C24C3B9A1E5F2D8C7B6A54",
+    "name": "Doe, John",
+    "fax": "",
+    "phone": "0211234567",
+    "customer": false,
+    "bill_address1": "",
+    "bill_address2": "",
+    "supplier": false,
+    "charge code": "GEN",
+    "margin": 0,
+    "comment": "",
+    "currency_ID": "",
+    "country": "",
+    "freightfac": 0,
+    "email": "",
+    "custom1": "",
+    "code": "PAT1",
+    "last": "Doe",
+    "first": "John",
+    "title": "",
+    "female": false,
+    "date_of_birth": "1970-01-31",
+    "overpayment": 0,
+    "group_ID": "",
+    "hold": false,
+    "ship_address1": "",
+    "ship_address2": "",
+    "url": "",
+    "barcode": "",
+    "postal_address1": "",
+    "postal_address2": "",
+    "category1_ID": "",
+    "region_ID": "",
+    "type": "patient",
+    "price_category": "",
+    "flag": "",
+    "manufacturer": false,
+    "print_invoice_alphabetical": false,
+    "custom2": "",
+    "custom3": "",
+    "default_order_days": 0,
+    "connection_type": 0,
+    "PATIENT_PHOTO": "[object Picture]",
+    "NEXT_OF_KIN_ID": "",
+    "POBOX": "",
+    "ZIP": 0,
+    "middle": "",
+    "preferred": false,
+    "Blood_Group": "",
+    "marital_status": "",
+    "Benchmark": false,
+    "next_of_kin_relative": "",
+    "mother_id": "",
+    "postal_address3": "",
+    "postal_address4": "",
+    "bill_address3": "",
+    "bill_address4": "",
+    "ship_address3": "",
+    "ship_address4": "",
+    "ethnicity_ID": "",
+    "occupation_ID": "",
+    "religion_ID": "",
+    "national_health_number": "",
+    "Master_RTM_Supplier_Code": 0,
+    "ordering_method": "",
+    "donor": false,
+    "latitude": 0,
+    "longitude": 0,
+    "Master_RTM_Supplier_name": "",
+    "category2_ID": "",
+    "category3_ID": "",
+    "category4_ID": "",
+    "category5_ID": "",
+    "category6_ID": "",
+    "bill_address5": "",
+    "bill_postal_zip_code": "",
+    "postal_address5": "",
+    "postal_zip_code": "",
+    "ship_address5": "",
+    "ship_postal_zip_code": "",
+    "supplying_store_id": "D77F67339BF8400886D009178F4962E1",
+    "license_number": "",
+    "license_expiry": "0000-00-00",
+    "has_current_license": false,
+    "custom_data": null,
+    "maximum_credit": 0,
+    "nationality_ID": "",
+    "created_date": "0000-00-00"
+}"#,
+);
+
 const NAME_UPSERT_1: (&'static str, &'static str) = (
     "1FB32324AF8049248D929CFB35F255BA",
     r#"{
@@ -389,6 +484,20 @@ pub fn get_test_name_records() -> Vec<TestSyncRecord> {
                 code: "GEN".to_owned(),
                 is_supplier: true,
                 is_customer: true,
+                r#type: NameRowType::Store,
+                first_name: None,
+                last_name: None,
+                date_of_birth: None,
+                phone: None,
+                home_store_id: None,
+                is_manufacturer: false,
+                is_donor: false,
+                email: None,
+                website: None,
+                address1: None,
+                address2: None,
+                country: None,
+                comment: None,
             })),
             identifier: "General",
             central_sync_buffer_row: CentralSyncBufferRow {
@@ -405,6 +514,20 @@ pub fn get_test_name_records() -> Vec<TestSyncRecord> {
                 code: "SNA".to_owned(),
                 is_customer: true,
                 is_supplier: false,
+                r#type: NameRowType::Facility,
+                first_name: None,
+                last_name: None,
+                date_of_birth: None,
+                phone: None,
+                home_store_id: None,
+                is_manufacturer: false,
+                is_donor: false,
+                email: None,
+                website: None,
+                address1: Some("234 Evil Street".to_owned()),
+                address2: Some("Scotland".to_owned()),
+                country: None,
+                comment: None,
             })),
             identifier: "Birch Store",
             central_sync_buffer_row: CentralSyncBufferRow {
@@ -421,6 +544,20 @@ pub fn get_test_name_records() -> Vec<TestSyncRecord> {
                 code: "SNA".to_owned(),
                 is_customer: true,
                 is_supplier: false,
+                r#type: NameRowType::Facility,
+                first_name: None,
+                last_name: None,
+                date_of_birth: None,
+                phone: None,
+                home_store_id: None,
+                is_manufacturer: false,
+                is_donor: false,
+                email: None,
+                website: None,
+                address1: Some("234 Evil Street".to_owned()),
+                address2: Some("Scotland".to_owned()),
+                country: None,
+                comment: None,
             })),
             identifier: "Birch Store",
             central_sync_buffer_row: CentralSyncBufferRow {
@@ -430,6 +567,36 @@ pub fn get_test_name_records() -> Vec<TestSyncRecord> {
                 data: NAME_3.1.to_owned(),
             },
         },
+        TestSyncRecord {
+            translated_record: TestSyncDataRecord::Name(Some(NameRow {
+                id: NAME_4.0.to_owned(),
+                name: "Doe, John".to_owned(),
+                code: "PAT1".to_owned(),
+                is_customer: false,
+                is_supplier: false,
+                r#type: NameRowType::Patient,
+                first_name: Some("John".to_owned()),
+                last_name: Some("Doe".to_owned()),
+                date_of_birth: Some(NaiveDate::from_ymd(1970, 1, 31)),
+                phone: Some("0211234567".to_owned()),
+                home_store_id: Some("D77F67339BF8400886D009178F4962E1".to_owned()),
+                is_manufacturer: false,
+                is_donor: false,
+                email: None,
+                website: None,
+                address1: None,
+                address2: None,
+                country: None,
+                comment: None,
+            })),
+            identifier: "Doe, John",
+            central_sync_buffer_row: CentralSyncBufferRow {
+                id: 203,
+                table_name: RECORD_TYPE.to_owned(),
+                record_id: NAME_4.0.to_owned(),
+                data: NAME_4.1.to_owned(),
+            },
+        },
     ]
 }
 #[allow(dead_code)]
@@ -441,6 +608,20 @@ pub fn get_test_name_upsert_records() -> Vec<TestSyncRecord> {
             code: "GEN".to_owned(),
             is_customer: true,
             is_supplier: true,
+            r#type: NameRowType::Store,
+            first_name: None,
+            last_name: None,
+            date_of_birth: None,
+            phone: None,
+            home_store_id: None,
+            is_manufacturer: false,
+            is_donor: false,
+            email: None,
+            website: None,
+            address1: None,
+            address2: None,
+            country: None,
+            comment: None,
         })),
         identifier: "General2",
         central_sync_buffer_row: CentralSyncBufferRow {
@@ -0,0 +1,105 @@
+use crate::sync::translation::test_data::{TestSyncDataRecord, TestSyncRecord};
+use repository::schema::{CentralSyncBufferRow, ReasonRow, ReasonRowType};
+
+const OPTIONS_1: (&'static str, &'static str) = (
+    "1F0D5C0D0DC74C1C9C5E0A55D2C7A3D1",
+    r#"{
+    "ID": "1F0D5C0D0DC74C1C9C5E0A55D2C7A3D1",
+    "title": "Damaged",
+    "type": "negativeInventoryAdjustment",
+    "isActive": true
+}"#,
+);
+
+const OPTIONS_2: (&'static str, &'static str) = (
+    "6C62B5E1AF3A4F6E8B4F1D7A9E2C3B5D",
+    r#"{
+    "ID": "6C62B5E1AF3A4F6E8B4F1D7A9E2C3B5D",
+    "title": "Found in store",
+    "type": "positiveInventoryAdjustment",
+    "isActive": false
+}"#,
+);
+
+const OPTIONS_3: (&'static str, &'static str) = (
+    "9D3A1B2C4E5F4A6B8C7D9E0F1A2B3C4D",
+    r#"{
+    "ID": "9D3A1B2C4E5F4A6B8C7D9E0F1A2B3C4D",
+    "title": "Expired",
+    "type": "returnReason",
+    "isActive": true
+}"#,
+);
+
+const OPTIONS_4: (&'static str, &'static str) = (
+    "C8B7A6F5E4D34C2B1A0F9E8D7C6B5A49",
+    r#"{
+    "ID": "C8B7A6F5E4D34C2B1A0F9E8D7C6B5A49",
+    "title": "Contaminated vial",
+    "type": "openVialWastage",
+    "isActive": true
+}"#,
+);
+
+#[allow(dead_code)]
+const RECORD_TYPE: &'static str = "options";
+#[allow(dead_code)]
+pub fn get_test_reason_records() -> Vec<TestSyncRecord> {
+    vec![
+        TestSyncRecord {
+            translated_record: TestSyncDataRecord::Reason(Some(ReasonRow {
+                id: OPTIONS_1.0.to_owned(),
+                r#type: ReasonRowType::NegativeInventoryAdjustment,
+                reason: "Damaged".to_owned(),
+                is_active: true,
+            })),
+            identifier: "Negative inventory adjustment reason",
+            central_sync_buffer_row: CentralSyncBufferRow {
+                id: 900,
+                table_name: RECORD_TYPE.to_owned(),
+                record_id: OPTIONS_1.0.to_owned(),
+                data: OPTIONS_1.1.to_owned(),
+            },
+        },
+        TestSyncRecord {
+            translated_record: TestSyncDataRecord::Reason(Some(ReasonRow {
+                id: OPTIONS_2.0.to_owned(),
+                r#type: ReasonRowType::PositiveInventoryAdjustment,
+                reason: "Found in store".to_owned(),
+                is_active: false,
+            })),
+            identifier: "Inactive positive inventory adjustment reason",
+            central_sync_buffer_row: CentralSyncBufferRow {
+                id: 901,
+                table_name: RECORD_TYPE.to_owned(),
+                record_id: OPTIONS_2.0.to_owned(),
+                data: OPTIONS_2.1.to_owned(),
+            },
+        },
+        TestSyncRecord {
+            translated_record: TestSyncDataRecord::Reason(Some(ReasonRow {
+                id: OPTIONS_3.0.to_owned(),
+                r#type: ReasonRowType::ReturnReason,
+                reason: "Expired".to_owned(),
+                is_active: true,
+            })),
+            identifier: "Return reason",
+            central_sync_buffer_row: CentralSyncBufferRow {
+                id: 902,
+                table_name: RECORD_TYPE.to_owned(),
+                record_id: OPTIONS_3.0.to_owned(),
+                data: OPTIONS_3.1.to_owned(),
+            },
+        },
+        TestSyncRecord {
+            translated_record: TestSyncDataRecord::Reason(None),
+            identifier: "Option which is not a reason",
+            central_sync_buffer_row: CentralSyncBufferRow {
+                id: 903,
+                table_name: RECORD_TYPE.to_owned(),
+                record_id: OPTIONS_4.0.to_owned(),
+                data: OPTIONS_4.1.to_owned(),
+            },
+        },
+    ]
+}
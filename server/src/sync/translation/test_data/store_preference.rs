@@ -0,0 +1,68 @@
+use crate::sync::translation::test_data::{TestSyncDataRecord, TestSyncRecord};
+use repository::schema::{CentralSyncBufferRow, StorePreferenceRow};
+
+const STORE_PREFERENCE_1: (&'static str, &'static str) = (
+    "0D2E5D7C39F04B33A1E5B9C1C6A3D4E1",
+    r#"{
+    "ID": "0D2E5D7C39F04B33A1E5B9C1C6A3D4E1",
+    "user_ID": "",
+    "store_ID": "4E27CEB263354EB7B1B33CEA8F7884D8",
+    "item": "store_preferences",
+    "data": {
+        "pack_to_one": true,
+        "monthsOverstock": 6,
+        "monthsUnderstock": 3,
+        "monthsItemsExpire": 3,
+        "monthsLeadTime": 0,
+        "usesVaccineModule": false
+    }
+}"#,
+);
+
+const USER_PREFERENCE_1: (&'static str, &'static str) = (
+    "5B7E2F0C4D3A4E8E9B1C2D3E4F5A6B7C",
+    r#"{
+    "ID": "5B7E2F0C4D3A4E8E9B1C2D3E4F5A6B7C",
+    "user_ID": "0763E2E3053D4C478E1E6B6B03FEC207",
+    "store_ID": "",
+    "item": "user_preferences",
+    "data": {
+        "show_item_codes": true
+    }
+}"#,
+);
+
+#[allow(dead_code)]
+const RECORD_TYPE: &'static str = "pref";
+#[allow(dead_code)]
+pub fn get_test_store_preference_records() -> Vec<TestSyncRecord> {
+    vec![
+        TestSyncRecord {
+            translated_record: TestSyncDataRecord::StorePreference(Some(StorePreferenceRow {
+                id: STORE_PREFERENCE_1.0.to_owned(),
+                store_id: "4E27CEB263354EB7B1B33CEA8F7884D8".to_owned(),
+                pack_to_one: true,
+                months_overstock: 6.0,
+                months_understock: 3.0,
+                months_items_expire: 3.0,
+            })),
+            identifier: "Store preferences",
+            central_sync_buffer_row: CentralSyncBufferRow {
+                id: 800,
+                table_name: RECORD_TYPE.to_owned(),
+                record_id: STORE_PREFERENCE_1.0.to_owned(),
+                data: STORE_PREFERENCE_1.1.to_owned(),
+            },
+        },
+        TestSyncRecord {
+            translated_record: TestSyncDataRecord::StorePreference(None),
+            identifier: "User preferences",
+            central_sync_buffer_row: CentralSyncBufferRow {
+                id: 801,
+                table_name: RECORD_TYPE.to_owned(),
+                record_id: USER_PREFERENCE_1.0.to_owned(),
+                data: USER_PREFERENCE_1.1.to_owned(),
+            },
+        },
+    ]
+}
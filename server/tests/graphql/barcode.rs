@@ -0,0 +1,99 @@
+mod graphql {
+    use crate::graphql::{assert_graphql_query, assert_standard_graphql_error};
+    use repository::mock::MockDataInserts;
+    use serde_json::json;
+    use server::test_utils::setup_all;
+
+    #[actix_rt::test]
+    async fn test_graphql_barcode() {
+        let (_, _, _, settings) = setup_all("test_graphql_barcode", MockDataInserts::all()).await;
+
+        let mutation = r#"
+        mutation ($input: InsertBarcodeInput!) {
+            insertBarcode(input: $input) {
+                id
+                value
+                itemId
+                packSize
+            }
+        }
+        "#;
+        let variables = Some(json!({
+          "input": {
+            "id": "barcode_a",
+            "value": "5012345678900",
+            "itemId": "item_a",
+            "packSize": 12
+          }
+        }));
+        let expected = json!({
+            "insertBarcode": {
+                "id": "barcode_a",
+                "value": "5012345678900",
+                "itemId": "item_a",
+                "packSize": 12
+            }
+        });
+        assert_graphql_query!(&settings, mutation, &variables, &expected, None);
+
+        // GS1 element string with the GTIN-14 of the barcode
+        let query = r#"
+        query ($value: String!) {
+            scanBarcode(value: $value) {
+                gtin
+                itemId
+                item {
+                    code
+                }
+                packSize
+                batch
+                expiryDate
+                serialNumber
+            }
+        }
+        "#;
+        let variables = Some(json!({
+            "value": "]d2010501234567890017240531\u{1d}10B-42\u{1d}21SN1"
+        }));
+        let expected = json!({
+            "scanBarcode": {
+                "gtin": "05012345678900",
+                "itemId": "item_a",
+                "item": {
+                    "code": "item_a_code"
+                },
+                "packSize": 12,
+                "batch": "B-42",
+                "expiryDate": "2024-05-31",
+                "serialNumber": "SN1"
+            }
+        });
+        assert_graphql_query!(&settings, query, &variables, &expected, None);
+
+        // invalid GS1 element string
+        let variables = Some(json!({ "value": "(01)05012345678901" }));
+        assert_standard_graphql_error!(&settings, query, &variables, "Bad user input", None, None);
+
+        let query = r#"
+        query ($itemId: String!) {
+            barcodes(itemId: $itemId) {
+                id
+            }
+        }
+        "#;
+        let expected = json!({
+            "barcodes": [{ "id": "barcode_a" }]
+        });
+        let variables = Some(json!({ "itemId": "item_a" }));
+        assert_graphql_query!(&settings, query, &variables, &expected, None);
+
+        let mutation = r#"
+        mutation ($id: String!) {
+            deleteBarcode(id: $id)
+        }
+        "#;
+        let expected = json!({ "deleteBarcode": "barcode_a" });
+        let variables = Some(json!({ "id": "barcode_a" }));
+        assert_graphql_query!(&settings, mutation, &variables, &expected, None);
+    }
+}
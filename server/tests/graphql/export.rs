@@ -0,0 +1,238 @@
+mod graphql {
+    use actix_web::{
+        http::StatusCode,
+        test::{call_service, init_service, read_body, TestRequest},
+        web::Data,
+    };
+    use graphql::{
+        config as graphql_config,
+        loader::{get_loaders, LoaderRegistry},
+    };
+    use repository::{
+        mock::MockDataInserts, schema::LocationRow, LocationRowRepository, StorageConnection,
+    };
+    use serde_json::{json, Value};
+    use server::test_utils::setup_all;
+    use service::{
+        auth_data::AuthData, service_provider::ServiceProvider, token_bucket::TokenBucket,
+    };
+    use std::sync::RwLock;
+
+    async fn export(db_name: &str, list: &str, request: Value) -> (StatusCode, String, Vec<u8>) {
+        export_with_data(db_name, list, request, |_| {}).await
+    }
+
+    async fn export_with_data(
+        db_name: &str,
+        list: &str,
+        request: Value,
+        insert_data: fn(&StorageConnection),
+    ) -> (StatusCode, String, Vec<u8>) {
+        let (_, connection, connection_manager, settings) =
+            setup_all(db_name, MockDataInserts::all()).await;
+        insert_data(&connection);
+        let loaders = get_loaders(&connection_manager).await;
+
+        let auth_data = Data::new(AuthData {
+            auth_token_secret: settings.auth.token_secret.to_owned(),
+            token_bucket: RwLock::new(TokenBucket::new()),
+            debug_no_ssl: true,
+            debug_no_access_control: true,
+        });
+        let mut app = init_service(actix_web::App::new().configure(graphql_config(
+            Data::new(connection_manager.clone()),
+            Data::new(LoaderRegistry { loaders }),
+            Data::new(ServiceProvider::new(connection_manager)),
+            auth_data,
+        )))
+        .await;
+
+        let response = call_service(
+            &mut app,
+            TestRequest::post()
+                .uri(&format!("/export/{}", list))
+                .set_json(&request)
+                .to_request(),
+        )
+        .await;
+        let status = response.status();
+        let content_type = response
+            .headers()
+            .get("content-type")
+            .map(|value| value.to_str().unwrap().to_string())
+            .unwrap_or_default();
+        (status, content_type, read_body(response).await.to_vec())
+    }
+
+    #[actix_rt::test]
+    async fn test_export_csv() {
+        let (status, content_type, body) = export(
+            "test_export_csv",
+            "locations",
+            json!({
+              "storeId": "store_a",
+              "filter": { "code": { "equalAny": ["code_location_1", "code_location_on_hold"] } },
+              "sort": { "key": "code", "desc": true },
+              "columns": ["code", "name", "onHold"]
+            }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(content_type, "text/csv; charset=utf-8");
+        assert_eq!(
+            String::from_utf8(body).unwrap(),
+            "code,name,onHold\n\
+            code_location_on_hold,name_location_on_hold,true\n\
+            code_location_1,name_location_1,false\n"
+        );
+
+        // localized date format
+        let (status, _, body) = export(
+            "test_export_csv_date_format",
+            "stockLines",
+            json!({
+              "storeId": "store_a",
+              "filter": { "expiryDate": { "equalTo": "2020-08-11" } },
+              "columns": ["id", "expiryDate"],
+              "dateFormat": "%d/%m/%Y"
+            }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(
+            String::from_utf8(body).unwrap(),
+            "id,expiryDate\nstock_line_si_d_siline_b,11/08/2020\n"
+        );
+    }
+
+    #[actix_rt::test]
+    async fn test_export_csv_multiple_pages() {
+        let (status, _, body) = export_with_data(
+            "test_export_csv_multiple_pages",
+            "locations",
+            json!({
+              "storeId": "store_a",
+              "sort": { "key": "code" },
+              "columns": ["code"]
+            }),
+            |connection| {
+                let repository = LocationRowRepository::new(connection);
+                for i in 0..1200 {
+                    repository
+                        .upsert_one(&LocationRow {
+                            id: format!("bulk_location_{}", i),
+                            code: format!("bulk_{:04}", i),
+                            name: format!("bulk location {}", i),
+                            on_hold: false,
+                            store_id: "store_a".to_string(),
+                            parent_id: None,
+                            location_type_id: None,
+                            volume: None,
+                        })
+                        .unwrap();
+                }
+            },
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let body = String::from_utf8(body).unwrap();
+        let mut lines = body.lines();
+        assert_eq!(lines.next(), Some("code"));
+        // bulk codes sort before the mock location codes
+        let codes: Vec<&str> = lines.collect();
+        let expected: Vec<String> = (0..1200).map(|i| format!("bulk_{:04}", i)).collect();
+        assert_eq!(&codes[0..1200], expected.as_slice());
+        assert!(!codes[1200..].iter().any(|code| code.starts_with("bulk_")));
+    }
+
+    #[actix_rt::test]
+    async fn test_export_csv_multiple_pages_sort_ties() {
+        let (status, _, body) = export_with_data(
+            "test_export_csv_multiple_pages_sort_ties",
+            "locations",
+            json!({
+              "storeId": "store_a",
+              "sort": { "key": "name" },
+              "columns": ["id"]
+            }),
+            |connection| {
+                let repository = LocationRowRepository::new(connection);
+                for i in 0..1200 {
+                    repository
+                        .upsert_one(&LocationRow {
+                            id: format!("bulk_location_{:04}", 1199 - i),
+                            code: format!("bulk_{}", i),
+                            name: "bulk location".to_string(),
+                            on_hold: false,
+                            store_id: "store_a".to_string(),
+                            parent_id: None,
+                            location_type_id: None,
+                            volume: None,
+                        })
+                        .unwrap();
+                }
+            },
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let body = String::from_utf8(body).unwrap();
+        // locations with the same name are ordered by id, each is exported once
+        let ids: Vec<&str> = body
+            .lines()
+            .skip(1)
+            .filter(|id| id.starts_with("bulk_"))
+            .collect();
+        let expected: Vec<String> = (0..1200)
+            .map(|i| format!("bulk_location_{:04}", i))
+            .collect();
+        assert_eq!(ids, expected);
+    }
+
+    #[actix_rt::test]
+    async fn test_export_xlsx() {
+        let (status, content_type, body) = export(
+            "test_export_xlsx",
+            "names",
+            json!({ "storeId": "store_a", "format": "xlsx" }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(
+            content_type,
+            "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet"
+        );
+        // xlsx is a zip archive
+        assert_eq!(&body[0..2], b"PK");
+    }
+
+    #[actix_rt::test]
+    async fn test_export_errors() {
+        let (status, _, _) = export(
+            "test_export_unknown_list",
+            "stores",
+            json!({ "storeId": "store_a" }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+
+        let (status, _, body) = export(
+            "test_export_unknown_column",
+            "items",
+            json!({ "storeId": "store_a", "columns": ["code", "price"] }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(
+            String::from_utf8(body).unwrap(),
+            "Bad user input: Unknown column: price"
+        );
+
+        let (status, _, _) = export(
+            "test_export_invalid_filter",
+            "invoices",
+            json!({ "storeId": "store_a", "filter": { "status": { "equalTo": "UNKNOWN" } } }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+}
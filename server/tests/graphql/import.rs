@@ -0,0 +1,97 @@
+mod graphql {
+    use actix_web::{
+        http::StatusCode,
+        test::{call_service, init_service, read_body, TestRequest},
+        web::Data,
+    };
+    use graphql::{
+        config as graphql_config,
+        loader::{get_loaders, LoaderRegistry},
+    };
+    use repository::mock::MockDataInserts;
+    use serde_json::{json, Value};
+    use server::test_utils::setup_all;
+    use service::{
+        auth_data::AuthData, service_provider::ServiceProvider, token_bucket::TokenBucket,
+    };
+    use std::sync::RwLock;
+
+    async fn import_opening_stock(db_name: &str, csv: &str) -> (StatusCode, Value) {
+        let (_, _, connection_manager, settings) = setup_all(db_name, MockDataInserts::all()).await;
+        let loaders = get_loaders(&connection_manager).await;
+
+        let auth_data = Data::new(AuthData {
+            auth_token_secret: settings.auth.token_secret.to_owned(),
+            token_bucket: RwLock::new(TokenBucket::new()),
+            debug_no_ssl: true,
+            debug_no_access_control: true,
+        });
+        let mut app = init_service(actix_web::App::new().configure(graphql_config(
+            Data::new(connection_manager.clone()),
+            Data::new(LoaderRegistry { loaders }),
+            Data::new(ServiceProvider::new(connection_manager)),
+            auth_data,
+        )))
+        .await;
+
+        let response = call_service(
+            &mut app,
+            TestRequest::post()
+                .uri("/import/openingStock?storeId=store_a")
+                .header("content-type", "text/csv")
+                .set_payload(csv.to_string())
+                .to_request(),
+        )
+        .await;
+        let status = response.status();
+        let body = read_body(response).await;
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    #[actix_rt::test]
+    async fn test_import_opening_stock() {
+        let (status, body) = import_opening_stock(
+            "test_import_opening_stock",
+            "\u{feff}item_code,batch,expiry_date,pack_size,number_of_packs,location_code\n\
+            item_a_code,B1,2025-01-31,1,10,code_location_1\n\
+            item_b_code,B2,,10,2,\n",
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["numberOfLines"], json!(2));
+        assert!(body["invoiceId"].is_string());
+    }
+
+    #[actix_rt::test]
+    async fn test_import_opening_stock_errors() {
+        let (status, body) = import_opening_stock(
+            "test_import_opening_stock_row_errors",
+            "item_code,pack_size,number_of_packs,location_code\n\
+            item_a_code,1,10,code_location_1\n\
+            unknown,0,10,\n",
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(
+            body["rowErrors"],
+            json!([{
+                "lineNumber": 3,
+                "errors": [
+                    { "code": "ItemDoesNotExist", "value": "unknown" },
+                    { "code": "PackSizeBelowOne", "value": null }
+                ]
+            }])
+        );
+
+        let (status, body) = import_opening_stock(
+            "test_import_opening_stock_missing_column",
+            "item_code,number_of_packs\nitem_a_code,10\n",
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(
+            body,
+            json!({ "message": "Bad user input: Missing column: pack_size", "rowErrors": [] })
+        );
+    }
+}
@@ -371,6 +371,8 @@ mod graphql {
                 number_of_packs,
                 location_id,
                 note,
+                return_reason_id: _,
+                directions: _,
             } = self;
 
             let stock_line = &other.0;
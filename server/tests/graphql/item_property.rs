@@ -0,0 +1,150 @@
+mod graphql {
+    use crate::graphql::assert_graphql_query;
+    use repository::{mock::MockDataInserts, schema::ItemCategoryRow, ItemCategoryRowRepository};
+    use serde_json::json;
+    use server::test_utils::setup_all;
+
+    #[actix_rt::test]
+    async fn test_graphql_item_properties() {
+        let (_, connection, _, settings) =
+            setup_all("test_graphql_item_properties", MockDataInserts::all()).await;
+
+        let repo = ItemCategoryRowRepository::new(&connection);
+        repo.upsert_one(&ItemCategoryRow {
+            id: "category_parent".to_string(),
+            name: "Anti-infective medicines".to_string(),
+            parent_id: None,
+        })
+        .unwrap();
+        repo.upsert_one(&ItemCategoryRow {
+            id: "category_child".to_string(),
+            name: "Antibacterials".to_string(),
+            parent_id: Some("category_parent".to_string()),
+        })
+        .unwrap();
+
+        let query = r#"
+        query {
+            itemCategories {
+                id
+                parentId
+            }
+        }
+        "#;
+        let expected = json!({
+            "itemCategories": [
+                { "id": "category_parent", "parentId": null },
+                { "id": "category_child", "parentId": "category_parent" }
+            ]
+        });
+        assert_graphql_query!(&settings, query, &None, &expected, None);
+
+        let mutation = r#"
+        mutation ($input: UpdateItemPropertiesInput!) {
+            updateItemProperties(input: $input) {
+                itemId
+                categoryId
+                strength
+                venCategory
+                isControlled
+            }
+        }
+        "#;
+        let variables = Some(json!({
+          "input": {
+            "itemId": "item_query_test1",
+            "categoryId": "category_child",
+            "strength": "250mg",
+            "venCategory": "VITAL",
+            "isControlled": true
+          }
+        }));
+        let expected = json!({
+            "updateItemProperties": {
+                "itemId": "item_query_test1",
+                "categoryId": "category_child",
+                "strength": "250mg",
+                "venCategory": "VITAL",
+                "isControlled": true
+            }
+        });
+        assert_graphql_query!(&settings, mutation, &variables, &expected, None);
+
+        // filter by parent category, VEN class and controlled flag
+        let query = r#"
+        query ($filter: ItemFilterInput) {
+            items(filter: $filter) {
+                ... on ItemConnector {
+                    nodes {
+                        id
+                        categoryId
+                        venCategory
+                        isControlled
+                        abcClass
+                    }
+                }
+            }
+        }
+        "#;
+        let variables = Some(json!({
+          "filter": {
+            "categoryId": { "equalTo": "category_parent" },
+            "venCategory": { "equalAny": ["VITAL", "ESSENTIAL"] },
+            "isControlled": { "equalTo": true }
+          }
+        }));
+        let expected = json!({
+            "items": {
+                "nodes": [{
+                    "id": "item_query_test1",
+                    "categoryId": "category_child",
+                    "venCategory": "VITAL",
+                    "isControlled": true,
+                    "abcClass": null
+                }]
+            }
+        });
+        assert_graphql_query!(&settings, query, &variables, &expected, None);
+
+        // without consumption in the period every item is class C
+        let mutation = r#"
+        mutation ($input: CalculateAbcClassificationInput!) {
+            calculateAbcClassification(input: $input) {
+                class
+                consumptionValue
+            }
+        }
+        "#;
+        let variables = Some(json!({
+          "input": {
+            "fromDatetime": "2021-01-01T00:00:00Z",
+            "toDatetime": "2021-01-31T00:00:00Z"
+          }
+        }));
+        let response = crate::graphql::run_gql_query(&settings, mutation, &variables, None).await;
+        let classes = response["data"]["calculateAbcClassification"]
+            .as_array()
+            .unwrap();
+        assert!(!classes.is_empty());
+        assert!(classes.iter().all(|class| class["class"] == "C"));
+
+        let variables = Some(json!({
+          "filter": {
+            "categoryId": { "equalTo": "category_parent" },
+            "abcClass": { "equalTo": "C" }
+          }
+        }));
+        let expected = json!({
+            "items": {
+                "nodes": [{
+                    "id": "item_query_test1",
+                    "categoryId": "category_child",
+                    "venCategory": "VITAL",
+                    "isControlled": true,
+                    "abcClass": "C"
+                }]
+            }
+        });
+        assert_graphql_query!(&settings, query, &variables, &expected, None);
+    }
+}
@@ -66,4 +66,44 @@ mod graphql {
         );
         assert_graphql_query!(&settings, query, &Some(variables), &expected, None);
     }
+
+    #[actix_rt::test]
+    async fn test_graphql_items_query_store_id_without_stores() {
+        // with an explicit storeId the current store is not looked up, so no store rows are needed
+        let (_, _, _, settings) = setup_all(
+            "test_items_query_store_id_without_stores",
+            MockDataInserts::none().units().items(),
+        )
+        .await;
+
+        let query = r#"query items($storeId: String, $itemFilter: ItemFilterInput!) {
+            items(storeId: $storeId, filter: $itemFilter) {
+                ... on ItemConnector {
+                  nodes {
+                      id
+                  }
+               }
+            }
+        }"#;
+
+        let variables = json!({
+            "storeId": "store_a",
+            "itemFilter": {
+                "name": {
+                    "like": "item_query_test"
+                }
+            }
+        });
+
+        let expected = json!({
+              "items": {
+                  "nodes": [
+                      { "id": "item_query_test1" },
+                      { "id": "item_query_test2" }
+                  ]
+              }
+          }
+        );
+        assert_graphql_query!(&settings, query, &Some(variables), &expected, None);
+    }
 }
@@ -169,7 +169,10 @@ mod graphql {
                     batch: None,
                     expiry_date: None,
                     note: None,
+                    return_reason_id: None,
+                    directions: None,
                 }],
+                sub_locations: Vec::new(),
             }))
         }));
 
@@ -0,0 +1,124 @@
+mod graphql {
+    use crate::graphql::{assert_graphql_query, run_gql_query};
+    use repository::mock::MockDataInserts;
+    use serde_json::json;
+    use server::test_utils::setup_all;
+
+    #[actix_rt::test]
+    async fn test_graphql_location_hierarchy() {
+        let (_, _, _, settings) =
+            setup_all("test_graphql_location_hierarchy", MockDataInserts::all()).await;
+
+        let mutation = r#"
+        mutation ($input: InsertLocationInput!) {
+            insertLocation(input: $input) {
+              ... on LocationNode {
+                id
+                parentId
+                volume
+              }
+            }
+          }
+        "#;
+        let variables = Some(json!({
+          "input": { "id": "room", "code": "room", "volume": 30.0 }
+        }));
+        let expected = json!({
+            "insertLocation": { "id": "room", "parentId": null, "volume": 30.0 }
+        });
+        assert_graphql_query!(&settings, mutation, &variables, &expected, None);
+
+        let variables = Some(json!({
+          "input": { "id": "shelf", "code": "shelf", "parentId": "room" }
+        }));
+        let expected = json!({
+            "insertLocation": { "id": "shelf", "parentId": "room", "volume": null }
+        });
+        assert_graphql_query!(&settings, mutation, &variables, &expected, None);
+
+        let variables = Some(json!({
+          "input": { "id": "bin", "code": "bin", "parentId": "shelf" }
+        }));
+        let expected = json!({
+            "insertLocation": { "id": "bin", "parentId": "shelf", "volume": null }
+        });
+        assert_graphql_query!(&settings, mutation, &variables, &expected, None);
+
+        // Path and sub locations
+        let query = r#"
+        query ($filter: LocationFilterInput) {
+            locations(filter: $filter) {
+              ... on LocationConnector {
+                nodes {
+                  id
+                  path {
+                    id
+                  }
+                  subLocations {
+                    id
+                  }
+                }
+              }
+            }
+          }
+        "#;
+        let variables = Some(json!({
+          "filter": { "parentId": { "equalTo": "room" } }
+        }));
+        let expected = json!({
+            "locations": {
+              "nodes": [{
+                "id": "shelf",
+                "path": [{ "id": "room" }, { "id": "shelf" }],
+                "subLocations": [{ "id": "bin" }]
+              }]
+            }
+        });
+        assert_graphql_query!(&settings, query, &variables, &expected, None);
+
+        // Moving a location into its own sub location
+        let mutation = r#"
+        mutation ($input: UpdateLocationInput!) {
+            updateLocation(input: $input) {
+              ... on UpdateLocationError {
+                error {
+                  __typename
+                }
+              }
+            }
+          }
+        "#;
+        let variables = Some(json!({
+          "input": { "id": "room", "parentId": "bin" }
+        }));
+        let expected = json!({
+            "updateLocation": {
+              "error": { "__typename": "ParentLocationIsSubLocation" }
+            }
+        });
+        assert_graphql_query!(&settings, mutation, &variables, &expected, None);
+
+        // Unknown location type
+        let mutation = r#"
+        mutation ($input: InsertLocationInput!) {
+            insertLocation(input: $input) {
+              ... on InsertLocationError {
+                error {
+                  ... on ForeignKeyError {
+                    key
+                  }
+                }
+              }
+            }
+          }
+        "#;
+        let variables = Some(json!({
+          "input": { "id": "fridge", "code": "fridge", "locationTypeId": "n/a" }
+        }));
+        let response = run_gql_query(&settings, mutation, &variables, None).await;
+        assert_eq!(
+            response["data"]["insertLocation"]["error"]["key"],
+            json!("locationTypeId")
+        );
+    }
+}
@@ -198,6 +198,9 @@ mod graphql {
                 name: "name".to_owned(),
                 code: "code".to_owned(),
                 on_hold: true,
+                parent_id: None,
+                location_type_id: None,
+                volume: None,
             })
         }));
 
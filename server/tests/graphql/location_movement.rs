@@ -0,0 +1,89 @@
+mod graphql {
+    use crate::graphql::{assert_graphql_query, run_gql_query};
+    use repository::mock::{mock_stock_line_a, MockDataInserts};
+    use serde_json::json;
+    use server::test_utils::setup_all;
+
+    #[actix_rt::test]
+    async fn test_graphql_move_stock() {
+        let (_, _, _, settings) =
+            setup_all("test_graphql_move_stock", MockDataInserts::all()).await;
+
+        let mutation = r#"
+        mutation ($input: MoveStockInput!) {
+            moveStock(input: $input) {
+                movement {
+                    id
+                    splitFromStockLineId
+                    toLocationId
+                    numberOfPacks
+                }
+                stockLine {
+                    locationId
+                    totalNumberOfPacks
+                }
+            }
+        }
+        "#;
+
+        // Location on hold
+        let variables = Some(json!({
+          "input": {
+            "id": "move",
+            "stockLineId": mock_stock_line_a().id,
+            "numberOfPacks": 5,
+            "locationId": "location_on_hold"
+          }
+        }));
+        let response = run_gql_query(&settings, mutation, &variables, None).await;
+        assert!(response["errors"][0]["extensions"]["details"]
+            .as_str()
+            .unwrap()
+            .contains("LocationIsOnHold"));
+
+        let variables = Some(json!({
+          "input": {
+            "id": "move",
+            "stockLineId": mock_stock_line_a().id,
+            "numberOfPacks": 5,
+            "locationId": "location_1"
+          }
+        }));
+        let expected = json!({
+            "moveStock": {
+                "movement": {
+                    "id": "move",
+                    "splitFromStockLineId": mock_stock_line_a().id,
+                    "toLocationId": "location_1",
+                    "numberOfPacks": 5
+                },
+                "stockLine": {
+                    "locationId": "location_1",
+                    "totalNumberOfPacks": 5
+                }
+            }
+        });
+        assert_graphql_query!(&settings, mutation, &variables, &expected, None);
+
+        let query = r#"
+        query ($locationId: String) {
+            locationMovements(locationId: $locationId) {
+                id
+                fromLocationId
+            }
+        }
+        "#;
+        let variables = Some(json!({ "locationId": "location_1" }));
+        let expected = json!({
+            "locationMovements": [{ "id": "move", "fromLocationId": null }]
+        });
+        assert_graphql_query!(&settings, query, &variables, &expected, None);
+
+        // Neither stock line nor location
+        let response = run_gql_query(&settings, query, &None, None).await;
+        assert!(response["errors"][0]["extensions"]["details"]
+            .as_str()
+            .unwrap()
+            .contains("stockLineId or locationId"));
+    }
+}
@@ -104,6 +104,65 @@ mod graphql {
             Some(service_provider(test_service, &connection_manager))
         );
 
+        // Parent location is a sub location
+        let test_service = TestService(Box::new(|_| {
+            Err(UpdateLocationError::ParentLocationIsSubLocation)
+        }));
+
+        let expected = json!({
+            "updateLocation": {
+              "error": {
+                "__typename": "ParentLocationIsSubLocation",
+              }
+            }
+          }
+        );
+
+        assert_graphql_query!(
+            &settings,
+            mutation,
+            &variables,
+            &expected,
+            Some(service_provider(test_service, &connection_manager))
+        );
+
+        // Location type does not exist
+        let foreign_key_mutation = r#"
+              mutation ($input: UpdateLocationInput!) {
+                  updateLocation(input: $input) {
+                    ... on UpdateLocationError {
+                      error {
+                        ... on ForeignKeyError {
+                            __typename
+                            key
+                        }
+                      }
+                    }
+                  }
+                }
+              "#;
+        let test_service = TestService(Box::new(|_| {
+            Err(UpdateLocationError::LocationTypeDoesNotExist)
+        }));
+
+        let expected = json!({
+            "updateLocation": {
+              "error": {
+                "__typename": "ForeignKeyError",
+                "key": "locationTypeId"
+              }
+            }
+          }
+        );
+
+        assert_graphql_query!(
+            &settings,
+            foreign_key_mutation,
+            &variables,
+            &expected,
+            Some(service_provider(test_service, &connection_manager))
+        );
+
         // Unique code violation
         let mutation = r#"
               mutation ($input: UpdateLocationInput!) {
@@ -214,6 +273,9 @@ mod graphql {
                 name: "name".to_owned(),
                 code: "code".to_owned(),
                 on_hold: true,
+                parent_id: None,
+                location_type_id: None,
+                volume: None,
             })
         }));
 
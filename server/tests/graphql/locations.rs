@@ -80,6 +80,9 @@ mod graphql {
                     name: "test_name".to_owned(),
                     code: "test_code".to_owned(),
                     on_hold: true,
+                    parent_id: None,
+                    location_type_id: None,
+                    volume: None,
                 }],
                 count: 1,
             })
@@ -13,7 +13,10 @@ use actix_web::{test::read_body, web::Data};
 use serde::{de::DeserializeOwned, Serialize};
 use serde_json::Value;
 
+mod barcode;
 pub mod common;
+mod export;
+mod import;
 mod inbound_shipment_delete;
 mod inbound_shipment_insert;
 mod inbound_shipment_line_delete;
@@ -22,12 +25,16 @@ mod inbound_shipment_line_update;
 mod inbound_shipment_update;
 mod invoice_query;
 mod invoices;
+mod item_property;
 mod items;
 mod location_delete;
+mod location_hierarchy;
 mod location_insert;
+mod location_movement;
 mod location_update;
 mod locations;
 mod master_lists;
+mod name_insert;
 mod names;
 mod outbound_shipment_delete;
 mod outbound_shipment_insert;
@@ -35,9 +42,16 @@ mod outbound_shipment_line_delete;
 mod outbound_shipment_line_insert;
 mod outbound_shipment_line_update;
 mod outbound_shipment_update;
+mod pack_variant;
 mod pagination;
+mod prescription;
+mod repack;
+mod report;
 mod requisition;
+mod rest;
+mod returns;
 mod stock_take_update;
+mod temperature_log;
 mod unallocated_line;
 
 pub async fn get_gql_result<IN, OUT>(settings: &Settings, query: IN) -> OUT
@@ -0,0 +1,109 @@
+mod graphql {
+    use crate::graphql::assert_graphql_query;
+    use repository::{mock::MockDataInserts, SyncOutRowRepository};
+    use serde_json::json;
+    use server::test_utils::setup_all;
+
+    #[actix_rt::test]
+    async fn test_graphql_name_insert_and_update() {
+        let (_, connection, _, settings) = setup_all(
+            "test_graphql_name_insert_and_update",
+            MockDataInserts::all(),
+        )
+        .await;
+
+        let mutation = r#"
+        mutation ($input: InsertNameInput!) {
+            insertName(input: $input) {
+                id
+                type
+                isCustomer
+                isSupplier
+                address1
+                homeStoreId
+                tags
+            }
+        }
+        "#;
+        let variables = Some(json!({
+          "input": {
+            "id": "outreach_clinic",
+            "code": "OUT1",
+            "name": "Outreach clinic",
+            "type": "FACILITY",
+            "isCustomer": true,
+            "isSupplier": false,
+            "address1": "1 Main Road",
+            "tags": ["Outreach"]
+          }
+        }));
+        let expected = json!({
+            "insertName": {
+                "id": "outreach_clinic",
+                "type": "FACILITY",
+                "isCustomer": true,
+                "isSupplier": false,
+                "address1": "1 Main Road",
+                "homeStoreId": "store_a",
+                "tags": ["Outreach"]
+            }
+        });
+        assert_graphql_query!(&settings, mutation, &variables, &expected, None);
+
+        let mutation = r#"
+        mutation ($input: UpdateNameInput!) {
+            updateName(input: $input) {
+                id
+                phone
+                tags
+            }
+        }
+        "#;
+        let variables = Some(json!({
+          "input": {
+            "id": "outreach_clinic",
+            "phone": "021555555",
+            "tags": ["Outreach", "Remote"]
+          }
+        }));
+        let expected = json!({
+            "updateName": {
+                "id": "outreach_clinic",
+                "phone": "021555555",
+                "tags": ["Outreach", "Remote"]
+            }
+        });
+        assert_graphql_query!(&settings, mutation, &variables, &expected, None);
+
+        // filter by tag and type
+        let query = r#"
+        query ($filter: NameFilterInput) {
+            names(filter: $filter) {
+                ... on NameConnector {
+                    nodes {
+                        id
+                    }
+                }
+            }
+            nameTags {
+                name
+            }
+        }
+        "#;
+        let variables = Some(json!({
+          "filter": {
+            "tag": { "equalTo": "Remote" },
+            "type": { "equalAny": ["FACILITY", "OTHER"] }
+          }
+        }));
+        let expected = json!({
+            "names": { "nodes": [{ "id": "outreach_clinic" }] },
+            "nameTags": [{ "name": "Outreach" }, { "name": "Remote" }]
+        });
+        assert_graphql_query!(&settings, query, &variables, &expected, None);
+
+        // the name, tags and tag joins are queued for upload
+        let queued = SyncOutRowRepository::new(&connection).find_all().unwrap();
+        assert_eq!(queued.len(), 6);
+    }
+}
@@ -0,0 +1,128 @@
+mod graphql {
+    use crate::graphql::assert_graphql_query;
+    use repository::mock::MockDataInserts;
+    use serde_json::json;
+    use server::test_utils::setup_all;
+
+    #[actix_rt::test]
+    async fn test_graphql_pack_variant() {
+        let (_, _, _, settings) =
+            setup_all("test_graphql_pack_variant", MockDataInserts::all()).await;
+
+        let mutation = r#"
+        mutation ($input: InsertPackVariantInput!) {
+            insertPackVariant(input: $input) {
+                id
+                itemId
+                name
+                packSize
+                volumePerPack
+            }
+        }
+        "#;
+        let variables = Some(json!({
+          "input": {
+            "id": "pack_variant_a",
+            "itemId": "item_a",
+            "name": "Box of 100",
+            "packSize": 100,
+            "volumePerPack": 0.5
+          }
+        }));
+        let expected = json!({
+            "insertPackVariant": {
+                "id": "pack_variant_a",
+                "itemId": "item_a",
+                "name": "Box of 100",
+                "packSize": 100,
+                "volumePerPack": 0.5
+            }
+        });
+        assert_graphql_query!(&settings, mutation, &variables, &expected, None);
+
+        let query = r#"
+        query ($itemId: String!) {
+            packVariants(itemId: $itemId) {
+                id
+                name
+                packSize
+            }
+        }
+        "#;
+        let variables = Some(json!({ "itemId": "item_a" }));
+        let expected = json!({
+            "packVariants": [{
+                "id": "pack_variant_a",
+                "name": "Box of 100",
+                "packSize": 100
+            }]
+        });
+        assert_graphql_query!(&settings, query, &variables, &expected, None);
+
+        // two boxes of 100 received as packs of 10
+        let mutation = r#"
+        mutation ($input: InsertInboundShipmentLineInput!) {
+            insertInboundShipmentLine(input: $input) {
+                ... on InvoiceLineNode {
+                    numberOfPacks
+                    numberOfUnits
+                    packVariant {
+                        name
+                    }
+                }
+                ... on InsertInboundShipmentLineError {
+                    error {
+                        __typename
+                    }
+                }
+            }
+        }
+        "#;
+        let variables = Some(json!({
+          "input": {
+            "id": "pack_variant_line_a",
+            "invoiceId": "inbound_shipment_c",
+            "itemId": "item_a",
+            "packSize": 10,
+            "costPricePerPack": 1.0,
+            "sellPricePerPack": 2.0,
+            "numberOfPacks": 2,
+            "packVariantId": "pack_variant_a",
+            "totalBeforeTax": 1.0,
+            "totalAfterTax": 1.0
+          }
+        }));
+        let expected = json!({
+            "insertInboundShipmentLine": {
+                "numberOfPacks": 20,
+                "numberOfUnits": 200,
+                "packVariant": null
+            }
+        });
+        assert_graphql_query!(&settings, mutation, &variables, &expected, None);
+
+        // packs of 100 don't fit into packs of 30
+        let variables = Some(json!({
+          "input": {
+            "id": "pack_variant_line_b",
+            "invoiceId": "inbound_shipment_c",
+            "itemId": "item_a",
+            "packSize": 30,
+            "costPricePerPack": 1.0,
+            "sellPricePerPack": 2.0,
+            "numberOfPacks": 1,
+            "packVariantId": "pack_variant_a",
+            "totalBeforeTax": 1.0,
+            "totalAfterTax": 1.0
+          }
+        }));
+        let expected = json!({
+            "insertInboundShipmentLine": {
+                "error": {
+                    "__typename": "CannotConvertPackVariant"
+                }
+            }
+        });
+        assert_graphql_query!(&settings, mutation, &variables, &expected, None);
+    }
+}
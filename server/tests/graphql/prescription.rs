@@ -0,0 +1,202 @@
+mod graphql {
+    use crate::graphql::assert_graphql_query;
+    use repository::{
+        mock::{mock_patient_a, mock_stock_line_a, MockDataInserts},
+        StockLineRowRepository,
+    };
+    use serde_json::json;
+    use server::test_utils::setup_all;
+
+    #[actix_rt::test]
+    async fn test_graphql_prescription() {
+        let (_, connection, _, settings) =
+            setup_all("test_graphql_prescription", MockDataInserts::all()).await;
+
+        let query = r#"
+        query ($filter: PatientFilterInput) {
+            patients(filter: $filter) {
+                totalCount
+                nodes {
+                    id
+                    name
+                    dateOfBirth
+                    homeStoreId
+                }
+            }
+        }
+        "#;
+        let variables = Some(json!({
+          "filter": { "code": { "equalTo": mock_patient_a().code } }
+        }));
+        let expected = json!({
+            "patients": {
+                "totalCount": 1,
+                "nodes": [{
+                    "id": mock_patient_a().id,
+                    "name": "Doe, Jane",
+                    "dateOfBirth": "1985-03-14",
+                    "homeStoreId": "store_a"
+                }]
+            }
+        });
+        assert_graphql_query!(&settings, query, &variables, &expected, None);
+
+        let mutation = r#"
+        mutation ($input: InsertPatientInput!) {
+            insertPatient(input: $input) {
+                id
+                name
+                homeStoreId
+            }
+        }
+        "#;
+        let variables = Some(json!({
+          "input": {
+            "id": "patient_b",
+            "code": "P0002",
+            "firstName": "Sam",
+            "lastName": "Smith"
+          }
+        }));
+        let expected = json!({
+            "insertPatient": {
+                "id": "patient_b",
+                "name": "Smith, Sam",
+                "homeStoreId": "store_a"
+            }
+        });
+        assert_graphql_query!(&settings, mutation, &variables, &expected, None);
+
+        let mutation = r#"
+        mutation ($input: InsertPrescriberInput!) {
+            insertPrescriber(input: $input) {
+                id
+                lastName
+            }
+        }
+        "#;
+        let variables = Some(json!({
+          "input": {
+            "id": "prescriber",
+            "code": "DR1",
+            "firstName": "Anna",
+            "lastName": "Young"
+          }
+        }));
+        let expected = json!({
+            "insertPrescriber": { "id": "prescriber", "lastName": "Young" }
+        });
+        assert_graphql_query!(&settings, mutation, &variables, &expected, None);
+
+        let mutation = r#"
+        mutation ($input: InsertPrescriptionInput!) {
+            insertPrescription(input: $input) {
+                id
+                type
+                status
+                otherPartyId
+                prescriberId
+            }
+        }
+        "#;
+        let variables = Some(json!({
+          "input": {
+            "id": "prescription",
+            "patientId": "patient_b",
+            "prescriberId": "prescriber"
+          }
+        }));
+        let expected = json!({
+            "insertPrescription": {
+                "id": "prescription",
+                "type": "PRESCRIPTION",
+                "status": "NEW",
+                "otherPartyId": "patient_b",
+                "prescriberId": "prescriber"
+            }
+        });
+        assert_graphql_query!(&settings, mutation, &variables, &expected, None);
+
+        let mutation = r#"
+        mutation ($input: InsertPrescriptionLineInput!) {
+            insertPrescriptionLine(input: $input) {
+                id
+                numberOfPacks
+                directions
+            }
+        }
+        "#;
+        let variables = Some(json!({
+          "input": {
+            "id": "prescription_line",
+            "invoiceId": "prescription",
+            "stockLineId": mock_stock_line_a().id,
+            "numberOfPacks": 2,
+            "directions": "Take 1 tablet daily"
+          }
+        }));
+        let expected = json!({
+            "insertPrescriptionLine": {
+                "id": "prescription_line",
+                "numberOfPacks": 2,
+                "directions": "Take 1 tablet daily"
+            }
+        });
+        assert_graphql_query!(&settings, mutation, &variables, &expected, None);
+
+        let mutation = r#"
+        mutation ($input: UpdatePrescriptionInput!) {
+            updatePrescription(input: $input) {
+                status
+            }
+        }
+        "#;
+        let variables = Some(json!({
+          "input": { "id": "prescription", "status": "PICKED" }
+        }));
+        let expected = json!({
+            "updatePrescription": { "status": "PICKED" }
+        });
+        assert_graphql_query!(&settings, mutation, &variables, &expected, None);
+
+        let stock_line = StockLineRowRepository::new(&connection)
+            .find_one_by_id(&mock_stock_line_a().id)
+            .unwrap();
+        assert_eq!(
+            stock_line.total_number_of_packs,
+            mock_stock_line_a().total_number_of_packs - 2
+        );
+
+        let query = r#"
+        query ($patientId: String!, $invoiceId: String!) {
+            patientDispensingHistory(patientId: $patientId) {
+                id
+            }
+            prescriptionLabels(invoiceId: $invoiceId) {
+                invoiceLineId
+                patientName
+                prescriberName
+                itemName
+                numberOfUnits
+                directions
+            }
+        }
+        "#;
+        let variables = Some(json!({
+          "patientId": "patient_b",
+          "invoiceId": "prescription"
+        }));
+        let expected = json!({
+            "patientDispensingHistory": [{ "id": "prescription" }],
+            "prescriptionLabels": [{
+                "invoiceLineId": "prescription_line",
+                "patientName": "Smith, Sam",
+                "prescriberName": "Young, Anna",
+                "itemName": "Item A",
+                "numberOfUnits": 2,
+                "directions": "Take 1 tablet daily"
+            }]
+        });
+        assert_graphql_query!(&settings, query, &variables, &expected, None);
+    }
+}
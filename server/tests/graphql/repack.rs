@@ -0,0 +1,78 @@
+mod graphql {
+    use crate::graphql::{assert_graphql_query, run_gql_query};
+    use repository::mock::{mock_stock_line_a, MockDataInserts};
+    use serde_json::json;
+    use server::test_utils::setup_all;
+
+    #[actix_rt::test]
+    async fn test_graphql_repack() {
+        let (_, _, _, settings) = setup_all("test_graphql_repack", MockDataInserts::all()).await;
+
+        let mutation = r#"
+        mutation ($input: RepackInput!) {
+            repack(input: $input) {
+                invoice {
+                    id
+                    type
+                    status
+                }
+                stockLineFrom {
+                    id
+                    availableNumberOfPacks
+                    totalNumberOfPacks
+                }
+                stockLineTo {
+                    packSize
+                    availableNumberOfPacks
+                    totalNumberOfPacks
+                }
+            }
+        }
+        "#;
+
+        // Units don't fill whole packs
+        let variables = Some(json!({
+          "input": {
+            "id": "repack",
+            "stockLineId": mock_stock_line_a().id,
+            "numberOfPacks": 3,
+            "newPackSize": 2
+          }
+        }));
+        let response = run_gql_query(&settings, mutation, &variables, None).await;
+        assert!(response["errors"][0]["extensions"]["details"]
+            .as_str()
+            .unwrap()
+            .contains("CannotSplitIntoWholePacks"));
+
+        // 10 single units into a pack of 10
+        let variables = Some(json!({
+          "input": {
+            "id": "repack",
+            "stockLineId": mock_stock_line_a().id,
+            "numberOfPacks": 10,
+            "newPackSize": 10
+          }
+        }));
+        let expected = json!({
+            "repack": {
+                "invoice": {
+                    "id": "repack",
+                    "type": "REPACK",
+                    "status": "VERIFIED"
+                },
+                "stockLineFrom": {
+                    "id": mock_stock_line_a().id,
+                    "availableNumberOfPacks": 20,
+                    "totalNumberOfPacks": 30
+                },
+                "stockLineTo": {
+                    "packSize": 10,
+                    "availableNumberOfPacks": 1,
+                    "totalNumberOfPacks": 1
+                }
+            }
+        });
+        assert_graphql_query!(&settings, mutation, &variables, &expected, None);
+    }
+}
@@ -0,0 +1,123 @@
+mod graphql {
+    use actix_web::{
+        http::{
+            header::{CONTENT_DISPOSITION, CONTENT_TYPE},
+            StatusCode,
+        },
+        test::{call_service, init_service, read_body, TestRequest},
+        web::Data,
+    };
+    use graphql::{
+        config as graphql_config,
+        loader::{get_loaders, LoaderRegistry},
+    };
+    use repository::{
+        mock::{mock_outbound_shipment_a, MockDataInserts},
+        schema::InvoiceRow,
+        InvoiceRepository,
+    };
+    use server::test_utils::setup_all;
+    use service::{
+        auth_data::AuthData, service_provider::ServiceProvider, token_bucket::TokenBucket,
+    };
+    use std::sync::RwLock;
+
+    #[actix_rt::test]
+    async fn test_report() {
+        let (_, connection, connection_manager, settings) =
+            setup_all("test_report", MockDataInserts::all()).await;
+        let loaders = get_loaders(&connection_manager).await;
+
+        let auth_data = Data::new(AuthData {
+            auth_token_secret: settings.auth.token_secret.to_owned(),
+            token_bucket: RwLock::new(TokenBucket::new()),
+            debug_no_ssl: true,
+            debug_no_access_control: true,
+        });
+        let mut app = init_service(actix_web::App::new().configure(graphql_config(
+            Data::new(connection_manager.clone()),
+            Data::new(LoaderRegistry { loaders }),
+            Data::new(ServiceProvider::new(connection_manager)),
+            auth_data,
+        )))
+        .await;
+
+        // html
+        let response = call_service(
+            &mut app,
+            TestRequest::get()
+                .uri("/reports/packing_list/outbound_shipment_a?storeId=store_b")
+                .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers().get(CONTENT_TYPE).unwrap(),
+            "text/html; charset=utf-8"
+        );
+        let body = read_body(response).await;
+        assert!(String::from_utf8(body.to_vec())
+            .unwrap()
+            .contains("<h1>Packing list</h1>"));
+
+        // pdf
+        let response = call_service(
+            &mut app,
+            TestRequest::get()
+                .uri("/reports/packing_list/outbound_shipment_a?storeId=store_b&format=pdf")
+                .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers().get(CONTENT_TYPE).unwrap(),
+            "application/pdf"
+        );
+        assert_eq!(
+            response.headers().get(CONTENT_DISPOSITION).unwrap(),
+            "inline; filename=\"packing_list_outbound_shipment_a.pdf\""
+        );
+        let body = read_body(response).await;
+        assert!(body.starts_with(b"%PDF-"));
+
+        // path parameters can't break out of the file name
+        InvoiceRepository::new(&connection)
+            .upsert_one(&InvoiceRow {
+                id: "a\"; b.pdf".to_string(),
+                ..mock_outbound_shipment_a()
+            })
+            .unwrap();
+        let response = call_service(
+            &mut app,
+            TestRequest::get()
+                .uri("/reports/packing_list/a%22%3B%20b.pdf?storeId=store_b&format=pdf")
+                .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers().get(CONTENT_DISPOSITION).unwrap(),
+            "inline; filename=\"packing_list_a___b_pdf.pdf\""
+        );
+
+        // record of another store
+        let response = call_service(
+            &mut app,
+            TestRequest::get()
+                .uri("/reports/packing_list/outbound_shipment_a?storeId=store_a")
+                .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        // report for another type of record
+        let response = call_service(
+            &mut app,
+            TestRequest::get()
+                .uri("/reports/goods_received_note/outbound_shipment_a?storeId=store_b")
+                .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+}
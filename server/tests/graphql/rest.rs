@@ -0,0 +1,135 @@
+mod graphql {
+    use actix_web::{
+        http::StatusCode,
+        test::{call_service, init_service, read_body, TestRequest},
+        web::Data,
+    };
+    use graphql::{
+        config as graphql_config,
+        loader::{get_loaders, LoaderRegistry},
+    };
+    use repository::mock::MockDataInserts;
+    use serde_json::{json, Value};
+    use server::test_utils::setup_all;
+    use service::{
+        auth_data::AuthData, service_provider::ServiceProvider, token_bucket::TokenBucket,
+    };
+    use std::sync::RwLock;
+
+    #[actix_rt::test]
+    async fn test_rest_api() {
+        let (_, _, connection_manager, settings) =
+            setup_all("test_rest_api", MockDataInserts::all()).await;
+        let loaders = get_loaders(&connection_manager).await;
+
+        let auth_data = Data::new(AuthData {
+            auth_token_secret: settings.auth.token_secret.to_owned(),
+            token_bucket: RwLock::new(TokenBucket::new()),
+            debug_no_ssl: true,
+            debug_no_access_control: true,
+        });
+        let mut app = init_service(actix_web::App::new().configure(graphql_config(
+            Data::new(connection_manager.clone()),
+            Data::new(LoaderRegistry { loaders }),
+            Data::new(ServiceProvider::new(connection_manager)),
+            auth_data,
+        )))
+        .await;
+
+        macro_rules! get {
+            ($uri:expr) => {{
+                let response =
+                    call_service(&mut app, TestRequest::get().uri($uri).to_request()).await;
+                let status = response.status();
+                let body: Value = serde_json::from_slice(&read_body(response).await).unwrap();
+                (status, body)
+            }};
+        }
+
+        // filter and pagination
+        let (status, body) = get!(
+            "/api/v1/items?storeId=store_a&first=1&filter=%7B%22code%22%3A%7B%22equalTo%22%3A%22item_b_code%22%7D%7D"
+        );
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["totalCount"], json!(1));
+        assert_eq!(body["nodes"][0]["id"], json!("item_b"));
+
+        let (status, body) = get!("/api/v1/names?storeId=store_a&first=2&offset=1");
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["nodes"].as_array().unwrap().len(), 2);
+        assert!(body["totalCount"].as_u64().unwrap() > 2);
+
+        // sort, only the first sort input is evaluated
+        let (status, body) = get!(
+            "/api/v1/names?storeId=store_a&sort=%5B%7B%22key%22%3A%22code%22%2C%22desc%22%3Atrue%7D%5D"
+        );
+        assert_eq!(status, StatusCode::OK);
+        let codes: Vec<&str> = body["nodes"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|node| node["code"].as_str().unwrap())
+            .collect();
+        let mut sorted = codes.clone();
+        sorted.sort_by_key(|code| std::cmp::Reverse(code.to_lowercase()));
+        assert_eq!(codes, sorted);
+
+        // stock lines are limited to the store
+        let (status, body) = get!("/api/v1/stock-lines?storeId=store_b");
+        assert_eq!(status, StatusCode::OK);
+        assert!(body["nodes"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .all(|node| node["storeId"] == json!("store_b")));
+
+        // invoice with lines
+        let (status, body) = get!("/api/v1/invoices/outbound_shipment_a?storeId=store_b");
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["type"], json!("OUTBOUND_SHIPMENT"));
+        assert_eq!(body["lines"].as_array().unwrap().len(), 2);
+        let (status, _) = get!("/api/v1/invoices/outbound_shipment_a?storeId=store_a");
+        assert_eq!(status, StatusCode::NOT_FOUND);
+
+        let (status, body) = get!(
+            "/api/v1/invoices?storeId=store_a&filter=%7B%22type%22%3A%7B%22equalTo%22%3A%22INBOUND_SHIPMENT%22%7D%7D"
+        );
+        assert_eq!(status, StatusCode::OK);
+        assert!(body["nodes"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .all(|node| node["type"] == json!("INBOUND_SHIPMENT")));
+
+        // stock take with lines
+        let (status, body) = get!("/api/v1/stock-takes/stock_take_a?storeId=store_a");
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["status"], json!("NEW"));
+        assert_eq!(body["lines"][0]["itemId"], json!("item_a"));
+        let (status, body) = get!("/api/v1/stock-takes?storeId=store_a");
+        assert_eq!(status, StatusCode::OK);
+        assert!(body["nodes"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .all(|node| node["storeId"] == json!("store_a")));
+
+        // errors
+        let (status, body) = get!("/api/v1/items?storeId=store_a&filter=invalid");
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert!(body["message"].is_string());
+        let (status, _) = get!("/api/v1/items?storeId=store_a&first=1001");
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        let (status, _) = get!("/api/v1/items/invalid?storeId=store_a");
+        assert_eq!(status, StatusCode::NOT_FOUND);
+
+        // OpenAPI document
+        let (status, body) = get!("/api/v1/openapi.json");
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(
+            body["paths"]["/invoices/{id}"]["get"]["operationId"],
+            json!("getInvoice")
+        );
+        assert!(body["components"]["schemas"]["ApiInvoiceWithLines"].is_object());
+    }
+}
@@ -0,0 +1,155 @@
+mod graphql {
+    use crate::graphql::assert_graphql_query;
+    use repository::{
+        mock::{mock_name_a, mock_stock_line_a, MockDataInserts},
+        schema::{ReasonRow, ReasonRowType},
+        ReasonRowRepository, StockLineRowRepository,
+    };
+    use serde_json::json;
+    use server::test_utils::setup_all;
+
+    #[actix_rt::test]
+    async fn test_graphql_supplier_return() {
+        let (_, connection, _, settings) =
+            setup_all("test_graphql_supplier_return", MockDataInserts::all()).await;
+
+        ReasonRowRepository::new(&connection)
+            .upsert_one(&ReasonRow {
+                id: "expired".to_string(),
+                r#type: ReasonRowType::ReturnReason,
+                reason: "Expired".to_string(),
+                is_active: true,
+            })
+            .unwrap();
+        let query = r#"
+        query {
+            returnReasons {
+                id
+                reason
+            }
+        }
+        "#;
+        let expected = json!({
+            "returnReasons": [{ "id": "expired", "reason": "Expired" }]
+        });
+        assert_graphql_query!(&settings, query, &None, &expected, None);
+
+        let mutation = r#"
+        mutation ($input: InsertReturnInput!) {
+            insertSupplierReturn(input: $input) {
+                id
+                type
+                status
+                otherPartyId
+            }
+        }
+        "#;
+        let variables = Some(json!({
+          "input": {
+            "id": "supplier_return",
+            "otherPartyId": mock_name_a().id
+          }
+        }));
+        let expected = json!({
+            "insertSupplierReturn": {
+                "id": "supplier_return",
+                "type": "SUPPLIER_RETURN",
+                "status": "NEW",
+                "otherPartyId": mock_name_a().id
+            }
+        });
+        assert_graphql_query!(&settings, mutation, &variables, &expected, None);
+
+        let mutation = r#"
+        mutation ($input: InsertReturnLineInput!) {
+            insertSupplierReturnLine(input: $input) {
+                id
+                type
+                numberOfPacks
+                returnReasonId
+            }
+        }
+        "#;
+        let variables = Some(json!({
+          "input": {
+            "id": "supplier_return_line",
+            "invoiceId": "supplier_return",
+            "stockLineId": mock_stock_line_a().id,
+            "numberOfPacks": 3,
+            "reasonId": "expired"
+          }
+        }));
+        let expected = json!({
+            "insertSupplierReturnLine": {
+                "id": "supplier_return_line",
+                "type": "STOCK_OUT",
+                "numberOfPacks": 3,
+                "returnReasonId": "expired"
+            }
+        });
+        assert_graphql_query!(&settings, mutation, &variables, &expected, None);
+
+        // a return with lines can't be deleted
+        let mutation = r#"
+        mutation ($id: String!) {
+            deleteSupplierReturn(id: $id)
+        }
+        "#;
+        let variables = Some(json!({ "id": "supplier_return" }));
+        let response = crate::graphql::run_gql_query(&settings, mutation, &variables, None).await;
+        assert_eq!(
+            response["errors"][0]["extensions"]["details"],
+            "InvoiceLinesExist"
+        );
+
+        let before = StockLineRowRepository::new(&connection)
+            .find_one_by_id(&mock_stock_line_a().id)
+            .unwrap();
+        let mutation = r#"
+        mutation ($input: UpdateSupplierReturnInput!) {
+            updateSupplierReturn(input: $input) {
+                id
+                status
+            }
+        }
+        "#;
+        let variables = Some(json!({
+          "input": {
+            "id": "supplier_return",
+            "status": "SHIPPED"
+          }
+        }));
+        let expected = json!({
+            "updateSupplierReturn": {
+                "id": "supplier_return",
+                "status": "SHIPPED"
+            }
+        });
+        assert_graphql_query!(&settings, mutation, &variables, &expected, None);
+        let after = StockLineRowRepository::new(&connection)
+            .find_one_by_id(&mock_stock_line_a().id)
+            .unwrap();
+        assert_eq!(
+            after.total_number_of_packs,
+            before.total_number_of_packs - 3
+        );
+
+        let query = r#"
+        query {
+            invoices(filter: { type: { equalTo: SUPPLIER_RETURN } }) {
+                ... on InvoiceConnector {
+                    nodes {
+                        id
+                    }
+                }
+            }
+        }
+        "#;
+        let expected = json!({
+            "invoices": {
+                "nodes": [{ "id": "supplier_return" }]
+            }
+        });
+        assert_graphql_query!(&settings, query, &None, &expected, None);
+    }
+}
@@ -0,0 +1,202 @@
+mod graphql {
+    use crate::graphql::assert_graphql_query;
+    use actix_web::{
+        http::StatusCode,
+        test::{call_service, init_service, read_body, TestRequest},
+        web::Data,
+    };
+    use graphql::{
+        config as graphql_config,
+        loader::{get_loaders, LoaderRegistry},
+    };
+    use repository::{
+        mock::MockDataInserts, schema::LocationTypeRow, LocationRowRepository,
+        LocationTypeRowRepository,
+    };
+    use serde_json::{json, Value};
+    use server::test_utils::setup_all;
+    use service::{
+        auth_data::AuthData, service_provider::ServiceProvider, token_bucket::TokenBucket,
+    };
+    use std::sync::RwLock;
+
+    #[actix_rt::test]
+    async fn test_graphql_temperature_log() {
+        let (_, connection, connection_manager, settings) =
+            setup_all("test_graphql_temperature_log", MockDataInserts::all()).await;
+
+        LocationTypeRowRepository::new(&connection)
+            .upsert_one(&LocationTypeRow {
+                id: "fridge".to_string(),
+                name: "Fridge".to_string(),
+                min_temperature: Some(2.0),
+                max_temperature: Some(8.0),
+            })
+            .unwrap();
+        let location_repository = LocationRowRepository::new(&connection);
+        let mut location = location_repository
+            .find_one_by_id("location_1")
+            .unwrap()
+            .unwrap();
+        location.location_type_id = Some("fridge".to_string());
+        location_repository.upsert_one(&location).unwrap();
+
+        let mutation = r#"
+        mutation ($input: InsertTemperatureBreachConfigInput!) {
+            insertTemperatureBreachConfig(input: $input) {
+              id
+              locationTypeId
+              maximumTemperature
+              isActive
+            }
+          }
+        "#;
+        let variables = Some(json!({
+          "input": {
+            "id": "fridge_hot",
+            "locationTypeId": "fridge",
+            "description": "Above 8°C for 30 minutes",
+            "maximumTemperature": 8.0,
+            "durationMinutes": 30
+          }
+        }));
+        let expected = json!({
+            "insertTemperatureBreachConfig": {
+              "id": "fridge_hot",
+              "locationTypeId": "fridge",
+              "maximumTemperature": 8.0,
+              "isActive": true
+            }
+        });
+        assert_graphql_query!(&settings, mutation, &variables, &expected, None);
+
+        let auth_data = Data::new(AuthData {
+            auth_token_secret: settings.auth.token_secret.to_owned(),
+            token_bucket: RwLock::new(TokenBucket::new()),
+            debug_no_ssl: true,
+            debug_no_access_control: true,
+        });
+        let loaders = get_loaders(&connection_manager).await;
+        let mut app = init_service(actix_web::App::new().configure(graphql_config(
+            Data::new(connection_manager.clone()),
+            Data::new(LoaderRegistry { loaders }),
+            Data::new(ServiceProvider::new(connection_manager)),
+            auth_data,
+        )))
+        .await;
+
+        // CSV export of a data logger
+        let response = call_service(
+            &mut app,
+            TestRequest::post()
+                .uri("/import/temperatureLog?storeId=store_a&locationId=location_1")
+                .header("content-type", "text/csv")
+                .set_payload(
+                    "Logger,FR-01\n\
+                    Date,Time,Temperature (°C)\n\
+                    2021-06-01,08:00:00,3.0\n\
+                    2021-06-01,08:30:00,10.0\n\
+                    2021-06-01,09:00:00,11.0\n\
+                    2021-06-01,09:30:00,12.0\n",
+                )
+                .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::OK);
+        let body: Value = serde_json::from_slice(&read_body(response).await).unwrap();
+        assert_eq!(
+            body,
+            json!({
+                "numberOfLogs": 4,
+                "numberOfDuplicates": 0,
+                "numberOfBreaches": 1,
+                "numberOfCurrentBreaches": 1
+            })
+        );
+
+        let query = r#"
+        query ($locationId: String) {
+            temperatureBreaches(locationId: $locationId) {
+              location {
+                id
+              }
+              temperatureBreachConfig {
+                id
+              }
+              startDatetime
+              endDatetime
+              durationMinutes
+              isCurrent
+            }
+            temperatureBreachCounts {
+              current
+            }
+          }
+        "#;
+        let variables = Some(json!({ "locationId": "location_1" }));
+        let expected = json!({
+            "temperatureBreaches": [{
+              "location": { "id": "location_1" },
+              "temperatureBreachConfig": { "id": "fridge_hot" },
+              "startDatetime": "2021-06-01T08:30:00+00:00",
+              "endDatetime": null,
+              "durationMinutes": 60,
+              "isCurrent": true
+            }],
+            "temperatureBreachCounts": { "current": 1 }
+        });
+        assert_graphql_query!(&settings, query, &variables, &expected, None);
+
+        // Readings pushed by a sensor, the first one is already logged
+        let response = call_service(
+            &mut app,
+            TestRequest::post()
+                .uri("/temperatureLog?storeId=store_a")
+                .set_json(&json!({
+                    "readings": [
+                        { "locationId": "location_1", "temperature": 12.0, "datetime": "2021-06-01T09:30:00Z" },
+                        { "locationId": "location_1", "temperature": 5.0, "datetime": "2021-06-01T10:00:00Z" }
+                    ]
+                }))
+                .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::OK);
+        let body: Value = serde_json::from_slice(&read_body(response).await).unwrap();
+        assert_eq!(
+            body,
+            json!({
+                "numberOfLogs": 1,
+                "numberOfDuplicates": 1,
+                "numberOfBreaches": 1,
+                "numberOfCurrentBreaches": 0
+            })
+        );
+
+        let expected = json!({
+            "temperatureBreaches": [{
+              "startDatetime": "2021-06-01T08:30:00+00:00",
+              "endDatetime": "2021-06-01T10:00:00+00:00",
+              "durationMinutes": 90,
+              "isCurrent": false
+            }],
+            "temperatureBreachCounts": { "current": 0 }
+        });
+        assert_graphql_query!(&settings, query, &variables, &expected, None);
+
+        // Unknown location
+        let response = call_service(
+            &mut app,
+            TestRequest::post()
+                .uri("/temperatureLog?storeId=store_a")
+                .set_json(&json!({
+                    "readings": [
+                        { "locationId": "invalid", "temperature": 5.0, "datetime": "2021-06-01T10:30:00Z" }
+                    ]
+                }))
+                .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+}
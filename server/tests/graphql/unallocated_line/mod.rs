@@ -22,5 +22,7 @@ pub fn successfull_invoice_line() -> InvoiceLine {
         stock_line_id: None,
         location_id: None,
         location_name: None,
+        return_reason_id: None,
+        directions: None,
     }
 }
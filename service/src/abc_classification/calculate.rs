@@ -0,0 +1,180 @@
+use std::collections::HashMap;
+
+use chrono::{NaiveDateTime, Utc};
+use domain::{EqualFilter, Pagination};
+use repository::{
+    schema::{ItemAbcClassRow, ItemAbcClassRowClass, ItemRowType},
+    ConsumptionRepository, ItemAbcClassRowRepository, ItemFilter, ItemQueryRepository,
+    RepositoryError, StorageConnection, StoreRowRepository,
+};
+use util::uuid::uuid;
+
+use crate::service_provider::ServiceContext;
+
+pub const DEFAULT_CLASS_A_PERCENTAGE: f64 = 80.0;
+pub const DEFAULT_CLASS_B_PERCENTAGE: f64 = 95.0;
+
+/// Period and thresholds of an ABC classification.
+///
+/// Items are ranked by the cost value of the stock issued in the period. Items making up the
+/// first `class_a_percentage` of the total consumption value are class A, up to
+/// `class_b_percentage` class B and the remaining items class C.
+#[derive(Clone, Debug)]
+pub struct CalculateAbcClassification {
+    pub from_datetime: NaiveDateTime,
+    pub to_datetime: NaiveDateTime,
+    /// Defaults to `DEFAULT_CLASS_A_PERCENTAGE`
+    pub class_a_percentage: Option<f64>,
+    /// Defaults to `DEFAULT_CLASS_B_PERCENTAGE`
+    pub class_b_percentage: Option<f64>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum CalculateAbcClassificationError {
+    DatabaseError(RepositoryError),
+    StoreDoesNotExist,
+    InvalidDateRange,
+    /// Percentages must be between 0 and 100 and class A must not exceed class B
+    InvalidPercentage,
+}
+
+impl From<RepositoryError> for CalculateAbcClassificationError {
+    fn from(error: RepositoryError) -> Self {
+        CalculateAbcClassificationError::DatabaseError(error)
+    }
+}
+
+pub fn calculate_abc_classification(
+    ctx: &ServiceContext,
+    store_id: &str,
+    input: CalculateAbcClassification,
+) -> Result<Vec<ItemAbcClassRow>, CalculateAbcClassificationError> {
+    let rows = ctx
+        .connection
+        .transaction_sync(|connection| {
+            let (class_a_share, class_b_share) = validate(connection, store_id, &input)?;
+            let consumption = get_consumption_values(connection, store_id, &input)?;
+            let rows = generate(store_id, consumption, class_a_share, class_b_share);
+
+            let repository = ItemAbcClassRowRepository::new(connection);
+            repository.delete_by_store_id(store_id)?;
+            for row in &rows {
+                repository.upsert_one(row)?;
+            }
+            Ok::<Vec<ItemAbcClassRow>, CalculateAbcClassificationError>(rows)
+        })
+        .map_err(|error| error.to_inner_error())?;
+    Ok(rows)
+}
+
+/// Returns the class A and B thresholds as share of the total consumption value (0 to 1)
+fn validate(
+    connection: &StorageConnection,
+    store_id: &str,
+    input: &CalculateAbcClassification,
+) -> Result<(f64, f64), CalculateAbcClassificationError> {
+    if StoreRowRepository::new(connection)
+        .find_one_by_id(store_id)?
+        .is_none()
+    {
+        return Err(CalculateAbcClassificationError::StoreDoesNotExist);
+    }
+
+    if input.from_datetime > input.to_datetime {
+        return Err(CalculateAbcClassificationError::InvalidDateRange);
+    }
+
+    let class_a = input
+        .class_a_percentage
+        .unwrap_or(DEFAULT_CLASS_A_PERCENTAGE);
+    let class_b = input
+        .class_b_percentage
+        .unwrap_or(DEFAULT_CLASS_B_PERCENTAGE);
+    if class_a <= 0.0 || class_a > class_b || class_b > 100.0 {
+        return Err(CalculateAbcClassificationError::InvalidPercentage);
+    }
+
+    Ok((class_a / 100.0, class_b / 100.0))
+}
+
+/// Consumption value of every item visible in the store and of every item consumed in the
+/// period, ordered by value (descending) and item id
+fn get_consumption_values(
+    connection: &StorageConnection,
+    store_id: &str,
+    input: &CalculateAbcClassification,
+) -> Result<Vec<(String, f64)>, RepositoryError> {
+    let mut values: HashMap<String, f64> = ItemQueryRepository::new(connection)
+        .query(
+            store_id,
+            Pagination::all(),
+            Some(
+                ItemFilter::new()
+                    .match_is_visible(true)
+                    .r#type(EqualFilter {
+                        equal_to: Some(ItemRowType::Stock),
+                        not_equal_to: None,
+                        equal_any: None,
+                    }),
+            ),
+            None,
+        )?
+        .into_iter()
+        .map(|item| (item.id, 0.0))
+        .collect();
+
+    for consumption in ConsumptionRepository::new(connection).query_value_by_item(
+        store_id,
+        input.from_datetime,
+        input.to_datetime,
+    )? {
+        values.insert(consumption.item_id, consumption.value);
+    }
+
+    let mut values: Vec<(String, f64)> = values.into_iter().collect();
+    values.sort_by(|(a_id, a_value), (b_id, b_value)| {
+        b_value
+            .partial_cmp(a_value)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then_with(|| a_id.cmp(b_id))
+    });
+    Ok(values)
+}
+
+fn generate(
+    store_id: &str,
+    consumption: Vec<(String, f64)>,
+    class_a_share: f64,
+    class_b_share: f64,
+) -> Vec<ItemAbcClassRow> {
+    let total: f64 = consumption.iter().map(|(_, value)| value).sum();
+    let calculated_datetime = Utc::now().naive_utc();
+
+    // An item is classified by the cumulative share before it is added, i.e. the item that
+    // crosses a threshold still belongs to the higher class
+    let mut cumulative = 0.0;
+    consumption
+        .into_iter()
+        .map(|(item_id, consumption_value)| {
+            let class = if consumption_value <= 0.0 || total <= 0.0 {
+                ItemAbcClassRowClass::C
+            } else if cumulative / total < class_a_share {
+                ItemAbcClassRowClass::A
+            } else if cumulative / total < class_b_share {
+                ItemAbcClassRowClass::B
+            } else {
+                ItemAbcClassRowClass::C
+            };
+            cumulative += consumption_value;
+
+            ItemAbcClassRow {
+                id: uuid(),
+                store_id: store_id.to_string(),
+                item_id,
+                class,
+                consumption_value,
+                calculated_datetime,
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,36 @@
+use repository::{schema::ItemAbcClassRow, ItemAbcClassRowRepository, RepositoryError};
+
+use crate::service_provider::ServiceContext;
+
+use self::calculate::{
+    calculate_abc_classification, CalculateAbcClassification, CalculateAbcClassificationError,
+};
+
+pub mod calculate;
+
+#[cfg(test)]
+mod tests;
+
+pub trait AbcClassificationServiceTrait: Sync + Send {
+    /// Stored ABC classification of the store, ordered by consumption value (descending)
+    fn get_abc_classification(
+        &self,
+        ctx: &ServiceContext,
+        store_id: &str,
+    ) -> Result<Vec<ItemAbcClassRow>, RepositoryError> {
+        ItemAbcClassRowRepository::new(&ctx.connection).find_many_by_store_id(store_id)
+    }
+
+    /// Recalculates and stores the ABC classification of the store, replacing the previous one
+    fn calculate_abc_classification(
+        &self,
+        ctx: &ServiceContext,
+        store_id: &str,
+        input: CalculateAbcClassification,
+    ) -> Result<Vec<ItemAbcClassRow>, CalculateAbcClassificationError> {
+        calculate_abc_classification(ctx, store_id, input)
+    }
+}
+
+pub struct AbcClassificationService {}
+impl AbcClassificationServiceTrait for AbcClassificationService {}
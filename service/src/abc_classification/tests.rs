@@ -0,0 +1,229 @@
+#[cfg(test)]
+mod abc_classification_test {
+    use chrono::{NaiveDate, NaiveDateTime};
+    use repository::{
+        mock::{
+            mock_item_a, mock_item_b, mock_item_c, mock_name_store_b, mock_store_a, MockDataInserts,
+        },
+        schema::{
+            InvoiceLineRow, InvoiceLineRowType, InvoiceRow, InvoiceRowStatus, InvoiceRowType,
+            ItemAbcClassRowClass,
+        },
+        test_db::setup_all,
+        InvoiceLineRowRepository, InvoiceRepository, StorageConnection,
+    };
+
+    use crate::{
+        abc_classification::calculate::{
+            CalculateAbcClassification, CalculateAbcClassificationError,
+        },
+        service_provider::ServiceProvider,
+    };
+
+    fn datetime(day: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd(2021, 5, day).and_hms(9, 0, 0)
+    }
+
+    fn insert_shipment(connection: &StorageConnection, id: &str, item_id: &str, value: f64) {
+        insert_issue(
+            connection,
+            id,
+            InvoiceRowType::OutboundShipment,
+            item_id,
+            value,
+        )
+    }
+
+    /// Shipped outbound shipment or dispensed prescription
+    fn insert_issue(
+        connection: &StorageConnection,
+        id: &str,
+        r#type: InvoiceRowType,
+        item_id: &str,
+        value: f64,
+    ) {
+        let (status, shipped_datetime) = match r#type {
+            InvoiceRowType::Prescription => (InvoiceRowStatus::Picked, None),
+            _ => (InvoiceRowStatus::Shipped, Some(datetime(10))),
+        };
+        InvoiceRepository::new(connection)
+            .upsert_one(&InvoiceRow {
+                id: id.to_string(),
+                name_id: mock_name_store_b().id,
+                store_id: mock_store_a().id,
+                invoice_number: 1,
+                r#type,
+                status,
+                on_hold: false,
+                comment: None,
+                their_reference: None,
+                created_datetime: datetime(10),
+                allocated_datetime: None,
+                picked_datetime: Some(datetime(10)),
+                shipped_datetime,
+                delivered_datetime: None,
+                verified_datetime: None,
+                color: None,
+                prescriber_id: None,
+            })
+            .unwrap();
+        InvoiceLineRowRepository::new(connection)
+            .upsert_one(&InvoiceLineRow {
+                id: format!("{}_line", id),
+                invoice_id: id.to_string(),
+                item_id: item_id.to_string(),
+                item_name: "".to_string(),
+                item_code: "".to_string(),
+                stock_line_id: None,
+                location_id: None,
+                batch: None,
+                expiry_date: None,
+                pack_size: 1,
+                cost_price_per_pack: value / 10.0,
+                sell_price_per_pack: 0.0,
+                total_before_tax: 0.0,
+                total_after_tax: 0.0,
+                tax: None,
+                r#type: InvoiceLineRowType::StockOut,
+                number_of_packs: 10,
+                note: None,
+                return_reason_id: None,
+                directions: None,
+            })
+            .unwrap();
+    }
+
+    fn input() -> CalculateAbcClassification {
+        CalculateAbcClassification {
+            from_datetime: datetime(1),
+            to_datetime: datetime(31),
+            class_a_percentage: None,
+            class_b_percentage: None,
+        }
+    }
+
+    #[actix_rt::test]
+    async fn calculate_abc_classification() {
+        let (_, connection, connection_manager, _) =
+            setup_all("calculate_abc_classification", MockDataInserts::all()).await;
+
+        let service_provider = ServiceProvider::new(connection_manager);
+        let context = service_provider.context().unwrap();
+        let service = service_provider.abc_classification_service;
+        let store_id = mock_store_a().id;
+
+        // errors
+        assert_eq!(
+            service.calculate_abc_classification(&context, "invalid", input()),
+            Err(CalculateAbcClassificationError::StoreDoesNotExist)
+        );
+        assert_eq!(
+            service.calculate_abc_classification(
+                &context,
+                &store_id,
+                CalculateAbcClassification {
+                    from_datetime: datetime(31),
+                    to_datetime: datetime(1),
+                    ..input()
+                }
+            ),
+            Err(CalculateAbcClassificationError::InvalidDateRange)
+        );
+        assert_eq!(
+            service.calculate_abc_classification(
+                &context,
+                &store_id,
+                CalculateAbcClassification {
+                    class_a_percentage: Some(90.0),
+                    class_b_percentage: Some(80.0),
+                    ..input()
+                }
+            ),
+            Err(CalculateAbcClassificationError::InvalidPercentage)
+        );
+
+        // 80%, 15% and 5% of the consumption value
+        insert_shipment(&connection, "abc_a", &mock_item_a().id, 800.0);
+        insert_shipment(&connection, "abc_b", &mock_item_b().id, 150.0);
+        insert_shipment(&connection, "abc_c", &mock_item_c().id, 50.0);
+
+        let result = service
+            .calculate_abc_classification(&context, &store_id, input())
+            .unwrap();
+        let classes: Vec<(String, ItemAbcClassRowClass, f64)> = result
+            .iter()
+            .take(3)
+            .map(|row| {
+                (
+                    row.item_id.clone(),
+                    row.class.clone(),
+                    row.consumption_value,
+                )
+            })
+            .collect();
+        assert_eq!(
+            classes,
+            vec![
+                (mock_item_a().id, ItemAbcClassRowClass::A, 800.0),
+                (mock_item_b().id, ItemAbcClassRowClass::B, 150.0),
+                (mock_item_c().id, ItemAbcClassRowClass::C, 50.0),
+            ]
+        );
+        // items without consumption are class C
+        assert!(result
+            .iter()
+            .skip(3)
+            .all(|row| row.class == ItemAbcClassRowClass::C && row.consumption_value == 0.0));
+
+        // recalculating replaces the previous classification
+        let result = service
+            .calculate_abc_classification(
+                &context,
+                &store_id,
+                CalculateAbcClassification {
+                    class_a_percentage: Some(50.0),
+                    class_b_percentage: Some(100.0),
+                    ..input()
+                },
+            )
+            .unwrap();
+        let stored = service.get_abc_classification(&context, &store_id).unwrap();
+        assert_eq!(stored.len(), result.len());
+        assert_eq!(stored[0].class, ItemAbcClassRowClass::A);
+        assert_eq!(stored[1].class, ItemAbcClassRowClass::B);
+        assert_eq!(stored[2].class, ItemAbcClassRowClass::B);
+    }
+
+    #[actix_rt::test]
+    async fn calculate_abc_classification_prescriptions() {
+        let (_, connection, connection_manager, _) = setup_all(
+            "calculate_abc_classification_prescriptions",
+            MockDataInserts::all(),
+        )
+        .await;
+
+        let service_provider = ServiceProvider::new(connection_manager);
+        let context = service_provider.context().unwrap();
+        let service = service_provider.abc_classification_service;
+        let store_id = mock_store_a().id;
+
+        // dispensed stock is consumption as well
+        insert_shipment(&connection, "abc_a", &mock_item_a().id, 300.0);
+        insert_issue(
+            &connection,
+            "abc_b",
+            InvoiceRowType::Prescription,
+            &mock_item_b().id,
+            700.0,
+        );
+
+        let result = service
+            .calculate_abc_classification(&context, &store_id, input())
+            .unwrap();
+        assert_eq!(result[0].item_id, mock_item_b().id);
+        assert_eq!(result[0].class, ItemAbcClassRowClass::A);
+        assert_eq!(result[0].consumption_value, 700.0);
+        assert_eq!(result[1].item_id, mock_item_a().id);
+        assert_eq!(result[1].consumption_value, 300.0);
+    }
+}
@@ -0,0 +1,31 @@
+use repository::{BarcodeRowRepository, RepositoryError};
+
+use crate::service_provider::ServiceContext;
+
+use super::validate::check_barcode_exists;
+
+#[derive(Debug, PartialEq)]
+pub enum DeleteBarcodeError {
+    BarcodeDoesNotExist,
+    DatabaseError(RepositoryError),
+}
+
+pub fn delete_barcode(ctx: &ServiceContext, id: &str) -> Result<String, DeleteBarcodeError> {
+    let barcode_id = ctx
+        .connection
+        .transaction_sync(|connection| {
+            if check_barcode_exists(id, connection)?.is_none() {
+                return Err(DeleteBarcodeError::BarcodeDoesNotExist);
+            }
+            BarcodeRowRepository::new(connection).delete(id)?;
+            Ok(id.to_string())
+        })
+        .map_err(|error| error.to_inner_error())?;
+    Ok(barcode_id)
+}
+
+impl From<RepositoryError> for DeleteBarcodeError {
+    fn from(error: RepositoryError) -> Self {
+        DeleteBarcodeError::DatabaseError(error)
+    }
+}
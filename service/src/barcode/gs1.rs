@@ -0,0 +1,254 @@
+//! Parser for GS1 element strings as encoded in GS1-128, GS1 DataMatrix and GS1 QR codes.
+//!
+//! Element strings are accepted as sent by scanners, with an optional symbology identifier and
+//! ASCII group separators (FNC1) after variable length fields, or in the human readable form with
+//! the application identifiers in brackets, e.g. `(01)09501101530003(17)250131(10)AB-123`.
+use chrono::{Datelike, NaiveDate};
+
+/// ASCII group separator, sent by scanners for FNC1
+pub const GROUP_SEPARATOR: char = '\u{1d}';
+
+/// Symbology identifiers of GS1-128, GS1 DataBar, GS1 DataMatrix and GS1 QR codes
+const SYMBOLOGY_IDENTIFIERS: [&str; 4] = ["]C1", "]e0", "]d2", "]Q3"];
+
+#[derive(Clone, Copy)]
+enum Length {
+    Fixed(usize),
+    /// Maximum length, the field ends with a group separator or the end of the data
+    Variable(usize),
+}
+
+/// Supported application identifiers and the length of their data
+const APPLICATION_IDENTIFIERS: [(&str, Length); 15] = [
+    // SSCC
+    ("00", Length::Fixed(18)),
+    // GTIN
+    ("01", Length::Fixed(14)),
+    // GTIN of contained trade items
+    ("02", Length::Fixed(14)),
+    // batch or lot number
+    ("10", Length::Variable(20)),
+    // production date
+    ("11", Length::Fixed(6)),
+    // packaging date
+    ("13", Length::Fixed(6)),
+    // best before date
+    ("15", Length::Fixed(6)),
+    // sell by date
+    ("16", Length::Fixed(6)),
+    // expiration date
+    ("17", Length::Fixed(6)),
+    // serial number
+    ("21", Length::Variable(20)),
+    // variable count of items
+    ("30", Length::Variable(8)),
+    // count of trade items
+    ("37", Length::Variable(8)),
+    // additional product identification
+    ("240", Length::Variable(30)),
+    // customer part number
+    ("241", Length::Variable(30)),
+    // customer purchase order number
+    ("400", Length::Variable(30)),
+];
+
+#[derive(Debug, PartialEq)]
+pub enum Gs1Error {
+    /// Element string starts with an application identifier that isn't supported
+    UnknownApplicationIdentifier(String),
+    /// Data of the application identifier is too short, too long or isn't numeric
+    InvalidLength(String),
+    InvalidDate(String),
+    InvalidCheckDigit(String),
+}
+
+/// Fields of a GS1 element string relevant for receiving and counting stock
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct Gs1Data {
+    /// GTIN with 14 digits
+    pub gtin: Option<String>,
+    pub batch: Option<String>,
+    pub expiry_date: Option<NaiveDate>,
+    pub serial: Option<String>,
+}
+
+/// Returns if the scanned value looks like a GS1 element string rather than a plain barcode
+pub fn is_element_string(value: &str) -> bool {
+    SYMBOLOGY_IDENTIFIERS
+        .iter()
+        .any(|identifier| value.starts_with(identifier))
+        || value.starts_with('(')
+        || value.contains(GROUP_SEPARATOR)
+        // GTIN followed by more application identifiers
+        || (value.len() > 16
+            && value.starts_with("01")
+            && value.chars().take(16).all(|c| c.is_ascii_digit()))
+}
+
+/// Parses a GS1 element string, dates with two digit years are resolved relative to `today`
+pub fn parse(value: &str, today: NaiveDate) -> Result<Gs1Data, Gs1Error> {
+    let mut data = Gs1Data::default();
+    for (ai, field) in split_fields(value)? {
+        match ai {
+            "01" => data.gtin = Some(check_gtin(ai, field)?),
+            "10" => data.batch = Some(field.to_string()),
+            "17" => data.expiry_date = Some(parse_date(ai, field, today)?),
+            "21" => data.serial = Some(field.to_string()),
+            _ => {}
+        }
+    }
+    Ok(data)
+}
+
+fn strip_symbology_identifier(value: &str) -> &str {
+    SYMBOLOGY_IDENTIFIERS
+        .iter()
+        .find_map(|identifier| value.strip_prefix(identifier))
+        .unwrap_or(value)
+}
+
+fn find_application_identifier(data: &str) -> Result<(&'static str, Length), Gs1Error> {
+    APPLICATION_IDENTIFIERS
+        .iter()
+        .find(|(ai, _)| data.starts_with(ai))
+        .cloned()
+        .ok_or_else(|| {
+            Gs1Error::UnknownApplicationIdentifier(data.chars().take(4).collect::<String>())
+        })
+}
+
+/// Splits the element string into application identifiers and their data
+fn split_fields(value: &str) -> Result<Vec<(&'static str, &str)>, Gs1Error> {
+    let value = strip_symbology_identifier(value.trim());
+    if value.starts_with('(') {
+        return split_bracketed_fields(value);
+    }
+
+    let mut fields = Vec::new();
+    let mut rest = value.trim_start_matches(GROUP_SEPARATOR);
+    while !rest.is_empty() {
+        let (ai, length) = find_application_identifier(rest)?;
+        rest = &rest[ai.len()..];
+        let field = match length {
+            Length::Fixed(length) => {
+                if rest.len() < length || !rest.is_char_boundary(length) {
+                    return Err(Gs1Error::InvalidLength(ai.to_string()));
+                }
+                let (field, remaining) = rest.split_at(length);
+                rest = remaining;
+                field
+            }
+            Length::Variable(max_length) => {
+                let end = rest.find(GROUP_SEPARATOR).unwrap_or_else(|| rest.len());
+                let (field, remaining) = rest.split_at(end);
+                if field.is_empty() || field.chars().count() > max_length {
+                    return Err(Gs1Error::InvalidLength(ai.to_string()));
+                }
+                rest = remaining;
+                field
+            }
+        };
+        fields.push((ai, field));
+        rest = rest.trim_start_matches(GROUP_SEPARATOR);
+    }
+    Ok(fields)
+}
+
+fn split_bracketed_fields(value: &str) -> Result<Vec<(&'static str, &str)>, Gs1Error> {
+    let mut fields = Vec::new();
+    for part in value.split('(').skip(1) {
+        let (ai, field) = part
+            .split_once(')')
+            .ok_or_else(|| Gs1Error::UnknownApplicationIdentifier(part.to_string()))?;
+        let (known_ai, length) = find_application_identifier(ai)?;
+        if known_ai != ai {
+            return Err(Gs1Error::UnknownApplicationIdentifier(ai.to_string()));
+        }
+        let valid_length = match length {
+            Length::Fixed(length) => field.len() == length,
+            Length::Variable(max_length) => {
+                !field.is_empty() && field.chars().count() <= max_length
+            }
+        };
+        if !valid_length {
+            return Err(Gs1Error::InvalidLength(known_ai.to_string()));
+        }
+        fields.push((known_ai, field));
+    }
+    Ok(fields)
+}
+
+/// Returns if the last digit of a GTIN-8, GTIN-12, GTIN-13 or GTIN-14 is a valid check digit
+pub fn is_valid_gtin(gtin: &str) -> bool {
+    if ![8, 12, 13, 14].contains(&gtin.len()) || !gtin.chars().all(|c| c.is_ascii_digit()) {
+        return false;
+    }
+    let digits: Vec<u32> = gtin.chars().filter_map(|c| c.to_digit(10)).collect();
+    let (check_digit, payload) = digits.split_last().unwrap();
+    // weights alternate 3 and 1 starting from the digit next to the check digit
+    let sum: u32 = payload
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(index, digit)| if index % 2 == 0 { digit * 3 } else { *digit })
+        .sum();
+    (10 - sum % 10) % 10 == *check_digit
+}
+
+/// Pads a GTIN-8, GTIN-12 or GTIN-13 to 14 digits
+pub fn to_gtin_14(gtin: &str) -> String {
+    format!("{:0>14}", gtin)
+}
+
+/// GTIN-14 and the shorter GTINs it was padded from, barcodes can be stored in any of these forms
+pub fn gtin_forms(gtin_14: &str) -> Vec<String> {
+    [14, 13, 12, 8]
+        .iter()
+        .filter(|length| gtin_14.len() >= **length)
+        .map(|length| gtin_14.split_at(gtin_14.len() - length))
+        .filter(|(padding, _)| padding.chars().all(|c| c == '0'))
+        .map(|(_, gtin)| gtin.to_string())
+        .collect()
+}
+
+fn check_gtin(ai: &str, field: &str) -> Result<String, Gs1Error> {
+    if !field.chars().all(|c| c.is_ascii_digit()) {
+        return Err(Gs1Error::InvalidLength(ai.to_string()));
+    }
+    if !is_valid_gtin(field) {
+        return Err(Gs1Error::InvalidCheckDigit(ai.to_string()));
+    }
+    Ok(field.to_string())
+}
+
+/// Parses a `YYMMDD` date. A day of `00` is the last day of the month and the century is chosen
+/// so that the year is at most 49 years in the past and 50 years in the future.
+fn parse_date(ai: &str, field: &str, today: NaiveDate) -> Result<NaiveDate, Gs1Error> {
+    let invalid = || Gs1Error::InvalidDate(ai.to_string());
+    if field.len() != 6 || !field.chars().all(|c| c.is_ascii_digit()) {
+        return Err(invalid());
+    }
+    let number = |range: std::ops::Range<usize>| field[range].parse::<u32>().map_err(|_| invalid());
+    let (year, month, day) = (number(0..2)? as i32, number(2..4)?, number(4..6)?);
+
+    let current_century = today.year() - today.year() % 100;
+    let century = match year - today.year() % 100 {
+        difference if difference >= 51 => current_century - 100,
+        difference if difference <= -50 => current_century + 100,
+        _ => current_century,
+    };
+    let year = century + year;
+
+    if day == 0 {
+        let (next_year, next_month) = if month == 12 {
+            (year + 1, 1)
+        } else {
+            (year, month + 1)
+        };
+        return NaiveDate::from_ymd_opt(next_year, next_month, 1)
+            .filter(|_| (1..=12).contains(&month))
+            .and_then(|first_of_next_month| first_of_next_month.pred_opt())
+            .ok_or_else(invalid);
+    }
+    NaiveDate::from_ymd_opt(year, month, day).ok_or_else(invalid)
+}
@@ -0,0 +1,95 @@
+use repository::{schema::BarcodeRow, BarcodeRowRepository, RepositoryError, StorageConnection};
+
+use crate::service_provider::ServiceContext;
+
+use super::validate::{
+    check_barcode_exists, check_barcode_value_is_unique, check_item_exists,
+    check_manufacturer_exists,
+};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct InsertBarcode {
+    pub id: String,
+    pub value: String,
+    pub item_id: String,
+    pub manufacturer_id: Option<String>,
+    pub pack_size: Option<i32>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum InsertBarcodeError {
+    BarcodeAlreadyExists,
+    EmptyValue,
+    BarcodeWithValueAlreadyExists,
+    ItemDoesNotExist,
+    ManufacturerDoesNotExist,
+    PackSizeBelowOne,
+    DatabaseError(RepositoryError),
+}
+
+pub fn insert_barcode(
+    ctx: &ServiceContext,
+    input: InsertBarcode,
+) -> Result<BarcodeRow, InsertBarcodeError> {
+    let barcode = ctx
+        .connection
+        .transaction_sync(|connection| {
+            validate(&input, connection)?;
+            let new_barcode = generate(input);
+            BarcodeRowRepository::new(connection).upsert_one(&new_barcode)?;
+            Ok::<BarcodeRow, InsertBarcodeError>(new_barcode)
+        })
+        .map_err(|error| error.to_inner_error())?;
+    Ok(barcode)
+}
+
+fn validate(
+    input: &InsertBarcode,
+    connection: &StorageConnection,
+) -> Result<(), InsertBarcodeError> {
+    if check_barcode_exists(&input.id, connection)?.is_some() {
+        return Err(InsertBarcodeError::BarcodeAlreadyExists);
+    }
+    if input.value.trim().is_empty() {
+        return Err(InsertBarcodeError::EmptyValue);
+    }
+    if !check_barcode_value_is_unique(&input.id, input.value.trim(), connection)? {
+        return Err(InsertBarcodeError::BarcodeWithValueAlreadyExists);
+    }
+    if !check_item_exists(&input.item_id, connection)? {
+        return Err(InsertBarcodeError::ItemDoesNotExist);
+    }
+    if let Some(manufacturer_id) = &input.manufacturer_id {
+        if !check_manufacturer_exists(manufacturer_id, connection)? {
+            return Err(InsertBarcodeError::ManufacturerDoesNotExist);
+        }
+    }
+    if input.pack_size.filter(|pack_size| *pack_size < 1).is_some() {
+        return Err(InsertBarcodeError::PackSizeBelowOne);
+    }
+    Ok(())
+}
+
+fn generate(
+    InsertBarcode {
+        id,
+        value,
+        item_id,
+        manufacturer_id,
+        pack_size,
+    }: InsertBarcode,
+) -> BarcodeRow {
+    BarcodeRow {
+        id,
+        value: value.trim().to_string(),
+        item_id,
+        manufacturer_id,
+        pack_size,
+    }
+}
+
+impl From<RepositoryError> for InsertBarcodeError {
+    fn from(error: RepositoryError) -> Self {
+        InsertBarcodeError::DatabaseError(error)
+    }
+}
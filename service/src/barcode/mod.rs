@@ -0,0 +1,78 @@
+use domain::item::Item;
+use repository::{
+    schema::BarcodeRow, BarcodeRowRepository, ItemFilter, ItemQueryRepository, RepositoryError,
+    StorageConnection,
+};
+
+use crate::service_provider::ServiceContext;
+
+use self::{
+    delete::{delete_barcode, DeleteBarcodeError},
+    insert::{insert_barcode, InsertBarcode, InsertBarcodeError},
+    scan::{scan_barcode, ScanBarcodeError, ScannedBarcode},
+    update::{update_barcode, UpdateBarcode, UpdateBarcodeError},
+};
+
+pub mod delete;
+pub mod gs1;
+pub mod insert;
+pub mod scan;
+pub mod update;
+mod validate;
+
+#[cfg(test)]
+mod tests;
+
+pub trait BarcodeServiceTrait: Sync + Send {
+    fn get_item_barcodes(
+        &self,
+        ctx: &ServiceContext,
+        item_id: &str,
+    ) -> Result<Vec<BarcodeRow>, RepositoryError> {
+        BarcodeRowRepository::new(&ctx.connection).find_many_by_item_id(item_id)
+    }
+
+    /// Looks up a scanned plain barcode or GS1 element string, the item is evaluated for the
+    /// store
+    fn scan_barcode(
+        &self,
+        ctx: &ServiceContext,
+        store_id: &str,
+        value: &str,
+    ) -> Result<ScannedBarcode, ScanBarcodeError> {
+        scan_barcode(ctx, store_id, value)
+    }
+
+    fn insert_barcode(
+        &self,
+        ctx: &ServiceContext,
+        input: InsertBarcode,
+    ) -> Result<BarcodeRow, InsertBarcodeError> {
+        insert_barcode(ctx, input)
+    }
+
+    fn update_barcode(
+        &self,
+        ctx: &ServiceContext,
+        input: UpdateBarcode,
+    ) -> Result<BarcodeRow, UpdateBarcodeError> {
+        update_barcode(ctx, input)
+    }
+
+    fn delete_barcode(&self, ctx: &ServiceContext, id: &str) -> Result<String, DeleteBarcodeError> {
+        delete_barcode(ctx, id)
+    }
+}
+
+pub struct BarcodeService {}
+impl BarcodeServiceTrait for BarcodeService {}
+
+fn find_item(
+    connection: &StorageConnection,
+    store_id: &str,
+    filter: ItemFilter,
+) -> Result<Option<Item>, RepositoryError> {
+    Ok(ItemQueryRepository::new(connection)
+        .query_by_filter(store_id, filter)?
+        .pop())
+}
@@ -0,0 +1,120 @@
+use chrono::{NaiveDate, Utc};
+use domain::{item::Item, EqualFilter, SimpleStringFilter};
+use repository::{schema::BarcodeRow, BarcodeRowRepository, ItemFilter, RepositoryError};
+
+use crate::service_provider::ServiceContext;
+
+use super::{
+    find_item,
+    gs1::{self, Gs1Data, Gs1Error},
+};
+
+#[derive(Debug, PartialEq)]
+pub enum ScanBarcodeError {
+    EmptyBarcode,
+    InvalidGs1(Gs1Error),
+    DatabaseError(RepositoryError),
+}
+
+/// Item of a scanned barcode, with the batch and expiry date of GS1 element strings to prefill
+/// inbound shipment and stock take lines
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScannedBarcode {
+    /// Scanned value with surrounding whitespace removed
+    pub value: String,
+    /// GTIN-14 of the scanned GTIN or the GS1 element string
+    pub gtin: Option<String>,
+    pub batch: Option<String>,
+    pub expiry_date: Option<NaiveDate>,
+    pub serial: Option<String>,
+    /// Matching barcode, if any
+    pub barcode: Option<BarcodeRow>,
+    /// Item of the matching barcode, or the item with the scanned value as code
+    pub item: Option<Item>,
+}
+
+impl ScannedBarcode {
+    /// Pack size the barcode is printed on
+    pub fn pack_size(&self) -> Option<i32> {
+        self.barcode.as_ref().and_then(|barcode| barcode.pack_size)
+    }
+}
+
+pub fn scan_barcode(
+    ctx: &ServiceContext,
+    store_id: &str,
+    value: &str,
+) -> Result<ScannedBarcode, ScanBarcodeError> {
+    let today = Utc::now().naive_utc().date();
+    scan_barcode_on(ctx, store_id, value, today)
+}
+
+pub(crate) fn scan_barcode_on(
+    ctx: &ServiceContext,
+    store_id: &str,
+    value: &str,
+    today: NaiveDate,
+) -> Result<ScannedBarcode, ScanBarcodeError> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Err(ScanBarcodeError::EmptyBarcode);
+    }
+    let is_element_string = gs1::is_element_string(value);
+    let data = if is_element_string {
+        gs1::parse(value, today).map_err(ScanBarcodeError::InvalidGs1)?
+    } else {
+        Gs1Data {
+            gtin: Some(value)
+                .filter(|value| gs1::is_valid_gtin(value))
+                .map(gs1::to_gtin_14),
+            ..Gs1Data::default()
+        }
+    };
+
+    let mut lookup_values = Vec::new();
+    if !is_element_string {
+        lookup_values.push(value.to_string());
+    }
+    if let Some(gtin) = &data.gtin {
+        lookup_values.extend(gs1::gtin_forms(gtin));
+    }
+    // prefer the barcode stored in the scanned form
+    let barcode = BarcodeRowRepository::new(&ctx.connection)
+        .find_many_by_values(&lookup_values)?
+        .into_iter()
+        .min_by_key(|barcode| {
+            lookup_values
+                .iter()
+                .position(|lookup_value| lookup_value == &barcode.value)
+        });
+
+    let item = match &barcode {
+        Some(barcode) => find_item(
+            &ctx.connection,
+            store_id,
+            ItemFilter::new().id(EqualFilter::equal_to(&barcode.item_id)),
+        )?,
+        None if !is_element_string => find_item(
+            &ctx.connection,
+            store_id,
+            ItemFilter::new().code(SimpleStringFilter::equal_to(value)),
+        )?,
+        None => None,
+    };
+
+    Ok(ScannedBarcode {
+        value: value.to_string(),
+        gtin: data.gtin,
+        batch: data.batch,
+        expiry_date: data.expiry_date,
+        serial: data.serial,
+        barcode,
+        item,
+    })
+}
+
+impl From<RepositoryError> for ScanBarcodeError {
+    fn from(error: RepositoryError) -> Self {
+        ScanBarcodeError::DatabaseError(error)
+    }
+}
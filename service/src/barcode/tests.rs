@@ -0,0 +1,357 @@
+#[cfg(test)]
+mod barcode_test {
+    use chrono::NaiveDate;
+    use repository::{
+        mock::{mock_item_a, mock_item_b, mock_name_store_b, mock_store_a, MockDataInserts},
+        schema::BarcodeRow,
+        test_db::setup_all,
+        BarcodeRowRepository,
+    };
+
+    use crate::{
+        barcode::{
+            delete::DeleteBarcodeError,
+            gs1::{self, Gs1Data, Gs1Error, GROUP_SEPARATOR},
+            insert::{InsertBarcode, InsertBarcodeError},
+            scan::{scan_barcode_on, ScanBarcodeError},
+            update::{UpdateBarcode, UpdateBarcodeError},
+        },
+        service_provider::ServiceProvider,
+    };
+
+    fn today() -> NaiveDate {
+        NaiveDate::from_ymd(2021, 10, 1)
+    }
+
+    fn insert_input(id: &str, value: &str) -> InsertBarcode {
+        InsertBarcode {
+            id: id.to_string(),
+            value: value.to_string(),
+            item_id: mock_item_a().id,
+            manufacturer_id: None,
+            pack_size: None,
+        }
+    }
+
+    #[test]
+    fn parse_gs1() {
+        // GS1 DataMatrix with symbology identifier and group separator after the batch
+        assert_eq!(
+            gs1::parse(
+                &format!(
+                    "]d2010950110153000317250131{}10AB-123{}21S1",
+                    GROUP_SEPARATOR, GROUP_SEPARATOR
+                ),
+                today()
+            ),
+            Ok(Gs1Data {
+                gtin: Some("09501101530003".to_string()),
+                batch: Some("AB-123".to_string()),
+                expiry_date: Some(NaiveDate::from_ymd(2025, 1, 31)),
+                serial: Some("S1".to_string()),
+            })
+        );
+        // human readable form, day 00 is the last day of the month
+        assert_eq!(
+            gs1::parse("(01)09501101530003(17)240200(10)AB-123", today()),
+            Ok(Gs1Data {
+                gtin: Some("09501101530003".to_string()),
+                batch: Some("AB-123".to_string()),
+                expiry_date: Some(NaiveDate::from_ymd(2024, 2, 29)),
+                serial: None,
+            })
+        );
+        // years more than 49 years in the past are in the next century
+        assert_eq!(
+            gs1::parse("0109501101530003", NaiveDate::from_ymd(2060, 1, 1)).unwrap(),
+            gs1::parse("0109501101530003", today()).unwrap()
+        );
+        assert_eq!(
+            gs1::parse("010950110153000317700101", NaiveDate::from_ymd(2060, 1, 1))
+                .unwrap()
+                .expiry_date,
+            Some(NaiveDate::from_ymd(2070, 1, 1))
+        );
+        assert_eq!(
+            gs1::parse("010950110153000317991231", today())
+                .unwrap()
+                .expiry_date,
+            Some(NaiveDate::from_ymd(1999, 12, 31))
+        );
+
+        // errors
+        assert_eq!(
+            gs1::parse("0109501101530011", today()),
+            Err(Gs1Error::InvalidCheckDigit("01".to_string()))
+        );
+        assert_eq!(
+            gs1::parse("01095011015300", today()),
+            Err(Gs1Error::InvalidLength("01".to_string()))
+        );
+        assert_eq!(
+            gs1::parse("010950110153000317251301", today()),
+            Err(Gs1Error::InvalidDate("17".to_string()))
+        );
+        assert_eq!(
+            gs1::parse("010950110153000399ABC", today()),
+            Err(Gs1Error::UnknownApplicationIdentifier("99AB".to_string()))
+        );
+        assert_eq!(
+            gs1::parse("(01)09501101530003(10)", today()),
+            Err(Gs1Error::InvalidLength("10".to_string()))
+        );
+
+        assert!(gs1::is_valid_gtin("5012345678900"));
+        assert!(gs1::is_valid_gtin("96385074"));
+        assert!(!gs1::is_valid_gtin("5012345678901"));
+        assert!(!gs1::is_valid_gtin("item_a_code"));
+        assert_eq!(
+            gs1::gtin_forms("00012345600012"),
+            vec!["00012345600012", "0012345600012", "012345600012"]
+        );
+    }
+
+    #[actix_rt::test]
+    async fn scan_barcode() {
+        let (_, connection, connection_manager, _) =
+            setup_all("scan_barcode", MockDataInserts::all()).await;
+        let service_provider = ServiceProvider::new(connection_manager);
+        let context = service_provider.context().unwrap();
+        let repository = BarcodeRowRepository::new(&connection);
+        let ean_13 = BarcodeRow {
+            id: "ean_13".to_string(),
+            value: "9501101530003".to_string(),
+            item_id: mock_item_a().id,
+            manufacturer_id: Some(mock_name_store_b().id),
+            pack_size: Some(10),
+        };
+        repository.upsert_one(&ean_13).unwrap();
+        let store_id = mock_store_a().id;
+
+        // plain barcode
+        let scanned = scan_barcode_on(&context, &store_id, " 9501101530003\n", today()).unwrap();
+        assert_eq!(scanned.value, "9501101530003");
+        assert_eq!(scanned.gtin, Some("09501101530003".to_string()));
+        assert_eq!(scanned.barcode, Some(ean_13.clone()));
+        assert_eq!(scanned.item.unwrap().id, mock_item_a().id);
+        assert_eq!(scanned.batch, None);
+
+        // GS1 element string with the GTIN-14 of the stored EAN-13
+        let scanned = scan_barcode_on(
+            &context,
+            &store_id,
+            "]C1010950110153000317220630109876",
+            today(),
+        )
+        .unwrap();
+        assert_eq!(scanned.barcode, Some(ean_13.clone()));
+        assert_eq!(scanned.pack_size(), Some(10));
+        assert_eq!(scanned.item.unwrap().id, mock_item_a().id);
+        assert_eq!(scanned.batch, Some("9876".to_string()));
+        assert_eq!(scanned.expiry_date, Some(NaiveDate::from_ymd(2022, 6, 30)));
+
+        // the barcode stored in the scanned form is preferred
+        let gtin_14 = BarcodeRow {
+            id: "gtin_14".to_string(),
+            value: "09501101530003".to_string(),
+            item_id: mock_item_b().id,
+            manufacturer_id: None,
+            pack_size: None,
+        };
+        repository.upsert_one(&gtin_14).unwrap();
+        let scanned = scan_barcode_on(&context, &store_id, "09501101530003", today()).unwrap();
+        assert_eq!(scanned.barcode, Some(gtin_14));
+        assert_eq!(scanned.item.unwrap().id, mock_item_b().id);
+
+        // falls back to the item code
+        let scanned = scan_barcode_on(&context, &store_id, "item_a_code", today()).unwrap();
+        assert_eq!(scanned.barcode, None);
+        assert_eq!(scanned.gtin, None);
+        assert_eq!(scanned.item.unwrap().id, mock_item_a().id);
+
+        // unknown barcode
+        let scanned =
+            scan_barcode_on(&context, &store_id, "(01)05012345678900(10)A1", today()).unwrap();
+        assert_eq!(scanned.barcode, None);
+        assert_eq!(scanned.item, None);
+        assert_eq!(scanned.batch, Some("A1".to_string()));
+
+        // EmptyBarcode
+        assert_eq!(
+            scan_barcode_on(&context, &store_id, "  ", today()),
+            Err(ScanBarcodeError::EmptyBarcode)
+        );
+        // InvalidGs1
+        assert_eq!(
+            scan_barcode_on(&context, &store_id, "(01)05012345678901", today()),
+            Err(ScanBarcodeError::InvalidGs1(Gs1Error::InvalidCheckDigit(
+                "01".to_string()
+            )))
+        );
+    }
+
+    #[actix_rt::test]
+    async fn barcode_mutations() {
+        let (_, _, connection_manager, _) =
+            setup_all("barcode_mutations", MockDataInserts::all()).await;
+        let service_provider = ServiceProvider::new(connection_manager);
+        let context = service_provider.context().unwrap();
+        let service = &service_provider.barcode_service;
+
+        // insert
+        let inserted = service
+            .insert_barcode(
+                &context,
+                InsertBarcode {
+                    manufacturer_id: Some(mock_name_store_b().id),
+                    pack_size: Some(20),
+                    ..insert_input("barcode_1", " 5012345678900 ")
+                },
+            )
+            .unwrap();
+        assert_eq!(
+            inserted,
+            BarcodeRow {
+                id: "barcode_1".to_string(),
+                value: "5012345678900".to_string(),
+                item_id: mock_item_a().id,
+                manufacturer_id: Some(mock_name_store_b().id),
+                pack_size: Some(20),
+            }
+        );
+        service
+            .insert_barcode(&context, insert_input("barcode_2", "96385074"))
+            .unwrap();
+        assert_eq!(
+            service
+                .get_item_barcodes(&context, &mock_item_a().id)
+                .unwrap()
+                .len(),
+            2
+        );
+
+        // BarcodeAlreadyExists
+        assert_eq!(
+            service.insert_barcode(&context, insert_input("barcode_1", "1")),
+            Err(InsertBarcodeError::BarcodeAlreadyExists)
+        );
+        // EmptyValue
+        assert_eq!(
+            service.insert_barcode(&context, insert_input("barcode_3", " ")),
+            Err(InsertBarcodeError::EmptyValue)
+        );
+        // BarcodeWithValueAlreadyExists
+        assert_eq!(
+            service.insert_barcode(&context, insert_input("barcode_3", "5012345678900")),
+            Err(InsertBarcodeError::BarcodeWithValueAlreadyExists)
+        );
+        // ItemDoesNotExist
+        assert_eq!(
+            service.insert_barcode(
+                &context,
+                InsertBarcode {
+                    item_id: "invalid".to_string(),
+                    ..insert_input("barcode_3", "3")
+                }
+            ),
+            Err(InsertBarcodeError::ItemDoesNotExist)
+        );
+        // ManufacturerDoesNotExist
+        assert_eq!(
+            service.insert_barcode(
+                &context,
+                InsertBarcode {
+                    manufacturer_id: Some("invalid".to_string()),
+                    ..insert_input("barcode_3", "3")
+                }
+            ),
+            Err(InsertBarcodeError::ManufacturerDoesNotExist)
+        );
+        // PackSizeBelowOne
+        assert_eq!(
+            service.insert_barcode(
+                &context,
+                InsertBarcode {
+                    pack_size: Some(0),
+                    ..insert_input("barcode_3", "3")
+                }
+            ),
+            Err(InsertBarcodeError::PackSizeBelowOne)
+        );
+
+        // update
+        let update_input = |id: &str| UpdateBarcode {
+            id: id.to_string(),
+            value: None,
+            item_id: None,
+            manufacturer_id: None,
+            pack_size: None,
+        };
+        let updated = service
+            .update_barcode(
+                &context,
+                UpdateBarcode {
+                    item_id: Some(mock_item_b().id),
+                    pack_size: Some(5),
+                    ..update_input("barcode_1")
+                },
+            )
+            .unwrap();
+        assert_eq!(
+            updated,
+            BarcodeRow {
+                item_id: mock_item_b().id,
+                pack_size: Some(5),
+                ..inserted
+            }
+        );
+        // the value can be kept
+        service
+            .update_barcode(
+                &context,
+                UpdateBarcode {
+                    value: Some("5012345678900".to_string()),
+                    ..update_input("barcode_1")
+                },
+            )
+            .unwrap();
+
+        // BarcodeDoesNotExist
+        assert_eq!(
+            service.update_barcode(&context, update_input("invalid")),
+            Err(UpdateBarcodeError::BarcodeDoesNotExist)
+        );
+        // BarcodeWithValueAlreadyExists
+        assert_eq!(
+            service.update_barcode(
+                &context,
+                UpdateBarcode {
+                    value: Some("96385074".to_string()),
+                    ..update_input("barcode_1")
+                }
+            ),
+            Err(UpdateBarcodeError::BarcodeWithValueAlreadyExists)
+        );
+        // ItemDoesNotExist
+        assert_eq!(
+            service.update_barcode(
+                &context,
+                UpdateBarcode {
+                    item_id: Some("invalid".to_string()),
+                    ..update_input("barcode_1")
+                }
+            ),
+            Err(UpdateBarcodeError::ItemDoesNotExist)
+        );
+
+        // delete
+        assert_eq!(
+            service.delete_barcode(&context, "barcode_1"),
+            Ok("barcode_1".to_string())
+        );
+        assert_eq!(
+            service.delete_barcode(&context, "barcode_1"),
+            Err(DeleteBarcodeError::BarcodeDoesNotExist)
+        );
+    }
+}
@@ -0,0 +1,108 @@
+use repository::{schema::BarcodeRow, BarcodeRowRepository, RepositoryError, StorageConnection};
+
+use crate::service_provider::ServiceContext;
+
+use super::validate::{
+    check_barcode_exists, check_barcode_value_is_unique, check_item_exists,
+    check_manufacturer_exists,
+};
+
+/// Omitted fields are left unchanged
+#[derive(Clone, Debug, PartialEq)]
+pub struct UpdateBarcode {
+    pub id: String,
+    pub value: Option<String>,
+    pub item_id: Option<String>,
+    pub manufacturer_id: Option<String>,
+    pub pack_size: Option<i32>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum UpdateBarcodeError {
+    BarcodeDoesNotExist,
+    EmptyValue,
+    BarcodeWithValueAlreadyExists,
+    ItemDoesNotExist,
+    ManufacturerDoesNotExist,
+    PackSizeBelowOne,
+    DatabaseError(RepositoryError),
+}
+
+pub fn update_barcode(
+    ctx: &ServiceContext,
+    input: UpdateBarcode,
+) -> Result<BarcodeRow, UpdateBarcodeError> {
+    let barcode = ctx
+        .connection
+        .transaction_sync(|connection| {
+            let existing = validate(&input, connection)?;
+            let updated_barcode = generate(existing, input);
+            BarcodeRowRepository::new(connection).upsert_one(&updated_barcode)?;
+            Ok::<BarcodeRow, UpdateBarcodeError>(updated_barcode)
+        })
+        .map_err(|error| error.to_inner_error())?;
+    Ok(barcode)
+}
+
+fn validate(
+    input: &UpdateBarcode,
+    connection: &StorageConnection,
+) -> Result<BarcodeRow, UpdateBarcodeError> {
+    let existing = match check_barcode_exists(&input.id, connection)? {
+        Some(existing) => existing,
+        None => return Err(UpdateBarcodeError::BarcodeDoesNotExist),
+    };
+    if let Some(value) = &input.value {
+        if value.trim().is_empty() {
+            return Err(UpdateBarcodeError::EmptyValue);
+        }
+        if !check_barcode_value_is_unique(&input.id, value.trim(), connection)? {
+            return Err(UpdateBarcodeError::BarcodeWithValueAlreadyExists);
+        }
+    }
+    if let Some(item_id) = &input.item_id {
+        if !check_item_exists(item_id, connection)? {
+            return Err(UpdateBarcodeError::ItemDoesNotExist);
+        }
+    }
+    if let Some(manufacturer_id) = &input.manufacturer_id {
+        if !check_manufacturer_exists(manufacturer_id, connection)? {
+            return Err(UpdateBarcodeError::ManufacturerDoesNotExist);
+        }
+    }
+    if input.pack_size.filter(|pack_size| *pack_size < 1).is_some() {
+        return Err(UpdateBarcodeError::PackSizeBelowOne);
+    }
+    Ok(existing)
+}
+
+fn generate(
+    mut barcode: BarcodeRow,
+    UpdateBarcode {
+        id: _,
+        value,
+        item_id,
+        manufacturer_id,
+        pack_size,
+    }: UpdateBarcode,
+) -> BarcodeRow {
+    if let Some(value) = value {
+        barcode.value = value.trim().to_string();
+    }
+    if let Some(item_id) = item_id {
+        barcode.item_id = item_id;
+    }
+    if manufacturer_id.is_some() {
+        barcode.manufacturer_id = manufacturer_id;
+    }
+    if pack_size.is_some() {
+        barcode.pack_size = pack_size;
+    }
+    barcode
+}
+
+impl From<RepositoryError> for UpdateBarcodeError {
+    fn from(error: RepositoryError) -> Self {
+        UpdateBarcodeError::DatabaseError(error)
+    }
+}
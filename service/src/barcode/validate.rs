@@ -0,0 +1,40 @@
+use repository::{
+    schema::BarcodeRow, BarcodeRowRepository, ItemRepository, NameRepository, RepositoryError,
+    StorageConnection,
+};
+
+pub fn check_barcode_exists(
+    id: &str,
+    connection: &StorageConnection,
+) -> Result<Option<BarcodeRow>, RepositoryError> {
+    BarcodeRowRepository::new(connection).find_one_by_id(id)
+}
+
+/// Barcode values are unique, the same value can't refer to different items or pack sizes
+pub fn check_barcode_value_is_unique(
+    id: &str,
+    value: &str,
+    connection: &StorageConnection,
+) -> Result<bool, RepositoryError> {
+    let barcodes =
+        BarcodeRowRepository::new(connection).find_many_by_values(&[value.to_string()])?;
+    Ok(barcodes.iter().all(|barcode| barcode.id == id))
+}
+
+pub fn check_item_exists(
+    item_id: &str,
+    connection: &StorageConnection,
+) -> Result<bool, RepositoryError> {
+    Ok(ItemRepository::new(connection)
+        .find_one_by_id(item_id)?
+        .is_some())
+}
+
+pub fn check_manufacturer_exists(
+    manufacturer_id: &str,
+    connection: &StorageConnection,
+) -> Result<bool, RepositoryError> {
+    Ok(!NameRepository::new(connection)
+        .find_many_by_id(&[manufacturer_id.to_string()])?
+        .is_empty())
+}
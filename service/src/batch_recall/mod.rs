@@ -0,0 +1,52 @@
+use repository::{schema::BatchRecallRow, RepositoryError};
+
+use crate::service_provider::ServiceContext;
+
+use self::{
+    recall::{recall_batch, RecallBatch},
+    trace::{get_batch_recall_trace, BatchRecallSelection, BatchRecallTrace},
+};
+
+pub mod recall;
+pub mod trace;
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Debug, PartialEq)]
+pub enum BatchRecallError {
+    DatabaseError(RepositoryError),
+    /// Neither item and batch nor stock line ids were provided
+    MissingBatchOrStockLines,
+    StockLineDoesNotExist(String),
+    EmptyReference,
+}
+
+impl From<RepositoryError> for BatchRecallError {
+    fn from(error: RepositoryError) -> Self {
+        BatchRecallError::DatabaseError(error)
+    }
+}
+
+pub trait BatchRecallServiceTrait: Sync + Send {
+    fn get_batch_recall_trace(
+        &self,
+        ctx: &ServiceContext,
+        store_id: &str,
+        selection: BatchRecallSelection,
+    ) -> Result<BatchRecallTrace, BatchRecallError> {
+        get_batch_recall_trace(ctx, store_id, selection)
+    }
+
+    fn recall_batch(
+        &self,
+        ctx: &ServiceContext,
+        store_id: &str,
+        input: RecallBatch,
+    ) -> Result<BatchRecallRow, BatchRecallError> {
+        recall_batch(ctx, store_id, input)
+    }
+}
+
+pub struct BatchRecallService {}
+impl BatchRecallServiceTrait for BatchRecallService {}
@@ -0,0 +1,115 @@
+use chrono::Utc;
+use domain::stock_line::StockLine;
+use repository::{
+    schema::{BatchRecallLineRow, BatchRecallRow},
+    BatchRecallLineRowRepository, BatchRecallRowRepository, StockLineRowRepository,
+    StorageConnection,
+};
+use util::uuid::uuid;
+
+use crate::service_provider::ServiceContext;
+
+use super::{
+    trace::{find_stock_lines, BatchRecallSelection},
+    BatchRecallError,
+};
+
+pub struct RecallBatch {
+    /// Recall reference of the manufacturer or regulator
+    pub reference: String,
+    pub selection: BatchRecallSelection,
+}
+
+/// Puts the remaining local stock of a batch on hold and records the recall
+pub fn recall_batch(
+    ctx: &ServiceContext,
+    store_id: &str,
+    input: RecallBatch,
+) -> Result<BatchRecallRow, BatchRecallError> {
+    let recall = ctx
+        .connection
+        .transaction_sync(|connection| -> Result<BatchRecallRow, BatchRecallError> {
+            let stock_lines = validate(connection, store_id, &input)?;
+            let (recall, lines) = generate(store_id, input, &stock_lines);
+
+            BatchRecallRowRepository::new(connection).upsert_one(&recall)?;
+            let line_repository = BatchRecallLineRowRepository::new(connection);
+            for line in lines {
+                line_repository.upsert_one(&line)?;
+            }
+            let stock_line_repository = StockLineRowRepository::new(connection);
+            let ids: Vec<String> = stock_lines.into_iter().map(|line| line.id).collect();
+            for mut row in stock_line_repository.find_many_by_ids(&ids)? {
+                row.on_hold = true;
+                stock_line_repository.upsert_one(&row)?;
+            }
+            Ok(recall)
+        })
+        .map_err(|error| error.to_inner_error())?;
+    Ok(recall)
+}
+
+fn validate(
+    connection: &StorageConnection,
+    store_id: &str,
+    input: &RecallBatch,
+) -> Result<Vec<StockLine>, BatchRecallError> {
+    if input.reference.trim().is_empty() {
+        return Err(BatchRecallError::EmptyReference);
+    }
+    let stock_lines = find_stock_lines(connection, store_id, &input.selection)?;
+    if let Some(ids) = &input.selection.stock_line_ids {
+        if let Some(id) = ids
+            .iter()
+            .find(|id| !stock_lines.iter().any(|line| &line.id == *id))
+        {
+            return Err(BatchRecallError::StockLineDoesNotExist(id.clone()));
+        }
+    }
+
+    Ok(stock_lines
+        .into_iter()
+        .filter(|line| line.total_number_of_packs > 0)
+        .collect())
+}
+
+fn generate(
+    store_id: &str,
+    RecallBatch {
+        reference,
+        selection,
+    }: RecallBatch,
+    stock_lines: &[StockLine],
+) -> (BatchRecallRow, Vec<BatchRecallLineRow>) {
+    // stock lines selected by id are usually of a single item and batch
+    let common = |value: fn(&StockLine) -> Option<String>| {
+        let first = stock_lines.first().and_then(value);
+        if stock_lines.iter().all(|line| value(line) == first) {
+            first
+        } else {
+            None
+        }
+    };
+    let recall = BatchRecallRow {
+        id: uuid(),
+        store_id: store_id.to_string(),
+        reference: reference.trim().to_string(),
+        item_id: selection
+            .item_id
+            .or_else(|| common(|line| Some(line.item_id.clone()))),
+        batch: selection
+            .batch
+            .or_else(|| common(|line| line.batch.clone())),
+        created_datetime: Utc::now().naive_utc(),
+    };
+    let lines = stock_lines
+        .iter()
+        .map(|stock_line| BatchRecallLineRow {
+            id: uuid(),
+            batch_recall_id: recall.id.clone(),
+            stock_line_id: stock_line.id.clone(),
+            number_of_packs: stock_line.total_number_of_packs,
+        })
+        .collect();
+    (recall, lines)
+}
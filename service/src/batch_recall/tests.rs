@@ -0,0 +1,297 @@
+#[cfg(test)]
+mod batch_recall_test {
+    use chrono::{NaiveDate, NaiveDateTime};
+    use repository::{
+        mock::{mock_name_store_a, mock_name_store_b, mock_store_a, MockDataInserts},
+        schema::{
+            InvoiceLineRow, InvoiceLineRowType, InvoiceRow, InvoiceRowStatus, InvoiceRowType,
+            StockLineRow,
+        },
+        test_db::setup_all,
+        BatchRecallLineRowRepository, InvoiceLineRowRepository, InvoiceRepository,
+        StockLineRowRepository, StorageConnection,
+    };
+
+    use crate::{
+        batch_recall::{recall::RecallBatch, trace::BatchRecallSelection, BatchRecallError},
+        service_provider::ServiceProvider,
+    };
+
+    fn datetime(day: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd(2021, 4, day).and_hms(9, 0, 0)
+    }
+
+    fn insert_stock_line(connection: &StorageConnection, id: &str, batch: &str, packs: i32) {
+        StockLineRowRepository::new(connection)
+            .upsert_one(&StockLineRow {
+                id: id.to_string(),
+                item_id: "item_query_test2".to_string(),
+                store_id: mock_store_a().id,
+                location_id: None,
+                batch: Some(batch.to_string()),
+                pack_size: 10,
+                cost_price_per_pack: 0.0,
+                sell_price_per_pack: 0.0,
+                available_number_of_packs: packs,
+                total_number_of_packs: packs,
+                expiry_date: None,
+                on_hold: false,
+                note: None,
+            })
+            .unwrap();
+    }
+
+    fn insert_invoice(
+        connection: &StorageConnection,
+        id: &str,
+        r#type: InvoiceRowType,
+        name_id: String,
+        day: u32,
+        lines: Vec<(Option<&str>, i32)>,
+    ) {
+        let (status, line_type) = match r#type {
+            InvoiceRowType::InboundShipment => {
+                (InvoiceRowStatus::Delivered, InvoiceLineRowType::StockIn)
+            }
+            _ => (InvoiceRowStatus::Shipped, InvoiceLineRowType::StockOut),
+        };
+        InvoiceRepository::new(connection)
+            .upsert_one(&InvoiceRow {
+                id: id.to_string(),
+                name_id,
+                store_id: mock_store_a().id,
+                invoice_number: 1,
+                r#type,
+                status,
+                on_hold: false,
+                comment: None,
+                their_reference: None,
+                created_datetime: datetime(day),
+                allocated_datetime: None,
+                picked_datetime: Some(datetime(day)),
+                shipped_datetime: Some(datetime(day)),
+                delivered_datetime: Some(datetime(day)),
+                verified_datetime: None,
+                color: None,
+                prescriber_id: None,
+            })
+            .unwrap();
+        for (index, (stock_line_id, number_of_packs)) in lines.into_iter().enumerate() {
+            InvoiceLineRowRepository::new(connection)
+                .upsert_one(&InvoiceLineRow {
+                    id: format!("{}_line{}", id, index),
+                    invoice_id: id.to_string(),
+                    item_id: "item_query_test2".to_string(),
+                    item_name: "".to_string(),
+                    item_code: "".to_string(),
+                    stock_line_id: stock_line_id.map(|id| id.to_string()),
+                    location_id: None,
+                    batch: Some("recall_batch".to_string()),
+                    expiry_date: None,
+                    pack_size: 10,
+                    cost_price_per_pack: 0.0,
+                    sell_price_per_pack: 0.0,
+                    total_before_tax: 0.0,
+                    total_after_tax: 0.0,
+                    tax: None,
+                    r#type: line_type.clone(),
+                    number_of_packs,
+                    note: None,
+                    return_reason_id: None,
+                    directions: None,
+                })
+                .unwrap();
+        }
+    }
+
+    fn insert_batch_history(connection: &StorageConnection) {
+        insert_stock_line(connection, "recall_line", "recall_batch", 4);
+        insert_stock_line(connection, "recall_other_batch", "other_batch", 7);
+        insert_invoice(
+            connection,
+            "recall_inbound",
+            InvoiceRowType::InboundShipment,
+            mock_name_store_a().id,
+            1,
+            vec![(Some("recall_line"), 10)],
+        );
+        insert_invoice(
+            connection,
+            "recall_outbound_1",
+            InvoiceRowType::OutboundShipment,
+            mock_name_store_b().id,
+            3,
+            vec![(Some("recall_line"), 3), (Some("recall_line"), 1)],
+        );
+        insert_invoice(
+            connection,
+            "recall_outbound_2",
+            InvoiceRowType::OutboundShipment,
+            mock_name_store_b().id,
+            5,
+            vec![(Some("recall_line"), 2)],
+        );
+        // not linked to a stock line, found by item and batch
+        insert_invoice(
+            connection,
+            "recall_outbound_3",
+            InvoiceRowType::OutboundShipment,
+            mock_name_store_a().id,
+            6,
+            vec![(None, 1)],
+        );
+    }
+
+    #[actix_rt::test]
+    async fn batch_recall_trace() {
+        let (_, connection, connection_manager, _) =
+            setup_all("batch_recall_trace", MockDataInserts::all()).await;
+
+        let service_provider = ServiceProvider::new(connection_manager);
+        let context = service_provider.context().unwrap();
+        let service = service_provider.batch_recall_service;
+        insert_batch_history(&connection);
+
+        assert_eq!(
+            service.get_batch_recall_trace(
+                &context,
+                &mock_store_a().id,
+                BatchRecallSelection {
+                    item_id: Some("item_query_test2".to_string()),
+                    ..Default::default()
+                }
+            ),
+            Err(BatchRecallError::MissingBatchOrStockLines)
+        );
+
+        let trace = service
+            .get_batch_recall_trace(
+                &context,
+                &mock_store_a().id,
+                BatchRecallSelection {
+                    item_id: Some("item_query_test2".to_string()),
+                    batch: Some("recall_batch".to_string()),
+                    stock_line_ids: None,
+                },
+            )
+            .unwrap();
+        assert_eq!(trace.stock_lines.len(), 1);
+        assert_eq!(trace.receipts.len(), 1);
+        assert_eq!(trace.issues.len(), 4);
+        assert_eq!(trace.units_received, 100);
+        assert_eq!(trace.units_issued, 70);
+        assert_eq!(trace.units_on_hand, 40);
+        assert_eq!(trace.customers.len(), 2);
+        assert_eq!(trace.customers[0].units, 10);
+        let customer = &trace.customers[1];
+        assert_eq!(customer.name_id, mock_name_store_b().id);
+        assert_eq!(customer.units, 60);
+        assert_eq!(customer.number_of_shipments, 2);
+        assert_eq!(customer.first_shipped_datetime, datetime(3));
+        assert_eq!(customer.last_shipped_datetime, datetime(5));
+
+        // by stock line only lines linked to the stock line are found
+        let trace = service
+            .get_batch_recall_trace(
+                &context,
+                &mock_store_a().id,
+                BatchRecallSelection {
+                    stock_line_ids: Some(vec!["recall_line".to_string()]),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        assert_eq!(trace.issues.len(), 3);
+        assert_eq!(trace.units_issued, 60);
+    }
+
+    #[actix_rt::test]
+    async fn recall_batch() {
+        let (_, connection, connection_manager, _) =
+            setup_all("recall_batch", MockDataInserts::all()).await;
+
+        let service_provider = ServiceProvider::new(connection_manager);
+        let context = service_provider.context().unwrap();
+        let service = service_provider.batch_recall_service;
+        insert_batch_history(&connection);
+
+        assert_eq!(
+            service.recall_batch(
+                &context,
+                &mock_store_a().id,
+                RecallBatch {
+                    reference: " ".to_string(),
+                    selection: BatchRecallSelection {
+                        stock_line_ids: Some(vec!["recall_line".to_string()]),
+                        ..Default::default()
+                    },
+                }
+            ),
+            Err(BatchRecallError::EmptyReference)
+        );
+        assert_eq!(
+            service.recall_batch(
+                &context,
+                &mock_store_a().id,
+                RecallBatch {
+                    reference: "FSN-1".to_string(),
+                    selection: BatchRecallSelection {
+                        stock_line_ids: Some(vec![
+                            "recall_line".to_string(),
+                            "item_b_line_a".to_string()
+                        ]),
+                        ..Default::default()
+                    },
+                }
+            ),
+            Err(BatchRecallError::StockLineDoesNotExist(
+                "item_b_line_a".to_string()
+            ))
+        );
+
+        let recall = service
+            .recall_batch(
+                &context,
+                &mock_store_a().id,
+                RecallBatch {
+                    reference: "FSN-1".to_string(),
+                    selection: BatchRecallSelection {
+                        stock_line_ids: Some(vec!["recall_line".to_string()]),
+                        ..Default::default()
+                    },
+                },
+            )
+            .unwrap();
+        assert_eq!(recall.reference, "FSN-1");
+        assert_eq!(recall.item_id, Some("item_query_test2".to_string()));
+        assert_eq!(recall.batch, Some("recall_batch".to_string()));
+
+        let lines = BatchRecallLineRowRepository::new(&connection)
+            .find_many_by_batch_recall_id(&recall.id)
+            .unwrap();
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0].number_of_packs, 4);
+        let stock_line = StockLineRowRepository::new(&connection)
+            .find_one_by_id("recall_line")
+            .unwrap();
+        assert!(stock_line.on_hold);
+        let stock_line = StockLineRowRepository::new(&connection)
+            .find_one_by_id("recall_other_batch")
+            .unwrap();
+        assert!(!stock_line.on_hold);
+
+        // recall shows up in the trace
+        let trace = service
+            .get_batch_recall_trace(
+                &context,
+                &mock_store_a().id,
+                BatchRecallSelection {
+                    item_id: Some("item_query_test2".to_string()),
+                    batch: Some("recall_batch".to_string()),
+                    stock_line_ids: None,
+                },
+            )
+            .unwrap();
+        assert_eq!(trace.recalls, vec![recall]);
+    }
+}
@@ -0,0 +1,200 @@
+use std::collections::{BTreeMap, HashSet};
+
+use chrono::NaiveDateTime;
+use domain::{
+    stock_line::{StockLine, StockLineFilter},
+    EqualFilter, Pagination,
+};
+use repository::{
+    schema::{BatchRecallRow, InvoiceRowType, StockMovementRow},
+    BatchRecallRowRepository, RepositoryError, StockLineRepository, StockMovementFilter,
+    StockMovementRepository, StorageConnection,
+};
+
+use crate::service_provider::ServiceContext;
+
+use super::BatchRecallError;
+
+/// Identifies the recalled stock, either by item and batch or by stock line ids
+#[derive(Clone, Debug, Default)]
+pub struct BatchRecallSelection {
+    pub item_id: Option<String>,
+    pub batch: Option<String>,
+    pub stock_line_ids: Option<Vec<String>>,
+}
+
+/// Stock of the recalled batch shipped to a customer
+#[derive(Clone, Debug, PartialEq)]
+pub struct BatchRecallCustomer {
+    pub name_id: String,
+    pub name: String,
+    /// Units shipped (number of packs * pack size)
+    pub units: i64,
+    pub number_of_shipments: i64,
+    pub first_shipped_datetime: NaiveDateTime,
+    pub last_shipped_datetime: NaiveDateTime,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct BatchRecallTrace {
+    /// Local stock lines of the batch
+    pub stock_lines: Vec<StockLine>,
+    /// Inbound shipment lines that received the batch
+    pub receipts: Vec<StockMovementRow>,
+    /// Outbound shipment and prescription lines that issued the batch, picked or later
+    pub issues: Vec<StockMovementRow>,
+    /// Inventory adjustments and repacks of the batch
+    pub adjustments: Vec<StockMovementRow>,
+    /// Supplier and customer returns of the batch
+    pub returns: Vec<StockMovementRow>,
+    /// Customers and patients that received the batch, ordered by name
+    pub customers: Vec<BatchRecallCustomer>,
+    pub units_received: i64,
+    pub units_issued: i64,
+    pub units_on_hand: i64,
+    /// Recalls previously recorded for the stock lines
+    pub recalls: Vec<BatchRecallRow>,
+}
+
+/// Follows a batch through the store: where it came from, who it went to and what's left
+pub fn get_batch_recall_trace(
+    ctx: &ServiceContext,
+    store_id: &str,
+    selection: BatchRecallSelection,
+) -> Result<BatchRecallTrace, BatchRecallError> {
+    let connection = &ctx.connection;
+    let stock_lines = find_stock_lines(connection, store_id, &selection)?;
+    let stock_line_ids: Vec<String> = stock_lines.iter().map(|line| line.id.clone()).collect();
+    let movements = find_movements(connection, store_id, &selection, &stock_line_ids)?;
+
+    let mut receipts = Vec::new();
+    let mut issues = Vec::new();
+    let mut adjustments = Vec::new();
+    let mut returns = Vec::new();
+    for movement in movements {
+        match movement.invoice_type {
+            InvoiceRowType::InboundShipment => receipts.push(movement),
+            InvoiceRowType::OutboundShipment | InvoiceRowType::Prescription => {
+                issues.push(movement)
+            }
+            InvoiceRowType::InventoryAdjustment
+            | InvoiceRowType::Repack
+            | InvoiceRowType::LocationMovement => adjustments.push(movement),
+            InvoiceRowType::SupplierReturn | InvoiceRowType::CustomerReturn => {
+                returns.push(movement)
+            }
+        }
+    }
+
+    let units_received = receipts.iter().map(units).sum();
+    let units_issued = -issues.iter().map(units).sum::<i64>();
+    let units_on_hand = stock_lines
+        .iter()
+        .map(|line| line.total_number_of_packs as i64 * line.pack_size as i64)
+        .sum();
+    let customers = customers(&issues);
+    let recalls = BatchRecallRowRepository::new(connection)
+        .find_many_by_stock_line_ids(store_id, &stock_line_ids)?;
+
+    Ok(BatchRecallTrace {
+        stock_lines,
+        receipts,
+        issues,
+        adjustments,
+        returns,
+        customers,
+        units_received,
+        units_issued,
+        units_on_hand,
+        recalls,
+    })
+}
+
+/// Local stock lines of the selection
+pub fn find_stock_lines(
+    connection: &StorageConnection,
+    store_id: &str,
+    selection: &BatchRecallSelection,
+) -> Result<Vec<StockLine>, BatchRecallError> {
+    let filter = StockLineFilter::new().store_id(EqualFilter::equal_to(store_id));
+    let filter = match selection {
+        BatchRecallSelection {
+            stock_line_ids: Some(ids),
+            ..
+        } if !ids.is_empty() => filter.id(EqualFilter::equal_any(ids.clone())),
+        BatchRecallSelection {
+            item_id: Some(item_id),
+            batch: Some(batch),
+            ..
+        } => filter
+            .item_id(EqualFilter::equal_to(item_id))
+            .batch(EqualFilter::equal_to(batch)),
+        _ => return Err(BatchRecallError::MissingBatchOrStockLines),
+    };
+    Ok(StockLineRepository::new(connection).query(Pagination::all(), Some(filter), None)?)
+}
+
+fn find_movements(
+    connection: &StorageConnection,
+    store_id: &str,
+    selection: &BatchRecallSelection,
+    stock_line_ids: &[String],
+) -> Result<Vec<StockMovementRow>, RepositoryError> {
+    let repository = StockMovementRepository::new(connection);
+    let filter = StockMovementFilter::new().store_id(EqualFilter::equal_to(store_id));
+    let mut movements = repository.query_by_filter(
+        filter
+            .clone()
+            .stock_line_id(EqualFilter::equal_any(stock_line_ids.to_vec())),
+    )?;
+
+    // lines of the batch that aren't linked to a local stock line, e.g. if the stock line was
+    // deleted
+    if let (Some(item_id), Some(batch)) = (&selection.item_id, &selection.batch) {
+        let ids: HashSet<String> = movements.iter().map(|m| m.id.clone()).collect();
+        let by_batch = repository.query_by_filter(
+            filter
+                .item_id(EqualFilter::equal_to(item_id))
+                .batch(EqualFilter::equal_to(batch)),
+        )?;
+        movements.extend(by_batch.into_iter().filter(|m| !ids.contains(&m.id)));
+        movements.sort_by(|a, b| (a.datetime, &a.id).cmp(&(b.datetime, &b.id)));
+    }
+
+    Ok(movements)
+}
+
+fn customers(issues: &[StockMovementRow]) -> Vec<BatchRecallCustomer> {
+    let mut customers: BTreeMap<(String, String), (BatchRecallCustomer, HashSet<String>)> =
+        BTreeMap::new();
+    for issue in issues {
+        let (customer, invoice_ids) = customers
+            .entry((issue.other_party_name.clone(), issue.other_party_id.clone()))
+            .or_insert_with(|| {
+                (
+                    BatchRecallCustomer {
+                        name_id: issue.other_party_id.clone(),
+                        name: issue.other_party_name.clone(),
+                        units: 0,
+                        number_of_shipments: 0,
+                        first_shipped_datetime: issue.datetime,
+                        last_shipped_datetime: issue.datetime,
+                    },
+                    HashSet::new(),
+                )
+            });
+        customer.units -= units(issue);
+        customer.first_shipped_datetime = customer.first_shipped_datetime.min(issue.datetime);
+        customer.last_shipped_datetime = customer.last_shipped_datetime.max(issue.datetime);
+        invoice_ids.insert(issue.invoice_id.clone());
+        customer.number_of_shipments = invoice_ids.len() as i64;
+    }
+    customers
+        .into_values()
+        .map(|(customer, _)| customer)
+        .collect()
+}
+
+fn units(movement: &StockMovementRow) -> i64 {
+    movement.number_of_packs as i64 * movement.pack_size as i64
+}
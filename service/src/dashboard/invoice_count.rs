@@ -244,7 +244,10 @@ mod invoice_count_service_test {
             MockDataInserts::all(),
         )
         .await;
-        let ctx = ServiceContext { connection };
+        let ctx = ServiceContext {
+            connection,
+            plugins: Default::default(),
+        };
         let service = InvoiceCountService {};
 
         // There are two invoice created at:
@@ -7,7 +7,7 @@ use repository::{
 };
 use util::uuid::uuid;
 
-use crate::current_store_id;
+use crate::invoice_line::generate_batch;
 
 use super::UpdateInboundShipmentError;
 
@@ -89,47 +89,10 @@ pub fn generate_lines_and_stock_lines(
     let lines = InvoiceLineRowRepository::new(connection).find_many_by_invoice_id(id)?;
     let mut result = Vec::new();
 
-    for invoice_lines in lines.into_iter() {
-        let stock_line_id = uuid();
-        let mut line = invoice_lines.clone();
-        line.stock_line_id = Some(stock_line_id.clone());
-
-        let InvoiceLineRow {
-            id: _,
-            invoice_id: _,
-            item_id,
-            item_name: _,
-            item_code: _,
-            stock_line_id: _,
-            location_id,
-            batch,
-            expiry_date,
-            pack_size,
-            cost_price_per_pack,
-            sell_price_per_pack,
-            total_before_tax: _,
-            total_after_tax: _,
-            tax: _,
-            r#type: _,
-            number_of_packs,
-            note,
-        }: InvoiceLineRow = invoice_lines;
-
-        let stock_line = StockLineRow {
-            id: stock_line_id,
-            item_id,
-            store_id: current_store_id(connection)?,
-            location_id,
-            batch,
-            pack_size,
-            cost_price_per_pack,
-            sell_price_per_pack,
-            available_number_of_packs: number_of_packs,
-            total_number_of_packs: number_of_packs,
-            expiry_date,
-            on_hold: false,
-            note,
-        };
+    for mut line in lines.into_iter() {
+        line.stock_line_id = Some(uuid());
+
+        let stock_line = generate_batch(&mut line, true, connection)?;
 
         result.push(LineAndStockLine { line, stock_line });
     }
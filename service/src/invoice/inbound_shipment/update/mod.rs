@@ -1,4 +1,7 @@
-use crate::WithDBError;
+use crate::{
+    plugin::{PluginHost, PluginRejection},
+    WithDBError,
+};
 use domain::{inbound_shipment::UpdateInboundShipment, name::Name};
 use repository::{
     InvoiceLineRowRepository, InvoiceRepository, RepositoryError, StockLineRowRepository,
@@ -15,13 +18,22 @@ use self::generate::LineAndStockLine;
 
 pub fn update_inbound_shipment(
     connection: &StorageConnection,
+    plugins: &PluginHost,
     patch: UpdateInboundShipment,
 ) -> Result<String, UpdateInboundShipmentError> {
-    let update_invoice = connection
+    let (update_invoice, previous_status) = connection
         .transaction_sync(|connection| {
             let invoice = validate(&patch, &connection)?;
+            let existing = invoice.clone();
             let (lines_and_invoice_lines_option, update_invoice) =
                 generate(invoice, patch, &connection)?;
+            if update_invoice.status != existing.status {
+                plugins.before_invoice_status_change(
+                    connection,
+                    &existing,
+                    &update_invoice.status,
+                )?;
+            }
 
             InvoiceRepository::new(&connection).upsert_one(&update_invoice)?;
 
@@ -34,7 +46,7 @@ pub fn update_inbound_shipment(
                     invoice_line_respository.upsert_one(&line)?;
                 }
             }
-            Ok(update_invoice)
+            Ok((update_invoice, existing.status))
         })
         .map_err(
             |error: TransactionError<UpdateInboundShipmentError>| match error {
@@ -44,6 +56,10 @@ pub fn update_inbound_shipment(
                 TransactionError::Inner(error) => error,
             },
         )?;
+
+    if update_invoice.status != previous_status {
+        plugins.after_invoice_status_change(connection, &update_invoice, &previous_status);
+    }
     Ok(update_invoice.id)
 }
 
@@ -58,6 +74,7 @@ pub enum UpdateInboundShipmentError {
     CannotReverseInvoiceStatus,
     CannotEditFinalised,
     CannotChangeStatusOfInvoiceOnHold,
+    RejectedByPlugin(PluginRejection),
 }
 
 impl From<RepositoryError> for UpdateInboundShipmentError {
@@ -66,6 +83,12 @@ impl From<RepositoryError> for UpdateInboundShipmentError {
     }
 }
 
+impl From<PluginRejection> for UpdateInboundShipmentError {
+    fn from(rejection: PluginRejection) -> Self {
+        UpdateInboundShipmentError::RejectedByPlugin(rejection)
+    }
+}
+
 impl<ERR> From<WithDBError<ERR>> for UpdateInboundShipmentError
 where
     ERR: Into<UpdateInboundShipmentError>,
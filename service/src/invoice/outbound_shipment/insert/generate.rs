@@ -36,6 +36,7 @@ pub fn generate(
         shipped_datetime: None,
         delivered_datetime: None,
         verified_datetime: None,
+        prescriber_id: None,
     };
 
     Ok(result)
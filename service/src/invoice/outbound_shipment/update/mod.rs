@@ -1,8 +1,10 @@
-use domain::{name::Name, outbound_shipment::UpdateOutboundShipment, invoice_line::InvoiceLine};
+use domain::{invoice_line::InvoiceLine, name::Name, outbound_shipment::UpdateOutboundShipment};
 use repository::{
     InvoiceRepository, RepositoryError, StockLineRowRepository, StorageConnection, TransactionError,
 };
 
+use crate::plugin::{PluginHost, PluginRejection};
+
 pub mod generate;
 pub mod validate;
 
@@ -11,12 +13,16 @@ use validate::validate;
 
 pub fn update_outbound_shipment(
     connection: &StorageConnection,
+    plugins: &PluginHost,
     patch: UpdateOutboundShipment,
 ) -> Result<String, UpdateOutboundShipmentError> {
-    let updated_invoice_id = connection.transaction_sync(|connection| {
+    let (update_invoice, previous_status) = connection.transaction_sync(|connection| {
         let invoice = validate(&patch, &connection)?;
-        let invoice_id = invoice.id.to_owned();
+        let existing = invoice.clone();
         let (stock_lines_option, update_invoice) = generate(invoice, patch, &connection)?;
+        if update_invoice.status != existing.status {
+            plugins.before_invoice_status_change(connection, &existing, &update_invoice.status)?;
+        }
 
         InvoiceRepository::new(&connection).upsert_one(&update_invoice)?;
         if let Some(stock_lines) = stock_lines_option {
@@ -25,10 +31,13 @@ pub fn update_outbound_shipment(
                 repository.upsert_one(&stock_line)?;
             }
         }
-        Ok(invoice_id)
+        Ok((update_invoice, existing.status))
     })?;
 
-    Ok(updated_invoice_id)
+    if update_invoice.status != previous_status {
+        plugins.after_invoice_status_change(connection, &update_invoice, &previous_status);
+    }
+    Ok(update_invoice.id)
 }
 
 #[derive(Debug)]
@@ -45,6 +54,7 @@ pub enum UpdateOutboundShipmentError {
     CanOnlyChangeToAllocatedWhenNoUnallocatedLines(Vec<InvoiceLine>),
     /// Holds the id of the invalid invoice line
     InvoiceLineHasNoStockLine(String),
+    RejectedByPlugin(PluginRejection),
 }
 
 impl From<RepositoryError> for UpdateOutboundShipmentError {
@@ -53,6 +63,12 @@ impl From<RepositoryError> for UpdateOutboundShipmentError {
     }
 }
 
+impl From<PluginRejection> for UpdateOutboundShipmentError {
+    fn from(rejection: PluginRejection) -> Self {
+        UpdateOutboundShipmentError::RejectedByPlugin(rejection)
+    }
+}
+
 impl From<TransactionError<UpdateOutboundShipmentError>> for UpdateOutboundShipmentError {
     fn from(error: TransactionError<UpdateOutboundShipmentError>) -> Self {
         match error {
@@ -44,7 +44,35 @@ pub fn check_invoice_is_editable(invoice: &InvoiceRow) -> Result<(), InvoiceIsNo
             InvoiceStatus::Picked => false,
             InvoiceStatus::Verified => false,
         },
+        // lines of returns can only be changed while new, see returns::validate
+        InvoiceRowType::SupplierReturn => match status {
+            InvoiceStatus::New => true,
+            InvoiceStatus::Picked => true,
+            InvoiceStatus::Allocated => false,
+            InvoiceStatus::Shipped => false,
+            InvoiceStatus::Delivered => false,
+            InvoiceStatus::Verified => false,
+        },
+        InvoiceRowType::CustomerReturn => match status {
+            InvoiceStatus::New => true,
+            InvoiceStatus::Delivered => true,
+            InvoiceStatus::Allocated => false,
+            InvoiceStatus::Picked => false,
+            InvoiceStatus::Shipped => false,
+            InvoiceStatus::Verified => false,
+        },
+        // lines of prescriptions can only be changed while new, see prescription::validate
+        InvoiceRowType::Prescription => match status {
+            InvoiceStatus::New => true,
+            InvoiceStatus::Picked => true,
+            InvoiceStatus::Allocated => false,
+            InvoiceStatus::Shipped => false,
+            InvoiceStatus::Delivered => false,
+            InvoiceStatus::Verified => false,
+        },
         InvoiceRowType::InventoryAdjustment => false,
+        InvoiceRowType::Repack => false,
+        InvoiceRowType::LocationMovement => false,
     };
 
     if is_editable {
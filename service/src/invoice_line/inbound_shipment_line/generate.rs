@@ -1,4 +1,4 @@
-use crate::current_store_id;
+use crate::{current_store_id, preference::get_store_preferences};
 use repository::{
     schema::{InvoiceLineRow, StockLineRow},
     RepositoryError, StorageConnection,
@@ -6,7 +6,22 @@ use repository::{
 use util::uuid::uuid;
 
 pub fn generate_batch(
-    InvoiceLineRow {
+    line: &mut InvoiceLineRow,
+    keep_existing_batch: bool,
+    connection: &StorageConnection,
+) -> Result<StockLineRow, RepositoryError> {
+    let store_id = current_store_id(connection)?;
+    // Received packs are broken down into single units if the store is configured to do so,
+    // the invoice line is converted as well to match the stock line it introduces
+    if line.pack_size > 1 && get_store_preferences(connection, &store_id)?.pack_to_one {
+        let pack_size = line.pack_size;
+        line.number_of_packs *= pack_size;
+        line.pack_size = 1;
+        line.cost_price_per_pack /= pack_size as f64;
+        line.sell_price_per_pack /= pack_size as f64;
+    }
+
+    let InvoiceLineRow {
         stock_line_id,
         item_id,
         pack_size,
@@ -18,10 +33,8 @@ pub fn generate_batch(
         location_id,
         note,
         ..
-    }: InvoiceLineRow,
-    keep_existing_batch: bool,
-    connection: &StorageConnection,
-) -> Result<StockLineRow, RepositoryError> {
+    } = line.clone();
+
     // Generate new id if requested via parameter or if stock_line_id is not already set on line
     let stock_line_id = match (stock_line_id, keep_existing_batch) {
         (Some(stock_line_id), true) => stock_line_id,
@@ -31,7 +44,7 @@ pub fn generate_batch(
     let result = StockLineRow {
         id: stock_line_id,
         item_id,
-        store_id: current_store_id(connection)?,
+        store_id,
         location_id,
         batch,
         pack_size,
@@ -18,7 +18,7 @@ pub fn generate(
     let mut new_line = generate_line(input, item_row);
 
     let new_batch_option = if status != InvoiceRowStatus::New {
-        let new_batch = generate_batch(new_line.clone(), false, connection)?;
+        let new_batch = generate_batch(&mut new_line, false, connection)?;
         new_line.stock_line_id = Some(new_batch.id.clone());
         Some(new_batch)
     } else {
@@ -40,6 +40,7 @@ fn generate_line(
         cost_price_per_pack,
         number_of_packs,
         location_id,
+        pack_variant_id: _,
         total_before_tax,
         total_after_tax,
         tax,
@@ -69,5 +70,7 @@ fn generate_line(
         total_after_tax,
         tax,
         note: None,
+        return_reason_id: None,
+        directions: None,
     }
 }
@@ -1,4 +1,7 @@
-use crate::WithDBError;
+use crate::{
+    plugin::{PluginHost, PluginRejection},
+    WithDBError,
+};
 use domain::inbound_shipment::InsertInboundShipmentLine;
 use repository::{
     InvoiceLineRowRepository, RepositoryError, StockLineRowRepository, StorageConnection,
@@ -13,12 +16,18 @@ use validate::validate;
 
 pub fn insert_inbound_shipment_line(
     connection: &StorageConnection,
+    plugins: &PluginHost,
     input: InsertInboundShipmentLine,
 ) -> Result<String, InsertInboundShipmentLineError> {
     let new_line = connection
         .transaction_sync(|connection| {
-            let (item, invoice) = validate(&input, &connection)?;
-            let (new_line, new_batch_option) = generate(input, item, invoice, connection)?;
+            let (item, invoice, number_of_packs) = validate(&input, &connection)?;
+            let input = InsertInboundShipmentLine {
+                number_of_packs,
+                ..input
+            };
+            let (new_line, new_batch_option) = generate(input, item, invoice.clone(), connection)?;
+            plugins.validate_invoice_line(connection, &invoice, &new_line)?;
 
             if let Some(new_batch) = new_batch_option {
                 StockLineRowRepository::new(&connection).upsert_one(&new_batch)?;
@@ -46,9 +55,14 @@ pub enum InsertInboundShipmentLineError {
     NotThisStoreInvoice,
     CannotEditFinalised,
     LocationDoesNotExist,
+    /// The item is restricted to a location type the location doesn't provide
+    LocationNotSuitableForItem,
     ItemNotFound,
     PackSizeBelowOne,
     NumberOfPacksBelowOne,
+    PackVariantDoesNotExist,
+    CannotConvertPackVariant,
+    RejectedByPlugin(PluginRejection),
 }
 
 impl From<RepositoryError> for InsertInboundShipmentLineError {
@@ -57,6 +71,12 @@ impl From<RepositoryError> for InsertInboundShipmentLineError {
     }
 }
 
+impl From<PluginRejection> for InsertInboundShipmentLineError {
+    fn from(rejection: PluginRejection) -> Self {
+        InsertInboundShipmentLineError::RejectedByPlugin(rejection)
+    }
+}
+
 impl<ERR> From<WithDBError<ERR>> for InsertInboundShipmentLineError
 where
     ERR: Into<InsertInboundShipmentLineError>,
@@ -4,14 +4,15 @@ use crate::{
         validate::InvoiceIsNotEditable, InvoiceDoesNotExist, WrongInvoiceType,
     },
     invoice_line::{
-        check_location_exists,
+        check_location_exists, check_location_is_suitable,
         inbound_shipment_line::check_pack_size,
         validate::{
-            check_item, check_line_does_not_exists, check_number_of_packs, ItemNotFound,
-            LineAlreadyExists, NumberOfPacksBelowOne,
+            check_item, check_line_does_not_exists, check_number_of_packs, check_pack_variant,
+            ItemNotFound, LineAlreadyExists, NumberOfPacksBelowOne, PackVariantError,
         },
-        LocationDoesNotExist, PackSizeBelowOne,
+        LocationDoesNotExist, LocationNotSuitableForItem, PackSizeBelowOne,
     },
+    u32_to_i32,
 };
 use domain::{inbound_shipment::InsertInboundShipmentLine, invoice::InvoiceType};
 use repository::{
@@ -24,7 +25,7 @@ use super::InsertInboundShipmentLineError;
 pub fn validate(
     input: &InsertInboundShipmentLine,
     connection: &StorageConnection,
-) -> Result<(ItemRow, InvoiceRow), InsertInboundShipmentLineError> {
+) -> Result<(ItemRow, InvoiceRow, u32), InsertInboundShipmentLineError> {
     check_line_does_not_exists(&input.id, connection)?;
     check_pack_size(Some(input.pack_size))?;
     check_number_of_packs(Some(input.number_of_packs))?;
@@ -35,12 +36,22 @@ pub fn validate(
     let invoice = check_invoice_exists(&input.invoice_id, connection)?;
     check_invoice_type(&invoice, InvoiceType::InboundShipment)?;
     check_invoice_is_editable(&invoice)?;
+    check_location_is_suitable(&input.location_id, &item.id, &invoice.store_id, connection)?;
 
     // TODO: InvoiceDoesNotBelongToCurrentStore
     // TODO: StockLineDoesNotBelongToCurrentStore
     // TODO: LocationDoesNotBelongToCurrentStore
 
-    Ok((item, invoice))
+    let number_of_packs = check_pack_variant(
+        &input.pack_variant_id,
+        &item.id,
+        Some(input.number_of_packs),
+        u32_to_i32(input.pack_size),
+        connection,
+    )?
+    .unwrap_or(input.number_of_packs);
+
+    Ok((item, invoice, number_of_packs))
 }
 
 impl From<ItemNotFound> for InsertInboundShipmentLineError {
@@ -55,6 +66,12 @@ impl From<LocationDoesNotExist> for InsertInboundShipmentLineError {
     }
 }
 
+impl From<LocationNotSuitableForItem> for InsertInboundShipmentLineError {
+    fn from(_: LocationNotSuitableForItem) -> Self {
+        InsertInboundShipmentLineError::LocationNotSuitableForItem
+    }
+}
+
 impl From<NumberOfPacksBelowOne> for InsertInboundShipmentLineError {
     fn from(_: NumberOfPacksBelowOne) -> Self {
         InsertInboundShipmentLineError::NumberOfPacksBelowOne
@@ -67,6 +84,19 @@ impl From<PackSizeBelowOne> for InsertInboundShipmentLineError {
     }
 }
 
+impl From<PackVariantError> for InsertInboundShipmentLineError {
+    fn from(error: PackVariantError) -> Self {
+        match error {
+            PackVariantError::PackVariantDoesNotExist => {
+                InsertInboundShipmentLineError::PackVariantDoesNotExist
+            }
+            PackVariantError::CannotConvertPackVariant => {
+                InsertInboundShipmentLineError::CannotConvertPackVariant
+            }
+        }
+    }
+}
+
 impl From<LineAlreadyExists> for InsertInboundShipmentLineError {
     fn from(_: LineAlreadyExists) -> Self {
         InsertInboundShipmentLineError::LineAlreadyExists
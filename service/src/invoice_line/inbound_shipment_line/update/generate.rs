@@ -20,11 +20,7 @@ pub fn generate(
     let mut update_line = generate_line(input, current_line, new_item_option);
 
     let upsert_batch_option = if status != InvoiceRowStatus::New {
-        let new_batch = generate_batch(
-            update_line.clone(),
-            batch_to_delete_id.is_none(),
-            connection,
-        )?;
+        let new_batch = generate_batch(&mut update_line, batch_to_delete_id.is_none(), connection)?;
         update_line.stock_line_id = Some(new_batch.id.clone());
         Some(new_batch)
     } else {
@@ -55,6 +51,7 @@ fn generate_line(
         expiry_date,
         number_of_packs,
         location_id,
+        pack_variant_id: _,
         id: _,
         invoice_id: _,
         item_id: _,
@@ -1,4 +1,7 @@
-use crate::WithDBError;
+use crate::{
+    plugin::{PluginHost, PluginRejection},
+    WithDBError,
+};
 use domain::inbound_shipment::UpdateInboundShipmentLine;
 use repository::{
     InvoiceLineRowRepository, RepositoryError, StockLineRowRepository, StorageConnectionManager,
@@ -13,15 +16,21 @@ use validate::validate;
 
 pub fn update_inbound_shipment_line(
     connection_manager: &StorageConnectionManager,
+    plugins: &PluginHost,
     input: UpdateInboundShipmentLine,
 ) -> Result<String, UpdateInboundShipmentLineError> {
     let connection = connection_manager.connection()?;
     let updated_line = connection
         .transaction_sync(|connection| {
-            let (line, item, invoice) = validate(&input, &connection)?;
+            let (line, item, invoice, number_of_packs) = validate(&input, &connection)?;
+            let input = UpdateInboundShipmentLine {
+                number_of_packs,
+                ..input
+            };
 
             let (updated_line, upsert_batch_option, delete_batch_id_option) =
-                generate(input, line, item, invoice, connection)?;
+                generate(input, line, item, invoice.clone(), connection)?;
+            plugins.validate_invoice_line(connection, &invoice, &updated_line)?;
 
             let stock_line_respository = StockLineRowRepository::new(&connection);
 
@@ -54,11 +63,16 @@ pub enum UpdateInboundShipmentLineError {
     NotThisStoreInvoice,
     CannotEditFinalised,
     LocationDoesNotExist,
+    /// The item is restricted to a location type the location doesn't provide
+    LocationNotSuitableForItem,
     ItemNotFound,
     PackSizeBelowOne,
     NumberOfPacksBelowOne,
+    PackVariantDoesNotExist,
+    CannotConvertPackVariant,
     BatchIsReserved,
     NotThisInvoiceLine(String),
+    RejectedByPlugin(PluginRejection),
 }
 
 impl From<RepositoryError> for UpdateInboundShipmentLineError {
@@ -67,6 +81,12 @@ impl From<RepositoryError> for UpdateInboundShipmentLineError {
     }
 }
 
+impl From<PluginRejection> for UpdateInboundShipmentLineError {
+    fn from(rejection: PluginRejection) -> Self {
+        UpdateInboundShipmentLineError::RejectedByPlugin(rejection)
+    }
+}
+
 impl<ERR> From<WithDBError<ERR>> for UpdateInboundShipmentLineError
 where
     ERR: Into<UpdateInboundShipmentLineError>,
@@ -4,13 +4,15 @@ use crate::{
         validate::InvoiceIsNotEditable, InvoiceDoesNotExist, WrongInvoiceType,
     },
     invoice_line::{
-        check_batch, check_location_exists, check_pack_size,
+        check_batch, check_location_exists, check_location_is_suitable, check_pack_size,
         validate::{
             check_item, check_line_belongs_to_invoice, check_line_exists, check_number_of_packs,
-            ItemNotFound, LineDoesNotExist, NotInvoiceLine, NumberOfPacksBelowOne,
+            check_pack_variant, ItemNotFound, LineDoesNotExist, NotInvoiceLine,
+            NumberOfPacksBelowOne, PackVariantError,
         },
-        BatchIsReserved, LocationDoesNotExist, PackSizeBelowOne,
+        BatchIsReserved, LocationDoesNotExist, LocationNotSuitableForItem, PackSizeBelowOne,
     },
+    u32_to_i32,
 };
 use domain::{inbound_shipment::UpdateInboundShipmentLine, invoice::InvoiceType};
 use repository::{
@@ -23,7 +25,10 @@ use super::UpdateInboundShipmentLineError;
 pub fn validate(
     input: &UpdateInboundShipmentLine,
     connection: &StorageConnection,
-) -> Result<(InvoiceLineRow, Option<ItemRow>, InvoiceRow), UpdateInboundShipmentLineError> {
+) -> Result<
+    (InvoiceLineRow, Option<ItemRow>, InvoiceRow, Option<u32>),
+    UpdateInboundShipmentLineError,
+> {
     let line = check_line_exists(&input.id, connection)?;
     check_pack_size(input.pack_size.clone())?;
     check_number_of_packs(input.number_of_packs.clone())?;
@@ -38,12 +43,26 @@ pub fn validate(
     check_batch(&line, connection)?;
 
     check_location_exists(&input.location_id, connection)?;
+    // the item or the location may change
+    if input.item_id.is_some() || input.location_id.is_some() {
+        let location_id = input.location_id.clone().or(line.location_id.clone());
+        let item_id = item.as_ref().map(|item| &item.id).unwrap_or(&line.item_id);
+        check_location_is_suitable(&location_id, item_id, &invoice.store_id, connection)?;
+    }
 
     // TODO: InvoiceDoesNotBelongToCurrentStore
     // TODO: StockLineDoesNotBelongToCurrentStore
     // TODO: LocationDoesNotBelongToCurrentStore
 
-    Ok((line, item, invoice))
+    let number_of_packs = check_pack_variant(
+        &input.pack_variant_id,
+        item.as_ref().map(|item| &item.id).unwrap_or(&line.item_id),
+        input.number_of_packs,
+        input.pack_size.map(u32_to_i32).unwrap_or(line.pack_size),
+        connection,
+    )?;
+
+    Ok((line, item, invoice, number_of_packs))
 }
 
 fn check_item_option(
@@ -63,6 +82,12 @@ impl From<ItemNotFound> for UpdateInboundShipmentLineError {
     }
 }
 
+impl From<LocationNotSuitableForItem> for UpdateInboundShipmentLineError {
+    fn from(_: LocationNotSuitableForItem) -> Self {
+        UpdateInboundShipmentLineError::LocationNotSuitableForItem
+    }
+}
+
 impl From<LocationDoesNotExist> for UpdateInboundShipmentLineError {
     fn from(_: LocationDoesNotExist) -> Self {
         UpdateInboundShipmentLineError::LocationDoesNotExist
@@ -81,6 +106,19 @@ impl From<PackSizeBelowOne> for UpdateInboundShipmentLineError {
     }
 }
 
+impl From<PackVariantError> for UpdateInboundShipmentLineError {
+    fn from(error: PackVariantError) -> Self {
+        match error {
+            PackVariantError::PackVariantDoesNotExist => {
+                UpdateInboundShipmentLineError::PackVariantDoesNotExist
+            }
+            PackVariantError::CannotConvertPackVariant => {
+                UpdateInboundShipmentLineError::CannotConvertPackVariant
+            }
+        }
+    }
+}
+
 impl From<LineDoesNotExist> for UpdateInboundShipmentLineError {
     fn from(_: LineDoesNotExist) -> Self {
         UpdateInboundShipmentLineError::LineDoesNotExist
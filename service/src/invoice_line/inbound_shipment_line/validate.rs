@@ -3,7 +3,7 @@ use repository::{
     LocationRowRepository, StockLineRowRepository, StorageConnection,
 };
 
-use crate::WithDBError;
+use crate::{location::hierarchy::check_location_suits_item, WithDBError};
 
 pub struct PackSizeBelowOne;
 
@@ -63,3 +63,24 @@ pub fn check_location_exists(
         None => Ok(()),
     }
 }
+
+pub struct LocationNotSuitableForItem;
+
+/// Checks that an item restricted to a location type can be put into the location
+pub fn check_location_is_suitable(
+    location_id: &Option<String>,
+    item_id: &str,
+    store_id: &str,
+    connection: &StorageConnection,
+) -> Result<(), WithDBError<LocationNotSuitableForItem>> {
+    let location = match location_id {
+        Some(location_id) => LocationRowRepository::new(connection).find_one_by_id(location_id)?,
+        None => None,
+    };
+    if let Some(location) = location {
+        if !check_location_suits_item(connection, store_id, item_id, location)? {
+            return Err(WithDBError::err(LocationNotSuitableForItem));
+        }
+    }
+    Ok(())
+}
@@ -44,6 +44,7 @@ fn generate_line(
         item_id,
         stock_line_id,
         number_of_packs,
+        pack_variant_id: _,
         total_before_tax,
         total_after_tax,
         tax,
@@ -83,5 +84,7 @@ fn generate_line(
         total_after_tax,
         tax,
         note,
+        return_reason_id: None,
+        directions: None,
     }
 }
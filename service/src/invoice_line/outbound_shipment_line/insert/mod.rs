@@ -1,4 +1,7 @@
-use crate::WithDBError;
+use crate::{
+    plugin::{PluginHost, PluginRejection},
+    WithDBError,
+};
 use domain::outbound_shipment::InsertOutboundShipmentLine;
 use repository::{
     InvoiceLineRowRepository, RepositoryError, StockLineRowRepository, StorageConnection,
@@ -13,12 +16,18 @@ use validate::validate;
 
 pub fn insert_outbound_shipment_line(
     connection: &StorageConnection,
+    plugins: &PluginHost,
     input: InsertOutboundShipmentLine,
 ) -> Result<String, InsertOutboundShipmentLineError> {
     let new_line = connection
         .transaction_sync(|connection| {
-            let (item, invoice, batch) = validate(&input, &connection)?;
-            let (new_line, update_batch) = generate(input, item, batch, invoice)?;
+            let (item, invoice, batch, number_of_packs) = validate(&input, &connection)?;
+            let input = InsertOutboundShipmentLine {
+                number_of_packs,
+                ..input
+            };
+            let (new_line, update_batch) = generate(input, item, batch, invoice.clone())?;
+            plugins.validate_invoice_line(connection, &invoice, &new_line)?;
             InvoiceLineRowRepository::new(&connection).upsert_one(&new_line)?;
             StockLineRowRepository::new(&connection).upsert_one(&update_batch)?;
             Ok(new_line)
@@ -45,6 +54,8 @@ pub enum InsertOutboundShipmentLineError {
     ItemNotFound,
     StockLineNotFound,
     NumberOfPacksBelowOne,
+    PackVariantDoesNotExist,
+    CannotConvertPackVariant,
     LocationIsOnHold,
     LocationNotFound,
     StockLineAlreadyExistsInInvoice(String),
@@ -52,6 +63,7 @@ pub enum InsertOutboundShipmentLineError {
     BatchIsOnHold,
 
     ReductionBelowZero { stock_line_id: String },
+    RejectedByPlugin(PluginRejection),
 }
 
 impl From<RepositoryError> for InsertOutboundShipmentLineError {
@@ -60,6 +72,12 @@ impl From<RepositoryError> for InsertOutboundShipmentLineError {
     }
 }
 
+impl From<PluginRejection> for InsertOutboundShipmentLineError {
+    fn from(rejection: PluginRejection) -> Self {
+        InsertOutboundShipmentLineError::RejectedByPlugin(rejection)
+    }
+}
+
 impl<ERR> From<WithDBError<ERR>> for InsertOutboundShipmentLineError
 where
     ERR: Into<InsertOutboundShipmentLineError>,
@@ -7,12 +7,13 @@ use crate::{
         check_batch_exists, check_batch_on_hold, check_item_matches_batch, check_location_on_hold,
         check_unique_stock_line,
         validate::{
-            check_item, check_line_does_not_exists, check_number_of_packs, ItemNotFound,
-            LineAlreadyExists, NumberOfPacksBelowOne,
+            check_item, check_line_does_not_exists, check_number_of_packs, check_pack_variant,
+            ItemNotFound, LineAlreadyExists, NumberOfPacksBelowOne, PackVariantError,
         },
         BatchIsOnHold, ItemDoesNotMatchStockLine, LocationIsOnHoldError,
         StockLineAlreadyExistsInInvoice, StockLineNotFound,
     },
+    preference::get_store_preferences,
     u32_to_i32,
 };
 use domain::{invoice::InvoiceType, outbound_shipment::InsertOutboundShipmentLine};
@@ -26,12 +27,20 @@ use super::InsertOutboundShipmentLineError;
 pub fn validate(
     input: &InsertOutboundShipmentLine,
     connection: &StorageConnection,
-) -> Result<(ItemRow, InvoiceRow, StockLineRow), InsertOutboundShipmentLineError> {
+) -> Result<(ItemRow, InvoiceRow, StockLineRow, u32), InsertOutboundShipmentLineError> {
     check_line_does_not_exists(&input.id, connection)?;
     check_number_of_packs(Some(input.number_of_packs))?;
     let batch = check_batch_exists(&input.stock_line_id, connection)?;
     let item = check_item(&input.item_id, connection)?;
     check_item_matches_batch(&batch, &item)?;
+    let number_of_packs = check_pack_variant(
+        &input.pack_variant_id,
+        &item.id,
+        Some(input.number_of_packs),
+        batch.pack_size,
+        connection,
+    )?
+    .unwrap_or(input.number_of_packs);
     let invoice = check_invoice_exists(&input.invoice_id, connection)?;
     check_unique_stock_line(
         &input.id,
@@ -45,16 +54,18 @@ pub fn validate(
 
     check_batch_on_hold(&batch)?;
     check_location_on_hold(&batch, connection)?;
-    check_reduction_below_zero(&input, &batch)?;
+    if !get_store_preferences(connection, &invoice.store_id)?.allow_negative_stock {
+        check_reduction_below_zero(number_of_packs, &batch)?;
+    }
 
-    Ok((item, invoice, batch))
+    Ok((item, invoice, batch, number_of_packs))
 }
 
 fn check_reduction_below_zero(
-    input: &InsertOutboundShipmentLine,
+    number_of_packs: u32,
     batch: &StockLineRow,
 ) -> Result<(), InsertOutboundShipmentLineError> {
-    if batch.available_number_of_packs < u32_to_i32(input.number_of_packs) {
+    if batch.available_number_of_packs < u32_to_i32(number_of_packs) {
         Err(InsertOutboundShipmentLineError::ReductionBelowZero {
             stock_line_id: batch.id.clone(),
         })
@@ -91,6 +102,19 @@ impl From<ItemDoesNotMatchStockLine> for InsertOutboundShipmentLineError {
     }
 }
 
+impl From<PackVariantError> for InsertOutboundShipmentLineError {
+    fn from(error: PackVariantError) -> Self {
+        match error {
+            PackVariantError::PackVariantDoesNotExist => {
+                InsertOutboundShipmentLineError::PackVariantDoesNotExist
+            }
+            PackVariantError::CannotConvertPackVariant => {
+                InsertOutboundShipmentLineError::CannotConvertPackVariant
+            }
+        }
+    }
+}
+
 impl From<ItemNotFound> for InsertOutboundShipmentLineError {
     fn from(_: ItemNotFound) -> Self {
         InsertOutboundShipmentLineError::ItemNotFound
@@ -45,7 +45,7 @@ fn generate_batch_update(
 ) -> StockLineRow {
     let mut update_batch = batch_pair.main_batch.clone();
 
-    let reduction = batch_pair.get_main_batch_reduction(input, existing_line);
+    let reduction = batch_pair.get_main_batch_reduction(input.number_of_packs, existing_line);
 
     update_batch.available_number_of_packs -= reduction;
     if adjust_total_number_of_packs {
@@ -120,6 +120,8 @@ fn generate_line(
         tax,
         r#type,
         note,
+        return_reason_id: None,
+        directions: None,
     };
 
     if let Some(number_of_packs) = input.number_of_packs {
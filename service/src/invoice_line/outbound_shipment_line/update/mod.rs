@@ -1,4 +1,7 @@
-use crate::{u32_to_i32, WithDBError};
+use crate::{
+    plugin::{PluginHost, PluginRejection},
+    u32_to_i32, WithDBError,
+};
 use domain::outbound_shipment::UpdateOutboundShipmentLine;
 use repository::{
     schema::{InvoiceLineRow, StockLineRow},
@@ -14,14 +17,20 @@ use validate::validate;
 
 pub fn update_outbound_shipment_line(
     connection_manager: &StorageConnectionManager,
+    plugins: &PluginHost,
     input: UpdateOutboundShipmentLine,
 ) -> Result<String, UpdateOutboundShipmentLineError> {
     let connection = connection_manager.connection()?;
     let new_line = connection
         .transaction_sync(|connection| {
-            let (line, item, batch_pair, invoice) = validate(&input, &connection)?;
+            let (line, item, batch_pair, invoice, number_of_packs) = validate(&input, &connection)?;
+            let input = UpdateOutboundShipmentLine {
+                number_of_packs,
+                ..input
+            };
 
-            let (new_line, batch_pair) = generate(input, line, item, batch_pair, invoice)?;
+            let (new_line, batch_pair) = generate(input, line, item, batch_pair, invoice.clone())?;
+            plugins.validate_invoice_line(connection, &invoice, &new_line)?;
             InvoiceLineRowRepository::new(&connection).upsert_one(&new_line)?;
 
             let stock_line_repo = StockLineRowRepository::new(&connection);
@@ -50,24 +59,24 @@ pub struct BatchPair {
 }
 
 impl BatchPair {
-    /// Calculate reduction amount to apply to main batch
+    /// Calculate reduction amount to apply to main batch, `number_of_packs` is the number of packs
+    /// of the stock line from the input
     pub fn get_main_batch_reduction(
         &self,
-        input: &UpdateOutboundShipmentLine,
+        number_of_packs: Option<u32>,
         existing_line: &InvoiceLineRow,
     ) -> i32 {
         // Previous batch exists, this mean new batch was requested means:
         // - reduction should be number of packs from input (or existing line if number of pack is missing in input)
         if self.previous_batch_option.is_some() {
-            input
-                .number_of_packs
+            number_of_packs
                 .map(u32_to_i32)
                 .unwrap_or(existing_line.number_of_packs)
         } else {
             // Previous batch does not exists, this mean updating existing batch, thus:
             // - reduction is the difference between input and existing line number of packs
-            if let Some(number_of_packs) = &input.number_of_packs {
-                u32_to_i32(*number_of_packs) - existing_line.number_of_packs
+            if let Some(number_of_packs) = number_of_packs {
+                u32_to_i32(number_of_packs) - existing_line.number_of_packs
             } else {
                 // No changes in input, no reduction
                 0
@@ -87,6 +96,8 @@ pub enum UpdateOutboundShipmentLineError {
     ItemNotFound,
     StockLineNotFound,
     NumberOfPacksBelowOne,
+    PackVariantDoesNotExist,
+    CannotConvertPackVariant,
     ItemDoesNotMatchStockLine,
     LocationIsOnHold,
     LocationNotFound,
@@ -97,6 +108,7 @@ pub enum UpdateOutboundShipmentLineError {
         stock_line_id: String,
         line_id: String,
     },
+    RejectedByPlugin(PluginRejection),
 }
 
 impl From<RepositoryError> for UpdateOutboundShipmentLineError {
@@ -105,6 +117,12 @@ impl From<RepositoryError> for UpdateOutboundShipmentLineError {
     }
 }
 
+impl From<PluginRejection> for UpdateOutboundShipmentLineError {
+    fn from(rejection: PluginRejection) -> Self {
+        UpdateOutboundShipmentLineError::RejectedByPlugin(rejection)
+    }
+}
+
 impl<ERR> From<WithDBError<ERR>> for UpdateOutboundShipmentLineError
 where
     ERR: Into<UpdateOutboundShipmentLineError>,
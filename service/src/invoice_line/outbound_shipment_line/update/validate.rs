@@ -8,11 +8,13 @@ use crate::{
         check_unique_stock_line,
         validate::{
             check_item, check_line_belongs_to_invoice, check_line_exists, check_number_of_packs,
-            ItemNotFound, LineDoesNotExist, NotInvoiceLine, NumberOfPacksBelowOne,
+            check_pack_variant, ItemNotFound, LineDoesNotExist, NotInvoiceLine,
+            NumberOfPacksBelowOne, PackVariantError,
         },
         BatchIsOnHold, ItemDoesNotMatchStockLine, LocationIsOnHoldError,
         StockLineAlreadyExistsInInvoice, StockLineNotFound,
     },
+    preference::get_store_preferences,
 };
 use domain::{invoice::InvoiceType, outbound_shipment::UpdateOutboundShipmentLine};
 use repository::{
@@ -25,7 +27,10 @@ use super::{BatchPair, UpdateOutboundShipmentLineError};
 pub fn validate(
     input: &UpdateOutboundShipmentLine,
     connection: &StorageConnection,
-) -> Result<(InvoiceLineRow, ItemRow, BatchPair, InvoiceRow), UpdateOutboundShipmentLineError> {
+) -> Result<
+    (InvoiceLineRow, ItemRow, BatchPair, InvoiceRow, Option<u32>),
+    UpdateOutboundShipmentLineError,
+> {
     let line = check_line_exists(&input.id, connection)?;
     let invoice = check_invoice_exists(&input.invoice_id, connection)?;
     check_unique_stock_line(
@@ -46,22 +51,31 @@ pub fn validate(
     let batch_pair = check_batch_exists_option(&input, &line, connection)?;
     let item = check_item_option(input.item_id.clone(), &line, connection)?;
     check_item_matches_batch(&batch_pair.main_batch, &item)?;
+    let number_of_packs = check_pack_variant(
+        &input.pack_variant_id,
+        &item.id,
+        input.number_of_packs,
+        batch_pair.main_batch.pack_size,
+        connection,
+    )?;
 
     check_batch_on_hold(&batch_pair.main_batch)?;
     check_location_on_hold(&batch_pair.main_batch, connection)?;
-    check_reduction_below_zero(&input, &line, &batch_pair)?;
+    if !get_store_preferences(connection, &invoice.store_id)?.allow_negative_stock {
+        check_reduction_below_zero(number_of_packs, &line, &batch_pair)?;
+    }
 
-    Ok((line, item, batch_pair, invoice))
+    Ok((line, item, batch_pair, invoice, number_of_packs))
 }
 
 fn check_reduction_below_zero(
-    input: &UpdateOutboundShipmentLine,
+    number_of_packs: Option<u32>,
     line: &InvoiceLineRow,
     batch_pair: &BatchPair,
 ) -> Result<(), UpdateOutboundShipmentLineError> {
     // If previous batch is present, this means we are adjust new batch thus:
     // - check full number of pack in invoice
-    let reduction = batch_pair.get_main_batch_reduction(input, line);
+    let reduction = batch_pair.get_main_batch_reduction(number_of_packs, line);
 
     if batch_pair.main_batch.available_number_of_packs < reduction {
         Err(UpdateOutboundShipmentLineError::ReductionBelowZero {
@@ -118,6 +132,19 @@ fn check_batch_exists_option(
     Ok(result)
 }
 
+impl From<PackVariantError> for UpdateOutboundShipmentLineError {
+    fn from(error: PackVariantError) -> Self {
+        match error {
+            PackVariantError::PackVariantDoesNotExist => {
+                UpdateOutboundShipmentLineError::PackVariantDoesNotExist
+            }
+            PackVariantError::CannotConvertPackVariant => {
+                UpdateOutboundShipmentLineError::CannotConvertPackVariant
+            }
+        }
+    }
+}
+
 impl From<ItemDoesNotMatchStockLine> for UpdateOutboundShipmentLineError {
     fn from(_: ItemDoesNotMatchStockLine) -> Self {
         UpdateOutboundShipmentLineError::ItemDoesNotMatchStockLine
@@ -28,6 +28,8 @@ pub fn generate(
         r#type: InvoiceLineRowType::Service,
         number_of_packs: 0,
         stock_line_id: None,
+        return_reason_id: None,
+        directions: None,
     };
 
     Ok(new_line)
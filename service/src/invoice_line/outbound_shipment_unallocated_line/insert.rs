@@ -125,6 +125,8 @@ fn generate(
         sell_price_per_pack: 0.0,
         cost_price_per_pack: 0.0,
         stock_line_id: None,
+        return_reason_id: None,
+        directions: None,
     };
 
     Ok(new_line)
@@ -340,6 +342,8 @@ mod test_insert {
                 sell_price_per_pack: 0.0,
                 cost_price_per_pack: 0.0,
                 stock_line_id: None,
+                return_reason_id: None,
+                directions: None,
             }
         )
     }
@@ -1,7 +1,8 @@
-use crate::WithDBError;
+use crate::{pack_variant::convert_number_of_packs, WithDBError};
 use repository::{
     schema::{InvoiceLineRow, InvoiceRow, ItemRow},
-    InvoiceLineRowRepository, ItemRepository, RepositoryError, StorageConnection,
+    InvoiceLineRowRepository, ItemRepository, PackVariantRowRepository, RepositoryError,
+    StorageConnection,
 };
 
 pub struct LineAlreadyExists;
@@ -111,3 +112,38 @@ pub fn check_line_belongs_to_invoice(
         Ok(())
     }
 }
+
+pub enum PackVariantError {
+    /// Pack variant doesn't exist or is a variant of another item
+    PackVariantDoesNotExist,
+    /// Packs of the pack variant don't fill a whole number of packs of the line
+    CannotConvertPackVariant,
+}
+
+/// Converts a number of packs of the pack variant to the number of packs of `pack_size`, the
+/// number of packs is returned unchanged without pack variant
+pub fn check_pack_variant(
+    pack_variant_id: &Option<String>,
+    item_id: &str,
+    number_of_packs: Option<u32>,
+    pack_size: i32,
+    connection: &StorageConnection,
+) -> Result<Option<u32>, WithDBError<PackVariantError>> {
+    let pack_variant_id = match pack_variant_id {
+        Some(pack_variant_id) => pack_variant_id,
+        None => return Ok(number_of_packs),
+    };
+    let pack_variant = PackVariantRowRepository::new(connection)
+        .find_one_by_id(pack_variant_id)?
+        .filter(|pack_variant| pack_variant.item_id == item_id)
+        .ok_or_else(|| WithDBError::err(PackVariantError::PackVariantDoesNotExist))?;
+
+    match number_of_packs {
+        Some(number_of_packs) => {
+            convert_number_of_packs(number_of_packs, pack_variant.pack_size, pack_size)
+                .map(Some)
+                .ok_or_else(|| WithDBError::err(PackVariantError::CannotConvertPackVariant))
+        }
+        None => Ok(None),
+    }
+}
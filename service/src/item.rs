@@ -11,6 +11,7 @@ pub const MIN_LIMIT: u32 = 1;
 
 pub fn get_items(
     connection_manager: &StorageConnectionManager,
+    store_id: &str,
     pagination: Option<PaginationOption>,
     filter: Option<ItemFilter>,
     sort: Option<ItemSort>,
@@ -20,7 +21,7 @@ pub fn get_items(
     let repository = ItemQueryRepository::new(&connection);
 
     Ok(ListResult {
-        rows: repository.query(pagination, filter.clone(), sort)?,
-        count: i64_to_u32(repository.count(filter)?),
+        rows: repository.query(store_id, pagination, filter.clone(), sort)?,
+        count: i64_to_u32(repository.count(store_id, filter)?),
     })
 }
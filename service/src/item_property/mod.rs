@@ -0,0 +1,46 @@
+use repository::{
+    schema::{ItemCategoryRow, ItemPropertyRow},
+    ItemCategoryRowRepository, RepositoryError,
+};
+
+use crate::service_provider::ServiceContext;
+
+use self::update::{
+    reset_item_properties, update_item_properties, UpdateItemProperties, UpdateItemPropertiesError,
+};
+
+pub mod update;
+
+#[cfg(test)]
+mod tests;
+
+pub trait ItemPropertyServiceTrait: Sync + Send {
+    /// All item categories ordered by name, sub categories reference their parent category
+    fn get_item_categories(
+        &self,
+        ctx: &ServiceContext,
+    ) -> Result<Vec<ItemCategoryRow>, RepositoryError> {
+        ItemCategoryRowRepository::new(&ctx.connection).find_all()
+    }
+
+    /// Stores the provided properties as local overrides of the synced item properties
+    fn update_item_properties(
+        &self,
+        ctx: &ServiceContext,
+        input: UpdateItemProperties,
+    ) -> Result<ItemPropertyRow, UpdateItemPropertiesError> {
+        update_item_properties(ctx, input)
+    }
+
+    /// Removes all local overrides, i.e. the synced item properties apply again
+    fn reset_item_properties(
+        &self,
+        ctx: &ServiceContext,
+        item_id: &str,
+    ) -> Result<ItemPropertyRow, UpdateItemPropertiesError> {
+        reset_item_properties(ctx, item_id)
+    }
+}
+
+pub struct ItemPropertyService {}
+impl ItemPropertyServiceTrait for ItemPropertyService {}
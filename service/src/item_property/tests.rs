@@ -0,0 +1,123 @@
+#[cfg(test)]
+mod item_property_test {
+    use repository::{
+        mock::{mock_item_a, MockDataInserts},
+        schema::{ItemCategoryRow, ItemPropertyRow, ItemRowVenCategory},
+        test_db::setup_all,
+        ItemCategoryRowRepository,
+    };
+
+    use crate::{
+        item_property::update::{UpdateItemProperties, UpdateItemPropertiesError},
+        service_provider::ServiceProvider,
+    };
+
+    #[actix_rt::test]
+    async fn update_item_properties() {
+        let (_, connection, connection_manager, _) =
+            setup_all("update_item_properties", MockDataInserts::all()).await;
+
+        let service_provider = ServiceProvider::new(connection_manager);
+        let context = service_provider.context().unwrap();
+        let service = service_provider.item_property_service;
+
+        ItemCategoryRowRepository::new(&connection)
+            .upsert_one(&ItemCategoryRow {
+                id: "category_a".to_string(),
+                name: "Analgesics".to_string(),
+                parent_id: None,
+            })
+            .unwrap();
+        assert_eq!(service.get_item_categories(&context).unwrap().len(), 1);
+
+        // errors
+        assert_eq!(
+            service.update_item_properties(
+                &context,
+                UpdateItemProperties {
+                    item_id: "invalid".to_string(),
+                    ..Default::default()
+                }
+            ),
+            Err(UpdateItemPropertiesError::ItemDoesNotExist)
+        );
+        assert_eq!(
+            service.update_item_properties(
+                &context,
+                UpdateItemProperties {
+                    item_id: mock_item_a().id,
+                    category_id: Some("invalid".to_string()),
+                    ..Default::default()
+                }
+            ),
+            Err(UpdateItemPropertiesError::CategoryDoesNotExist)
+        );
+
+        // overrides
+        let item_id = mock_item_a().id;
+        let result = service
+            .update_item_properties(
+                &context,
+                UpdateItemProperties {
+                    item_id: item_id.clone(),
+                    category_id: Some("category_a".to_string()),
+                    ven_category: Some(ItemRowVenCategory::Vital),
+                    is_controlled: Some(true),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        assert_eq!(
+            result,
+            ItemPropertyRow {
+                item_id: item_id.clone(),
+                category_id: Some("category_a".to_string()),
+                strength: None,
+                dosage_form: None,
+                ven_category: Some(ItemRowVenCategory::Vital),
+                is_controlled: true,
+                storage_requirement: None,
+            }
+        );
+
+        // None values are left unchanged
+        let result = service
+            .update_item_properties(
+                &context,
+                UpdateItemProperties {
+                    item_id: item_id.clone(),
+                    dosage_form: Some("Tablet".to_string()),
+                    storage_requirement: Some("Below 25°C".to_string()),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        assert_eq!(
+            result,
+            ItemPropertyRow {
+                item_id: item_id.clone(),
+                category_id: Some("category_a".to_string()),
+                strength: None,
+                dosage_form: Some("Tablet".to_string()),
+                ven_category: Some(ItemRowVenCategory::Vital),
+                is_controlled: true,
+                storage_requirement: Some("Below 25°C".to_string()),
+            }
+        );
+
+        // reset to synced properties
+        let result = service.reset_item_properties(&context, &item_id).unwrap();
+        assert_eq!(
+            result,
+            ItemPropertyRow {
+                item_id,
+                category_id: None,
+                strength: None,
+                dosage_form: None,
+                ven_category: None,
+                is_controlled: false,
+                storage_requirement: None,
+            }
+        );
+    }
+}
@@ -0,0 +1,129 @@
+use repository::{
+    schema::{ItemPropertyOverrideRow, ItemPropertyRow, ItemRowVenCategory},
+    ItemCategoryRowRepository, ItemPropertyOverrideRowRepository, ItemPropertyRepository,
+    RepositoryError, StorageConnection,
+};
+use util::uuid::uuid;
+
+use crate::service_provider::ServiceContext;
+
+/// Item properties to override locally, `None` values are left unchanged
+#[derive(Default)]
+pub struct UpdateItemProperties {
+    pub item_id: String,
+    pub category_id: Option<String>,
+    pub strength: Option<String>,
+    pub dosage_form: Option<String>,
+    pub ven_category: Option<ItemRowVenCategory>,
+    pub is_controlled: Option<bool>,
+    pub storage_requirement: Option<String>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum UpdateItemPropertiesError {
+    DatabaseError(RepositoryError),
+    ItemDoesNotExist,
+    CategoryDoesNotExist,
+}
+
+pub fn update_item_properties(
+    ctx: &ServiceContext,
+    input: UpdateItemProperties,
+) -> Result<ItemPropertyRow, UpdateItemPropertiesError> {
+    let properties = ctx
+        .connection
+        .transaction_sync(|connection| {
+            validate(connection, &input)?;
+            let existing = ItemPropertyOverrideRowRepository::new(connection)
+                .find_one_by_item_id(&input.item_id)?;
+            let item_id = input.item_id.clone();
+            let row = generate(existing, input);
+            ItemPropertyOverrideRowRepository::new(connection).upsert_one(&row)?;
+            get_item_properties(connection, &item_id)
+        })
+        .map_err(|error| error.to_inner_error())?;
+    Ok(properties)
+}
+
+pub fn reset_item_properties(
+    ctx: &ServiceContext,
+    item_id: &str,
+) -> Result<ItemPropertyRow, UpdateItemPropertiesError> {
+    let properties = ctx
+        .connection
+        .transaction_sync(|connection| {
+            ItemPropertyOverrideRowRepository::new(connection).delete_by_item_id(item_id)?;
+            get_item_properties(connection, item_id)
+        })
+        .map_err(|error| error.to_inner_error())?;
+    Ok(properties)
+}
+
+fn validate(
+    connection: &StorageConnection,
+    input: &UpdateItemProperties,
+) -> Result<(), UpdateItemPropertiesError> {
+    if ItemPropertyRepository::new(connection)
+        .find_one_by_item_id(&input.item_id)?
+        .is_none()
+    {
+        return Err(UpdateItemPropertiesError::ItemDoesNotExist);
+    }
+
+    if let Some(category_id) = &input.category_id {
+        if ItemCategoryRowRepository::new(connection)
+            .find_one_by_id(category_id)?
+            .is_none()
+        {
+            return Err(UpdateItemPropertiesError::CategoryDoesNotExist);
+        }
+    }
+
+    Ok(())
+}
+
+fn generate(
+    existing: Option<ItemPropertyOverrideRow>,
+    UpdateItemProperties {
+        item_id,
+        category_id,
+        strength,
+        dosage_form,
+        ven_category,
+        is_controlled,
+        storage_requirement,
+    }: UpdateItemProperties,
+) -> ItemPropertyOverrideRow {
+    let mut row = existing.unwrap_or_else(|| ItemPropertyOverrideRow {
+        id: uuid(),
+        item_id,
+        category_id: None,
+        strength: None,
+        dosage_form: None,
+        ven_category: None,
+        is_controlled: None,
+        storage_requirement: None,
+    });
+    row.category_id = category_id.or(row.category_id);
+    row.strength = strength.or(row.strength);
+    row.dosage_form = dosage_form.or(row.dosage_form);
+    row.ven_category = ven_category.or(row.ven_category);
+    row.is_controlled = is_controlled.or(row.is_controlled);
+    row.storage_requirement = storage_requirement.or(row.storage_requirement);
+    row
+}
+
+fn get_item_properties(
+    connection: &StorageConnection,
+    item_id: &str,
+) -> Result<ItemPropertyRow, UpdateItemPropertiesError> {
+    ItemPropertyRepository::new(connection)
+        .find_one_by_item_id(item_id)?
+        .ok_or(UpdateItemPropertiesError::ItemDoesNotExist)
+}
+
+impl From<RepositoryError> for UpdateItemPropertiesError {
+    fn from(error: RepositoryError) -> Self {
+        UpdateItemPropertiesError::DatabaseError(error)
+    }
+}
@@ -2,22 +2,42 @@ use domain::{Pagination, PaginationOption, DEFAULT_LIMIT};
 use repository::{RepositoryError, StorageConnection, StoreRowRepository};
 use std::convert::TryInto;
 
+pub mod abc_classification;
 pub mod auth_data;
+pub mod barcode;
+pub mod batch_recall;
 pub mod dashboard;
 pub mod invoice;
 pub mod invoice_line;
 pub mod item;
+pub mod item_property;
 pub mod location;
+pub mod location_movement;
 pub mod master_list;
 pub mod name;
 pub mod number;
+pub mod pack_variant;
+pub mod patient;
 pub mod permission_validation;
 pub mod permissions;
+pub mod plugin;
+pub mod preference;
+pub mod prescription;
+pub mod repack;
+pub mod report;
+pub mod returns;
 pub mod service_provider;
+pub mod stock_expiry;
+pub mod stock_import;
 pub mod stock_line;
+pub mod stock_movement;
+pub mod stock_on_hand;
 pub mod stock_take;
 pub mod stock_take_line;
+pub mod stock_valuation;
 pub mod store;
+pub mod sync_out;
+pub mod temperature_log;
 pub mod token;
 pub mod token_bucket;
 pub mod user_account;
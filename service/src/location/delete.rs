@@ -2,18 +2,20 @@ use super::validate::check_location_exists;
 use crate::{service_provider::ServiceContext, validate::check_record_belongs_to_current_store};
 use domain::{
     invoice_line::InvoiceLine,
-    location::DeleteLocation,
+    location::{DeleteLocation, Location, LocationFilter},
     stock_line::{StockLine, StockLineFilter},
     EqualFilter,
 };
 use repository::{
-    InvoiceLineRepository, LocationRowRepository, RepositoryError, StockLineRepository,
-    StorageConnection, InvoiceLineFilter,
+    InvoiceLineFilter, InvoiceLineRepository, LocationRepository, LocationRowRepository,
+    RepositoryError, StockLineRepository, StorageConnection,
 };
 #[derive(PartialEq, Debug)]
 pub struct LocationInUse {
     pub stock_lines: Vec<StockLine>,
     pub invoice_lines: Vec<InvoiceLine>,
+    /// Locations with this location as parent
+    pub sub_locations: Vec<Location>,
 }
 
 #[derive(PartialEq, Debug)]
@@ -67,11 +69,14 @@ pub fn check_location_in_use(
         .query_by_filter(StockLineFilter::new().location_id(EqualFilter::equal_to(id)))?;
     let invoice_lines = InvoiceLineRepository::new(connection)
         .query_by_filter(InvoiceLineFilter::new().location_id(EqualFilter::equal_to(id)))?;
+    let sub_locations = LocationRepository::new(connection)
+        .query_by_filter(LocationFilter::new().parent_id(EqualFilter::equal_to(id)))?;
 
-    if stock_lines.len() > 0 || invoice_lines.len() > 0 {
+    if stock_lines.len() > 0 || invoice_lines.len() > 0 || sub_locations.len() > 0 {
         Ok(Some(LocationInUse {
             stock_lines,
             invoice_lines,
+            sub_locations,
         }))
     } else {
         Ok(None)
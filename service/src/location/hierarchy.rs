@@ -0,0 +1,173 @@
+use std::collections::HashSet;
+
+use domain::location::Location;
+use repository::{
+    location_to_domain,
+    schema::{LocationRow, LocationTypeRow},
+    ItemStoreJoinRowRepository, LocationRowRepository, LocationTypeRowRepository, RepositoryError,
+    StorageConnection,
+};
+
+use crate::{service_provider::ServiceContext, SingleRecordError};
+
+/// Location and its ancestors, starting with the outermost location
+pub fn get_location_path_rows(
+    connection: &StorageConnection,
+    location: LocationRow,
+) -> Result<Vec<LocationRow>, RepositoryError> {
+    let repository = LocationRowRepository::new(connection);
+    let mut visited = HashSet::new();
+    let mut parent_id = location.parent_id.clone();
+    visited.insert(location.id.clone());
+    let mut path = vec![location];
+
+    while let Some(id) = parent_id {
+        // stop on broken links or cycles
+        if !visited.insert(id.clone()) {
+            break;
+        }
+        let parent = match repository.find_one_by_id(&id)? {
+            Some(parent) => parent,
+            None => break,
+        };
+        parent_id = parent.parent_id.clone();
+        path.push(parent);
+    }
+
+    path.reverse();
+    Ok(path)
+}
+
+/// All locations below the location, breadth first
+pub fn get_sub_location_rows(
+    connection: &StorageConnection,
+    location: &LocationRow,
+) -> Result<Vec<LocationRow>, RepositoryError> {
+    let store_locations =
+        LocationRowRepository::new(connection).find_many_by_store_id(&location.store_id)?;
+    let mut visited = HashSet::new();
+    visited.insert(location.id.clone());
+    let mut result: Vec<LocationRow> = Vec::new();
+
+    let mut index = 0;
+    let mut parent_id = location.id.clone();
+    loop {
+        for candidate in &store_locations {
+            if candidate.parent_id.as_ref() == Some(&parent_id)
+                && visited.insert(candidate.id.clone())
+            {
+                result.push(candidate.clone());
+            }
+        }
+        match result.get(index) {
+            Some(next) => parent_id = next.id.clone(),
+            None => break,
+        }
+        index += 1;
+    }
+    Ok(result)
+}
+
+/// Type of the location, or of its nearest ancestor with a type
+pub fn get_inherited_location_type(
+    connection: &StorageConnection,
+    location: LocationRow,
+) -> Result<Option<LocationTypeRow>, RepositoryError> {
+    let location_type_id = get_location_path_rows(connection, location)?
+        .into_iter()
+        .rev()
+        .find_map(|location| location.location_type_id);
+    match location_type_id {
+        Some(id) => LocationTypeRowRepository::new(connection).find_one_by_id(&id),
+        None => Ok(None),
+    }
+}
+
+/// Checks that the location is suitable for an item restricted to a location type in the store.
+///
+/// The location is suitable if its (inherited) type is the restricted type, or if its temperature
+/// range lies within the temperature range of the restricted type. Items without restriction can
+/// be put into any location.
+pub fn check_location_suits_item(
+    connection: &StorageConnection,
+    store_id: &str,
+    item_id: &str,
+    location: LocationRow,
+) -> Result<bool, RepositoryError> {
+    let restricted_type_id = match ItemStoreJoinRowRepository::new(connection)
+        .find_one_by_item_and_store_id(item_id, store_id)?
+        .and_then(|join| join.restricted_location_type_id)
+    {
+        Some(restricted_type_id) => restricted_type_id,
+        None => return Ok(true),
+    };
+    let location_type = match get_inherited_location_type(connection, location)? {
+        Some(location_type) => location_type,
+        None => return Ok(false),
+    };
+    if location_type.id == restricted_type_id {
+        return Ok(true);
+    }
+    let restricted_type =
+        match LocationTypeRowRepository::new(connection).find_one_by_id(&restricted_type_id)? {
+            Some(restricted_type) => restricted_type,
+            None => return Ok(false),
+        };
+    Ok(temperature_range_within(&location_type, &restricted_type))
+}
+
+fn temperature_range_within(location_type: &LocationTypeRow, required: &LocationTypeRow) -> bool {
+    if required.min_temperature.is_none() && required.max_temperature.is_none() {
+        return false;
+    }
+    let min_ok = match (required.min_temperature, location_type.min_temperature) {
+        (None, _) => true,
+        (Some(required), Some(actual)) => actual >= required,
+        (Some(_), None) => false,
+    };
+    let max_ok = match (required.max_temperature, location_type.max_temperature) {
+        (None, _) => true,
+        (Some(required), Some(actual)) => actual <= required,
+        (Some(_), None) => false,
+    };
+    min_ok && max_ok
+}
+
+fn get_location_row(
+    connection: &StorageConnection,
+    id: &str,
+) -> Result<LocationRow, SingleRecordError> {
+    LocationRowRepository::new(connection)
+        .find_one_by_id(id)?
+        .ok_or(SingleRecordError::NotFound(id.to_string()))
+}
+
+pub fn get_location_path(
+    ctx: &ServiceContext,
+    id: &str,
+) -> Result<Vec<Location>, SingleRecordError> {
+    let location = get_location_row(&ctx.connection, id)?;
+    Ok(get_location_path_rows(&ctx.connection, location)?
+        .into_iter()
+        .map(location_to_domain)
+        .collect())
+}
+
+pub fn get_sub_locations(
+    ctx: &ServiceContext,
+    id: &str,
+) -> Result<Vec<Location>, SingleRecordError> {
+    let location = get_location_row(&ctx.connection, id)?;
+    Ok(get_sub_location_rows(&ctx.connection, &location)?
+        .into_iter()
+        .map(location_to_domain)
+        .collect())
+}
+
+pub fn get_location_type(
+    ctx: &ServiceContext,
+    id: &str,
+) -> Result<Option<LocationTypeRow>, SingleRecordError> {
+    let location = get_location_row(&ctx.connection, id)?;
+    Ok(get_inherited_location_type(&ctx.connection, location)?)
+}
@@ -1,4 +1,10 @@
-use super::{query::get_location, validate::check_location_code_is_unique};
+use super::{
+    query::get_location,
+    validate::{
+        check_location_code_is_unique, check_location_type_exists, check_parent_location,
+        ParentLocationError,
+    },
+};
 use crate::{current_store_id, service_provider::ServiceContext, SingleRecordError};
 use domain::{
     location::{InsertLocation, Location, LocationFilter},
@@ -13,6 +19,8 @@ use repository::{
 pub enum InsertLocationError {
     LocationAlreadyExists,
     LocationWithCodeAlreadyExists,
+    ParentLocationDoesNotExist,
+    LocationTypeDoesNotExist,
     CreatedRecordDoesNotExist,
     DatabaseError(RepositoryError),
 }
@@ -44,6 +52,15 @@ pub fn validate(
     if !check_location_code_is_unique(&input.id, Some(input.code.clone()), connection)? {
         return Err(InsertLocationError::LocationWithCodeAlreadyExists);
     }
+    // a new location can't be the parent of an existing one
+    if let Err(ParentLocationError::ParentLocationDoesNotExist) =
+        check_parent_location(&input.id, &input.parent_id, connection)?
+    {
+        return Err(InsertLocationError::ParentLocationDoesNotExist);
+    }
+    if !check_location_type_exists(&input.location_type_id, connection)? {
+        return Err(InsertLocationError::LocationTypeDoesNotExist);
+    }
 
     Ok(())
 }
@@ -54,6 +71,9 @@ pub fn generate(
         code,
         name,
         on_hold,
+        parent_id,
+        location_type_id,
+        volume,
     }: InsertLocation,
     connection: &StorageConnection,
 ) -> Result<LocationRow, RepositoryError> {
@@ -63,6 +83,9 @@ pub fn generate(
         code,
         on_hold: on_hold.unwrap_or(false),
         store_id: current_store_id(connection)?,
+        parent_id,
+        location_type_id,
+        volume,
     };
 
     Ok(result)
@@ -1,5 +1,6 @@
 use self::{
     delete::{delete_location, DeleteLocationError},
+    hierarchy::{get_location_path, get_location_type, get_sub_locations},
     insert::{insert_location, InsertLocationError},
     query::{get_location, get_locations},
     update::{update_location, UpdateLocationError},
@@ -13,8 +14,10 @@ use domain::{
     },
     PaginationOption,
 };
+use repository::schema::LocationTypeRow;
 
 pub mod delete;
+pub mod hierarchy;
 pub mod insert;
 pub mod query;
 pub mod update;
@@ -39,6 +42,33 @@ pub trait LocationServiceTrait: Sync + Send {
         get_location(ctx, id)
     }
 
+    /// The location and its ancestors, starting with the outermost location
+    fn get_location_path(
+        &self,
+        ctx: &ServiceContext,
+        id: &str,
+    ) -> Result<Vec<Location>, SingleRecordError> {
+        get_location_path(ctx, id)
+    }
+
+    /// All locations below the location
+    fn get_sub_locations(
+        &self,
+        ctx: &ServiceContext,
+        id: &str,
+    ) -> Result<Vec<Location>, SingleRecordError> {
+        get_sub_locations(ctx, id)
+    }
+
+    /// Type of the location, inherited from the nearest ancestor with a type if not set
+    fn get_location_type(
+        &self,
+        ctx: &ServiceContext,
+        id: &str,
+    ) -> Result<Option<LocationTypeRow>, SingleRecordError> {
+        get_location_type(ctx, id)
+    }
+
     fn delete_location(
         &self,
         ctx: &ServiceContext,
@@ -6,8 +6,8 @@ mod query {
         EqualFilter,
     };
     use repository::{
-        mock::MockDataInserts, test_db::setup_all, InvoiceLineRepository, LocationRepository,
-        StockLineRepository, InvoiceLineFilter,
+        mock::MockDataInserts, test_db::setup_all, InvoiceLineFilter, InvoiceLineRepository,
+        LocationRepository, StockLineRepository,
     };
 
     use crate::{
@@ -75,7 +75,8 @@ mod query {
             service.delete_location(&context, DeleteLocation { id: location_id }),
             Err(DeleteLocationError::LocationInUse(LocationInUse {
                 stock_lines,
-                invoice_lines
+                invoice_lines,
+                sub_locations: Vec::new(),
             }))
         );
 
@@ -96,7 +97,8 @@ mod query {
             service.delete_location(&context, DeleteLocation { id: location_id }),
             Err(DeleteLocationError::LocationInUse(LocationInUse {
                 stock_lines,
-                invoice_lines
+                invoice_lines,
+                sub_locations: Vec::new(),
             }))
         );
     }
@@ -0,0 +1,199 @@
+#[cfg(test)]
+mod query {
+    use domain::location::{InsertLocation, UpdateLocation};
+    use repository::{
+        mock::{mock_item_a, mock_item_b, mock_stock_line_a, mock_store_a, MockDataInserts},
+        schema::{ItemStoreJoinRow, LocationTypeRow},
+        test_db::setup_all,
+        ItemStoreJoinRowRepository, LocationRowRepository, LocationTypeRowRepository,
+    };
+
+    use crate::{
+        location::{
+            hierarchy::check_location_suits_item, insert::InsertLocationError,
+            update::UpdateLocationError,
+        },
+        location_movement::move_stock::{MoveStock, MoveStockError},
+        service_provider::ServiceProvider,
+    };
+
+    fn location_type(id: &str, min: Option<f64>, max: Option<f64>) -> LocationTypeRow {
+        LocationTypeRow {
+            id: id.to_string(),
+            name: id.to_string(),
+            min_temperature: min,
+            max_temperature: max,
+        }
+    }
+
+    fn insert_input(
+        id: &str,
+        parent_id: Option<&str>,
+        location_type_id: Option<&str>,
+    ) -> InsertLocation {
+        InsertLocation {
+            id: id.to_string(),
+            code: id.to_string(),
+            name: None,
+            on_hold: None,
+            parent_id: parent_id.map(str::to_string),
+            location_type_id: location_type_id.map(str::to_string),
+            volume: None,
+        }
+    }
+
+    fn update_parent(id: &str, parent_id: &str) -> UpdateLocation {
+        UpdateLocation {
+            id: id.to_string(),
+            code: None,
+            name: None,
+            on_hold: None,
+            parent_id: Some(parent_id.to_string()),
+            location_type_id: None,
+            volume: None,
+        }
+    }
+
+    #[actix_rt::test]
+    async fn location_service_hierarchy() {
+        let (_, connection, connection_manager, _) =
+            setup_all("location_service_hierarchy", MockDataInserts::all()).await;
+        let service_provider = ServiceProvider::new(connection_manager);
+        let context = service_provider.context().unwrap();
+        let service = &service_provider.location_service;
+
+        let type_repository = LocationTypeRowRepository::new(&connection);
+        type_repository
+            .upsert_one(&location_type("cold_room", Some(2.0), Some(8.0)))
+            .unwrap();
+        type_repository
+            .upsert_one(&location_type("fridge", Some(3.0), Some(7.0)))
+            .unwrap();
+        type_repository
+            .upsert_one(&location_type("freezer", Some(-25.0), Some(-15.0)))
+            .unwrap();
+
+        // ParentLocationDoesNotExist
+        assert_eq!(
+            service.insert_location(&context, insert_input("building", Some("invalid"), None)),
+            Err(InsertLocationError::ParentLocationDoesNotExist)
+        );
+        assert_eq!(
+            service.insert_location(
+                &context,
+                insert_input("building", Some("location_in_another_store"), None)
+            ),
+            Err(InsertLocationError::ParentLocationDoesNotExist)
+        );
+        // LocationTypeDoesNotExist
+        assert_eq!(
+            service.insert_location(&context, insert_input("building", None, Some("invalid"))),
+            Err(InsertLocationError::LocationTypeDoesNotExist)
+        );
+
+        // building -> cold room -> shelf -> fridge
+        for input in vec![
+            insert_input("building", None, None),
+            insert_input("cold_room", Some("building"), Some("cold_room")),
+            insert_input("shelf", Some("cold_room"), None),
+            insert_input("fridge", Some("shelf"), Some("fridge")),
+            insert_input("freezer", Some("building"), Some("freezer")),
+        ] {
+            service.insert_location(&context, input).unwrap();
+        }
+        let shelf = service.get_location(&context, "shelf".to_string()).unwrap();
+        assert_eq!(shelf.parent_id, Some("cold_room".to_string()));
+        assert_eq!(shelf.location_type_id, None);
+
+        // ParentLocationIsSubLocation
+        assert_eq!(
+            service.update_location(&context, update_parent("building", "fridge")),
+            Err(UpdateLocationError::ParentLocationIsSubLocation)
+        );
+        assert_eq!(
+            service.update_location(&context, update_parent("building", "building")),
+            Err(UpdateLocationError::ParentLocationIsSubLocation)
+        );
+        // ParentLocationDoesNotExist
+        assert_eq!(
+            service.update_location(&context, update_parent("building", "invalid")),
+            Err(UpdateLocationError::ParentLocationDoesNotExist)
+        );
+
+        // walk up and down the tree
+        let ids = |locations: Vec<domain::location::Location>| -> Vec<String> {
+            locations.into_iter().map(|location| location.id).collect()
+        };
+        assert_eq!(
+            ids(service.get_location_path(&context, "fridge").unwrap()),
+            vec!["building", "cold_room", "shelf", "fridge"]
+        );
+        assert_eq!(
+            ids(service.get_sub_locations(&context, "building").unwrap()),
+            vec!["cold_room", "freezer", "shelf", "fridge"]
+        );
+        assert_eq!(
+            ids(service.get_sub_locations(&context, "fridge").unwrap()),
+            Vec::<String>::new()
+        );
+
+        // types are inherited from the nearest typed ancestor
+        assert_eq!(
+            service
+                .get_location_type(&context, "shelf")
+                .unwrap()
+                .map(|location_type| location_type.id),
+            Some("cold_room".to_string())
+        );
+        assert_eq!(
+            service.get_location_type(&context, "building").unwrap(),
+            None
+        );
+
+        // item a needs a cold room, item b has no restriction
+        ItemStoreJoinRowRepository::new(&connection)
+            .upsert_one(&ItemStoreJoinRow {
+                id: "item_a_store_a".to_string(),
+                item_id: mock_item_a().id,
+                store_id: mock_store_a().id,
+                default_sell_price_per_pack: 0.0,
+                margin: 0.0,
+                ignore_for_orders: false,
+                inactive: false,
+                restricted_location_type_id: Some("cold_room".to_string()),
+            })
+            .unwrap();
+        let suits = |item_id: &str, location_id: &str| {
+            let location = LocationRowRepository::new(&connection)
+                .find_one_by_id(location_id)
+                .unwrap()
+                .unwrap();
+            check_location_suits_item(&connection, &mock_store_a().id, item_id, location).unwrap()
+        };
+        assert!(suits(&mock_item_a().id, "shelf"));
+        // fridge range lies within the cold room range
+        assert!(suits(&mock_item_a().id, "fridge"));
+        assert!(!suits(&mock_item_a().id, "freezer"));
+        assert!(!suits(&mock_item_a().id, "building"));
+        assert!(suits(&mock_item_b().id, "building"));
+
+        // stock moves are checked
+        let move_stock = |id: &str, location_id: &str| {
+            service_provider.location_movement_service.move_stock(
+                &context,
+                &mock_store_a().id,
+                MoveStock {
+                    id: id.to_string(),
+                    stock_line_id: mock_stock_line_a().id,
+                    number_of_packs: 1,
+                    location_id: location_id.to_string(),
+                },
+            )
+        };
+        assert_eq!(
+            move_stock("move", "building").map(|_| ()),
+            Err(MoveStockError::LocationNotSuitableForItem)
+        );
+        assert!(move_stock("move", "shelf").is_ok());
+    }
+}
@@ -34,7 +34,10 @@ mod query {
                     id: mock_data["base"].locations[0].id.clone(),
                     code: "invalid".to_owned(),
                     name: None,
-                    on_hold: None
+                    on_hold: None,
+                    parent_id: None,
+                    location_type_id: None,
+                    volume: None,
                 },
             ),
             Err(InsertLocationError::LocationAlreadyExists)
@@ -47,7 +50,10 @@ mod query {
                     id: "new_id".to_owned(),
                     code: locations_in_store[0].code.clone(),
                     name: None,
-                    on_hold: None
+                    on_hold: None,
+                    parent_id: None,
+                    location_type_id: None,
+                    volume: None,
                 },
             ),
             Err(InsertLocationError::LocationWithCodeAlreadyExists)
@@ -70,6 +76,9 @@ mod query {
             name: "new_code".to_owned(),
             code: "new_code".to_owned(),
             on_hold: false,
+            parent_id: None,
+            location_type_id: None,
+            volume: None,
         };
 
         assert_eq!(
@@ -79,7 +88,10 @@ mod query {
                     id: "new_id".to_owned(),
                     code: "new_code".to_owned(),
                     name: None,
-                    on_hold: None
+                    on_hold: None,
+                    parent_id: None,
+                    location_type_id: None,
+                    volume: None,
                 },
             ),
             Ok(result_location.clone())
@@ -107,13 +119,19 @@ mod query {
                     code: "store_b_location_code".to_owned(),
                     name: Some("new_location_name".to_owned()),
                     on_hold: Some(true),
+                    parent_id: None,
+                    location_type_id: None,
+                    volume: None,
                 },
             ),
             Ok(Location {
                 id: "new_id2".to_owned(),
                 name: "new_location_name".to_owned(),
                 code: "store_b_location_code".to_owned(),
-                on_hold: true
+                on_hold: true,
+                parent_id: None,
+                location_type_id: None,
+                volume: None,
             })
         );
     }
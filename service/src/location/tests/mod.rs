@@ -1,6 +1,8 @@
 #[cfg(test)]
 mod delete;
 #[cfg(test)]
+mod hierarchy;
+#[cfg(test)]
 mod insert;
 #[cfg(test)]
 mod query;
@@ -41,7 +41,10 @@ mod query {
                     id: "invalid".to_owned(),
                     code: None,
                     name: None,
-                    on_hold: None
+                    on_hold: None,
+                    parent_id: None,
+                    location_type_id: None,
+                    volume: None,
                 },
             ),
             Err(UpdateLocationError::LocationDoesNotExist)
@@ -55,7 +58,10 @@ mod query {
                     id: locations_not_in_store[0].id.clone(),
                     code: None,
                     name: None,
-                    on_hold: None
+                    on_hold: None,
+                    parent_id: None,
+                    location_type_id: None,
+                    volume: None,
                 },
             ),
             Err(UpdateLocationError::LocationDoesNotBelongToCurrentStore)
@@ -69,7 +75,10 @@ mod query {
                     id: locations_in_store[0].id.clone(),
                     code: Some(locations_in_store[1].code.clone()),
                     name: None,
-                    on_hold: None
+                    on_hold: None,
+                    parent_id: None,
+                    location_type_id: None,
+                    volume: None,
                 },
             ),
             Err(UpdateLocationError::CodeAlreadyExists)
@@ -101,7 +110,10 @@ mod query {
                     id: location.id.clone(),
                     code: None,
                     name: None,
-                    on_hold: None
+                    on_hold: None,
+                    parent_id: None,
+                    location_type_id: None,
+                    volume: None,
                 },
             ),
             Ok(location.clone())
@@ -128,6 +140,9 @@ mod query {
                     code: Some(location.code.clone()),
                     name: Some(location.name.clone()),
                     on_hold: Some(location.on_hold),
+                    parent_id: None,
+                    location_type_id: None,
+                    volume: None,
                 },
             ),
             Ok(location.clone())
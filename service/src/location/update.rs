@@ -1,6 +1,9 @@
 use super::{
     query::get_location,
-    validate::{check_location_code_is_unique, check_location_exists},
+    validate::{
+        check_location_code_is_unique, check_location_exists, check_location_type_exists,
+        check_parent_location, ParentLocationError,
+    },
 };
 use crate::{
     service_provider::ServiceContext, validate::check_record_belongs_to_current_store,
@@ -14,6 +17,10 @@ pub enum UpdateLocationError {
     LocationDoesNotExist,
     CodeAlreadyExists,
     LocationDoesNotBelongToCurrentStore,
+    ParentLocationDoesNotExist,
+    /// The parent is the location itself or one of its sub locations
+    ParentLocationIsSubLocation,
+    LocationTypeDoesNotExist,
     UpdatedRecordDoesNotExist,
     DatabaseError(RepositoryError),
 }
@@ -52,6 +59,19 @@ pub fn validate(
         return Err(UpdateLocationError::LocationDoesNotBelongToCurrentStore);
     }
 
+    match check_parent_location(&input.id, &input.parent_id, connection)? {
+        Ok(()) => {}
+        Err(ParentLocationError::ParentLocationDoesNotExist) => {
+            return Err(UpdateLocationError::ParentLocationDoesNotExist)
+        }
+        Err(ParentLocationError::ParentLocationIsSubLocation) => {
+            return Err(UpdateLocationError::ParentLocationIsSubLocation)
+        }
+    }
+    if !check_location_type_exists(&input.location_type_id, connection)? {
+        return Err(UpdateLocationError::LocationTypeDoesNotExist);
+    }
+
     Ok(location_row)
 }
 
@@ -61,12 +81,24 @@ pub fn generate(
         code,
         name,
         on_hold,
+        parent_id,
+        location_type_id,
+        volume,
     }: UpdateLocation,
     mut location_row: LocationRow,
 ) -> LocationRow {
     location_row.code = code.unwrap_or(location_row.code);
     location_row.name = name.unwrap_or(location_row.name);
     location_row.on_hold = on_hold.unwrap_or(location_row.on_hold);
+    if parent_id.is_some() {
+        location_row.parent_id = parent_id;
+    }
+    if location_type_id.is_some() {
+        location_row.location_type_id = location_type_id;
+    }
+    if volume.is_some() {
+        location_row.volume = volume;
+    }
     location_row
 }
 
@@ -1,9 +1,10 @@
 use domain::{location::LocationFilter, EqualFilter};
 use repository::{
-    schema::LocationRow, LocationRepository, LocationRowRepository, RepositoryError,
-    StorageConnection,
+    schema::LocationRow, LocationRepository, LocationRowRepository, LocationTypeRowRepository,
+    RepositoryError, StorageConnection,
 };
 
+use super::hierarchy::get_location_path_rows;
 use crate::current_store_id;
 
 pub fn check_location_code_is_unique(
@@ -27,6 +28,47 @@ pub fn check_location_code_is_unique(
     }
 }
 
+pub enum ParentLocationError {
+    ParentLocationDoesNotExist,
+    ParentLocationIsSubLocation,
+}
+
+/// Checks that the parent is a location of the current store and, for an existing location, that
+/// the parent isn't the location itself or one of its sub locations
+pub fn check_parent_location(
+    id: &str,
+    parent_id: &Option<String>,
+    connection: &StorageConnection,
+) -> Result<Result<(), ParentLocationError>, RepositoryError> {
+    let parent_id = match parent_id {
+        Some(parent_id) => parent_id,
+        None => return Ok(Ok(())),
+    };
+    let parent = match LocationRowRepository::new(connection).find_one_by_id(parent_id)? {
+        Some(parent) if parent.store_id == current_store_id(connection)? => parent,
+        _ => return Ok(Err(ParentLocationError::ParentLocationDoesNotExist)),
+    };
+    let is_sub_location = get_location_path_rows(connection, parent)?
+        .iter()
+        .any(|location| location.id == id);
+    if is_sub_location {
+        return Ok(Err(ParentLocationError::ParentLocationIsSubLocation));
+    }
+    Ok(Ok(()))
+}
+
+pub fn check_location_type_exists(
+    location_type_id: &Option<String>,
+    connection: &StorageConnection,
+) -> Result<bool, RepositoryError> {
+    match location_type_id {
+        Some(location_type_id) => Ok(LocationTypeRowRepository::new(connection)
+            .find_one_by_id(location_type_id)?
+            .is_some()),
+        None => Ok(true),
+    }
+}
+
 pub fn check_location_exists(
     id: &str,
     connection: &StorageConnection,
@@ -0,0 +1,54 @@
+use domain::stock_line::StockLine;
+use repository::{schema::LocationMovementRow, LocationMovementRowRepository, RepositoryError};
+
+use crate::service_provider::ServiceContext;
+
+use self::move_stock::{move_stock, MoveStock, MoveStockError};
+
+pub mod move_stock;
+
+#[cfg(test)]
+mod tests;
+
+/// Logged move and the stock line now holding the moved packs
+#[derive(Debug, PartialEq)]
+pub struct MovedStock {
+    pub movement: LocationMovementRow,
+    pub stock_line: StockLine,
+}
+
+pub trait LocationMovementServiceTrait: Sync + Send {
+    /// Moves packs of a stock line to another location, splitting the stock line if only some of
+    /// its packs are moved
+    fn move_stock(
+        &self,
+        ctx: &ServiceContext,
+        store_id: &str,
+        input: MoveStock,
+    ) -> Result<MovedStock, MoveStockError> {
+        move_stock(ctx, store_id, input)
+    }
+
+    fn get_stock_line_movements(
+        &self,
+        ctx: &ServiceContext,
+        store_id: &str,
+        stock_line_id: &str,
+    ) -> Result<Vec<LocationMovementRow>, RepositoryError> {
+        LocationMovementRowRepository::new(&ctx.connection)
+            .find_many_by_stock_line_id(store_id, stock_line_id)
+    }
+
+    fn get_location_movements(
+        &self,
+        ctx: &ServiceContext,
+        store_id: &str,
+        location_id: &str,
+    ) -> Result<Vec<LocationMovementRow>, RepositoryError> {
+        LocationMovementRowRepository::new(&ctx.connection)
+            .find_many_by_location_id(store_id, location_id)
+    }
+}
+
+pub struct LocationMovementService {}
+impl LocationMovementServiceTrait for LocationMovementService {}
@@ -0,0 +1,317 @@
+use chrono::Utc;
+use domain::{
+    stock_line::{StockLine, StockLineFilter},
+    EqualFilter,
+};
+use repository::{
+    schema::{
+        InvoiceLineRow, InvoiceLineRowType, InvoiceRow, InvoiceRowStatus, InvoiceRowType, ItemRow,
+        LocationMovementRow, NumberRowType, StockLineRow,
+    },
+    InvoiceLineRowRepository, InvoiceRepository, ItemRepository, LocationMovementRowRepository,
+    LocationRowRepository, RepositoryError, StockLineRepository, StockLineRowRepository,
+    StorageConnection, StoreRowRepository,
+};
+use util::uuid::uuid;
+
+use crate::{
+    location::hierarchy::check_location_suits_item, number::next_number,
+    service_provider::ServiceContext,
+};
+
+use super::MovedStock;
+
+pub struct MoveStock {
+    /// Id of the new location movement
+    pub id: String,
+    pub stock_line_id: String,
+    /// Moving fewer packs than the stock line holds splits them off into a new stock line. Every
+    /// move is recorded as a verified location movement invoice so it shows in the stock ledger
+    pub number_of_packs: u32,
+    pub location_id: String,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum MoveStockError {
+    DatabaseError(RepositoryError),
+    InternalError(String),
+    MovementAlreadyExists,
+    StockLineDoesNotExist,
+    NumberOfPacksBelowOne,
+    /// Not enough available packs in the stock line, packs reserved for shipments can only be
+    /// moved with the whole stock line
+    ReductionBelowZero,
+    LocationDoesNotExist,
+    LocationIsOnHold,
+    /// The item is restricted to a location type the location doesn't provide
+    LocationNotSuitableForItem,
+    /// The stock line is already in the location
+    LocationUnchanged,
+}
+
+pub fn move_stock(
+    ctx: &ServiceContext,
+    store_id: &str,
+    input: MoveStock,
+) -> Result<MovedStock, MoveStockError> {
+    let moved = ctx
+        .connection
+        .transaction_sync(|connection| {
+            move_stock_in_transaction(connection, store_id, input, false)
+        })
+        .map_err(|error| error.to_inner_error())?;
+    Ok(moved)
+}
+
+/// Moves stock into a quarantine location, unlike other moves the location may be on hold
+pub(crate) fn quarantine_stock(
+    connection: &StorageConnection,
+    store_id: &str,
+    input: MoveStock,
+) -> Result<MovedStock, MoveStockError> {
+    connection
+        .transaction_sync(|connection| move_stock_in_transaction(connection, store_id, input, true))
+        .map_err(|error| error.to_inner_error())
+}
+
+fn move_stock_in_transaction(
+    connection: &StorageConnection,
+    store_id: &str,
+    input: MoveStock,
+    allow_location_on_hold: bool,
+) -> Result<MovedStock, MoveStockError> {
+    let stock_line = validate(connection, store_id, &input, allow_location_on_hold)?;
+    let (stock_line_rows, movement) = generate(stock_line.clone(), &input);
+
+    let repository = StockLineRowRepository::new(connection);
+    for row in &stock_line_rows {
+        repository.upsert_one(row)?;
+    }
+    LocationMovementRowRepository::new(connection).upsert_one(&movement)?;
+    if let Some(moved) = stock_line_rows.last() {
+        let (invoice, lines) = generate_movement_invoice(
+            connection,
+            store_id,
+            &stock_line,
+            moved,
+            movement.number_of_packs,
+        )?;
+        InvoiceRepository::new(connection).upsert_one(&invoice)?;
+        let line_repository = InvoiceLineRowRepository::new(connection);
+        for line in &lines {
+            line_repository.upsert_one(line)?;
+        }
+    }
+
+    let stock_line = get_stock_line(connection, &movement.stock_line_id)?;
+    Ok(MovedStock {
+        movement,
+        stock_line,
+    })
+}
+
+fn validate(
+    connection: &StorageConnection,
+    store_id: &str,
+    input: &MoveStock,
+    allow_location_on_hold: bool,
+) -> Result<StockLineRow, MoveStockError> {
+    if LocationMovementRowRepository::new(connection)
+        .find_one_by_id(&input.id)?
+        .is_some()
+    {
+        return Err(MoveStockError::MovementAlreadyExists);
+    }
+    let stock_line =
+        match StockLineRowRepository::new(connection).find_one_by_id(&input.stock_line_id) {
+            Ok(stock_line) if stock_line.store_id == store_id => stock_line,
+            Ok(_) | Err(RepositoryError::NotFound) => {
+                return Err(MoveStockError::StockLineDoesNotExist)
+            }
+            Err(error) => return Err(error.into()),
+        };
+    if input.number_of_packs < 1 {
+        return Err(MoveStockError::NumberOfPacksBelowOne);
+    }
+    let number_of_packs = input.number_of_packs as i32;
+    if number_of_packs != stock_line.total_number_of_packs
+        && number_of_packs > stock_line.available_number_of_packs
+    {
+        return Err(MoveStockError::ReductionBelowZero);
+    }
+    let location = LocationRowRepository::new(connection)
+        .find_one_by_id(&input.location_id)?
+        .filter(|location| location.store_id == store_id)
+        .ok_or(MoveStockError::LocationDoesNotExist)?;
+    if location.on_hold && !allow_location_on_hold {
+        return Err(MoveStockError::LocationIsOnHold);
+    }
+    if stock_line.location_id.as_deref() == Some(location.id.as_str()) {
+        return Err(MoveStockError::LocationUnchanged);
+    }
+    if !check_location_suits_item(connection, store_id, &stock_line.item_id, location)? {
+        return Err(MoveStockError::LocationNotSuitableForItem);
+    }
+    Ok(stock_line)
+}
+
+/// Moves the whole stock line or splits the moved packs off into a new stock line, the moved
+/// stock line is last
+fn generate(
+    stock_line: StockLineRow,
+    input: &MoveStock,
+) -> (Vec<StockLineRow>, LocationMovementRow) {
+    let number_of_packs = input.number_of_packs as i32;
+    let from_location_id = stock_line.location_id.clone();
+
+    let (stock_line_rows, stock_line_id, split_from_stock_line_id) =
+        if number_of_packs == stock_line.total_number_of_packs {
+            let stock_line_id = stock_line.id.clone();
+            let moved = StockLineRow {
+                location_id: Some(input.location_id.clone()),
+                ..stock_line
+            };
+            (vec![moved], stock_line_id, None)
+        } else {
+            let split = StockLineRow {
+                id: uuid(),
+                location_id: Some(input.location_id.clone()),
+                available_number_of_packs: number_of_packs,
+                total_number_of_packs: number_of_packs,
+                ..stock_line.clone()
+            };
+            let split_id = split.id.clone();
+            let split_from_id = stock_line.id.clone();
+            let remaining = StockLineRow {
+                available_number_of_packs: stock_line.available_number_of_packs - number_of_packs,
+                total_number_of_packs: stock_line.total_number_of_packs - number_of_packs,
+                ..stock_line
+            };
+            (vec![remaining, split], split_id, Some(split_from_id))
+        };
+
+    let movement = LocationMovementRow {
+        id: input.id.clone(),
+        store_id: stock_line_rows[0].store_id.clone(),
+        stock_line_id,
+        split_from_stock_line_id,
+        from_location_id,
+        to_location_id: input.location_id.clone(),
+        number_of_packs,
+        moved_datetime: Utc::now().naive_utc(),
+    };
+    (stock_line_rows, movement)
+}
+
+fn generate_movement_line(
+    invoice_id: &str,
+    item: &ItemRow,
+    r#type: InvoiceLineRowType,
+    stock_line: &StockLineRow,
+    number_of_packs: i32,
+) -> InvoiceLineRow {
+    let total = stock_line.cost_price_per_pack * number_of_packs as f64;
+    InvoiceLineRow {
+        id: uuid(),
+        invoice_id: invoice_id.to_string(),
+        item_id: item.id.clone(),
+        item_name: item.name.clone(),
+        item_code: item.code.clone(),
+        stock_line_id: Some(stock_line.id.clone()),
+        location_id: stock_line.location_id.clone(),
+        batch: stock_line.batch.clone(),
+        expiry_date: stock_line.expiry_date,
+        pack_size: stock_line.pack_size,
+        cost_price_per_pack: stock_line.cost_price_per_pack,
+        sell_price_per_pack: stock_line.sell_price_per_pack,
+        total_before_tax: total,
+        total_after_tax: total,
+        tax: None,
+        r#type,
+        number_of_packs,
+        note: None,
+        return_reason_id: None,
+        directions: None,
+    }
+}
+
+/// Records the moved packs as taken out of the stock line in its old location and put into the
+/// moved stock line in the new location. The moved stock line is the stock line itself when all
+/// packs are moved, otherwise the stock line split off.
+fn generate_movement_invoice(
+    connection: &StorageConnection,
+    store_id: &str,
+    from: &StockLineRow,
+    moved: &StockLineRow,
+    number_of_packs: i32,
+) -> Result<(InvoiceRow, Vec<InvoiceLineRow>), MoveStockError> {
+    let store = StoreRowRepository::new(connection)
+        .find_one_by_id(store_id)?
+        .ok_or_else(|| MoveStockError::InternalError(format!("Can't find store {}!", store_id)))?;
+    let item = ItemRepository::new(connection)
+        .find_one_by_id(&moved.item_id)?
+        .ok_or_else(|| {
+            MoveStockError::InternalError(format!(
+                "Can't find item {} of stock line {}!",
+                moved.item_id, moved.id
+            ))
+        })?;
+    let id = uuid();
+    let lines = vec![
+        generate_movement_line(
+            &id,
+            &item,
+            InvoiceLineRowType::StockOut,
+            from,
+            number_of_packs,
+        ),
+        generate_movement_line(
+            &id,
+            &item,
+            InvoiceLineRowType::StockIn,
+            moved,
+            number_of_packs,
+        ),
+    ];
+
+    // the store moves its own stock, the store is the other party
+    let now = Utc::now().naive_utc();
+    let invoice = InvoiceRow {
+        id,
+        name_id: store.name_id,
+        store_id: store.id.clone(),
+        invoice_number: next_number(connection, &NumberRowType::LocationMovement, &store.id)?,
+        r#type: InvoiceRowType::LocationMovement,
+        status: InvoiceRowStatus::Verified,
+        on_hold: false,
+        comment: None,
+        their_reference: None,
+        created_datetime: now,
+        allocated_datetime: None,
+        picked_datetime: None,
+        shipped_datetime: None,
+        delivered_datetime: None,
+        verified_datetime: Some(now),
+        color: None,
+        prescriber_id: None,
+    };
+    Ok((invoice, lines))
+}
+
+fn get_stock_line(
+    connection: &StorageConnection,
+    stock_line_id: &str,
+) -> Result<StockLine, MoveStockError> {
+    StockLineRepository::new(connection)
+        .query_by_filter(StockLineFilter::new().id(EqualFilter::equal_to(stock_line_id)))?
+        .pop()
+        .ok_or(MoveStockError::InternalError(
+            "Moved stock line not found".to_string(),
+        ))
+}
+
+impl From<RepositoryError> for MoveStockError {
+    fn from(error: RepositoryError) -> Self {
+        MoveStockError::DatabaseError(error)
+    }
+}
@@ -0,0 +1,389 @@
+#[cfg(test)]
+mod location_movement_test {
+    use chrono::Utc;
+    use domain::EqualFilter;
+    use repository::{
+        mock::{mock_name_store_b, mock_stock_line_a, mock_store_a, MockDataInserts},
+        schema::{
+            InvoiceLineRow, InvoiceLineRowType, InvoiceRow, InvoiceRowStatus, InvoiceRowType,
+        },
+        test_db::setup_all,
+        InvoiceLineRowRepository, InvoiceRepository, StockLineRowRepository,
+    };
+
+    use crate::{
+        location_movement::move_stock::{MoveStock, MoveStockError},
+        service_provider::ServiceProvider,
+        stock_movement::StockLedgerInput,
+        stock_on_hand::historical::HistoricalStockOnHandGroupBy,
+    };
+
+    fn input(id: &str, stock_line_id: &str, number_of_packs: u32, location_id: &str) -> MoveStock {
+        MoveStock {
+            id: id.to_string(),
+            stock_line_id: stock_line_id.to_string(),
+            number_of_packs,
+            location_id: location_id.to_string(),
+        }
+    }
+
+    #[actix_rt::test]
+    async fn move_stock() {
+        let (_, connection, connection_manager, _) =
+            setup_all("move_stock", MockDataInserts::all()).await;
+        let service_provider = ServiceProvider::new(connection_manager);
+        let context = service_provider.context().unwrap();
+        let service = &service_provider.location_movement_service;
+        let store_id = &mock_store_a().id;
+        // 30 available of 40 packs
+        let stock_line_id = &mock_stock_line_a().id;
+
+        // StockLineDoesNotExist
+        assert_eq!(
+            service.move_stock(
+                &context,
+                store_id,
+                input("move", "invalid", 1, "location_1")
+            ),
+            Err(MoveStockError::StockLineDoesNotExist)
+        );
+        // NumberOfPacksBelowOne
+        assert_eq!(
+            service.move_stock(
+                &context,
+                store_id,
+                input("move", stock_line_id, 0, "location_1")
+            ),
+            Err(MoveStockError::NumberOfPacksBelowOne)
+        );
+        // ReductionBelowZero
+        assert_eq!(
+            service.move_stock(
+                &context,
+                store_id,
+                input("move", stock_line_id, 31, "location_1")
+            ),
+            Err(MoveStockError::ReductionBelowZero)
+        );
+        // LocationDoesNotExist
+        assert_eq!(
+            service.move_stock(
+                &context,
+                store_id,
+                input("move", stock_line_id, 1, "location_in_another_store")
+            ),
+            Err(MoveStockError::LocationDoesNotExist)
+        );
+        // LocationIsOnHold
+        assert_eq!(
+            service.move_stock(
+                &context,
+                store_id,
+                input("move", stock_line_id, 1, "location_on_hold")
+            ),
+            Err(MoveStockError::LocationIsOnHold)
+        );
+
+        // some packs are split off into a new stock line
+        let moved = service
+            .move_stock(
+                &context,
+                store_id,
+                input("move", stock_line_id, 10, "location_1"),
+            )
+            .unwrap();
+        let split = moved.stock_line;
+        assert_ne!(&split.id, stock_line_id);
+        assert_eq!(split.location_id, Some("location_1".to_string()));
+        assert_eq!(split.available_number_of_packs, 10);
+        assert_eq!(split.total_number_of_packs, 10);
+        assert_eq!(moved.movement.stock_line_id, split.id);
+        assert_eq!(
+            moved.movement.split_from_stock_line_id,
+            Some(stock_line_id.clone())
+        );
+        assert_eq!(moved.movement.from_location_id, None);
+        assert_eq!(moved.movement.number_of_packs, 10);
+        let remaining = StockLineRowRepository::new(&connection)
+            .find_one_by_id(stock_line_id)
+            .unwrap();
+        assert_eq!(remaining.location_id, None);
+        assert_eq!(remaining.available_number_of_packs, 20);
+        assert_eq!(remaining.total_number_of_packs, 30);
+
+        // MovementAlreadyExists
+        assert_eq!(
+            service.move_stock(
+                &context,
+                store_id,
+                input("move", &split.id, 10, "location_2")
+            ),
+            Err(MoveStockError::MovementAlreadyExists)
+        );
+        // LocationUnchanged
+        assert_eq!(
+            service.move_stock(
+                &context,
+                store_id,
+                input("move2", &split.id, 10, "location_1")
+            ),
+            Err(MoveStockError::LocationUnchanged)
+        );
+
+        // all packs move the stock line
+        let moved = service
+            .move_stock(
+                &context,
+                store_id,
+                input("move2", &split.id, 10, "location_2"),
+            )
+            .unwrap();
+        assert_eq!(moved.stock_line.id, split.id);
+        assert_eq!(moved.stock_line.location_id, Some("location_2".to_string()));
+        assert_eq!(moved.movement.split_from_stock_line_id, None);
+        assert_eq!(
+            moved.movement.from_location_id,
+            Some("location_1".to_string())
+        );
+
+        // packs reserved for shipments move with the whole stock line
+        let moved = service
+            .move_stock(
+                &context,
+                store_id,
+                input("move3", stock_line_id, 30, "location_2"),
+            )
+            .unwrap();
+        assert_eq!(&moved.stock_line.id, stock_line_id);
+        assert_eq!(moved.stock_line.available_number_of_packs, 20);
+        assert_eq!(moved.stock_line.total_number_of_packs, 30);
+
+        // the log per stock line includes packs split off it
+        let mut ids: Vec<String> = service
+            .get_stock_line_movements(&context, store_id, stock_line_id)
+            .unwrap()
+            .into_iter()
+            .map(|movement| movement.id)
+            .collect();
+        ids.sort();
+        assert_eq!(ids, vec!["move", "move3"]);
+
+        // the log per location includes moves in and out
+        let mut ids: Vec<String> = service
+            .get_location_movements(&context, store_id, "location_1")
+            .unwrap()
+            .into_iter()
+            .map(|movement| movement.id)
+            .collect();
+        ids.sort();
+        assert_eq!(ids, vec!["move", "move2"]);
+        assert_eq!(
+            service
+                .get_location_movements(&context, store_id, "location_2")
+                .unwrap()
+                .len(),
+            2
+        );
+    }
+
+    #[actix_rt::test]
+    async fn move_stock_partial_ledger() {
+        let (_, _, connection_manager, _) =
+            setup_all("move_stock_partial_ledger", MockDataInserts::all()).await;
+        let service_provider = ServiceProvider::new(connection_manager);
+        let context = service_provider.context().unwrap();
+        let store_id = &mock_store_a().id;
+        // 40 packs of size 1
+        let stock_line = mock_stock_line_a();
+
+        // mock movements are dated at setup, the ledger and history only cover the move
+        let before_move = Utc::now().naive_utc();
+        let split = service_provider
+            .location_movement_service
+            .move_stock(
+                &context,
+                store_id,
+                input("move", &stock_line.id, 10, "location_1"),
+            )
+            .unwrap()
+            .stock_line;
+        let after_move = Utc::now().naive_utc();
+
+        // the split shows in the ledger of both stock lines
+        let ledger = |stock_line_id: &str| {
+            service_provider
+                .stock_movement_service
+                .get_stock_ledger(
+                    &context,
+                    store_id,
+                    StockLedgerInput {
+                        stock_line_id: Some(stock_line_id.to_string()),
+                        from_datetime: Some(before_move),
+                        ..Default::default()
+                    },
+                )
+                .unwrap()
+        };
+        let original_ledger = ledger(&stock_line.id);
+        assert_eq!(original_ledger.lines.len(), 1);
+        assert_eq!(original_ledger.lines[0].quantity_out, 10);
+        assert_eq!(
+            original_ledger.opening_balance - original_ledger.closing_balance,
+            10
+        );
+        let split_ledger = ledger(&split.id);
+        assert_eq!(split_ledger.opening_balance, 0);
+        assert_eq!(split_ledger.lines.len(), 1);
+        assert_eq!(split_ledger.lines[0].quantity_in, 10);
+        assert_eq!(split_ledger.closing_balance, 10);
+
+        let historical = |datetime| {
+            service_provider
+                .stock_on_hand_service
+                .get_historical_stock_on_hand(
+                    &context,
+                    store_id,
+                    datetime,
+                    HistoricalStockOnHandGroupBy::Batch,
+                    Some(EqualFilter::equal_to(&stock_line.item_id)),
+                )
+                .unwrap()
+                .into_iter()
+                .filter(|row| {
+                    row.stock_line_id == Some(stock_line.id.clone())
+                        || row.stock_line_id == Some(split.id.clone())
+                })
+                .map(|row| (row.stock_line_id.unwrap(), row.total_units))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(historical(before_move), vec![(stock_line.id.clone(), 40)]);
+        let mut after = historical(after_move);
+        after.sort();
+        let mut expected = vec![(stock_line.id.clone(), 30), (split.id.clone(), 10)];
+        expected.sort();
+        assert_eq!(after, expected);
+    }
+
+    #[actix_rt::test]
+    async fn move_stock_whole_line_ledger() {
+        let (_, connection, connection_manager, _) =
+            setup_all("move_stock_whole_line_ledger", MockDataInserts::all()).await;
+        let service_provider = ServiceProvider::new(connection_manager);
+        let context = service_provider.context().unwrap();
+        let service = &service_provider.location_movement_service;
+        let store_id = &mock_store_a().id;
+        // 40 packs of size 1
+        let stock_line = mock_stock_line_a();
+
+        // mock movements are dated at setup, the ledger only covers the moves
+        let before_move = Utc::now().naive_utc();
+        service
+            .move_stock(
+                &context,
+                store_id,
+                input("move", &stock_line.id, 40, "location_1"),
+            )
+            .unwrap();
+        let moved = service
+            .move_stock(
+                &context,
+                store_id,
+                input("move2", &stock_line.id, 40, "location_2"),
+            )
+            .unwrap()
+            .stock_line;
+
+        // ship 5 packs from the new location
+        let now = Utc::now().naive_utc();
+        InvoiceRepository::new(&connection)
+            .upsert_one(&InvoiceRow {
+                id: "shipment".to_string(),
+                name_id: mock_name_store_b().id,
+                store_id: store_id.clone(),
+                invoice_number: 1,
+                r#type: InvoiceRowType::OutboundShipment,
+                status: InvoiceRowStatus::Picked,
+                on_hold: false,
+                comment: None,
+                their_reference: None,
+                created_datetime: now,
+                allocated_datetime: None,
+                picked_datetime: Some(now),
+                shipped_datetime: None,
+                delivered_datetime: None,
+                verified_datetime: None,
+                color: None,
+                prescriber_id: None,
+            })
+            .unwrap();
+        InvoiceLineRowRepository::new(&connection)
+            .upsert_one(&InvoiceLineRow {
+                id: "shipment_line".to_string(),
+                invoice_id: "shipment".to_string(),
+                item_id: stock_line.item_id.clone(),
+                item_name: "".to_string(),
+                item_code: "".to_string(),
+                stock_line_id: Some(stock_line.id.clone()),
+                location_id: moved.location_id.clone(),
+                batch: None,
+                expiry_date: None,
+                pack_size: 1,
+                cost_price_per_pack: 0.0,
+                sell_price_per_pack: 0.0,
+                total_before_tax: 0.0,
+                total_after_tax: 0.0,
+                tax: None,
+                r#type: InvoiceLineRowType::StockOut,
+                number_of_packs: 5,
+                note: None,
+                return_reason_id: None,
+                directions: None,
+            })
+            .unwrap();
+
+        let ledger = |location_id: &str| {
+            service_provider
+                .stock_movement_service
+                .get_stock_ledger(
+                    &context,
+                    store_id,
+                    StockLedgerInput {
+                        location_id: Some(location_id.to_string()),
+                        from_datetime: Some(before_move),
+                        ..Default::default()
+                    },
+                )
+                .unwrap()
+        };
+        // the packs passed through the first location
+        let first = ledger("location_1");
+        assert_eq!(first.lines.len(), 2);
+        assert_eq!(first.lines[0].quantity_in, 40);
+        assert_eq!(first.lines[1].quantity_out, 40);
+        assert_eq!(first.closing_balance, first.opening_balance);
+        // and are shipped from the second one
+        let second = ledger("location_2");
+        assert_eq!(second.lines.len(), 2);
+        assert_eq!(second.lines[0].quantity_in, 40);
+        assert_eq!(second.lines[1].quantity_out, 5);
+        assert_eq!(second.closing_balance - second.opening_balance, 35);
+
+        // the stock line itself didn't change
+        let stock_line_ledger = service_provider
+            .stock_movement_service
+            .get_stock_ledger(
+                &context,
+                store_id,
+                StockLedgerInput {
+                    stock_line_id: Some(stock_line.id.clone()),
+                    from_datetime: Some(before_move),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        assert_eq!(
+            stock_line_ledger.opening_balance - stock_line_ledger.closing_balance,
+            5
+        );
+    }
+}
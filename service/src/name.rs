@@ -1,26 +0,0 @@
-use domain::{
-    name::{Name, NameFilter, NameSort},
-    PaginationOption,
-};
-use repository::{NameQueryRepository, StorageConnectionManager};
-
-use super::{get_default_pagination, i64_to_u32, ListError, ListResult};
-
-pub const MAX_LIMIT: u32 = 1000;
-pub const MIN_LIMIT: u32 = 1;
-
-pub fn get_names(
-    connection_manager: &StorageConnectionManager,
-    pagination: Option<PaginationOption>,
-    filter: Option<NameFilter>,
-    sort: Option<NameSort>,
-) -> Result<ListResult<Name>, ListError> {
-    let pagination = get_default_pagination(pagination, MAX_LIMIT, MIN_LIMIT)?;
-    let connection = connection_manager.connection()?;
-    let repository = NameQueryRepository::new(&connection);
-
-    Ok(ListResult {
-        rows: repository.query(pagination, filter.clone(), sort)?,
-        count: i64_to_u32(repository.count(filter)?),
-    })
-}
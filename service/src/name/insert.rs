@@ -0,0 +1,172 @@
+use domain::name::{Name, NameType};
+use repository::{
+    schema::{
+        NameRow, NameRowType, NameStoreJoinRow, SyncOutRowActionType, SyncOutRowTableNameType,
+    },
+    NameRepository, NameStoreJoinRepository, RepositoryError, StorageConnection,
+};
+use util::uuid::uuid;
+
+use crate::{
+    service_provider::ServiceContext, sync_out::queue_upload, validate::check_store_exists,
+    SingleRecordError,
+};
+
+use super::{
+    get_name,
+    tag::set_name_tags,
+    validate::{check_name_code_is_unique, check_name_exists},
+};
+
+pub struct InsertName {
+    pub id: String,
+    pub code: String,
+    pub name: String,
+    pub r#type: NameType,
+    pub is_customer: bool,
+    pub is_supplier: bool,
+    pub is_manufacturer: bool,
+    pub is_donor: bool,
+    pub phone: Option<String>,
+    pub email: Option<String>,
+    pub website: Option<String>,
+    pub address1: Option<String>,
+    pub address2: Option<String>,
+    pub country: Option<String>,
+    pub comment: Option<String>,
+    /// Tag (category) names, missing tags are created
+    pub tags: Vec<String>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum InsertNameError {
+    DatabaseError(RepositoryError),
+    InternalError(String),
+    NameAlreadyExists,
+    NameWithCodeAlreadyExists,
+    NameIsEmpty,
+    /// Store names are only created in central
+    CannotCreateStoreName,
+    InvalidStore,
+}
+
+fn validate(
+    connection: &StorageConnection,
+    store_id: &str,
+    input: &InsertName,
+) -> Result<(), InsertNameError> {
+    if check_name_exists(connection, &input.id)?.is_some() {
+        return Err(InsertNameError::NameAlreadyExists);
+    }
+    if !check_name_code_is_unique(connection, &input.id, &input.code)? {
+        return Err(InsertNameError::NameWithCodeAlreadyExists);
+    }
+    if input.name.trim().is_empty() {
+        return Err(InsertNameError::NameIsEmpty);
+    }
+    if input.r#type == NameType::Store {
+        return Err(InsertNameError::CannotCreateStoreName);
+    }
+    if !check_store_exists(connection, store_id)? {
+        return Err(InsertNameError::InvalidStore);
+    }
+    Ok(())
+}
+
+/// The store the name is created in becomes its home store
+fn generate(
+    store_id: &str,
+    InsertName {
+        id,
+        code,
+        name,
+        r#type,
+        is_customer,
+        is_supplier,
+        is_manufacturer,
+        is_donor,
+        phone,
+        email,
+        website,
+        address1,
+        address2,
+        country,
+        comment,
+        tags: _,
+    }: InsertName,
+) -> (NameRow, NameStoreJoinRow) {
+    let name_store_join = NameStoreJoinRow {
+        id: uuid(),
+        name_id: id.clone(),
+        store_id: store_id.to_string(),
+        name_is_customer: is_customer,
+        name_is_supplier: is_supplier,
+    };
+    let name = NameRow {
+        id,
+        name,
+        code,
+        is_customer,
+        is_supplier,
+        r#type: NameRowType::from(r#type),
+        first_name: None,
+        last_name: None,
+        date_of_birth: None,
+        phone,
+        home_store_id: Some(store_id.to_string()),
+        is_manufacturer,
+        is_donor,
+        email,
+        website,
+        address1,
+        address2,
+        country,
+        comment,
+    };
+    (name, name_store_join)
+}
+
+pub fn insert_name(
+    ctx: &ServiceContext,
+    store_id: &str,
+    mut input: InsertName,
+) -> Result<Name, InsertNameError> {
+    let name = ctx
+        .connection
+        .transaction_sync(|connection| {
+            validate(connection, store_id, &input)?;
+            let tags = std::mem::take(&mut input.tags);
+            let (new_name, name_store_join) = generate(store_id, input);
+            NameRepository::new(connection).upsert_one(&new_name)?;
+            NameStoreJoinRepository::new(connection).upsert_one(&name_store_join)?;
+            queue_upload(
+                connection,
+                store_id,
+                SyncOutRowTableNameType::Name,
+                &new_name.id,
+                SyncOutRowActionType::Insert,
+            )?;
+            set_name_tags(connection, store_id, &new_name.id, tags)?;
+
+            get_name(connection, &new_name.id).map_err(InsertNameError::from)
+        })
+        .map_err(|error| error.to_inner_error())?;
+    Ok(name)
+}
+
+impl From<RepositoryError> for InsertNameError {
+    fn from(error: RepositoryError) -> Self {
+        InsertNameError::DatabaseError(error)
+    }
+}
+
+impl From<SingleRecordError> for InsertNameError {
+    fn from(error: SingleRecordError) -> Self {
+        match error {
+            SingleRecordError::DatabaseError(error) => InsertNameError::DatabaseError(error),
+            SingleRecordError::NotFound(_) => {
+                InsertNameError::InternalError("Failed to read the just inserted name!".to_string())
+            }
+        }
+    }
+}
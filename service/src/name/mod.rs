@@ -0,0 +1,85 @@
+use domain::{
+    name::{Name, NameFilter, NameSort},
+    EqualFilter, PaginationOption,
+};
+use repository::{
+    schema::NameTagRow, NameQueryRepository, NameTagRowRepository, RepositoryError,
+    StorageConnection, StorageConnectionManager,
+};
+
+use crate::service_provider::ServiceContext;
+
+use self::{
+    insert::{insert_name, InsertName, InsertNameError},
+    update::{update_name, UpdateName, UpdateNameError},
+};
+
+use super::{get_default_pagination, i64_to_u32, ListError, ListResult, SingleRecordError};
+
+pub mod insert;
+pub mod tag;
+pub mod update;
+pub mod validate;
+
+#[cfg(test)]
+mod tests;
+
+pub const MAX_LIMIT: u32 = 1000;
+pub const MIN_LIMIT: u32 = 1;
+
+pub fn get_names(
+    connection_manager: &StorageConnectionManager,
+    pagination: Option<PaginationOption>,
+    filter: Option<NameFilter>,
+    sort: Option<NameSort>,
+) -> Result<ListResult<Name>, ListError> {
+    let pagination = get_default_pagination(pagination, MAX_LIMIT, MIN_LIMIT)?;
+    let connection = connection_manager.connection()?;
+    let repository = NameQueryRepository::new(&connection);
+
+    Ok(ListResult {
+        rows: repository.query(pagination, filter.clone(), sort)?,
+        count: i64_to_u32(repository.count(filter)?),
+    })
+}
+
+pub fn get_name(connection: &StorageConnection, id: &str) -> Result<Name, SingleRecordError> {
+    let mut result = NameQueryRepository::new(connection)
+        .query_by_filter(NameFilter::new().id(EqualFilter::equal_to(id)))?;
+
+    if let Some(record) = result.pop() {
+        Ok(record)
+    } else {
+        Err(SingleRecordError::NotFound(id.to_string()))
+    }
+}
+
+/// Names created locally (customers, suppliers or patients) have the creating store as their home
+/// store and can only be edited there. All changes are queued for upload to central.
+pub trait NameServiceTrait: Sync + Send {
+    /// All name tags (categories), ordered by name
+    fn get_name_tags(&self, ctx: &ServiceContext) -> Result<Vec<NameTagRow>, RepositoryError> {
+        NameTagRowRepository::new(&ctx.connection).find_all()
+    }
+
+    fn insert_name(
+        &self,
+        ctx: &ServiceContext,
+        store_id: &str,
+        input: InsertName,
+    ) -> Result<Name, InsertNameError> {
+        insert_name(ctx, store_id, input)
+    }
+
+    fn update_name(
+        &self,
+        ctx: &ServiceContext,
+        store_id: &str,
+        input: UpdateName,
+    ) -> Result<Name, UpdateNameError> {
+        update_name(ctx, store_id, input)
+    }
+}
+
+pub struct NameService {}
+impl NameServiceTrait for NameService {}
@@ -0,0 +1,80 @@
+use repository::{
+    schema::{NameTagJoinRow, NameTagRow, SyncOutRowActionType, SyncOutRowTableNameType},
+    NameTagJoinRowRepository, NameTagRowRepository, RepositoryError, StorageConnection,
+};
+use util::uuid::uuid;
+
+use crate::sync_out::queue_upload;
+
+/// Replaces the tags of a name with the given tag names. Tags that don't exist yet are created.
+/// All new and removed rows are queued for upload.
+pub fn set_name_tags(
+    connection: &StorageConnection,
+    store_id: &str,
+    name_id: &str,
+    tags: Vec<String>,
+) -> Result<(), RepositoryError> {
+    let mut tags: Vec<String> = tags
+        .into_iter()
+        .map(|tag| tag.trim().to_string())
+        .filter(|tag| !tag.is_empty())
+        .collect();
+    tags.sort();
+    tags.dedup();
+
+    let tag_repo = NameTagRowRepository::new(connection);
+    let tag_join_repo = NameTagJoinRowRepository::new(connection);
+    let existing = tag_join_repo.find_many_by_name_id(&[name_id.to_string()])?;
+
+    for (tag_join, tag) in &existing {
+        if !tags.contains(&tag.name) {
+            tag_join_repo.delete(&tag_join.id)?;
+            queue_upload(
+                connection,
+                store_id,
+                SyncOutRowTableNameType::NameTagJoin,
+                &tag_join.id,
+                SyncOutRowActionType::Delete,
+            )?;
+        }
+    }
+
+    for tag_name in tags {
+        if existing.iter().any(|(_, tag)| tag.name == tag_name) {
+            continue;
+        }
+        let tag = match tag_repo.find_one_by_name(&tag_name)? {
+            Some(tag) => tag,
+            None => {
+                let tag = NameTagRow {
+                    id: uuid(),
+                    name: tag_name,
+                };
+                tag_repo.upsert_one(&tag)?;
+                queue_upload(
+                    connection,
+                    store_id,
+                    SyncOutRowTableNameType::NameTag,
+                    &tag.id,
+                    SyncOutRowActionType::Insert,
+                )?;
+                tag
+            }
+        };
+        let tag_join = NameTagJoinRow {
+            id: uuid(),
+            name_id: name_id.to_string(),
+            name_tag_id: tag.id,
+        };
+        tag_join_repo.upsert_one(&tag_join)?;
+        queue_upload(
+            connection,
+            store_id,
+            SyncOutRowTableNameType::NameTagJoin,
+            &tag_join.id,
+            SyncOutRowActionType::Insert,
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,209 @@
+#[cfg(test)]
+mod name_test {
+    use domain::{
+        name::{NameFilter, NameType},
+        EqualFilter,
+    };
+    use repository::{
+        mock::{mock_name_a, mock_store_a, mock_store_b, MockDataInserts},
+        schema::{SyncOutRowActionType, SyncOutRowTableNameType},
+        test_db::setup_all,
+        NameQueryRepository, NameTagRowRepository, SyncOutRowRepository,
+    };
+
+    use crate::{
+        name::{
+            insert::{InsertName, InsertNameError},
+            update::{UpdateName, UpdateNameError},
+        },
+        service_provider::ServiceProvider,
+    };
+
+    fn insert_input(id: &str, code: &str) -> InsertName {
+        InsertName {
+            id: id.to_string(),
+            code: code.to_string(),
+            name: "Outreach clinic".to_string(),
+            r#type: NameType::Facility,
+            is_customer: true,
+            is_supplier: false,
+            is_manufacturer: false,
+            is_donor: false,
+            phone: None,
+            email: Some("clinic@example.com".to_string()),
+            website: None,
+            address1: Some("1 Main Road".to_string()),
+            address2: None,
+            country: None,
+            comment: None,
+            tags: vec!["Clinic".to_string(), "Outreach".to_string()],
+        }
+    }
+
+    fn update_input(id: &str) -> UpdateName {
+        UpdateName {
+            id: id.to_string(),
+            code: None,
+            name: None,
+            is_customer: None,
+            is_supplier: None,
+            is_manufacturer: None,
+            is_donor: None,
+            phone: None,
+            email: None,
+            website: None,
+            address1: None,
+            address2: None,
+            country: None,
+            comment: None,
+            tags: None,
+        }
+    }
+
+    #[actix_rt::test]
+    async fn name_insert_and_update() {
+        let (_, connection, connection_manager, _) =
+            setup_all("name_insert_and_update", MockDataInserts::all()).await;
+        let service_provider = ServiceProvider::new(connection_manager);
+        let context = service_provider.context().unwrap();
+        let service = &service_provider.name_service;
+        let store_id = &mock_store_a().id;
+
+        assert_eq!(
+            service.insert_name(&context, store_id, insert_input(&mock_name_a().id, "C1")),
+            Err(InsertNameError::NameAlreadyExists)
+        );
+        assert_eq!(
+            service.insert_name(
+                &context,
+                store_id,
+                insert_input("clinic", &mock_name_a().code)
+            ),
+            Err(InsertNameError::NameWithCodeAlreadyExists)
+        );
+        assert_eq!(
+            service.insert_name(
+                &context,
+                store_id,
+                InsertName {
+                    name: "".to_string(),
+                    ..insert_input("clinic", "C1")
+                }
+            ),
+            Err(InsertNameError::NameIsEmpty)
+        );
+        assert_eq!(
+            service.insert_name(
+                &context,
+                store_id,
+                InsertName {
+                    r#type: NameType::Store,
+                    ..insert_input("clinic", "C1")
+                }
+            ),
+            Err(InsertNameError::CannotCreateStoreName)
+        );
+        assert_eq!(
+            service.insert_name(&context, "invalid", insert_input("clinic", "C1")),
+            Err(InsertNameError::InvalidStore)
+        );
+
+        // the creating store becomes the home store
+        let name = service
+            .insert_name(&context, store_id, insert_input("clinic", "C1"))
+            .unwrap();
+        assert_eq!(name.home_store_id, Some(store_id.clone()));
+        assert!(name.is_customer);
+        assert_eq!(name.address1, Some("1 Main Road".to_string()));
+        assert_eq!(
+            name.tags,
+            vec!["Clinic".to_string(), "Outreach".to_string()]
+        );
+
+        // name, tags and tag joins are queued for upload
+        let queued = SyncOutRowRepository::new(&connection).find_all().unwrap();
+        assert_eq!(queued.len(), 5);
+        assert_eq!(queued[0].table_name, SyncOutRowTableNameType::Name);
+        assert_eq!(queued[0].record_id, "clinic");
+        assert_eq!(queued[0].action, SyncOutRowActionType::Insert);
+
+        // existing tags are reused
+        service
+            .insert_name(
+                &context,
+                store_id,
+                InsertName {
+                    tags: vec!["Clinic".to_string()],
+                    ..insert_input("clinic_2", "C2")
+                },
+            )
+            .unwrap();
+        assert_eq!(
+            NameTagRowRepository::new(&connection)
+                .find_all()
+                .unwrap()
+                .len(),
+            2
+        );
+        let tagged = NameQueryRepository::new(&connection)
+            .query_by_filter(NameFilter::new().tag(EqualFilter::equal_to("Clinic")))
+            .unwrap();
+        assert_eq!(tagged.len(), 2);
+
+        assert_eq!(
+            service.update_name(&context, store_id, update_input("invalid")),
+            Err(UpdateNameError::NameDoesNotExist)
+        );
+        // names from central have no home store
+        assert_eq!(
+            service.update_name(&context, store_id, update_input(&mock_name_a().id)),
+            Err(UpdateNameError::NotNameHomeStore)
+        );
+        assert_eq!(
+            service.update_name(&context, &mock_store_b().id, update_input("clinic")),
+            Err(UpdateNameError::NotNameHomeStore)
+        );
+        assert_eq!(
+            service.update_name(
+                &context,
+                store_id,
+                UpdateName {
+                    code: Some("C2".to_string()),
+                    ..update_input("clinic")
+                }
+            ),
+            Err(UpdateNameError::NameWithCodeAlreadyExists)
+        );
+
+        let name = service
+            .update_name(
+                &context,
+                store_id,
+                UpdateName {
+                    is_supplier: Some(true),
+                    country: Some("Tonga".to_string()),
+                    tags: Some(vec!["Clinic".to_string(), "Remote".to_string()]),
+                    ..update_input("clinic")
+                },
+            )
+            .unwrap();
+        assert!(name.is_customer);
+        assert!(name.is_supplier);
+        assert_eq!(name.email, Some("clinic@example.com".to_string()));
+        assert_eq!(name.country, Some("Tonga".to_string()));
+        assert_eq!(name.tags, vec!["Clinic".to_string(), "Remote".to_string()]);
+
+        let tagged = NameQueryRepository::new(&connection)
+            .query_by_filter(NameFilter::new().tag(EqualFilter::equal_to("Outreach")))
+            .unwrap();
+        assert!(tagged.is_empty());
+        let queued = SyncOutRowRepository::new(&connection).find_all().unwrap();
+        assert!(queued
+            .iter()
+            .any(|row| row.record_id == "clinic" && row.action == SyncOutRowActionType::Update));
+        assert!(queued
+            .iter()
+            .any(|row| row.table_name == SyncOutRowTableNameType::NameTagJoin
+                && row.action == SyncOutRowActionType::Delete));
+    }
+}
@@ -0,0 +1,173 @@
+use domain::name::Name;
+use repository::{
+    schema::{NameRow, NameStoreJoinRow, SyncOutRowActionType, SyncOutRowTableNameType},
+    NameRepository, NameStoreJoinRepository, RepositoryError, StorageConnection,
+};
+use util::uuid::uuid;
+
+use crate::{service_provider::ServiceContext, sync_out::queue_upload, SingleRecordError};
+
+use super::{
+    get_name,
+    tag::set_name_tags,
+    validate::{check_is_home_store, check_name_code_is_unique, check_name_exists},
+};
+
+/// Omitted fields are left unchanged
+pub struct UpdateName {
+    pub id: String,
+    pub code: Option<String>,
+    pub name: Option<String>,
+    pub is_customer: Option<bool>,
+    pub is_supplier: Option<bool>,
+    pub is_manufacturer: Option<bool>,
+    pub is_donor: Option<bool>,
+    pub phone: Option<String>,
+    pub email: Option<String>,
+    pub website: Option<String>,
+    pub address1: Option<String>,
+    pub address2: Option<String>,
+    pub country: Option<String>,
+    pub comment: Option<String>,
+    /// Replaces all tags of the name, missing tags are created
+    pub tags: Option<Vec<String>>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum UpdateNameError {
+    DatabaseError(RepositoryError),
+    InternalError(String),
+    NameDoesNotExist,
+    /// Names can only be edited in their home store
+    NotNameHomeStore,
+    NameWithCodeAlreadyExists,
+    NameIsEmpty,
+}
+
+fn validate(
+    connection: &StorageConnection,
+    store_id: &str,
+    input: &UpdateName,
+) -> Result<NameRow, UpdateNameError> {
+    let name =
+        check_name_exists(connection, &input.id)?.ok_or(UpdateNameError::NameDoesNotExist)?;
+    if !check_is_home_store(&name, store_id) {
+        return Err(UpdateNameError::NotNameHomeStore);
+    }
+    if let Some(code) = &input.code {
+        if !check_name_code_is_unique(connection, &input.id, code)? {
+            return Err(UpdateNameError::NameWithCodeAlreadyExists);
+        }
+    }
+    if let Some(name) = &input.name {
+        if name.trim().is_empty() {
+            return Err(UpdateNameError::NameIsEmpty);
+        }
+    }
+    Ok(name)
+}
+
+fn generate(
+    store_id: &str,
+    existing: NameRow,
+    existing_name_store_join: Option<NameStoreJoinRow>,
+    UpdateName {
+        id: _,
+        code,
+        name,
+        is_customer,
+        is_supplier,
+        is_manufacturer,
+        is_donor,
+        phone,
+        email,
+        website,
+        address1,
+        address2,
+        country,
+        comment,
+        tags: _,
+    }: UpdateName,
+) -> (NameRow, NameStoreJoinRow) {
+    let name = NameRow {
+        name: name.unwrap_or(existing.name),
+        code: code.unwrap_or(existing.code),
+        is_customer: is_customer.unwrap_or(existing.is_customer),
+        is_supplier: is_supplier.unwrap_or(existing.is_supplier),
+        is_manufacturer: is_manufacturer.unwrap_or(existing.is_manufacturer),
+        is_donor: is_donor.unwrap_or(existing.is_donor),
+        phone: phone.or(existing.phone),
+        email: email.or(existing.email),
+        website: website.or(existing.website),
+        address1: address1.or(existing.address1),
+        address2: address2.or(existing.address2),
+        country: country.or(existing.country),
+        comment: comment.or(existing.comment),
+        ..existing
+    };
+    let name_store_join = match existing_name_store_join {
+        Some(name_store_join) => NameStoreJoinRow {
+            name_is_customer: name.is_customer,
+            name_is_supplier: name.is_supplier,
+            ..name_store_join
+        },
+        None => NameStoreJoinRow {
+            id: uuid(),
+            name_id: name.id.clone(),
+            store_id: store_id.to_string(),
+            name_is_customer: name.is_customer,
+            name_is_supplier: name.is_supplier,
+        },
+    };
+    (name, name_store_join)
+}
+
+pub fn update_name(
+    ctx: &ServiceContext,
+    store_id: &str,
+    mut input: UpdateName,
+) -> Result<Name, UpdateNameError> {
+    let name = ctx
+        .connection
+        .transaction_sync(|connection| {
+            let existing = validate(connection, store_id, &input)?;
+            let existing_name_store_join = NameStoreJoinRepository::new(connection)
+                .find_one_by_name_and_store_id(&input.id, store_id)?;
+            let tags = input.tags.take();
+            let (updated, name_store_join) =
+                generate(store_id, existing, existing_name_store_join, input);
+            NameRepository::new(connection).upsert_one(&updated)?;
+            NameStoreJoinRepository::new(connection).upsert_one(&name_store_join)?;
+            queue_upload(
+                connection,
+                store_id,
+                SyncOutRowTableNameType::Name,
+                &updated.id,
+                SyncOutRowActionType::Update,
+            )?;
+            if let Some(tags) = tags {
+                set_name_tags(connection, store_id, &updated.id, tags)?;
+            }
+
+            get_name(connection, &updated.id).map_err(UpdateNameError::from)
+        })
+        .map_err(|error| error.to_inner_error())?;
+    Ok(name)
+}
+
+impl From<RepositoryError> for UpdateNameError {
+    fn from(error: RepositoryError) -> Self {
+        UpdateNameError::DatabaseError(error)
+    }
+}
+
+impl From<SingleRecordError> for UpdateNameError {
+    fn from(error: SingleRecordError) -> Self {
+        match error {
+            SingleRecordError::DatabaseError(error) => UpdateNameError::DatabaseError(error),
+            SingleRecordError::NotFound(_) => {
+                UpdateNameError::InternalError("Failed to read the just updated name!".to_string())
+            }
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use domain::{name::NameFilter, SimpleStringFilter};
+use repository::{
+    schema::NameRow, NameQueryRepository, NameRepository, RepositoryError, StorageConnection,
+};
+
+pub fn check_name_exists(
+    connection: &StorageConnection,
+    id: &str,
+) -> Result<Option<NameRow>, RepositoryError> {
+    Ok(NameRepository::new(connection)
+        .find_many_by_id(&[id.to_string()])?
+        .pop())
+}
+
+/// Names can only be edited in their home store, names from central have no home store
+pub fn check_is_home_store(name: &NameRow, store_id: &str) -> bool {
+    name.home_store_id.as_deref() == Some(store_id)
+}
+
+/// Checks no other name has the code
+pub fn check_name_code_is_unique(
+    connection: &StorageConnection,
+    id: &str,
+    code: &str,
+) -> Result<bool, RepositoryError> {
+    let names = NameQueryRepository::new(connection)
+        .query_by_filter(NameFilter::new().code(SimpleStringFilter::equal_to(code)))?;
+    Ok(names.iter().all(|name| name.id == id))
+}
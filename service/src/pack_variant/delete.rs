@@ -0,0 +1,35 @@
+use repository::{PackVariantRowRepository, RepositoryError};
+
+use crate::service_provider::ServiceContext;
+
+use super::validate::check_pack_variant_exists;
+
+#[derive(Debug, PartialEq)]
+pub enum DeletePackVariantError {
+    PackVariantDoesNotExist,
+    DatabaseError(RepositoryError),
+}
+
+/// Lines keep their pack size, they are no longer shown with the name of the deleted variant
+pub fn delete_pack_variant(
+    ctx: &ServiceContext,
+    id: &str,
+) -> Result<String, DeletePackVariantError> {
+    let pack_variant_id = ctx
+        .connection
+        .transaction_sync(|connection| {
+            if check_pack_variant_exists(id, connection)?.is_none() {
+                return Err(DeletePackVariantError::PackVariantDoesNotExist);
+            }
+            PackVariantRowRepository::new(connection).delete(id)?;
+            Ok(id.to_string())
+        })
+        .map_err(|error| error.to_inner_error())?;
+    Ok(pack_variant_id)
+}
+
+impl From<RepositoryError> for DeletePackVariantError {
+    fn from(error: RepositoryError) -> Self {
+        DeletePackVariantError::DatabaseError(error)
+    }
+}
@@ -0,0 +1,107 @@
+use repository::{
+    schema::PackVariantRow, PackVariantRowRepository, RepositoryError, StorageConnection,
+};
+
+use crate::service_provider::ServiceContext;
+
+use super::validate::{
+    check_item_exists, check_measure, check_pack_size_is_unique, check_pack_variant_exists,
+    check_unit_exists,
+};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct InsertPackVariant {
+    pub id: String,
+    pub item_id: String,
+    pub name: String,
+    pub pack_size: i32,
+    pub unit_id: Option<String>,
+    pub volume_per_pack: Option<f64>,
+    pub weight_per_pack: Option<f64>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum InsertPackVariantError {
+    PackVariantAlreadyExists,
+    ItemDoesNotExist,
+    EmptyName,
+    PackSizeBelowOne,
+    PackVariantWithPackSizeAlreadyExists,
+    UnitDoesNotExist,
+    NegativeVolumeOrWeight,
+    DatabaseError(RepositoryError),
+}
+
+pub fn insert_pack_variant(
+    ctx: &ServiceContext,
+    input: InsertPackVariant,
+) -> Result<PackVariantRow, InsertPackVariantError> {
+    let pack_variant = ctx
+        .connection
+        .transaction_sync(|connection| {
+            validate(&input, connection)?;
+            let new_pack_variant = generate(input);
+            PackVariantRowRepository::new(connection).upsert_one(&new_pack_variant)?;
+            Ok::<PackVariantRow, InsertPackVariantError>(new_pack_variant)
+        })
+        .map_err(|error| error.to_inner_error())?;
+    Ok(pack_variant)
+}
+
+fn validate(
+    input: &InsertPackVariant,
+    connection: &StorageConnection,
+) -> Result<(), InsertPackVariantError> {
+    if check_pack_variant_exists(&input.id, connection)?.is_some() {
+        return Err(InsertPackVariantError::PackVariantAlreadyExists);
+    }
+    if !check_item_exists(&input.item_id, connection)? {
+        return Err(InsertPackVariantError::ItemDoesNotExist);
+    }
+    if input.name.trim().is_empty() {
+        return Err(InsertPackVariantError::EmptyName);
+    }
+    if input.pack_size < 1 {
+        return Err(InsertPackVariantError::PackSizeBelowOne);
+    }
+    if !check_pack_size_is_unique(&input.id, &input.item_id, input.pack_size, connection)? {
+        return Err(InsertPackVariantError::PackVariantWithPackSizeAlreadyExists);
+    }
+    if let Some(unit_id) = &input.unit_id {
+        if !check_unit_exists(unit_id, connection)? {
+            return Err(InsertPackVariantError::UnitDoesNotExist);
+        }
+    }
+    if !check_measure(input.volume_per_pack) || !check_measure(input.weight_per_pack) {
+        return Err(InsertPackVariantError::NegativeVolumeOrWeight);
+    }
+    Ok(())
+}
+
+fn generate(
+    InsertPackVariant {
+        id,
+        item_id,
+        name,
+        pack_size,
+        unit_id,
+        volume_per_pack,
+        weight_per_pack,
+    }: InsertPackVariant,
+) -> PackVariantRow {
+    PackVariantRow {
+        id,
+        item_id,
+        name: name.trim().to_string(),
+        pack_size,
+        unit_id,
+        volume_per_pack,
+        weight_per_pack,
+    }
+}
+
+impl From<RepositoryError> for InsertPackVariantError {
+    fn from(error: RepositoryError) -> Self {
+        InsertPackVariantError::DatabaseError(error)
+    }
+}
@@ -0,0 +1,79 @@
+use std::convert::TryFrom;
+
+use repository::{schema::PackVariantRow, PackVariantRowRepository, RepositoryError};
+
+use crate::service_provider::ServiceContext;
+
+use self::{
+    delete::{delete_pack_variant, DeletePackVariantError},
+    insert::{insert_pack_variant, InsertPackVariant, InsertPackVariantError},
+    update::{update_pack_variant, UpdatePackVariant, UpdatePackVariantError},
+};
+
+pub mod delete;
+pub mod insert;
+pub mod update;
+mod validate;
+
+#[cfg(test)]
+mod tests;
+
+pub trait PackVariantServiceTrait: Sync + Send {
+    /// Pack variants of the item ordered by pack size
+    fn get_item_pack_variants(
+        &self,
+        ctx: &ServiceContext,
+        item_id: &str,
+    ) -> Result<Vec<PackVariantRow>, RepositoryError> {
+        PackVariantRowRepository::new(&ctx.connection).find_many_by_item_ids(&[item_id.to_string()])
+    }
+
+    fn insert_pack_variant(
+        &self,
+        ctx: &ServiceContext,
+        input: InsertPackVariant,
+    ) -> Result<PackVariantRow, InsertPackVariantError> {
+        insert_pack_variant(ctx, input)
+    }
+
+    fn update_pack_variant(
+        &self,
+        ctx: &ServiceContext,
+        input: UpdatePackVariant,
+    ) -> Result<PackVariantRow, UpdatePackVariantError> {
+        update_pack_variant(ctx, input)
+    }
+
+    fn delete_pack_variant(
+        &self,
+        ctx: &ServiceContext,
+        id: &str,
+    ) -> Result<String, DeletePackVariantError> {
+        delete_pack_variant(ctx, id)
+    }
+}
+
+pub struct PackVariantService {}
+impl PackVariantServiceTrait for PackVariantService {}
+
+/// Number of units in `number_of_packs` packs of `pack_size` units
+pub fn number_of_units(number_of_packs: i32, pack_size: i32) -> i64 {
+    number_of_packs as i64 * pack_size as i64
+}
+
+/// Converts a number of packs to the number of packs of another pack size, `None` if the units
+/// don't fill a whole number of packs
+pub fn convert_number_of_packs(
+    number_of_packs: u32,
+    from_pack_size: i32,
+    to_pack_size: i32,
+) -> Option<u32> {
+    if from_pack_size < 1 || to_pack_size < 1 {
+        return None;
+    }
+    let units = number_of_packs as i64 * from_pack_size as i64;
+    if units % to_pack_size as i64 != 0 {
+        return None;
+    }
+    u32::try_from(units / to_pack_size as i64).ok()
+}
@@ -0,0 +1,387 @@
+#[cfg(test)]
+mod pack_variant_test {
+    use domain::{
+        inbound_shipment::InsertInboundShipmentLine, outbound_shipment::InsertOutboundShipmentLine,
+    };
+    use repository::{
+        mock::{
+            mock_inbound_shipment_c, mock_item_a, mock_item_b, mock_outbound_shipment_c,
+            MockDataInserts,
+        },
+        schema::{PackVariantRow, StockLineRow},
+        test_db::setup_all,
+        InvoiceLineRowRepository, StockLineRowRepository,
+    };
+
+    use crate::{
+        invoice_line::{
+            insert_inbound_shipment_line, insert_outbound_shipment_line,
+            InsertInboundShipmentLineError, InsertOutboundShipmentLineError,
+        },
+        pack_variant::{
+            convert_number_of_packs,
+            delete::DeletePackVariantError,
+            insert::{InsertPackVariant, InsertPackVariantError},
+            number_of_units,
+            update::{UpdatePackVariant, UpdatePackVariantError},
+        },
+        plugin::PluginHost,
+        service_provider::ServiceProvider,
+    };
+
+    fn insert_input(id: &str, pack_size: i32) -> InsertPackVariant {
+        InsertPackVariant {
+            id: id.to_string(),
+            item_id: mock_item_a().id,
+            name: format!("Pack of {}", pack_size),
+            pack_size,
+            unit_id: None,
+            volume_per_pack: None,
+            weight_per_pack: None,
+        }
+    }
+
+    fn update_input(id: &str) -> UpdatePackVariant {
+        UpdatePackVariant {
+            id: id.to_string(),
+            name: None,
+            pack_size: None,
+            unit_id: None,
+            volume_per_pack: None,
+            weight_per_pack: None,
+        }
+    }
+
+    #[test]
+    fn pack_conversion() {
+        assert_eq!(number_of_units(3, 100), 300);
+        // 2 bottles of 100 are 20 boxes of 10
+        assert_eq!(convert_number_of_packs(2, 100, 10), Some(20));
+        assert_eq!(convert_number_of_packs(20, 10, 100), Some(2));
+        assert_eq!(convert_number_of_packs(7, 1, 1), Some(7));
+        // 15 units don't fill whole boxes of 10
+        assert_eq!(convert_number_of_packs(3, 5, 10), None);
+        assert_eq!(convert_number_of_packs(3, 5, 0), None);
+    }
+
+    #[actix_rt::test]
+    async fn pack_variant_mutations() {
+        let (_, _, connection_manager, _) =
+            setup_all("pack_variant_mutations", MockDataInserts::all()).await;
+        let service_provider = ServiceProvider::new(connection_manager);
+        let context = service_provider.context().unwrap();
+        let service = &service_provider.pack_variant_service;
+
+        // insert
+        let bottle = service
+            .insert_pack_variant(
+                &context,
+                InsertPackVariant {
+                    name: " Bottle of 100 ".to_string(),
+                    unit_id: Some("item_query_test2".to_string()),
+                    volume_per_pack: Some(0.25),
+                    weight_per_pack: Some(0.3),
+                    ..insert_input("bottle", 100)
+                },
+            )
+            .unwrap();
+        assert_eq!(
+            bottle,
+            PackVariantRow {
+                id: "bottle".to_string(),
+                item_id: mock_item_a().id,
+                name: "Bottle of 100".to_string(),
+                pack_size: 100,
+                unit_id: Some("item_query_test2".to_string()),
+                volume_per_pack: Some(0.25),
+                weight_per_pack: Some(0.3),
+            }
+        );
+        service
+            .insert_pack_variant(&context, insert_input("box", 10))
+            .unwrap();
+        // the same pack size can be used by another item
+        service
+            .insert_pack_variant(
+                &context,
+                InsertPackVariant {
+                    item_id: mock_item_b().id,
+                    ..insert_input("item_b_box", 10)
+                },
+            )
+            .unwrap();
+        let pack_variants = service
+            .get_item_pack_variants(&context, &mock_item_a().id)
+            .unwrap();
+        assert_eq!(
+            pack_variants
+                .iter()
+                .map(|pack_variant| pack_variant.id.as_str())
+                .collect::<Vec<&str>>(),
+            vec!["box", "bottle"]
+        );
+
+        // PackVariantAlreadyExists
+        assert_eq!(
+            service.insert_pack_variant(&context, insert_input("box", 20)),
+            Err(InsertPackVariantError::PackVariantAlreadyExists)
+        );
+        // ItemDoesNotExist
+        assert_eq!(
+            service.insert_pack_variant(
+                &context,
+                InsertPackVariant {
+                    item_id: "invalid".to_string(),
+                    ..insert_input("new", 20)
+                }
+            ),
+            Err(InsertPackVariantError::ItemDoesNotExist)
+        );
+        // EmptyName
+        assert_eq!(
+            service.insert_pack_variant(
+                &context,
+                InsertPackVariant {
+                    name: " ".to_string(),
+                    ..insert_input("new", 20)
+                }
+            ),
+            Err(InsertPackVariantError::EmptyName)
+        );
+        // PackSizeBelowOne
+        assert_eq!(
+            service.insert_pack_variant(&context, insert_input("new", 0)),
+            Err(InsertPackVariantError::PackSizeBelowOne)
+        );
+        // PackVariantWithPackSizeAlreadyExists
+        assert_eq!(
+            service.insert_pack_variant(&context, insert_input("new", 10)),
+            Err(InsertPackVariantError::PackVariantWithPackSizeAlreadyExists)
+        );
+        // UnitDoesNotExist
+        assert_eq!(
+            service.insert_pack_variant(
+                &context,
+                InsertPackVariant {
+                    unit_id: Some("invalid".to_string()),
+                    ..insert_input("new", 20)
+                }
+            ),
+            Err(InsertPackVariantError::UnitDoesNotExist)
+        );
+        // NegativeVolumeOrWeight
+        assert_eq!(
+            service.insert_pack_variant(
+                &context,
+                InsertPackVariant {
+                    weight_per_pack: Some(-1.0),
+                    ..insert_input("new", 20)
+                }
+            ),
+            Err(InsertPackVariantError::NegativeVolumeOrWeight)
+        );
+
+        // update
+        let updated = service
+            .update_pack_variant(
+                &context,
+                UpdatePackVariant {
+                    name: Some("Box of 10 blisters".to_string()),
+                    volume_per_pack: Some(0.1),
+                    ..update_input("box")
+                },
+            )
+            .unwrap();
+        assert_eq!(updated.name, "Box of 10 blisters");
+        assert_eq!(updated.pack_size, 10);
+        assert_eq!(updated.volume_per_pack, Some(0.1));
+        // the pack size can be kept
+        service
+            .update_pack_variant(
+                &context,
+                UpdatePackVariant {
+                    pack_size: Some(10),
+                    ..update_input("box")
+                },
+            )
+            .unwrap();
+
+        // PackVariantDoesNotExist
+        assert_eq!(
+            service.update_pack_variant(&context, update_input("invalid")),
+            Err(UpdatePackVariantError::PackVariantDoesNotExist)
+        );
+        // EmptyName
+        assert_eq!(
+            service.update_pack_variant(
+                &context,
+                UpdatePackVariant {
+                    name: Some("".to_string()),
+                    ..update_input("box")
+                }
+            ),
+            Err(UpdatePackVariantError::EmptyName)
+        );
+        // PackVariantWithPackSizeAlreadyExists
+        assert_eq!(
+            service.update_pack_variant(
+                &context,
+                UpdatePackVariant {
+                    pack_size: Some(100),
+                    ..update_input("box")
+                }
+            ),
+            Err(UpdatePackVariantError::PackVariantWithPackSizeAlreadyExists)
+        );
+        // UnitDoesNotExist
+        assert_eq!(
+            service.update_pack_variant(
+                &context,
+                UpdatePackVariant {
+                    unit_id: Some("invalid".to_string()),
+                    ..update_input("box")
+                }
+            ),
+            Err(UpdatePackVariantError::UnitDoesNotExist)
+        );
+
+        // delete
+        assert_eq!(
+            service.delete_pack_variant(&context, "box"),
+            Ok("box".to_string())
+        );
+        assert_eq!(
+            service.delete_pack_variant(&context, "box"),
+            Err(DeletePackVariantError::PackVariantDoesNotExist)
+        );
+    }
+
+    #[actix_rt::test]
+    async fn pack_variant_lines() {
+        let (_, connection, connection_manager, _) =
+            setup_all("pack_variant_lines", MockDataInserts::all()).await;
+        let service_provider = ServiceProvider::new(connection_manager);
+        let context = service_provider.context().unwrap();
+        let service = &service_provider.pack_variant_service;
+        let plugins = PluginHost::default();
+        service
+            .insert_pack_variant(&context, insert_input("bottle", 100))
+            .unwrap();
+        service
+            .insert_pack_variant(&context, insert_input("strip", 5))
+            .unwrap();
+        service
+            .insert_pack_variant(
+                &context,
+                InsertPackVariant {
+                    item_id: mock_item_b().id,
+                    ..insert_input("item_b_bottle", 100)
+                },
+            )
+            .unwrap();
+
+        // inbound: 2 bottles of 100 are received as 20 packs of 10
+        let inbound_line =
+            |id: &str, pack_variant_id: &str, number_of_packs: u32| InsertInboundShipmentLine {
+                id: id.to_string(),
+                invoice_id: mock_inbound_shipment_c().id,
+                item_id: mock_item_a().id,
+                location_id: None,
+                pack_size: 10,
+                batch: None,
+                cost_price_per_pack: 1.0,
+                sell_price_per_pack: 2.0,
+                expiry_date: None,
+                number_of_packs,
+                pack_variant_id: Some(pack_variant_id.to_string()),
+                total_before_tax: 0.0,
+                total_after_tax: 0.0,
+                tax: None,
+            };
+        let id =
+            insert_inbound_shipment_line(&connection, &plugins, inbound_line("in_1", "bottle", 2))
+                .unwrap();
+        let line = InvoiceLineRowRepository::new(&connection)
+            .find_one_by_id(&id)
+            .unwrap();
+        assert_eq!(line.pack_size, 10);
+        assert_eq!(line.number_of_packs, 20);
+
+        assert!(matches!(
+            insert_inbound_shipment_line(&connection, &plugins, inbound_line("in_2", "strip", 3)),
+            Err(InsertInboundShipmentLineError::CannotConvertPackVariant)
+        ));
+        // variant of another item
+        assert!(matches!(
+            insert_inbound_shipment_line(
+                &connection,
+                &plugins,
+                inbound_line("in_2", "item_b_bottle", 1)
+            ),
+            Err(InsertInboundShipmentLineError::PackVariantDoesNotExist)
+        ));
+
+        // outbound: 1 bottle of 100 is picked as 10 packs of the stock line
+        StockLineRowRepository::new(&connection)
+            .upsert_one(&StockLineRow {
+                id: "pack_variant_stock_line".to_string(),
+                item_id: mock_item_a().id,
+                store_id: mock_outbound_shipment_c().store_id,
+                location_id: None,
+                batch: None,
+                pack_size: 10,
+                cost_price_per_pack: 1.0,
+                sell_price_per_pack: 2.0,
+                available_number_of_packs: 50,
+                total_number_of_packs: 50,
+                expiry_date: None,
+                on_hold: false,
+                note: None,
+            })
+            .unwrap();
+        let outbound_line =
+            |id: &str, pack_variant_id: &str, number_of_packs: u32| InsertOutboundShipmentLine {
+                id: id.to_string(),
+                invoice_id: mock_outbound_shipment_c().id,
+                item_id: mock_item_a().id,
+                stock_line_id: "pack_variant_stock_line".to_string(),
+                number_of_packs,
+                pack_variant_id: Some(pack_variant_id.to_string()),
+                total_before_tax: 0.0,
+                total_after_tax: 0.0,
+                tax: None,
+            };
+        assert!(matches!(
+            insert_outbound_shipment_line(
+                &connection,
+                &plugins,
+                outbound_line("out_1", "strip", 3)
+            ),
+            Err(InsertOutboundShipmentLineError::CannotConvertPackVariant)
+        ));
+        // the reduction is checked in packs of the stock line
+        assert!(matches!(
+            insert_outbound_shipment_line(
+                &connection,
+                &plugins,
+                outbound_line("out_1", "bottle", 6)
+            ),
+            Err(InsertOutboundShipmentLineError::ReductionBelowZero { .. })
+        ));
+        let id = insert_outbound_shipment_line(
+            &connection,
+            &plugins,
+            outbound_line("out_1", "bottle", 1),
+        )
+        .unwrap();
+        let line = InvoiceLineRowRepository::new(&connection)
+            .find_one_by_id(&id)
+            .unwrap();
+        assert_eq!(line.pack_size, 10);
+        assert_eq!(line.number_of_packs, 10);
+        let stock_line = StockLineRowRepository::new(&connection)
+            .find_one_by_id("pack_variant_stock_line")
+            .unwrap();
+        assert_eq!(stock_line.available_number_of_packs, 40);
+    }
+}
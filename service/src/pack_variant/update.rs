@@ -0,0 +1,114 @@
+use repository::{
+    schema::PackVariantRow, PackVariantRowRepository, RepositoryError, StorageConnection,
+};
+
+use crate::service_provider::ServiceContext;
+
+use super::validate::{
+    check_measure, check_pack_size_is_unique, check_pack_variant_exists, check_unit_exists,
+};
+
+/// Omitted fields are left unchanged, the item of a pack variant can't be changed
+#[derive(Clone, Debug, PartialEq)]
+pub struct UpdatePackVariant {
+    pub id: String,
+    pub name: Option<String>,
+    pub pack_size: Option<i32>,
+    pub unit_id: Option<String>,
+    pub volume_per_pack: Option<f64>,
+    pub weight_per_pack: Option<f64>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum UpdatePackVariantError {
+    PackVariantDoesNotExist,
+    EmptyName,
+    PackSizeBelowOne,
+    PackVariantWithPackSizeAlreadyExists,
+    UnitDoesNotExist,
+    NegativeVolumeOrWeight,
+    DatabaseError(RepositoryError),
+}
+
+pub fn update_pack_variant(
+    ctx: &ServiceContext,
+    input: UpdatePackVariant,
+) -> Result<PackVariantRow, UpdatePackVariantError> {
+    let pack_variant = ctx
+        .connection
+        .transaction_sync(|connection| {
+            let existing = validate(&input, connection)?;
+            let updated_pack_variant = generate(existing, input);
+            PackVariantRowRepository::new(connection).upsert_one(&updated_pack_variant)?;
+            Ok::<PackVariantRow, UpdatePackVariantError>(updated_pack_variant)
+        })
+        .map_err(|error| error.to_inner_error())?;
+    Ok(pack_variant)
+}
+
+fn validate(
+    input: &UpdatePackVariant,
+    connection: &StorageConnection,
+) -> Result<PackVariantRow, UpdatePackVariantError> {
+    let existing = match check_pack_variant_exists(&input.id, connection)? {
+        Some(existing) => existing,
+        None => return Err(UpdatePackVariantError::PackVariantDoesNotExist),
+    };
+    if let Some(name) = &input.name {
+        if name.trim().is_empty() {
+            return Err(UpdatePackVariantError::EmptyName);
+        }
+    }
+    if let Some(pack_size) = input.pack_size {
+        if pack_size < 1 {
+            return Err(UpdatePackVariantError::PackSizeBelowOne);
+        }
+        if !check_pack_size_is_unique(&input.id, &existing.item_id, pack_size, connection)? {
+            return Err(UpdatePackVariantError::PackVariantWithPackSizeAlreadyExists);
+        }
+    }
+    if let Some(unit_id) = &input.unit_id {
+        if !check_unit_exists(unit_id, connection)? {
+            return Err(UpdatePackVariantError::UnitDoesNotExist);
+        }
+    }
+    if !check_measure(input.volume_per_pack) || !check_measure(input.weight_per_pack) {
+        return Err(UpdatePackVariantError::NegativeVolumeOrWeight);
+    }
+    Ok(existing)
+}
+
+fn generate(
+    mut pack_variant: PackVariantRow,
+    UpdatePackVariant {
+        id: _,
+        name,
+        pack_size,
+        unit_id,
+        volume_per_pack,
+        weight_per_pack,
+    }: UpdatePackVariant,
+) -> PackVariantRow {
+    if let Some(name) = name {
+        pack_variant.name = name.trim().to_string();
+    }
+    if let Some(pack_size) = pack_size {
+        pack_variant.pack_size = pack_size;
+    }
+    if unit_id.is_some() {
+        pack_variant.unit_id = unit_id;
+    }
+    if volume_per_pack.is_some() {
+        pack_variant.volume_per_pack = volume_per_pack;
+    }
+    if weight_per_pack.is_some() {
+        pack_variant.weight_per_pack = weight_per_pack;
+    }
+    pack_variant
+}
+
+impl From<RepositoryError> for UpdatePackVariantError {
+    fn from(error: RepositoryError) -> Self {
+        UpdatePackVariantError::DatabaseError(error)
+    }
+}
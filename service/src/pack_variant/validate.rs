@@ -0,0 +1,48 @@
+use repository::{
+    schema::PackVariantRow, ItemRepository, PackVariantRowRepository, RepositoryError,
+    StorageConnection, UnitRowRepository,
+};
+
+pub fn check_pack_variant_exists(
+    id: &str,
+    connection: &StorageConnection,
+) -> Result<Option<PackVariantRow>, RepositoryError> {
+    PackVariantRowRepository::new(connection).find_one_by_id(id)
+}
+
+/// Pack sizes are unique per item, lines are matched to their pack variant by the pack size
+pub fn check_pack_size_is_unique(
+    id: &str,
+    item_id: &str,
+    pack_size: i32,
+    connection: &StorageConnection,
+) -> Result<bool, RepositoryError> {
+    let existing = PackVariantRowRepository::new(connection)
+        .find_one_by_item_id_and_pack_size(item_id, pack_size)?;
+    Ok(existing
+        .filter(|pack_variant| pack_variant.id != id)
+        .is_none())
+}
+
+pub fn check_item_exists(
+    item_id: &str,
+    connection: &StorageConnection,
+) -> Result<bool, RepositoryError> {
+    Ok(ItemRepository::new(connection)
+        .find_one_by_id(item_id)?
+        .is_some())
+}
+
+pub fn check_unit_exists(
+    unit_id: &str,
+    connection: &StorageConnection,
+) -> Result<bool, RepositoryError> {
+    Ok(UnitRowRepository::new(connection)
+        .find_one_by_id_option(unit_id)?
+        .is_some())
+}
+
+/// Volume and weight can't be negative
+pub fn check_measure(measure: Option<f64>) -> bool {
+    measure.filter(|measure| *measure < 0.0).is_none()
+}
@@ -0,0 +1,135 @@
+use chrono::NaiveDate;
+use domain::patient::Patient;
+use repository::{
+    schema::{NameRow, NameRowType, SyncOutRowActionType, SyncOutRowTableNameType},
+    NameRepository, RepositoryError, StorageConnection,
+};
+
+use crate::{
+    service_provider::ServiceContext, sync_out::queue_upload, validate::check_store_exists,
+    SingleRecordError,
+};
+
+use super::{
+    query::get_patient,
+    validate::{check_patient_code_is_unique, patient_name},
+};
+
+pub struct InsertPatient {
+    pub id: String,
+    pub code: String,
+    pub first_name: Option<String>,
+    pub last_name: String,
+    pub date_of_birth: Option<NaiveDate>,
+    pub phone: Option<String>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum InsertPatientError {
+    DatabaseError(RepositoryError),
+    InternalError(String),
+    PatientAlreadyExists,
+    PatientWithCodeAlreadyExists,
+    LastNameIsEmpty,
+    InvalidStore,
+}
+
+fn validate(
+    connection: &StorageConnection,
+    store_id: &str,
+    input: &InsertPatient,
+) -> Result<(), InsertPatientError> {
+    if !NameRepository::new(connection)
+        .find_many_by_id(&[input.id.to_string()])?
+        .is_empty()
+    {
+        return Err(InsertPatientError::PatientAlreadyExists);
+    }
+    if !check_patient_code_is_unique(connection, &input.id, &input.code)? {
+        return Err(InsertPatientError::PatientWithCodeAlreadyExists);
+    }
+    if input.last_name.trim().is_empty() {
+        return Err(InsertPatientError::LastNameIsEmpty);
+    }
+    if !check_store_exists(connection, store_id)? {
+        return Err(InsertPatientError::InvalidStore);
+    }
+    Ok(())
+}
+
+/// The store the patient is registered in becomes its home store
+fn generate(
+    store_id: &str,
+    InsertPatient {
+        id,
+        code,
+        first_name,
+        last_name,
+        date_of_birth,
+        phone,
+    }: InsertPatient,
+) -> NameRow {
+    NameRow {
+        id,
+        name: patient_name(&first_name, &last_name),
+        code,
+        is_customer: false,
+        is_supplier: false,
+        r#type: NameRowType::Patient,
+        first_name,
+        last_name: Some(last_name),
+        date_of_birth,
+        phone,
+        home_store_id: Some(store_id.to_string()),
+        is_manufacturer: false,
+        is_donor: false,
+        email: None,
+        website: None,
+        address1: None,
+        address2: None,
+        country: None,
+        comment: None,
+    }
+}
+
+pub fn insert_patient(
+    ctx: &ServiceContext,
+    store_id: &str,
+    input: InsertPatient,
+) -> Result<Patient, InsertPatientError> {
+    let patient = ctx
+        .connection
+        .transaction_sync(|connection| {
+            validate(connection, store_id, &input)?;
+            let new_patient = generate(store_id, input);
+            NameRepository::new(connection).upsert_one(&new_patient)?;
+            queue_upload(
+                connection,
+                store_id,
+                SyncOutRowTableNameType::Name,
+                &new_patient.id,
+                SyncOutRowActionType::Insert,
+            )?;
+
+            get_patient(ctx, new_patient.id).map_err(InsertPatientError::from)
+        })
+        .map_err(|error| error.to_inner_error())?;
+    Ok(patient)
+}
+
+impl From<RepositoryError> for InsertPatientError {
+    fn from(error: RepositoryError) -> Self {
+        InsertPatientError::DatabaseError(error)
+    }
+}
+
+impl From<SingleRecordError> for InsertPatientError {
+    fn from(error: SingleRecordError) -> Self {
+        match error {
+            SingleRecordError::DatabaseError(error) => InsertPatientError::DatabaseError(error),
+            SingleRecordError::NotFound(_) => InsertPatientError::InternalError(
+                "Failed to read the just inserted patient!".to_string(),
+            ),
+        }
+    }
+}
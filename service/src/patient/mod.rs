@@ -0,0 +1,76 @@
+use domain::{
+    patient::{Patient, PatientFilter, PatientSort},
+    PaginationOption,
+};
+use repository::{schema::PrescriberRow, RepositoryError};
+
+use crate::{service_provider::ServiceContext, ListError, ListResult, SingleRecordError};
+
+use self::{
+    insert::{insert_patient, InsertPatient, InsertPatientError},
+    prescriber::{get_prescribers, insert_prescriber, InsertPrescriber, InsertPrescriberError},
+    query::{get_patient, get_patients},
+    update::{update_patient, UpdatePatient, UpdatePatientError},
+};
+
+pub mod insert;
+pub mod prescriber;
+pub mod query;
+pub mod update;
+pub mod validate;
+
+#[cfg(test)]
+mod tests;
+
+/// Patients are names of type patient. They are visible in all stores but can only be edited in
+/// their home store, i.e. the store they were registered in.
+pub trait PatientServiceTrait: Sync + Send {
+    fn get_patients(
+        &self,
+        ctx: &ServiceContext,
+        pagination: Option<PaginationOption>,
+        filter: Option<PatientFilter>,
+        sort: Option<PatientSort>,
+    ) -> Result<ListResult<Patient>, ListError> {
+        get_patients(ctx, pagination, filter, sort)
+    }
+
+    fn get_patient(&self, ctx: &ServiceContext, id: String) -> Result<Patient, SingleRecordError> {
+        get_patient(ctx, id)
+    }
+
+    fn insert_patient(
+        &self,
+        ctx: &ServiceContext,
+        store_id: &str,
+        input: InsertPatient,
+    ) -> Result<Patient, InsertPatientError> {
+        insert_patient(ctx, store_id, input)
+    }
+
+    fn update_patient(
+        &self,
+        ctx: &ServiceContext,
+        store_id: &str,
+        input: UpdatePatient,
+    ) -> Result<Patient, UpdatePatientError> {
+        update_patient(ctx, store_id, input)
+    }
+
+    /// All prescribers, ordered by last and first name
+    fn get_prescribers(&self, ctx: &ServiceContext) -> Result<Vec<PrescriberRow>, RepositoryError> {
+        get_prescribers(ctx)
+    }
+
+    fn insert_prescriber(
+        &self,
+        ctx: &ServiceContext,
+        store_id: &str,
+        input: InsertPrescriber,
+    ) -> Result<PrescriberRow, InsertPrescriberError> {
+        insert_prescriber(ctx, store_id, input)
+    }
+}
+
+pub struct PatientService {}
+impl PatientServiceTrait for PatientService {}
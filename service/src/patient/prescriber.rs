@@ -0,0 +1,96 @@
+use repository::{
+    schema::PrescriberRow, PrescriberRowRepository, RepositoryError, StorageConnection,
+};
+
+use crate::{service_provider::ServiceContext, validate::check_store_exists};
+
+pub struct InsertPrescriber {
+    pub id: String,
+    pub code: String,
+    pub first_name: Option<String>,
+    pub last_name: String,
+    pub registration_code: Option<String>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum InsertPrescriberError {
+    DatabaseError(RepositoryError),
+    InternalError(String),
+    PrescriberAlreadyExists,
+    LastNameIsEmpty,
+    InvalidStore,
+}
+
+pub fn get_prescribers(ctx: &ServiceContext) -> Result<Vec<PrescriberRow>, RepositoryError> {
+    PrescriberRowRepository::new(&ctx.connection).find_all()
+}
+
+fn validate(
+    connection: &StorageConnection,
+    store_id: &str,
+    input: &InsertPrescriber,
+) -> Result<(), InsertPrescriberError> {
+    if PrescriberRowRepository::new(connection)
+        .find_one_by_id(&input.id)?
+        .is_some()
+    {
+        return Err(InsertPrescriberError::PrescriberAlreadyExists);
+    }
+    if input.last_name.trim().is_empty() {
+        return Err(InsertPrescriberError::LastNameIsEmpty);
+    }
+    if !check_store_exists(connection, store_id)? {
+        return Err(InsertPrescriberError::InvalidStore);
+    }
+    Ok(())
+}
+
+/// Like patients, prescribers belong to the store they were registered in
+fn generate(
+    store_id: &str,
+    InsertPrescriber {
+        id,
+        code,
+        first_name,
+        last_name,
+        registration_code,
+    }: InsertPrescriber,
+) -> PrescriberRow {
+    PrescriberRow {
+        id,
+        code,
+        first_name,
+        last_name,
+        registration_code,
+        home_store_id: Some(store_id.to_string()),
+    }
+}
+
+pub fn insert_prescriber(
+    ctx: &ServiceContext,
+    store_id: &str,
+    input: InsertPrescriber,
+) -> Result<PrescriberRow, InsertPrescriberError> {
+    let prescriber = ctx
+        .connection
+        .transaction_sync(|connection| {
+            validate(connection, store_id, &input)?;
+            let new_prescriber = generate(store_id, input);
+            let repository = PrescriberRowRepository::new(connection);
+            repository.upsert_one(&new_prescriber)?;
+
+            repository.find_one_by_id(&new_prescriber.id)?.ok_or(
+                InsertPrescriberError::InternalError(
+                    "Failed to read the just inserted prescriber!".to_string(),
+                ),
+            )
+        })
+        .map_err(|error| error.to_inner_error())?;
+    Ok(prescriber)
+}
+
+impl From<RepositoryError> for InsertPrescriberError {
+    fn from(error: RepositoryError) -> Self {
+        InsertPrescriberError::DatabaseError(error)
+    }
+}
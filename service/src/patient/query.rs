@@ -0,0 +1,41 @@
+use domain::{
+    patient::{Patient, PatientFilter, PatientSort},
+    EqualFilter, PaginationOption,
+};
+use repository::PatientRepository;
+
+use crate::{
+    get_default_pagination, i64_to_u32, service_provider::ServiceContext, ListError, ListResult,
+    SingleRecordError,
+};
+
+pub const MAX_LIMIT: u32 = 1000;
+pub const MIN_LIMIT: u32 = 1;
+
+pub fn get_patients(
+    ctx: &ServiceContext,
+    pagination: Option<PaginationOption>,
+    filter: Option<PatientFilter>,
+    sort: Option<PatientSort>,
+) -> Result<ListResult<Patient>, ListError> {
+    let pagination = get_default_pagination(pagination, MAX_LIMIT, MIN_LIMIT)?;
+    let repository = PatientRepository::new(&ctx.connection);
+
+    Ok(ListResult {
+        rows: repository.query(pagination, filter.clone(), sort)?,
+        count: i64_to_u32(repository.count(filter)?),
+    })
+}
+
+pub fn get_patient(ctx: &ServiceContext, id: String) -> Result<Patient, SingleRecordError> {
+    let repository = PatientRepository::new(&ctx.connection);
+
+    let mut result =
+        repository.query_by_filter(PatientFilter::new().id(EqualFilter::equal_to(&id)))?;
+
+    if let Some(record) = result.pop() {
+        Ok(record)
+    } else {
+        Err(SingleRecordError::NotFound(id))
+    }
+}
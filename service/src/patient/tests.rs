@@ -0,0 +1,222 @@
+#[cfg(test)]
+mod patient_test {
+    use chrono::NaiveDate;
+    use domain::{
+        patient::{PatientFilter, PatientSort, PatientSortField},
+        SimpleStringFilter,
+    };
+    use repository::{
+        mock::{mock_name_a, mock_patient_a, mock_store_a, mock_store_b, MockDataInserts},
+        test_db::setup_all,
+    };
+
+    use crate::{
+        patient::{
+            insert::{InsertPatient, InsertPatientError},
+            prescriber::{InsertPrescriber, InsertPrescriberError},
+            update::{UpdatePatient, UpdatePatientError},
+        },
+        service_provider::ServiceProvider,
+        SingleRecordError,
+    };
+
+    fn insert_input(id: &str, code: &str) -> InsertPatient {
+        InsertPatient {
+            id: id.to_string(),
+            code: code.to_string(),
+            first_name: Some("John".to_string()),
+            last_name: "Smith".to_string(),
+            date_of_birth: Some(NaiveDate::from_ymd(1990, 7, 1)),
+            phone: None,
+        }
+    }
+
+    fn update_input(id: &str) -> UpdatePatient {
+        UpdatePatient {
+            id: id.to_string(),
+            code: None,
+            first_name: None,
+            last_name: None,
+            date_of_birth: None,
+            phone: None,
+        }
+    }
+
+    #[actix_rt::test]
+    async fn patient_query() {
+        let (_, _, connection_manager, _) =
+            setup_all("patient_query", MockDataInserts::all()).await;
+        let service_provider = ServiceProvider::new(connection_manager);
+        let context = service_provider.context().unwrap();
+        let service = &service_provider.patient_service;
+
+        // only names of type patient
+        let result = service.get_patients(&context, None, None, None).unwrap();
+        assert_eq!(result.count, 1);
+        assert_eq!(result.rows[0].id, mock_patient_a().id);
+        assert_eq!(
+            service.get_patient(&context, mock_name_a().id),
+            Err(SingleRecordError::NotFound(mock_name_a().id))
+        );
+
+        service
+            .insert_patient(
+                &context,
+                &mock_store_b().id,
+                insert_input("patient_b", "P0002"),
+            )
+            .unwrap();
+        let result = service
+            .get_patients(
+                &context,
+                None,
+                Some(PatientFilter {
+                    last_name: Some(SimpleStringFilter::like("smi")),
+                    ..PatientFilter::new()
+                }),
+                None,
+            )
+            .unwrap();
+        assert_eq!(result.count, 1);
+        assert_eq!(result.rows[0].name, "Smith, John");
+
+        let result = service
+            .get_patients(
+                &context,
+                None,
+                None,
+                Some(PatientSort {
+                    key: PatientSortField::DateOfBirth,
+                    desc: Some(true),
+                }),
+            )
+            .unwrap();
+        let ids: Vec<String> = result.rows.into_iter().map(|patient| patient.id).collect();
+        assert_eq!(ids, vec!["patient_b".to_string(), mock_patient_a().id]);
+    }
+
+    #[actix_rt::test]
+    async fn patient_insert_and_update() {
+        let (_, _, connection_manager, _) =
+            setup_all("patient_insert_and_update", MockDataInserts::all()).await;
+        let service_provider = ServiceProvider::new(connection_manager);
+        let context = service_provider.context().unwrap();
+        let service = &service_provider.patient_service;
+        let store_id = &mock_store_a().id;
+
+        // PatientAlreadyExists, names of other types count as well
+        assert_eq!(
+            service.insert_patient(&context, store_id, insert_input(&mock_name_a().id, "P0002")),
+            Err(InsertPatientError::PatientAlreadyExists)
+        );
+        assert_eq!(
+            service.insert_patient(&context, store_id, insert_input("patient_b", "P0001")),
+            Err(InsertPatientError::PatientWithCodeAlreadyExists)
+        );
+        assert_eq!(
+            service.insert_patient(
+                &context,
+                store_id,
+                InsertPatient {
+                    last_name: " ".to_string(),
+                    ..insert_input("patient_b", "P0002")
+                }
+            ),
+            Err(InsertPatientError::LastNameIsEmpty)
+        );
+        assert_eq!(
+            service.insert_patient(&context, "invalid", insert_input("patient_b", "P0002")),
+            Err(InsertPatientError::InvalidStore)
+        );
+
+        // the registering store becomes the home store
+        let patient = service
+            .insert_patient(&context, store_id, insert_input("patient_b", "P0002"))
+            .unwrap();
+        assert_eq!(patient.name, "Smith, John");
+        assert_eq!(patient.home_store_id, Some(store_id.clone()));
+
+        assert_eq!(
+            service.update_patient(&context, store_id, update_input("invalid")),
+            Err(UpdatePatientError::PatientDoesNotExist)
+        );
+        assert_eq!(
+            service.update_patient(&context, store_id, update_input(&mock_name_a().id)),
+            Err(UpdatePatientError::PatientDoesNotExist)
+        );
+        // patients can only be edited in their home store
+        assert_eq!(
+            service.update_patient(&context, &mock_store_b().id, update_input("patient_b")),
+            Err(UpdatePatientError::NotPatientHomeStore)
+        );
+        assert_eq!(
+            service.update_patient(
+                &context,
+                store_id,
+                UpdatePatient {
+                    code: Some("P0001".to_string()),
+                    ..update_input("patient_b")
+                }
+            ),
+            Err(UpdatePatientError::PatientWithCodeAlreadyExists)
+        );
+
+        // the name follows the first and last name
+        let patient = service
+            .update_patient(
+                &context,
+                store_id,
+                UpdatePatient {
+                    last_name: Some("Brown".to_string()),
+                    phone: Some("021555555".to_string()),
+                    ..update_input("patient_b")
+                },
+            )
+            .unwrap();
+        assert_eq!(patient.name, "Brown, John");
+        assert_eq!(patient.code, "P0002");
+        assert_eq!(patient.phone, Some("021555555".to_string()));
+        assert_eq!(patient.date_of_birth, Some(NaiveDate::from_ymd(1990, 7, 1)));
+    }
+
+    #[actix_rt::test]
+    async fn prescriber_insert() {
+        let (_, _, connection_manager, _) =
+            setup_all("prescriber_insert", MockDataInserts::all()).await;
+        let service_provider = ServiceProvider::new(connection_manager);
+        let context = service_provider.context().unwrap();
+        let service = &service_provider.patient_service;
+        let store_id = &mock_store_a().id;
+
+        let input = |id: &str, last_name: &str| InsertPrescriber {
+            id: id.to_string(),
+            code: id.to_string(),
+            first_name: Some("Anna".to_string()),
+            last_name: last_name.to_string(),
+            registration_code: Some("MC1234".to_string()),
+        };
+        assert_eq!(
+            service.insert_prescriber(&context, store_id, input("prescriber_a", "")),
+            Err(InsertPrescriberError::LastNameIsEmpty)
+        );
+        let prescriber = service
+            .insert_prescriber(&context, store_id, input("prescriber_a", "Young"))
+            .unwrap();
+        assert_eq!(prescriber.home_store_id, Some(store_id.clone()));
+        assert_eq!(
+            service.insert_prescriber(&context, store_id, input("prescriber_a", "Young")),
+            Err(InsertPrescriberError::PrescriberAlreadyExists)
+        );
+        service
+            .insert_prescriber(&context, store_id, input("prescriber_b", "Adams"))
+            .unwrap();
+
+        let ids: Vec<String> = service
+            .get_prescribers(&context)
+            .unwrap()
+            .into_iter()
+            .map(|prescriber| prescriber.id)
+            .collect();
+        assert_eq!(ids, vec!["prescriber_b", "prescriber_a"]);
+    }
+}
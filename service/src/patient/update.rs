@@ -0,0 +1,128 @@
+use chrono::NaiveDate;
+use domain::patient::Patient;
+use repository::{
+    schema::{NameRow, SyncOutRowActionType, SyncOutRowTableNameType},
+    NameRepository, RepositoryError, StorageConnection,
+};
+
+use crate::{service_provider::ServiceContext, sync_out::queue_upload, SingleRecordError};
+
+use super::{
+    query::get_patient,
+    validate::{
+        check_is_home_store, check_patient_code_is_unique, check_patient_exists, patient_name,
+    },
+};
+
+pub struct UpdatePatient {
+    pub id: String,
+    pub code: Option<String>,
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    pub date_of_birth: Option<NaiveDate>,
+    pub phone: Option<String>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum UpdatePatientError {
+    DatabaseError(RepositoryError),
+    InternalError(String),
+    PatientDoesNotExist,
+    /// Patients can only be edited in their home store
+    NotPatientHomeStore,
+    PatientWithCodeAlreadyExists,
+    LastNameIsEmpty,
+}
+
+fn validate(
+    connection: &StorageConnection,
+    store_id: &str,
+    input: &UpdatePatient,
+) -> Result<NameRow, UpdatePatientError> {
+    let patient = check_patient_exists(connection, &input.id)?
+        .ok_or(UpdatePatientError::PatientDoesNotExist)?;
+    if !check_is_home_store(&patient, store_id) {
+        return Err(UpdatePatientError::NotPatientHomeStore);
+    }
+    if let Some(code) = &input.code {
+        if !check_patient_code_is_unique(connection, &input.id, code)? {
+            return Err(UpdatePatientError::PatientWithCodeAlreadyExists);
+        }
+    }
+    if let Some(last_name) = &input.last_name {
+        if last_name.trim().is_empty() {
+            return Err(UpdatePatientError::LastNameIsEmpty);
+        }
+    }
+    Ok(patient)
+}
+
+fn generate(
+    existing: NameRow,
+    UpdatePatient {
+        id: _,
+        code,
+        first_name,
+        last_name,
+        date_of_birth,
+        phone,
+    }: UpdatePatient,
+) -> NameRow {
+    let first_name = first_name.or(existing.first_name);
+    let last_name = last_name.or(existing.last_name);
+    let name = match &last_name {
+        Some(last_name) => patient_name(&first_name, last_name),
+        None => existing.name,
+    };
+    NameRow {
+        name,
+        code: code.unwrap_or(existing.code),
+        first_name,
+        last_name,
+        date_of_birth: date_of_birth.or(existing.date_of_birth),
+        phone: phone.or(existing.phone),
+        ..existing
+    }
+}
+
+pub fn update_patient(
+    ctx: &ServiceContext,
+    store_id: &str,
+    input: UpdatePatient,
+) -> Result<Patient, UpdatePatientError> {
+    let patient = ctx
+        .connection
+        .transaction_sync(|connection| {
+            let existing = validate(connection, store_id, &input)?;
+            let updated = generate(existing, input);
+            NameRepository::new(connection).upsert_one(&updated)?;
+            queue_upload(
+                connection,
+                store_id,
+                SyncOutRowTableNameType::Name,
+                &updated.id,
+                SyncOutRowActionType::Update,
+            )?;
+
+            get_patient(ctx, updated.id).map_err(UpdatePatientError::from)
+        })
+        .map_err(|error| error.to_inner_error())?;
+    Ok(patient)
+}
+
+impl From<RepositoryError> for UpdatePatientError {
+    fn from(error: RepositoryError) -> Self {
+        UpdatePatientError::DatabaseError(error)
+    }
+}
+
+impl From<SingleRecordError> for UpdatePatientError {
+    fn from(error: SingleRecordError) -> Self {
+        match error {
+            SingleRecordError::DatabaseError(error) => UpdatePatientError::DatabaseError(error),
+            SingleRecordError::NotFound(_) => UpdatePatientError::InternalError(
+                "Failed to read the just updated patient!".to_string(),
+            ),
+        }
+    }
+}
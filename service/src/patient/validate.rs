@@ -0,0 +1,40 @@
+use domain::{patient::PatientFilter, SimpleStringFilter};
+use repository::{
+    schema::{NameRow, NameRowType},
+    NameRepository, PatientRepository, RepositoryError, StorageConnection,
+};
+
+/// Returns the name row of the patient, `None` if the name doesn't exist or isn't a patient
+pub fn check_patient_exists(
+    connection: &StorageConnection,
+    id: &str,
+) -> Result<Option<NameRow>, RepositoryError> {
+    let name = NameRepository::new(connection)
+        .find_many_by_id(&[id.to_string()])?
+        .pop();
+    Ok(name.filter(|name| name.r#type == NameRowType::Patient))
+}
+
+/// Patients can only be edited in their home store
+pub fn check_is_home_store(patient: &NameRow, store_id: &str) -> bool {
+    patient.home_store_id.as_deref() == Some(store_id)
+}
+
+/// Checks no other patient has the code
+pub fn check_patient_code_is_unique(
+    connection: &StorageConnection,
+    id: &str,
+    code: &str,
+) -> Result<bool, RepositoryError> {
+    let patients = PatientRepository::new(connection)
+        .query_by_filter(PatientFilter::new().code(SimpleStringFilter::equal_to(code)))?;
+    Ok(patients.iter().all(|patient| patient.id == id))
+}
+
+/// Full name of a patient, e.g. "Doe, Jane"
+pub fn patient_name(first_name: &Option<String>, last_name: &str) -> String {
+    match first_name {
+        Some(first_name) if !first_name.is_empty() => format!("{}, {}", last_name, first_name),
+        _ => last_name.to_string(),
+    }
+}
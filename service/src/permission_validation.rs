@@ -19,6 +19,12 @@ pub enum PermissionDSL {
 #[derive(Debug, PartialEq, Eq, Hash)]
 pub enum Resource {
     RouteMe,
+    // list queries
+    QueryItems,
+    QueryNames,
+    QueryStockLines,
+    QueryInvoices,
+    QueryStockTakes,
     // stock take
     InsertStockTake,
     UpdateStockTake,
@@ -27,6 +33,54 @@ pub enum Resource {
     InsertStockTakeLine,
     UpdateStockTakeLine,
     DeleteStockTakeLine,
+    // store preferences
+    QueryStorePreferences,
+    UpdateStorePreferences,
+    // stock on hand
+    QueryStockOnHand,
+    QueryStockMovements,
+    QueryStockExpiryReport,
+    HoldStockLines,
+    QueryBatchRecallTrace,
+    RecallBatch,
+    QueryStockValuation,
+    // export
+    ExportList,
+    // import
+    ImportOpeningStock,
+    // report
+    QueryReports,
+    RenderReport,
+    // plugin
+    RunPluginReport,
+    // barcode
+    ScanBarcode,
+    MutateBarcode,
+    // pack variant
+    MutatePackVariant,
+    // item properties
+    MutateItemProperties,
+    QueryAbcClassification,
+    CalculateAbcClassification,
+    // returns
+    MutateSupplierReturn,
+    MutateCustomerReturn,
+    // repack
+    RepackStock,
+    // location movements
+    QueryLocationMovements,
+    MoveStock,
+    // temperature logs
+    QueryTemperatureLogs,
+    IngestTemperatureLogs,
+    MutateTemperatureBreachConfigs,
+    // patients and prescriptions
+    QueryPatients,
+    MutatePatient,
+    MutatePrescriber,
+    MutatePrescription,
+    // names
+    MutateName,
 }
 
 fn default() -> PermissionDSL {
@@ -40,6 +94,12 @@ fn all_permissions() -> HashMap<Resource, PermissionDSL> {
     let mut map = HashMap::new();
     // me
     map.insert(Resource::RouteMe, PermissionDSL::HasApiRole(ApiRole::User));
+    // list queries
+    map.insert(Resource::QueryItems, default());
+    map.insert(Resource::QueryNames, default());
+    map.insert(Resource::QueryStockLines, default());
+    map.insert(Resource::QueryInvoices, default());
+    map.insert(Resource::QueryStockTakes, default());
     // stock take
     map.insert(Resource::InsertStockTake, default());
     map.insert(Resource::UpdateStockTake, default());
@@ -48,6 +108,54 @@ fn all_permissions() -> HashMap<Resource, PermissionDSL> {
     map.insert(Resource::InsertStockTakeLine, default());
     map.insert(Resource::UpdateStockTakeLine, default());
     map.insert(Resource::DeleteStockTakeLine, default());
+    // store preferences
+    map.insert(Resource::QueryStorePreferences, default());
+    map.insert(Resource::UpdateStorePreferences, default());
+    // stock on hand
+    map.insert(Resource::QueryStockOnHand, default());
+    map.insert(Resource::QueryStockMovements, default());
+    map.insert(Resource::QueryStockExpiryReport, default());
+    map.insert(Resource::HoldStockLines, default());
+    map.insert(Resource::QueryBatchRecallTrace, default());
+    map.insert(Resource::RecallBatch, default());
+    map.insert(Resource::QueryStockValuation, default());
+    // export
+    map.insert(Resource::ExportList, default());
+    // import
+    map.insert(Resource::ImportOpeningStock, default());
+    // report
+    map.insert(Resource::QueryReports, default());
+    map.insert(Resource::RenderReport, default());
+    // plugin
+    map.insert(Resource::RunPluginReport, default());
+    // barcode
+    map.insert(Resource::ScanBarcode, default());
+    map.insert(Resource::MutateBarcode, default());
+    // pack variant
+    map.insert(Resource::MutatePackVariant, default());
+    // item properties
+    map.insert(Resource::MutateItemProperties, default());
+    map.insert(Resource::QueryAbcClassification, default());
+    map.insert(Resource::CalculateAbcClassification, default());
+    // returns
+    map.insert(Resource::MutateSupplierReturn, default());
+    map.insert(Resource::MutateCustomerReturn, default());
+    // repack
+    map.insert(Resource::RepackStock, default());
+    // location movements
+    map.insert(Resource::QueryLocationMovements, default());
+    map.insert(Resource::MoveStock, default());
+    // temperature logs
+    map.insert(Resource::QueryTemperatureLogs, default());
+    map.insert(Resource::IngestTemperatureLogs, default());
+    map.insert(Resource::MutateTemperatureBreachConfigs, default());
+    // patients and prescriptions
+    map.insert(Resource::QueryPatients, default());
+    map.insert(Resource::MutatePatient, default());
+    map.insert(Resource::MutatePrescriber, default());
+    map.insert(Resource::MutatePrescription, default());
+    // names
+    map.insert(Resource::MutateName, default());
 
     map
 }
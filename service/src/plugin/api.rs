@@ -0,0 +1,147 @@
+use domain::{stock_line::StockLineFilter, EqualFilter};
+use repository::{
+    schema::{InvoiceRow, ItemRow, NameRow},
+    InvoiceLineRowRepository, InvoiceRepository, ItemFilter, ItemQueryRepository, ItemRepository,
+    NameRepository, NameStoreJoinRepository, RepositoryError, StockLineRepository,
+    StockTakeLineFilter, StockTakeLineRepository, StockTakeRowRepository, StorageConnection,
+};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use super::data::{
+    PluginInvoice, PluginInvoiceLine, PluginItem, PluginName, PluginStockLine, PluginStockTake,
+    PluginStockTakeLine,
+};
+
+/// Read only queries a plugin can make through the `query` host function.
+///
+/// Records are limited to the store the hook runs for, records of other stores are returned as
+/// `null`. Items must be visible in the store and names must be joined to the store.
+#[derive(Deserialize, Debug, PartialEq)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum PluginQuery {
+    #[serde(rename_all = "camelCase")]
+    Item { id: String },
+    #[serde(rename_all = "camelCase")]
+    Name { id: String },
+    #[serde(rename_all = "camelCase")]
+    Invoice { id: String },
+    #[serde(rename_all = "camelCase")]
+    InvoiceLines { invoice_id: String },
+    #[serde(rename_all = "camelCase")]
+    StockLines { item_id: Option<String> },
+    #[serde(rename_all = "camelCase")]
+    StockTake { id: String },
+}
+
+/// Runs a JSON encoded [PluginQuery], returns `{"result": ...}` or `{"error": "..."}`
+pub fn run_query(connection: &StorageConnection, store_id: &str, request: &[u8]) -> Value {
+    let query = match serde_json::from_slice::<PluginQuery>(request) {
+        Ok(query) => query,
+        Err(error) => return json!({ "error": format!("Invalid query: {}", error) }),
+    };
+    match query_result(connection, store_id, query) {
+        Ok(result) => json!({ "result": result }),
+        Err(error) => json!({ "error": format!("Database error: {}", error) }),
+    }
+}
+
+fn store_invoice(
+    connection: &StorageConnection,
+    store_id: &str,
+    id: String,
+) -> Result<Option<InvoiceRow>, RepositoryError> {
+    Ok(InvoiceRepository::new(connection)
+        .find_many_by_id(&[id])?
+        .pop()
+        .filter(|invoice| invoice.store_id == store_id))
+}
+
+fn store_item(
+    connection: &StorageConnection,
+    store_id: &str,
+    id: String,
+) -> Result<Option<ItemRow>, RepositoryError> {
+    let filter = ItemFilter::new()
+        .id(EqualFilter::equal_to(&id))
+        .match_is_visible(true);
+    if ItemQueryRepository::new(connection).count(store_id, Some(filter))? == 0 {
+        return Ok(None);
+    }
+    ItemRepository::new(connection).find_one_by_id(&id)
+}
+
+fn store_name(
+    connection: &StorageConnection,
+    store_id: &str,
+    id: String,
+) -> Result<Option<NameRow>, RepositoryError> {
+    if NameStoreJoinRepository::new(connection)
+        .find_one_by_name_and_store_id(&id, store_id)?
+        .is_none()
+    {
+        return Ok(None);
+    }
+    Ok(NameRepository::new(connection)
+        .find_many_by_id(&[id])?
+        .pop())
+}
+
+fn query_result(
+    connection: &StorageConnection,
+    store_id: &str,
+    query: PluginQuery,
+) -> Result<Value, RepositoryError> {
+    let result = match query {
+        PluginQuery::Item { id } => {
+            json!(store_item(connection, store_id, id)?.map(PluginItem::from))
+        }
+        PluginQuery::Name { id } => {
+            json!(store_name(connection, store_id, id)?.map(PluginName::from))
+        }
+        PluginQuery::Invoice { id } => json!(store_invoice(connection, store_id, id)?
+            .as_ref()
+            .map(PluginInvoice::from)),
+        PluginQuery::InvoiceLines { invoice_id } => {
+            match store_invoice(connection, store_id, invoice_id)? {
+                Some(invoice) => json!(InvoiceLineRowRepository::new(connection)
+                    .find_many_by_invoice_id(&invoice.id)?
+                    .iter()
+                    .map(PluginInvoiceLine::from)
+                    .collect::<Vec<PluginInvoiceLine>>()),
+                None => Value::Null,
+            }
+        }
+        PluginQuery::StockLines { item_id } => {
+            let mut filter = StockLineFilter::new().store_id(EqualFilter::equal_to(store_id));
+            if let Some(item_id) = item_id {
+                filter = filter.item_id(EqualFilter::equal_to(&item_id));
+            }
+            json!(StockLineRepository::new(connection)
+                .query_by_filter(filter)?
+                .into_iter()
+                .map(PluginStockLine::from)
+                .collect::<Vec<PluginStockLine>>())
+        }
+        PluginQuery::StockTake { id } => {
+            let stock_take = StockTakeRowRepository::new(connection)
+                .find_one_by_id(&id)?
+                .filter(|stock_take| stock_take.store_id == store_id);
+            match stock_take {
+                Some(stock_take) => {
+                    let lines: Vec<PluginStockTakeLine> = StockTakeLineRepository::new(connection)
+                        .query_by_filter(
+                            StockTakeLineFilter::new()
+                                .stock_take_id(EqualFilter::equal_to(&stock_take.id)),
+                        )?
+                        .iter()
+                        .map(|line| PluginStockTakeLine::from(&line.line))
+                        .collect();
+                    json!({ "stockTake": PluginStockTake::from(&stock_take), "lines": lines })
+                }
+                None => Value::Null,
+            }
+        }
+    };
+    Ok(result)
+}
@@ -0,0 +1,267 @@
+use chrono::{NaiveDate, NaiveDateTime};
+use domain::stock_line::StockLine;
+use repository::schema::{
+    InvoiceLineRow, InvoiceLineRowType, InvoiceRow, InvoiceRowStatus, InvoiceRowType, ItemRow,
+    ItemRowType, NameRow, StockTakeLineRow, StockTakeRow, StockTakeStatus,
+};
+use serde::Serialize;
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PluginItem {
+    pub id: String,
+    pub code: String,
+    pub name: String,
+    pub unit_id: Option<String>,
+    pub r#type: &'static str,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PluginName {
+    pub id: String,
+    pub code: String,
+    pub name: String,
+    pub is_customer: bool,
+    pub is_supplier: bool,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PluginInvoice {
+    pub id: String,
+    pub store_id: String,
+    pub other_party_id: String,
+    pub invoice_number: i64,
+    pub r#type: &'static str,
+    pub status: &'static str,
+    pub on_hold: bool,
+    pub comment: Option<String>,
+    pub their_reference: Option<String>,
+    pub created_datetime: NaiveDateTime,
+    pub allocated_datetime: Option<NaiveDateTime>,
+    pub picked_datetime: Option<NaiveDateTime>,
+    pub shipped_datetime: Option<NaiveDateTime>,
+    pub delivered_datetime: Option<NaiveDateTime>,
+    pub verified_datetime: Option<NaiveDateTime>,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PluginInvoiceLine {
+    pub id: String,
+    pub invoice_id: String,
+    pub item_id: String,
+    pub item_code: String,
+    pub item_name: String,
+    pub r#type: &'static str,
+    pub stock_line_id: Option<String>,
+    pub location_id: Option<String>,
+    pub batch: Option<String>,
+    pub expiry_date: Option<NaiveDate>,
+    pub pack_size: i32,
+    pub number_of_packs: i32,
+    pub cost_price_per_pack: f64,
+    pub sell_price_per_pack: f64,
+    pub total_before_tax: f64,
+    pub total_after_tax: f64,
+    pub tax: Option<f64>,
+    pub note: Option<String>,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PluginStockLine {
+    pub id: String,
+    pub item_id: String,
+    pub location_id: Option<String>,
+    pub batch: Option<String>,
+    pub expiry_date: Option<NaiveDate>,
+    pub pack_size: i32,
+    pub available_number_of_packs: i32,
+    pub total_number_of_packs: i32,
+    pub cost_price_per_pack: f64,
+    pub sell_price_per_pack: f64,
+    pub on_hold: bool,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PluginStockTake {
+    pub id: String,
+    pub store_id: String,
+    pub status: &'static str,
+    pub description: Option<String>,
+    pub comment: Option<String>,
+    pub created_datetime: NaiveDateTime,
+    pub finalised_datetime: Option<NaiveDateTime>,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PluginStockTakeLine {
+    pub id: String,
+    pub stock_take_id: String,
+    pub item_id: String,
+    pub stock_line_id: Option<String>,
+    pub location_id: Option<String>,
+    pub batch: Option<String>,
+    pub expiry_date: Option<NaiveDate>,
+    pub pack_size: Option<i32>,
+    pub snapshot_number_of_packs: i32,
+    pub counted_number_of_packs: Option<i32>,
+    pub comment: Option<String>,
+}
+
+pub fn invoice_status(status: &InvoiceRowStatus) -> &'static str {
+    match status {
+        InvoiceRowStatus::New => "NEW",
+        InvoiceRowStatus::Allocated => "ALLOCATED",
+        InvoiceRowStatus::Picked => "PICKED",
+        InvoiceRowStatus::Shipped => "SHIPPED",
+        InvoiceRowStatus::Delivered => "DELIVERED",
+        InvoiceRowStatus::Verified => "VERIFIED",
+    }
+}
+
+impl From<ItemRow> for PluginItem {
+    fn from(row: ItemRow) -> Self {
+        PluginItem {
+            id: row.id,
+            code: row.code,
+            name: row.name,
+            unit_id: row.unit_id,
+            r#type: match row.r#type {
+                ItemRowType::Stock => "STOCK",
+                ItemRowType::Service => "SERVICE",
+                ItemRowType::NonStock => "NON_STOCK",
+            },
+        }
+    }
+}
+
+impl From<NameRow> for PluginName {
+    fn from(row: NameRow) -> Self {
+        PluginName {
+            id: row.id,
+            code: row.code,
+            name: row.name,
+            is_customer: row.is_customer,
+            is_supplier: row.is_supplier,
+        }
+    }
+}
+
+impl From<&InvoiceRow> for PluginInvoice {
+    fn from(row: &InvoiceRow) -> Self {
+        PluginInvoice {
+            id: row.id.clone(),
+            store_id: row.store_id.clone(),
+            other_party_id: row.name_id.clone(),
+            invoice_number: row.invoice_number,
+            r#type: match row.r#type {
+                InvoiceRowType::OutboundShipment => "OUTBOUND_SHIPMENT",
+                InvoiceRowType::InboundShipment => "INBOUND_SHIPMENT",
+                InvoiceRowType::InventoryAdjustment => "INVENTORY_ADJUSTMENT",
+                InvoiceRowType::SupplierReturn => "SUPPLIER_RETURN",
+                InvoiceRowType::CustomerReturn => "CUSTOMER_RETURN",
+                InvoiceRowType::Repack => "REPACK",
+                InvoiceRowType::Prescription => "PRESCRIPTION",
+                InvoiceRowType::LocationMovement => "LOCATION_MOVEMENT",
+            },
+            status: invoice_status(&row.status),
+            on_hold: row.on_hold,
+            comment: row.comment.clone(),
+            their_reference: row.their_reference.clone(),
+            created_datetime: row.created_datetime,
+            allocated_datetime: row.allocated_datetime,
+            picked_datetime: row.picked_datetime,
+            shipped_datetime: row.shipped_datetime,
+            delivered_datetime: row.delivered_datetime,
+            verified_datetime: row.verified_datetime,
+        }
+    }
+}
+
+impl From<&InvoiceLineRow> for PluginInvoiceLine {
+    fn from(row: &InvoiceLineRow) -> Self {
+        PluginInvoiceLine {
+            id: row.id.clone(),
+            invoice_id: row.invoice_id.clone(),
+            item_id: row.item_id.clone(),
+            item_code: row.item_code.clone(),
+            item_name: row.item_name.clone(),
+            r#type: match row.r#type {
+                InvoiceLineRowType::StockIn => "STOCK_IN",
+                InvoiceLineRowType::StockOut => "STOCK_OUT",
+                InvoiceLineRowType::UnallocatedStock => "UNALLOCATED_STOCK",
+                InvoiceLineRowType::Service => "SERVICE",
+            },
+            stock_line_id: row.stock_line_id.clone(),
+            location_id: row.location_id.clone(),
+            batch: row.batch.clone(),
+            expiry_date: row.expiry_date,
+            pack_size: row.pack_size,
+            number_of_packs: row.number_of_packs,
+            cost_price_per_pack: row.cost_price_per_pack,
+            sell_price_per_pack: row.sell_price_per_pack,
+            total_before_tax: row.total_before_tax,
+            total_after_tax: row.total_after_tax,
+            tax: row.tax,
+            note: row.note.clone(),
+        }
+    }
+}
+
+impl From<StockLine> for PluginStockLine {
+    fn from(stock_line: StockLine) -> Self {
+        PluginStockLine {
+            id: stock_line.id,
+            item_id: stock_line.item_id,
+            location_id: stock_line.location_id,
+            batch: stock_line.batch,
+            expiry_date: stock_line.expiry_date,
+            pack_size: stock_line.pack_size,
+            available_number_of_packs: stock_line.available_number_of_packs,
+            total_number_of_packs: stock_line.total_number_of_packs,
+            cost_price_per_pack: stock_line.cost_price_per_pack,
+            sell_price_per_pack: stock_line.sell_price_per_pack,
+            on_hold: stock_line.on_hold,
+        }
+    }
+}
+
+impl From<&StockTakeRow> for PluginStockTake {
+    fn from(row: &StockTakeRow) -> Self {
+        PluginStockTake {
+            id: row.id.clone(),
+            store_id: row.store_id.clone(),
+            status: match row.status {
+                StockTakeStatus::New => "NEW",
+                StockTakeStatus::Finalized => "FINALIZED",
+            },
+            description: row.description.clone(),
+            comment: row.comment.clone(),
+            created_datetime: row.created_datetime,
+            finalised_datetime: row.finalised_datetime,
+        }
+    }
+}
+
+impl From<&StockTakeLineRow> for PluginStockTakeLine {
+    fn from(row: &StockTakeLineRow) -> Self {
+        PluginStockTakeLine {
+            id: row.id.clone(),
+            stock_take_id: row.stock_take_id.clone(),
+            item_id: row.item_id.clone(),
+            stock_line_id: row.stock_line_id.clone(),
+            location_id: row.location_id.clone(),
+            batch: row.batch.clone(),
+            expiry_date: row.expiry_date,
+            pack_size: row.pack_size,
+            snapshot_number_of_packs: row.snapshot_number_of_packs,
+            counted_number_of_packs: row.counted_number_of_packs,
+            comment: row.comment.clone(),
+        }
+    }
+}
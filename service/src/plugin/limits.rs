@@ -0,0 +1,140 @@
+use parity_wasm::elements::{
+    External, FunctionType, ImportCountType, ImportEntry, ImportSection, Instruction, Internal,
+    Module, Section, Type, TypeSection, ValueType,
+};
+
+use super::runtime::HOST_MODULE;
+
+/// Maximum number of instructions a plugin can execute per hook call (see `inject_gas_counter`)
+pub const GAS_LIMIT: u64 = 50_000_000;
+/// Maximum memory of a plugin, 16 MiB in 64 KiB pages
+pub const MAX_MEMORY_PAGES: u32 = 256;
+
+/// Plugins must declare the maximum size of their memory
+pub fn check_memory_limits(module: &Module) -> Result<(), String> {
+    let memories = match module.memory_section() {
+        Some(section) => section.entries(),
+        None => return Ok(()),
+    };
+    for memory in memories {
+        match memory.limits().maximum() {
+            None => return Err("Plugin memory has no maximum size".to_string()),
+            Some(maximum) if maximum > MAX_MEMORY_PAGES => {
+                return Err(format!(
+                    "Plugin memory maximum of {} pages exceeds the limit of {} pages",
+                    maximum, MAX_MEMORY_PAGES
+                ))
+            }
+            Some(_) => {}
+        }
+    }
+    Ok(())
+}
+
+/// Adds the `gas(i32)` host import and calls it at the start of every function and every loop
+/// iteration with the number of instructions of the function or loop. The host traps once the
+/// plugin used up `GAS_LIMIT`, this bounds plugins that run into an infinite loop.
+///
+/// The import is appended to the imported functions, indices of the functions defined by the
+/// module are shifted by one.
+pub fn inject_gas_counter(mut module: Module) -> Result<Module, String> {
+    let gas_function = module.import_count(ImportCountType::Function) as u32;
+
+    if module.type_section().is_none() {
+        module
+            .insert_section(Section::Type(TypeSection::with_types(vec![])))
+            .map_err(|error| error.to_string())?;
+    }
+    let types = module.type_section_mut().unwrap().types_mut();
+    types.push(Type::Function(FunctionType::new(
+        vec![ValueType::I32],
+        vec![],
+    )));
+    let gas_type = types.len() as u32 - 1;
+
+    if module.import_section().is_none() {
+        module
+            .insert_section(Section::Import(ImportSection::with_entries(vec![])))
+            .map_err(|error| error.to_string())?;
+    }
+    module
+        .import_section_mut()
+        .unwrap()
+        .entries_mut()
+        .push(ImportEntry::new(
+            HOST_MODULE.to_string(),
+            "gas".to_string(),
+            External::Function(gas_type),
+        ));
+
+    let shift = |index: u32| {
+        if index >= gas_function {
+            index + 1
+        } else {
+            index
+        }
+    };
+    if let Some(code) = module.code_section_mut() {
+        for body in code.bodies_mut() {
+            let instructions = body.code_mut().elements_mut();
+            *instructions = meter_instructions(instructions, gas_function, shift);
+        }
+    }
+    if let Some(exports) = module.export_section_mut() {
+        for export in exports.entries_mut() {
+            if let Internal::Function(index) = export.internal_mut() {
+                *index = shift(*index);
+            }
+        }
+    }
+    if let Some(elements) = module.elements_section_mut() {
+        for segment in elements.entries_mut() {
+            for member in segment.members_mut() {
+                *member = shift(*member);
+            }
+        }
+    }
+    if let Some(start) = module.start_section() {
+        module.set_start_section(shift(start));
+    }
+    Ok(module)
+}
+
+fn meter_instructions(
+    instructions: &[Instruction],
+    gas_function: u32,
+    shift: impl Fn(u32) -> u32,
+) -> Vec<Instruction> {
+    let mut metered = Vec::with_capacity(instructions.len() + 2);
+    metered.push(Instruction::I32Const(instructions.len() as i32));
+    metered.push(Instruction::Call(gas_function));
+    for (index, instruction) in instructions.iter().enumerate() {
+        match instruction {
+            Instruction::Call(function) => metered.push(Instruction::Call(shift(*function))),
+            Instruction::Loop(_) => {
+                metered.push(instruction.clone());
+                // a branch to a loop continues right after the loop instruction
+                metered.push(Instruction::I32Const(
+                    loop_length(&instructions[index + 1..]) as i32,
+                ));
+                metered.push(Instruction::Call(gas_function));
+            }
+            _ => metered.push(instruction.clone()),
+        }
+    }
+    metered
+}
+
+/// Number of instructions up to and including the `end` of the current block
+fn loop_length(instructions: &[Instruction]) -> usize {
+    let mut depth = 0;
+    for (index, instruction) in instructions.iter().enumerate() {
+        match instruction {
+            Instruction::Block(_) | Instruction::Loop(_) | Instruction::If(_) => depth += 1,
+            Instruction::End if depth == 0 => return index + 1,
+            Instruction::End => depth -= 1,
+            _ => {}
+        }
+    }
+    instructions.len()
+}
@@ -0,0 +1,388 @@
+//! Host for WebAssembly plugins, loaded from the `*.wasm` files of the plugin directory.
+//!
+//! Hook inputs and outputs are passed as JSON through the linear memory of the plugin. A plugin
+//! exports:
+//! - `memory`
+//! - `alloc(len: i32) -> i32`, to allocate the input of a hook
+//! - any of the [PluginHook] functions `(ptr: i32, len: i32) -> i64`, returning `(ptr << 32) |
+//!   len` of the JSON output or 0 for no output. `{"error": "..."}` rejects the operation for
+//!   `before` and validation hooks.
+//!
+//! Plugins can import from the `omsupply` module:
+//! - `log(ptr: i32, len: i32)`
+//! - `query(ptr: i32, len: i32) -> i32`, runs a read only [api::PluginQuery] and returns the
+//!   length of the JSON result
+//! - `read_result(ptr: i32)`, copies the result of the last query into the plugin memory
+//!
+//! Every hook call runs in a fresh instance of the module, plugins can't keep state between
+//! calls. A hook call can execute at most [limits::GAS_LIMIT] instructions and plugins must
+//! declare a maximum memory size of at most [limits::MAX_MEMORY_PAGES].
+use std::{ffi::OsStr, fs, path::Path};
+
+use parity_wasm::elements;
+use repository::{
+    schema::{InvoiceLineRow, InvoiceRow, InvoiceRowStatus, StockTakeRow},
+    StockTakeLine, StorageConnection,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use wasmi::Module;
+
+use self::data::{
+    invoice_status, PluginInvoice, PluginInvoiceLine, PluginStockTake, PluginStockTakeLine,
+};
+
+pub mod api;
+pub mod data;
+pub mod limits;
+mod runtime;
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PluginHook {
+    /// Input: `{storeId, invoice, newStatus}`, can reject the status change
+    BeforeInvoiceStatusChange,
+    /// Input: `{storeId, invoice, previousStatus}`, runs after the change is committed
+    AfterInvoiceStatusChange,
+    /// Input: `{storeId, invoice, line}`, can reject inserted or updated invoice lines
+    ValidateInvoiceLine,
+    /// Input: `{storeId, stockTake, lines}`, can reject finalising the stock take
+    BeforeStockTakeFinalise,
+    /// Input: `{storeId, arguments}`, output is the result of the `pluginReport` query
+    Report,
+}
+
+impl PluginHook {
+    pub fn all() -> [PluginHook; 5] {
+        [
+            PluginHook::BeforeInvoiceStatusChange,
+            PluginHook::AfterInvoiceStatusChange,
+            PluginHook::ValidateInvoiceLine,
+            PluginHook::BeforeStockTakeFinalise,
+            PluginHook::Report,
+        ]
+    }
+
+    /// Name of the function a plugin exports for the hook
+    pub fn export_name(&self) -> &'static str {
+        match self {
+            PluginHook::BeforeInvoiceStatusChange => "before_invoice_status_change",
+            PluginHook::AfterInvoiceStatusChange => "after_invoice_status_change",
+            PluginHook::ValidateInvoiceLine => "validate_invoice_line",
+            PluginHook::BeforeStockTakeFinalise => "before_stock_take_finalise",
+            PluginHook::Report => "report",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum PluginError {
+    /// Plugin directory or file couldn't be read
+    IoError(String),
+    /// Module isn't valid WebAssembly or doesn't implement the plugin interface
+    InvalidModule {
+        plugin: String,
+        message: String,
+    },
+    PluginDoesNotExist(String),
+    HookNotImplemented {
+        plugin: String,
+        hook: PluginHook,
+    },
+    /// Plugin trapped or returned invalid output
+    ExecutionError {
+        plugin: String,
+        message: String,
+    },
+}
+
+/// Operation rejected by a plugin hook
+#[derive(Debug, Clone, PartialEq)]
+pub struct PluginRejection {
+    pub plugin: String,
+    pub message: String,
+}
+
+#[derive(Deserialize, Default)]
+struct HookOutput {
+    error: Option<String>,
+}
+
+pub struct Plugin {
+    /// File stem of the module
+    pub name: String,
+    pub hooks: Vec<PluginHook>,
+    module: Module,
+}
+
+impl Plugin {
+    pub fn new(name: &str, bytes: &[u8]) -> Result<Plugin, PluginError> {
+        let invalid = |message: String| PluginError::InvalidModule {
+            plugin: name.to_string(),
+            message,
+        };
+        let module = parity_wasm::deserialize_buffer::<elements::Module>(bytes)
+            .map_err(|error| invalid(error.to_string()))?;
+        limits::check_memory_limits(&module).map_err(invalid)?;
+        let module = limits::inject_gas_counter(module).map_err(invalid)?;
+        let module =
+            Module::from_parity_wasm_module(module).map_err(|error| invalid(error.to_string()))?;
+        // instantiate once to check the imports and exports
+        let instance = runtime::instantiate(&module).map_err(invalid)?;
+        if runtime::exported_memory(&instance).is_none() {
+            return Err(invalid("Plugin doesn't export memory".to_string()));
+        }
+        if instance.export_by_name("alloc").is_none() {
+            return Err(invalid("Plugin doesn't export alloc".to_string()));
+        }
+        let hooks = PluginHook::all()
+            .iter()
+            .filter(|hook| instance.export_by_name(hook.export_name()).is_some())
+            .cloned()
+            .collect();
+
+        Ok(Plugin {
+            name: name.to_string(),
+            hooks,
+            module,
+        })
+    }
+
+    pub fn implements(&self, hook: PluginHook) -> bool {
+        self.hooks.contains(&hook)
+    }
+
+    fn call<T: Serialize>(
+        &self,
+        connection: &StorageConnection,
+        store_id: &str,
+        hook: PluginHook,
+        input: &T,
+    ) -> Result<Option<Value>, PluginError> {
+        let execution_error = |message: String| PluginError::ExecutionError {
+            plugin: self.name.clone(),
+            message,
+        };
+        let input =
+            serde_json::to_vec(input).map_err(|error| execution_error(error.to_string()))?;
+        let output = runtime::call(
+            &self.module,
+            &self.name,
+            hook.export_name(),
+            connection,
+            store_id,
+            &input,
+        )
+        .map_err(execution_error)?;
+        match output {
+            Some(output) => serde_json::from_slice(&output)
+                .map(Some)
+                .map_err(|error| execution_error(format!("Invalid output: {}", error))),
+            None => Ok(None),
+        }
+    }
+
+    /// Runs a `before` or validation hook, an error of the plugin rejects the operation
+    fn check<T: Serialize>(
+        &self,
+        connection: &StorageConnection,
+        store_id: &str,
+        hook: PluginHook,
+        input: &T,
+    ) -> Result<(), PluginRejection> {
+        let output = self
+            .call(connection, store_id, hook, input)
+            .and_then(|output| match output {
+                Some(output) => serde_json::from_value::<HookOutput>(output).map_err(|error| {
+                    PluginError::ExecutionError {
+                        plugin: self.name.clone(),
+                        message: format!("Invalid output: {}", error),
+                    }
+                }),
+                None => Ok(HookOutput::default()),
+            });
+        let message = match output {
+            Ok(HookOutput { error: None }) => return Ok(()),
+            Ok(HookOutput {
+                error: Some(message),
+            }) => message,
+            Err(error) => {
+                log::error!("Plugin {} failed: {:?}", self.name, error);
+                format!("Plugin failed: {:?}", error)
+            }
+        };
+        Err(PluginRejection {
+            plugin: self.name.clone(),
+            message,
+        })
+    }
+}
+
+/// Loaded plugins, shared by all requests through the service provider
+#[derive(Default)]
+pub struct PluginHost {
+    plugins: Vec<Plugin>,
+}
+
+impl PluginHost {
+    pub fn new(plugins: Vec<Plugin>) -> PluginHost {
+        PluginHost { plugins }
+    }
+
+    /// Loads the `*.wasm` files of the directory, ordered by file name
+    pub fn load_directory(directory: &Path) -> Result<PluginHost, PluginError> {
+        let io_error = |error: std::io::Error| {
+            PluginError::IoError(format!("{}: {}", directory.display(), error))
+        };
+        let mut paths = Vec::new();
+        for entry in fs::read_dir(directory).map_err(io_error)? {
+            let path = entry.map_err(io_error)?.path();
+            if path.is_file() && path.extension() == Some(OsStr::new("wasm")) {
+                paths.push(path);
+            }
+        }
+        paths.sort();
+
+        let mut plugins = Vec::new();
+        for path in paths {
+            let name = path
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().to_string())
+                .unwrap_or_default();
+            let bytes = fs::read(&path).map_err(io_error)?;
+            let plugin = Plugin::new(&name, &bytes)?;
+            log::info!("Loaded plugin {} with hooks {:?}", name, plugin.hooks);
+            plugins.push(plugin);
+        }
+        Ok(PluginHost { plugins })
+    }
+
+    pub fn plugins(&self) -> &[Plugin] {
+        &self.plugins
+    }
+
+    fn implementing(&self, hook: PluginHook) -> impl Iterator<Item = &Plugin> {
+        self.plugins
+            .iter()
+            .filter(move |plugin| plugin.implements(hook))
+    }
+
+    pub fn before_invoice_status_change(
+        &self,
+        connection: &StorageConnection,
+        invoice: &InvoiceRow,
+        new_status: &InvoiceRowStatus,
+    ) -> Result<(), PluginRejection> {
+        let input = json!({
+            "storeId": invoice.store_id,
+            "invoice": PluginInvoice::from(invoice),
+            "newStatus": invoice_status(new_status),
+        });
+        for plugin in self.implementing(PluginHook::BeforeInvoiceStatusChange) {
+            plugin.check(
+                connection,
+                &invoice.store_id,
+                PluginHook::BeforeInvoiceStatusChange,
+                &input,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Failures are only logged, the status change is already committed
+    pub fn after_invoice_status_change(
+        &self,
+        connection: &StorageConnection,
+        invoice: &InvoiceRow,
+        previous_status: &InvoiceRowStatus,
+    ) {
+        let input = json!({
+            "storeId": invoice.store_id,
+            "invoice": PluginInvoice::from(invoice),
+            "previousStatus": invoice_status(previous_status),
+        });
+        for plugin in self.implementing(PluginHook::AfterInvoiceStatusChange) {
+            if let Err(error) = plugin.call(
+                connection,
+                &invoice.store_id,
+                PluginHook::AfterInvoiceStatusChange,
+                &input,
+            ) {
+                log::error!("Plugin {} failed: {:?}", plugin.name, error);
+            }
+        }
+    }
+
+    pub fn validate_invoice_line(
+        &self,
+        connection: &StorageConnection,
+        invoice: &InvoiceRow,
+        line: &InvoiceLineRow,
+    ) -> Result<(), PluginRejection> {
+        let input = json!({
+            "storeId": invoice.store_id,
+            "invoice": PluginInvoice::from(invoice),
+            "line": PluginInvoiceLine::from(line),
+        });
+        for plugin in self.implementing(PluginHook::ValidateInvoiceLine) {
+            plugin.check(
+                connection,
+                &invoice.store_id,
+                PluginHook::ValidateInvoiceLine,
+                &input,
+            )?;
+        }
+        Ok(())
+    }
+
+    pub fn before_stock_take_finalise(
+        &self,
+        connection: &StorageConnection,
+        stock_take: &StockTakeRow,
+        lines: &[StockTakeLine],
+    ) -> Result<(), PluginRejection> {
+        let input = json!({
+            "storeId": stock_take.store_id,
+            "stockTake": PluginStockTake::from(stock_take),
+            "lines": lines
+                .iter()
+                .map(|line| PluginStockTakeLine::from(&line.line))
+                .collect::<Vec<PluginStockTakeLine>>(),
+        });
+        for plugin in self.implementing(PluginHook::BeforeStockTakeFinalise) {
+            plugin.check(
+                connection,
+                &stock_take.store_id,
+                PluginHook::BeforeStockTakeFinalise,
+                &input,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Runs the report hook of a plugin, returns its JSON output
+    pub fn report(
+        &self,
+        connection: &StorageConnection,
+        store_id: &str,
+        plugin: &str,
+        arguments: Value,
+    ) -> Result<Value, PluginError> {
+        let plugin = self
+            .plugins
+            .iter()
+            .find(|candidate| candidate.name == plugin)
+            .ok_or_else(|| PluginError::PluginDoesNotExist(plugin.to_string()))?;
+        if !plugin.implements(PluginHook::Report) {
+            return Err(PluginError::HookNotImplemented {
+                plugin: plugin.name.clone(),
+                hook: PluginHook::Report,
+            });
+        }
+        let input = json!({ "storeId": store_id, "arguments": arguments });
+        let output = plugin.call(connection, store_id, PluginHook::Report, &input)?;
+        Ok(output.unwrap_or(Value::Null))
+    }
+}
@@ -0,0 +1,232 @@
+use std::fmt;
+
+use repository::StorageConnection;
+use wasmi::{
+    Error, Externals, FuncInstance, FuncRef, HostError, ImportsBuilder, MemoryRef, Module,
+    ModuleImportResolver, ModuleInstance, ModuleRef, RuntimeArgs, RuntimeValue, Signature, Trap,
+    ValueType,
+};
+
+use super::{api::run_query, limits::GAS_LIMIT};
+
+/// Module name of the host functions imported by plugins
+pub const HOST_MODULE: &str = "omsupply";
+
+const LOG: usize = 0;
+const QUERY: usize = 1;
+const READ_RESULT: usize = 2;
+/// Injected into every plugin when loading it, see `limits::inject_gas_counter`
+const GAS: usize = 3;
+
+struct HostImports;
+
+impl ModuleImportResolver for HostImports {
+    fn resolve_func(&self, field_name: &str, signature: &Signature) -> Result<FuncRef, Error> {
+        let (index, expected) = match field_name {
+            "log" => (
+                LOG,
+                Signature::new(&[ValueType::I32, ValueType::I32][..], None),
+            ),
+            "query" => (
+                QUERY,
+                Signature::new(&[ValueType::I32, ValueType::I32][..], Some(ValueType::I32)),
+            ),
+            "read_result" => (READ_RESULT, Signature::new(&[ValueType::I32][..], None)),
+            "gas" => (GAS, Signature::new(&[ValueType::I32][..], None)),
+            _ => {
+                return Err(Error::Instantiation(format!(
+                    "Unknown host function {}::{}",
+                    HOST_MODULE, field_name
+                )))
+            }
+        };
+        if signature != &expected {
+            return Err(Error::Instantiation(format!(
+                "Invalid signature of host function {}::{}",
+                HOST_MODULE, field_name
+            )));
+        }
+        Ok(FuncInstance::alloc_host(expected, index))
+    }
+}
+
+#[derive(Debug)]
+struct HostTrap(String);
+
+impl fmt::Display for HostTrap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl HostError for HostTrap {}
+
+/// Instructions used by the plugin instance
+#[derive(Default)]
+struct Gas {
+    used: u64,
+}
+
+impl Gas {
+    fn charge(&mut self, args: &RuntimeArgs) -> Result<Option<RuntimeValue>, Trap> {
+        let amount: u32 = args.nth_checked(0)?;
+        self.used += amount as u64;
+        if self.used > GAS_LIMIT {
+            return Err(HostTrap(format!(
+                "Plugin exceeded the limit of {} instructions",
+                GAS_LIMIT
+            ))
+            .into());
+        }
+        Ok(None)
+    }
+}
+
+/// Externals of the start function, only the injected gas counter can be called
+impl Externals for Gas {
+    fn invoke_index(
+        &mut self,
+        index: usize,
+        args: RuntimeArgs,
+    ) -> Result<Option<RuntimeValue>, Trap> {
+        match index {
+            GAS => self.charge(&args),
+            _ => Err(HostTrap("The start function can't call host functions".to_string()).into()),
+        }
+    }
+}
+
+/// State of the host functions during one hook call
+struct HostState<'a> {
+    plugin: &'a str,
+    connection: &'a StorageConnection,
+    store_id: &'a str,
+    memory: MemoryRef,
+    /// Result of the last `query`, copied into the plugin memory by `read_result`
+    pending_result: Vec<u8>,
+    gas: Gas,
+}
+
+impl<'a> HostState<'a> {
+    fn read(&self, args: &RuntimeArgs, ptr: usize, len: usize) -> Result<Vec<u8>, Trap> {
+        let ptr: u32 = args.nth_checked(ptr)?;
+        let len: u32 = args.nth_checked(len)?;
+        self.memory
+            .get(ptr, len as usize)
+            .map_err(|error| HostTrap(error.to_string()).into())
+    }
+}
+
+impl<'a> Externals for HostState<'a> {
+    fn invoke_index(
+        &mut self,
+        index: usize,
+        args: RuntimeArgs,
+    ) -> Result<Option<RuntimeValue>, Trap> {
+        match index {
+            LOG => {
+                let message = self.read(&args, 0, 1)?;
+                log::info!(
+                    "Plugin {}: {}",
+                    self.plugin,
+                    String::from_utf8_lossy(&message)
+                );
+                Ok(None)
+            }
+            QUERY => {
+                let request = self.read(&args, 0, 1)?;
+                let result = run_query(self.connection, self.store_id, &request);
+                self.pending_result = result.to_string().into_bytes();
+                Ok(Some(RuntimeValue::I32(self.pending_result.len() as i32)))
+            }
+            READ_RESULT => {
+                let ptr: u32 = args.nth_checked(0)?;
+                self.memory
+                    .set(ptr, &self.pending_result)
+                    .map_err(|error| HostTrap(error.to_string()))?;
+                self.pending_result.clear();
+                Ok(None)
+            }
+            GAS => self.gas.charge(&args),
+            _ => Err(HostTrap(format!("Unknown host function index {}", index)).into()),
+        }
+    }
+}
+
+/// Instantiates the module and runs its start function, the start function can't call host
+/// functions
+pub fn instantiate(module: &Module) -> Result<ModuleRef, String> {
+    let imports = ImportsBuilder::new().with_resolver(HOST_MODULE, &HostImports);
+    ModuleInstance::new(module, &imports)
+        .map_err(|error| error.to_string())?
+        .run_start(&mut Gas::default())
+        .map_err(|error| error.to_string())
+}
+
+pub fn exported_memory(instance: &ModuleRef) -> Option<MemoryRef> {
+    instance
+        .export_by_name("memory")
+        .and_then(|export| export.as_memory().cloned())
+}
+
+/// Calls an exported `(ptr: i32, len: i32) -> i64` function of a fresh instance with the input
+/// copied into the plugin memory. The plugin returns `(ptr << 32) | len` of its output or 0 for
+/// no output.
+pub fn call(
+    module: &Module,
+    plugin: &str,
+    export: &str,
+    connection: &StorageConnection,
+    store_id: &str,
+    input: &[u8],
+) -> Result<Option<Vec<u8>>, String> {
+    let instance = instantiate(module)?;
+    let memory = exported_memory(&instance).ok_or("Plugin doesn't export memory")?;
+    let mut state = HostState {
+        plugin,
+        connection,
+        store_id,
+        memory: memory.clone(),
+        pending_result: Vec::new(),
+        gas: Gas::default(),
+    };
+
+    let input_ptr = match instance
+        .invoke_export(
+            "alloc",
+            &[RuntimeValue::I32(input.len() as i32)],
+            &mut state,
+        )
+        .map_err(|error| error.to_string())?
+    {
+        Some(RuntimeValue::I32(ptr)) => ptr,
+        _ => return Err("alloc must return an i32 pointer".to_string()),
+    };
+    memory
+        .set(input_ptr as u32, input)
+        .map_err(|error| error.to_string())?;
+
+    let output = match instance
+        .invoke_export(
+            export,
+            &[
+                RuntimeValue::I32(input_ptr),
+                RuntimeValue::I32(input.len() as i32),
+            ],
+            &mut state,
+        )
+        .map_err(|error| error.to_string())?
+    {
+        Some(RuntimeValue::I64(output)) => output as u64,
+        _ => return Err(format!("{} must return an i64", export)),
+    };
+    if output == 0 {
+        return Ok(None);
+    }
+    let output_ptr = (output >> 32) as u32;
+    let output_len = (output & 0xffff_ffff) as usize;
+    memory
+        .get(output_ptr, output_len)
+        .map(Some)
+        .map_err(|error| error.to_string())
+}
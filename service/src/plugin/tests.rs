@@ -0,0 +1,441 @@
+#[cfg(test)]
+mod plugin_test {
+    use std::sync::Arc;
+
+    use domain::outbound_shipment::{UpdateOutboundShipment, UpdateOutboundShipmentStatus};
+    use parity_wasm::{
+        builder,
+        elements::{BlockType, External, ImportEntry, Instruction, Instructions, ValueType},
+    };
+    use repository::{
+        mock::{
+            mock_inbound_shipment_a, mock_outbound_shipment_a,
+            mock_outbound_shipment_a_invoice_lines, mock_outbound_shipment_no_lines,
+            mock_stock_take_no_count_change, mock_store_a, mock_store_b, MockDataInserts,
+        },
+        schema::{InvoiceRowStatus, StockTakeStatus},
+        test_db::setup_all,
+        InvoiceRepository,
+    };
+    use serde_json::{json, Value};
+
+    use crate::{
+        invoice::{update_outbound_shipment, UpdateOutboundShipmentError},
+        plugin::{
+            api::run_query, limits::MAX_MEMORY_PAGES, Plugin, PluginError, PluginHook, PluginHost,
+            PluginRejection,
+        },
+        service_provider::ServiceProvider,
+        stock_take::update::{UpdateStockTakeError, UpdateStockTakeInput},
+    };
+
+    const LOG: u32 = 0;
+    const QUERY: u32 = 1;
+    const READ_RESULT: u32 = 2;
+    /// Address `alloc` returns for the hook input
+    const INPUT: i32 = 4096;
+    /// Address query results are copied to
+    const RESULT: i32 = 16384;
+
+    /// Returns `(ptr << 32) | len` of a data segment
+    fn output(ptr: i32, data: &str) -> Vec<Instruction> {
+        vec![
+            Instruction::I64Const(((ptr as i64) << 32) | data.len() as i64),
+            Instruction::End,
+        ]
+    }
+
+    /// Builds a plugin importing all host functions, exporting memory, `alloc` and the hooks
+    fn plugin_module(hooks: Vec<(&str, Vec<Instruction>)>, data: Vec<(i32, &str)>) -> Vec<u8> {
+        let mut module = builder::module();
+        let imports = [
+            ("log", vec![ValueType::I32, ValueType::I32], None),
+            (
+                "query",
+                vec![ValueType::I32, ValueType::I32],
+                Some(ValueType::I32),
+            ),
+            ("read_result", vec![ValueType::I32], None),
+        ];
+        for (field, params, result) in imports.iter() {
+            let mut signature = builder::signature().with_params(params.clone());
+            if let Some(result) = result {
+                signature = signature.with_result(*result);
+            }
+            let type_ref = module.push_signature(signature.build_sig());
+            module.push_import(ImportEntry::new(
+                "omsupply".to_string(),
+                field.to_string(),
+                External::Function(type_ref),
+            ));
+        }
+        let mut module = module
+            .memory()
+            .with_min(2)
+            .with_max(Some(16))
+            .build()
+            .export()
+            .field("memory")
+            .internal()
+            .memory(0)
+            .build()
+            .function()
+            .signature()
+            .with_param(ValueType::I32)
+            .with_result(ValueType::I32)
+            .build()
+            .body()
+            .with_instructions(Instructions::new(vec![
+                Instruction::I32Const(INPUT),
+                Instruction::End,
+            ]))
+            .build()
+            .build()
+            .export()
+            .field("alloc")
+            .internal()
+            .func(imports.len() as u32)
+            .build();
+        for (index, (name, instructions)) in hooks.into_iter().enumerate() {
+            module = module
+                .function()
+                .signature()
+                .with_params(vec![ValueType::I32, ValueType::I32])
+                .with_result(ValueType::I64)
+                .build()
+                .body()
+                .with_instructions(Instructions::new(instructions))
+                .build()
+                .build()
+                .export()
+                .field(name)
+                .internal()
+                .func((imports.len() + 1 + index) as u32)
+                .build();
+        }
+        for (offset, value) in data {
+            module = module
+                .data()
+                .offset(Instruction::I32Const(offset))
+                .value(value.as_bytes().to_vec())
+                .build();
+        }
+        parity_wasm::serialize(module.build()).unwrap()
+    }
+
+    /// Rejects all invoice status changes, invoice lines and stock take finalisations
+    fn rejecting_plugin() -> Plugin {
+        let error = r#"{"error":"Rejected by test plugin"}"#;
+        let bytes = plugin_module(
+            vec![
+                ("before_invoice_status_change", output(1024, error)),
+                ("validate_invoice_line", output(1024, error)),
+                ("before_stock_take_finalise", output(1024, error)),
+                // failing after hooks are only logged
+                (
+                    "after_invoice_status_change",
+                    vec![Instruction::Unreachable, Instruction::End],
+                ),
+            ],
+            vec![(1024, error)],
+        );
+        Plugin::new("rejecting", &bytes).unwrap()
+    }
+
+    #[test]
+    fn load_plugin() {
+        let plugin = rejecting_plugin();
+        assert_eq!(plugin.name, "rejecting");
+        assert_eq!(
+            plugin.hooks,
+            vec![
+                PluginHook::BeforeInvoiceStatusChange,
+                PluginHook::AfterInvoiceStatusChange,
+                PluginHook::ValidateInvoiceLine,
+                PluginHook::BeforeStockTakeFinalise,
+            ]
+        );
+        assert!(!plugin.implements(PluginHook::Report));
+
+        // InvalidModule
+        assert!(matches!(
+            Plugin::new("invalid", b"not wasm"),
+            Err(PluginError::InvalidModule { plugin, .. }) if plugin == "invalid"
+        ));
+        let with_memory = |max: Option<u32>| {
+            parity_wasm::serialize(
+                builder::module()
+                    .memory()
+                    .with_max(max)
+                    .build()
+                    .export()
+                    .field("memory")
+                    .internal()
+                    .memory(0)
+                    .build()
+                    .build(),
+            )
+            .unwrap()
+        };
+        assert!(matches!(
+            Plugin::new("without_alloc", &with_memory(Some(1))),
+            Err(PluginError::InvalidModule { message, .. }) if message.contains("alloc")
+        ));
+        // memory must be limited
+        assert!(matches!(
+            Plugin::new("unlimited_memory", &with_memory(None)),
+            Err(PluginError::InvalidModule { message, .. }) if message.contains("no maximum")
+        ));
+        assert!(matches!(
+            Plugin::new("large_memory", &with_memory(Some(MAX_MEMORY_PAGES + 1))),
+            Err(PluginError::InvalidModule { message, .. }) if message.contains("exceeds")
+        ));
+    }
+
+    #[actix_rt::test]
+    async fn plugin_instruction_limit() {
+        let (_, connection, _, _) =
+            setup_all("plugin_instruction_limit", MockDataInserts::all()).await;
+        // loops forever, the large loop body keeps the number of iterations until the limit low
+        let looping = Plugin::new(
+            "looping",
+            &plugin_module(
+                vec![(
+                    "report",
+                    vec![Instruction::Loop(BlockType::NoResult)]
+                        .into_iter()
+                        .chain(vec![Instruction::Nop; 1000])
+                        .chain(vec![
+                            Instruction::Br(0),
+                            Instruction::End,
+                            Instruction::I64Const(0),
+                            Instruction::End,
+                        ])
+                        .collect(),
+                )],
+                vec![],
+            ),
+        )
+        .unwrap();
+        let host = PluginHost::new(vec![looping]);
+        assert!(matches!(
+            host.report(&connection, &mock_store_a().id, "looping", json!(null)),
+            Err(PluginError::ExecutionError { plugin, message })
+                if plugin == "looping" && message.contains("limit")
+        ));
+    }
+
+    #[actix_rt::test]
+    async fn plugin_report() {
+        let (_, connection, _, _) = setup_all("plugin_report", MockDataInserts::all()).await;
+
+        // returns its input
+        let echo = Plugin::new(
+            "echo",
+            &plugin_module(
+                vec![(
+                    "report",
+                    vec![
+                        Instruction::GetLocal(0),
+                        Instruction::I64ExtendUI32,
+                        Instruction::I64Const(32),
+                        Instruction::I64Shl,
+                        Instruction::GetLocal(1),
+                        Instruction::I64ExtendUI32,
+                        Instruction::I64Or,
+                        Instruction::End,
+                    ],
+                )],
+                vec![],
+            ),
+        )
+        .unwrap();
+
+        // logs, queries an item and returns the query result
+        let request = r#"{"type":"item","id":"item_query_test1"}"#;
+        let message = "Querying item_query_test1";
+        let query = Plugin::new(
+            "query",
+            &plugin_module(
+                vec![(
+                    "report",
+                    vec![
+                        Instruction::I32Const(2048),
+                        Instruction::I32Const(message.len() as i32),
+                        Instruction::Call(LOG),
+                        Instruction::I32Const(1024),
+                        Instruction::I32Const(request.len() as i32),
+                        Instruction::Call(QUERY),
+                        Instruction::SetLocal(1),
+                        Instruction::I32Const(RESULT),
+                        Instruction::Call(READ_RESULT),
+                        Instruction::I64Const((RESULT as i64) << 32),
+                        Instruction::GetLocal(1),
+                        Instruction::I64ExtendUI32,
+                        Instruction::I64Or,
+                        Instruction::End,
+                    ],
+                )],
+                vec![(1024, request), (2048, message)],
+            ),
+        )
+        .unwrap();
+        let host = PluginHost::new(vec![echo, query, rejecting_plugin()]);
+
+        assert_eq!(
+            host.report(
+                &connection,
+                &mock_store_a().id,
+                "echo",
+                json!({ "from": "2021-01-01" })
+            ),
+            Ok(json!({ "storeId": "store_a", "arguments": { "from": "2021-01-01" } }))
+        );
+        assert_eq!(
+            host.report(&connection, &mock_store_a().id, "query", json!(null)),
+            Ok(json!({
+                "result": {
+                    "id": "item_query_test1",
+                    "code": "code_item_query_test1",
+                    "name": "name_item_query_test1",
+                    "unitId": null,
+                    "type": "STOCK"
+                }
+            }))
+        );
+        // item isn't visible in store_b
+        assert_eq!(
+            host.report(&connection, &mock_store_b().id, "query", json!(null)),
+            Ok(json!({ "result": null }))
+        );
+
+        // PluginDoesNotExist
+        assert_eq!(
+            host.report(&connection, &mock_store_a().id, "invalid", json!(null)),
+            Err(PluginError::PluginDoesNotExist("invalid".to_string()))
+        );
+        // HookNotImplemented
+        assert_eq!(
+            host.report(&connection, &mock_store_a().id, "rejecting", json!(null)),
+            Err(PluginError::HookNotImplemented {
+                plugin: "rejecting".to_string(),
+                hook: PluginHook::Report
+            })
+        );
+    }
+
+    #[actix_rt::test]
+    async fn plugin_query_store() {
+        let (_, connection, _, _) = setup_all("plugin_query_store", MockDataInserts::all()).await;
+
+        let invoice_id = mock_inbound_shipment_a().id;
+        let stock_take_id = mock_stock_take_no_count_change().id;
+        let requests = vec![
+            json!({ "type": "item", "id": "item_query_test1" }),
+            json!({ "type": "name", "id": "name_a" }),
+            json!({ "type": "invoice", "id": invoice_id }),
+            json!({ "type": "invoiceLines", "invoiceId": invoice_id }),
+            json!({ "type": "stockTake", "id": stock_take_id }),
+        ];
+
+        for request in requests {
+            let request = request.to_string();
+            let result = run_query(&connection, &mock_store_a().id, request.as_bytes());
+            assert_ne!(result["result"], json!(null), "{}", request);
+            // records of store_a can't be read by a hook running for store_b
+            let result = run_query(&connection, &mock_store_b().id, request.as_bytes());
+            assert_eq!(result, json!({ "result": null }), "{}", request);
+        }
+
+        let request = json!({ "type": "stockLines", "itemId": null }).to_string();
+        let stock_line_ids = |store_id: &str| -> Vec<Value> {
+            run_query(&connection, store_id, request.as_bytes())["result"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|stock_line| stock_line["id"].clone())
+                .collect()
+        };
+        let store_a_ids = stock_line_ids(&mock_store_a().id);
+        assert!(!store_a_ids.is_empty());
+        assert!(stock_line_ids(&mock_store_b().id)
+            .iter()
+            .all(|id| !store_a_ids.contains(id)));
+    }
+
+    #[actix_rt::test]
+    async fn plugin_hooks() {
+        let (_, connection, connection_manager, _) =
+            setup_all("plugin_hooks", MockDataInserts::all()).await;
+        let host = PluginHost::new(vec![rejecting_plugin()]);
+        let rejection = PluginRejection {
+            plugin: "rejecting".to_string(),
+            message: "Rejected by test plugin".to_string(),
+        };
+
+        // before_invoice_status_change
+        let invoice = mock_outbound_shipment_no_lines();
+        let patch = || UpdateOutboundShipment {
+            id: invoice.id.clone(),
+            other_party_id: None,
+            status: Some(UpdateOutboundShipmentStatus::Shipped),
+            on_hold: None,
+            comment: None,
+            their_reference: None,
+            color: None,
+        };
+        assert!(matches!(
+            update_outbound_shipment(&connection, &host, patch()),
+            Err(UpdateOutboundShipmentError::RejectedByPlugin(error)) if error == rejection
+        ));
+        assert_eq!(
+            InvoiceRepository::new(&connection)
+                .find_one_by_id(&invoice.id)
+                .unwrap()
+                .status,
+            InvoiceRowStatus::Picked
+        );
+        // without plugins, the failing after hook doesn't fail the update either
+        update_outbound_shipment(&connection, &PluginHost::default(), patch()).unwrap();
+
+        // a trapping plugin rejects
+        let trapping = Plugin::new(
+            "trapping",
+            &plugin_module(
+                vec![(
+                    "validate_invoice_line",
+                    vec![Instruction::Unreachable, Instruction::End],
+                )],
+                vec![],
+            ),
+        )
+        .unwrap();
+        let line = mock_outbound_shipment_a_invoice_lines().pop().unwrap();
+        let invoice = mock_outbound_shipment_a();
+        let error = PluginHost::new(vec![trapping])
+            .validate_invoice_line(&connection, &invoice, &line)
+            .unwrap_err();
+        assert_eq!(error.plugin, "trapping");
+        assert!(error.message.starts_with("Plugin failed"));
+
+        // before_stock_take_finalise
+        let mut service_provider = ServiceProvider::new(connection_manager);
+        service_provider.plugins = Arc::new(host);
+        let context = service_provider.context().unwrap();
+        let stock_take = mock_stock_take_no_count_change();
+        let result = service_provider.stock_take_service.update_stock_take(
+            &context,
+            &mock_store_a().id,
+            UpdateStockTakeInput {
+                id: stock_take.id,
+                comment: None,
+                description: None,
+                status: Some(StockTakeStatus::Finalized),
+            },
+        );
+        assert_eq!(
+            result,
+            Err(UpdateStockTakeError::RejectedByPlugin(rejection))
+        );
+    }
+}
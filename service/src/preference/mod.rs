@@ -0,0 +1,143 @@
+use repository::{
+    schema::{StorePreferenceOverrideRow, StorePreferenceRow},
+    RepositoryError, StorageConnection, StorePreferenceOverrideRowRepository,
+    StorePreferenceRowRepository,
+};
+
+use crate::service_provider::ServiceContext;
+
+use self::update::{update_store_preferences, UpdateStorePreferences, UpdateStorePreferencesError};
+
+pub mod update;
+
+#[cfg(test)]
+mod tests;
+
+pub const DEFAULT_MONTHS_OVERSTOCK: f64 = 6.0;
+pub const DEFAULT_MONTHS_UNDERSTOCK: f64 = 3.0;
+pub const DEFAULT_MONTHS_ITEMS_EXPIRE: f64 = 3.0;
+pub const DEFAULT_MONTHS_OF_STOCK_TARGET: f64 = 3.0;
+pub const DEFAULT_EXPIRING_SOON_DAYS: i32 = 7;
+
+/// Preferences of a store.
+///
+/// Every value is resolved from the local override, the preferences synced from central and the
+/// default value, in that order.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StorePreferences {
+    pub store_id: String,
+    /// Stock lines of received goods are stored with a pack size of one
+    pub pack_to_one: bool,
+    pub months_overstock: f64,
+    pub months_understock: f64,
+    pub months_items_expire: f64,
+    /// Outbound shipments can allocate more than the available stock
+    pub allow_negative_stock: bool,
+    /// Timezone offset in hours, `None` to use the timezone of the server
+    pub timezone_offset: Option<i32>,
+    pub months_of_stock_target: f64,
+    /// Changed stock take lines need a comment before the stock take can be finalised
+    pub requires_reason_on_adjustment: bool,
+    /// Stock expiring within this number of days is counted as expiring soon
+    pub expiring_soon_days: i32,
+}
+
+impl StorePreferences {
+    pub fn default_for_store(store_id: &str) -> StorePreferences {
+        StorePreferences {
+            store_id: store_id.to_string(),
+            pack_to_one: false,
+            months_overstock: DEFAULT_MONTHS_OVERSTOCK,
+            months_understock: DEFAULT_MONTHS_UNDERSTOCK,
+            months_items_expire: DEFAULT_MONTHS_ITEMS_EXPIRE,
+            allow_negative_stock: false,
+            timezone_offset: None,
+            months_of_stock_target: DEFAULT_MONTHS_OF_STOCK_TARGET,
+            requires_reason_on_adjustment: false,
+            expiring_soon_days: DEFAULT_EXPIRING_SOON_DAYS,
+        }
+    }
+}
+
+pub trait PreferenceServiceTrait: Sync + Send {
+    fn get_store_preferences(
+        &self,
+        ctx: &ServiceContext,
+        store_id: &str,
+    ) -> Result<StorePreferences, RepositoryError> {
+        get_store_preferences(&ctx.connection, store_id)
+    }
+
+    /// Stores the provided preferences as local overrides
+    fn update_store_preferences(
+        &self,
+        ctx: &ServiceContext,
+        store_id: &str,
+        input: UpdateStorePreferences,
+    ) -> Result<StorePreferences, UpdateStorePreferencesError> {
+        update_store_preferences(ctx, store_id, input)
+    }
+}
+
+pub struct PreferenceService {}
+impl PreferenceServiceTrait for PreferenceService {}
+
+/// Loads the preferences of a store.
+///
+/// Takes a plain connection so that it can be used from within other services.
+pub fn get_store_preferences(
+    connection: &StorageConnection,
+    store_id: &str,
+) -> Result<StorePreferences, RepositoryError> {
+    let synced = StorePreferenceRowRepository::new(connection).find_one_by_store_id(store_id)?;
+    let overrides =
+        StorePreferenceOverrideRowRepository::new(connection).find_one_by_store_id(store_id)?;
+    Ok(resolve_preferences(store_id, synced, overrides))
+}
+
+fn resolve_preferences(
+    store_id: &str,
+    synced: Option<StorePreferenceRow>,
+    overrides: Option<StorePreferenceOverrideRow>,
+) -> StorePreferences {
+    let mut preferences = StorePreferences::default_for_store(store_id);
+
+    if let Some(synced) = synced {
+        preferences.pack_to_one = synced.pack_to_one;
+        preferences.months_overstock = synced.months_overstock;
+        preferences.months_understock = synced.months_understock;
+        preferences.months_items_expire = synced.months_items_expire;
+    }
+
+    if let Some(overrides) = overrides {
+        let StorePreferenceOverrideRow {
+            id: _,
+            store_id: _,
+            pack_to_one,
+            months_overstock,
+            months_understock,
+            months_items_expire,
+            allow_negative_stock,
+            timezone_offset,
+            months_of_stock_target,
+            requires_reason_on_adjustment,
+            expiring_soon_days,
+        } = overrides;
+        preferences.pack_to_one = pack_to_one.unwrap_or(preferences.pack_to_one);
+        preferences.months_overstock = months_overstock.unwrap_or(preferences.months_overstock);
+        preferences.months_understock = months_understock.unwrap_or(preferences.months_understock);
+        preferences.months_items_expire =
+            months_items_expire.unwrap_or(preferences.months_items_expire);
+        preferences.allow_negative_stock =
+            allow_negative_stock.unwrap_or(preferences.allow_negative_stock);
+        preferences.timezone_offset = timezone_offset.or(preferences.timezone_offset);
+        preferences.months_of_stock_target =
+            months_of_stock_target.unwrap_or(preferences.months_of_stock_target);
+        preferences.requires_reason_on_adjustment =
+            requires_reason_on_adjustment.unwrap_or(preferences.requires_reason_on_adjustment);
+        preferences.expiring_soon_days =
+            expiring_soon_days.unwrap_or(preferences.expiring_soon_days);
+    }
+
+    preferences
+}
@@ -0,0 +1,179 @@
+#[cfg(test)]
+mod preference_test {
+    use repository::{
+        mock::{mock_store_a, mock_store_b, MockDataInserts},
+        schema::StorePreferenceRow,
+        test_db::setup_all,
+        StorePreferenceRowRepository,
+    };
+
+    use crate::{
+        preference::{
+            update::{UpdateStorePreferences, UpdateStorePreferencesError},
+            StorePreferences,
+        },
+        service_provider::ServiceProvider,
+    };
+
+    #[actix_rt::test]
+    async fn get_store_preferences() {
+        let (_, connection, connection_manager, _) =
+            setup_all("get_store_preferences", MockDataInserts::all()).await;
+
+        let service_provider = ServiceProvider::new(connection_manager);
+        let context = service_provider.context().unwrap();
+        let service = service_provider.preference_service;
+
+        // defaults without synced preferences
+        let store_a = mock_store_a();
+        assert_eq!(
+            service
+                .get_store_preferences(&context, &store_a.id)
+                .unwrap(),
+            StorePreferences::default_for_store(&store_a.id)
+        );
+
+        // synced preferences
+        StorePreferenceRowRepository::new(&connection)
+            .upsert_one(&StorePreferenceRow {
+                id: "store_a_preferences".to_string(),
+                store_id: store_a.id.clone(),
+                pack_to_one: true,
+                months_overstock: 4.0,
+                months_understock: 2.0,
+                months_items_expire: 1.0,
+            })
+            .unwrap();
+        let preferences = service
+            .get_store_preferences(&context, &store_a.id)
+            .unwrap();
+        assert_eq!(
+            preferences,
+            StorePreferences {
+                pack_to_one: true,
+                months_overstock: 4.0,
+                months_understock: 2.0,
+                months_items_expire: 1.0,
+                ..StorePreferences::default_for_store(&store_a.id)
+            }
+        );
+
+        // local overrides take precedence over synced preferences
+        let preferences = service
+            .update_store_preferences(
+                &context,
+                &store_a.id,
+                UpdateStorePreferences {
+                    pack_to_one: Some(false),
+                    allow_negative_stock: Some(true),
+                    timezone_offset: Some(12),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        assert_eq!(
+            preferences,
+            StorePreferences {
+                pack_to_one: false,
+                months_overstock: 4.0,
+                months_understock: 2.0,
+                months_items_expire: 1.0,
+                allow_negative_stock: true,
+                timezone_offset: Some(12),
+                ..StorePreferences::default_for_store(&store_a.id)
+            }
+        );
+        assert_eq!(
+            service
+                .get_store_preferences(&context, &store_a.id)
+                .unwrap(),
+            preferences
+        );
+
+        // other stores are not affected
+        let store_b = mock_store_b();
+        assert_eq!(
+            service
+                .get_store_preferences(&context, &store_b.id)
+                .unwrap(),
+            StorePreferences::default_for_store(&store_b.id)
+        );
+    }
+
+    #[actix_rt::test]
+    async fn update_store_preferences() {
+        let (_, _, connection_manager, _) =
+            setup_all("update_store_preferences", MockDataInserts::all()).await;
+
+        let service_provider = ServiceProvider::new(connection_manager);
+        let context = service_provider.context().unwrap();
+        let service = service_provider.preference_service;
+        let store_a = mock_store_a();
+
+        // error: StoreDoesNotExist
+        let error = service
+            .update_store_preferences(&context, "invalid", UpdateStorePreferences::default())
+            .unwrap_err();
+        assert_eq!(error, UpdateStorePreferencesError::StoreDoesNotExist);
+
+        // error: InvalidTimezoneOffset
+        let error = service
+            .update_store_preferences(
+                &context,
+                &store_a.id,
+                UpdateStorePreferences {
+                    timezone_offset: Some(15),
+                    ..Default::default()
+                },
+            )
+            .unwrap_err();
+        assert_eq!(error, UpdateStorePreferencesError::InvalidTimezoneOffset);
+
+        // error: NegativeValue
+        let error = service
+            .update_store_preferences(
+                &context,
+                &store_a.id,
+                UpdateStorePreferences {
+                    expiring_soon_days: Some(-1),
+                    ..Default::default()
+                },
+            )
+            .unwrap_err();
+        assert_eq!(error, UpdateStorePreferencesError::NegativeValue);
+
+        // success
+        service
+            .update_store_preferences(
+                &context,
+                &store_a.id,
+                UpdateStorePreferences {
+                    expiring_soon_days: Some(30),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+
+        // previous overrides are kept when updating other preferences
+        let preferences = service
+            .update_store_preferences(
+                &context,
+                &store_a.id,
+                UpdateStorePreferences {
+                    requires_reason_on_adjustment: Some(true),
+                    months_of_stock_target: Some(5.0),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        assert_eq!(
+            preferences,
+            StorePreferences {
+                expiring_soon_days: 30,
+                requires_reason_on_adjustment: true,
+                months_of_stock_target: 5.0,
+                ..StorePreferences::default_for_store(&store_a.id)
+            }
+        );
+    }
+}
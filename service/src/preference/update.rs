@@ -0,0 +1,128 @@
+use repository::{
+    schema::StorePreferenceOverrideRow, RepositoryError, StorageConnection,
+    StorePreferenceOverrideRowRepository, StoreRowRepository,
+};
+use util::uuid::uuid;
+
+use crate::service_provider::ServiceContext;
+
+use super::{get_store_preferences, StorePreferences};
+
+/// Preferences to override locally, `None` values are left unchanged
+#[derive(Default)]
+pub struct UpdateStorePreferences {
+    pub pack_to_one: Option<bool>,
+    pub months_overstock: Option<f64>,
+    pub months_understock: Option<f64>,
+    pub months_items_expire: Option<f64>,
+    pub allow_negative_stock: Option<bool>,
+    pub timezone_offset: Option<i32>,
+    pub months_of_stock_target: Option<f64>,
+    pub requires_reason_on_adjustment: Option<bool>,
+    pub expiring_soon_days: Option<i32>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum UpdateStorePreferencesError {
+    DatabaseError(RepositoryError),
+    StoreDoesNotExist,
+    InvalidTimezoneOffset,
+    NegativeValue,
+}
+
+pub fn update_store_preferences(
+    ctx: &ServiceContext,
+    store_id: &str,
+    input: UpdateStorePreferences,
+) -> Result<StorePreferences, UpdateStorePreferencesError> {
+    let preferences = ctx
+        .connection
+        .transaction_sync(|connection| {
+            let existing = validate(connection, store_id, &input)?;
+            let row = generate(store_id, existing, input);
+            StorePreferenceOverrideRowRepository::new(connection).upsert_one(&row)?;
+            get_store_preferences(connection, store_id).map_err(UpdateStorePreferencesError::from)
+        })
+        .map_err(|error| error.to_inner_error())?;
+    Ok(preferences)
+}
+
+fn validate(
+    connection: &StorageConnection,
+    store_id: &str,
+    input: &UpdateStorePreferences,
+) -> Result<Option<StorePreferenceOverrideRow>, UpdateStorePreferencesError> {
+    if StoreRowRepository::new(connection)
+        .find_one_by_id(store_id)?
+        .is_none()
+    {
+        return Err(UpdateStorePreferencesError::StoreDoesNotExist);
+    }
+
+    if let Some(offset) = input.timezone_offset {
+        if !(-12..=14).contains(&offset) {
+            return Err(UpdateStorePreferencesError::InvalidTimezoneOffset);
+        }
+    }
+
+    let months = [
+        input.months_overstock,
+        input.months_understock,
+        input.months_items_expire,
+        input.months_of_stock_target,
+    ];
+    if months.iter().flatten().any(|months| *months < 0.0)
+        || matches!(input.expiring_soon_days, Some(days) if days < 0)
+    {
+        return Err(UpdateStorePreferencesError::NegativeValue);
+    }
+
+    Ok(StorePreferenceOverrideRowRepository::new(connection).find_one_by_store_id(store_id)?)
+}
+
+fn generate(
+    store_id: &str,
+    existing: Option<StorePreferenceOverrideRow>,
+    UpdateStorePreferences {
+        pack_to_one,
+        months_overstock,
+        months_understock,
+        months_items_expire,
+        allow_negative_stock,
+        timezone_offset,
+        months_of_stock_target,
+        requires_reason_on_adjustment,
+        expiring_soon_days,
+    }: UpdateStorePreferences,
+) -> StorePreferenceOverrideRow {
+    let mut row = existing.unwrap_or_else(|| StorePreferenceOverrideRow {
+        id: uuid(),
+        store_id: store_id.to_string(),
+        pack_to_one: None,
+        months_overstock: None,
+        months_understock: None,
+        months_items_expire: None,
+        allow_negative_stock: None,
+        timezone_offset: None,
+        months_of_stock_target: None,
+        requires_reason_on_adjustment: None,
+        expiring_soon_days: None,
+    });
+    row.pack_to_one = pack_to_one.or(row.pack_to_one);
+    row.months_overstock = months_overstock.or(row.months_overstock);
+    row.months_understock = months_understock.or(row.months_understock);
+    row.months_items_expire = months_items_expire.or(row.months_items_expire);
+    row.allow_negative_stock = allow_negative_stock.or(row.allow_negative_stock);
+    row.timezone_offset = timezone_offset.or(row.timezone_offset);
+    row.months_of_stock_target = months_of_stock_target.or(row.months_of_stock_target);
+    row.requires_reason_on_adjustment =
+        requires_reason_on_adjustment.or(row.requires_reason_on_adjustment);
+    row.expiring_soon_days = expiring_soon_days.or(row.expiring_soon_days);
+    row
+}
+
+impl From<RepositoryError> for UpdateStorePreferencesError {
+    fn from(error: RepositoryError) -> Self {
+        UpdateStorePreferencesError::DatabaseError(error)
+    }
+}
@@ -0,0 +1,64 @@
+use repository::{InvoiceLineRowRepository, InvoiceRepository, RepositoryError, StorageConnection};
+
+use crate::{
+    invoice::check_invoice_exists_option, service_provider::ServiceContext,
+    validate::check_store_id_matches,
+};
+
+use super::validate::{check_is_prescription, check_lines_editable};
+
+#[derive(Debug, PartialEq)]
+pub enum DeletePrescriptionError {
+    DatabaseError(RepositoryError),
+    InvoiceDoesNotExist,
+    NotAPrescription,
+    NotThisStoreInvoice,
+    CannotEditPrescription,
+    /// Lines have to be deleted first
+    InvoiceLinesExist,
+}
+
+fn validate(
+    connection: &StorageConnection,
+    store_id: &str,
+    id: &str,
+) -> Result<(), DeletePrescriptionError> {
+    let existing = check_invoice_exists_option(id, connection)?
+        .ok_or(DeletePrescriptionError::InvoiceDoesNotExist)?;
+    if !check_is_prescription(&existing) {
+        return Err(DeletePrescriptionError::NotAPrescription);
+    }
+    if !check_store_id_matches(store_id, &existing.store_id) {
+        return Err(DeletePrescriptionError::NotThisStoreInvoice);
+    }
+    if !check_lines_editable(&existing) {
+        return Err(DeletePrescriptionError::CannotEditPrescription);
+    }
+    if !InvoiceLineRowRepository::new(connection)
+        .find_many_by_invoice_id(id)?
+        .is_empty()
+    {
+        return Err(DeletePrescriptionError::InvoiceLinesExist);
+    }
+    Ok(())
+}
+
+pub fn delete_prescription(
+    ctx: &ServiceContext,
+    store_id: &str,
+    id: &str,
+) -> Result<String, DeletePrescriptionError> {
+    ctx.connection
+        .transaction_sync(|connection| {
+            validate(connection, store_id, id)?;
+            InvoiceRepository::new(connection).delete(id)?;
+            Ok(id.to_string())
+        })
+        .map_err(|error| error.to_inner_error())
+}
+
+impl From<RepositoryError> for DeletePrescriptionError {
+    fn from(error: RepositoryError) -> Self {
+        DeletePrescriptionError::DatabaseError(error)
+    }
+}
@@ -0,0 +1,86 @@
+use repository::{
+    schema::{InvoiceLineRow, StockLineRow},
+    InvoiceLineRowRepository, RepositoryError, StockLineRowRepository, StorageConnection,
+};
+
+use crate::service_provider::ServiceContext;
+
+use super::validate::{check_prescription_lines_editable, PrescriptionCheckError};
+
+#[derive(Debug, PartialEq)]
+pub enum DeletePrescriptionLineError {
+    DatabaseError(RepositoryError),
+    LineDoesNotExist,
+    InvoiceDoesNotExist,
+    NotAPrescription,
+    NotThisStoreInvoice,
+    CannotEditPrescription,
+}
+
+fn validate(
+    connection: &StorageConnection,
+    store_id: &str,
+    id: &str,
+) -> Result<InvoiceLineRow, DeletePrescriptionLineError> {
+    let line = match InvoiceLineRowRepository::new(connection).find_one_by_id(id) {
+        Ok(line) => line,
+        Err(RepositoryError::NotFound) => {
+            return Err(DeletePrescriptionLineError::LineDoesNotExist)
+        }
+        Err(error) => return Err(error.into()),
+    };
+    check_prescription_lines_editable(connection, store_id, &line.invoice_id)?;
+    Ok(line)
+}
+
+/// Releases the packs reserved by the line
+fn generate(
+    connection: &StorageConnection,
+    line: &InvoiceLineRow,
+) -> Result<Option<StockLineRow>, RepositoryError> {
+    let stock_line_id = match &line.stock_line_id {
+        Some(stock_line_id) => stock_line_id,
+        None => return Ok(None),
+    };
+    let stock_line = StockLineRowRepository::new(connection).find_one_by_id(stock_line_id)?;
+    Ok(Some(StockLineRow {
+        available_number_of_packs: stock_line.available_number_of_packs + line.number_of_packs,
+        ..stock_line
+    }))
+}
+
+pub fn delete_prescription_line(
+    ctx: &ServiceContext,
+    store_id: &str,
+    id: &str,
+) -> Result<String, DeletePrescriptionLineError> {
+    ctx.connection
+        .transaction_sync(|connection| {
+            let line = validate(connection, store_id, id)?;
+            if let Some(stock_line) = generate(connection, &line)? {
+                StockLineRowRepository::new(connection).upsert_one(&stock_line)?;
+            }
+            InvoiceLineRowRepository::new(connection).delete(id)?;
+            Ok(id.to_string())
+        })
+        .map_err(|error| error.to_inner_error())
+}
+
+impl From<RepositoryError> for DeletePrescriptionLineError {
+    fn from(error: RepositoryError) -> Self {
+        DeletePrescriptionLineError::DatabaseError(error)
+    }
+}
+
+impl From<PrescriptionCheckError> for DeletePrescriptionLineError {
+    fn from(error: PrescriptionCheckError) -> Self {
+        use DeletePrescriptionLineError::*;
+        match error {
+            PrescriptionCheckError::DatabaseError(error) => DatabaseError(error),
+            PrescriptionCheckError::InvoiceDoesNotExist => InvoiceDoesNotExist,
+            PrescriptionCheckError::NotAPrescription => NotAPrescription,
+            PrescriptionCheckError::NotThisStoreInvoice => NotThisStoreInvoice,
+            PrescriptionCheckError::CannotEditPrescription => CannotEditPrescription,
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use domain::{
+    invoice::{Invoice, InvoiceFilter, InvoiceSort, InvoiceSortField, InvoiceStatus, InvoiceType},
+    EqualFilter, Pagination,
+};
+use repository::{InvoiceQueryRepository, RepositoryError};
+
+use crate::service_provider::ServiceContext;
+
+/// Prescriptions of all stores are included since patients can be dispensed to in any store
+pub fn get_patient_dispensing_history(
+    ctx: &ServiceContext,
+    patient_id: &str,
+) -> Result<Vec<Invoice>, RepositoryError> {
+    let filter = InvoiceFilter::new()
+        .name_id(EqualFilter::equal_to(patient_id))
+        .r#type(InvoiceType::Prescription.equal_to())
+        .status(InvoiceStatus::equal_any(vec![
+            InvoiceStatus::Picked,
+            InvoiceStatus::Verified,
+        ]));
+    let sort = InvoiceSort {
+        key: InvoiceSortField::PickedDatetime,
+        desc: Some(true),
+    };
+    InvoiceQueryRepository::new(&ctx.connection).query(Pagination::all(), Some(filter), Some(sort))
+}
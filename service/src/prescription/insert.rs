@@ -0,0 +1,113 @@
+use chrono::Utc;
+use domain::invoice::Invoice;
+use repository::{
+    schema::{InvoiceRow, InvoiceRowStatus, InvoiceRowType, NumberRowType},
+    InvoiceRepository, RepositoryError, StorageConnection,
+};
+
+use crate::{
+    invoice::check_invoice_exists_option, number::next_number,
+    patient::validate::check_patient_exists, service_provider::ServiceContext,
+    validate::check_store_exists,
+};
+
+use super::validate::{check_prescriber_exists, get_prescription};
+
+pub struct InsertPrescription {
+    pub id: String,
+    pub patient_id: String,
+    pub prescriber_id: Option<String>,
+    pub their_reference: Option<String>,
+    pub comment: Option<String>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum InsertPrescriptionError {
+    DatabaseError(RepositoryError),
+    InternalError(String),
+    InvoiceAlreadyExists,
+    InvalidStore,
+    PatientDoesNotExist,
+    PrescriberDoesNotExist,
+}
+
+/// Patients can be dispensed to from any store, not only their home store
+fn validate(
+    connection: &StorageConnection,
+    store_id: &str,
+    input: &InsertPrescription,
+) -> Result<(), InsertPrescriptionError> {
+    if check_invoice_exists_option(&input.id, connection)?.is_some() {
+        return Err(InsertPrescriptionError::InvoiceAlreadyExists);
+    }
+    if !check_store_exists(connection, store_id)? {
+        return Err(InsertPrescriptionError::InvalidStore);
+    }
+    if check_patient_exists(connection, &input.patient_id)?.is_none() {
+        return Err(InsertPrescriptionError::PatientDoesNotExist);
+    }
+    if !check_prescriber_exists(connection, &input.prescriber_id)? {
+        return Err(InsertPrescriptionError::PrescriberDoesNotExist);
+    }
+    Ok(())
+}
+
+fn generate(
+    connection: &StorageConnection,
+    store_id: &str,
+    InsertPrescription {
+        id,
+        patient_id,
+        prescriber_id,
+        their_reference,
+        comment,
+    }: InsertPrescription,
+) -> Result<InvoiceRow, RepositoryError> {
+    Ok(InvoiceRow {
+        id,
+        name_id: patient_id,
+        store_id: store_id.to_string(),
+        invoice_number: next_number(connection, &NumberRowType::Prescription, store_id)?,
+        r#type: InvoiceRowType::Prescription,
+        status: InvoiceRowStatus::New,
+        on_hold: false,
+        comment,
+        their_reference,
+        created_datetime: Utc::now().naive_utc(),
+        allocated_datetime: None,
+        picked_datetime: None,
+        shipped_datetime: None,
+        delivered_datetime: None,
+        verified_datetime: None,
+        color: None,
+        prescriber_id,
+    })
+}
+
+pub fn insert_prescription(
+    ctx: &ServiceContext,
+    store_id: &str,
+    input: InsertPrescription,
+) -> Result<Invoice, InsertPrescriptionError> {
+    let result = ctx
+        .connection
+        .transaction_sync(|connection| {
+            validate(connection, store_id, &input)?;
+            let new_prescription = generate(connection, store_id, input)?;
+            InvoiceRepository::new(connection).upsert_one(&new_prescription)?;
+
+            get_prescription(connection, &new_prescription.id)?.ok_or(
+                InsertPrescriptionError::InternalError(
+                    "Failed to read the just inserted prescription!".to_string(),
+                ),
+            )
+        })
+        .map_err(|error| error.to_inner_error())?;
+    Ok(result)
+}
+
+impl From<RepositoryError> for InsertPrescriptionError {
+    fn from(error: RepositoryError) -> Self {
+        InsertPrescriptionError::DatabaseError(error)
+    }
+}
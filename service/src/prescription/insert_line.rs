@@ -0,0 +1,203 @@
+use domain::invoice_line::InvoiceLine;
+use repository::{
+    schema::{InvoiceLineRow, InvoiceLineRowType, InvoiceRow, StockLineRow},
+    InvoiceLineRowRepository, ItemRepository, RepositoryError, StockLineRowRepository,
+    StorageConnection,
+};
+
+use crate::{
+    invoice_line::{check_unique_stock_line, StockLineAlreadyExistsInInvoice},
+    plugin::PluginRejection,
+    preference::get_store_preferences,
+    returns::validate::check_stock_line_exists,
+    service_provider::ServiceContext,
+    WithDBError,
+};
+
+use super::validate::{
+    check_prescription_lines_editable, check_stock_line_dispensable, get_prescription_line,
+    PrescriptionCheckError, StockLineCheckError,
+};
+
+pub struct InsertPrescriptionLine {
+    pub id: String,
+    pub invoice_id: String,
+    /// The stock line the packs are dispensed from
+    pub stock_line_id: String,
+    pub number_of_packs: u32,
+    /// Dosage directions for the patient, printed on the label
+    pub directions: Option<String>,
+    pub note: Option<String>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum InsertPrescriptionLineError {
+    DatabaseError(RepositoryError),
+    InternalError(String),
+    LineAlreadyExists,
+    InvoiceDoesNotExist,
+    NotAPrescription,
+    NotThisStoreInvoice,
+    CannotEditPrescription,
+    StockLineDoesNotExist,
+    /// Holds the id of the line that already dispenses from the stock line
+    StockLineAlreadyExistsInInvoice(String),
+    NumberOfPacksBelowOne,
+    BatchIsOnHold,
+    LocationIsOnHold,
+    LocationNotFound,
+    /// Not enough available packs in the stock line
+    ReductionBelowZero,
+    RejectedByPlugin(PluginRejection),
+}
+
+fn validate(
+    connection: &StorageConnection,
+    store_id: &str,
+    input: &InsertPrescriptionLine,
+) -> Result<(InvoiceRow, StockLineRow), InsertPrescriptionLineError> {
+    match InvoiceLineRowRepository::new(connection).find_one_by_id(&input.id) {
+        Ok(_) => return Err(InsertPrescriptionLineError::LineAlreadyExists),
+        Err(RepositoryError::NotFound) => {}
+        Err(error) => return Err(error.into()),
+    };
+    let invoice = check_prescription_lines_editable(connection, store_id, &input.invoice_id)?;
+    if input.number_of_packs < 1 {
+        return Err(InsertPrescriptionLineError::NumberOfPacksBelowOne);
+    }
+    let stock_line = check_stock_line_exists(connection, store_id, &input.stock_line_id)?
+        .ok_or(InsertPrescriptionLineError::StockLineDoesNotExist)?;
+    check_unique_stock_line(
+        &input.id,
+        &invoice.id,
+        Some(input.stock_line_id.clone()),
+        connection,
+    )?;
+    check_stock_line_dispensable(connection, &stock_line)?;
+    if !get_store_preferences(connection, store_id)?.allow_negative_stock
+        && stock_line.available_number_of_packs < input.number_of_packs as i32
+    {
+        return Err(InsertPrescriptionLineError::ReductionBelowZero);
+    }
+    Ok((invoice, stock_line))
+}
+
+/// Prescription lines reserve the dispensed packs
+fn generate(
+    connection: &StorageConnection,
+    stock_line: StockLineRow,
+    InsertPrescriptionLine {
+        id,
+        invoice_id,
+        stock_line_id,
+        number_of_packs,
+        directions,
+        note,
+    }: InsertPrescriptionLine,
+) -> Result<(InvoiceLineRow, StockLineRow), InsertPrescriptionLineError> {
+    let item = ItemRepository::new(connection)
+        .find_one_by_id(&stock_line.item_id)?
+        .ok_or_else(|| {
+            InsertPrescriptionLineError::InternalError(format!(
+                "Can't find item {} of stock line {}!",
+                stock_line.item_id, stock_line.id
+            ))
+        })?;
+    let number_of_packs = number_of_packs as i32;
+    let total = stock_line.sell_price_per_pack * number_of_packs as f64;
+    let new_line = InvoiceLineRow {
+        id,
+        invoice_id,
+        item_id: item.id,
+        item_name: item.name,
+        item_code: item.code,
+        stock_line_id: Some(stock_line_id),
+        location_id: stock_line.location_id.clone(),
+        batch: stock_line.batch.clone(),
+        expiry_date: stock_line.expiry_date,
+        pack_size: stock_line.pack_size,
+        cost_price_per_pack: stock_line.cost_price_per_pack,
+        sell_price_per_pack: stock_line.sell_price_per_pack,
+        total_before_tax: total,
+        total_after_tax: total,
+        tax: None,
+        r#type: InvoiceLineRowType::StockOut,
+        number_of_packs,
+        note,
+        return_reason_id: None,
+        directions,
+    };
+    let stock_line = StockLineRow {
+        available_number_of_packs: stock_line.available_number_of_packs - number_of_packs,
+        ..stock_line
+    };
+    Ok((new_line, stock_line))
+}
+
+pub fn insert_prescription_line(
+    ctx: &ServiceContext,
+    store_id: &str,
+    input: InsertPrescriptionLine,
+) -> Result<InvoiceLine, InsertPrescriptionLineError> {
+    let result = ctx
+        .connection
+        .transaction_sync(|connection| {
+            let (invoice, stock_line) = validate(connection, store_id, &input)?;
+            let (new_line, stock_line) = generate(connection, stock_line, input)?;
+            ctx.plugins
+                .validate_invoice_line(connection, &invoice, &new_line)
+                .map_err(InsertPrescriptionLineError::RejectedByPlugin)?;
+            InvoiceLineRowRepository::new(connection).upsert_one(&new_line)?;
+            StockLineRowRepository::new(connection).upsert_one(&stock_line)?;
+
+            get_prescription_line(connection, &new_line.id)?.ok_or(
+                InsertPrescriptionLineError::InternalError(
+                    "Failed to read the just inserted prescription line!".to_string(),
+                ),
+            )
+        })
+        .map_err(|error| error.to_inner_error())?;
+    Ok(result)
+}
+
+impl From<RepositoryError> for InsertPrescriptionLineError {
+    fn from(error: RepositoryError) -> Self {
+        InsertPrescriptionLineError::DatabaseError(error)
+    }
+}
+
+impl From<PrescriptionCheckError> for InsertPrescriptionLineError {
+    fn from(error: PrescriptionCheckError) -> Self {
+        use InsertPrescriptionLineError::*;
+        match error {
+            PrescriptionCheckError::DatabaseError(error) => DatabaseError(error),
+            PrescriptionCheckError::InvoiceDoesNotExist => InvoiceDoesNotExist,
+            PrescriptionCheckError::NotAPrescription => NotAPrescription,
+            PrescriptionCheckError::NotThisStoreInvoice => NotThisStoreInvoice,
+            PrescriptionCheckError::CannotEditPrescription => CannotEditPrescription,
+        }
+    }
+}
+
+impl From<StockLineCheckError> for InsertPrescriptionLineError {
+    fn from(error: StockLineCheckError) -> Self {
+        use InsertPrescriptionLineError::*;
+        match error {
+            StockLineCheckError::DatabaseError(error) => DatabaseError(error),
+            StockLineCheckError::BatchIsOnHold => BatchIsOnHold,
+            StockLineCheckError::LocationIsOnHold => LocationIsOnHold,
+            StockLineCheckError::LocationNotFound => LocationNotFound,
+        }
+    }
+}
+
+impl From<WithDBError<StockLineAlreadyExistsInInvoice>> for InsertPrescriptionLineError {
+    fn from(error: WithDBError<StockLineAlreadyExistsInInvoice>) -> Self {
+        match error {
+            WithDBError::DatabaseError(error) => InsertPrescriptionLineError::DatabaseError(error),
+            WithDBError::Error(StockLineAlreadyExistsInInvoice(line_id)) => {
+                InsertPrescriptionLineError::StockLineAlreadyExistsInInvoice(line_id)
+            }
+        }
+    }
+}
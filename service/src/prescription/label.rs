@@ -0,0 +1,110 @@
+use chrono::{NaiveDate, NaiveDateTime};
+use repository::{
+    InvoiceLineRowRepository, InvoiceRepository, NameRepository, PrescriberRowRepository,
+    RepositoryError, StoreRowRepository,
+};
+
+use crate::{
+    patient::validate::check_patient_exists, service_provider::ServiceContext,
+    validate::check_store_id_matches,
+};
+
+use super::validate::check_is_prescription;
+
+/// Data printed on the label of a dispensed item
+#[derive(Debug, PartialEq)]
+pub struct PrescriptionLabel {
+    pub invoice_line_id: String,
+    pub store_name: String,
+    pub patient_name: String,
+    pub patient_date_of_birth: Option<NaiveDate>,
+    /// Full name of the prescriber, e.g. "Dr Smith, John"
+    pub prescriber_name: Option<String>,
+    pub prescription_number: i64,
+    /// Picked datetime of the prescription, created datetime if not picked yet
+    pub dispensed_datetime: NaiveDateTime,
+    pub item_name: String,
+    pub item_code: String,
+    /// Number of packs times the pack size
+    pub number_of_units: i64,
+    pub directions: Option<String>,
+    pub batch: Option<String>,
+    pub expiry_date: Option<NaiveDate>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum PrescriptionLabelError {
+    DatabaseError(RepositoryError),
+    InvoiceDoesNotExist,
+    NotAPrescription,
+    NotThisStoreInvoice,
+}
+
+pub fn get_prescription_labels(
+    ctx: &ServiceContext,
+    store_id: &str,
+    id: &str,
+) -> Result<Vec<PrescriptionLabel>, PrescriptionLabelError> {
+    let connection = &ctx.connection;
+    let invoice = match InvoiceRepository::new(connection).find_one_by_id(id) {
+        Ok(invoice) => invoice,
+        Err(RepositoryError::NotFound) => return Err(PrescriptionLabelError::InvoiceDoesNotExist),
+        Err(error) => return Err(error.into()),
+    };
+    if !check_is_prescription(&invoice) {
+        return Err(PrescriptionLabelError::NotAPrescription);
+    }
+    if !check_store_id_matches(store_id, &invoice.store_id) {
+        return Err(PrescriptionLabelError::NotThisStoreInvoice);
+    }
+
+    let store_name = match StoreRowRepository::new(connection).find_one_by_id(store_id)? {
+        Some(store) => NameRepository::new(connection)
+            .find_many_by_id(&[store.name_id])?
+            .pop()
+            .map(|name| name.name),
+        None => None,
+    }
+    .unwrap_or_default();
+    let patient = check_patient_exists(connection, &invoice.name_id)?;
+    let prescriber_name = match &invoice.prescriber_id {
+        Some(prescriber_id) => PrescriberRowRepository::new(connection)
+            .find_one_by_id(prescriber_id)?
+            .map(|prescriber| match prescriber.first_name {
+                Some(first_name) => format!("{}, {}", prescriber.last_name, first_name),
+                None => prescriber.last_name,
+            }),
+        None => None,
+    };
+    let dispensed_datetime = invoice.picked_datetime.unwrap_or(invoice.created_datetime);
+
+    let mut lines = InvoiceLineRowRepository::new(connection).find_many_by_invoice_id(id)?;
+    lines.sort_by(|a, b| a.item_name.cmp(&b.item_name));
+    Ok(lines
+        .into_iter()
+        .map(|line| PrescriptionLabel {
+            invoice_line_id: line.id,
+            store_name: store_name.clone(),
+            patient_name: patient
+                .as_ref()
+                .map(|patient| patient.name.clone())
+                .unwrap_or_default(),
+            patient_date_of_birth: patient.as_ref().and_then(|patient| patient.date_of_birth),
+            prescriber_name: prescriber_name.clone(),
+            prescription_number: invoice.invoice_number,
+            dispensed_datetime,
+            item_name: line.item_name,
+            item_code: line.item_code,
+            number_of_units: line.number_of_packs as i64 * line.pack_size as i64,
+            directions: line.directions,
+            batch: line.batch,
+            expiry_date: line.expiry_date,
+        })
+        .collect())
+}
+
+impl From<RepositoryError> for PrescriptionLabelError {
+    fn from(error: RepositoryError) -> Self {
+        PrescriptionLabelError::DatabaseError(error)
+    }
+}
@@ -0,0 +1,109 @@
+use domain::{invoice::Invoice, invoice_line::InvoiceLine};
+use repository::RepositoryError;
+
+use crate::service_provider::ServiceContext;
+
+use self::{
+    delete::{delete_prescription, DeletePrescriptionError},
+    delete_line::{delete_prescription_line, DeletePrescriptionLineError},
+    history::get_patient_dispensing_history,
+    insert::{insert_prescription, InsertPrescription, InsertPrescriptionError},
+    insert_line::{insert_prescription_line, InsertPrescriptionLine, InsertPrescriptionLineError},
+    label::{get_prescription_labels, PrescriptionLabel, PrescriptionLabelError},
+    update::{update_prescription, UpdatePrescription, UpdatePrescriptionError},
+    update_line::{update_prescription_line, UpdatePrescriptionLine, UpdatePrescriptionLineError},
+};
+
+pub mod delete;
+pub mod delete_line;
+pub mod history;
+pub mod insert;
+pub mod insert_line;
+pub mod label;
+pub mod update;
+pub mod update_line;
+pub mod validate;
+
+#[cfg(test)]
+mod tests;
+
+/// Prescriptions dispense stock to a patient. Like outbound shipments their lines reserve the
+/// available packs of the stock lines, the stock leaves the store when the prescription is
+/// picked. The invoice number of a prescription is its prescription number.
+pub trait PrescriptionServiceTrait: Sync + Send {
+    fn insert_prescription(
+        &self,
+        ctx: &ServiceContext,
+        store_id: &str,
+        input: InsertPrescription,
+    ) -> Result<Invoice, InsertPrescriptionError> {
+        insert_prescription(ctx, store_id, input)
+    }
+
+    fn update_prescription(
+        &self,
+        ctx: &ServiceContext,
+        store_id: &str,
+        input: UpdatePrescription,
+    ) -> Result<Invoice, UpdatePrescriptionError> {
+        update_prescription(ctx, store_id, input)
+    }
+
+    fn delete_prescription(
+        &self,
+        ctx: &ServiceContext,
+        store_id: &str,
+        id: &str,
+    ) -> Result<String, DeletePrescriptionError> {
+        delete_prescription(ctx, store_id, id)
+    }
+
+    fn insert_prescription_line(
+        &self,
+        ctx: &ServiceContext,
+        store_id: &str,
+        input: InsertPrescriptionLine,
+    ) -> Result<InvoiceLine, InsertPrescriptionLineError> {
+        insert_prescription_line(ctx, store_id, input)
+    }
+
+    fn update_prescription_line(
+        &self,
+        ctx: &ServiceContext,
+        store_id: &str,
+        input: UpdatePrescriptionLine,
+    ) -> Result<InvoiceLine, UpdatePrescriptionLineError> {
+        update_prescription_line(ctx, store_id, input)
+    }
+
+    fn delete_prescription_line(
+        &self,
+        ctx: &ServiceContext,
+        store_id: &str,
+        id: &str,
+    ) -> Result<String, DeletePrescriptionLineError> {
+        delete_prescription_line(ctx, store_id, id)
+    }
+
+    /// Picked and verified prescriptions of the patient from all stores, latest first
+    fn get_patient_dispensing_history(
+        &self,
+        ctx: &ServiceContext,
+        patient_id: &str,
+    ) -> Result<Vec<Invoice>, RepositoryError> {
+        get_patient_dispensing_history(ctx, patient_id)
+    }
+
+    /// Label data of each line of the prescription
+    fn get_prescription_labels(
+        &self,
+        ctx: &ServiceContext,
+        store_id: &str,
+        id: &str,
+    ) -> Result<Vec<PrescriptionLabel>, PrescriptionLabelError> {
+        get_prescription_labels(ctx, store_id, id)
+    }
+}
+
+pub struct PrescriptionService {}
+impl PrescriptionServiceTrait for PrescriptionService {}
@@ -0,0 +1,414 @@
+#[cfg(test)]
+mod prescription_test {
+    use domain::{
+        invoice::{InvoiceStatus, InvoiceType},
+        invoice_line::InvoiceLineType,
+    };
+    use repository::{
+        mock::{
+            mock_item_a, mock_name_a, mock_patient_a, mock_stock_line_a, mock_store_a,
+            mock_store_b, MockDataInserts,
+        },
+        schema::{PrescriberRow, StockLineRow},
+        test_db::setup_all,
+        PrescriberRowRepository, StockLineRowRepository, StorageConnection,
+    };
+
+    use crate::{
+        prescription::{
+            delete::DeletePrescriptionError,
+            delete_line::DeletePrescriptionLineError,
+            insert::{InsertPrescription, InsertPrescriptionError},
+            insert_line::{InsertPrescriptionLine, InsertPrescriptionLineError},
+            label::PrescriptionLabelError,
+            update::{PrescriptionStatus, UpdatePrescription, UpdatePrescriptionError},
+            update_line::{UpdatePrescriptionLine, UpdatePrescriptionLineError},
+        },
+        service_provider::ServiceProvider,
+    };
+
+    fn insert_input(id: &str, patient_id: &str) -> InsertPrescription {
+        InsertPrescription {
+            id: id.to_string(),
+            patient_id: patient_id.to_string(),
+            prescriber_id: Some("prescriber_a".to_string()),
+            their_reference: None,
+            comment: None,
+        }
+    }
+
+    fn line_input(id: &str, invoice_id: &str, number_of_packs: u32) -> InsertPrescriptionLine {
+        InsertPrescriptionLine {
+            id: id.to_string(),
+            invoice_id: invoice_id.to_string(),
+            stock_line_id: mock_stock_line_a().id,
+            number_of_packs,
+            directions: Some("Take 1 tablet twice daily".to_string()),
+            note: None,
+        }
+    }
+
+    fn update_input(id: &str, status: Option<PrescriptionStatus>) -> UpdatePrescription {
+        UpdatePrescription {
+            id: id.to_string(),
+            status,
+            prescriber_id: None,
+            comment: None,
+            their_reference: None,
+        }
+    }
+
+    fn insert_prescriber(connection: &StorageConnection) {
+        PrescriberRowRepository::new(connection)
+            .upsert_one(&PrescriberRow {
+                id: "prescriber_a".to_string(),
+                code: "DR1".to_string(),
+                first_name: Some("Anna".to_string()),
+                last_name: "Young".to_string(),
+                registration_code: None,
+                home_store_id: Some(mock_store_b().id),
+            })
+            .unwrap();
+    }
+
+    fn stock_line_a(connection: &StorageConnection) -> StockLineRow {
+        StockLineRowRepository::new(connection)
+            .find_one_by_id(&mock_stock_line_a().id)
+            .unwrap()
+    }
+
+    #[actix_rt::test]
+    async fn prescription() {
+        let (_, connection, connection_manager, _) =
+            setup_all("prescription", MockDataInserts::all()).await;
+        insert_prescriber(&connection);
+        let service_provider = ServiceProvider::new(connection_manager);
+        let context = service_provider.context().unwrap();
+        let service = &service_provider.prescription_service;
+        let store_id = &mock_store_a().id;
+
+        assert_eq!(
+            service.insert_prescription(
+                &context,
+                store_id,
+                insert_input("prescription", "invalid")
+            ),
+            Err(InsertPrescriptionError::PatientDoesNotExist)
+        );
+        // only patients can be dispensed to
+        assert_eq!(
+            service.insert_prescription(
+                &context,
+                store_id,
+                insert_input("prescription", &mock_name_a().id)
+            ),
+            Err(InsertPrescriptionError::PatientDoesNotExist)
+        );
+        assert_eq!(
+            service.insert_prescription(
+                &context,
+                store_id,
+                InsertPrescription {
+                    prescriber_id: Some("invalid".to_string()),
+                    ..insert_input("prescription", &mock_patient_a().id)
+                }
+            ),
+            Err(InsertPrescriptionError::PrescriberDoesNotExist)
+        );
+
+        let prescription = service
+            .insert_prescription(
+                &context,
+                store_id,
+                insert_input("prescription", &mock_patient_a().id),
+            )
+            .unwrap();
+        assert_eq!(prescription.r#type, InvoiceType::Prescription);
+        assert_eq!(prescription.status, InvoiceStatus::New);
+        assert_eq!(prescription.invoice_number, 1);
+        assert_eq!(prescription.prescriber_id, Some("prescriber_a".to_string()));
+        assert_eq!(
+            service.insert_prescription(
+                &context,
+                store_id,
+                insert_input("prescription", &mock_patient_a().id)
+            ),
+            Err(InsertPrescriptionError::InvoiceAlreadyExists)
+        );
+
+        // line errors
+        let before = stock_line_a(&connection);
+        assert_eq!(
+            service.insert_prescription_line(
+                &context,
+                store_id,
+                InsertPrescriptionLine {
+                    stock_line_id: "invalid".to_string(),
+                    ..line_input("line", "prescription", 1)
+                }
+            ),
+            Err(InsertPrescriptionLineError::StockLineDoesNotExist)
+        );
+        assert_eq!(
+            service.insert_prescription_line(
+                &context,
+                &mock_store_b().id,
+                line_input("line", "prescription", 1)
+            ),
+            Err(InsertPrescriptionLineError::NotThisStoreInvoice)
+        );
+        assert_eq!(
+            service.insert_prescription_line(
+                &context,
+                store_id,
+                line_input("line", "prescription", 0)
+            ),
+            Err(InsertPrescriptionLineError::NumberOfPacksBelowOne)
+        );
+        assert_eq!(
+            service.insert_prescription_line(
+                &context,
+                store_id,
+                line_input(
+                    "line",
+                    "prescription",
+                    before.available_number_of_packs as u32 + 1
+                )
+            ),
+            Err(InsertPrescriptionLineError::ReductionBelowZero)
+        );
+
+        // the dispensed packs are reserved
+        let line = service
+            .insert_prescription_line(&context, store_id, line_input("line", "prescription", 10))
+            .unwrap();
+        assert_eq!(line.r#type, InvoiceLineType::StockOut);
+        assert_eq!(line.item_id, mock_item_a().id);
+        assert_eq!(
+            line.directions,
+            Some("Take 1 tablet twice daily".to_string())
+        );
+        let stock_line = stock_line_a(&connection);
+        assert_eq!(
+            stock_line.available_number_of_packs,
+            before.available_number_of_packs - 10
+        );
+        assert_eq!(
+            stock_line.total_number_of_packs,
+            before.total_number_of_packs
+        );
+        assert_eq!(
+            service.insert_prescription_line(
+                &context,
+                store_id,
+                line_input("line2", "prescription", 1)
+            ),
+            Err(InsertPrescriptionLineError::StockLineAlreadyExistsInInvoice("line".to_string()))
+        );
+
+        let line = service
+            .update_prescription_line(
+                &context,
+                store_id,
+                UpdatePrescriptionLine {
+                    id: "line".to_string(),
+                    number_of_packs: Some(4),
+                    directions: Some("Take 2 tablets daily".to_string()),
+                    note: None,
+                },
+            )
+            .unwrap();
+        assert_eq!(line.directions, Some("Take 2 tablets daily".to_string()));
+        assert_eq!(
+            stock_line_a(&connection).available_number_of_packs,
+            before.available_number_of_packs - 4
+        );
+
+        // the invoice has lines
+        assert_eq!(
+            service.delete_prescription(&context, store_id, "prescription"),
+            Err(DeletePrescriptionError::InvoiceLinesExist)
+        );
+
+        // not dispensed yet
+        assert_eq!(
+            service
+                .get_patient_dispensing_history(&context, &mock_patient_a().id)
+                .unwrap(),
+            vec![]
+        );
+
+        // picking dispenses the stock
+        let prescription = service
+            .update_prescription(
+                &context,
+                store_id,
+                update_input("prescription", Some(PrescriptionStatus::Picked)),
+            )
+            .unwrap();
+        assert_eq!(prescription.status, InvoiceStatus::Picked);
+        assert!(prescription.picked_datetime.is_some());
+        let stock_line = stock_line_a(&connection);
+        assert_eq!(
+            stock_line.available_number_of_packs,
+            before.available_number_of_packs - 4
+        );
+        assert_eq!(
+            stock_line.total_number_of_packs,
+            before.total_number_of_packs - 4
+        );
+
+        // lines are fixed once picked
+        assert_eq!(
+            service.update_prescription_line(
+                &context,
+                store_id,
+                UpdatePrescriptionLine {
+                    id: "line".to_string(),
+                    number_of_packs: Some(1),
+                    directions: None,
+                    note: None,
+                },
+            ),
+            Err(UpdatePrescriptionLineError::CannotEditPrescription)
+        );
+        assert_eq!(
+            service.delete_prescription_line(&context, store_id, "line"),
+            Err(DeletePrescriptionLineError::CannotEditPrescription)
+        );
+
+        // verifying doesn't change the stock again
+        service
+            .update_prescription(
+                &context,
+                store_id,
+                update_input("prescription", Some(PrescriptionStatus::Verified)),
+            )
+            .unwrap();
+        assert_eq!(stock_line_a(&connection), stock_line);
+        assert_eq!(
+            service.update_prescription(
+                &context,
+                store_id,
+                UpdatePrescription {
+                    comment: Some("comment".to_string()),
+                    ..update_input("prescription", None)
+                },
+            ),
+            Err(UpdatePrescriptionError::CannotEditPrescription)
+        );
+
+        let history = service
+            .get_patient_dispensing_history(&context, &mock_patient_a().id)
+            .unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].id, "prescription");
+    }
+
+    #[actix_rt::test]
+    async fn prescription_delete() {
+        let (_, connection, connection_manager, _) =
+            setup_all("prescription_delete", MockDataInserts::all()).await;
+        insert_prescriber(&connection);
+        let service_provider = ServiceProvider::new(connection_manager);
+        let context = service_provider.context().unwrap();
+        let service = &service_provider.prescription_service;
+        let store_id = &mock_store_a().id;
+
+        let before = stock_line_a(&connection);
+        service
+            .insert_prescription(
+                &context,
+                store_id,
+                insert_input("prescription", &mock_patient_a().id),
+            )
+            .unwrap();
+        service
+            .insert_prescription_line(&context, store_id, line_input("line", "prescription", 5))
+            .unwrap();
+
+        // deleting the line releases the reserved packs
+        assert_eq!(
+            service.delete_prescription_line(&context, &mock_store_b().id, "line"),
+            Err(DeletePrescriptionLineError::NotThisStoreInvoice)
+        );
+        assert_eq!(
+            service.delete_prescription_line(&context, store_id, "line"),
+            Ok("line".to_string())
+        );
+        assert_eq!(stock_line_a(&connection), before);
+
+        assert_eq!(
+            service.update_prescription(
+                &context,
+                store_id,
+                UpdatePrescription {
+                    prescriber_id: Some("invalid".to_string()),
+                    ..update_input("prescription", None)
+                },
+            ),
+            Err(UpdatePrescriptionError::PrescriberDoesNotExist)
+        );
+        assert_eq!(
+            service.delete_prescription(&context, store_id, "prescription"),
+            Ok("prescription".to_string())
+        );
+        assert_eq!(
+            service.delete_prescription(&context, store_id, "prescription"),
+            Err(DeletePrescriptionError::InvoiceDoesNotExist)
+        );
+    }
+
+    #[actix_rt::test]
+    async fn prescription_labels() {
+        let (_, connection, connection_manager, _) =
+            setup_all("prescription_labels", MockDataInserts::all()).await;
+        insert_prescriber(&connection);
+        let service_provider = ServiceProvider::new(connection_manager);
+        let context = service_provider.context().unwrap();
+        let service = &service_provider.prescription_service;
+        let store_id = &mock_store_a().id;
+
+        let prescription = service
+            .insert_prescription(
+                &context,
+                store_id,
+                insert_input("prescription", &mock_patient_a().id),
+            )
+            .unwrap();
+        service
+            .insert_prescription_line(&context, store_id, line_input("line", "prescription", 3))
+            .unwrap();
+
+        assert_eq!(
+            service.get_prescription_labels(&context, store_id, "invalid"),
+            Err(PrescriptionLabelError::InvoiceDoesNotExist)
+        );
+        assert_eq!(
+            service.get_prescription_labels(&context, &mock_store_b().id, "prescription"),
+            Err(PrescriptionLabelError::NotThisStoreInvoice)
+        );
+
+        let labels = service
+            .get_prescription_labels(&context, store_id, "prescription")
+            .unwrap();
+        assert_eq!(labels.len(), 1);
+        let label = &labels[0];
+        assert_eq!(label.invoice_line_id, "line");
+        assert_eq!(label.store_name, "Store A");
+        assert_eq!(label.patient_name, mock_patient_a().name);
+        assert_eq!(label.patient_date_of_birth, mock_patient_a().date_of_birth);
+        assert_eq!(label.prescriber_name, Some("Young, Anna".to_string()));
+        assert_eq!(label.prescription_number, prescription.invoice_number);
+        assert_eq!(label.dispensed_datetime, prescription.created_datetime);
+        assert_eq!(label.item_name, mock_item_a().name);
+        assert_eq!(
+            label.number_of_units,
+            3 * mock_stock_line_a().pack_size as i64
+        );
+        assert_eq!(
+            label.directions,
+            Some("Take 1 tablet twice daily".to_string())
+        );
+    }
+}
@@ -0,0 +1,196 @@
+use std::collections::HashMap;
+
+use chrono::{NaiveDateTime, Utc};
+use domain::invoice::{Invoice, InvoiceStatus};
+use repository::{
+    schema::{InvoiceRow, InvoiceRowStatus, StockLineRow},
+    InvoiceLineRowRepository, InvoiceRepository, RepositoryError, StockLineRowRepository,
+    StorageConnection,
+};
+
+use crate::{
+    invoice::{
+        check_invoice_exists_option, check_invoice_is_editable, check_invoice_status,
+        InvoiceStatusError,
+    },
+    plugin::PluginRejection,
+    service_provider::ServiceContext,
+    validate::check_store_id_matches,
+};
+
+use super::validate::{check_is_prescription, check_prescriber_exists, get_prescription};
+
+pub enum PrescriptionStatus {
+    /// Stock is dispensed
+    Picked,
+    Verified,
+}
+
+pub struct UpdatePrescription {
+    pub id: String,
+    pub status: Option<PrescriptionStatus>,
+    pub prescriber_id: Option<String>,
+    pub comment: Option<String>,
+    pub their_reference: Option<String>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum UpdatePrescriptionError {
+    DatabaseError(RepositoryError),
+    InternalError(String),
+    InvoiceDoesNotExist,
+    NotAPrescription,
+    NotThisStoreInvoice,
+    CannotEditPrescription,
+    CannotReverseInvoiceStatus,
+    PrescriberDoesNotExist,
+    RejectedByPlugin(PluginRejection),
+}
+
+fn validate(
+    connection: &StorageConnection,
+    store_id: &str,
+    input: &UpdatePrescription,
+) -> Result<InvoiceRow, UpdatePrescriptionError> {
+    let existing = check_invoice_exists_option(&input.id, connection)?
+        .ok_or(UpdatePrescriptionError::InvoiceDoesNotExist)?;
+    if !check_is_prescription(&existing) {
+        return Err(UpdatePrescriptionError::NotAPrescription);
+    }
+    if !check_store_id_matches(store_id, &existing.store_id) {
+        return Err(UpdatePrescriptionError::NotThisStoreInvoice);
+    }
+    if check_invoice_is_editable(&existing).is_err() {
+        return Err(UpdatePrescriptionError::CannotEditPrescription);
+    }
+    let status = input.status.as_ref().map(|status| match status {
+        PrescriptionStatus::Picked => InvoiceStatus::Picked,
+        PrescriptionStatus::Verified => InvoiceStatus::Verified,
+    });
+    check_invoice_status(&existing, status, &None).map_err(|error| match error {
+        InvoiceStatusError::CannotReverseInvoiceStatus => {
+            UpdatePrescriptionError::CannotReverseInvoiceStatus
+        }
+        // prescriptions can't be put on hold
+        InvoiceStatusError::CannotChangeStatusOfInvoiceOnHold => {
+            UpdatePrescriptionError::CannotEditPrescription
+        }
+    })?;
+    if !check_prescriber_exists(connection, &input.prescriber_id)? {
+        return Err(UpdatePrescriptionError::PrescriberDoesNotExist);
+    }
+    Ok(existing)
+}
+
+fn generate_invoice(existing: InvoiceRow, input: UpdatePrescription) -> InvoiceRow {
+    let now = Utc::now().naive_utc();
+    let status = match input.status {
+        Some(PrescriptionStatus::Picked) => InvoiceRowStatus::Picked,
+        Some(PrescriptionStatus::Verified) => InvoiceRowStatus::Verified,
+        None => existing.status.clone(),
+    };
+    let reached = |target: InvoiceRowStatus| {
+        InvoiceStatus::from(status.clone()).index() >= InvoiceStatus::from(target).index()
+    };
+    let set_datetime = |existing: Option<NaiveDateTime>, reached: bool| match existing {
+        None if reached => Some(now),
+        existing => existing,
+    };
+    let picked_datetime = set_datetime(existing.picked_datetime, reached(InvoiceRowStatus::Picked));
+    let verified_datetime = set_datetime(
+        existing.verified_datetime,
+        reached(InvoiceRowStatus::Verified),
+    );
+
+    InvoiceRow {
+        status,
+        prescriber_id: input.prescriber_id.or(existing.prescriber_id),
+        comment: input.comment.or(existing.comment),
+        their_reference: input.their_reference.or(existing.their_reference),
+        picked_datetime,
+        verified_datetime,
+        ..existing
+    }
+}
+
+/// Stock leaves the store when the prescription is picked, the available packs have already been
+/// reserved by the lines
+fn generate_stock_lines(
+    connection: &StorageConnection,
+    invoice_id: &str,
+) -> Result<Vec<StockLineRow>, UpdatePrescriptionError> {
+    let lines = InvoiceLineRowRepository::new(connection).find_many_by_invoice_id(invoice_id)?;
+    let repository = StockLineRowRepository::new(connection);
+    let mut stock_lines: HashMap<String, StockLineRow> = HashMap::new();
+    for line in lines {
+        let stock_line_id = line.stock_line_id.clone().ok_or_else(|| {
+            UpdatePrescriptionError::InternalError(format!(
+                "Prescription line {} has no stock line",
+                line.id
+            ))
+        })?;
+        let stock_line = match stock_lines.remove(&stock_line_id) {
+            Some(stock_line) => stock_line,
+            None => repository.find_one_by_id(&stock_line_id)?,
+        };
+        let stock_line = StockLineRow {
+            total_number_of_packs: stock_line.total_number_of_packs - line.number_of_packs,
+            ..stock_line
+        };
+        stock_lines.insert(stock_line_id, stock_line);
+    }
+    Ok(stock_lines
+        .into_iter()
+        .map(|(_, stock_line)| stock_line)
+        .collect())
+}
+
+pub fn update_prescription(
+    ctx: &ServiceContext,
+    store_id: &str,
+    input: UpdatePrescription,
+) -> Result<Invoice, UpdatePrescriptionError> {
+    let (result, updated, previous_status) = ctx
+        .connection
+        .transaction_sync(|connection| {
+            let existing = validate(connection, store_id, &input)?;
+            let previous_status = existing.status.clone();
+            let updated = generate_invoice(existing.clone(), input);
+            if updated.status != previous_status {
+                ctx.plugins
+                    .before_invoice_status_change(connection, &existing, &updated.status)
+                    .map_err(UpdatePrescriptionError::RejectedByPlugin)?;
+            }
+            if previous_status == InvoiceRowStatus::New && updated.status != InvoiceRowStatus::New {
+                let repository = StockLineRowRepository::new(connection);
+                for stock_line in generate_stock_lines(connection, &updated.id)? {
+                    repository.upsert_one(&stock_line)?;
+                }
+            }
+            InvoiceRepository::new(connection).upsert_one(&updated)?;
+
+            let result = get_prescription(connection, &updated.id)?.ok_or(
+                UpdatePrescriptionError::InternalError(
+                    "Failed to read the just updated prescription!".to_string(),
+                ),
+            )?;
+            Ok::<(Invoice, InvoiceRow, InvoiceRowStatus), UpdatePrescriptionError>((
+                result,
+                updated,
+                previous_status,
+            ))
+        })
+        .map_err(|error| error.to_inner_error())?;
+
+    if updated.status != previous_status {
+        ctx.plugins
+            .after_invoice_status_change(&ctx.connection, &updated, &previous_status);
+    }
+    Ok(result)
+}
+
+impl From<RepositoryError> for UpdatePrescriptionError {
+    fn from(error: RepositoryError) -> Self {
+        UpdatePrescriptionError::DatabaseError(error)
+    }
+}
@@ -0,0 +1,148 @@
+use domain::invoice_line::InvoiceLine;
+use repository::{
+    schema::{InvoiceLineRow, InvoiceRow, StockLineRow},
+    InvoiceLineRowRepository, RepositoryError, StockLineRowRepository, StorageConnection,
+};
+
+use crate::{
+    plugin::PluginRejection, preference::get_store_preferences, service_provider::ServiceContext,
+};
+
+use super::validate::{
+    check_prescription_lines_editable, get_prescription_line, PrescriptionCheckError,
+};
+
+pub struct UpdatePrescriptionLine {
+    pub id: String,
+    pub number_of_packs: Option<u32>,
+    pub directions: Option<String>,
+    pub note: Option<String>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum UpdatePrescriptionLineError {
+    DatabaseError(RepositoryError),
+    InternalError(String),
+    LineDoesNotExist,
+    InvoiceDoesNotExist,
+    NotAPrescription,
+    NotThisStoreInvoice,
+    CannotEditPrescription,
+    NumberOfPacksBelowOne,
+    /// Not enough available packs in the stock line
+    ReductionBelowZero,
+    RejectedByPlugin(PluginRejection),
+}
+
+fn validate(
+    connection: &StorageConnection,
+    store_id: &str,
+    input: &UpdatePrescriptionLine,
+) -> Result<(InvoiceRow, InvoiceLineRow), UpdatePrescriptionLineError> {
+    let line = match InvoiceLineRowRepository::new(connection).find_one_by_id(&input.id) {
+        Ok(line) => line,
+        Err(RepositoryError::NotFound) => {
+            return Err(UpdatePrescriptionLineError::LineDoesNotExist)
+        }
+        Err(error) => return Err(error.into()),
+    };
+    let invoice = check_prescription_lines_editable(connection, store_id, &line.invoice_id)?;
+    if let Some(number_of_packs) = input.number_of_packs {
+        if number_of_packs < 1 {
+            return Err(UpdatePrescriptionLineError::NumberOfPacksBelowOne);
+        }
+    }
+    Ok((invoice, line))
+}
+
+fn generate(
+    connection: &StorageConnection,
+    store_id: &str,
+    existing: InvoiceLineRow,
+    UpdatePrescriptionLine {
+        id: _,
+        number_of_packs,
+        directions,
+        note,
+    }: UpdatePrescriptionLine,
+) -> Result<(InvoiceLineRow, Option<StockLineRow>), UpdatePrescriptionLineError> {
+    let number_of_packs = number_of_packs
+        .map(|number_of_packs| number_of_packs as i32)
+        .unwrap_or(existing.number_of_packs);
+    let delta = number_of_packs - existing.number_of_packs;
+
+    let stock_line = match &existing.stock_line_id {
+        Some(stock_line_id) if delta != 0 => {
+            let stock_line =
+                StockLineRowRepository::new(connection).find_one_by_id(stock_line_id)?;
+            if !get_store_preferences(connection, store_id)?.allow_negative_stock
+                && stock_line.available_number_of_packs < delta
+            {
+                return Err(UpdatePrescriptionLineError::ReductionBelowZero);
+            }
+            Some(StockLineRow {
+                available_number_of_packs: stock_line.available_number_of_packs - delta,
+                ..stock_line
+            })
+        }
+        _ => None,
+    };
+
+    let total = existing.sell_price_per_pack * number_of_packs as f64;
+    let line = InvoiceLineRow {
+        number_of_packs,
+        total_before_tax: total,
+        total_after_tax: total,
+        directions: directions.or(existing.directions),
+        note: note.or(existing.note),
+        ..existing
+    };
+    Ok((line, stock_line))
+}
+
+pub fn update_prescription_line(
+    ctx: &ServiceContext,
+    store_id: &str,
+    input: UpdatePrescriptionLine,
+) -> Result<InvoiceLine, UpdatePrescriptionLineError> {
+    let result = ctx
+        .connection
+        .transaction_sync(|connection| {
+            let (invoice, existing) = validate(connection, store_id, &input)?;
+            let (line, stock_line) = generate(connection, store_id, existing, input)?;
+            ctx.plugins
+                .validate_invoice_line(connection, &invoice, &line)
+                .map_err(UpdatePrescriptionLineError::RejectedByPlugin)?;
+            InvoiceLineRowRepository::new(connection).upsert_one(&line)?;
+            if let Some(stock_line) = stock_line {
+                StockLineRowRepository::new(connection).upsert_one(&stock_line)?;
+            }
+
+            get_prescription_line(connection, &line.id)?.ok_or(
+                UpdatePrescriptionLineError::InternalError(
+                    "Failed to read the just updated prescription line!".to_string(),
+                ),
+            )
+        })
+        .map_err(|error| error.to_inner_error())?;
+    Ok(result)
+}
+
+impl From<RepositoryError> for UpdatePrescriptionLineError {
+    fn from(error: RepositoryError) -> Self {
+        UpdatePrescriptionLineError::DatabaseError(error)
+    }
+}
+
+impl From<PrescriptionCheckError> for UpdatePrescriptionLineError {
+    fn from(error: PrescriptionCheckError) -> Self {
+        use UpdatePrescriptionLineError::*;
+        match error {
+            PrescriptionCheckError::DatabaseError(error) => DatabaseError(error),
+            PrescriptionCheckError::InvoiceDoesNotExist => InvoiceDoesNotExist,
+            PrescriptionCheckError::NotAPrescription => NotAPrescription,
+            PrescriptionCheckError::NotThisStoreInvoice => NotThisStoreInvoice,
+            PrescriptionCheckError::CannotEditPrescription => CannotEditPrescription,
+        }
+    }
+}
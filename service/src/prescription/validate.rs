@@ -0,0 +1,113 @@
+use domain::{
+    invoice::{Invoice, InvoiceFilter},
+    invoice_line::InvoiceLine,
+    EqualFilter,
+};
+use repository::{
+    schema::{InvoiceRow, InvoiceRowStatus, InvoiceRowType, StockLineRow},
+    InvoiceLineFilter, InvoiceLineRepository, InvoiceQueryRepository, InvoiceRepository,
+    PrescriberRowRepository, RepositoryError, StorageConnection,
+};
+
+use crate::{
+    invoice_line::{check_batch_on_hold, check_location_on_hold, LocationIsOnHoldError},
+    validate::check_store_id_matches,
+    WithDBError,
+};
+
+pub fn check_is_prescription(invoice: &InvoiceRow) -> bool {
+    invoice.r#type == InvoiceRowType::Prescription
+}
+
+/// Lines of a prescription can only be changed before the stock is dispensed
+pub fn check_lines_editable(invoice: &InvoiceRow) -> bool {
+    invoice.status == InvoiceRowStatus::New
+}
+
+/// A prescriber is optional but if set it must exist
+pub fn check_prescriber_exists(
+    connection: &StorageConnection,
+    prescriber_id: &Option<String>,
+) -> Result<bool, RepositoryError> {
+    match prescriber_id {
+        Some(prescriber_id) => Ok(PrescriberRowRepository::new(connection)
+            .find_one_by_id(prescriber_id)?
+            .is_some()),
+        None => Ok(true),
+    }
+}
+
+pub fn get_prescription(
+    connection: &StorageConnection,
+    id: &str,
+) -> Result<Option<Invoice>, RepositoryError> {
+    let mut result = InvoiceQueryRepository::new(connection)
+        .query_by_filter(InvoiceFilter::new().id(EqualFilter::equal_to(id)))?;
+    Ok(result.pop())
+}
+
+pub fn get_prescription_line(
+    connection: &StorageConnection,
+    id: &str,
+) -> Result<Option<InvoiceLine>, RepositoryError> {
+    let mut result = InvoiceLineRepository::new(connection)
+        .query_by_filter(InvoiceLineFilter::new().id(EqualFilter::equal_to(id)))?;
+    Ok(result.pop())
+}
+
+pub enum PrescriptionCheckError {
+    DatabaseError(RepositoryError),
+    InvoiceDoesNotExist,
+    NotAPrescription,
+    NotThisStoreInvoice,
+    CannotEditPrescription,
+}
+
+/// Returns the prescription if its lines can be changed from the store
+pub fn check_prescription_lines_editable(
+    connection: &StorageConnection,
+    store_id: &str,
+    invoice_id: &str,
+) -> Result<InvoiceRow, PrescriptionCheckError> {
+    let invoice = match InvoiceRepository::new(connection).find_one_by_id(invoice_id) {
+        Ok(invoice) => invoice,
+        Err(RepositoryError::NotFound) => return Err(PrescriptionCheckError::InvoiceDoesNotExist),
+        Err(error) => return Err(PrescriptionCheckError::DatabaseError(error)),
+    };
+    if !check_is_prescription(&invoice) {
+        return Err(PrescriptionCheckError::NotAPrescription);
+    }
+    if !check_store_id_matches(store_id, &invoice.store_id) {
+        return Err(PrescriptionCheckError::NotThisStoreInvoice);
+    }
+    if !check_lines_editable(&invoice) {
+        return Err(PrescriptionCheckError::CannotEditPrescription);
+    }
+    Ok(invoice)
+}
+
+pub enum StockLineCheckError {
+    DatabaseError(RepositoryError),
+    BatchIsOnHold,
+    LocationIsOnHold,
+    LocationNotFound,
+}
+
+/// Stock can't be dispensed from a stock line or a location that is on hold
+pub fn check_stock_line_dispensable(
+    connection: &StorageConnection,
+    stock_line: &StockLineRow,
+) -> Result<(), StockLineCheckError> {
+    if check_batch_on_hold(stock_line).is_err() {
+        return Err(StockLineCheckError::BatchIsOnHold);
+    }
+    check_location_on_hold(stock_line, connection).map_err(|error| match error {
+        WithDBError::DatabaseError(error) => StockLineCheckError::DatabaseError(error),
+        WithDBError::Error(LocationIsOnHoldError::LocationIsOnHold) => {
+            StockLineCheckError::LocationIsOnHold
+        }
+        WithDBError::Error(LocationIsOnHoldError::LocationNotFound) => {
+            StockLineCheckError::LocationNotFound
+        }
+    })
+}
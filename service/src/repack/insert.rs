@@ -0,0 +1,351 @@
+use chrono::Utc;
+use domain::{
+    invoice::{Invoice, InvoiceFilter},
+    stock_line::{StockLine, StockLineFilter},
+    EqualFilter,
+};
+use repository::{
+    schema::{
+        InvoiceLineRow, InvoiceLineRowType, InvoiceRow, InvoiceRowStatus, InvoiceRowType, ItemRow,
+        NumberRowType, StockLineRow, StoreRow,
+    },
+    InvoiceLineRowRepository, InvoiceQueryRepository, InvoiceRepository, ItemRepository,
+    LocationRowRepository, RepositoryError, StockLineRepository, StockLineRowRepository,
+    StorageConnection, StoreRowRepository,
+};
+use util::uuid::uuid;
+
+use crate::{
+    invoice::check_invoice_exists_option, number::next_number,
+    pack_variant::convert_number_of_packs, service_provider::ServiceContext,
+};
+
+use super::Repack;
+
+pub struct InsertRepack {
+    /// Id of the new repack invoice
+    pub id: String,
+    pub stock_line_id: String,
+    /// Number of packs taken from the stock line
+    pub number_of_packs: u32,
+    pub new_pack_size: u32,
+    /// Location of the repacked stock, defaults to the location of the stock line
+    pub new_location_id: Option<String>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum InsertRepackError {
+    DatabaseError(RepositoryError),
+    InternalError(String),
+    RepackAlreadyExists,
+    InvalidStore,
+    StockLineDoesNotExist,
+    StockLineIsOnHold,
+    NumberOfPacksBelowOne,
+    /// Not enough available packs in the stock line
+    ReductionBelowZero,
+    PackSizeBelowOne,
+    PackSizeUnchanged,
+    /// The units of the taken packs don't fill a whole number of new packs
+    CannotSplitIntoWholePacks,
+    LocationDoesNotExist,
+    LocationIsOnHold,
+}
+
+struct ValidateResult {
+    store: StoreRow,
+    stock_line: StockLineRow,
+    new_number_of_packs: i32,
+}
+
+fn validate(
+    connection: &StorageConnection,
+    store_id: &str,
+    input: &InsertRepack,
+) -> Result<ValidateResult, InsertRepackError> {
+    if check_invoice_exists_option(&input.id, connection)?.is_some() {
+        return Err(InsertRepackError::RepackAlreadyExists);
+    }
+    let store = StoreRowRepository::new(connection)
+        .find_one_by_id(store_id)?
+        .ok_or(InsertRepackError::InvalidStore)?;
+    let stock_line =
+        match StockLineRowRepository::new(connection).find_one_by_id(&input.stock_line_id) {
+            Ok(stock_line) if stock_line.store_id == store_id => stock_line,
+            Ok(_) | Err(RepositoryError::NotFound) => {
+                return Err(InsertRepackError::StockLineDoesNotExist)
+            }
+            Err(error) => return Err(error.into()),
+        };
+    if stock_line.on_hold {
+        return Err(InsertRepackError::StockLineIsOnHold);
+    }
+    if input.number_of_packs < 1 {
+        return Err(InsertRepackError::NumberOfPacksBelowOne);
+    }
+    if stock_line.available_number_of_packs < input.number_of_packs as i32 {
+        return Err(InsertRepackError::ReductionBelowZero);
+    }
+    if input.new_pack_size < 1 {
+        return Err(InsertRepackError::PackSizeBelowOne);
+    }
+    if input.new_pack_size as i32 == stock_line.pack_size {
+        return Err(InsertRepackError::PackSizeUnchanged);
+    }
+    let new_number_of_packs = convert_number_of_packs(
+        input.number_of_packs,
+        stock_line.pack_size,
+        input.new_pack_size as i32,
+    )
+    .ok_or(InsertRepackError::CannotSplitIntoWholePacks)?;
+    if let Some(location_id) = &input.new_location_id {
+        let location = LocationRowRepository::new(connection)
+            .find_one_by_id(location_id)?
+            .filter(|location| location.store_id == store_id)
+            .ok_or(InsertRepackError::LocationDoesNotExist)?;
+        if location.on_hold {
+            return Err(InsertRepackError::LocationIsOnHold);
+        }
+    }
+    Ok(ValidateResult {
+        store,
+        stock_line,
+        new_number_of_packs: new_number_of_packs as i32,
+    })
+}
+
+struct GenerateResult {
+    invoice: InvoiceRow,
+    lines: Vec<InvoiceLineRow>,
+    stock_line_from: StockLineRow,
+    stock_line_to: StockLineRow,
+}
+
+/// Adds the packs to a matching stock line of the batch or creates a new one. Prices per pack are
+/// scaled by the pack size.
+fn generate_stock_line_to(
+    connection: &StorageConnection,
+    stock_line: &StockLineRow,
+    new_pack_size: i32,
+    new_number_of_packs: i32,
+    location_id: Option<String>,
+) -> Result<StockLineRow, RepositoryError> {
+    let ratio = new_pack_size as f64 / stock_line.pack_size as f64;
+    let cost_price_per_pack = stock_line.cost_price_per_pack * ratio;
+    let sell_price_per_pack = stock_line.sell_price_per_pack * ratio;
+
+    let existing = StockLineRowRepository::new(connection)
+        .find_many_by_item_id(&stock_line.store_id, &stock_line.item_id)?
+        .into_iter()
+        .find(|candidate| {
+            candidate.id != stock_line.id
+                && !candidate.on_hold
+                && candidate.pack_size == new_pack_size
+                && candidate.batch == stock_line.batch
+                && candidate.expiry_date == stock_line.expiry_date
+                && candidate.location_id == location_id
+                && (candidate.cost_price_per_pack - cost_price_per_pack).abs() < f64::EPSILON
+                && (candidate.sell_price_per_pack - sell_price_per_pack).abs() < f64::EPSILON
+        });
+    Ok(match existing {
+        Some(existing) => StockLineRow {
+            available_number_of_packs: existing.available_number_of_packs + new_number_of_packs,
+            total_number_of_packs: existing.total_number_of_packs + new_number_of_packs,
+            ..existing
+        },
+        None => StockLineRow {
+            id: uuid(),
+            item_id: stock_line.item_id.clone(),
+            store_id: stock_line.store_id.clone(),
+            location_id,
+            batch: stock_line.batch.clone(),
+            pack_size: new_pack_size,
+            cost_price_per_pack,
+            sell_price_per_pack,
+            available_number_of_packs: new_number_of_packs,
+            total_number_of_packs: new_number_of_packs,
+            expiry_date: stock_line.expiry_date,
+            on_hold: false,
+            note: stock_line.note.clone(),
+        },
+    })
+}
+
+fn generate_line(
+    invoice_id: &str,
+    item: &ItemRow,
+    r#type: InvoiceLineRowType,
+    stock_line: &StockLineRow,
+    number_of_packs: i32,
+) -> InvoiceLineRow {
+    let total = stock_line.cost_price_per_pack * number_of_packs as f64;
+    InvoiceLineRow {
+        id: uuid(),
+        invoice_id: invoice_id.to_string(),
+        item_id: item.id.clone(),
+        item_name: item.name.clone(),
+        item_code: item.code.clone(),
+        stock_line_id: Some(stock_line.id.clone()),
+        location_id: stock_line.location_id.clone(),
+        batch: stock_line.batch.clone(),
+        expiry_date: stock_line.expiry_date,
+        pack_size: stock_line.pack_size,
+        cost_price_per_pack: stock_line.cost_price_per_pack,
+        sell_price_per_pack: stock_line.sell_price_per_pack,
+        total_before_tax: total,
+        total_after_tax: total,
+        tax: None,
+        r#type,
+        number_of_packs,
+        note: None,
+        return_reason_id: None,
+        directions: None,
+    }
+}
+
+fn generate(
+    connection: &StorageConnection,
+    ValidateResult {
+        store,
+        stock_line,
+        new_number_of_packs,
+    }: ValidateResult,
+    InsertRepack {
+        id,
+        stock_line_id: _,
+        number_of_packs,
+        new_pack_size,
+        new_location_id,
+    }: InsertRepack,
+) -> Result<GenerateResult, InsertRepackError> {
+    let item = ItemRepository::new(connection)
+        .find_one_by_id(&stock_line.item_id)?
+        .ok_or_else(|| {
+            InsertRepackError::InternalError(format!(
+                "Can't find item {} of stock line {}!",
+                stock_line.item_id, stock_line.id
+            ))
+        })?;
+    let number_of_packs = number_of_packs as i32;
+    let location_id = new_location_id.or(stock_line.location_id.clone());
+    let stock_line_to = generate_stock_line_to(
+        connection,
+        &stock_line,
+        new_pack_size as i32,
+        new_number_of_packs,
+        location_id,
+    )?;
+    let lines = vec![
+        generate_line(
+            &id,
+            &item,
+            InvoiceLineRowType::StockOut,
+            &stock_line,
+            number_of_packs,
+        ),
+        generate_line(
+            &id,
+            &item,
+            InvoiceLineRowType::StockIn,
+            &stock_line_to,
+            new_number_of_packs,
+        ),
+    ];
+    let stock_line_from = StockLineRow {
+        available_number_of_packs: stock_line.available_number_of_packs - number_of_packs,
+        total_number_of_packs: stock_line.total_number_of_packs - number_of_packs,
+        ..stock_line
+    };
+
+    // the store repacks its own stock, the store is the other party
+    let now = Utc::now().naive_utc();
+    let invoice = InvoiceRow {
+        id,
+        name_id: store.name_id,
+        store_id: store.id.clone(),
+        invoice_number: next_number(connection, &NumberRowType::Repack, &store.id)?,
+        r#type: InvoiceRowType::Repack,
+        status: InvoiceRowStatus::Verified,
+        on_hold: false,
+        comment: None,
+        their_reference: None,
+        created_datetime: now,
+        allocated_datetime: None,
+        picked_datetime: None,
+        shipped_datetime: None,
+        delivered_datetime: None,
+        verified_datetime: Some(now),
+        color: None,
+        prescriber_id: None,
+    };
+    Ok(GenerateResult {
+        invoice,
+        lines,
+        stock_line_from,
+        stock_line_to,
+    })
+}
+
+fn get_repack_invoice(
+    connection: &StorageConnection,
+    id: &str,
+) -> Result<Invoice, InsertRepackError> {
+    let mut result = InvoiceQueryRepository::new(connection)
+        .query_by_filter(InvoiceFilter::new().id(EqualFilter::equal_to(id)))?;
+    result.pop().ok_or(InsertRepackError::InternalError(
+        "Failed to read the just inserted repack!".to_string(),
+    ))
+}
+
+fn get_stock_line(
+    connection: &StorageConnection,
+    id: &str,
+) -> Result<StockLine, InsertRepackError> {
+    let mut result = StockLineRepository::new(connection)
+        .query_by_filter(StockLineFilter::new().id(EqualFilter::equal_to(id)))?;
+    result.pop().ok_or(InsertRepackError::InternalError(format!(
+        "Failed to read the repacked stock line {}!",
+        id
+    )))
+}
+
+pub fn insert_repack(
+    ctx: &ServiceContext,
+    store_id: &str,
+    input: InsertRepack,
+) -> Result<Repack, InsertRepackError> {
+    let result = ctx
+        .connection
+        .transaction_sync(|connection| {
+            let validated = validate(connection, store_id, &input)?;
+            let GenerateResult {
+                invoice,
+                lines,
+                stock_line_from,
+                stock_line_to,
+            } = generate(connection, validated, input)?;
+
+            let stock_line_repository = StockLineRowRepository::new(connection);
+            stock_line_repository.upsert_one(&stock_line_from)?;
+            stock_line_repository.upsert_one(&stock_line_to)?;
+            InvoiceRepository::new(connection).upsert_one(&invoice)?;
+            let line_repository = InvoiceLineRowRepository::new(connection);
+            for line in lines {
+                line_repository.upsert_one(&line)?;
+            }
+
+            Ok::<Repack, InsertRepackError>(Repack {
+                invoice: get_repack_invoice(connection, &invoice.id)?,
+                stock_line_from: get_stock_line(connection, &stock_line_from.id)?,
+                stock_line_to: get_stock_line(connection, &stock_line_to.id)?,
+            })
+        })
+        .map_err(|error| error.to_inner_error())?;
+    Ok(result)
+}
+
+impl From<RepositoryError> for InsertRepackError {
+    fn from(error: RepositoryError) -> Self {
+        InsertRepackError::DatabaseError(error)
+    }
+}
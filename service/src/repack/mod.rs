@@ -0,0 +1,35 @@
+use domain::{invoice::Invoice, stock_line::StockLine};
+
+use crate::service_provider::ServiceContext;
+
+use self::insert::{insert_repack, InsertRepack, InsertRepackError};
+
+pub mod insert;
+
+#[cfg(test)]
+mod tests;
+
+/// Result of a repack: the verified repack invoice and the stock lines the packs were moved
+/// between
+#[derive(Debug, PartialEq)]
+pub struct Repack {
+    pub invoice: Invoice,
+    pub stock_line_from: StockLine,
+    pub stock_line_to: StockLine,
+}
+
+pub trait RepackServiceTrait: Sync + Send {
+    /// Takes packs from a stock line and repacks them into a stock line of the same batch with a
+    /// different pack size
+    fn insert_repack(
+        &self,
+        ctx: &ServiceContext,
+        store_id: &str,
+        input: InsertRepack,
+    ) -> Result<Repack, InsertRepackError> {
+        insert_repack(ctx, store_id, input)
+    }
+}
+
+pub struct RepackService {}
+impl RepackServiceTrait for RepackService {}
@@ -0,0 +1,196 @@
+#[cfg(test)]
+mod repack_test {
+    use domain::{
+        invoice::{InvoiceStatus, InvoiceType},
+        EqualFilter,
+    };
+    use repository::{
+        mock::{mock_item_a, mock_store_a, MockDataInserts},
+        schema::{InvoiceLineRowType, StockLineRow},
+        test_db::setup_all,
+        InvoiceLineRowRepository, StockLineRowRepository, StockMovementFilter,
+        StockMovementRepository,
+    };
+
+    use crate::{
+        repack::insert::{InsertRepack, InsertRepackError},
+        service_provider::ServiceProvider,
+    };
+
+    fn stock_line_box() -> StockLineRow {
+        StockLineRow {
+            id: "box_of_100".to_string(),
+            item_id: mock_item_a().id,
+            store_id: mock_store_a().id,
+            location_id: None,
+            batch: Some("repack_batch".to_string()),
+            pack_size: 100,
+            cost_price_per_pack: 50.0,
+            sell_price_per_pack: 80.0,
+            available_number_of_packs: 5,
+            total_number_of_packs: 6,
+            expiry_date: None,
+            on_hold: false,
+            note: None,
+        }
+    }
+
+    fn input(id: &str, number_of_packs: u32, new_pack_size: u32) -> InsertRepack {
+        InsertRepack {
+            id: id.to_string(),
+            stock_line_id: stock_line_box().id,
+            number_of_packs,
+            new_pack_size,
+            new_location_id: None,
+        }
+    }
+
+    #[actix_rt::test]
+    async fn repack() {
+        let (_, connection, connection_manager, _) =
+            setup_all("repack", MockDataInserts::all()).await;
+        StockLineRowRepository::new(&connection)
+            .upsert_one(&stock_line_box())
+            .unwrap();
+        let service_provider = ServiceProvider::new(connection_manager);
+        let context = service_provider.context().unwrap();
+        let service = &service_provider.repack_service;
+        let store_id = &mock_store_a().id;
+
+        // StockLineDoesNotExist
+        assert_eq!(
+            service.insert_repack(
+                &context,
+                store_id,
+                InsertRepack {
+                    stock_line_id: "invalid".to_string(),
+                    ..input("repack", 1, 10)
+                }
+            ),
+            Err(InsertRepackError::StockLineDoesNotExist)
+        );
+        // ReductionBelowZero
+        assert_eq!(
+            service.insert_repack(&context, store_id, input("repack", 6, 10)),
+            Err(InsertRepackError::ReductionBelowZero)
+        );
+        // PackSizeUnchanged
+        assert_eq!(
+            service.insert_repack(&context, store_id, input("repack", 1, 100)),
+            Err(InsertRepackError::PackSizeUnchanged)
+        );
+        // CannotSplitIntoWholePacks
+        assert_eq!(
+            service.insert_repack(&context, store_id, input("repack", 1, 30)),
+            Err(InsertRepackError::CannotSplitIntoWholePacks)
+        );
+        // LocationIsOnHold
+        assert_eq!(
+            service.insert_repack(
+                &context,
+                store_id,
+                InsertRepack {
+                    new_location_id: Some("location_on_hold".to_string()),
+                    ..input("repack", 1, 10)
+                }
+            ),
+            Err(InsertRepackError::LocationIsOnHold)
+        );
+        // LocationDoesNotExist
+        assert_eq!(
+            service.insert_repack(
+                &context,
+                store_id,
+                InsertRepack {
+                    new_location_id: Some("location_in_another_store".to_string()),
+                    ..input("repack", 1, 10)
+                }
+            ),
+            Err(InsertRepackError::LocationDoesNotExist)
+        );
+
+        // 2 boxes of 100 into 20 packs of 10
+        let repack = service
+            .insert_repack(&context, store_id, input("repack", 2, 10))
+            .unwrap();
+        assert_eq!(repack.invoice.r#type, InvoiceType::Repack);
+        assert_eq!(repack.invoice.status, InvoiceStatus::Verified);
+        assert_eq!(repack.stock_line_from.available_number_of_packs, 3);
+        assert_eq!(repack.stock_line_from.total_number_of_packs, 4);
+        let stock_line_to = repack.stock_line_to;
+        assert_eq!(stock_line_to.pack_size, 10);
+        assert_eq!(stock_line_to.batch, Some("repack_batch".to_string()));
+        assert_eq!(stock_line_to.available_number_of_packs, 20);
+        assert_eq!(stock_line_to.total_number_of_packs, 20);
+        assert_eq!(stock_line_to.cost_price_per_pack, 5.0);
+        assert_eq!(stock_line_to.sell_price_per_pack, 8.0);
+
+        let lines = InvoiceLineRowRepository::new(&connection)
+            .find_many_by_invoice_id("repack")
+            .unwrap();
+        assert_eq!(lines.len(), 2);
+        let line_out = lines
+            .iter()
+            .find(|line| line.r#type == InvoiceLineRowType::StockOut)
+            .unwrap();
+        assert_eq!(line_out.stock_line_id, Some(stock_line_box().id));
+        assert_eq!(line_out.number_of_packs, 2);
+        let line_in = lines
+            .iter()
+            .find(|line| line.r#type == InvoiceLineRowType::StockIn)
+            .unwrap();
+        assert_eq!(line_in.stock_line_id, Some(stock_line_to.id.clone()));
+        assert_eq!(line_in.number_of_packs, 20);
+
+        // the ledger shows the repack without changing the units of the batch
+        let movements = StockMovementRepository::new(&connection)
+            .query_by_filter(
+                StockMovementFilter::new().batch(EqualFilter::equal_to("repack_batch")),
+            )
+            .unwrap()
+            .into_iter()
+            .filter(|movement| movement.invoice_id == "repack")
+            .collect::<Vec<_>>();
+        assert_eq!(movements.len(), 2);
+        assert_eq!(
+            movements
+                .iter()
+                .map(|movement| movement.number_of_packs as i64 * movement.pack_size as i64)
+                .sum::<i64>(),
+            0
+        );
+
+        // RepackAlreadyExists
+        assert_eq!(
+            service.insert_repack(&context, store_id, input("repack", 1, 10)),
+            Err(InsertRepackError::RepackAlreadyExists)
+        );
+
+        // repacking into the same pack size adds to the existing stock line
+        let repack = service
+            .insert_repack(&context, store_id, input("repack2", 1, 10))
+            .unwrap();
+        assert_eq!(repack.stock_line_to.id, stock_line_to.id);
+        assert_eq!(repack.stock_line_to.total_number_of_packs, 30);
+        assert_eq!(repack.stock_line_from.total_number_of_packs, 3);
+
+        // a new location creates another stock line
+        let repack = service
+            .insert_repack(
+                &context,
+                store_id,
+                InsertRepack {
+                    new_location_id: Some("location_1".to_string()),
+                    ..input("repack3", 1, 10)
+                },
+            )
+            .unwrap();
+        assert_ne!(repack.stock_line_to.id, stock_line_to.id);
+        assert_eq!(
+            repack.stock_line_to.location_id,
+            Some("location_1".to_string())
+        );
+        assert_eq!(repack.stock_line_to.total_number_of_packs, 10);
+        assert_ne!(repack.invoice.invoice_number, 0);
+    }
+}
@@ -0,0 +1,453 @@
+use std::collections::HashMap;
+
+use chrono::{NaiveDate, NaiveDateTime, Utc};
+use domain::{
+    invoice::{Invoice, InvoiceFilter, InvoiceStatus, InvoiceType},
+    invoice_line::{InvoiceLine, InvoiceLineType},
+    EqualFilter, Pagination,
+};
+use repository::{
+    schema::{InvoiceStatsRow, StockTakeStatus},
+    InvoiceLineFilter, InvoiceLineRepository, InvoiceQueryRepository, ItemRepository,
+    NameRepository, PackVariantRowRepository, StockTakeLine, StockTakeLineFilter,
+    StockTakeLineRepository, StockTakeRowRepository, StorageConnection, StoreRowRepository,
+};
+use serde::Serialize;
+
+use crate::pack_variant::number_of_units;
+
+use super::RenderReportError;
+
+const DATE_FORMAT: &str = "%Y-%m-%d";
+const DATETIME_FORMAT: &str = "%Y-%m-%d %H:%M";
+
+/// Store the report is printed in
+#[derive(Serialize, Debug)]
+pub struct ReportStore {
+    pub id: String,
+    pub code: String,
+    pub name: String,
+}
+
+#[derive(Serialize, Debug)]
+pub struct ReportInvoice {
+    pub id: String,
+    pub invoice_number: i64,
+    pub status: &'static str,
+    pub other_party_id: String,
+    pub other_party_name: String,
+    pub their_reference: Option<String>,
+    pub comment: Option<String>,
+    pub created_datetime: String,
+    pub allocated_datetime: Option<String>,
+    pub picked_datetime: Option<String>,
+    pub shipped_datetime: Option<String>,
+    pub delivered_datetime: Option<String>,
+    pub verified_datetime: Option<String>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct ReportInvoiceLine {
+    pub item_code: String,
+    pub item_name: String,
+    pub location_name: Option<String>,
+    pub batch: Option<String>,
+    pub expiry_date: Option<String>,
+    pub pack_size: i32,
+    /// Name of the pack variant of the item with the pack size
+    pub pack_variant_name: Option<String>,
+    pub number_of_packs: i32,
+    /// Number of packs * pack size
+    pub quantity: i64,
+    pub cost_price_per_pack: f64,
+    pub sell_price_per_pack: f64,
+    /// Number of packs * sell price, or cost price for inbound shipments
+    pub total: f64,
+    pub note: Option<String>,
+}
+
+/// Lines of one location, groups are in pick order
+#[derive(Serialize, Debug)]
+pub struct ReportLocationGroup<T> {
+    pub location_name: Option<String>,
+    pub lines: Vec<T>,
+}
+
+/// Totals from the invoice_stats view
+#[derive(Serialize, Debug, Default)]
+pub struct ReportInvoiceTotals {
+    pub total_before_tax: f64,
+    pub total_after_tax: f64,
+    pub stock_total_before_tax: f64,
+    pub stock_total_after_tax: f64,
+    pub service_total_before_tax: f64,
+    pub service_total_after_tax: f64,
+    /// Total after tax - total before tax
+    pub tax: f64,
+}
+
+/// Data of inbound and outbound shipment templates
+#[derive(Serialize, Debug)]
+pub struct InvoiceReportData {
+    pub printed_datetime: String,
+    pub store: ReportStore,
+    pub invoice: ReportInvoice,
+    /// Stock lines ordered by item name
+    pub lines: Vec<ReportInvoiceLine>,
+    pub location_groups: Vec<ReportLocationGroup<ReportInvoiceLine>>,
+    pub service_lines: Vec<ReportInvoiceLine>,
+    pub totals: ReportInvoiceTotals,
+}
+
+#[derive(Serialize, Debug)]
+pub struct ReportStockTake {
+    pub id: String,
+    pub status: &'static str,
+    pub description: Option<String>,
+    pub comment: Option<String>,
+    pub created_datetime: String,
+    pub finalised_datetime: Option<String>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct ReportStockTakeLine {
+    pub item_code: String,
+    pub item_name: String,
+    pub location_name: Option<String>,
+    pub batch: Option<String>,
+    pub expiry_date: Option<String>,
+    pub pack_size: Option<i32>,
+    /// Name of the pack variant of the item with the pack size
+    pub pack_variant_name: Option<String>,
+    pub snapshot_number_of_packs: i32,
+    pub counted_number_of_packs: Option<i32>,
+    /// Snapshot number of packs * pack size
+    pub snapshot_units: Option<i64>,
+    /// Counted number of packs * pack size
+    pub counted_units: Option<i64>,
+    pub comment: Option<String>,
+}
+
+/// Data of stock take templates
+#[derive(Serialize, Debug)]
+pub struct StockTakeReportData {
+    pub printed_datetime: String,
+    pub store: ReportStore,
+    pub stock_take: ReportStockTake,
+    pub location_groups: Vec<ReportLocationGroup<ReportStockTakeLine>>,
+    pub number_of_lines: usize,
+}
+
+fn format_date(date: Option<NaiveDate>) -> Option<String> {
+    date.map(|date| date.format(DATE_FORMAT).to_string())
+}
+
+fn format_datetime(datetime: Option<NaiveDateTime>) -> Option<String> {
+    datetime.map(|datetime| datetime.format(DATETIME_FORMAT).to_string())
+}
+
+fn printed_datetime() -> String {
+    Utc::now().naive_utc().format(DATETIME_FORMAT).to_string()
+}
+
+fn get_store(
+    connection: &StorageConnection,
+    store_id: &str,
+) -> Result<ReportStore, RenderReportError> {
+    let store = StoreRowRepository::new(connection)
+        .find_one_by_id(store_id)?
+        .ok_or(RenderReportError::RecordDoesNotExist)?;
+    let name = NameRepository::new(connection)
+        .find_many_by_id(&[store.name_id])?
+        .pop()
+        .map(|name| name.name)
+        .unwrap_or_default();
+    Ok(ReportStore {
+        id: store.id,
+        code: store.code,
+        name,
+    })
+}
+
+/// Pack variant names by item id and pack size
+fn get_pack_variant_names(
+    connection: &StorageConnection,
+    item_ids: &[String],
+) -> Result<HashMap<(String, i32), String>, RenderReportError> {
+    Ok(PackVariantRowRepository::new(connection)
+        .find_many_by_item_ids(item_ids)?
+        .into_iter()
+        .map(|pack_variant| {
+            (
+                (pack_variant.item_id, pack_variant.pack_size),
+                pack_variant.name,
+            )
+        })
+        .collect())
+}
+
+/// Groups lines by location, ordered by location name with lines without location last
+fn group_by_location<T>(
+    lines: impl IntoIterator<Item = (Option<String>, T)>,
+) -> Vec<ReportLocationGroup<T>> {
+    let mut groups: Vec<ReportLocationGroup<T>> = Vec::new();
+    for (location_name, line) in lines {
+        match groups
+            .iter_mut()
+            .find(|group| group.location_name == location_name)
+        {
+            Some(group) => group.lines.push(line),
+            None => groups.push(ReportLocationGroup {
+                location_name,
+                lines: vec![line],
+            }),
+        }
+    }
+    groups.sort_by(|a, b| match (&a.location_name, &b.location_name) {
+        (Some(a), Some(b)) => a.to_lowercase().cmp(&b.to_lowercase()),
+        (a, b) => b.is_some().cmp(&a.is_some()),
+    });
+    groups
+}
+
+fn invoice_status(status: &InvoiceStatus) -> &'static str {
+    match status {
+        InvoiceStatus::New => "NEW",
+        InvoiceStatus::Allocated => "ALLOCATED",
+        InvoiceStatus::Picked => "PICKED",
+        InvoiceStatus::Shipped => "SHIPPED",
+        InvoiceStatus::Delivered => "DELIVERED",
+        InvoiceStatus::Verified => "VERIFIED",
+    }
+}
+
+fn invoice_line(
+    line: InvoiceLine,
+    invoice_type: &InvoiceType,
+    pack_variant_names: &HashMap<(String, i32), String>,
+) -> ReportInvoiceLine {
+    let price_per_pack = match invoice_type {
+        InvoiceType::InboundShipment => line.cost_price_per_pack,
+        _ => line.sell_price_per_pack,
+    };
+    ReportInvoiceLine {
+        quantity: number_of_units(line.number_of_packs, line.pack_size),
+        pack_variant_name: pack_variant_names
+            .get(&(line.item_id.clone(), line.pack_size))
+            .cloned(),
+        total: line.number_of_packs as f64 * price_per_pack,
+        item_code: line.item_code,
+        item_name: line.item_name,
+        location_name: line.location_name,
+        batch: line.batch,
+        expiry_date: format_date(line.expiry_date),
+        pack_size: line.pack_size,
+        number_of_packs: line.number_of_packs,
+        cost_price_per_pack: line.cost_price_per_pack,
+        sell_price_per_pack: line.sell_price_per_pack,
+        note: line.note,
+    }
+}
+
+/// Data of an invoice of the store with the given type
+pub fn get_invoice_data(
+    connection: &StorageConnection,
+    store_id: &str,
+    invoice_id: &str,
+    invoice_type: InvoiceType,
+) -> Result<InvoiceReportData, RenderReportError> {
+    let mut filter = InvoiceFilter::new().id(EqualFilter::equal_to(invoice_id));
+    filter.store_id = Some(EqualFilter::equal_to(store_id));
+    let invoice: Invoice = InvoiceQueryRepository::new(connection)
+        .query_by_filter(filter)?
+        .pop()
+        .ok_or(RenderReportError::RecordDoesNotExist)?;
+    if invoice.r#type != invoice_type {
+        return Err(RenderReportError::WrongRecordType);
+    }
+
+    let mut invoice_lines = InvoiceLineRepository::new(connection).query(
+        Pagination::all(),
+        Some(InvoiceLineFilter::new().invoice_id(EqualFilter::equal_to(invoice_id))),
+        None,
+    )?;
+    // first expiring batch first within an item
+    invoice_lines.sort_by(|a, b| {
+        a.item_name
+            .to_lowercase()
+            .cmp(&b.item_name.to_lowercase())
+            .then(a.expiry_date.cmp(&b.expiry_date))
+    });
+    let item_ids: Vec<String> = invoice_lines
+        .iter()
+        .map(|line| line.item_id.clone())
+        .collect();
+    let pack_variant_names = get_pack_variant_names(connection, &item_ids)?;
+    let mut stock_lines = Vec::new();
+    let mut location_lines = Vec::new();
+    let mut service_lines = Vec::new();
+    for line in invoice_lines {
+        match line.r#type {
+            InvoiceLineType::StockIn | InvoiceLineType::StockOut => {
+                location_lines.push((
+                    line.location_name.clone(),
+                    invoice_line(line.clone(), &invoice.r#type, &pack_variant_names),
+                ));
+                stock_lines.push(invoice_line(line, &invoice.r#type, &pack_variant_names));
+            }
+            InvoiceLineType::Service => {
+                service_lines.push(invoice_line(line, &invoice.r#type, &pack_variant_names))
+            }
+            // placeholders, nothing has been picked for them yet
+            InvoiceLineType::UnallocatedStock => {}
+        }
+    }
+
+    let totals = InvoiceLineRepository::new(connection)
+        .stats(&[invoice.id.clone()])?
+        .pop()
+        .map(
+            |InvoiceStatsRow {
+                 total_before_tax,
+                 total_after_tax,
+                 stock_total_before_tax,
+                 stock_total_after_tax,
+                 service_total_before_tax,
+                 service_total_after_tax,
+                 ..
+             }| ReportInvoiceTotals {
+                total_before_tax,
+                total_after_tax,
+                stock_total_before_tax,
+                stock_total_after_tax,
+                service_total_before_tax,
+                service_total_after_tax,
+                tax: total_after_tax - total_before_tax,
+            },
+        )
+        .unwrap_or_default();
+
+    Ok(InvoiceReportData {
+        printed_datetime: printed_datetime(),
+        store: get_store(connection, store_id)?,
+        invoice: ReportInvoice {
+            status: invoice_status(&invoice.status),
+            created_datetime: invoice.created_datetime.format(DATETIME_FORMAT).to_string(),
+            allocated_datetime: format_datetime(invoice.allocated_datetime),
+            picked_datetime: format_datetime(invoice.picked_datetime),
+            shipped_datetime: format_datetime(invoice.shipped_datetime),
+            delivered_datetime: format_datetime(invoice.delivered_datetime),
+            verified_datetime: format_datetime(invoice.verified_datetime),
+            id: invoice.id,
+            invoice_number: invoice.invoice_number,
+            other_party_id: invoice.other_party_id,
+            other_party_name: invoice.other_party_name,
+            their_reference: invoice.their_reference,
+            comment: invoice.comment,
+        },
+        lines: stock_lines,
+        location_groups: group_by_location(location_lines),
+        service_lines,
+        totals,
+    })
+}
+
+pub fn get_stock_take_data(
+    connection: &StorageConnection,
+    store_id: &str,
+    stock_take_id: &str,
+) -> Result<StockTakeReportData, RenderReportError> {
+    let stock_take = StockTakeRowRepository::new(connection)
+        .find_one_by_id(stock_take_id)?
+        .filter(|stock_take| stock_take.store_id == store_id)
+        .ok_or(RenderReportError::RecordDoesNotExist)?;
+
+    let lines = StockTakeLineRepository::new(connection).query_by_filter(
+        StockTakeLineFilter::new().stock_take_id(EqualFilter::equal_to(stock_take_id)),
+    )?;
+    let item_ids: Vec<String> = lines.iter().map(|line| line.line.item_id.clone()).collect();
+    let items: HashMap<String, (String, String)> = ItemRepository::new(connection)
+        .find_many_by_id(&item_ids)?
+        .into_iter()
+        .map(|item| (item.id, (item.code, item.name)))
+        .collect();
+    let pack_variant_names = get_pack_variant_names(connection, &item_ids)?;
+
+    let mut lines: Vec<(Option<String>, ReportStockTakeLine)> = lines
+        .into_iter()
+        .map(
+            |StockTakeLine {
+                 line,
+                 stock_line,
+                 location,
+             }| {
+                let (item_code, item_name) = items.get(&line.item_id).cloned().unwrap_or_default();
+                let location_name = location.map(|location| location.name);
+                // values of the stock take line take precedence over the counted stock line
+                let batch = line
+                    .batch
+                    .or_else(|| stock_line.as_ref().and_then(|row| row.batch.clone()));
+                let expiry_date = line
+                    .expiry_date
+                    .or_else(|| stock_line.as_ref().and_then(|row| row.expiry_date));
+                let pack_size = line
+                    .pack_size
+                    .or_else(|| stock_line.as_ref().map(|row| row.pack_size));
+                let item_id = &line.item_id;
+                let pack_variant_name = pack_size.and_then(|pack_size| {
+                    pack_variant_names
+                        .get(&(item_id.clone(), pack_size))
+                        .cloned()
+                });
+                let units = |number_of_packs: i32| {
+                    pack_size.map(|pack_size| number_of_units(number_of_packs, pack_size))
+                };
+                (
+                    location_name.clone(),
+                    ReportStockTakeLine {
+                        item_code,
+                        item_name,
+                        location_name,
+                        batch,
+                        expiry_date: format_date(expiry_date),
+                        pack_size,
+                        pack_variant_name,
+                        snapshot_number_of_packs: line.snapshot_number_of_packs,
+                        counted_number_of_packs: line.counted_number_of_packs,
+                        snapshot_units: units(line.snapshot_number_of_packs),
+                        counted_units: line.counted_number_of_packs.and_then(units),
+                        comment: line.comment,
+                    },
+                )
+            },
+        )
+        .collect();
+    lines.sort_by(|(_, a), (_, b)| {
+        a.item_name
+            .to_lowercase()
+            .cmp(&b.item_name.to_lowercase())
+            .then(a.expiry_date.cmp(&b.expiry_date))
+    });
+    let number_of_lines = lines.len();
+
+    Ok(StockTakeReportData {
+        printed_datetime: printed_datetime(),
+        store: get_store(connection, store_id)?,
+        stock_take: ReportStockTake {
+            id: stock_take.id,
+            status: match stock_take.status {
+                StockTakeStatus::New => "NEW",
+                StockTakeStatus::Finalized => "FINALIZED",
+            },
+            description: stock_take.description,
+            comment: stock_take.comment,
+            created_datetime: stock_take
+                .created_datetime
+                .format(DATETIME_FORMAT)
+                .to_string(),
+            finalised_datetime: format_datetime(stock_take.finalised_datetime),
+        },
+        location_groups: group_by_location(lines),
+        number_of_lines,
+    })
+}
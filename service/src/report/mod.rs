@@ -0,0 +1,64 @@
+use repository::{
+    schema::{ReportContext, ReportRow},
+    RepositoryError,
+};
+
+use crate::service_provider::ServiceContext;
+
+use self::{query::get_reports, render::render_report};
+
+pub mod data;
+pub mod pdf;
+pub mod query;
+pub mod render;
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ReportFormat {
+    Html,
+    Pdf,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum RenderReportError {
+    DatabaseError(RepositoryError),
+    ReportDoesNotExist,
+    /// Record doesn't exist in the store
+    RecordDoesNotExist,
+    /// Record isn't of the type the report is for, e.g. an inbound shipment for a packing list
+    WrongRecordType,
+    /// Template couldn't be parsed or rendered
+    TemplateError(String),
+}
+
+impl From<RepositoryError> for RenderReportError {
+    fn from(error: RepositoryError) -> Self {
+        RenderReportError::DatabaseError(error)
+    }
+}
+
+pub trait ReportServiceTrait: Sync + Send {
+    fn get_reports(
+        &self,
+        ctx: &ServiceContext,
+        context: Option<ReportContext>,
+    ) -> Result<Vec<ReportRow>, RepositoryError> {
+        get_reports(ctx, context)
+    }
+
+    fn render_report(
+        &self,
+        ctx: &ServiceContext,
+        store_id: &str,
+        report_id: &str,
+        record_id: &str,
+        format: ReportFormat,
+    ) -> Result<Vec<u8>, RenderReportError> {
+        render_report(ctx, store_id, report_id, record_id, format)
+    }
+}
+
+pub struct ReportService {}
+impl ReportServiceTrait for ReportService {}
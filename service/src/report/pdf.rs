@@ -0,0 +1,417 @@
+use pdf_writer::{Content, Finish, Name, Pdf, Rect, Ref, Str};
+
+/// A4 in points
+const PAGE_WIDTH: f32 = 595.0;
+const PAGE_HEIGHT: f32 = 842.0;
+const MARGIN: f32 = 40.0;
+const FONT_SIZE: f32 = 9.0;
+const LINE_HEIGHT: f32 = 11.0;
+/// Courier glyphs are 0.6 em wide
+const CHARACTERS_PER_LINE: usize = ((PAGE_WIDTH - 2.0 * MARGIN) / (FONT_SIZE * 0.6)) as usize;
+const LINES_PER_PAGE: usize = ((PAGE_HEIGHT - 2.0 * MARGIN) / LINE_HEIGHT) as usize;
+const MIN_COLUMN_WIDTH: usize = 4;
+const COLUMN_GAP: &str = "  ";
+
+#[derive(Debug, PartialEq)]
+enum Block {
+    Heading(String),
+    Paragraph(String),
+    /// Rows of cells, the flag is set for rows made of header cells
+    Table(Vec<(bool, Vec<String>)>),
+}
+
+#[derive(Debug, PartialEq)]
+struct Line {
+    text: String,
+    bold: bool,
+}
+
+/// Prints the text of a rendered report to PDF.
+///
+/// There is no HTML layout engine, headings, paragraphs and tables are laid out as plain text in
+/// a fixed width font. Styles and images of the template are ignored.
+pub fn html_to_pdf(html: &str) -> Vec<u8> {
+    let lines = layout(parse_blocks(html));
+    let pages: Vec<&[Line]> = if lines.is_empty() {
+        vec![&[]]
+    } else {
+        lines.chunks(LINES_PER_PAGE).collect()
+    };
+
+    let catalog_id = Ref::new(1);
+    let page_tree_id = Ref::new(2);
+    let font_id = Ref::new(3);
+    let bold_font_id = Ref::new(4);
+    let page_ids: Vec<Ref> = (0..pages.len())
+        .map(|index| Ref::new(5 + 2 * index as i32))
+        .collect();
+
+    let mut pdf = Pdf::new();
+    pdf.catalog(catalog_id).pages(page_tree_id);
+    pdf.pages(page_tree_id)
+        .kids(page_ids.iter().copied())
+        .count(pages.len() as i32);
+    pdf.type1_font(font_id)
+        .base_font(Name(b"Courier"))
+        .encoding_predefined(Name(b"WinAnsiEncoding"));
+    pdf.type1_font(bold_font_id)
+        .base_font(Name(b"Courier-Bold"))
+        .encoding_predefined(Name(b"WinAnsiEncoding"));
+
+    for (page_id, lines) in page_ids.into_iter().zip(pages) {
+        let content_id = Ref::new(page_id.get() + 1);
+        let mut page = pdf.page(page_id);
+        page.media_box(Rect::new(0.0, 0.0, PAGE_WIDTH, PAGE_HEIGHT))
+            .parent(page_tree_id)
+            .contents(content_id);
+        page.resources()
+            .fonts()
+            .pair(Name(b"F1"), font_id)
+            .pair(Name(b"F2"), bold_font_id);
+        page.finish();
+
+        let mut content = Content::new();
+        content.begin_text();
+        content.next_line(MARGIN, PAGE_HEIGHT - MARGIN - FONT_SIZE);
+        let mut bold = None;
+        for (index, line) in lines.iter().enumerate() {
+            if index > 0 {
+                content.next_line(0.0, -LINE_HEIGHT);
+            }
+            if bold != Some(line.bold) {
+                let font = if line.bold { b"F2" } else { b"F1" };
+                content.set_font(Name(font), FONT_SIZE);
+                bold = Some(line.bold);
+            }
+            content.show(Str(&encode(&line.text)));
+        }
+        content.end_text();
+        pdf.stream(content_id, &content.finish());
+    }
+
+    pdf.finish()
+}
+
+/// WinAnsi matches Latin-1 for printable characters, anything else is replaced
+fn encode(text: &str) -> Vec<u8> {
+    text.chars()
+        .map(|c| match c as u32 {
+            code @ 0x20..=0x7e | code @ 0xa0..=0xff => code as u8,
+            _ => b'?',
+        })
+        .collect()
+}
+
+fn decode_entities(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        result.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let entity = rest.find(';').map(|end| (&rest[1..end], end));
+        let decoded = entity.and_then(|(name, end)| {
+            let c = match name {
+                "amp" => Some('&'),
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                "nbsp" => Some(' '),
+                _ if name.starts_with("#x") || name.starts_with("#X") => {
+                    u32::from_str_radix(&name[2..], 16)
+                        .ok()
+                        .and_then(char::from_u32)
+                }
+                _ if name.starts_with('#') => name[1..].parse().ok().and_then(char::from_u32),
+                _ => None,
+            };
+            c.map(|c| (c, end))
+        });
+        match decoded {
+            Some((c, end)) => {
+                result.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                result.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+#[derive(Default)]
+struct BlockParser {
+    blocks: Vec<Block>,
+    text: String,
+    table: Option<Vec<(bool, Vec<String>)>>,
+    row: Option<(bool, Vec<String>)>,
+}
+
+impl BlockParser {
+    fn take_text(&mut self) -> String {
+        let text = decode_entities(&self.text.split_whitespace().collect::<Vec<_>>().join(" "));
+        self.text.clear();
+        text
+    }
+
+    /// Text outside of table cells ends up in a paragraph
+    fn end_paragraph(&mut self) {
+        if self.row.is_some() {
+            return;
+        }
+        let text = self.take_text();
+        if !text.is_empty() {
+            self.blocks.push(Block::Paragraph(text));
+        }
+    }
+
+    fn tag(&mut self, name: &str, is_closing: bool) {
+        match (name, is_closing) {
+            ("h1" | "h2" | "h3" | "h4" | "h5" | "h6", false) => self.end_paragraph(),
+            ("h1" | "h2" | "h3" | "h4" | "h5" | "h6", true) => {
+                let text = self.take_text();
+                if !text.is_empty() {
+                    self.blocks.push(Block::Heading(text));
+                }
+            }
+            ("p" | "div" | "br" | "li" | "ul" | "ol", _) => self.end_paragraph(),
+            ("table", false) => {
+                self.end_paragraph();
+                self.table = Some(Vec::new());
+            }
+            ("table", true) => {
+                self.tag("tr", true);
+                if let Some(table) = self.table.take() {
+                    if !table.is_empty() {
+                        self.blocks.push(Block::Table(table));
+                    }
+                }
+            }
+            ("tr", false) => {
+                self.tag("tr", true);
+                self.row = Some((true, Vec::new()));
+            }
+            ("tr", true) => {
+                if let Some(row) = self.row.take() {
+                    if let Some(table) = self.table.as_mut() {
+                        table.push(row);
+                    }
+                }
+            }
+            ("td" | "th", false) => {
+                self.text.clear();
+            }
+            ("td" | "th", true) => {
+                let text = self.take_text();
+                if let Some((is_header, cells)) = self.row.as_mut() {
+                    *is_header = *is_header && name == "th";
+                    cells.push(text);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn parse_blocks(html: &str) -> Vec<Block> {
+    let mut parser = BlockParser::default();
+    // content of these elements isn't printed
+    let mut skip_until: Option<String> = None;
+    let mut rest = html;
+    while let Some(start) = rest.find('<') {
+        if skip_until.is_none() {
+            parser.text.push_str(&rest[..start]);
+        }
+        let end = match rest[start..].find('>') {
+            Some(end) => start + end,
+            None => {
+                rest = "";
+                break;
+            }
+        };
+        let tag = &rest[start + 1..end];
+        rest = &rest[end + 1..];
+
+        let is_closing = tag.starts_with('/');
+        let name: String = tag
+            .trim_start_matches('/')
+            .chars()
+            .take_while(|c| c.is_ascii_alphanumeric())
+            .collect::<String>()
+            .to_lowercase();
+        if let Some(skipped) = &skip_until {
+            if is_closing && &name == skipped {
+                skip_until = None;
+            }
+            continue;
+        }
+        if !is_closing && matches!(name.as_str(), "head" | "style" | "script" | "title") {
+            skip_until = Some(name);
+            continue;
+        }
+        parser.tag(&name, is_closing);
+    }
+    if skip_until.is_none() {
+        parser.text.push_str(rest);
+    }
+    parser.tag("table", true);
+    parser.end_paragraph();
+    parser.blocks
+}
+
+/// Splits text into lines of at most `width` characters, breaking at spaces where possible
+fn wrap(text: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    for word in text.split(' ') {
+        let mut word: Vec<char> = word.chars().collect();
+        while word.len() > width {
+            if !line.is_empty() {
+                lines.push(std::mem::take(&mut line));
+            }
+            lines.push(word.drain(..width).collect());
+        }
+        let word: String = word.into_iter().collect();
+        let line_length = line.chars().count();
+        if line_length > 0 && line_length + 1 + word.chars().count() > width {
+            lines.push(std::mem::take(&mut line));
+        }
+        if !line.is_empty() {
+            line.push(' ');
+        }
+        line.push_str(&word);
+    }
+    if !line.is_empty() || lines.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+/// Widths of the table columns, the widest columns are narrowed until the table fits the page
+fn column_widths(rows: &[(bool, Vec<String>)]) -> Vec<usize> {
+    let column_count = rows.iter().map(|(_, cells)| cells.len()).max().unwrap_or(0);
+    let mut widths = vec![MIN_COLUMN_WIDTH; column_count];
+    for (_, cells) in rows {
+        for (index, cell) in cells.iter().enumerate() {
+            widths[index] = widths[index].max(cell.chars().count());
+        }
+    }
+    let available = CHARACTERS_PER_LINE.saturating_sub(COLUMN_GAP.len() * column_count.max(1) - 1);
+    while widths.iter().sum::<usize>() > available {
+        let widest = widths
+            .iter_mut()
+            .max()
+            .filter(|width| **width > MIN_COLUMN_WIDTH);
+        match widest {
+            Some(width) => *width -= 1,
+            None => break,
+        }
+    }
+    widths
+}
+
+fn layout(blocks: Vec<Block>) -> Vec<Line> {
+    let mut lines = Vec::new();
+    for block in blocks {
+        match block {
+            Block::Heading(text) => {
+                if !lines.is_empty() {
+                    lines.push(Line {
+                        text: String::new(),
+                        bold: false,
+                    });
+                }
+                lines.extend(
+                    wrap(&text, CHARACTERS_PER_LINE)
+                        .into_iter()
+                        .map(|text| Line { text, bold: true }),
+                );
+            }
+            Block::Paragraph(text) => lines.extend(
+                wrap(&text, CHARACTERS_PER_LINE)
+                    .into_iter()
+                    .map(|text| Line { text, bold: false }),
+            ),
+            Block::Table(rows) => {
+                let widths = column_widths(&rows);
+                for (is_header, cells) in &rows {
+                    let wrapped: Vec<Vec<String>> = widths
+                        .iter()
+                        .enumerate()
+                        .map(|(index, width)| {
+                            wrap(cells.get(index).map(String::as_str).unwrap_or(""), *width)
+                        })
+                        .collect();
+                    let height = wrapped.iter().map(Vec::len).max().unwrap_or(1);
+                    for line_index in 0..height {
+                        let text = wrapped
+                            .iter()
+                            .zip(&widths)
+                            .map(|(cell, width)| {
+                                let text = cell.get(line_index).map(String::as_str).unwrap_or("");
+                                format!("{:width$}", text, width = width)
+                            })
+                            .collect::<Vec<_>>()
+                            .join(COLUMN_GAP);
+                        lines.push(Line {
+                            text: text.trim_end().to_string(),
+                            bold: *is_header,
+                        });
+                    }
+                }
+                lines.push(Line {
+                    text: String::new(),
+                    bold: false,
+                });
+            }
+        }
+    }
+    lines
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_blocks() {
+        let html = "<html><head><title>Ignored</title><style>td { x: 1 }</style></head>\
+            <body><h1>Packing  list</h1><p>Store &amp; co</p>\
+            <table><tr><th>Item</th><th>Packs</th></tr><tr><td>Amoxicillin</td><td>10</td></tr></table>\
+            Printed</body></html>";
+        assert_eq!(
+            parse_blocks(html),
+            vec![
+                Block::Heading("Packing list".to_string()),
+                Block::Paragraph("Store & co".to_string()),
+                Block::Table(vec![
+                    (true, vec!["Item".to_string(), "Packs".to_string()]),
+                    (false, vec!["Amoxicillin".to_string(), "10".to_string()]),
+                ]),
+                Block::Paragraph("Printed".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_wrap() {
+        assert_eq!(wrap("one two three", 7), vec!["one two", "three"]);
+        assert_eq!(wrap("abcdefghij", 4), vec!["abcd", "efgh", "ij"]);
+        assert_eq!(wrap("", 4), vec![""]);
+    }
+
+    #[test]
+    fn test_html_to_pdf() {
+        let rows: String = (0..200)
+            .map(|index| format!("<tr><td>Item {}</td><td>{}</td></tr>", index, index))
+            .collect();
+        let pdf = html_to_pdf(&format!("<h1>Report</h1><table>{}</table>", rows));
+        let pdf = String::from_utf8_lossy(&pdf);
+        assert!(pdf.starts_with("%PDF-"));
+        // 203 lines over pages of LINES_PER_PAGE lines
+        assert_eq!(pdf.matches("/Type /Page\n").count(), 3);
+    }
+}
@@ -0,0 +1,77 @@
+use repository::{
+    schema::{ReportContext, ReportRow},
+    ReportRowRepository, RepositoryError,
+};
+
+use crate::service_provider::ServiceContext;
+
+use super::RenderReportError;
+
+/// Templates shipped with the server, a report row with the same id replaces them
+pub fn built_in_reports() -> Vec<ReportRow> {
+    let report = |id: &str, name: &str, context: ReportContext, template: &str| ReportRow {
+        id: id.to_string(),
+        name: name.to_string(),
+        context,
+        template: template.to_string(),
+    };
+    vec![
+        report(
+            "packing_list",
+            "Packing list",
+            ReportContext::OutboundShipment,
+            include_str!("templates/packing_list.html"),
+        ),
+        report(
+            "delivery_note",
+            "Delivery note",
+            ReportContext::OutboundShipment,
+            include_str!("templates/delivery_note.html"),
+        ),
+        report(
+            "invoice",
+            "Invoice",
+            ReportContext::OutboundShipment,
+            include_str!("templates/invoice.html"),
+        ),
+        report(
+            "goods_received_note",
+            "Goods received note",
+            ReportContext::InboundShipment,
+            include_str!("templates/goods_received_note.html"),
+        ),
+        report(
+            "stock_take_count_sheet",
+            "Stock take count sheet",
+            ReportContext::StockTake,
+            include_str!("templates/stock_take_count_sheet.html"),
+        ),
+    ]
+}
+
+/// Built-in and local reports ordered by name, all contexts if `context` is None
+pub fn get_reports(
+    ctx: &ServiceContext,
+    context: Option<ReportContext>,
+) -> Result<Vec<ReportRow>, RepositoryError> {
+    let mut reports = ReportRowRepository::new(&ctx.connection).find_many_by_context(context)?;
+    for built_in in built_in_reports() {
+        let is_replaced = reports.iter().any(|report| report.id == built_in.id);
+        let is_in_context = context.is_none() || context == Some(built_in.context);
+        if !is_replaced && is_in_context {
+            reports.push(built_in);
+        }
+    }
+    reports.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(reports)
+}
+
+pub fn get_report(ctx: &ServiceContext, report_id: &str) -> Result<ReportRow, RenderReportError> {
+    if let Some(report) = ReportRowRepository::new(&ctx.connection).find_one_by_id(report_id)? {
+        return Ok(report);
+    }
+    built_in_reports()
+        .into_iter()
+        .find(|report| report.id == report_id)
+        .ok_or(RenderReportError::ReportDoesNotExist)
+}
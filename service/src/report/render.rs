@@ -0,0 +1,91 @@
+use std::collections::HashMap;
+
+use domain::invoice::InvoiceType;
+use repository::schema::ReportContext;
+use serde::Serialize;
+use tera::{Context, Tera};
+
+use crate::service_provider::ServiceContext;
+
+use super::{
+    data::{get_invoice_data, get_stock_take_data},
+    pdf::html_to_pdf,
+    query::get_report,
+    RenderReportError, ReportFormat,
+};
+
+/// Name the template is registered under, the .html extension turns on autoescaping
+const TEMPLATE_NAME: &str = "report.html";
+
+/// Renders the report for a record of the store, `record_id` is the id of the invoice or stock
+/// take
+pub fn render_report(
+    ctx: &ServiceContext,
+    store_id: &str,
+    report_id: &str,
+    record_id: &str,
+    format: ReportFormat,
+) -> Result<Vec<u8>, RenderReportError> {
+    let report = get_report(ctx, report_id)?;
+    let connection = &ctx.connection;
+    let html = match report.context {
+        ReportContext::OutboundShipment => render_html(
+            &report.template,
+            &get_invoice_data(
+                connection,
+                store_id,
+                record_id,
+                InvoiceType::OutboundShipment,
+            )?,
+        ),
+        ReportContext::InboundShipment => render_html(
+            &report.template,
+            &get_invoice_data(
+                connection,
+                store_id,
+                record_id,
+                InvoiceType::InboundShipment,
+            )?,
+        ),
+        ReportContext::StockTake => render_html(
+            &report.template,
+            &get_stock_take_data(connection, store_id, record_id)?,
+        ),
+    }?;
+
+    Ok(match format {
+        ReportFormat::Html => html.into_bytes(),
+        ReportFormat::Pdf => html_to_pdf(&html),
+    })
+}
+
+fn template_error(error: tera::Error) -> RenderReportError {
+    // the cause, e.g. the unknown variable, is in the source of the error
+    let mut message = error.to_string();
+    let mut source = std::error::Error::source(&error);
+    while let Some(cause) = source {
+        message = format!("{}: {}", message, cause);
+        source = cause.source();
+    }
+    RenderReportError::TemplateError(message)
+}
+
+/// Formats numbers with two decimals, e.g. prices
+fn money_filter(
+    value: &tera::Value,
+    _: &HashMap<String, tera::Value>,
+) -> tera::Result<tera::Value> {
+    let number = value
+        .as_f64()
+        .ok_or_else(|| tera::Error::msg(format!("money filter expects a number, got {}", value)))?;
+    Ok(tera::Value::String(format!("{:.2}", number)))
+}
+
+pub fn render_html<T: Serialize>(template: &str, data: &T) -> Result<String, RenderReportError> {
+    let mut tera = Tera::default();
+    tera.register_filter("money", money_filter);
+    tera.add_raw_template(TEMPLATE_NAME, template)
+        .map_err(template_error)?;
+    let context = Context::from_serialize(data).map_err(template_error)?;
+    tera.render(TEMPLATE_NAME, &context).map_err(template_error)
+}
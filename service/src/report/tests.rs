@@ -0,0 +1,170 @@
+#[cfg(test)]
+mod report_test {
+    use repository::{
+        mock::{mock_store_a, mock_store_b, MockDataInserts},
+        schema::{ReportContext, ReportRow},
+        test_db::setup_all,
+        ReportRowRepository,
+    };
+
+    use crate::{
+        report::{RenderReportError, ReportFormat},
+        service_provider::ServiceProvider,
+    };
+
+    #[actix_rt::test]
+    async fn get_reports() {
+        let (_, connection, connection_manager, _) =
+            setup_all("get_reports", MockDataInserts::all()).await;
+
+        let service_provider = ServiceProvider::new(connection_manager);
+        let context = service_provider.context().unwrap();
+        let service = service_provider.report_service;
+
+        ReportRowRepository::new(&connection)
+            .upsert_one(&ReportRow {
+                id: "packing_list".to_string(),
+                name: "Local packing list".to_string(),
+                context: ReportContext::OutboundShipment,
+                template: "{{ invoice.invoice_number }}".to_string(),
+            })
+            .unwrap();
+
+        let reports = service
+            .get_reports(&context, Some(ReportContext::OutboundShipment))
+            .unwrap();
+        assert_eq!(
+            reports
+                .iter()
+                .map(|report| report.name.as_str())
+                .collect::<Vec<&str>>(),
+            vec!["Delivery note", "Invoice", "Local packing list"]
+        );
+
+        let reports = service.get_reports(&context, None).unwrap();
+        assert_eq!(reports.len(), 5);
+    }
+
+    #[actix_rt::test]
+    async fn render_report() {
+        let (_, _, connection_manager, _) =
+            setup_all("render_report", MockDataInserts::all()).await;
+
+        let service_provider = ServiceProvider::new(connection_manager);
+        let context = service_provider.context().unwrap();
+        let service = service_provider.report_service;
+        let render = |store_id: &str, report_id: &str, record_id: &str| {
+            service
+                .render_report(&context, store_id, report_id, record_id, ReportFormat::Html)
+                .map(|html| String::from_utf8(html).unwrap())
+        };
+
+        // outbound shipment
+        let html = render(&mock_store_b().id, "packing_list", "outbound_shipment_a").unwrap();
+        assert!(html.contains("<h1>Packing list</h1>"));
+        assert!(html.contains("<td>Store A</td>"));
+        assert!(html.contains("<td>item_a_code</td>"));
+        assert!(html.contains("Location: No location"));
+
+        // totals from invoice_stats
+        let html = render(&mock_store_b().id, "invoice", "outbound_shipment_a").unwrap();
+        assert!(html.contains("<th>Total before tax</th><td class=\"number\">2.61</td>"));
+        assert!(html.contains("<th>Total</th><td class=\"number\">3.00</td>"));
+
+        // lines are grouped by location, lines without location last
+        let html = render(
+            &mock_store_a().id,
+            "goods_received_note",
+            "inbound_shipment_c",
+        )
+        .unwrap();
+        let location = html.find("Location: name_location_1").unwrap();
+        let no_location = html.find("Location: No location").unwrap();
+        assert!(location < no_location);
+
+        // stock take
+        let html = render(&mock_store_a().id, "stock_take_count_sheet", "stock_take_a").unwrap();
+        assert!(html.contains("<td>item_a_code</td>"));
+
+        // pdf
+        let pdf = service
+            .render_report(
+                &context,
+                &mock_store_b().id,
+                "packing_list",
+                "outbound_shipment_a",
+                ReportFormat::Pdf,
+            )
+            .unwrap();
+        assert!(pdf.starts_with(b"%PDF-"));
+
+        // ReportDoesNotExist
+        assert_eq!(
+            render(&mock_store_b().id, "invalid", "outbound_shipment_a"),
+            Err(RenderReportError::ReportDoesNotExist)
+        );
+        // RecordDoesNotExist, shipment of another store
+        assert_eq!(
+            render(&mock_store_a().id, "packing_list", "outbound_shipment_a"),
+            Err(RenderReportError::RecordDoesNotExist)
+        );
+        assert_eq!(
+            render(&mock_store_b().id, "stock_take_count_sheet", "stock_take_a"),
+            Err(RenderReportError::RecordDoesNotExist)
+        );
+        // WrongRecordType
+        assert_eq!(
+            render(&mock_store_a().id, "packing_list", "inbound_shipment_c"),
+            Err(RenderReportError::WrongRecordType)
+        );
+    }
+
+    #[actix_rt::test]
+    async fn render_local_report() {
+        let (_, connection, connection_manager, _) =
+            setup_all("render_local_report", MockDataInserts::all()).await;
+
+        let service_provider = ServiceProvider::new(connection_manager);
+        let context = service_provider.context().unwrap();
+        let service = service_provider.report_service;
+        let repo = ReportRowRepository::new(&connection);
+        let render = |report_id: &str| {
+            service
+                .render_report(
+                    &context,
+                    &mock_store_b().id,
+                    report_id,
+                    "outbound_shipment_a",
+                    ReportFormat::Html,
+                )
+                .map(|html| String::from_utf8(html).unwrap())
+        };
+
+        // replaces the built-in template, values are escaped
+        repo.upsert_one(&ReportRow {
+            id: "packing_list".to_string(),
+            name: "Packing list".to_string(),
+            context: ReportContext::OutboundShipment,
+            template: "{{ invoice.invoice_number }} {{ store.name | upper }} {{ \"<b>\" }}"
+                .to_string(),
+        })
+        .unwrap();
+        assert_eq!(
+            render("packing_list"),
+            Ok("1 STORE B &lt;b&gt;".to_string())
+        );
+
+        // TemplateError
+        repo.upsert_one(&ReportRow {
+            id: "unknown_variable".to_string(),
+            name: "Unknown variable".to_string(),
+            context: ReportContext::OutboundShipment,
+            template: "{{ unknown }}".to_string(),
+        })
+        .unwrap();
+        assert!(matches!(
+            render("unknown_variable"),
+            Err(RenderReportError::TemplateError(message)) if message.contains("unknown")
+        ));
+    }
+}
@@ -0,0 +1,73 @@
+use repository::{InvoiceLineRowRepository, InvoiceRepository, RepositoryError, StorageConnection};
+
+use crate::{
+    invoice::check_invoice_exists_option, service_provider::ServiceContext,
+    validate::check_store_id_matches,
+};
+
+use super::{
+    validate::{check_is_return_type, check_lines_editable},
+    ReturnType,
+};
+
+#[derive(Debug, PartialEq)]
+pub enum DeleteReturnError {
+    DatabaseError(RepositoryError),
+    InvoiceDoesNotExist,
+    NotASupplierReturn,
+    NotACustomerReturn,
+    NotThisStoreInvoice,
+    CannotEditReturn,
+    /// Lines have to be deleted first
+    InvoiceLinesExist,
+}
+
+fn validate(
+    connection: &StorageConnection,
+    store_id: &str,
+    r#type: ReturnType,
+    id: &str,
+) -> Result<(), DeleteReturnError> {
+    let existing = check_invoice_exists_option(id, connection)?
+        .ok_or(DeleteReturnError::InvoiceDoesNotExist)?;
+    if !check_is_return_type(r#type, &existing) {
+        return Err(match r#type {
+            ReturnType::Supplier => DeleteReturnError::NotASupplierReturn,
+            ReturnType::Customer => DeleteReturnError::NotACustomerReturn,
+        });
+    }
+    if !check_store_id_matches(store_id, &existing.store_id) {
+        return Err(DeleteReturnError::NotThisStoreInvoice);
+    }
+    if !check_lines_editable(&existing) {
+        return Err(DeleteReturnError::CannotEditReturn);
+    }
+    if !InvoiceLineRowRepository::new(connection)
+        .find_many_by_invoice_id(id)?
+        .is_empty()
+    {
+        return Err(DeleteReturnError::InvoiceLinesExist);
+    }
+    Ok(())
+}
+
+pub fn delete_return(
+    ctx: &ServiceContext,
+    store_id: &str,
+    r#type: ReturnType,
+    id: &str,
+) -> Result<String, DeleteReturnError> {
+    ctx.connection
+        .transaction_sync(|connection| {
+            validate(connection, store_id, r#type, id)?;
+            InvoiceRepository::new(connection).delete(id)?;
+            Ok(id.to_string())
+        })
+        .map_err(|error| error.to_inner_error())
+}
+
+impl From<RepositoryError> for DeleteReturnError {
+    fn from(error: RepositoryError) -> Self {
+        DeleteReturnError::DatabaseError(error)
+    }
+}
@@ -0,0 +1,92 @@
+use repository::{
+    schema::{InvoiceLineRow, StockLineRow},
+    InvoiceLineRowRepository, RepositoryError, StockLineRowRepository, StorageConnection,
+};
+
+use crate::service_provider::ServiceContext;
+
+use super::{
+    validate::{check_return_lines_editable, ReturnCheckError},
+    ReturnType,
+};
+
+#[derive(Debug, PartialEq)]
+pub enum DeleteReturnLineError {
+    DatabaseError(RepositoryError),
+    LineDoesNotExist,
+    InvoiceDoesNotExist,
+    NotASupplierReturn,
+    NotACustomerReturn,
+    NotThisStoreInvoice,
+    CannotEditReturn,
+}
+
+fn validate(
+    connection: &StorageConnection,
+    store_id: &str,
+    r#type: ReturnType,
+    id: &str,
+) -> Result<InvoiceLineRow, DeleteReturnLineError> {
+    let line = match InvoiceLineRowRepository::new(connection).find_one_by_id(id) {
+        Ok(line) => line,
+        Err(RepositoryError::NotFound) => return Err(DeleteReturnLineError::LineDoesNotExist),
+        Err(error) => return Err(error.into()),
+    };
+    check_return_lines_editable(connection, store_id, r#type, &line.invoice_id)?;
+    Ok(line)
+}
+
+/// Releases the packs reserved by a supplier return line
+fn generate(
+    connection: &StorageConnection,
+    r#type: ReturnType,
+    line: &InvoiceLineRow,
+) -> Result<Option<StockLineRow>, RepositoryError> {
+    let stock_line_id = match (r#type, &line.stock_line_id) {
+        (ReturnType::Supplier, Some(stock_line_id)) => stock_line_id,
+        _ => return Ok(None),
+    };
+    let stock_line = StockLineRowRepository::new(connection).find_one_by_id(stock_line_id)?;
+    Ok(Some(StockLineRow {
+        available_number_of_packs: stock_line.available_number_of_packs + line.number_of_packs,
+        ..stock_line
+    }))
+}
+
+pub fn delete_return_line(
+    ctx: &ServiceContext,
+    store_id: &str,
+    r#type: ReturnType,
+    id: &str,
+) -> Result<String, DeleteReturnLineError> {
+    ctx.connection
+        .transaction_sync(|connection| {
+            let line = validate(connection, store_id, r#type, id)?;
+            if let Some(stock_line) = generate(connection, r#type, &line)? {
+                StockLineRowRepository::new(connection).upsert_one(&stock_line)?;
+            }
+            InvoiceLineRowRepository::new(connection).delete(id)?;
+            Ok(id.to_string())
+        })
+        .map_err(|error| error.to_inner_error())
+}
+
+impl From<RepositoryError> for DeleteReturnLineError {
+    fn from(error: RepositoryError) -> Self {
+        DeleteReturnLineError::DatabaseError(error)
+    }
+}
+
+impl From<ReturnCheckError> for DeleteReturnLineError {
+    fn from(error: ReturnCheckError) -> Self {
+        use DeleteReturnLineError::*;
+        match error {
+            ReturnCheckError::DatabaseError(error) => DatabaseError(error),
+            ReturnCheckError::InvoiceDoesNotExist => InvoiceDoesNotExist,
+            ReturnCheckError::WrongReturnType(ReturnType::Supplier) => NotASupplierReturn,
+            ReturnCheckError::WrongReturnType(ReturnType::Customer) => NotACustomerReturn,
+            ReturnCheckError::NotThisStoreInvoice => NotThisStoreInvoice,
+            ReturnCheckError::CannotEditReturn => CannotEditReturn,
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use chrono::Utc;
+use domain::{invoice::Invoice, name::Name};
+use repository::{
+    schema::{InvoiceRow, InvoiceRowStatus},
+    InvoiceRepository, RepositoryError, StorageConnection,
+};
+
+use crate::{
+    invoice::check_invoice_exists_option, number::next_number, service_provider::ServiceContext,
+    validate::check_store_exists,
+};
+
+use super::{
+    validate::{check_other_party_exists, check_other_party_type, get_return},
+    ReturnType,
+};
+
+pub struct InsertReturn {
+    pub id: String,
+    pub other_party_id: String,
+    pub their_reference: Option<String>,
+    pub comment: Option<String>,
+    pub color: Option<String>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum InsertReturnError {
+    DatabaseError(RepositoryError),
+    InternalError(String),
+    InvoiceAlreadyExists,
+    InvalidStore,
+    OtherPartyDoesNotExist,
+    OtherPartyNotASupplier(Name),
+    OtherPartyNotACustomer(Name),
+}
+
+fn validate(
+    connection: &StorageConnection,
+    store_id: &str,
+    r#type: ReturnType,
+    input: &InsertReturn,
+) -> Result<(), InsertReturnError> {
+    if check_invoice_exists_option(&input.id, connection)?.is_some() {
+        return Err(InsertReturnError::InvoiceAlreadyExists);
+    }
+    if !check_store_exists(connection, store_id)? {
+        return Err(InsertReturnError::InvalidStore);
+    }
+    let other_party = check_other_party_exists(connection, &input.other_party_id)?
+        .ok_or(InsertReturnError::OtherPartyDoesNotExist)?;
+    if !check_other_party_type(r#type, &other_party) {
+        return Err(match r#type {
+            ReturnType::Supplier => InsertReturnError::OtherPartyNotASupplier(other_party),
+            ReturnType::Customer => InsertReturnError::OtherPartyNotACustomer(other_party),
+        });
+    }
+    Ok(())
+}
+
+fn generate(
+    connection: &StorageConnection,
+    store_id: &str,
+    r#type: ReturnType,
+    InsertReturn {
+        id,
+        other_party_id,
+        their_reference,
+        comment,
+        color,
+    }: InsertReturn,
+) -> Result<InvoiceRow, RepositoryError> {
+    Ok(InvoiceRow {
+        id,
+        name_id: other_party_id,
+        store_id: store_id.to_string(),
+        invoice_number: next_number(connection, &r#type.number_type(), store_id)?,
+        r#type: r#type.invoice_type(),
+        status: InvoiceRowStatus::New,
+        on_hold: false,
+        comment,
+        their_reference,
+        created_datetime: Utc::now().naive_utc(),
+        allocated_datetime: None,
+        picked_datetime: None,
+        shipped_datetime: None,
+        delivered_datetime: None,
+        verified_datetime: None,
+        color,
+        prescriber_id: None,
+    })
+}
+
+pub fn insert_return(
+    ctx: &ServiceContext,
+    store_id: &str,
+    r#type: ReturnType,
+    input: InsertReturn,
+) -> Result<Invoice, InsertReturnError> {
+    let result = ctx
+        .connection
+        .transaction_sync(|connection| {
+            validate(connection, store_id, r#type, &input)?;
+            let new_return = generate(connection, store_id, r#type, input)?;
+            InvoiceRepository::new(connection).upsert_one(&new_return)?;
+
+            get_return(connection, &new_return.id)?.ok_or(InsertReturnError::InternalError(
+                "Failed to read the just inserted return!".to_string(),
+            ))
+        })
+        .map_err(|error| error.to_inner_error())?;
+    Ok(result)
+}
+
+impl From<RepositoryError> for InsertReturnError {
+    fn from(error: RepositoryError) -> Self {
+        InsertReturnError::DatabaseError(error)
+    }
+}
@@ -0,0 +1,190 @@
+use domain::invoice_line::InvoiceLine;
+use repository::{
+    schema::{InvoiceLineRow, InvoiceRow, StockLineRow},
+    InvoiceLineRowRepository, ItemRepository, RepositoryError, StockLineRowRepository,
+    StorageConnection,
+};
+
+use crate::{plugin::PluginRejection, service_provider::ServiceContext};
+
+use super::{
+    validate::{
+        check_return_lines_editable, check_return_reason, check_stock_line_exists, get_return_line,
+        ReasonCheckError, ReturnCheckError,
+    },
+    ReturnType,
+};
+
+pub struct InsertReturnLine {
+    pub id: String,
+    pub invoice_id: String,
+    /// The stock line the packs are returned from (supplier return) or to (customer return)
+    pub stock_line_id: String,
+    pub number_of_packs: u32,
+    pub reason_id: Option<String>,
+    pub note: Option<String>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum InsertReturnLineError {
+    DatabaseError(RepositoryError),
+    InternalError(String),
+    LineAlreadyExists,
+    InvoiceDoesNotExist,
+    NotASupplierReturn,
+    NotACustomerReturn,
+    NotThisStoreInvoice,
+    CannotEditReturn,
+    StockLineDoesNotExist,
+    NumberOfPacksBelowOne,
+    /// Not enough available packs in the stock line
+    ReductionBelowZero,
+    ReasonDoesNotExist,
+    NotAReturnReason,
+    RejectedByPlugin(PluginRejection),
+}
+
+fn validate(
+    connection: &StorageConnection,
+    store_id: &str,
+    r#type: ReturnType,
+    input: &InsertReturnLine,
+) -> Result<(InvoiceRow, StockLineRow), InsertReturnLineError> {
+    match InvoiceLineRowRepository::new(connection).find_one_by_id(&input.id) {
+        Ok(_) => return Err(InsertReturnLineError::LineAlreadyExists),
+        Err(RepositoryError::NotFound) => {}
+        Err(error) => return Err(error.into()),
+    };
+    let invoice = check_return_lines_editable(connection, store_id, r#type, &input.invoice_id)?;
+    if input.number_of_packs < 1 {
+        return Err(InsertReturnLineError::NumberOfPacksBelowOne);
+    }
+    let stock_line = check_stock_line_exists(connection, store_id, &input.stock_line_id)?
+        .ok_or(InsertReturnLineError::StockLineDoesNotExist)?;
+    if r#type == ReturnType::Supplier
+        && stock_line.available_number_of_packs < input.number_of_packs as i32
+    {
+        return Err(InsertReturnLineError::ReductionBelowZero);
+    }
+    check_return_reason(connection, &input.reason_id)?;
+    Ok((invoice, stock_line))
+}
+
+/// Supplier return lines reserve the returned packs, customer return lines only add stock once
+/// the return is delivered.
+fn generate(
+    connection: &StorageConnection,
+    r#type: ReturnType,
+    stock_line: StockLineRow,
+    InsertReturnLine {
+        id,
+        invoice_id,
+        stock_line_id,
+        number_of_packs,
+        reason_id,
+        note,
+    }: InsertReturnLine,
+) -> Result<(InvoiceLineRow, Option<StockLineRow>), InsertReturnLineError> {
+    let item = ItemRepository::new(connection)
+        .find_one_by_id(&stock_line.item_id)?
+        .ok_or_else(|| {
+            InsertReturnLineError::InternalError(format!(
+                "Can't find item {} of stock line {}!",
+                stock_line.item_id, stock_line.id
+            ))
+        })?;
+    let number_of_packs = number_of_packs as i32;
+    let price_per_pack = match r#type {
+        ReturnType::Supplier => stock_line.cost_price_per_pack,
+        ReturnType::Customer => stock_line.sell_price_per_pack,
+    };
+    let total = price_per_pack * number_of_packs as f64;
+    let new_line = InvoiceLineRow {
+        id,
+        invoice_id,
+        item_id: item.id,
+        item_name: item.name,
+        item_code: item.code,
+        stock_line_id: Some(stock_line_id),
+        location_id: stock_line.location_id.clone(),
+        batch: stock_line.batch.clone(),
+        expiry_date: stock_line.expiry_date,
+        pack_size: stock_line.pack_size,
+        cost_price_per_pack: stock_line.cost_price_per_pack,
+        sell_price_per_pack: stock_line.sell_price_per_pack,
+        total_before_tax: total,
+        total_after_tax: total,
+        tax: None,
+        r#type: r#type.line_type(),
+        number_of_packs,
+        note,
+        return_reason_id: reason_id,
+        directions: None,
+    };
+    let stock_line = match r#type {
+        ReturnType::Supplier => Some(StockLineRow {
+            available_number_of_packs: stock_line.available_number_of_packs - number_of_packs,
+            ..stock_line
+        }),
+        ReturnType::Customer => None,
+    };
+    Ok((new_line, stock_line))
+}
+
+pub fn insert_return_line(
+    ctx: &ServiceContext,
+    store_id: &str,
+    r#type: ReturnType,
+    input: InsertReturnLine,
+) -> Result<InvoiceLine, InsertReturnLineError> {
+    let result = ctx
+        .connection
+        .transaction_sync(|connection| {
+            let (invoice, stock_line) = validate(connection, store_id, r#type, &input)?;
+            let (new_line, stock_line) = generate(connection, r#type, stock_line, input)?;
+            ctx.plugins
+                .validate_invoice_line(connection, &invoice, &new_line)
+                .map_err(InsertReturnLineError::RejectedByPlugin)?;
+            InvoiceLineRowRepository::new(connection).upsert_one(&new_line)?;
+            if let Some(stock_line) = stock_line {
+                StockLineRowRepository::new(connection).upsert_one(&stock_line)?;
+            }
+
+            get_return_line(connection, &new_line.id)?.ok_or(InsertReturnLineError::InternalError(
+                "Failed to read the just inserted return line!".to_string(),
+            ))
+        })
+        .map_err(|error| error.to_inner_error())?;
+    Ok(result)
+}
+
+impl From<RepositoryError> for InsertReturnLineError {
+    fn from(error: RepositoryError) -> Self {
+        InsertReturnLineError::DatabaseError(error)
+    }
+}
+
+impl From<ReturnCheckError> for InsertReturnLineError {
+    fn from(error: ReturnCheckError) -> Self {
+        use InsertReturnLineError::*;
+        match error {
+            ReturnCheckError::DatabaseError(error) => DatabaseError(error),
+            ReturnCheckError::InvoiceDoesNotExist => InvoiceDoesNotExist,
+            ReturnCheckError::WrongReturnType(ReturnType::Supplier) => NotASupplierReturn,
+            ReturnCheckError::WrongReturnType(ReturnType::Customer) => NotACustomerReturn,
+            ReturnCheckError::NotThisStoreInvoice => NotThisStoreInvoice,
+            ReturnCheckError::CannotEditReturn => CannotEditReturn,
+        }
+    }
+}
+
+impl From<ReasonCheckError> for InsertReturnLineError {
+    fn from(error: ReasonCheckError) -> Self {
+        use InsertReturnLineError::*;
+        match error {
+            ReasonCheckError::DatabaseError(error) => DatabaseError(error),
+            ReasonCheckError::ReasonDoesNotExist => ReasonDoesNotExist,
+            ReasonCheckError::NotAReturnReason => NotAReturnReason,
+        }
+    }
+}
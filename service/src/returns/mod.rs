@@ -0,0 +1,179 @@
+use domain::{invoice::Invoice, invoice_line::InvoiceLine};
+use repository::{
+    schema::{InvoiceLineRowType, InvoiceRowType, NumberRowType, ReasonRow, ReasonRowType},
+    ReasonRowRepository, RepositoryError,
+};
+
+use crate::service_provider::ServiceContext;
+
+use self::{
+    delete::{delete_return, DeleteReturnError},
+    delete_line::{delete_return_line, DeleteReturnLineError},
+    insert::{insert_return, InsertReturn, InsertReturnError},
+    insert_line::{insert_return_line, InsertReturnLine, InsertReturnLineError},
+    update::{
+        update_customer_return, update_supplier_return, UpdateCustomerReturn, UpdateReturnError,
+        UpdateSupplierReturn,
+    },
+    update_line::{update_return_line, UpdateReturnLine, UpdateReturnLineError},
+};
+
+pub mod delete;
+pub mod delete_line;
+pub mod insert;
+pub mod insert_line;
+pub mod update;
+pub mod update_line;
+pub mod validate;
+
+#[cfg(test)]
+mod tests;
+
+/// Supplier returns send stock back to a supplier, customer returns take stock back from a
+/// customer.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ReturnType {
+    Supplier,
+    Customer,
+}
+
+impl ReturnType {
+    pub fn invoice_type(&self) -> InvoiceRowType {
+        match self {
+            ReturnType::Supplier => InvoiceRowType::SupplierReturn,
+            ReturnType::Customer => InvoiceRowType::CustomerReturn,
+        }
+    }
+
+    pub fn number_type(&self) -> NumberRowType {
+        match self {
+            ReturnType::Supplier => NumberRowType::SupplierReturn,
+            ReturnType::Customer => NumberRowType::CustomerReturn,
+        }
+    }
+
+    pub fn line_type(&self) -> InvoiceLineRowType {
+        match self {
+            ReturnType::Supplier => InvoiceLineRowType::StockOut,
+            ReturnType::Customer => InvoiceLineRowType::StockIn,
+        }
+    }
+}
+
+pub trait ReturnServiceTrait: Sync + Send {
+    /// Active reasons for returning stock, ordered by reason
+    fn get_return_reasons(&self, ctx: &ServiceContext) -> Result<Vec<ReasonRow>, RepositoryError> {
+        ReasonRowRepository::new(&ctx.connection).find_active_by_type(ReasonRowType::ReturnReason)
+    }
+
+    fn insert_supplier_return(
+        &self,
+        ctx: &ServiceContext,
+        store_id: &str,
+        input: InsertReturn,
+    ) -> Result<Invoice, InsertReturnError> {
+        insert_return(ctx, store_id, ReturnType::Supplier, input)
+    }
+
+    fn update_supplier_return(
+        &self,
+        ctx: &ServiceContext,
+        store_id: &str,
+        input: UpdateSupplierReturn,
+    ) -> Result<Invoice, UpdateReturnError> {
+        update_supplier_return(ctx, store_id, input)
+    }
+
+    fn delete_supplier_return(
+        &self,
+        ctx: &ServiceContext,
+        store_id: &str,
+        id: &str,
+    ) -> Result<String, DeleteReturnError> {
+        delete_return(ctx, store_id, ReturnType::Supplier, id)
+    }
+
+    fn insert_supplier_return_line(
+        &self,
+        ctx: &ServiceContext,
+        store_id: &str,
+        input: InsertReturnLine,
+    ) -> Result<InvoiceLine, InsertReturnLineError> {
+        insert_return_line(ctx, store_id, ReturnType::Supplier, input)
+    }
+
+    fn update_supplier_return_line(
+        &self,
+        ctx: &ServiceContext,
+        store_id: &str,
+        input: UpdateReturnLine,
+    ) -> Result<InvoiceLine, UpdateReturnLineError> {
+        update_return_line(ctx, store_id, ReturnType::Supplier, input)
+    }
+
+    fn delete_supplier_return_line(
+        &self,
+        ctx: &ServiceContext,
+        store_id: &str,
+        id: &str,
+    ) -> Result<String, DeleteReturnLineError> {
+        delete_return_line(ctx, store_id, ReturnType::Supplier, id)
+    }
+
+    fn insert_customer_return(
+        &self,
+        ctx: &ServiceContext,
+        store_id: &str,
+        input: InsertReturn,
+    ) -> Result<Invoice, InsertReturnError> {
+        insert_return(ctx, store_id, ReturnType::Customer, input)
+    }
+
+    fn update_customer_return(
+        &self,
+        ctx: &ServiceContext,
+        store_id: &str,
+        input: UpdateCustomerReturn,
+    ) -> Result<Invoice, UpdateReturnError> {
+        update_customer_return(ctx, store_id, input)
+    }
+
+    fn delete_customer_return(
+        &self,
+        ctx: &ServiceContext,
+        store_id: &str,
+        id: &str,
+    ) -> Result<String, DeleteReturnError> {
+        delete_return(ctx, store_id, ReturnType::Customer, id)
+    }
+
+    fn insert_customer_return_line(
+        &self,
+        ctx: &ServiceContext,
+        store_id: &str,
+        input: InsertReturnLine,
+    ) -> Result<InvoiceLine, InsertReturnLineError> {
+        insert_return_line(ctx, store_id, ReturnType::Customer, input)
+    }
+
+    fn update_customer_return_line(
+        &self,
+        ctx: &ServiceContext,
+        store_id: &str,
+        input: UpdateReturnLine,
+    ) -> Result<InvoiceLine, UpdateReturnLineError> {
+        update_return_line(ctx, store_id, ReturnType::Customer, input)
+    }
+
+    fn delete_customer_return_line(
+        &self,
+        ctx: &ServiceContext,
+        store_id: &str,
+        id: &str,
+    ) -> Result<String, DeleteReturnLineError> {
+        delete_return_line(ctx, store_id, ReturnType::Customer, id)
+    }
+}
+
+pub struct ReturnService {}
+impl ReturnServiceTrait for ReturnService {}
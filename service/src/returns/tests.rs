@@ -0,0 +1,464 @@
+#[cfg(test)]
+mod return_test {
+    use domain::{
+        invoice::{InvoiceStatus, InvoiceType},
+        invoice_line::InvoiceLineType,
+    };
+    use repository::{
+        mock::{
+            mock_name_a, mock_name_store_a, mock_stock_line_a, mock_store_a, mock_store_b,
+            MockDataInserts,
+        },
+        schema::{ReasonRow, ReasonRowType, StockLineRow},
+        test_db::setup_all,
+        InvoiceLineRowRepository, ReasonRowRepository, StockLineRowRepository, StorageConnection,
+    };
+
+    use crate::{
+        returns::{
+            delete::DeleteReturnError,
+            delete_line::DeleteReturnLineError,
+            insert::{InsertReturn, InsertReturnError},
+            insert_line::{InsertReturnLine, InsertReturnLineError},
+            update::{
+                CustomerReturnStatus, SupplierReturnStatus, UpdateCustomerReturn,
+                UpdateReturnError, UpdateSupplierReturn,
+            },
+            update_line::{UpdateReturnLine, UpdateReturnLineError},
+        },
+        service_provider::ServiceProvider,
+    };
+
+    fn insert_input(id: &str, other_party_id: &str) -> InsertReturn {
+        InsertReturn {
+            id: id.to_string(),
+            other_party_id: other_party_id.to_string(),
+            their_reference: None,
+            comment: None,
+            color: None,
+        }
+    }
+
+    fn line_input(id: &str, invoice_id: &str, number_of_packs: u32) -> InsertReturnLine {
+        InsertReturnLine {
+            id: id.to_string(),
+            invoice_id: invoice_id.to_string(),
+            stock_line_id: mock_stock_line_a().id,
+            number_of_packs,
+            reason_id: Some("damaged".to_string()),
+            note: None,
+        }
+    }
+
+    fn insert_reasons(connection: &StorageConnection) {
+        let repository = ReasonRowRepository::new(connection);
+        repository
+            .upsert_one(&ReasonRow {
+                id: "damaged".to_string(),
+                r#type: ReasonRowType::ReturnReason,
+                reason: "Damaged".to_string(),
+                is_active: true,
+            })
+            .unwrap();
+        repository
+            .upsert_one(&ReasonRow {
+                id: "found".to_string(),
+                r#type: ReasonRowType::PositiveInventoryAdjustment,
+                reason: "Found".to_string(),
+                is_active: true,
+            })
+            .unwrap();
+    }
+
+    fn stock_line_a(connection: &StorageConnection) -> StockLineRow {
+        StockLineRowRepository::new(connection)
+            .find_one_by_id(&mock_stock_line_a().id)
+            .unwrap()
+    }
+
+    #[actix_rt::test]
+    async fn supplier_return() {
+        let (_, connection, connection_manager, _) =
+            setup_all("supplier_return", MockDataInserts::all()).await;
+        insert_reasons(&connection);
+        let service_provider = ServiceProvider::new(connection_manager);
+        let context = service_provider.context().unwrap();
+        let service = &service_provider.return_service;
+        let store_id = &mock_store_a().id;
+
+        // OtherPartyDoesNotExist
+        assert_eq!(
+            service.insert_supplier_return(&context, store_id, insert_input("return", "invalid")),
+            Err(InsertReturnError::OtherPartyDoesNotExist)
+        );
+        // OtherPartyNotASupplier
+        assert!(matches!(
+            service.insert_supplier_return(
+                &context,
+                store_id,
+                insert_input("return", &mock_name_store_a().id)
+            ),
+            Err(InsertReturnError::OtherPartyNotASupplier(_))
+        ));
+
+        let supplier_return = service
+            .insert_supplier_return(
+                &context,
+                store_id,
+                insert_input("return", &mock_name_a().id),
+            )
+            .unwrap();
+        assert_eq!(supplier_return.r#type, InvoiceType::SupplierReturn);
+        assert_eq!(supplier_return.status, InvoiceStatus::New);
+        // InvoiceAlreadyExists
+        assert_eq!(
+            service.insert_supplier_return(
+                &context,
+                store_id,
+                insert_input("return", &mock_name_a().id)
+            ),
+            Err(InsertReturnError::InvoiceAlreadyExists)
+        );
+
+        // line errors
+        let before = stock_line_a(&connection);
+        assert_eq!(
+            service.insert_supplier_return_line(
+                &context,
+                store_id,
+                InsertReturnLine {
+                    stock_line_id: "invalid".to_string(),
+                    ..line_input("line", "return", 1)
+                }
+            ),
+            Err(InsertReturnLineError::StockLineDoesNotExist)
+        );
+        assert_eq!(
+            service.insert_supplier_return_line(
+                &context,
+                &mock_store_b().id,
+                line_input("line", "return", 1)
+            ),
+            Err(InsertReturnLineError::NotThisStoreInvoice)
+        );
+        assert_eq!(
+            service.insert_customer_return_line(
+                &context,
+                store_id,
+                line_input("line", "return", 1)
+            ),
+            Err(InsertReturnLineError::NotACustomerReturn)
+        );
+        assert_eq!(
+            service.insert_supplier_return_line(
+                &context,
+                store_id,
+                line_input("line", "return", 0)
+            ),
+            Err(InsertReturnLineError::NumberOfPacksBelowOne)
+        );
+        assert_eq!(
+            service.insert_supplier_return_line(
+                &context,
+                store_id,
+                line_input(
+                    "line",
+                    "return",
+                    before.available_number_of_packs as u32 + 1
+                )
+            ),
+            Err(InsertReturnLineError::ReductionBelowZero)
+        );
+        assert_eq!(
+            service.insert_supplier_return_line(
+                &context,
+                store_id,
+                InsertReturnLine {
+                    reason_id: Some("invalid".to_string()),
+                    ..line_input("line", "return", 1)
+                }
+            ),
+            Err(InsertReturnLineError::ReasonDoesNotExist)
+        );
+        assert_eq!(
+            service.insert_supplier_return_line(
+                &context,
+                store_id,
+                InsertReturnLine {
+                    reason_id: Some("found".to_string()),
+                    ..line_input("line", "return", 1)
+                }
+            ),
+            Err(InsertReturnLineError::NotAReturnReason)
+        );
+
+        // the returned packs are reserved
+        let line = service
+            .insert_supplier_return_line(&context, store_id, line_input("line", "return", 10))
+            .unwrap();
+        assert_eq!(line.r#type, InvoiceLineType::StockOut);
+        assert_eq!(line.return_reason_id, Some("damaged".to_string()));
+        let stock_line = stock_line_a(&connection);
+        assert_eq!(
+            stock_line.available_number_of_packs,
+            before.available_number_of_packs - 10
+        );
+        assert_eq!(
+            stock_line.total_number_of_packs,
+            before.total_number_of_packs
+        );
+
+        service
+            .update_supplier_return_line(
+                &context,
+                store_id,
+                UpdateReturnLine {
+                    id: "line".to_string(),
+                    number_of_packs: Some(5),
+                    reason_id: None,
+                    note: Some("Broken seal".to_string()),
+                },
+            )
+            .unwrap();
+        assert_eq!(
+            stock_line_a(&connection).available_number_of_packs,
+            before.available_number_of_packs - 5
+        );
+
+        // the invoice has lines
+        assert_eq!(
+            service.delete_supplier_return(&context, store_id, "return"),
+            Err(DeleteReturnError::InvoiceLinesExist)
+        );
+
+        // picking takes the packs out of the store
+        let supplier_return = service
+            .update_supplier_return(
+                &context,
+                store_id,
+                UpdateSupplierReturn {
+                    id: "return".to_string(),
+                    status: Some(SupplierReturnStatus::Picked),
+                    on_hold: None,
+                    comment: None,
+                    their_reference: None,
+                    color: None,
+                },
+            )
+            .unwrap();
+        assert_eq!(supplier_return.status, InvoiceStatus::Picked);
+        let stock_line = stock_line_a(&connection);
+        assert_eq!(
+            stock_line.available_number_of_packs,
+            before.available_number_of_packs - 5
+        );
+        assert_eq!(
+            stock_line.total_number_of_packs,
+            before.total_number_of_packs - 5
+        );
+
+        // lines are fixed once picked
+        assert_eq!(
+            service.update_supplier_return_line(
+                &context,
+                store_id,
+                UpdateReturnLine {
+                    id: "line".to_string(),
+                    number_of_packs: Some(1),
+                    reason_id: None,
+                    note: None,
+                },
+            ),
+            Err(UpdateReturnLineError::CannotEditReturn)
+        );
+        assert_eq!(
+            service.delete_supplier_return_line(&context, store_id, "line"),
+            Err(DeleteReturnLineError::CannotEditReturn)
+        );
+
+        // shipping doesn't change the stock again
+        service
+            .update_supplier_return(
+                &context,
+                store_id,
+                UpdateSupplierReturn {
+                    id: "return".to_string(),
+                    status: Some(SupplierReturnStatus::Shipped),
+                    on_hold: None,
+                    comment: None,
+                    their_reference: None,
+                    color: None,
+                },
+            )
+            .unwrap();
+        assert_eq!(stock_line_a(&connection), stock_line);
+        assert_eq!(
+            service.update_supplier_return(
+                &context,
+                store_id,
+                UpdateSupplierReturn {
+                    id: "return".to_string(),
+                    status: None,
+                    on_hold: None,
+                    comment: Some("comment".to_string()),
+                    their_reference: None,
+                    color: None,
+                },
+            ),
+            Err(UpdateReturnError::CannotEditReturn)
+        );
+    }
+
+    #[actix_rt::test]
+    async fn supplier_return_delete() {
+        let (_, connection, connection_manager, _) =
+            setup_all("supplier_return_delete", MockDataInserts::all()).await;
+        insert_reasons(&connection);
+        let service_provider = ServiceProvider::new(connection_manager);
+        let context = service_provider.context().unwrap();
+        let service = &service_provider.return_service;
+        let store_id = &mock_store_a().id;
+
+        let before = stock_line_a(&connection);
+        service
+            .insert_supplier_return(
+                &context,
+                store_id,
+                insert_input("return", &mock_name_a().id),
+            )
+            .unwrap();
+        service
+            .insert_supplier_return_line(&context, store_id, line_input("line", "return", 10))
+            .unwrap();
+
+        // deleting the line releases the reserved packs
+        assert_eq!(
+            service.delete_supplier_return_line(&context, store_id, "line"),
+            Ok("line".to_string())
+        );
+        assert_eq!(stock_line_a(&connection), before);
+        assert_eq!(
+            service.delete_supplier_return_line(&context, store_id, "line"),
+            Err(DeleteReturnLineError::LineDoesNotExist)
+        );
+
+        assert_eq!(
+            service.delete_customer_return(&context, store_id, "return"),
+            Err(DeleteReturnError::NotACustomerReturn)
+        );
+        assert_eq!(
+            service.delete_supplier_return(&context, store_id, "return"),
+            Ok("return".to_string())
+        );
+        assert_eq!(
+            service.delete_supplier_return(&context, store_id, "return"),
+            Err(DeleteReturnError::InvoiceDoesNotExist)
+        );
+    }
+
+    #[actix_rt::test]
+    async fn customer_return() {
+        let (_, connection, connection_manager, _) =
+            setup_all("customer_return", MockDataInserts::all()).await;
+        insert_reasons(&connection);
+        let service_provider = ServiceProvider::new(connection_manager);
+        let context = service_provider.context().unwrap();
+        let service = &service_provider.return_service;
+        let store_id = &mock_store_a().id;
+
+        // OtherPartyNotACustomer
+        assert!(matches!(
+            service.insert_customer_return(
+                &context,
+                store_id,
+                insert_input("return", &mock_name_a().id)
+            ),
+            Err(InsertReturnError::OtherPartyNotACustomer(_))
+        ));
+        let customer_return = service
+            .insert_customer_return(
+                &context,
+                store_id,
+                insert_input("return", &mock_name_store_a().id),
+            )
+            .unwrap();
+        assert_eq!(customer_return.r#type, InvoiceType::CustomerReturn);
+
+        // the stock only changes once the return is delivered
+        let before = stock_line_a(&connection);
+        let line = service
+            .insert_customer_return_line(&context, store_id, line_input("line", "return", 5))
+            .unwrap();
+        assert_eq!(line.r#type, InvoiceLineType::StockIn);
+        assert_eq!(stock_line_a(&connection), before);
+        assert_eq!(
+            service.update_supplier_return(
+                &context,
+                store_id,
+                UpdateSupplierReturn {
+                    id: "return".to_string(),
+                    status: Some(SupplierReturnStatus::Picked),
+                    on_hold: None,
+                    comment: None,
+                    their_reference: None,
+                    color: None,
+                },
+            ),
+            Err(UpdateReturnError::NotASupplierReturn)
+        );
+
+        let customer_return = service
+            .update_customer_return(
+                &context,
+                store_id,
+                UpdateCustomerReturn {
+                    id: "return".to_string(),
+                    status: Some(CustomerReturnStatus::Delivered),
+                    on_hold: None,
+                    comment: None,
+                    their_reference: None,
+                    color: None,
+                },
+            )
+            .unwrap();
+        assert_eq!(customer_return.status, InvoiceStatus::Delivered);
+        assert!(customer_return.delivered_datetime.is_some());
+        let stock_line = stock_line_a(&connection);
+        assert_eq!(
+            stock_line.available_number_of_packs,
+            before.available_number_of_packs + 5
+        );
+        assert_eq!(
+            stock_line.total_number_of_packs,
+            before.total_number_of_packs + 5
+        );
+        assert_eq!(
+            InvoiceLineRowRepository::new(&connection)
+                .find_one_by_id("line")
+                .unwrap()
+                .number_of_packs,
+            5
+        );
+
+        // verifying doesn't change the stock again
+        service
+            .update_customer_return(
+                &context,
+                store_id,
+                UpdateCustomerReturn {
+                    id: "return".to_string(),
+                    status: Some(CustomerReturnStatus::Verified),
+                    on_hold: None,
+                    comment: None,
+                    their_reference: None,
+                    color: None,
+                },
+            )
+            .unwrap();
+        assert_eq!(stock_line_a(&connection), stock_line);
+        assert_eq!(
+            service.delete_customer_return(&context, store_id, "return"),
+            Err(DeleteReturnError::CannotEditReturn)
+        );
+    }
+}
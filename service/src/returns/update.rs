@@ -0,0 +1,292 @@
+use std::collections::HashMap;
+
+use chrono::{NaiveDateTime, Utc};
+use domain::invoice::{Invoice, InvoiceStatus};
+use repository::{
+    schema::{InvoiceRow, InvoiceRowStatus, StockLineRow},
+    InvoiceLineRowRepository, InvoiceRepository, RepositoryError, StockLineRowRepository,
+    StorageConnection,
+};
+
+use crate::{
+    invoice::{
+        check_invoice_exists_option, check_invoice_is_editable, check_invoice_status,
+        InvoiceStatusError,
+    },
+    plugin::PluginRejection,
+    service_provider::ServiceContext,
+    validate::check_store_id_matches,
+};
+
+use super::{
+    validate::{check_is_return_type, get_return},
+    ReturnType,
+};
+
+pub enum SupplierReturnStatus {
+    Picked,
+    Shipped,
+}
+
+pub enum CustomerReturnStatus {
+    Delivered,
+    Verified,
+}
+
+pub struct UpdateSupplierReturn {
+    pub id: String,
+    pub status: Option<SupplierReturnStatus>,
+    pub on_hold: Option<bool>,
+    pub comment: Option<String>,
+    pub their_reference: Option<String>,
+    pub color: Option<String>,
+}
+
+pub struct UpdateCustomerReturn {
+    pub id: String,
+    pub status: Option<CustomerReturnStatus>,
+    pub on_hold: Option<bool>,
+    pub comment: Option<String>,
+    pub their_reference: Option<String>,
+    pub color: Option<String>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum UpdateReturnError {
+    DatabaseError(RepositoryError),
+    InternalError(String),
+    InvoiceDoesNotExist,
+    NotASupplierReturn,
+    NotACustomerReturn,
+    NotThisStoreInvoice,
+    CannotEditReturn,
+    CannotReverseInvoiceStatus,
+    CannotChangeStatusOfInvoiceOnHold,
+    RejectedByPlugin(PluginRejection),
+}
+
+struct UpdateReturn {
+    id: String,
+    status: Option<InvoiceRowStatus>,
+    on_hold: Option<bool>,
+    comment: Option<String>,
+    their_reference: Option<String>,
+    color: Option<String>,
+}
+
+fn validate(
+    connection: &StorageConnection,
+    store_id: &str,
+    r#type: ReturnType,
+    input: &UpdateReturn,
+) -> Result<InvoiceRow, UpdateReturnError> {
+    let existing = check_invoice_exists_option(&input.id, connection)?
+        .ok_or(UpdateReturnError::InvoiceDoesNotExist)?;
+    if !check_is_return_type(r#type, &existing) {
+        return Err(match r#type {
+            ReturnType::Supplier => UpdateReturnError::NotASupplierReturn,
+            ReturnType::Customer => UpdateReturnError::NotACustomerReturn,
+        });
+    }
+    if !check_store_id_matches(store_id, &existing.store_id) {
+        return Err(UpdateReturnError::NotThisStoreInvoice);
+    }
+    if check_invoice_is_editable(&existing).is_err() {
+        return Err(UpdateReturnError::CannotEditReturn);
+    }
+    let status = input.status.clone().map(InvoiceStatus::from);
+    check_invoice_status(&existing, status, &input.on_hold).map_err(|error| match error {
+        InvoiceStatusError::CannotReverseInvoiceStatus => {
+            UpdateReturnError::CannotReverseInvoiceStatus
+        }
+        InvoiceStatusError::CannotChangeStatusOfInvoiceOnHold => {
+            UpdateReturnError::CannotChangeStatusOfInvoiceOnHold
+        }
+    })?;
+    Ok(existing)
+}
+
+fn generate_invoice(r#type: ReturnType, existing: InvoiceRow, input: UpdateReturn) -> InvoiceRow {
+    let now = Utc::now().naive_utc();
+    let status = input.status.unwrap_or(existing.status.clone());
+    let reached = |target: InvoiceRowStatus| {
+        InvoiceStatus::from(status.clone()).index() >= InvoiceStatus::from(target).index()
+    };
+    let (picked, shipped, delivered, verified) = match r#type {
+        ReturnType::Supplier => (
+            reached(InvoiceRowStatus::Picked),
+            reached(InvoiceRowStatus::Shipped),
+            false,
+            false,
+        ),
+        ReturnType::Customer => (
+            false,
+            false,
+            reached(InvoiceRowStatus::Delivered),
+            reached(InvoiceRowStatus::Verified),
+        ),
+    };
+    let set_datetime = |existing: Option<NaiveDateTime>, reached: bool| match existing {
+        None if reached => Some(now),
+        existing => existing,
+    };
+    let picked_datetime = set_datetime(existing.picked_datetime, picked);
+    let shipped_datetime = set_datetime(existing.shipped_datetime, shipped);
+    let delivered_datetime = set_datetime(existing.delivered_datetime, delivered);
+    let verified_datetime = set_datetime(existing.verified_datetime, verified);
+
+    InvoiceRow {
+        status,
+        on_hold: input.on_hold.unwrap_or(existing.on_hold),
+        comment: input.comment.or(existing.comment),
+        their_reference: input.their_reference.or(existing.their_reference),
+        color: input.color.or(existing.color),
+        picked_datetime,
+        shipped_datetime,
+        delivered_datetime,
+        verified_datetime,
+        ..existing
+    }
+}
+
+/// Stock leaves the store when a supplier return is picked (the available packs have already
+/// been reserved by the lines) and enters the store when a customer return is delivered.
+fn generate_stock_lines(
+    connection: &StorageConnection,
+    r#type: ReturnType,
+    invoice_id: &str,
+) -> Result<Vec<StockLineRow>, UpdateReturnError> {
+    let lines = InvoiceLineRowRepository::new(connection).find_many_by_invoice_id(invoice_id)?;
+    let repository = StockLineRowRepository::new(connection);
+    let mut stock_lines: HashMap<String, StockLineRow> = HashMap::new();
+    for line in lines {
+        let stock_line_id = line.stock_line_id.clone().ok_or_else(|| {
+            UpdateReturnError::InternalError(format!("Return line {} has no stock line", line.id))
+        })?;
+        let stock_line = match stock_lines.remove(&stock_line_id) {
+            Some(stock_line) => stock_line,
+            None => repository.find_one_by_id(&stock_line_id)?,
+        };
+        let stock_line = match r#type {
+            ReturnType::Supplier => StockLineRow {
+                total_number_of_packs: stock_line.total_number_of_packs - line.number_of_packs,
+                ..stock_line
+            },
+            ReturnType::Customer => StockLineRow {
+                available_number_of_packs: stock_line.available_number_of_packs
+                    + line.number_of_packs,
+                total_number_of_packs: stock_line.total_number_of_packs + line.number_of_packs,
+                ..stock_line
+            },
+        };
+        stock_lines.insert(stock_line_id, stock_line);
+    }
+    Ok(stock_lines
+        .into_iter()
+        .map(|(_, stock_line)| stock_line)
+        .collect())
+}
+
+fn update_return(
+    ctx: &ServiceContext,
+    store_id: &str,
+    r#type: ReturnType,
+    input: UpdateReturn,
+) -> Result<Invoice, UpdateReturnError> {
+    let (result, updated, previous_status) = ctx
+        .connection
+        .transaction_sync(|connection| {
+            let existing = validate(connection, store_id, r#type, &input)?;
+            let previous_status = existing.status.clone();
+            let updated = generate_invoice(r#type, existing.clone(), input);
+            if updated.status != previous_status {
+                ctx.plugins
+                    .before_invoice_status_change(connection, &existing, &updated.status)
+                    .map_err(UpdateReturnError::RejectedByPlugin)?;
+            }
+            if previous_status == InvoiceRowStatus::New && updated.status != InvoiceRowStatus::New {
+                let repository = StockLineRowRepository::new(connection);
+                for stock_line in generate_stock_lines(connection, r#type, &updated.id)? {
+                    repository.upsert_one(&stock_line)?;
+                }
+            }
+            InvoiceRepository::new(connection).upsert_one(&updated)?;
+
+            let result =
+                get_return(connection, &updated.id)?.ok_or(UpdateReturnError::InternalError(
+                    "Failed to read the just updated return!".to_string(),
+                ))?;
+            Ok::<(Invoice, InvoiceRow, InvoiceRowStatus), UpdateReturnError>((
+                result,
+                updated,
+                previous_status,
+            ))
+        })
+        .map_err(|error| error.to_inner_error())?;
+
+    if updated.status != previous_status {
+        ctx.plugins
+            .after_invoice_status_change(&ctx.connection, &updated, &previous_status);
+    }
+    Ok(result)
+}
+
+pub fn update_supplier_return(
+    ctx: &ServiceContext,
+    store_id: &str,
+    UpdateSupplierReturn {
+        id,
+        status,
+        on_hold,
+        comment,
+        their_reference,
+        color,
+    }: UpdateSupplierReturn,
+) -> Result<Invoice, UpdateReturnError> {
+    let status = status.map(|status| match status {
+        SupplierReturnStatus::Picked => InvoiceRowStatus::Picked,
+        SupplierReturnStatus::Shipped => InvoiceRowStatus::Shipped,
+    });
+    let input = UpdateReturn {
+        id,
+        status,
+        on_hold,
+        comment,
+        their_reference,
+        color,
+    };
+    update_return(ctx, store_id, ReturnType::Supplier, input)
+}
+
+pub fn update_customer_return(
+    ctx: &ServiceContext,
+    store_id: &str,
+    UpdateCustomerReturn {
+        id,
+        status,
+        on_hold,
+        comment,
+        their_reference,
+        color,
+    }: UpdateCustomerReturn,
+) -> Result<Invoice, UpdateReturnError> {
+    let status = status.map(|status| match status {
+        CustomerReturnStatus::Delivered => InvoiceRowStatus::Delivered,
+        CustomerReturnStatus::Verified => InvoiceRowStatus::Verified,
+    });
+    let input = UpdateReturn {
+        id,
+        status,
+        on_hold,
+        comment,
+        their_reference,
+        color,
+    };
+    update_return(ctx, store_id, ReturnType::Customer, input)
+}
+
+impl From<RepositoryError> for UpdateReturnError {
+    fn from(error: RepositoryError) -> Self {
+        UpdateReturnError::DatabaseError(error)
+    }
+}
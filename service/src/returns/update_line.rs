@@ -0,0 +1,166 @@
+use domain::invoice_line::InvoiceLine;
+use repository::{
+    schema::{InvoiceLineRow, InvoiceRow, StockLineRow},
+    InvoiceLineRowRepository, RepositoryError, StockLineRowRepository, StorageConnection,
+};
+
+use crate::{plugin::PluginRejection, service_provider::ServiceContext};
+
+use super::{
+    validate::{
+        check_return_lines_editable, check_return_reason, get_return_line, ReasonCheckError,
+        ReturnCheckError,
+    },
+    ReturnType,
+};
+
+pub struct UpdateReturnLine {
+    pub id: String,
+    pub number_of_packs: Option<u32>,
+    pub reason_id: Option<String>,
+    pub note: Option<String>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum UpdateReturnLineError {
+    DatabaseError(RepositoryError),
+    InternalError(String),
+    LineDoesNotExist,
+    InvoiceDoesNotExist,
+    NotASupplierReturn,
+    NotACustomerReturn,
+    NotThisStoreInvoice,
+    CannotEditReturn,
+    NumberOfPacksBelowOne,
+    /// Not enough available packs in the stock line
+    ReductionBelowZero,
+    ReasonDoesNotExist,
+    NotAReturnReason,
+    RejectedByPlugin(PluginRejection),
+}
+
+fn validate(
+    connection: &StorageConnection,
+    store_id: &str,
+    r#type: ReturnType,
+    input: &UpdateReturnLine,
+) -> Result<(InvoiceRow, InvoiceLineRow), UpdateReturnLineError> {
+    let line = match InvoiceLineRowRepository::new(connection).find_one_by_id(&input.id) {
+        Ok(line) => line,
+        Err(RepositoryError::NotFound) => return Err(UpdateReturnLineError::LineDoesNotExist),
+        Err(error) => return Err(error.into()),
+    };
+    let invoice = check_return_lines_editable(connection, store_id, r#type, &line.invoice_id)?;
+    if let Some(number_of_packs) = input.number_of_packs {
+        if number_of_packs < 1 {
+            return Err(UpdateReturnLineError::NumberOfPacksBelowOne);
+        }
+    }
+    check_return_reason(connection, &input.reason_id)?;
+    Ok((invoice, line))
+}
+
+fn generate(
+    connection: &StorageConnection,
+    r#type: ReturnType,
+    existing: InvoiceLineRow,
+    UpdateReturnLine {
+        id: _,
+        number_of_packs,
+        reason_id,
+        note,
+    }: UpdateReturnLine,
+) -> Result<(InvoiceLineRow, Option<StockLineRow>), UpdateReturnLineError> {
+    let number_of_packs = number_of_packs
+        .map(|number_of_packs| number_of_packs as i32)
+        .unwrap_or(existing.number_of_packs);
+    let delta = number_of_packs - existing.number_of_packs;
+
+    let stock_line = match (r#type, &existing.stock_line_id) {
+        (ReturnType::Supplier, Some(stock_line_id)) if delta != 0 => {
+            let stock_line =
+                StockLineRowRepository::new(connection).find_one_by_id(stock_line_id)?;
+            if stock_line.available_number_of_packs < delta {
+                return Err(UpdateReturnLineError::ReductionBelowZero);
+            }
+            Some(StockLineRow {
+                available_number_of_packs: stock_line.available_number_of_packs - delta,
+                ..stock_line
+            })
+        }
+        _ => None,
+    };
+
+    let price_per_pack = match r#type {
+        ReturnType::Supplier => existing.cost_price_per_pack,
+        ReturnType::Customer => existing.sell_price_per_pack,
+    };
+    let total = price_per_pack * number_of_packs as f64;
+    let line = InvoiceLineRow {
+        number_of_packs,
+        total_before_tax: total,
+        total_after_tax: total,
+        return_reason_id: reason_id.or(existing.return_reason_id),
+        note: note.or(existing.note),
+        ..existing
+    };
+    Ok((line, stock_line))
+}
+
+pub fn update_return_line(
+    ctx: &ServiceContext,
+    store_id: &str,
+    r#type: ReturnType,
+    input: UpdateReturnLine,
+) -> Result<InvoiceLine, UpdateReturnLineError> {
+    let result = ctx
+        .connection
+        .transaction_sync(|connection| {
+            let (invoice, existing) = validate(connection, store_id, r#type, &input)?;
+            let (line, stock_line) = generate(connection, r#type, existing, input)?;
+            ctx.plugins
+                .validate_invoice_line(connection, &invoice, &line)
+                .map_err(UpdateReturnLineError::RejectedByPlugin)?;
+            InvoiceLineRowRepository::new(connection).upsert_one(&line)?;
+            if let Some(stock_line) = stock_line {
+                StockLineRowRepository::new(connection).upsert_one(&stock_line)?;
+            }
+
+            get_return_line(connection, &line.id)?.ok_or(UpdateReturnLineError::InternalError(
+                "Failed to read the just updated return line!".to_string(),
+            ))
+        })
+        .map_err(|error| error.to_inner_error())?;
+    Ok(result)
+}
+
+impl From<RepositoryError> for UpdateReturnLineError {
+    fn from(error: RepositoryError) -> Self {
+        UpdateReturnLineError::DatabaseError(error)
+    }
+}
+
+impl From<ReturnCheckError> for UpdateReturnLineError {
+    fn from(error: ReturnCheckError) -> Self {
+        use UpdateReturnLineError::*;
+        match error {
+            ReturnCheckError::DatabaseError(error) => DatabaseError(error),
+            ReturnCheckError::InvoiceDoesNotExist => InvoiceDoesNotExist,
+            ReturnCheckError::WrongReturnType(ReturnType::Supplier) => NotASupplierReturn,
+            ReturnCheckError::WrongReturnType(ReturnType::Customer) => NotACustomerReturn,
+            ReturnCheckError::NotThisStoreInvoice => NotThisStoreInvoice,
+            ReturnCheckError::CannotEditReturn => CannotEditReturn,
+        }
+    }
+}
+
+impl From<ReasonCheckError> for UpdateReturnLineError {
+    fn from(error: ReasonCheckError) -> Self {
+        use UpdateReturnLineError::*;
+        match error {
+            ReasonCheckError::DatabaseError(error) => DatabaseError(error),
+            ReasonCheckError::ReasonDoesNotExist => ReasonDoesNotExist,
+            ReasonCheckError::NotAReturnReason => NotAReturnReason,
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use domain::{
+    invoice::{Invoice, InvoiceFilter},
+    invoice_line::InvoiceLine,
+    name::{Name, NameFilter},
+    EqualFilter,
+};
+use repository::{
+    schema::{InvoiceRow, InvoiceRowStatus, ReasonRowType, StockLineRow},
+    InvoiceLineFilter, InvoiceLineRepository, InvoiceQueryRepository, InvoiceRepository,
+    NameQueryRepository, ReasonRowRepository, RepositoryError, StockLineRowRepository,
+    StorageConnection,
+};
+
+use crate::validate::check_store_id_matches;
+
+use super::ReturnType;
+
+pub fn check_other_party_exists(
+    connection: &StorageConnection,
+    other_party_id: &str,
+) -> Result<Option<Name>, RepositoryError> {
+    let mut result = NameQueryRepository::new(connection)
+        .query_by_filter(NameFilter::new().id(EqualFilter::equal_to(other_party_id)))?;
+    Ok(result.pop())
+}
+
+/// Goods are returned to a supplier and taken back from a customer
+pub fn check_other_party_type(r#type: ReturnType, other_party: &Name) -> bool {
+    match r#type {
+        ReturnType::Supplier => other_party.is_supplier,
+        ReturnType::Customer => other_party.is_customer,
+    }
+}
+
+pub fn check_is_return_type(r#type: ReturnType, invoice: &InvoiceRow) -> bool {
+    invoice.r#type == r#type.invoice_type()
+}
+
+/// Lines of a return can only be changed before the stock is moved
+pub fn check_lines_editable(invoice: &InvoiceRow) -> bool {
+    invoice.status == InvoiceRowStatus::New
+}
+
+/// Returns the stock line if it exists and belongs to the store
+pub fn check_stock_line_exists(
+    connection: &StorageConnection,
+    store_id: &str,
+    stock_line_id: &str,
+) -> Result<Option<StockLineRow>, RepositoryError> {
+    match StockLineRowRepository::new(connection).find_one_by_id(stock_line_id) {
+        Ok(stock_line) if stock_line.store_id == store_id => Ok(Some(stock_line)),
+        Ok(_) | Err(RepositoryError::NotFound) => Ok(None),
+        Err(error) => Err(error),
+    }
+}
+
+pub enum ReasonCheckError {
+    DatabaseError(RepositoryError),
+    ReasonDoesNotExist,
+    NotAReturnReason,
+}
+
+/// A return reason is optional but if set it must be an active reason of type `ReturnReason`
+pub fn check_return_reason(
+    connection: &StorageConnection,
+    reason_id: &Option<String>,
+) -> Result<(), ReasonCheckError> {
+    let reason_id = match reason_id {
+        Some(reason_id) => reason_id,
+        None => return Ok(()),
+    };
+    let reason = ReasonRowRepository::new(connection)
+        .find_one_by_id(reason_id)
+        .map_err(ReasonCheckError::DatabaseError)?
+        .ok_or(ReasonCheckError::ReasonDoesNotExist)?;
+    if reason.r#type != ReasonRowType::ReturnReason || !reason.is_active {
+        return Err(ReasonCheckError::NotAReturnReason);
+    }
+    Ok(())
+}
+
+pub fn get_return(
+    connection: &StorageConnection,
+    id: &str,
+) -> Result<Option<Invoice>, RepositoryError> {
+    let mut result = InvoiceQueryRepository::new(connection)
+        .query_by_filter(InvoiceFilter::new().id(EqualFilter::equal_to(id)))?;
+    Ok(result.pop())
+}
+
+pub fn get_return_line(
+    connection: &StorageConnection,
+    id: &str,
+) -> Result<Option<InvoiceLine>, RepositoryError> {
+    let mut result = InvoiceLineRepository::new(connection)
+        .query_by_filter(InvoiceLineFilter::new().id(EqualFilter::equal_to(id)))?;
+    Ok(result.pop())
+}
+
+pub enum ReturnCheckError {
+    DatabaseError(RepositoryError),
+    InvoiceDoesNotExist,
+    WrongReturnType(ReturnType),
+    NotThisStoreInvoice,
+    CannotEditReturn,
+}
+
+/// Returns the return invoice if its lines can be changed from the store
+pub fn check_return_lines_editable(
+    connection: &StorageConnection,
+    store_id: &str,
+    r#type: ReturnType,
+    invoice_id: &str,
+) -> Result<InvoiceRow, ReturnCheckError> {
+    let invoice = match InvoiceRepository::new(connection).find_one_by_id(invoice_id) {
+        Ok(invoice) => invoice,
+        Err(RepositoryError::NotFound) => return Err(ReturnCheckError::InvoiceDoesNotExist),
+        Err(error) => return Err(ReturnCheckError::DatabaseError(error)),
+    };
+    if !check_is_return_type(r#type, &invoice) {
+        return Err(ReturnCheckError::WrongReturnType(r#type));
+    }
+    if !check_store_id_matches(store_id, &invoice.store_id) {
+        return Err(ReturnCheckError::NotThisStoreInvoice);
+    }
+    if !check_lines_editable(&invoice) {
+        return Err(ReturnCheckError::CannotEditReturn);
+    }
+    Ok(invoice)
+}
@@ -3,31 +3,72 @@ use std::sync::Arc;
 use repository::{RepositoryError, StorageConnection, StorageConnectionManager};
 
 use crate::{
+    abc_classification::{AbcClassificationService, AbcClassificationServiceTrait},
+    barcode::{BarcodeService, BarcodeServiceTrait},
+    batch_recall::{BatchRecallService, BatchRecallServiceTrait},
     dashboard::{
         invoice_count::{InvoiceCountService, InvoiceCountServiceTrait},
         stock_expiry_count::{StockExpiryCountServiceTrait, StockExpiryServiceCount},
     },
     invoice_line::{OutboundShipmentLineService, OutboundShipmentLineServiceTrait},
+    item_property::{ItemPropertyService, ItemPropertyServiceTrait},
     location::{LocationService, LocationServiceTrait},
+    location_movement::{LocationMovementService, LocationMovementServiceTrait},
     master_list::{MasterListService, MasterListServiceTrait},
+    name::{NameService, NameServiceTrait},
+    pack_variant::{PackVariantService, PackVariantServiceTrait},
+    patient::{PatientService, PatientServiceTrait},
     permission_validation::{ValidationService, ValidationServiceTrait},
     permissions::{PermissionService, PermissionServiceTrait},
+    plugin::PluginHost,
+    preference::{PreferenceService, PreferenceServiceTrait},
+    prescription::{PrescriptionService, PrescriptionServiceTrait},
+    repack::{RepackService, RepackServiceTrait},
+    report::{ReportService, ReportServiceTrait},
+    returns::{ReturnService, ReturnServiceTrait},
+    stock_expiry::{StockExpiryService, StockExpiryServiceTrait},
+    stock_import::{StockImportService, StockImportServiceTrait},
+    stock_movement::{StockMovementService, StockMovementServiceTrait},
+    stock_on_hand::{StockOnHandService, StockOnHandServiceTrait},
     stock_take::{StockTakeService, StockTakeServiceTrait},
     stock_take_line::{StockTakeLineService, StockTakeLineServiceTrait},
+    stock_valuation::{StockValuationService, StockValuationServiceTrait},
     store::{StoreService, StoreServiceTrait},
+    temperature_log::{TemperatureLogService, TemperatureLogServiceTrait},
 };
 
 pub struct ServiceProvider {
     pub connection_manager: StorageConnectionManager,
     pub permission_service: Arc<dyn PermissionServiceTrait>,
     pub validation_service: Box<dyn ValidationServiceTrait>,
+    /// Loaded plugins, empty by default
+    pub plugins: Arc<PluginHost>,
 
     pub location_service: Box<dyn LocationServiceTrait>,
     pub master_list_service: Box<dyn MasterListServiceTrait>,
     pub stock_take_service: Box<dyn StockTakeServiceTrait>,
     pub stock_take_line_service: Box<dyn StockTakeLineServiceTrait>,
     pub store_service: Box<dyn StoreServiceTrait>,
+    pub preference_service: Box<dyn PreferenceServiceTrait>,
     pub outbound_shipment_line: Box<dyn OutboundShipmentLineServiceTrait>,
+    pub stock_on_hand_service: Box<dyn StockOnHandServiceTrait>,
+    pub stock_movement_service: Box<dyn StockMovementServiceTrait>,
+    pub stock_expiry_service: Box<dyn StockExpiryServiceTrait>,
+    pub batch_recall_service: Box<dyn BatchRecallServiceTrait>,
+    pub barcode_service: Box<dyn BarcodeServiceTrait>,
+    pub pack_variant_service: Box<dyn PackVariantServiceTrait>,
+    pub item_property_service: Box<dyn ItemPropertyServiceTrait>,
+    pub abc_classification_service: Box<dyn AbcClassificationServiceTrait>,
+    pub return_service: Box<dyn ReturnServiceTrait>,
+    pub repack_service: Box<dyn RepackServiceTrait>,
+    pub location_movement_service: Box<dyn LocationMovementServiceTrait>,
+    pub temperature_log_service: Box<dyn TemperatureLogServiceTrait>,
+    pub stock_valuation_service: Box<dyn StockValuationServiceTrait>,
+    pub stock_import_service: Box<dyn StockImportServiceTrait>,
+    pub report_service: Box<dyn ReportServiceTrait>,
+    pub patient_service: Box<dyn PatientServiceTrait>,
+    pub prescription_service: Box<dyn PrescriptionServiceTrait>,
+    pub name_service: Box<dyn NameServiceTrait>,
     // Dashboard:
     pub invoice_count_service: Box<dyn InvoiceCountServiceTrait>,
     pub stock_expiry_count_service: Box<dyn StockExpiryCountServiceTrait>,
@@ -35,6 +76,7 @@ pub struct ServiceProvider {
 
 pub struct ServiceContext {
     pub connection: StorageConnection,
+    pub plugins: Arc<PluginHost>,
 }
 
 impl ServiceProvider {
@@ -44,10 +86,30 @@ impl ServiceProvider {
             connection_manager,
             permission_service: permission_service.clone(),
             validation_service: Box::new(ValidationService::new(permission_service)),
+            plugins: Arc::new(PluginHost::default()),
             location_service: Box::new(LocationService {}),
             master_list_service: Box::new(MasterListService {}),
             store_service: Box::new(StoreService {}),
+            preference_service: Box::new(PreferenceService {}),
             outbound_shipment_line: Box::new(OutboundShipmentLineService {}),
+            stock_on_hand_service: Box::new(StockOnHandService {}),
+            stock_movement_service: Box::new(StockMovementService {}),
+            stock_expiry_service: Box::new(StockExpiryService {}),
+            batch_recall_service: Box::new(BatchRecallService {}),
+            barcode_service: Box::new(BarcodeService {}),
+            pack_variant_service: Box::new(PackVariantService {}),
+            item_property_service: Box::new(ItemPropertyService {}),
+            abc_classification_service: Box::new(AbcClassificationService {}),
+            return_service: Box::new(ReturnService {}),
+            repack_service: Box::new(RepackService {}),
+            location_movement_service: Box::new(LocationMovementService {}),
+            temperature_log_service: Box::new(TemperatureLogService {}),
+            stock_valuation_service: Box::new(StockValuationService {}),
+            stock_import_service: Box::new(StockImportService {}),
+            report_service: Box::new(ReportService {}),
+            patient_service: Box::new(PatientService {}),
+            prescription_service: Box::new(PrescriptionService {}),
+            name_service: Box::new(NameService {}),
             invoice_count_service: Box::new(InvoiceCountService {}),
             stock_expiry_count_service: Box::new(StockExpiryServiceCount {}),
             stock_take_service: Box::new(StockTakeService {}),
@@ -59,6 +121,7 @@ impl ServiceProvider {
     pub fn context(&self) -> Result<ServiceContext, RepositoryError> {
         Ok(ServiceContext {
             connection: self.connection()?,
+            plugins: self.plugins.clone(),
         })
     }
 
@@ -0,0 +1,149 @@
+use domain::{
+    stock_line::{StockLine, StockLineFilter},
+    EqualFilter, Pagination,
+};
+use repository::{
+    schema::StockLineRow, LocationRowRepository, RepositoryError, StockLineRepository,
+    StockLineRowRepository, StorageConnection,
+};
+
+use util::uuid::uuid;
+
+use crate::{
+    location_movement::move_stock::{quarantine_stock, MoveStock, MoveStockError},
+    service_provider::ServiceContext,
+};
+
+/// Puts stock lines on hold and/or moves them to a (quarantine) location, moves are logged as
+/// location movements
+#[derive(Clone, Debug)]
+pub struct HoldStockLines {
+    pub stock_line_ids: Vec<String>,
+    pub on_hold: bool,
+    pub location_id: Option<String>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum HoldStockLinesError {
+    DatabaseError(RepositoryError),
+    InternalError(String),
+    NoStockLines,
+    StockLineDoesNotExist(String),
+    StockLineDoesNotBelongToCurrentStore(String),
+    LocationDoesNotExist,
+    LocationDoesNotBelongToCurrentStore,
+    /// The item of the stock line is restricted to a location type the location doesn't provide
+    LocationNotSuitableForItem(String),
+}
+
+pub fn hold_stock_lines(
+    ctx: &ServiceContext,
+    store_id: &str,
+    input: HoldStockLines,
+) -> Result<Vec<StockLine>, HoldStockLinesError> {
+    let stock_lines = ctx
+        .connection
+        .transaction_sync(|connection| {
+            let stock_line_rows = validate(connection, store_id, &input)?;
+            let repository = StockLineRowRepository::new(connection);
+            for row in generate(stock_line_rows, &input) {
+                repository.upsert_one(&row)?;
+                if let Some(location_id) = &input.location_id {
+                    move_stock_line(connection, store_id, &row, location_id)?;
+                }
+            }
+
+            StockLineRepository::new(connection)
+                .query(
+                    Pagination::all(),
+                    Some(StockLineFilter::new().id(EqualFilter::equal_any(input.stock_line_ids))),
+                    None,
+                )
+                .map_err(HoldStockLinesError::from)
+        })
+        .map_err(|error| error.to_inner_error())?;
+    Ok(stock_lines)
+}
+
+fn validate(
+    connection: &StorageConnection,
+    store_id: &str,
+    input: &HoldStockLines,
+) -> Result<Vec<StockLineRow>, HoldStockLinesError> {
+    if input.stock_line_ids.is_empty() {
+        return Err(HoldStockLinesError::NoStockLines);
+    }
+
+    let stock_line_rows =
+        StockLineRowRepository::new(connection).find_many_by_ids(&input.stock_line_ids)?;
+    for id in &input.stock_line_ids {
+        match stock_line_rows.iter().find(|row| &row.id == id) {
+            None => return Err(HoldStockLinesError::StockLineDoesNotExist(id.clone())),
+            Some(row) if row.store_id != store_id => {
+                return Err(HoldStockLinesError::StockLineDoesNotBelongToCurrentStore(
+                    id.clone(),
+                ))
+            }
+            Some(_) => {}
+        }
+    }
+
+    if let Some(location_id) = &input.location_id {
+        match LocationRowRepository::new(connection).find_one_by_id(location_id)? {
+            None => return Err(HoldStockLinesError::LocationDoesNotExist),
+            Some(location) if location.store_id != store_id => {
+                return Err(HoldStockLinesError::LocationDoesNotBelongToCurrentStore)
+            }
+            Some(_) => {}
+        }
+    }
+
+    Ok(stock_line_rows)
+}
+
+fn generate(stock_line_rows: Vec<StockLineRow>, input: &HoldStockLines) -> Vec<StockLineRow> {
+    stock_line_rows
+        .into_iter()
+        .map(|mut row| {
+            row.on_hold = input.on_hold;
+            row
+        })
+        .collect()
+}
+
+/// Moves the whole stock line, empty stock lines and stock lines already in the location stay
+fn move_stock_line(
+    connection: &StorageConnection,
+    store_id: &str,
+    stock_line: &StockLineRow,
+    location_id: &str,
+) -> Result<(), HoldStockLinesError> {
+    if stock_line.total_number_of_packs < 1
+        || stock_line.location_id.as_deref() == Some(location_id)
+    {
+        return Ok(());
+    }
+    let input = MoveStock {
+        id: uuid(),
+        stock_line_id: stock_line.id.clone(),
+        number_of_packs: stock_line.total_number_of_packs as u32,
+        location_id: location_id.to_string(),
+    };
+    match quarantine_stock(connection, store_id, input) {
+        Ok(_) => Ok(()),
+        Err(MoveStockError::DatabaseError(error)) => Err(HoldStockLinesError::DatabaseError(error)),
+        Err(MoveStockError::LocationNotSuitableForItem) => Err(
+            HoldStockLinesError::LocationNotSuitableForItem(stock_line.id.clone()),
+        ),
+        Err(error) => Err(HoldStockLinesError::InternalError(format!(
+            "Failed to move stock line {}: {:?}",
+            stock_line.id, error
+        ))),
+    }
+}
+
+impl From<RepositoryError> for HoldStockLinesError {
+    fn from(error: RepositoryError) -> Self {
+        HoldStockLinesError::DatabaseError(error)
+    }
+}
@@ -0,0 +1,39 @@
+use domain::stock_line::StockLine;
+use repository::RepositoryError;
+
+use crate::service_provider::ServiceContext;
+
+use self::{
+    hold::{hold_stock_lines, HoldStockLines, HoldStockLinesError},
+    report::{get_stock_expiry_report, StockExpiryReportInput, StockExpiryReportRow},
+};
+
+pub mod hold;
+pub mod report;
+
+#[cfg(test)]
+mod tests;
+
+pub trait StockExpiryServiceTrait: Sync + Send {
+    /// Expired and short dated stock of the store
+    fn get_stock_expiry_report(
+        &self,
+        ctx: &ServiceContext,
+        store_id: &str,
+        input: StockExpiryReportInput,
+    ) -> Result<Vec<StockExpiryReportRow>, RepositoryError> {
+        get_stock_expiry_report(ctx, store_id, input)
+    }
+
+    fn hold_stock_lines(
+        &self,
+        ctx: &ServiceContext,
+        store_id: &str,
+        input: HoldStockLines,
+    ) -> Result<Vec<StockLine>, HoldStockLinesError> {
+        hold_stock_lines(ctx, store_id, input)
+    }
+}
+
+pub struct StockExpiryService {}
+impl StockExpiryServiceTrait for StockExpiryService {}
@@ -0,0 +1,112 @@
+use std::collections::{BTreeMap, HashSet};
+
+use chrono::{Duration, NaiveDate};
+use domain::{
+    master_list_line::MasterListLineFilter,
+    stock_line::{StockLine, StockLineFilter},
+    DateFilter, EqualFilter, Pagination,
+};
+use repository::{MasterListLineRepository, RepositoryError, StockLineRepository};
+
+use crate::service_provider::ServiceContext;
+
+#[derive(Clone, Debug)]
+pub struct StockExpiryReportInput {
+    /// Current date in the store's timezone, stock expiring on or before it is expired
+    pub today: NaiveDate,
+    /// Stock expiring within this number of days is short dated
+    pub days_till_expired: i32,
+    /// Only include items of the master list
+    pub master_list_id: Option<String>,
+    pub item_id: Option<EqualFilter<String>>,
+}
+
+/// Expired and short dated stock of an item in a location
+#[derive(Clone, Debug, PartialEq)]
+pub struct StockExpiryReportRow {
+    pub item_id: String,
+    pub location_id: Option<String>,
+    pub location_name: Option<String>,
+    pub earliest_expiry_date: NaiveDate,
+    pub total_number_of_packs: i64,
+    /// Number of units (number of packs * pack size)
+    pub total_units: i64,
+    /// Units that are already expired
+    pub expired_units: i64,
+    pub total_cost_value: f64,
+    pub total_sell_value: f64,
+    /// Stock lines of the group, earliest expiry first
+    pub stock_lines: Vec<StockLine>,
+}
+
+/// Stock lines in stock that expire within `days_till_expired` days, grouped by item and
+/// location and ordered by item and location id
+pub fn get_stock_expiry_report(
+    ctx: &ServiceContext,
+    store_id: &str,
+    input: StockExpiryReportInput,
+) -> Result<Vec<StockExpiryReportRow>, RepositoryError> {
+    let expiring_before = input.today + Duration::days(input.days_till_expired as i64);
+    let mut filter = StockLineFilter::new()
+        .store_id(EqualFilter::equal_to(store_id))
+        .expiry_date(DateFilter {
+            equal_to: None,
+            before_or_equal_to: Some(expiring_before),
+            after_or_equal_to: None,
+        });
+    filter.item_id = input.item_id;
+
+    let mut stock_lines =
+        StockLineRepository::new(&ctx.connection).query(Pagination::all(), Some(filter), None)?;
+
+    if let Some(master_list_id) = input.master_list_id {
+        let item_ids: HashSet<String> = MasterListLineRepository::new(&ctx.connection)
+            .query(
+                Pagination::all(),
+                Some(
+                    MasterListLineFilter::new()
+                        .master_list_id(EqualFilter::equal_to(&master_list_id)),
+                ),
+            )?
+            .into_iter()
+            .map(|line| line.item_id)
+            .collect();
+        stock_lines.retain(|stock_line| item_ids.contains(&stock_line.item_id));
+    }
+    stock_lines.sort_by_key(|stock_line| stock_line.expiry_date);
+
+    let mut groups: BTreeMap<(String, Option<String>), StockExpiryReportRow> = BTreeMap::new();
+    for stock_line in stock_lines {
+        let expiry_date = match stock_line.expiry_date {
+            Some(expiry_date) if stock_line.total_number_of_packs > 0 => expiry_date,
+            _ => continue,
+        };
+        let number_of_packs = stock_line.total_number_of_packs as i64;
+        let units = number_of_packs * stock_line.pack_size as i64;
+
+        let row = groups
+            .entry((stock_line.item_id.clone(), stock_line.location_id.clone()))
+            .or_insert_with(|| StockExpiryReportRow {
+                item_id: stock_line.item_id.clone(),
+                location_id: stock_line.location_id.clone(),
+                location_name: stock_line.location_name.clone(),
+                earliest_expiry_date: expiry_date,
+                total_number_of_packs: 0,
+                total_units: 0,
+                expired_units: 0,
+                total_cost_value: 0.0,
+                total_sell_value: 0.0,
+                stock_lines: Vec::new(),
+            });
+        row.total_number_of_packs += number_of_packs;
+        row.total_units += units;
+        if expiry_date <= input.today {
+            row.expired_units += units;
+        }
+        row.total_cost_value += number_of_packs as f64 * stock_line.cost_price_per_pack;
+        row.total_sell_value += number_of_packs as f64 * stock_line.sell_price_per_pack;
+        row.stock_lines.push(stock_line);
+    }
+
+    Ok(groups.into_values().collect())
+}
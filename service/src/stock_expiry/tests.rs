@@ -0,0 +1,312 @@
+#[cfg(test)]
+mod stock_expiry_test {
+    use chrono::NaiveDate;
+    use domain::EqualFilter;
+    use repository::{
+        mock::{mock_stock_line_a, mock_store_a, MockDataInserts},
+        schema::{ItemStoreJoinRow, LocationTypeRow, StockLineRow},
+        test_db::setup_all,
+        ItemStoreJoinRowRepository, LocationTypeRowRepository, StockLineRowRepository,
+        StorageConnection,
+    };
+
+    use crate::{
+        service_provider::ServiceProvider,
+        stock_expiry::{
+            hold::{HoldStockLines, HoldStockLinesError},
+            report::StockExpiryReportInput,
+        },
+    };
+
+    fn insert_stock_line(
+        connection: &StorageConnection,
+        id: &str,
+        item_id: &str,
+        location_id: Option<&str>,
+        expiry_date: NaiveDate,
+        total_number_of_packs: i32,
+    ) {
+        StockLineRowRepository::new(connection)
+            .upsert_one(&StockLineRow {
+                id: id.to_string(),
+                item_id: item_id.to_string(),
+                store_id: mock_store_a().id,
+                location_id: location_id.map(|id| id.to_string()),
+                batch: Some(id.to_string()),
+                pack_size: 10,
+                cost_price_per_pack: 2.0,
+                sell_price_per_pack: 3.0,
+                available_number_of_packs: total_number_of_packs,
+                total_number_of_packs,
+                expiry_date: Some(expiry_date),
+                on_hold: false,
+                note: None,
+            })
+            .unwrap();
+    }
+
+    #[actix_rt::test]
+    async fn stock_expiry_report() {
+        let (_, connection, connection_manager, _) =
+            setup_all("stock_expiry_report", MockDataInserts::all()).await;
+
+        let service_provider = ServiceProvider::new(connection_manager);
+        let context = service_provider.context().unwrap();
+        let service = service_provider.stock_expiry_service;
+
+        let today = NaiveDate::from_ymd(2021, 6, 1);
+        let date = |month: u32, day: u32| NaiveDate::from_ymd(2021, month, day);
+        insert_stock_line(
+            &connection,
+            "expired",
+            "item_query_test1",
+            None,
+            date(5, 1),
+            2,
+        );
+        insert_stock_line(
+            &connection,
+            "short_dated",
+            "item_query_test1",
+            None,
+            date(6, 20),
+            3,
+        );
+        insert_stock_line(
+            &connection,
+            "short_dated_location",
+            "item_query_test1",
+            Some("location_1"),
+            date(6, 25),
+            1,
+        );
+        insert_stock_line(
+            &connection,
+            "long_dated",
+            "item_query_test1",
+            None,
+            date(12, 1),
+            5,
+        );
+        insert_stock_line(
+            &connection,
+            "empty",
+            "item_query_test1",
+            None,
+            date(5, 1),
+            0,
+        );
+        insert_stock_line(
+            &connection,
+            "other_item",
+            "item_query_test2",
+            None,
+            date(5, 1),
+            4,
+        );
+
+        let input = StockExpiryReportInput {
+            today,
+            days_till_expired: 30,
+            master_list_id: None,
+            item_id: Some(EqualFilter::equal_any(vec![
+                "item_query_test1".to_string(),
+                "item_query_test2".to_string(),
+            ])),
+        };
+        let report = service
+            .get_stock_expiry_report(&context, &mock_store_a().id, input.clone())
+            .unwrap();
+        assert_eq!(report.len(), 3);
+
+        let row = &report[0];
+        assert_eq!(row.item_id, "item_query_test1");
+        assert_eq!(row.location_id, None);
+        assert_eq!(row.earliest_expiry_date, date(5, 1));
+        assert_eq!(row.total_number_of_packs, 5);
+        assert_eq!(row.total_units, 50);
+        assert_eq!(row.expired_units, 20);
+        assert_eq!(row.total_cost_value, 10.0);
+        assert_eq!(row.total_sell_value, 15.0);
+        let ids: Vec<&str> = row
+            .stock_lines
+            .iter()
+            .map(|line| line.id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["expired", "short_dated"]);
+
+        let row = &report[1];
+        assert_eq!(row.location_id, Some("location_1".to_string()));
+        assert_eq!(row.total_units, 10);
+        assert_eq!(row.expired_units, 0);
+
+        assert_eq!(report[2].item_id, "item_query_test2");
+
+        // master list filter
+        let report = service
+            .get_stock_expiry_report(
+                &context,
+                &mock_store_a().id,
+                StockExpiryReportInput {
+                    master_list_id: Some("item_query_test1".to_string()),
+                    ..input
+                },
+            )
+            .unwrap();
+        assert!(report.iter().all(|row| row.item_id == "item_query_test1"));
+        assert_eq!(report.len(), 2);
+    }
+
+    #[actix_rt::test]
+    async fn hold_stock_lines() {
+        let (_, connection, connection_manager, _) =
+            setup_all("hold_stock_lines", MockDataInserts::all()).await;
+
+        let service_provider = ServiceProvider::new(connection_manager);
+        let context = service_provider.context().unwrap();
+        let service = &service_provider.stock_expiry_service;
+
+        let input = HoldStockLines {
+            stock_line_ids: vec![mock_stock_line_a().id],
+            on_hold: true,
+            location_id: Some("location_on_hold".to_string()),
+        };
+
+        // errors
+        assert_eq!(
+            service.hold_stock_lines(
+                &context,
+                &mock_store_a().id,
+                HoldStockLines {
+                    stock_line_ids: vec![],
+                    ..input.clone()
+                }
+            ),
+            Err(HoldStockLinesError::NoStockLines)
+        );
+        assert_eq!(
+            service.hold_stock_lines(
+                &context,
+                &mock_store_a().id,
+                HoldStockLines {
+                    stock_line_ids: vec![mock_stock_line_a().id, "invalid".to_string()],
+                    ..input.clone()
+                }
+            ),
+            Err(HoldStockLinesError::StockLineDoesNotExist(
+                "invalid".to_string()
+            ))
+        );
+        assert_eq!(
+            service.hold_stock_lines(
+                &context,
+                &mock_store_a().id,
+                HoldStockLines {
+                    stock_line_ids: vec!["item_b_line_a".to_string()],
+                    ..input.clone()
+                }
+            ),
+            Err(HoldStockLinesError::StockLineDoesNotBelongToCurrentStore(
+                "item_b_line_a".to_string()
+            ))
+        );
+        assert_eq!(
+            service.hold_stock_lines(
+                &context,
+                &mock_store_a().id,
+                HoldStockLines {
+                    location_id: Some("invalid".to_string()),
+                    ..input.clone()
+                }
+            ),
+            Err(HoldStockLinesError::LocationDoesNotExist)
+        );
+        assert_eq!(
+            service.hold_stock_lines(
+                &context,
+                &mock_store_a().id,
+                HoldStockLines {
+                    location_id: Some("location_in_another_store".to_string()),
+                    ..input.clone()
+                }
+            ),
+            Err(HoldStockLinesError::LocationDoesNotBelongToCurrentStore)
+        );
+
+        // success
+        let result = service
+            .hold_stock_lines(&context, &mock_store_a().id, input)
+            .unwrap();
+        assert_eq!(result.len(), 1);
+        let stock_line = StockLineRowRepository::new(&connection)
+            .find_one_by_id(&mock_stock_line_a().id)
+            .unwrap();
+        assert!(stock_line.on_hold);
+        assert_eq!(stock_line.location_id, Some("location_on_hold".to_string()));
+        // the move is logged
+        let movements = service_provider
+            .location_movement_service
+            .get_stock_line_movements(&context, &mock_store_a().id, &mock_stock_line_a().id)
+            .unwrap();
+        assert_eq!(movements.len(), 1);
+        assert_eq!(movements[0].to_location_id, "location_on_hold");
+        assert_eq!(
+            movements[0].number_of_packs,
+            mock_stock_line_a().total_number_of_packs
+        );
+
+        // release without moving
+        service
+            .hold_stock_lines(
+                &context,
+                &mock_store_a().id,
+                HoldStockLines {
+                    stock_line_ids: vec![mock_stock_line_a().id],
+                    on_hold: false,
+                    location_id: None,
+                },
+            )
+            .unwrap();
+        let stock_line = StockLineRowRepository::new(&connection)
+            .find_one_by_id(&mock_stock_line_a().id)
+            .unwrap();
+        assert!(!stock_line.on_hold);
+        assert_eq!(stock_line.location_id, Some("location_on_hold".to_string()));
+
+        // the item needs a cold room
+        LocationTypeRowRepository::new(&connection)
+            .upsert_one(&LocationTypeRow {
+                id: "cold_room".to_string(),
+                name: "Cold room".to_string(),
+                min_temperature: Some(2.0),
+                max_temperature: Some(8.0),
+            })
+            .unwrap();
+        ItemStoreJoinRowRepository::new(&connection)
+            .upsert_one(&ItemStoreJoinRow {
+                id: "item_a_store_a".to_string(),
+                item_id: stock_line.item_id.clone(),
+                store_id: mock_store_a().id,
+                default_sell_price_per_pack: 0.0,
+                margin: 0.0,
+                ignore_for_orders: false,
+                inactive: false,
+                restricted_location_type_id: Some("cold_room".to_string()),
+            })
+            .unwrap();
+        assert_eq!(
+            service.hold_stock_lines(
+                &context,
+                &mock_store_a().id,
+                HoldStockLines {
+                    stock_line_ids: vec![mock_stock_line_a().id],
+                    on_hold: true,
+                    location_id: Some("location_1".to_string()),
+                }
+            ),
+            Err(HoldStockLinesError::LocationNotSuitableForItem(
+                mock_stock_line_a().id
+            ))
+        );
+    }
+}
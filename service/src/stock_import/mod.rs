@@ -0,0 +1,62 @@
+use repository::RepositoryError;
+
+use crate::service_provider::ServiceContext;
+
+use self::opening_stock::{import_opening_stock, ImportOpeningStock, ImportOpeningStockResult};
+
+pub mod opening_stock;
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Debug, PartialEq)]
+pub enum ImportRowErrorKind {
+    /// Row couldn't be read, e.g. wrong number of fields
+    InvalidRow(String),
+    MissingItemCode,
+    ItemDoesNotExist(String),
+    /// Expiry date is not in the YYYY-MM-DD format
+    InvalidExpiryDate(String),
+    PackSizeBelowOne,
+    NumberOfPacksBelowOne,
+    /// Holds the column of the price that is not a positive number
+    InvalidPrice(String),
+    LocationDoesNotExist(String),
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ImportRowError {
+    /// Line in the file, the header is on line 1
+    pub line_number: usize,
+    pub errors: Vec<ImportRowErrorKind>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ImportOpeningStockError {
+    DatabaseError(RepositoryError),
+    InternalError(String),
+    InvalidCsv(String),
+    MissingColumn(String),
+    NoRows,
+    RowErrors(Vec<ImportRowError>),
+}
+
+impl From<RepositoryError> for ImportOpeningStockError {
+    fn from(error: RepositoryError) -> Self {
+        ImportOpeningStockError::DatabaseError(error)
+    }
+}
+
+pub trait StockImportServiceTrait: Sync + Send {
+    fn import_opening_stock(
+        &self,
+        ctx: &ServiceContext,
+        store_id: &str,
+        input: ImportOpeningStock,
+    ) -> Result<ImportOpeningStockResult, ImportOpeningStockError> {
+        import_opening_stock(ctx, store_id, input)
+    }
+}
+
+pub struct StockImportService {}
+impl StockImportServiceTrait for StockImportService {}
@@ -0,0 +1,346 @@
+use std::collections::HashMap;
+
+use chrono::{NaiveDate, Utc};
+use domain::{
+    item::Item, location::LocationFilter, name::NameFilter, EqualFilter, SimpleStringFilter,
+};
+use repository::{
+    schema::{
+        InvoiceLineRow, InvoiceLineRowType, InvoiceRow, InvoiceRowStatus, InvoiceRowType,
+        NumberRowType, StockLineRow,
+    },
+    InvoiceLineRowRepository, InvoiceRepository, ItemFilter, ItemQueryRepository,
+    LocationRepository, NameQueryRepository, StockLineRowRepository, StorageConnection,
+};
+use serde::Deserialize;
+use util::{constants::INVENTORY_ADJUSTMENT_NAME_CODE, uuid::uuid};
+
+use crate::{number::next_number, service_provider::ServiceContext};
+
+use super::{ImportOpeningStockError, ImportRowError, ImportRowErrorKind};
+
+/// Columns that must be present in the header of the file
+const REQUIRED_COLUMNS: [&str; 3] = ["item_code", "pack_size", "number_of_packs"];
+
+pub struct ImportOpeningStock {
+    /// Content of the CSV file, the first line holds the column names: item_code, batch,
+    /// expiry_date (YYYY-MM-DD), pack_size, number_of_packs, cost_price_per_pack,
+    /// sell_price_per_pack and location_code
+    pub csv: String,
+    pub comment: Option<String>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ImportOpeningStockResult {
+    pub invoice: InvoiceRow,
+    pub stock_lines: Vec<StockLineRow>,
+}
+
+#[derive(Deserialize)]
+struct OpeningStockRecord {
+    item_code: Option<String>,
+    batch: Option<String>,
+    expiry_date: Option<String>,
+    pack_size: Option<String>,
+    number_of_packs: Option<String>,
+    cost_price_per_pack: Option<String>,
+    sell_price_per_pack: Option<String>,
+    location_code: Option<String>,
+}
+
+struct OpeningStockLine {
+    item: Item,
+    batch: Option<String>,
+    expiry_date: Option<NaiveDate>,
+    pack_size: i32,
+    number_of_packs: i32,
+    cost_price_per_pack: f64,
+    sell_price_per_pack: f64,
+    location_id: Option<String>,
+}
+
+/// Imports the stock of a new store as a single inventory adjustment.
+///
+/// Nothing is written unless every row of the file is valid.
+pub fn import_opening_stock(
+    ctx: &ServiceContext,
+    store_id: &str,
+    input: ImportOpeningStock,
+) -> Result<ImportOpeningStockResult, ImportOpeningStockError> {
+    let result = ctx
+        .connection
+        .transaction_sync(
+            |connection| -> Result<ImportOpeningStockResult, ImportOpeningStockError> {
+                let lines = validate(connection, store_id, &input.csv)?;
+                let (result, invoice_lines) = generate(connection, store_id, input.comment, lines)?;
+
+                InvoiceRepository::new(connection).upsert_one(&result.invoice)?;
+                let stock_line_repo = StockLineRowRepository::new(connection);
+                for stock_line in &result.stock_lines {
+                    stock_line_repo.upsert_one(stock_line)?;
+                }
+                let invoice_line_repo = InvoiceLineRowRepository::new(connection);
+                for invoice_line in &invoice_lines {
+                    invoice_line_repo.upsert_one(invoice_line)?;
+                }
+                Ok(result)
+            },
+        )
+        .map_err(|error| error.to_inner_error())?;
+    Ok(result)
+}
+
+fn validate(
+    connection: &StorageConnection,
+    store_id: &str,
+    csv: &str,
+) -> Result<Vec<OpeningStockLine>, ImportOpeningStockError> {
+    let mut reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .from_reader(csv.as_bytes());
+    let headers = reader
+        .headers()
+        .map_err(|error| ImportOpeningStockError::InvalidCsv(error.to_string()))?
+        .clone();
+    for column in REQUIRED_COLUMNS {
+        if !headers.iter().any(|header| header == column) {
+            return Err(ImportOpeningStockError::MissingColumn(column.to_string()));
+        }
+    }
+
+    let mut items: HashMap<String, Option<Item>> = HashMap::new();
+    let mut locations: HashMap<String, Option<String>> = HashMap::new();
+    let mut lines = Vec::new();
+    let mut row_errors = Vec::new();
+    for (index, record) in reader.deserialize::<OpeningStockRecord>().enumerate() {
+        // the header is on the first line
+        let line_number = index + 2;
+        let record = match record {
+            Ok(record) => record,
+            Err(error) => {
+                row_errors.push(ImportRowError {
+                    line_number,
+                    errors: vec![ImportRowErrorKind::InvalidRow(error.to_string())],
+                });
+                continue;
+            }
+        };
+        match validate_record(connection, store_id, record, &mut items, &mut locations)? {
+            Ok(line) => lines.push(line),
+            Err(errors) => row_errors.push(ImportRowError {
+                line_number,
+                errors,
+            }),
+        }
+    }
+
+    if !row_errors.is_empty() {
+        return Err(ImportOpeningStockError::RowErrors(row_errors));
+    }
+    if lines.is_empty() {
+        return Err(ImportOpeningStockError::NoRows);
+    }
+    Ok(lines)
+}
+
+fn non_empty(value: Option<String>) -> Option<String> {
+    value.filter(|value| !value.is_empty())
+}
+
+/// Returns all problems of the row, the outer result is for database errors
+fn validate_record(
+    connection: &StorageConnection,
+    store_id: &str,
+    record: OpeningStockRecord,
+    items: &mut HashMap<String, Option<Item>>,
+    locations: &mut HashMap<String, Option<String>>,
+) -> Result<Result<OpeningStockLine, Vec<ImportRowErrorKind>>, ImportOpeningStockError> {
+    let mut errors = Vec::new();
+
+    let item = match non_empty(record.item_code) {
+        None => {
+            errors.push(ImportRowErrorKind::MissingItemCode);
+            None
+        }
+        Some(code) => {
+            if !items.contains_key(&code) {
+                let item = ItemQueryRepository::new(connection)
+                    .query_by_filter(
+                        store_id,
+                        ItemFilter::new().code(SimpleStringFilter::equal_to(&code)),
+                    )?
+                    .pop();
+                items.insert(code.clone(), item);
+            }
+            let item = items[&code].clone();
+            if item.is_none() {
+                errors.push(ImportRowErrorKind::ItemDoesNotExist(code));
+            }
+            item
+        }
+    };
+
+    let expiry_date = match non_empty(record.expiry_date) {
+        None => None,
+        Some(value) => match NaiveDate::parse_from_str(&value, "%Y-%m-%d") {
+            Ok(date) => Some(date),
+            Err(_) => {
+                errors.push(ImportRowErrorKind::InvalidExpiryDate(value));
+                None
+            }
+        },
+    };
+
+    let pack_size = match non_empty(record.pack_size).map(|value| value.parse::<i32>()) {
+        Some(Ok(pack_size)) if pack_size >= 1 => pack_size,
+        _ => {
+            errors.push(ImportRowErrorKind::PackSizeBelowOne);
+            0
+        }
+    };
+    let number_of_packs = match non_empty(record.number_of_packs).map(|value| value.parse::<i32>())
+    {
+        Some(Ok(number_of_packs)) if number_of_packs >= 1 => number_of_packs,
+        _ => {
+            errors.push(ImportRowErrorKind::NumberOfPacksBelowOne);
+            0
+        }
+    };
+
+    let mut price = |value: Option<String>, column: &str| match non_empty(value)
+        .map(|value| value.parse::<f64>())
+    {
+        None => 0.0,
+        Some(Ok(price)) if price >= 0.0 => price,
+        Some(_) => {
+            errors.push(ImportRowErrorKind::InvalidPrice(column.to_string()));
+            0.0
+        }
+    };
+    let cost_price_per_pack = price(record.cost_price_per_pack, "cost_price_per_pack");
+    let sell_price_per_pack = price(record.sell_price_per_pack, "sell_price_per_pack");
+
+    let location_id = match non_empty(record.location_code) {
+        None => None,
+        Some(code) => {
+            if !locations.contains_key(&code) {
+                let location = LocationRepository::new(connection)
+                    .query_by_filter(
+                        LocationFilter::new()
+                            .code(EqualFilter::equal_to(&code))
+                            .store_id(EqualFilter::equal_to(store_id)),
+                    )?
+                    .pop();
+                locations.insert(code.clone(), location.map(|location| location.id));
+            }
+            let location_id = locations[&code].clone();
+            if location_id.is_none() {
+                errors.push(ImportRowErrorKind::LocationDoesNotExist(code));
+            }
+            location_id
+        }
+    };
+
+    Ok(match item {
+        Some(item) if errors.is_empty() => Ok(OpeningStockLine {
+            item,
+            batch: non_empty(record.batch),
+            expiry_date,
+            pack_size,
+            number_of_packs,
+            cost_price_per_pack,
+            sell_price_per_pack,
+            location_id,
+        }),
+        _ => Err(errors),
+    })
+}
+
+/// Returns the result and the invoice lines of the new stock lines
+fn generate(
+    connection: &StorageConnection,
+    store_id: &str,
+    comment: Option<String>,
+    lines: Vec<OpeningStockLine>,
+) -> Result<(ImportOpeningStockResult, Vec<InvoiceLineRow>), ImportOpeningStockError> {
+    let inventory_adjustment_name = NameQueryRepository::new(connection)
+        .query_by_filter(
+            NameFilter::new().code(SimpleStringFilter::equal_to(INVENTORY_ADJUSTMENT_NAME_CODE)),
+        )?
+        .pop()
+        .ok_or(ImportOpeningStockError::InternalError(
+            "Missing inventory adjustment name".to_string(),
+        ))?;
+
+    let now = Utc::now().naive_utc();
+    let invoice = InvoiceRow {
+        id: uuid(),
+        name_id: inventory_adjustment_name.id,
+        store_id: store_id.to_string(),
+        invoice_number: next_number(connection, &NumberRowType::InventoryAdjustment, store_id)?,
+        r#type: InvoiceRowType::InventoryAdjustment,
+        status: InvoiceRowStatus::Verified,
+        on_hold: false,
+        comment: comment.or(Some("Opening balance".to_string())),
+        their_reference: None,
+        created_datetime: now,
+        allocated_datetime: None,
+        picked_datetime: None,
+        shipped_datetime: None,
+        delivered_datetime: None,
+        verified_datetime: Some(now),
+        color: None,
+        prescriber_id: None,
+    };
+
+    let mut stock_lines = Vec::new();
+    let mut invoice_lines = Vec::new();
+    for line in lines {
+        let stock_line = StockLineRow {
+            id: uuid(),
+            item_id: line.item.id.clone(),
+            store_id: store_id.to_string(),
+            location_id: line.location_id,
+            batch: line.batch,
+            pack_size: line.pack_size,
+            cost_price_per_pack: line.cost_price_per_pack,
+            sell_price_per_pack: line.sell_price_per_pack,
+            available_number_of_packs: line.number_of_packs,
+            total_number_of_packs: line.number_of_packs,
+            expiry_date: line.expiry_date,
+            on_hold: false,
+            note: None,
+        };
+        invoice_lines.push(InvoiceLineRow {
+            id: uuid(),
+            r#type: InvoiceLineRowType::StockIn,
+            invoice_id: invoice.id.clone(),
+            item_id: line.item.id,
+            item_name: line.item.name,
+            item_code: line.item.code,
+            stock_line_id: Some(stock_line.id.clone()),
+            location_id: stock_line.location_id.clone(),
+            batch: stock_line.batch.clone(),
+            expiry_date: stock_line.expiry_date,
+            pack_size: stock_line.pack_size,
+            cost_price_per_pack: stock_line.cost_price_per_pack,
+            sell_price_per_pack: stock_line.sell_price_per_pack,
+            total_before_tax: 0.0,
+            total_after_tax: 0.0,
+            tax: None,
+            number_of_packs: stock_line.total_number_of_packs,
+            note: None,
+            return_reason_id: None,
+            directions: None,
+        });
+        stock_lines.push(stock_line);
+    }
+
+    Ok((
+        ImportOpeningStockResult {
+            invoice,
+            stock_lines,
+        },
+        invoice_lines,
+    ))
+}
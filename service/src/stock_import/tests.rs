@@ -0,0 +1,175 @@
+#[cfg(test)]
+mod stock_import_test {
+    use chrono::NaiveDate;
+    use repository::{
+        mock::{mock_store_a, MockDataInserts},
+        schema::{InvoiceLineRowType, InvoiceRowStatus, InvoiceRowType},
+        test_db::setup_all,
+        InvoiceLineRowRepository, InvoiceRepository, StockLineRowRepository,
+    };
+
+    use crate::{
+        service_provider::ServiceProvider,
+        stock_import::{
+            opening_stock::ImportOpeningStock, ImportOpeningStockError, ImportRowError,
+            ImportRowErrorKind,
+        },
+    };
+
+    fn input(csv: &str) -> ImportOpeningStock {
+        ImportOpeningStock {
+            csv: csv.to_string(),
+            comment: None,
+        }
+    }
+
+    #[actix_rt::test]
+    async fn import_opening_stock_errors() {
+        let (_, _, connection_manager, _) =
+            setup_all("import_opening_stock_errors", MockDataInserts::all()).await;
+
+        let service_provider = ServiceProvider::new(connection_manager);
+        let context = service_provider.context().unwrap();
+        let service = service_provider.stock_import_service;
+        let store_id = mock_store_a().id;
+
+        // MissingColumn
+        assert_eq!(
+            service.import_opening_stock(&context, &store_id, input("item_code,pack_size\n")),
+            Err(ImportOpeningStockError::MissingColumn(
+                "number_of_packs".to_string()
+            ))
+        );
+
+        // NoRows
+        assert_eq!(
+            service.import_opening_stock(
+                &context,
+                &store_id,
+                input("item_code,pack_size,number_of_packs\n")
+            ),
+            Err(ImportOpeningStockError::NoRows)
+        );
+
+        // RowErrors
+        let csv = "\
+item_code,batch,expiry_date,pack_size,number_of_packs,cost_price_per_pack,sell_price_per_pack,location_code
+item_a_code,B1,2025-01-31,1,10,1.5,2.0,code_location_1
+unknown_code,B2,31/01/2025,0,10,1.5,2.0,
+,B3,,1,-1,-1,abc,store_b_location
+item_b_code,B4
+";
+        let errors = match service.import_opening_stock(&context, &store_id, input(csv)) {
+            Err(ImportOpeningStockError::RowErrors(errors)) => errors,
+            result => panic!("Expected row errors, got {:?}", result),
+        };
+        assert_eq!(errors.len(), 3);
+        assert_eq!(
+            errors[0],
+            ImportRowError {
+                line_number: 3,
+                errors: vec![
+                    ImportRowErrorKind::ItemDoesNotExist("unknown_code".to_string()),
+                    ImportRowErrorKind::InvalidExpiryDate("31/01/2025".to_string()),
+                    ImportRowErrorKind::PackSizeBelowOne,
+                ]
+            }
+        );
+        assert_eq!(
+            errors[1],
+            ImportRowError {
+                line_number: 4,
+                errors: vec![
+                    ImportRowErrorKind::MissingItemCode,
+                    ImportRowErrorKind::NumberOfPacksBelowOne,
+                    ImportRowErrorKind::InvalidPrice("cost_price_per_pack".to_string()),
+                    ImportRowErrorKind::InvalidPrice("sell_price_per_pack".to_string()),
+                    ImportRowErrorKind::LocationDoesNotExist("store_b_location".to_string()),
+                ]
+            }
+        );
+        assert_eq!(errors[2].line_number, 5);
+        assert!(matches!(
+            errors[2].errors[..],
+            [ImportRowErrorKind::InvalidRow(_)]
+        ));
+    }
+
+    #[actix_rt::test]
+    async fn import_opening_stock_success() {
+        let (_, connection, connection_manager, _) =
+            setup_all("import_opening_stock_success", MockDataInserts::all()).await;
+
+        let service_provider = ServiceProvider::new(connection_manager);
+        let context = service_provider.context().unwrap();
+        let service = service_provider.stock_import_service;
+        let store_id = mock_store_a().id;
+
+        let csv = "\
+item_code,batch,expiry_date,pack_size,number_of_packs,cost_price_per_pack,sell_price_per_pack,location_code
+item_a_code,B1,2025-01-31,1,10,1.5,2.0,code_location_1
+item_b_code,,,10,3,,,
+";
+        let result = service
+            .import_opening_stock(
+                &context,
+                &store_id,
+                ImportOpeningStock {
+                    csv: csv.to_string(),
+                    comment: Some("Go live".to_string()),
+                },
+            )
+            .unwrap();
+
+        let invoice = InvoiceRepository::new(&connection)
+            .find_one_by_id(&result.invoice.id)
+            .unwrap();
+        assert_eq!(invoice.r#type, InvoiceRowType::InventoryAdjustment);
+        assert_eq!(invoice.status, InvoiceRowStatus::Verified);
+        assert_eq!(invoice.store_id, store_id);
+        assert_eq!(invoice.comment, Some("Go live".to_string()));
+
+        assert_eq!(result.stock_lines.len(), 2);
+        let stock_line_ids: Vec<String> = result
+            .stock_lines
+            .iter()
+            .map(|line| line.id.clone())
+            .collect();
+        let stock_lines = StockLineRowRepository::new(&connection)
+            .find_many_by_ids(&stock_line_ids)
+            .unwrap();
+        let first = stock_lines
+            .iter()
+            .find(|line| line.item_id == "item_a")
+            .unwrap();
+        assert_eq!(first.batch, Some("B1".to_string()));
+        assert_eq!(first.expiry_date, Some(NaiveDate::from_ymd(2025, 1, 31)));
+        assert_eq!(first.location_id, Some("location_1".to_string()));
+        assert_eq!(first.available_number_of_packs, 10);
+        assert_eq!(first.total_number_of_packs, 10);
+        assert_eq!(first.cost_price_per_pack, 1.5);
+        assert_eq!(first.sell_price_per_pack, 2.0);
+        let second = stock_lines
+            .iter()
+            .find(|line| line.item_id == "item_b")
+            .unwrap();
+        assert_eq!(second.batch, None);
+        assert_eq!(second.location_id, None);
+        assert_eq!(second.pack_size, 10);
+        assert_eq!(second.cost_price_per_pack, 0.0);
+
+        let invoice_lines = InvoiceLineRowRepository::new(&connection)
+            .find_many_by_invoice_id(&invoice.id)
+            .unwrap();
+        assert_eq!(invoice_lines.len(), 2);
+        for line in invoice_lines {
+            assert_eq!(line.r#type, InvoiceLineRowType::StockIn);
+            let stock_line = stock_lines
+                .iter()
+                .find(|stock_line| Some(&stock_line.id) == line.stock_line_id.as_ref())
+                .unwrap();
+            assert_eq!(line.item_id, stock_line.item_id);
+            assert_eq!(line.number_of_packs, stock_line.total_number_of_packs);
+        }
+    }
+}
@@ -1,13 +1,28 @@
-use crate::SingleRecordError;
+use crate::{get_default_pagination, i64_to_u32, ListError, ListResult, SingleRecordError};
 use domain::{
     stock_line::{StockLine, StockLineFilter},
-    Pagination, EqualFilter,
+    EqualFilter, Pagination, PaginationOption,
 };
 use repository::{StockLineRepository, StorageConnectionManager};
 
 pub const MAX_LIMIT: u32 = 1000;
 pub const MIN_LIMIT: u32 = 1;
 
+pub fn get_stock_lines(
+    connection_manager: &StorageConnectionManager,
+    pagination: Option<PaginationOption>,
+    filter: Option<StockLineFilter>,
+) -> Result<ListResult<StockLine>, ListError> {
+    let pagination = get_default_pagination(pagination, MAX_LIMIT, MIN_LIMIT)?;
+    let connection = connection_manager.connection()?;
+    let repository = StockLineRepository::new(&connection);
+
+    Ok(ListResult {
+        rows: repository.query(pagination, filter.clone(), None)?,
+        count: i64_to_u32(repository.count(filter)?),
+    })
+}
+
 pub fn get_stock_line(
     connection_manager: &StorageConnectionManager,
     id: String,
@@ -0,0 +1,137 @@
+use chrono::NaiveDateTime;
+use domain::{DatetimeFilter, EqualFilter};
+use repository::{
+    schema::StockMovementRow, RepositoryError, StockMovementFilter, StockMovementRepository,
+};
+
+use crate::service_provider::ServiceContext;
+
+#[cfg(test)]
+mod tests;
+
+/// Selects the stock movements of a ledger, at least one of item, stock line (batch) or location
+/// must be set
+#[derive(Clone, Debug, Default)]
+pub struct StockLedgerInput {
+    pub item_id: Option<String>,
+    pub stock_line_id: Option<String>,
+    pub location_id: Option<String>,
+    pub from_datetime: Option<NaiveDateTime>,
+    pub to_datetime: Option<NaiveDateTime>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct StockLedgerLine {
+    pub movement: StockMovementRow,
+    /// Units received (number of packs * pack size)
+    pub quantity_in: i64,
+    /// Units issued (number of packs * pack size)
+    pub quantity_out: i64,
+    /// Units on hand after the movement
+    pub balance: i64,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct StockLedger {
+    /// Units on hand before the first movement in the date range
+    pub opening_balance: i64,
+    pub closing_balance: i64,
+    pub lines: Vec<StockLedgerLine>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum StockLedgerError {
+    DatabaseError(RepositoryError),
+    MissingItemBatchOrLocation,
+    InvalidDateRange,
+}
+
+impl From<RepositoryError> for StockLedgerError {
+    fn from(error: RepositoryError) -> Self {
+        StockLedgerError::DatabaseError(error)
+    }
+}
+
+pub trait StockMovementServiceTrait: Sync + Send {
+    /// Chronological list of stock movements of the store with a running balance
+    fn get_stock_ledger(
+        &self,
+        ctx: &ServiceContext,
+        store_id: &str,
+        input: StockLedgerInput,
+    ) -> Result<StockLedger, StockLedgerError> {
+        get_stock_ledger(ctx, store_id, input)
+    }
+}
+
+pub struct StockMovementService {}
+impl StockMovementServiceTrait for StockMovementService {}
+
+pub fn get_stock_ledger(
+    ctx: &ServiceContext,
+    store_id: &str,
+    input: StockLedgerInput,
+) -> Result<StockLedger, StockLedgerError> {
+    if input.item_id.is_none() && input.stock_line_id.is_none() && input.location_id.is_none() {
+        return Err(StockLedgerError::MissingItemBatchOrLocation);
+    }
+    if let (Some(from), Some(to)) = (&input.from_datetime, &input.to_datetime) {
+        if from > to {
+            return Err(StockLedgerError::InvalidDateRange);
+        }
+    }
+
+    let mut filter = StockMovementFilter::new().store_id(EqualFilter::equal_to(store_id));
+    if let Some(item_id) = &input.item_id {
+        filter = filter.item_id(EqualFilter::equal_to(item_id));
+    }
+    if let Some(stock_line_id) = &input.stock_line_id {
+        filter = filter.stock_line_id(EqualFilter::equal_to(stock_line_id));
+    }
+    if let Some(location_id) = &input.location_id {
+        filter = filter.location_id(EqualFilter::equal_to(location_id));
+    }
+    let until_end = match input.to_datetime {
+        Some(to) => filter.clone().datetime(DatetimeFilter {
+            equal_to: None,
+            before_or_equal_to: Some(to),
+            after_or_equal_to: None,
+        }),
+        None => filter.clone(),
+    };
+    let in_range = filter.datetime(DatetimeFilter {
+        equal_to: None,
+        before_or_equal_to: input.to_datetime,
+        after_or_equal_to: input.from_datetime,
+    });
+
+    let repository = StockMovementRepository::new(&ctx.connection);
+    let movements = repository.query_by_filter(in_range)?;
+    let closing_balance = repository.sum_units(until_end)?;
+    let opening_balance = closing_balance - movements.iter().map(units).sum::<i64>();
+
+    let mut balance = opening_balance;
+    let lines = movements
+        .into_iter()
+        .map(|movement| {
+            let quantity = units(&movement);
+            balance += quantity;
+            StockLedgerLine {
+                movement,
+                quantity_in: quantity.max(0),
+                quantity_out: (-quantity).max(0),
+                balance,
+            }
+        })
+        .collect();
+
+    Ok(StockLedger {
+        opening_balance,
+        closing_balance,
+        lines,
+    })
+}
+
+fn units(movement: &StockMovementRow) -> i64 {
+    movement.number_of_packs as i64 * movement.pack_size as i64
+}
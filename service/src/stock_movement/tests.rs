@@ -0,0 +1,328 @@
+#[cfg(test)]
+mod stock_movement_test {
+    use chrono::{NaiveDate, NaiveDateTime};
+    use domain::inbound_shipment::InsertInboundShipmentLine;
+    use repository::{
+        mock::{mock_name_store_b, mock_store_a, MockDataInserts},
+        schema::{
+            InvoiceLineRow, InvoiceLineRowType, InvoiceRow, InvoiceRowStatus, InvoiceRowType,
+            StorePreferenceRow,
+        },
+        test_db::setup_all,
+        InvoiceLineRowRepository, InvoiceRepository, StockLineRowRepository, StorageConnection,
+        StorePreferenceRowRepository,
+    };
+
+    use crate::{
+        invoice_line::insert_inbound_shipment_line,
+        service_provider::ServiceProvider,
+        stock_movement::{StockLedgerError, StockLedgerInput},
+    };
+
+    fn datetime(day: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd(2021, 3, day).and_hms(10, 0, 0)
+    }
+
+    fn insert_invoice(
+        connection: &StorageConnection,
+        id: &str,
+        r#type: InvoiceRowType,
+        status: InvoiceRowStatus,
+        day: u32,
+        lines: Vec<(InvoiceLineRowType, i32)>,
+    ) {
+        let status_datetime = Some(datetime(day));
+        let invoice = InvoiceRow {
+            id: id.to_string(),
+            name_id: mock_name_store_b().id,
+            store_id: mock_store_a().id,
+            invoice_number: 1,
+            r#type,
+            status,
+            on_hold: false,
+            comment: None,
+            their_reference: None,
+            created_datetime: datetime(1),
+            allocated_datetime: None,
+            picked_datetime: status_datetime,
+            shipped_datetime: None,
+            delivered_datetime: status_datetime,
+            verified_datetime: status_datetime,
+            color: None,
+            prescriber_id: None,
+        };
+        InvoiceRepository::new(connection)
+            .upsert_one(&invoice)
+            .unwrap();
+
+        for (index, (r#type, number_of_packs)) in lines.into_iter().enumerate() {
+            InvoiceLineRowRepository::new(connection)
+                .upsert_one(&InvoiceLineRow {
+                    id: format!("{}_line{}", id, index),
+                    invoice_id: id.to_string(),
+                    item_id: "item_query_test2".to_string(),
+                    item_name: "".to_string(),
+                    item_code: "".to_string(),
+                    stock_line_id: Some("item_a_line_a".to_string()),
+                    location_id: None,
+                    batch: None,
+                    expiry_date: None,
+                    pack_size: 2,
+                    cost_price_per_pack: 0.0,
+                    sell_price_per_pack: 0.0,
+                    total_before_tax: 0.0,
+                    total_after_tax: 0.0,
+                    tax: None,
+                    r#type,
+                    number_of_packs,
+                    note: None,
+                    return_reason_id: None,
+                    directions: None,
+                })
+                .unwrap();
+        }
+    }
+
+    #[actix_rt::test]
+    async fn stock_ledger() {
+        let (_, connection, connection_manager, _) =
+            setup_all("stock_ledger", MockDataInserts::all()).await;
+
+        let service_provider = ServiceProvider::new(connection_manager);
+        let context = service_provider.context().unwrap();
+        let service = service_provider.stock_movement_service;
+
+        insert_invoice(
+            &connection,
+            "ledger_inbound",
+            InvoiceRowType::InboundShipment,
+            InvoiceRowStatus::Delivered,
+            2,
+            vec![(InvoiceLineRowType::StockIn, 50)],
+        );
+        insert_invoice(
+            &connection,
+            "ledger_outbound",
+            InvoiceRowType::OutboundShipment,
+            InvoiceRowStatus::Picked,
+            5,
+            vec![
+                (InvoiceLineRowType::StockOut, 10),
+                (InvoiceLineRowType::StockOut, 5),
+            ],
+        );
+        insert_invoice(
+            &connection,
+            "ledger_adjustment",
+            InvoiceRowType::InventoryAdjustment,
+            InvoiceRowStatus::Verified,
+            9,
+            vec![(InvoiceLineRowType::StockIn, 2)],
+        );
+        // not yet affecting stock
+        insert_invoice(
+            &connection,
+            "ledger_new_outbound",
+            InvoiceRowType::OutboundShipment,
+            InvoiceRowStatus::New,
+            10,
+            vec![(InvoiceLineRowType::StockOut, 20)],
+        );
+
+        // full ledger
+        let ledger = service
+            .get_stock_ledger(
+                &context,
+                &mock_store_a().id,
+                StockLedgerInput {
+                    item_id: Some("item_query_test2".to_string()),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        assert_eq!(ledger.opening_balance, 0);
+        assert_eq!(ledger.closing_balance, 74);
+        let lines: Vec<(String, i64, i64, i64)> = ledger
+            .lines
+            .iter()
+            .map(|line| {
+                (
+                    line.movement.invoice_id.clone(),
+                    line.quantity_in,
+                    line.quantity_out,
+                    line.balance,
+                )
+            })
+            .collect();
+        assert_eq!(
+            lines,
+            vec![
+                ("ledger_inbound".to_string(), 100, 0, 100),
+                ("ledger_outbound".to_string(), 0, 20, 80),
+                ("ledger_outbound".to_string(), 0, 10, 70),
+                ("ledger_adjustment".to_string(), 4, 0, 74),
+            ]
+        );
+        assert_eq!(
+            ledger.lines[0].movement.other_party_name,
+            mock_name_store_b().name
+        );
+
+        // date range
+        let ledger = service
+            .get_stock_ledger(
+                &context,
+                &mock_store_a().id,
+                StockLedgerInput {
+                    stock_line_id: Some("item_a_line_a".to_string()),
+                    item_id: Some("item_query_test2".to_string()),
+                    from_datetime: Some(datetime(3)),
+                    to_datetime: Some(datetime(6)),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        assert_eq!(ledger.opening_balance, 100);
+        assert_eq!(ledger.closing_balance, 70);
+        assert_eq!(ledger.lines.len(), 2);
+
+        // errors
+        assert_eq!(
+            service.get_stock_ledger(&context, &mock_store_a().id, StockLedgerInput::default()),
+            Err(StockLedgerError::MissingItemBatchOrLocation)
+        );
+        assert_eq!(
+            service.get_stock_ledger(
+                &context,
+                &mock_store_a().id,
+                StockLedgerInput {
+                    item_id: Some("item_query_test2".to_string()),
+                    from_datetime: Some(datetime(6)),
+                    to_datetime: Some(datetime(3)),
+                    ..Default::default()
+                },
+            ),
+            Err(StockLedgerError::InvalidDateRange)
+        );
+    }
+
+    #[actix_rt::test]
+    async fn stock_ledger_pack_to_one() {
+        let (_, connection, connection_manager, _) =
+            setup_all("stock_ledger_pack_to_one", MockDataInserts::all()).await;
+
+        let service_provider = ServiceProvider::new(connection_manager);
+        let context = service_provider.context().unwrap();
+        let service = service_provider.stock_movement_service;
+
+        StorePreferenceRowRepository::new(&connection)
+            .upsert_one(&StorePreferenceRow {
+                id: "store_a_preferences".to_string(),
+                store_id: mock_store_a().id,
+                pack_to_one: true,
+                months_overstock: 6.0,
+                months_understock: 3.0,
+                months_items_expire: 3.0,
+            })
+            .unwrap();
+
+        // receive 2 packs of 10
+        insert_invoice(
+            &connection,
+            "pack_to_one_inbound",
+            InvoiceRowType::InboundShipment,
+            InvoiceRowStatus::Delivered,
+            2,
+            Vec::new(),
+        );
+        insert_inbound_shipment_line(
+            &connection,
+            &service_provider.plugins,
+            InsertInboundShipmentLine {
+                id: "pack_to_one_inbound_line".to_string(),
+                invoice_id: "pack_to_one_inbound".to_string(),
+                item_id: "item_query_test2".to_string(),
+                location_id: None,
+                pack_size: 10,
+                batch: None,
+                cost_price_per_pack: 50.0,
+                sell_price_per_pack: 60.0,
+                expiry_date: None,
+                number_of_packs: 2,
+                pack_variant_id: None,
+                total_before_tax: 100.0,
+                total_after_tax: 100.0,
+                tax: None,
+            },
+        )
+        .unwrap();
+
+        // invoice line is converted to single units like the stock line it introduces
+        let line = InvoiceLineRowRepository::new(&connection)
+            .find_one_by_id("pack_to_one_inbound_line")
+            .unwrap();
+        let stock_line_id = line.stock_line_id.clone().unwrap();
+        let stock_line = StockLineRowRepository::new(&connection)
+            .find_one_by_id(&stock_line_id)
+            .unwrap();
+        assert_eq!((line.pack_size, line.number_of_packs), (1, 20));
+        assert_eq!(line.cost_price_per_pack, 5.0);
+        assert_eq!(line.sell_price_per_pack, 6.0);
+        assert_eq!(
+            (stock_line.pack_size, stock_line.total_number_of_packs),
+            (line.pack_size, line.number_of_packs)
+        );
+
+        // issue 5 units from the received stock line
+        insert_invoice(
+            &connection,
+            "pack_to_one_outbound",
+            InvoiceRowType::OutboundShipment,
+            InvoiceRowStatus::Picked,
+            5,
+            Vec::new(),
+        );
+        InvoiceLineRowRepository::new(&connection)
+            .upsert_one(&InvoiceLineRow {
+                id: "pack_to_one_outbound_line".to_string(),
+                invoice_id: "pack_to_one_outbound".to_string(),
+                stock_line_id: Some(stock_line_id.clone()),
+                pack_size: stock_line.pack_size,
+                number_of_packs: 5,
+                r#type: InvoiceLineRowType::StockOut,
+                ..line
+            })
+            .unwrap();
+
+        let ledger = service
+            .get_stock_ledger(
+                &context,
+                &mock_store_a().id,
+                StockLedgerInput {
+                    stock_line_id: Some(stock_line_id),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        let lines: Vec<(String, i32, i32, i64)> = ledger
+            .lines
+            .iter()
+            .map(|line| {
+                (
+                    line.movement.invoice_id.clone(),
+                    line.movement.pack_size,
+                    line.movement.number_of_packs,
+                    line.balance,
+                )
+            })
+            .collect();
+        assert_eq!(
+            lines,
+            vec![
+                ("pack_to_one_inbound".to_string(), 1, 20, 20),
+                ("pack_to_one_outbound".to_string(), 1, -5, 15),
+            ]
+        );
+        assert_eq!(ledger.closing_balance, 15);
+    }
+}
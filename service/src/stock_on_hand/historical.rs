@@ -0,0 +1,126 @@
+use std::collections::BTreeMap;
+
+use chrono::{NaiveDate, NaiveDateTime};
+use domain::{stock_line::StockLineFilter, EqualFilter, Pagination};
+use repository::{
+    RepositoryError, StockLineMovementTotal, StockLineRepository, StockMovementRepository,
+};
+
+use crate::service_provider::ServiceContext;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum HistoricalStockOnHandGroupBy {
+    Item,
+    /// Per stock line
+    Batch,
+    Location,
+}
+
+/// Stock on hand of an item at a point in time, batch and location fields are only set if they
+/// are part of the grouping
+#[derive(Clone, Debug, PartialEq)]
+pub struct HistoricalStockOnHand {
+    pub item_id: String,
+    pub stock_line_id: Option<String>,
+    pub batch: Option<String>,
+    pub expiry_date: Option<NaiveDate>,
+    pub location_id: Option<String>,
+    /// Number of units (number of packs * pack size)
+    pub total_units: i64,
+}
+
+/// Rebuilds the stock on hand of the store at `datetime` by taking the current stock of every
+/// stock line and reversing the movements (see stock_movement view) that happened after
+/// `datetime`.
+///
+/// Locations aren't tracked historically, a stock line is reported at its current location.
+/// Rows without stock at `datetime` are omitted, rows are ordered by item id.
+pub fn get_historical_stock_on_hand(
+    ctx: &ServiceContext,
+    store_id: &str,
+    datetime: NaiveDateTime,
+    group_by: HistoricalStockOnHandGroupBy,
+    item_id: Option<EqualFilter<String>>,
+) -> Result<Vec<HistoricalStockOnHand>, RepositoryError> {
+    let mut stock_line_filter = StockLineFilter::new().store_id(EqualFilter::equal_to(store_id));
+    stock_line_filter.item_id = item_id.clone();
+    let stock_lines = StockLineRepository::new(&ctx.connection).query(
+        Pagination::all(),
+        Some(stock_line_filter),
+        None,
+    )?;
+    let movements = StockMovementRepository::new(&ctx.connection)
+        .sum_units_by_stock_line_after(store_id, item_id, datetime)?;
+
+    // Historical stock per stock line, keyed by (item_id, stock_line_id)
+    let mut per_stock_line: BTreeMap<(String, Option<String>), HistoricalStockOnHand> =
+        BTreeMap::new();
+    for stock_line in stock_lines {
+        per_stock_line.insert(
+            (stock_line.item_id.clone(), Some(stock_line.id.clone())),
+            HistoricalStockOnHand {
+                item_id: stock_line.item_id,
+                stock_line_id: Some(stock_line.id),
+                batch: stock_line.batch,
+                expiry_date: stock_line.expiry_date,
+                location_id: stock_line.location_id,
+                total_units: stock_line.total_number_of_packs as i64 * stock_line.pack_size as i64,
+            },
+        );
+    }
+    for StockLineMovementTotal {
+        item_id,
+        stock_line_id,
+        units,
+    } in movements
+    {
+        // movements of deleted stock lines still count towards the item
+        per_stock_line
+            .entry((item_id.clone(), stock_line_id.clone()))
+            .or_insert_with(|| HistoricalStockOnHand {
+                item_id,
+                stock_line_id,
+                batch: None,
+                expiry_date: None,
+                location_id: None,
+                total_units: 0,
+            })
+            .total_units -= units;
+    }
+
+    let rows = per_stock_line.into_values();
+    let result = match group_by {
+        HistoricalStockOnHandGroupBy::Batch => rows.filter(|row| row.total_units != 0).collect(),
+        HistoricalStockOnHandGroupBy::Item => group(rows, |row| (row.item_id.clone(), None)),
+        HistoricalStockOnHandGroupBy::Location => {
+            group(rows, |row| (row.item_id.clone(), row.location_id.clone()))
+        }
+    };
+    Ok(result)
+}
+
+fn group<I, F>(rows: I, key: F) -> Vec<HistoricalStockOnHand>
+where
+    I: Iterator<Item = HistoricalStockOnHand>,
+    F: Fn(&HistoricalStockOnHand) -> (String, Option<String>),
+{
+    let mut grouped: BTreeMap<(String, Option<String>), HistoricalStockOnHand> = BTreeMap::new();
+    for row in rows {
+        let (item_id, location_id) = key(&row);
+        grouped
+            .entry((item_id.clone(), location_id.clone()))
+            .or_insert_with(|| HistoricalStockOnHand {
+                item_id,
+                stock_line_id: None,
+                batch: None,
+                expiry_date: None,
+                location_id,
+                total_units: 0,
+            })
+            .total_units += row.total_units;
+    }
+    grouped
+        .into_values()
+        .filter(|row| row.total_units != 0)
+        .collect()
+}
@@ -0,0 +1,97 @@
+use std::collections::HashMap;
+
+use chrono::{Duration, NaiveDate, NaiveDateTime};
+use domain::EqualFilter;
+use repository::{
+    ConsumptionRepository, RepositoryError, StockOnHandFilter, StockOnHandRepository,
+    StorageConnection,
+};
+
+/// Number of months of consumption used for the average monthly consumption
+pub const AVERAGE_MONTHLY_CONSUMPTION_LOOKBACK_MONTHS: u32 = 3;
+const DAYS_PER_MONTH: f64 = 365.25 / 12.0;
+
+/// Stock figures of an item in a store
+#[derive(Clone, Debug, PartialEq)]
+pub struct ItemStockSummary {
+    pub item_id: String,
+    pub total_number_of_packs: i64,
+    pub available_number_of_packs: i64,
+    pub total_units: i64,
+    pub available_units: i64,
+    pub number_of_batches: i64,
+    pub earliest_expiry_date: Option<NaiveDate>,
+    /// Value of the stock on hand at cost price
+    pub total_cost_value: f64,
+    /// Average units issued (shipped or dispensed) per month over the lookback period
+    pub average_monthly_consumption: f64,
+    /// Number of months the stock on hand lasts at the average monthly consumption, `None` if
+    /// there was no consumption
+    pub months_of_stock: Option<f64>,
+}
+
+impl ItemStockSummary {
+    fn empty(item_id: &str) -> ItemStockSummary {
+        ItemStockSummary {
+            item_id: item_id.to_string(),
+            total_number_of_packs: 0,
+            available_number_of_packs: 0,
+            total_units: 0,
+            available_units: 0,
+            number_of_batches: 0,
+            earliest_expiry_date: None,
+            total_cost_value: 0.0,
+            average_monthly_consumption: 0.0,
+            months_of_stock: None,
+        }
+    }
+}
+
+/// Returns a summary for every requested item, items without stock have zero values
+pub fn get_item_stock_summaries(
+    connection: &StorageConnection,
+    store_id: &str,
+    item_ids: Vec<String>,
+    now: NaiveDateTime,
+) -> Result<Vec<ItemStockSummary>, RepositoryError> {
+    let stock_on_hand = StockOnHandRepository::new(connection).query_by_filter(
+        StockOnHandFilter::new()
+            .store_id(EqualFilter::equal_to(store_id))
+            .item_id(EqualFilter::equal_any(item_ids.clone())),
+    )?;
+    let mut stock_on_hand: HashMap<String, _> = stock_on_hand
+        .into_iter()
+        .map(|row| (row.item_id.clone(), row))
+        .collect();
+
+    let lookback_months = AVERAGE_MONTHLY_CONSUMPTION_LOOKBACK_MONTHS as f64;
+    let from = now - Duration::days((lookback_months * DAYS_PER_MONTH).round() as i64);
+    let consumption: HashMap<String, i64> = ConsumptionRepository::new(connection)
+        .query_by_item(store_id, item_ids.clone(), from, now)?
+        .into_iter()
+        .map(|row| (row.item_id, row.quantity))
+        .collect();
+
+    Ok(item_ids
+        .iter()
+        .map(|item_id| {
+            let mut summary = ItemStockSummary::empty(item_id);
+            if let Some(row) = stock_on_hand.remove(item_id) {
+                summary.total_number_of_packs = row.total_number_of_packs;
+                summary.available_number_of_packs = row.available_number_of_packs;
+                summary.total_units = row.total_units;
+                summary.available_units = row.available_units;
+                summary.number_of_batches = row.number_of_batches;
+                summary.earliest_expiry_date = row.earliest_expiry_date;
+                summary.total_cost_value = row.total_cost_value;
+            }
+            let consumed = consumption.get(item_id).copied().unwrap_or(0);
+            summary.average_monthly_consumption = consumed as f64 / lookback_months;
+            if summary.average_monthly_consumption > 0.0 {
+                summary.months_of_stock =
+                    Some(summary.total_units as f64 / summary.average_monthly_consumption);
+            }
+            summary
+        })
+        .collect())
+}
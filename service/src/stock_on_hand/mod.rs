@@ -0,0 +1,63 @@
+use chrono::NaiveDateTime;
+use domain::{EqualFilter, PaginationOption};
+use repository::{RepositoryError, StockOnHand, StockOnHandFilter, StockOnHandSort};
+
+use crate::{service_provider::ServiceContext, ListError, ListResult};
+
+use self::{
+    historical::{
+        get_historical_stock_on_hand, HistoricalStockOnHand, HistoricalStockOnHandGroupBy,
+    },
+    item_summary::{get_item_stock_summaries, ItemStockSummary},
+    query::get_stock_on_hand,
+};
+
+pub mod historical;
+pub mod item_summary;
+pub mod query;
+
+#[cfg(test)]
+mod tests;
+
+pub trait StockOnHandServiceTrait: Sync + Send {
+    /// Stock on hand of the store aggregated per item, or per item and location if `by_location`
+    /// is set
+    fn get_stock_on_hand(
+        &self,
+        ctx: &ServiceContext,
+        store_id: &str,
+        by_location: bool,
+        pagination: Option<PaginationOption>,
+        filter: Option<StockOnHandFilter>,
+        sort: Option<StockOnHandSort>,
+    ) -> Result<ListResult<StockOnHand>, ListError> {
+        get_stock_on_hand(ctx, store_id, by_location, pagination, filter, sort)
+    }
+
+    /// # Arguments
+    /// * now the time used to calculate the average monthly consumption (useful for testing)
+    fn get_item_stock_summaries(
+        &self,
+        ctx: &ServiceContext,
+        store_id: &str,
+        item_ids: Vec<String>,
+        now: NaiveDateTime,
+    ) -> Result<Vec<ItemStockSummary>, RepositoryError> {
+        get_item_stock_summaries(&ctx.connection, store_id, item_ids, now)
+    }
+
+    /// Stock on hand of the store as it was at `datetime`
+    fn get_historical_stock_on_hand(
+        &self,
+        ctx: &ServiceContext,
+        store_id: &str,
+        datetime: NaiveDateTime,
+        group_by: HistoricalStockOnHandGroupBy,
+        item_id: Option<EqualFilter<String>>,
+    ) -> Result<Vec<HistoricalStockOnHand>, RepositoryError> {
+        get_historical_stock_on_hand(ctx, store_id, datetime, group_by, item_id)
+    }
+}
+
+pub struct StockOnHandService {}
+impl StockOnHandServiceTrait for StockOnHandService {}
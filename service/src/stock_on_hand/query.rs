@@ -0,0 +1,37 @@
+use domain::{EqualFilter, PaginationOption};
+use repository::{StockOnHand, StockOnHandFilter, StockOnHandRepository, StockOnHandSort};
+
+use crate::{
+    get_default_pagination, i64_to_u32, service_provider::ServiceContext, ListError, ListResult,
+};
+
+pub const MAX_LIMIT: u32 = 1000;
+pub const MIN_LIMIT: u32 = 1;
+
+pub fn get_stock_on_hand(
+    ctx: &ServiceContext,
+    store_id: &str,
+    by_location: bool,
+    pagination: Option<PaginationOption>,
+    filter: Option<StockOnHandFilter>,
+    sort: Option<StockOnHandSort>,
+) -> Result<ListResult<StockOnHand>, ListError> {
+    let pagination = get_default_pagination(pagination, MAX_LIMIT, MIN_LIMIT)?;
+    let filter = filter
+        .unwrap_or(StockOnHandFilter::new())
+        .store_id(EqualFilter::equal_to(store_id));
+    let repository = StockOnHandRepository::new(&ctx.connection);
+
+    let result = if by_location {
+        ListResult {
+            rows: repository.query_by_location(pagination, Some(filter.clone()), sort)?,
+            count: i64_to_u32(repository.count_by_location(Some(filter))?),
+        }
+    } else {
+        ListResult {
+            rows: repository.query(pagination, Some(filter.clone()), sort)?,
+            count: i64_to_u32(repository.count(Some(filter))?),
+        }
+    };
+    Ok(result)
+}
@@ -0,0 +1,312 @@
+#[cfg(test)]
+mod stock_on_hand_test {
+    use chrono::{Duration, NaiveDate};
+    use domain::EqualFilter;
+    use repository::{
+        mock::{mock_store_a, MockDataInserts},
+        schema::{
+            InvoiceLineRow, InvoiceLineRowType, InvoiceRow, InvoiceRowStatus, InvoiceRowType,
+            StockLineRow,
+        },
+        test_db::setup_all,
+        InvoiceLineRowRepository, InvoiceRepository, StockLineRowRepository, StockOnHandFilter,
+    };
+
+    use crate::{
+        service_provider::ServiceProvider, stock_on_hand::historical::HistoricalStockOnHandGroupBy,
+        ListError,
+    };
+
+    fn outbound_shipment(id: &str, shipped_datetime: chrono::NaiveDateTime) -> InvoiceRow {
+        InvoiceRow {
+            id: id.to_string(),
+            name_id: "name_store_b".to_string(),
+            store_id: mock_store_a().id,
+            invoice_number: 100,
+            r#type: InvoiceRowType::OutboundShipment,
+            status: InvoiceRowStatus::Shipped,
+            on_hold: false,
+            comment: None,
+            their_reference: None,
+            created_datetime: shipped_datetime,
+            allocated_datetime: None,
+            picked_datetime: None,
+            shipped_datetime: Some(shipped_datetime),
+            delivered_datetime: None,
+            verified_datetime: None,
+            color: None,
+            prescriber_id: None,
+        }
+    }
+
+    fn outbound_line(id: &str, invoice_id: &str, number_of_packs: i32) -> InvoiceLineRow {
+        InvoiceLineRow {
+            id: id.to_string(),
+            invoice_id: invoice_id.to_string(),
+            item_id: "item_query_test2".to_string(),
+            item_name: "".to_string(),
+            item_code: "".to_string(),
+            stock_line_id: None,
+            location_id: None,
+            batch: None,
+            expiry_date: None,
+            pack_size: 10,
+            cost_price_per_pack: 0.0,
+            sell_price_per_pack: 0.0,
+            total_before_tax: 0.0,
+            total_after_tax: 0.0,
+            tax: None,
+            r#type: InvoiceLineRowType::StockOut,
+            number_of_packs,
+            note: None,
+            return_reason_id: None,
+            directions: None,
+        }
+    }
+
+    #[actix_rt::test]
+    async fn stock_on_hand_item_summaries() {
+        let (_, connection, connection_manager, _) =
+            setup_all("stock_on_hand_item_summaries", MockDataInserts::all()).await;
+
+        let service_provider = ServiceProvider::new(connection_manager);
+        let context = service_provider.context().unwrap();
+        let service = service_provider.stock_on_hand_service;
+
+        let now = NaiveDate::from_ymd(2021, 6, 30).and_hms(12, 0, 0);
+        StockLineRowRepository::new(&connection)
+            .upsert_one(&StockLineRow {
+                id: "stock_on_hand_line".to_string(),
+                item_id: "item_query_test2".to_string(),
+                store_id: mock_store_a().id,
+                location_id: None,
+                batch: None,
+                pack_size: 10,
+                cost_price_per_pack: 4.0,
+                sell_price_per_pack: 5.0,
+                available_number_of_packs: 8,
+                total_number_of_packs: 9,
+                expiry_date: Some(NaiveDate::from_ymd(2022, 1, 1)),
+                on_hold: false,
+                note: None,
+            })
+            .unwrap();
+        // shipped within the consumption period
+        let recent = outbound_shipment("stock_on_hand_recent", now - Duration::days(10));
+        // shipped before the consumption period
+        let old = outbound_shipment("stock_on_hand_old", now - Duration::days(200));
+        // dispensed within the consumption period
+        let prescription = InvoiceRow {
+            r#type: InvoiceRowType::Prescription,
+            status: InvoiceRowStatus::Picked,
+            picked_datetime: Some(now - Duration::days(20)),
+            shipped_datetime: None,
+            ..outbound_shipment("stock_on_hand_prescription", now - Duration::days(20))
+        };
+        for invoice in vec![recent.clone(), old.clone(), prescription.clone()] {
+            InvoiceRepository::new(&connection)
+                .upsert_one(&invoice)
+                .unwrap();
+        }
+        for line in vec![
+            outbound_line("stock_on_hand_recent_line_a", &recent.id, 2),
+            outbound_line("stock_on_hand_recent_line_b", &recent.id, 1),
+            outbound_line("stock_on_hand_old_line", &old.id, 50),
+            outbound_line("stock_on_hand_prescription_line", &prescription.id, 3),
+        ] {
+            InvoiceLineRowRepository::new(&connection)
+                .upsert_one(&line)
+                .unwrap();
+        }
+
+        let summaries = service
+            .get_item_stock_summaries(
+                &context,
+                &mock_store_a().id,
+                vec![
+                    "item_query_test2".to_string(),
+                    "mock_item_service_item".to_string(),
+                ],
+                now,
+            )
+            .unwrap();
+        assert_eq!(summaries.len(), 2);
+
+        let summary = &summaries[0];
+        assert_eq!(summary.item_id, "item_query_test2");
+        assert_eq!(summary.total_number_of_packs, 9);
+        assert_eq!(summary.available_units, 80);
+        assert_eq!(summary.number_of_batches, 1);
+        assert_eq!(
+            summary.earliest_expiry_date,
+            Some(NaiveDate::from_ymd(2022, 1, 1))
+        );
+        assert_eq!(summary.total_cost_value, 36.0);
+        // 30 units shipped and 30 units dispensed over 3 months
+        assert_eq!(summary.average_monthly_consumption, 20.0);
+        assert_eq!(summary.months_of_stock, Some(4.5));
+
+        // items without stock
+        let summary = &summaries[1];
+        assert_eq!(summary.item_id, "mock_item_service_item");
+        assert_eq!(summary.total_units, 0);
+        assert_eq!(summary.months_of_stock, None);
+    }
+
+    #[actix_rt::test]
+    async fn stock_on_hand_query() {
+        let (_, _, connection_manager, _) =
+            setup_all("stock_on_hand_query", MockDataInserts::all()).await;
+
+        let service_provider = ServiceProvider::new(connection_manager);
+        let context = service_provider.context().unwrap();
+        let service = service_provider.stock_on_hand_service;
+
+        // only stock of the requested store is returned
+        let result = service
+            .get_stock_on_hand(&context, &mock_store_a().id, false, None, None, None)
+            .unwrap();
+        assert!(result.count > 0);
+        assert_eq!(result.rows.len() as u32, result.count);
+        assert!(result
+            .rows
+            .iter()
+            .all(|row| row.store_id == mock_store_a().id));
+
+        // store filter can't be overwritten
+        let result = service
+            .get_stock_on_hand(
+                &context,
+                &mock_store_a().id,
+                true,
+                None,
+                Some(StockOnHandFilter::new().store_id(EqualFilter::equal_to("store_b"))),
+                None,
+            )
+            .unwrap();
+        assert!(result
+            .rows
+            .iter()
+            .all(|row| row.store_id == mock_store_a().id));
+
+        assert_eq!(
+            service.get_stock_on_hand(
+                &context,
+                &mock_store_a().id,
+                false,
+                Some(domain::PaginationOption {
+                    limit: Some(2000),
+                    offset: None
+                }),
+                None,
+                None,
+            ),
+            Err(ListError::LimitAboveMax(1000))
+        );
+    }
+
+    #[actix_rt::test]
+    async fn historical_stock_on_hand() {
+        let (_, connection, connection_manager, _) =
+            setup_all("historical_stock_on_hand", MockDataInserts::all()).await;
+
+        let service_provider = ServiceProvider::new(connection_manager);
+        let context = service_provider.context().unwrap();
+        let service = service_provider.stock_on_hand_service;
+
+        let day = |day: u32| NaiveDate::from_ymd(2021, 3, day).and_hms(12, 0, 0);
+        for (id, location_id, total_number_of_packs) in vec![
+            ("historical_line_a", None, 9),
+            ("historical_line_b", Some("location_1".to_string()), 0),
+        ] {
+            StockLineRowRepository::new(&connection)
+                .upsert_one(&StockLineRow {
+                    id: id.to_string(),
+                    item_id: "item_query_test2".to_string(),
+                    store_id: mock_store_a().id,
+                    location_id,
+                    batch: Some(id.to_string()),
+                    pack_size: 10,
+                    cost_price_per_pack: 0.0,
+                    sell_price_per_pack: 0.0,
+                    available_number_of_packs: total_number_of_packs,
+                    total_number_of_packs,
+                    expiry_date: None,
+                    on_hold: false,
+                    note: None,
+                })
+                .unwrap();
+        }
+        let first = outbound_shipment("historical_first", day(10));
+        let second = outbound_shipment("historical_second", day(20));
+        for invoice in vec![first.clone(), second.clone()] {
+            InvoiceRepository::new(&connection)
+                .upsert_one(&invoice)
+                .unwrap();
+        }
+        for (id, invoice_id, stock_line_id, number_of_packs) in vec![
+            ("historical_first_a", &first.id, "historical_line_a", 2),
+            ("historical_first_b", &first.id, "historical_line_b", 3),
+            ("historical_second_a", &second.id, "historical_line_a", 1),
+        ] {
+            InvoiceLineRowRepository::new(&connection)
+                .upsert_one(&InvoiceLineRow {
+                    stock_line_id: Some(stock_line_id.to_string()),
+                    ..outbound_line(id, invoice_id, number_of_packs)
+                })
+                .unwrap();
+        }
+
+        let get = |datetime, group_by| {
+            service
+                .get_historical_stock_on_hand(
+                    &context,
+                    &mock_store_a().id,
+                    datetime,
+                    group_by,
+                    Some(EqualFilter::equal_to("item_query_test2")),
+                )
+                .unwrap()
+                .into_iter()
+                .map(|row| (row.stock_line_id, row.location_id, row.total_units))
+                .collect::<Vec<_>>()
+        };
+
+        // current stock
+        assert_eq!(
+            get(day(25), HistoricalStockOnHandGroupBy::Batch),
+            vec![(Some("historical_line_a".to_string()), None, 90)]
+        );
+        // movements at exactly the requested time are not reversed
+        assert_eq!(
+            get(day(20), HistoricalStockOnHandGroupBy::Item),
+            vec![(None, None, 90)]
+        );
+        assert_eq!(
+            get(day(15), HistoricalStockOnHandGroupBy::Item),
+            vec![(None, None, 100)]
+        );
+        assert_eq!(
+            get(day(5), HistoricalStockOnHandGroupBy::Batch),
+            vec![
+                (Some("historical_line_a".to_string()), None, 120),
+                (
+                    Some("historical_line_b".to_string()),
+                    Some("location_1".to_string()),
+                    30
+                ),
+            ]
+        );
+        assert_eq!(
+            get(day(5), HistoricalStockOnHandGroupBy::Location),
+            vec![
+                (None, None, 120),
+                (None, Some("location_1".to_string()), 30)
+            ]
+        );
+        assert_eq!(
+            get(day(5), HistoricalStockOnHandGroupBy::Item),
+            vec![(None, None, 150)]
+        );
+    }
+}
@@ -5,8 +5,9 @@ mod stock_take_test {
         mock::{
             mock_stock_line_a, mock_stock_take_a, mock_stock_take_finalized_without_lines,
             mock_stock_take_full_edit, mock_stock_take_line_a, mock_stock_take_line_new_stock_line,
-            mock_stock_take_new_stock_line, mock_stock_take_no_count_change,
-            mock_stock_take_no_lines, mock_stock_take_stock_deficit, mock_stock_take_stock_surplus,
+            mock_stock_take_line_stock_surplus, mock_stock_take_new_stock_line,
+            mock_stock_take_no_count_change, mock_stock_take_no_lines,
+            mock_stock_take_stock_deficit, mock_stock_take_stock_surplus,
             mock_stock_take_without_lines, mock_store_a, MockDataInserts,
         },
         schema::{InvoiceLineRowType, StockTakeRow, StockTakeStatus},
@@ -15,6 +16,7 @@ mod stock_take_test {
     };
 
     use crate::{
+        preference::update::UpdateStorePreferences,
         service_provider::ServiceProvider,
         stock_take::{
             delete::DeleteStockTakeError,
@@ -200,6 +202,51 @@ mod stock_take_test {
             .unwrap_err();
         assert_eq!(error, UpdateStockTakeError::NoLines);
 
+        // error: AdjustmentReasonRequired
+        let store_a = mock_store_a();
+        service_provider
+            .preference_service
+            .update_store_preferences(
+                &context,
+                &store_a.id,
+                UpdateStorePreferences {
+                    requires_reason_on_adjustment: Some(true),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        let stock_take = mock_stock_take_stock_surplus();
+        let error = service
+            .update_stock_take(
+                &context,
+                &store_a.id,
+                UpdateStockTakeInput {
+                    id: stock_take.id,
+                    comment: None,
+                    description: None,
+                    status: Some(StockTakeStatus::Finalized),
+                },
+            )
+            .unwrap_err();
+        match error {
+            UpdateStockTakeError::AdjustmentReasonRequired(lines) => {
+                let line_ids: Vec<String> = lines.into_iter().map(|line| line.line.id).collect();
+                assert_eq!(line_ids, vec![mock_stock_take_line_stock_surplus().id]);
+            }
+            _ => panic!("Expected AdjustmentReasonRequired, got {:?}", error),
+        }
+        service_provider
+            .preference_service
+            .update_store_preferences(
+                &context,
+                &store_a.id,
+                UpdateStorePreferences {
+                    requires_reason_on_adjustment: Some(false),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+
         // success surplus should result in StockIn shipment line
         let store_a = mock_store_a();
         let stock_take = mock_stock_take_stock_surplus();
@@ -12,7 +12,11 @@ use repository::{
 use util::{constants::INVENTORY_ADJUSTMENT_NAME_CODE, uuid::uuid};
 
 use crate::{
-    number::next_number, service_provider::ServiceContext, validate::check_store_id_matches,
+    number::next_number,
+    plugin::{PluginHost, PluginRejection},
+    preference::get_store_preferences,
+    service_provider::ServiceContext,
+    validate::check_store_id_matches,
 };
 
 use super::{
@@ -38,6 +42,10 @@ pub enum UpdateStockTakeError {
     NoLines,
     /// Holds list of affected stock lines
     SnapshotCountCurrentCountMismatch(Vec<StockTakeLine>),
+    /// Holds list of adjusted lines without a comment, only if the store requires a reason for
+    /// adjustments
+    AdjustmentReasonRequired(Vec<StockTakeLine>),
+    RejectedByPlugin(PluginRejection),
 }
 
 fn check_snapshot_matches_current_count(
@@ -59,6 +67,29 @@ fn check_snapshot_matches_current_count(
     None
 }
 
+/// Returns the lines which adjust the stock but don't have a comment
+fn check_adjustments_have_reason(stock_take_lines: &[StockTakeLine]) -> Option<Vec<StockTakeLine>> {
+    let missing_reason: Vec<StockTakeLine> = stock_take_lines
+        .iter()
+        .filter(|line| {
+            let is_adjusted = match line.line.counted_number_of_packs {
+                Some(counted) => counted != line.line.snapshot_number_of_packs,
+                None => false,
+            };
+            let has_reason = match &line.line.comment {
+                Some(comment) => !comment.trim().is_empty(),
+                None => false,
+            };
+            is_adjusted && !has_reason
+        })
+        .cloned()
+        .collect();
+    if !missing_reason.is_empty() {
+        return Some(missing_reason);
+    }
+    None
+}
+
 fn load_stock_take_lines(
     connection: &StorageConnection,
     stock_take_id: &str,
@@ -70,6 +101,7 @@ fn load_stock_take_lines(
 
 fn validate(
     connection: &StorageConnection,
+    plugins: &PluginHost,
     store_id: &str,
     input: &UpdateStockTakeInput,
 ) -> Result<(StockTakeRow, Vec<StockTakeLine>), UpdateStockTakeError> {
@@ -95,6 +127,18 @@ fn validate(
                 mismatches,
             ));
         }
+
+        if get_store_preferences(connection, store_id)?.requires_reason_on_adjustment {
+            if let Some(missing_reason) = check_adjustments_have_reason(&stock_take_lines) {
+                return Err(UpdateStockTakeError::AdjustmentReasonRequired(
+                    missing_reason,
+                ));
+            }
+        }
+
+        plugins
+            .before_stock_take_finalise(connection, &existing, &stock_take_lines)
+            .map_err(UpdateStockTakeError::RejectedByPlugin)?;
     }
 
     Ok((existing, stock_take_lines))
@@ -176,6 +220,8 @@ fn generate_stock_line_update(
             tax: None,
             number_of_packs: quantiy_change,
             note: stock_line.note.clone(),
+            return_reason_id: None,
+            directions: None,
         })
     } else {
         None
@@ -242,6 +288,8 @@ fn generate_new_stock_line(
             tax: None,
             number_of_packs: counted_number_of_packs,
             note: row.note,
+            return_reason_id: None,
+            directions: None,
         })
     } else {
         None
@@ -328,6 +376,7 @@ fn generate(
         delivered_datetime: None,
         verified_datetime: Some(now.clone()),
         color: None,
+        prescriber_id: None,
     };
 
     let stock_take = StockTakeRow {
@@ -358,7 +407,8 @@ pub fn update_stock_take(
         .connection
         .transaction_sync(|connection| {
             let stock_take_id = input.id.clone();
-            let (existing, stock_take_lines) = validate(connection, store_id, &input)?;
+            let (existing, stock_take_lines) =
+                validate(connection, &ctx.plugins, store_id, &input)?;
             let result = generate(connection, input, existing, stock_take_lines, store_id)?;
 
             // write data to the DB
@@ -1,6 +1,6 @@
 use domain::{location::LocationFilter, EqualFilter};
 use repository::{
-    schema::StockTakeLineRow, ItemFilter, ItemQueryRepository, LocationRepository, RepositoryError,
+    schema::StockTakeLineRow, ItemRepository, LocationRepository, RepositoryError,
     StockTakeLineRowRepository, StorageConnection,
 };
 
@@ -24,7 +24,7 @@ pub fn check_item_exists(
     connection: &StorageConnection,
     id: &str,
 ) -> Result<bool, RepositoryError> {
-    let count = ItemQueryRepository::new(connection)
-        .count(Some(ItemFilter::new().id(EqualFilter::equal_to(id))))?;
-    Ok(count == 1)
+    Ok(ItemRepository::new(connection)
+        .find_one_by_id(id)?
+        .is_some())
 }
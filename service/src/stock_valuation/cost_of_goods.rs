@@ -0,0 +1,233 @@
+use std::collections::{BTreeMap, HashMap, VecDeque};
+
+use chrono::NaiveDateTime;
+use domain::EqualFilter;
+use repository::{
+    schema::{InvoiceRowType, StockMovementRow},
+    RepositoryError, StockMovementFilter, StockMovementRepository, StockOnHandFilter,
+    StockOnHandRepository,
+};
+
+use crate::service_provider::ServiceContext;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CostingMethod {
+    /// Cost price of the issued batch
+    BatchActualCost,
+    /// Issues are costed from the oldest receipts still in stock
+    Fifo,
+    /// Issues are costed at the average unit cost of the stock at the time of issue
+    WeightedAverage,
+}
+
+#[derive(Clone, Debug)]
+pub struct CostOfGoodsIssuedInput {
+    pub from_datetime: NaiveDateTime,
+    pub to_datetime: NaiveDateTime,
+    pub method: CostingMethod,
+    pub item_id: Option<EqualFilter<String>>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ItemCostOfGoodsIssued {
+    pub item_id: String,
+    /// Units issued in the period (number of packs * pack size)
+    pub units_issued: i64,
+    pub cost: f64,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CostOfGoodsIssued {
+    pub total_cost: f64,
+    /// Items issued in the period, ordered by item id
+    pub items: Vec<ItemCostOfGoodsIssued>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum CostOfGoodsIssuedError {
+    DatabaseError(RepositoryError),
+    InvalidDateRange,
+}
+
+impl From<RepositoryError> for CostOfGoodsIssuedError {
+    fn from(error: RepositoryError) -> Self {
+        CostOfGoodsIssuedError::DatabaseError(error)
+    }
+}
+
+/// Cost of the stock issued by outbound shipments and prescriptions in the period.
+///
+/// Movements (see stock_movement view) are replayed per item from the first one. Repacks and
+/// location movements only move stock between stock lines of the store and are skipped. Stock on hand
+/// that isn't explained by the movements, e.g. imported opening balances, is treated as received
+/// before the first movement at the current average cost of the item.
+pub fn get_cost_of_goods_issued(
+    ctx: &ServiceContext,
+    store_id: &str,
+    input: CostOfGoodsIssuedInput,
+) -> Result<CostOfGoodsIssued, CostOfGoodsIssuedError> {
+    if input.from_datetime > input.to_datetime {
+        return Err(CostOfGoodsIssuedError::InvalidDateRange);
+    }
+
+    let mut movement_filter = StockMovementFilter::new().store_id(EqualFilter::equal_to(store_id));
+    movement_filter.item_id = input.item_id.clone();
+    let movements =
+        StockMovementRepository::new(&ctx.connection).query_by_filter(movement_filter)?;
+
+    let mut stock_filter = StockOnHandFilter::new().store_id(EqualFilter::equal_to(store_id));
+    stock_filter.item_id = input.item_id.clone();
+    let stock_on_hand: HashMap<String, (i64, f64)> = StockOnHandRepository::new(&ctx.connection)
+        .query_by_filter(stock_filter)?
+        .into_iter()
+        .map(|row| (row.item_id, (row.total_units, row.total_cost_value)))
+        .collect();
+
+    let mut movements_by_item: BTreeMap<String, Vec<StockMovementRow>> = BTreeMap::new();
+    for movement in movements {
+        if is_internal_transfer(&movement.invoice_type) {
+            continue;
+        }
+        movements_by_item
+            .entry(movement.item_id.clone())
+            .or_default()
+            .push(movement);
+    }
+
+    let items: Vec<ItemCostOfGoodsIssued> = movements_by_item
+        .into_iter()
+        .map(|(item_id, movements)| {
+            let (current_units, current_value) =
+                stock_on_hand.get(&item_id).cloned().unwrap_or((0, 0.0));
+            let mut costing = ItemCosting::new(input.method);
+            let opening_units = current_units - movements.iter().map(units).sum::<i64>();
+            if opening_units > 0 {
+                let unit_cost = if current_units > 0 {
+                    current_value / current_units as f64
+                } else {
+                    unit_cost(&movements[0])
+                };
+                costing.receive(opening_units, unit_cost);
+            }
+
+            let mut issued = ItemCostOfGoodsIssued {
+                item_id,
+                units_issued: 0,
+                cost: 0.0,
+            };
+            for movement in movements
+                .iter()
+                .take_while(|movement| movement.datetime <= input.to_datetime)
+            {
+                let quantity = units(movement);
+                if quantity >= 0 {
+                    costing.receive(quantity, unit_cost(movement));
+                    continue;
+                }
+                let cost = costing.issue(-quantity, unit_cost(movement));
+                if is_issue(&movement.invoice_type) && movement.datetime >= input.from_datetime {
+                    issued.units_issued -= quantity;
+                    issued.cost += cost;
+                }
+            }
+            issued
+        })
+        .filter(|issued| issued.units_issued != 0)
+        .collect();
+
+    Ok(CostOfGoodsIssued {
+        total_cost: items.iter().map(|item| item.cost).sum(),
+        items,
+    })
+}
+
+fn is_issue(invoice_type: &InvoiceRowType) -> bool {
+    matches!(
+        invoice_type,
+        InvoiceRowType::OutboundShipment | InvoiceRowType::Prescription
+    )
+}
+
+/// Stock out and stock in lines of the same invoice, the units stay in the store
+fn is_internal_transfer(invoice_type: &InvoiceRowType) -> bool {
+    matches!(
+        invoice_type,
+        InvoiceRowType::Repack | InvoiceRowType::LocationMovement
+    )
+}
+
+/// Inventory of a single item while replaying its movements
+struct ItemCosting {
+    method: CostingMethod,
+    /// Received units and their unit cost, oldest first
+    layers: VecDeque<(i64, f64)>,
+    units: i64,
+    value: f64,
+}
+
+impl ItemCosting {
+    fn new(method: CostingMethod) -> Self {
+        ItemCosting {
+            method,
+            layers: VecDeque::new(),
+            units: 0,
+            value: 0.0,
+        }
+    }
+
+    fn receive(&mut self, units: i64, unit_cost: f64) {
+        self.layers.push_back((units, unit_cost));
+        self.units += units;
+        self.value += units as f64 * unit_cost;
+    }
+
+    /// Returns the cost of the issued units, `line_unit_cost` is used for the batch actual cost
+    /// and for units issued beyond the stock on record
+    fn issue(&mut self, units: i64, line_unit_cost: f64) -> f64 {
+        let cost = match self.method {
+            CostingMethod::BatchActualCost => units as f64 * line_unit_cost,
+            CostingMethod::Fifo => {
+                let mut remaining = units;
+                let mut cost = 0.0;
+                while remaining > 0 {
+                    let (layer_units, layer_cost) = match self.layers.front_mut() {
+                        Some(layer) => layer,
+                        None => break,
+                    };
+                    let taken = remaining.min(*layer_units);
+                    cost += taken as f64 * *layer_cost;
+                    remaining -= taken;
+                    *layer_units -= taken;
+                    if *layer_units == 0 {
+                        self.layers.pop_front();
+                    }
+                }
+                cost + remaining as f64 * line_unit_cost
+            }
+            CostingMethod::WeightedAverage => {
+                let average = if self.units > 0 {
+                    self.value / self.units as f64
+                } else {
+                    line_unit_cost
+                };
+                units as f64 * average
+            }
+        };
+
+        self.units -= units;
+        self.value = if self.units > 0 {
+            (self.value - cost).max(0.0)
+        } else {
+            0.0
+        };
+        cost
+    }
+}
+
+fn units(movement: &StockMovementRow) -> i64 {
+    movement.number_of_packs as i64 * movement.pack_size as i64
+}
+
+fn unit_cost(movement: &StockMovementRow) -> f64 {
+    movement.cost_price_per_pack / movement.pack_size.max(1) as f64
+}
@@ -0,0 +1,39 @@
+use repository::RepositoryError;
+
+use crate::service_provider::ServiceContext;
+
+use self::{
+    cost_of_goods::{
+        get_cost_of_goods_issued, CostOfGoodsIssued, CostOfGoodsIssuedError, CostOfGoodsIssuedInput,
+    },
+    valuation::{get_stock_valuation, StockValuationGroupBy, StockValuationReport},
+};
+
+pub mod cost_of_goods;
+pub mod valuation;
+
+#[cfg(test)]
+mod tests;
+
+pub trait StockValuationServiceTrait: Sync + Send {
+    fn get_stock_valuation(
+        &self,
+        ctx: &ServiceContext,
+        store_id: &str,
+        group_by: StockValuationGroupBy,
+    ) -> Result<StockValuationReport, RepositoryError> {
+        get_stock_valuation(ctx, store_id, group_by)
+    }
+
+    fn get_cost_of_goods_issued(
+        &self,
+        ctx: &ServiceContext,
+        store_id: &str,
+        input: CostOfGoodsIssuedInput,
+    ) -> Result<CostOfGoodsIssued, CostOfGoodsIssuedError> {
+        get_cost_of_goods_issued(ctx, store_id, input)
+    }
+}
+
+pub struct StockValuationService {}
+impl StockValuationServiceTrait for StockValuationService {}
@@ -0,0 +1,452 @@
+#[cfg(test)]
+mod stock_valuation_test {
+    use chrono::{NaiveDate, NaiveDateTime};
+    use domain::EqualFilter;
+    use repository::{
+        mock::{mock_name_store_a, mock_name_store_b, mock_store_a, MockDataInserts},
+        schema::{
+            InvoiceLineRow, InvoiceLineRowType, InvoiceRow, InvoiceRowStatus, InvoiceRowType,
+            StockLineRow,
+        },
+        test_db::setup_all,
+        InvoiceLineRowRepository, InvoiceRepository, StockLineRowRepository, StorageConnection,
+    };
+
+    use crate::{
+        service_provider::ServiceProvider,
+        stock_valuation::{
+            cost_of_goods::{CostOfGoodsIssuedError, CostOfGoodsIssuedInput, CostingMethod},
+            valuation::StockValuationGroupBy,
+        },
+    };
+
+    fn datetime(day: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd(2021, 5, day).and_hms(9, 0, 0)
+    }
+
+    fn insert_stock_line(connection: &StorageConnection, id: &str, cost: f64, packs: i32) {
+        StockLineRowRepository::new(connection)
+            .upsert_one(&StockLineRow {
+                id: id.to_string(),
+                item_id: "item_query_test2".to_string(),
+                store_id: mock_store_a().id,
+                location_id: None,
+                batch: Some(id.to_string()),
+                pack_size: 1,
+                cost_price_per_pack: cost,
+                sell_price_per_pack: 0.0,
+                available_number_of_packs: packs,
+                total_number_of_packs: packs,
+                expiry_date: None,
+                on_hold: false,
+                note: None,
+            })
+            .unwrap();
+    }
+
+    fn insert_invoice(
+        connection: &StorageConnection,
+        id: &str,
+        r#type: InvoiceRowType,
+        day: u32,
+        stock_line_id: &str,
+        cost: f64,
+        number_of_packs: i32,
+    ) {
+        let (name_id, status, line_type) = match r#type {
+            InvoiceRowType::InboundShipment => (
+                mock_name_store_a().id,
+                InvoiceRowStatus::Delivered,
+                InvoiceLineRowType::StockIn,
+            ),
+            InvoiceRowType::Prescription => (
+                mock_name_store_b().id,
+                InvoiceRowStatus::Picked,
+                InvoiceLineRowType::StockOut,
+            ),
+            _ => (
+                mock_name_store_b().id,
+                InvoiceRowStatus::Shipped,
+                InvoiceLineRowType::StockOut,
+            ),
+        };
+        InvoiceRepository::new(connection)
+            .upsert_one(&InvoiceRow {
+                id: id.to_string(),
+                name_id,
+                store_id: mock_store_a().id,
+                invoice_number: 1,
+                r#type,
+                status,
+                on_hold: false,
+                comment: None,
+                their_reference: None,
+                created_datetime: datetime(day),
+                allocated_datetime: None,
+                picked_datetime: Some(datetime(day)),
+                shipped_datetime: Some(datetime(day)),
+                delivered_datetime: Some(datetime(day)),
+                verified_datetime: None,
+                color: None,
+                prescriber_id: None,
+            })
+            .unwrap();
+        InvoiceLineRowRepository::new(connection)
+            .upsert_one(&InvoiceLineRow {
+                id: format!("{}_line", id),
+                invoice_id: id.to_string(),
+                item_id: "item_query_test2".to_string(),
+                item_name: "".to_string(),
+                item_code: "".to_string(),
+                stock_line_id: Some(stock_line_id.to_string()),
+                location_id: None,
+                batch: Some(stock_line_id.to_string()),
+                expiry_date: None,
+                pack_size: 1,
+                cost_price_per_pack: cost,
+                sell_price_per_pack: 0.0,
+                total_before_tax: 0.0,
+                total_after_tax: 0.0,
+                tax: None,
+                r#type: line_type,
+                number_of_packs,
+                note: None,
+                return_reason_id: None,
+                directions: None,
+            })
+            .unwrap();
+    }
+
+    /// Verified repack of all units of a stock line into a new stock line
+    fn insert_repack(
+        connection: &StorageConnection,
+        id: &str,
+        day: u32,
+        from_stock_line_id: &str,
+        to_stock_line_id: &str,
+        cost: f64,
+        units: i32,
+    ) {
+        InvoiceRepository::new(connection)
+            .upsert_one(&InvoiceRow {
+                id: id.to_string(),
+                name_id: mock_name_store_a().id,
+                store_id: mock_store_a().id,
+                invoice_number: 1,
+                r#type: InvoiceRowType::Repack,
+                status: InvoiceRowStatus::Verified,
+                on_hold: false,
+                comment: None,
+                their_reference: None,
+                created_datetime: datetime(day),
+                allocated_datetime: None,
+                picked_datetime: None,
+                shipped_datetime: None,
+                delivered_datetime: None,
+                verified_datetime: Some(datetime(day)),
+                color: None,
+                prescriber_id: None,
+            })
+            .unwrap();
+        let lines = vec![
+            (InvoiceLineRowType::StockOut, from_stock_line_id, 1, units),
+            // packs of 5
+            (InvoiceLineRowType::StockIn, to_stock_line_id, 5, units / 5),
+        ];
+        for (index, (r#type, stock_line_id, pack_size, number_of_packs)) in
+            lines.into_iter().enumerate()
+        {
+            InvoiceLineRowRepository::new(connection)
+                .upsert_one(&InvoiceLineRow {
+                    id: format!("{}_line{}", id, index),
+                    invoice_id: id.to_string(),
+                    item_id: "item_query_test2".to_string(),
+                    item_name: "".to_string(),
+                    item_code: "".to_string(),
+                    stock_line_id: Some(stock_line_id.to_string()),
+                    location_id: None,
+                    batch: Some(stock_line_id.to_string()),
+                    expiry_date: None,
+                    pack_size,
+                    cost_price_per_pack: cost * pack_size as f64,
+                    sell_price_per_pack: 0.0,
+                    total_before_tax: 0.0,
+                    total_after_tax: 0.0,
+                    tax: None,
+                    r#type,
+                    number_of_packs,
+                    note: None,
+                    return_reason_id: None,
+                    directions: None,
+                })
+                .unwrap();
+        }
+    }
+
+    // Receives 10 @ 1, 10 @ 4, issues 5 of the second batch, receives 5 @ 7 and then issues 10
+    // of the first batch
+    fn insert_item_history(connection: &StorageConnection) {
+        insert_stock_line(connection, "valuation_a", 1.0, 0);
+        insert_stock_line(connection, "valuation_b", 4.0, 5);
+        insert_stock_line(connection, "valuation_c", 7.0, 5);
+        let inbound = InvoiceRowType::InboundShipment;
+        let outbound = InvoiceRowType::OutboundShipment;
+        insert_invoice(
+            connection,
+            "valuation_1",
+            inbound.clone(),
+            1,
+            "valuation_a",
+            1.0,
+            10,
+        );
+        insert_invoice(
+            connection,
+            "valuation_2",
+            inbound.clone(),
+            2,
+            "valuation_b",
+            4.0,
+            10,
+        );
+        insert_invoice(
+            connection,
+            "valuation_3",
+            outbound.clone(),
+            3,
+            "valuation_b",
+            4.0,
+            5,
+        );
+        insert_invoice(connection, "valuation_4", inbound, 4, "valuation_c", 7.0, 5);
+        insert_invoice(
+            connection,
+            "valuation_5",
+            outbound,
+            5,
+            "valuation_a",
+            1.0,
+            10,
+        );
+    }
+
+    #[actix_rt::test]
+    async fn stock_valuation() {
+        let (_, connection, connection_manager, _) =
+            setup_all("stock_valuation", MockDataInserts::all()).await;
+
+        let service_provider = ServiceProvider::new(connection_manager);
+        let context = service_provider.context().unwrap();
+        let service = service_provider.stock_valuation_service;
+        insert_item_history(&connection);
+
+        let by_item = service
+            .get_stock_valuation(&context, &mock_store_a().id, StockValuationGroupBy::Item)
+            .unwrap();
+        let item = by_item
+            .rows
+            .iter()
+            .find(|row| row.item_id == Some("item_query_test2".to_string()))
+            .unwrap();
+        assert_eq!(item.location_id, None);
+        assert_eq!(item.total_units, 10);
+        assert_eq!(item.total_cost_value, 55.0);
+
+        let by_location = service
+            .get_stock_valuation(
+                &context,
+                &mock_store_a().id,
+                StockValuationGroupBy::Location,
+            )
+            .unwrap();
+        assert!(by_location.rows.iter().all(|row| row.item_id.is_none()));
+        assert_eq!(by_location.total_cost_value, by_item.total_cost_value);
+        assert_eq!(
+            by_location
+                .rows
+                .iter()
+                .map(|row| row.total_units)
+                .sum::<i64>(),
+            by_item.rows.iter().map(|row| row.total_units).sum::<i64>()
+        );
+    }
+
+    #[actix_rt::test]
+    async fn cost_of_goods_issued() {
+        let (_, connection, connection_manager, _) =
+            setup_all("cost_of_goods_issued", MockDataInserts::all()).await;
+
+        let service_provider = ServiceProvider::new(connection_manager);
+        let context = service_provider.context().unwrap();
+        let service = service_provider.stock_valuation_service;
+        insert_item_history(&connection);
+
+        let input = |from_day: u32, method: CostingMethod| CostOfGoodsIssuedInput {
+            from_datetime: datetime(from_day),
+            to_datetime: datetime(5),
+            method,
+            item_id: Some(EqualFilter::equal_to("item_query_test2")),
+        };
+
+        assert_eq!(
+            service.get_cost_of_goods_issued(
+                &context,
+                &mock_store_a().id,
+                CostOfGoodsIssuedInput {
+                    from_datetime: datetime(6),
+                    ..input(1, CostingMethod::Fifo)
+                }
+            ),
+            Err(CostOfGoodsIssuedError::InvalidDateRange)
+        );
+
+        // whole period
+        let result = service
+            .get_cost_of_goods_issued(
+                &context,
+                &mock_store_a().id,
+                input(1, CostingMethod::BatchActualCost),
+            )
+            .unwrap();
+        assert_eq!(result.items.len(), 1);
+        assert_eq!(result.items[0].units_issued, 15);
+        assert_eq!(result.total_cost, 30.0);
+
+        // last issue only
+        let cost = |method| {
+            service
+                .get_cost_of_goods_issued(&context, &mock_store_a().id, input(4, method))
+                .unwrap()
+                .total_cost
+        };
+        assert_eq!(cost(CostingMethod::BatchActualCost), 10.0);
+        // 5 remaining @ 1 and 5 @ 4
+        assert_eq!(cost(CostingMethod::Fifo), 25.0);
+        // 15 units worth 37.5 after the first issue, plus 5 @ 7
+        assert_eq!(cost(CostingMethod::WeightedAverage), 36.25);
+    }
+
+    #[actix_rt::test]
+    async fn cost_of_goods_issued_prescriptions() {
+        let (_, connection, connection_manager, _) =
+            setup_all("cost_of_goods_issued_prescriptions", MockDataInserts::all()).await;
+
+        let service_provider = ServiceProvider::new(connection_manager);
+        let context = service_provider.context().unwrap();
+        let service = service_provider.stock_valuation_service;
+        insert_item_history(&connection);
+        // dispenses 5 of the last batch
+        insert_invoice(
+            &connection,
+            "valuation_6",
+            InvoiceRowType::Prescription,
+            6,
+            "valuation_c",
+            7.0,
+            5,
+        );
+
+        let cost_of_goods = |method| {
+            service
+                .get_cost_of_goods_issued(
+                    &context,
+                    &mock_store_a().id,
+                    CostOfGoodsIssuedInput {
+                        from_datetime: datetime(6),
+                        to_datetime: datetime(6),
+                        method,
+                        item_id: Some(EqualFilter::equal_to("item_query_test2")),
+                    },
+                )
+                .unwrap()
+        };
+        let result = cost_of_goods(CostingMethod::BatchActualCost);
+        assert_eq!(result.items.len(), 1);
+        assert_eq!(result.items[0].units_issued, 5);
+        assert_eq!(result.total_cost, 35.0);
+        // 5 remaining @ 4
+        assert_eq!(cost_of_goods(CostingMethod::Fifo).total_cost, 20.0);
+    }
+
+    #[actix_rt::test]
+    async fn cost_of_goods_issued_repack() {
+        let (_, connection, connection_manager, _) =
+            setup_all("cost_of_goods_issued_repack", MockDataInserts::all()).await;
+
+        let service_provider = ServiceProvider::new(connection_manager);
+        let context = service_provider.context().unwrap();
+        let service = service_provider.stock_valuation_service;
+        // receives 10 @ 1 and 10 @ 4, repacks the second batch, receives 10 @ 7 and then issues
+        // 15 units
+        insert_stock_line(&connection, "repack_a", 1.0, 0);
+        insert_stock_line(&connection, "repack_b", 4.0, 0);
+        insert_stock_line(&connection, "repack_b_repacked", 4.0, 2);
+        insert_stock_line(&connection, "repack_c", 7.0, 10);
+        let inbound = InvoiceRowType::InboundShipment;
+        insert_invoice(
+            &connection,
+            "repack_1",
+            inbound.clone(),
+            1,
+            "repack_a",
+            1.0,
+            10,
+        );
+        insert_invoice(
+            &connection,
+            "repack_2",
+            inbound.clone(),
+            2,
+            "repack_b",
+            4.0,
+            10,
+        );
+        insert_repack(
+            &connection,
+            "repack_3",
+            3,
+            "repack_b",
+            "repack_b_repacked",
+            4.0,
+            10,
+        );
+        insert_invoice(&connection, "repack_4", inbound, 4, "repack_c", 7.0, 10);
+        insert_invoice(
+            &connection,
+            "repack_5",
+            InvoiceRowType::OutboundShipment,
+            5,
+            "repack_a",
+            1.0,
+            10,
+        );
+        insert_invoice(
+            &connection,
+            "repack_6",
+            InvoiceRowType::OutboundShipment,
+            5,
+            "repack_b_repacked",
+            4.0,
+            1,
+        );
+
+        let cost = |method| {
+            service
+                .get_cost_of_goods_issued(
+                    &context,
+                    &mock_store_a().id,
+                    CostOfGoodsIssuedInput {
+                        from_datetime: datetime(5),
+                        to_datetime: datetime(5),
+                        method,
+                        item_id: Some(EqualFilter::equal_to("item_query_test2")),
+                    },
+                )
+                .unwrap()
+        };
+        let result = cost(CostingMethod::Fifo);
+        assert_eq!(result.items[0].units_issued, 11);
+        // the repack doesn't make the second batch newer than the third: 10 @ 1 and 1 @ 4
+        assert_eq!(result.total_cost, 14.0);
+    }
+}
@@ -0,0 +1,75 @@
+use std::collections::BTreeMap;
+
+use domain::{EqualFilter, Pagination};
+use repository::{RepositoryError, StockOnHandFilter, StockOnHandRepository};
+
+use crate::service_provider::ServiceContext;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StockValuationGroupBy {
+    Item,
+    Location,
+}
+
+/// Value of the stock on hand at the cost price of each batch, item or location is set depending
+/// on the grouping
+#[derive(Clone, Debug, PartialEq)]
+pub struct StockValuation {
+    pub item_id: Option<String>,
+    pub location_id: Option<String>,
+    /// Number of units (number of packs * pack size)
+    pub total_units: i64,
+    pub total_cost_value: f64,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct StockValuationReport {
+    pub total_cost_value: f64,
+    pub rows: Vec<StockValuation>,
+}
+
+pub fn get_stock_valuation(
+    ctx: &ServiceContext,
+    store_id: &str,
+    group_by: StockValuationGroupBy,
+) -> Result<StockValuationReport, RepositoryError> {
+    let repository = StockOnHandRepository::new(&ctx.connection);
+    let filter = StockOnHandFilter::new().store_id(EqualFilter::equal_to(store_id));
+
+    let rows: Vec<StockValuation> = match group_by {
+        StockValuationGroupBy::Item => repository
+            .query_by_filter(filter)?
+            .into_iter()
+            .map(|stock_on_hand| StockValuation {
+                item_id: Some(stock_on_hand.item_id),
+                location_id: None,
+                total_units: stock_on_hand.total_units,
+                total_cost_value: stock_on_hand.total_cost_value,
+            })
+            .collect(),
+        StockValuationGroupBy::Location => {
+            let mut locations: BTreeMap<Option<String>, StockValuation> = BTreeMap::new();
+            for stock_on_hand in
+                repository.query_by_location(Pagination::all(), Some(filter), None)?
+            {
+                let location_id = stock_on_hand.location_id;
+                let location = locations
+                    .entry(location_id.clone())
+                    .or_insert_with(|| StockValuation {
+                        item_id: None,
+                        location_id,
+                        total_units: 0,
+                        total_cost_value: 0.0,
+                    });
+                location.total_units += stock_on_hand.total_units;
+                location.total_cost_value += stock_on_hand.total_cost_value;
+            }
+            locations.into_values().collect()
+        }
+    };
+
+    Ok(StockValuationReport {
+        total_cost_value: rows.iter().map(|row| row.total_cost_value).sum(),
+        rows,
+    })
+}
@@ -0,0 +1,26 @@
+use chrono::Utc;
+use repository::{
+    schema::{SyncOutRow, SyncOutRowActionType, SyncOutRowTableNameType},
+    RepositoryError, StorageConnection, SyncOutRowRepository,
+};
+use util::uuid::uuid;
+
+/// Queues a record changed in this store for upload to central. Remote sync pushes the queue to
+/// central, currently only name, name tag and name tag join records are pushed.
+pub fn queue_upload(
+    connection: &StorageConnection,
+    store_id: &str,
+    table_name: SyncOutRowTableNameType,
+    record_id: &str,
+    action: SyncOutRowActionType,
+) -> Result<(), RepositoryError> {
+    SyncOutRowRepository::new(connection).insert_one(&SyncOutRow {
+        id: uuid(),
+        created_at: Utc::now().naive_utc(),
+        table_name,
+        record_id: record_id.to_string(),
+        store_id: store_id.to_string(),
+        site_id: None,
+        action,
+    })
+}
@@ -0,0 +1,199 @@
+use std::collections::{HashMap, HashSet};
+
+use chrono::NaiveDateTime;
+use domain::{
+    stock_line::{StockLine, StockLineFilter},
+    EqualFilter,
+};
+use repository::{
+    schema::{
+        LocationMovementRow, LocationRow, TemperatureBreachConfigRow, TemperatureBreachRow,
+        TemperatureLogRow,
+    },
+    LocationMovementRowRepository, RepositoryError, StockLineRepository, StorageConnection,
+    TemperatureBreachConfigRowRepository, TemperatureBreachRowRepository,
+    TemperatureLogRowRepository,
+};
+use util::uuid::uuid;
+
+use crate::location::hierarchy::get_inherited_location_type;
+
+fn is_out_of_range(config: &TemperatureBreachConfigRow, temperature: f64) -> bool {
+    let below = matches!(config.minimum_temperature, Some(minimum) if temperature < minimum);
+    let above = matches!(config.maximum_temperature, Some(maximum) if temperature > maximum);
+    below || above
+}
+
+/// Out of range periods of the readings that lasted at least the duration of the config, as
+/// (start, end, duration in minutes)
+fn find_breach_periods(
+    config: &TemperatureBreachConfigRow,
+    logs: &[TemperatureLogRow],
+) -> Vec<(NaiveDateTime, Option<NaiveDateTime>, i32)> {
+    let mut periods = Vec::new();
+    // first and last out of range reading of the current period
+    let mut out_of_range: Option<(NaiveDateTime, NaiveDateTime)> = None;
+    for log in logs {
+        if is_out_of_range(config, log.temperature) {
+            out_of_range = match out_of_range {
+                Some((start, _)) => Some((start, log.datetime)),
+                None => Some((log.datetime, log.datetime)),
+            };
+        } else if let Some((start, _)) = out_of_range.take() {
+            periods.push((start, Some(log.datetime), log.datetime));
+        }
+    }
+    if let Some((start, last)) = out_of_range {
+        periods.push((start, None, last));
+    }
+
+    periods
+        .into_iter()
+        .map(|(start, end, until)| (start, end, (until - start).num_minutes() as i32))
+        .filter(|(_, _, duration_minutes)| *duration_minutes >= config.duration_minutes)
+        .collect()
+}
+
+/// Recalculates the breaches of the location from all its readings and the active breach configs
+/// of its (inherited) location type. Ids of breaches that still start at the same reading are
+/// kept.
+pub fn update_location_breaches(
+    connection: &StorageConnection,
+    location: LocationRow,
+) -> Result<Vec<TemperatureBreachRow>, RepositoryError> {
+    let breach_repository = TemperatureBreachRowRepository::new(connection);
+    let existing_ids: HashMap<(String, NaiveDateTime), String> = breach_repository
+        .find_many(&location.store_id, Some(&location.id), false)?
+        .into_iter()
+        .map(|breach| {
+            (
+                (breach.temperature_breach_config_id, breach.start_datetime),
+                breach.id,
+            )
+        })
+        .collect();
+    breach_repository.delete_by_location_id(&location.store_id, &location.id)?;
+
+    let store_id = location.store_id.clone();
+    let location_id = location.id.clone();
+    let configs = match get_inherited_location_type(connection, location)? {
+        Some(location_type) => TemperatureBreachConfigRowRepository::new(connection)
+            .find_many_by_location_type_id(&location_type.id)?
+            .into_iter()
+            .filter(|config| config.is_active)
+            .collect(),
+        None => Vec::new(),
+    };
+    if configs.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let logs = TemperatureLogRowRepository::new(connection).find_many_by_location_id(
+        &store_id,
+        &location_id,
+        None,
+        None,
+    )?;
+    let mut breaches = Vec::new();
+    for config in configs {
+        for (start_datetime, end_datetime, duration_minutes) in find_breach_periods(&config, &logs)
+        {
+            let id = existing_ids
+                .get(&(config.id.clone(), start_datetime))
+                .cloned()
+                .unwrap_or_else(uuid);
+            let breach = TemperatureBreachRow {
+                id,
+                store_id: store_id.clone(),
+                location_id: location_id.clone(),
+                temperature_breach_config_id: config.id.clone(),
+                start_datetime,
+                end_datetime,
+                duration_minutes,
+            };
+            breach_repository.upsert_one(&breach)?;
+            breaches.push(breach);
+        }
+    }
+    breaches.sort_by(|a, b| b.start_datetime.cmp(&a.start_datetime));
+    Ok(breaches)
+}
+
+fn overlaps(
+    (start, end): (Option<NaiveDateTime>, Option<NaiveDateTime>),
+    breach: &TemperatureBreachRow,
+) -> bool {
+    let starts_before_breach_end = match (start, breach.end_datetime) {
+        (Some(start), Some(breach_end)) => start < breach_end,
+        _ => true,
+    };
+    let ends_after_breach_start = match end {
+        Some(end) => end > breach.start_datetime,
+        None => true,
+    };
+    starts_before_breach_end && ends_after_breach_start
+}
+
+/// Checks the location history of a stock line, reconstructed from its moves, against the breach
+fn was_in_location_during_breach(
+    current_location_id: Option<&str>,
+    movements: &[LocationMovementRow],
+    breach: &TemperatureBreachRow,
+) -> bool {
+    let location_id = breach.location_id.as_str();
+    let mut location = match movements.first() {
+        // packs split off another stock line are accounted for by that stock line until the split
+        Some(first) if first.split_from_stock_line_id.is_some() => None,
+        Some(first) => first.from_location_id.as_deref(),
+        None => current_location_id,
+    };
+    let mut since = None;
+    for movement in movements {
+        if location == Some(location_id) && overlaps((since, Some(movement.moved_datetime)), breach)
+        {
+            return true;
+        }
+        location = Some(movement.to_location_id.as_str());
+        since = Some(movement.moved_datetime);
+    }
+    location == Some(location_id) && overlaps((since, None), breach)
+}
+
+/// Stock lines that were in the location of the breach while it lasted
+pub fn get_breach_stock_lines(
+    connection: &StorageConnection,
+    breach: &TemperatureBreachRow,
+) -> Result<Vec<StockLine>, RepositoryError> {
+    let movement_repository = LocationMovementRowRepository::new(connection);
+    let mut stock_line_ids: HashSet<String> = movement_repository
+        .find_many_by_location_id(&breach.store_id, &breach.location_id)?
+        .into_iter()
+        .map(|movement| movement.stock_line_id)
+        .collect();
+    let current_stock_lines = StockLineRepository::new(connection).query_by_filter(
+        StockLineFilter::new()
+            .store_id(EqualFilter::equal_to(&breach.store_id))
+            .location_id(EqualFilter::equal_to(&breach.location_id)),
+    )?;
+    stock_line_ids.extend(current_stock_lines.into_iter().map(|line| line.id));
+    if stock_line_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let stock_lines = StockLineRepository::new(connection).query_by_filter(
+        StockLineFilter::new().id(EqualFilter::equal_any(stock_line_ids.into_iter().collect())),
+    )?;
+    let mut result = Vec::new();
+    for stock_line in stock_lines {
+        let movements: Vec<LocationMovementRow> = movement_repository
+            .find_many_by_stock_line_id(&breach.store_id, &stock_line.id)?
+            .into_iter()
+            .filter(|movement| movement.stock_line_id == stock_line.id)
+            .collect();
+        if was_in_location_during_breach(stock_line.location_id.as_deref(), &movements, breach) {
+            result.push(stock_line);
+        }
+    }
+    result.sort_by(|a, b| a.id.cmp(&b.id));
+    Ok(result)
+}
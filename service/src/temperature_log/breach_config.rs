@@ -0,0 +1,150 @@
+use repository::{
+    schema::TemperatureBreachConfigRow, LocationTypeRowRepository, RepositoryError,
+    StorageConnection, TemperatureBreachConfigRowRepository,
+};
+
+use crate::service_provider::ServiceContext;
+
+pub struct InsertTemperatureBreachConfig {
+    pub id: String,
+    pub location_type_id: String,
+    pub description: String,
+    pub minimum_temperature: Option<f64>,
+    pub maximum_temperature: Option<f64>,
+    pub duration_minutes: u32,
+    /// Defaults to true
+    pub is_active: Option<bool>,
+}
+
+/// Fields that are None are left unchanged
+pub struct UpdateTemperatureBreachConfig {
+    pub id: String,
+    pub description: Option<String>,
+    pub minimum_temperature: Option<f64>,
+    pub maximum_temperature: Option<f64>,
+    pub duration_minutes: Option<u32>,
+    pub is_active: Option<bool>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum InsertTemperatureBreachConfigError {
+    DatabaseError(RepositoryError),
+    ConfigAlreadyExists,
+    LocationTypeDoesNotExist,
+    /// Neither a minimum nor a maximum temperature is set
+    NoTemperatureRange,
+    MinimumAboveMaximum,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum UpdateTemperatureBreachConfigError {
+    DatabaseError(RepositoryError),
+    ConfigDoesNotExist,
+    MinimumAboveMaximum,
+}
+
+fn minimum_above_maximum(config: &TemperatureBreachConfigRow) -> bool {
+    matches!(
+        (config.minimum_temperature, config.maximum_temperature),
+        (Some(minimum), Some(maximum)) if minimum > maximum
+    )
+}
+
+/// Breach configs apply to readings ingested after the change, breaches of a location are
+/// recalculated with the configs of its location type whenever readings are ingested
+pub fn insert_temperature_breach_config(
+    ctx: &ServiceContext,
+    input: InsertTemperatureBreachConfig,
+) -> Result<TemperatureBreachConfigRow, InsertTemperatureBreachConfigError> {
+    let config = ctx
+        .connection
+        .transaction_sync(|connection| {
+            validate_insert(connection, &input)?;
+            let config = TemperatureBreachConfigRow {
+                id: input.id.clone(),
+                location_type_id: input.location_type_id.clone(),
+                description: input.description.clone(),
+                minimum_temperature: input.minimum_temperature,
+                maximum_temperature: input.maximum_temperature,
+                duration_minutes: input.duration_minutes as i32,
+                is_active: input.is_active.unwrap_or(true),
+            };
+            if minimum_above_maximum(&config) {
+                return Err(InsertTemperatureBreachConfigError::MinimumAboveMaximum);
+            }
+            TemperatureBreachConfigRowRepository::new(connection).upsert_one(&config)?;
+            Ok(config)
+        })
+        .map_err(|error| error.to_inner_error())?;
+    Ok(config)
+}
+
+fn validate_insert(
+    connection: &StorageConnection,
+    input: &InsertTemperatureBreachConfig,
+) -> Result<(), InsertTemperatureBreachConfigError> {
+    if TemperatureBreachConfigRowRepository::new(connection)
+        .find_one_by_id(&input.id)?
+        .is_some()
+    {
+        return Err(InsertTemperatureBreachConfigError::ConfigAlreadyExists);
+    }
+    if LocationTypeRowRepository::new(connection)
+        .find_one_by_id(&input.location_type_id)?
+        .is_none()
+    {
+        return Err(InsertTemperatureBreachConfigError::LocationTypeDoesNotExist);
+    }
+    if input.minimum_temperature.is_none() && input.maximum_temperature.is_none() {
+        return Err(InsertTemperatureBreachConfigError::NoTemperatureRange);
+    }
+    Ok(())
+}
+
+pub fn update_temperature_breach_config(
+    ctx: &ServiceContext,
+    input: UpdateTemperatureBreachConfig,
+) -> Result<TemperatureBreachConfigRow, UpdateTemperatureBreachConfigError> {
+    let config = ctx
+        .connection
+        .transaction_sync(|connection| {
+            let repository = TemperatureBreachConfigRowRepository::new(connection);
+            let mut config = repository
+                .find_one_by_id(&input.id)?
+                .ok_or(UpdateTemperatureBreachConfigError::ConfigDoesNotExist)?;
+            if let Some(description) = input.description.clone() {
+                config.description = description;
+            }
+            if input.minimum_temperature.is_some() {
+                config.minimum_temperature = input.minimum_temperature;
+            }
+            if input.maximum_temperature.is_some() {
+                config.maximum_temperature = input.maximum_temperature;
+            }
+            if let Some(duration_minutes) = input.duration_minutes {
+                config.duration_minutes = duration_minutes as i32;
+            }
+            if let Some(is_active) = input.is_active {
+                config.is_active = is_active;
+            }
+            if minimum_above_maximum(&config) {
+                return Err(UpdateTemperatureBreachConfigError::MinimumAboveMaximum);
+            }
+            repository.upsert_one(&config)?;
+            Ok(config)
+        })
+        .map_err(|error| error.to_inner_error())?;
+    Ok(config)
+}
+
+impl From<RepositoryError> for InsertTemperatureBreachConfigError {
+    fn from(error: RepositoryError) -> Self {
+        InsertTemperatureBreachConfigError::DatabaseError(error)
+    }
+}
+
+impl From<RepositoryError> for UpdateTemperatureBreachConfigError {
+    fn from(error: RepositoryError) -> Self {
+        UpdateTemperatureBreachConfigError::DatabaseError(error)
+    }
+}
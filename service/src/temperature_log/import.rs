@@ -0,0 +1,296 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, NaiveDateTime};
+use domain::{location::LocationFilter, EqualFilter};
+use repository::{
+    schema::LocationRow, LocationRepository, LocationRowRepository, RepositoryError,
+    StorageConnection,
+};
+
+use crate::service_provider::ServiceContext;
+
+use super::{insert::ingest_readings, IngestedTemperatureLogs};
+
+/// Column names holding the date and time of a reading in one column
+const DATETIME_COLUMNS: [&str; 5] = ["datetime", "date time", "date/time", "timestamp", "time"];
+
+/// Formats of the date and time columns exported by common data loggers, RFC 3339 is accepted as
+/// well
+const DATETIME_FORMATS: [&str; 8] = [
+    "%Y-%m-%d %H:%M:%S",
+    "%Y-%m-%dT%H:%M:%S",
+    "%Y-%m-%d %H:%M",
+    "%Y/%m/%d %H:%M:%S",
+    "%d/%m/%Y %H:%M:%S",
+    "%d/%m/%Y %H:%M",
+    "%d.%m.%Y %H:%M:%S",
+    "%d.%m.%Y %H:%M",
+];
+
+pub struct ImportTemperatureLogs {
+    /// Location of all readings, required unless the file has a location_code column
+    pub location_id: Option<String>,
+    /// Content of the CSV file exported by a data logger. Lines before the header (e.g. logger
+    /// details) are skipped. The header needs a temperature column (name starting with "temp",
+    /// values in °C unless the name contains °F) and either a date time column or separate date
+    /// and time columns. Comma, semicolon and tab separated files are supported.
+    pub csv: String,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum TemperatureLogRowErrorKind {
+    /// Row couldn't be read, e.g. wrong number of fields
+    InvalidRow(String),
+    InvalidDatetime(String),
+    InvalidTemperature(String),
+    /// Holds the location code
+    LocationDoesNotExist(String),
+}
+
+#[derive(Debug, PartialEq)]
+pub struct TemperatureLogRowError {
+    /// Line in the file, starting at 1
+    pub line_number: usize,
+    pub errors: Vec<TemperatureLogRowErrorKind>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ImportTemperatureLogsError {
+    DatabaseError(RepositoryError),
+    /// No line of the file has a temperature and a date time column
+    MissingHeader,
+    /// Neither the location_id of the input nor a location_code column is provided
+    MissingLocation,
+    LocationDoesNotExist,
+    NoRows,
+    RowErrors(Vec<TemperatureLogRowError>),
+}
+
+struct Header {
+    /// Index of the header line
+    line_index: usize,
+    delimiter: u8,
+    temperature: usize,
+    fahrenheit: bool,
+    /// Either one date time column or a date and a time column
+    datetime: (usize, Option<usize>),
+    location_code: Option<usize>,
+}
+
+/// Imports the readings of a data logger and updates the breaches of their locations.
+///
+/// Nothing is written unless every row of the file is valid. Readings of a location at a time that
+/// is already logged are skipped, so overlapping exports can be imported.
+pub fn import_temperature_logs(
+    ctx: &ServiceContext,
+    store_id: &str,
+    input: ImportTemperatureLogs,
+) -> Result<IngestedTemperatureLogs, ImportTemperatureLogsError> {
+    let result = ctx
+        .connection
+        .transaction_sync(|connection| {
+            let readings = validate(connection, store_id, &input)?;
+            Ok::<IngestedTemperatureLogs, ImportTemperatureLogsError>(ingest_readings(
+                connection, readings,
+            )?)
+        })
+        .map_err(|error| error.to_inner_error())?;
+    Ok(result)
+}
+
+fn find_header(csv: &str) -> Option<Header> {
+    for (line_index, line) in csv.lines().enumerate() {
+        let delimiter = [b',', b';', b'\t']
+            .iter()
+            .copied()
+            .max_by_key(|delimiter| line.bytes().filter(|byte| byte == delimiter).count())
+            .unwrap_or(b',');
+        let columns: Vec<String> = line
+            .split(delimiter as char)
+            .map(|column| column.trim().trim_matches('"').trim().to_lowercase())
+            .collect();
+        let find = |name: &str| columns.iter().position(|column| column == name);
+
+        let temperature = match columns.iter().position(|column| column.starts_with("temp")) {
+            Some(temperature) => temperature,
+            None => continue,
+        };
+        let datetime = match (
+            columns
+                .iter()
+                .position(|column| DATETIME_COLUMNS.contains(&column.as_str())),
+            find("date"),
+        ) {
+            (Some(time), Some(date)) if columns[time] == "time" => (date, Some(time)),
+            (Some(datetime), _) => (datetime, None),
+            _ => continue,
+        };
+        let column = &columns[temperature];
+        return Some(Header {
+            line_index,
+            delimiter,
+            temperature,
+            fahrenheit: column.contains("°f") || column.contains("(f)"),
+            datetime,
+            location_code: find("location_code"),
+        });
+    }
+    None
+}
+
+fn parse_datetime(value: &str) -> Option<NaiveDateTime> {
+    if let Ok(datetime) = DateTime::parse_from_rfc3339(value) {
+        return Some(datetime.naive_utc());
+    }
+    DATETIME_FORMATS
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(value, format).ok())
+}
+
+fn parse_temperature(value: &str, fahrenheit: bool) -> Option<f64> {
+    let value = value
+        .trim_end_matches(|c: char| c == '°' || c == 'C' || c == 'F' || c.is_whitespace())
+        .replace(',', ".");
+    let temperature: f64 = value.parse().ok().filter(|value: &f64| value.is_finite())?;
+    Some(match fahrenheit {
+        true => (temperature - 32.0) * 5.0 / 9.0,
+        false => temperature,
+    })
+}
+
+fn validate(
+    connection: &StorageConnection,
+    store_id: &str,
+    input: &ImportTemperatureLogs,
+) -> Result<Vec<(LocationRow, f64, NaiveDateTime)>, ImportTemperatureLogsError> {
+    let header = find_header(&input.csv).ok_or(ImportTemperatureLogsError::MissingHeader)?;
+    let default_location = match (&input.location_id, header.location_code) {
+        (Some(location_id), _) => Some(
+            LocationRowRepository::new(connection)
+                .find_one_by_id(location_id)?
+                .filter(|location| location.store_id == store_id)
+                .ok_or(ImportTemperatureLogsError::LocationDoesNotExist)?,
+        ),
+        (None, Some(_)) => None,
+        (None, None) => return Err(ImportTemperatureLogsError::MissingLocation),
+    };
+
+    let body: String = input
+        .csv
+        .lines()
+        .skip(header.line_index)
+        .collect::<Vec<&str>>()
+        .join("\n");
+    let mut reader = csv::ReaderBuilder::new()
+        .delimiter(header.delimiter)
+        .trim(csv::Trim::All)
+        .flexible(true)
+        .from_reader(body.as_bytes());
+
+    let mut locations: HashMap<String, Option<LocationRow>> = HashMap::new();
+    let mut readings = Vec::new();
+    let mut row_errors = Vec::new();
+    for record in reader.records() {
+        let record = match record {
+            Ok(record) => record,
+            Err(error) => {
+                let line_number = error
+                    .position()
+                    .map(|position| header.line_index + position.line() as usize)
+                    .unwrap_or_default();
+                row_errors.push(TemperatureLogRowError {
+                    line_number,
+                    errors: vec![TemperatureLogRowErrorKind::InvalidRow(error.to_string())],
+                });
+                continue;
+            }
+        };
+        // skip empty lines at the end of the file
+        if record.iter().all(|field| field.is_empty()) {
+            continue;
+        }
+        let line_number = header.line_index
+            + record
+                .position()
+                .map(|position| position.line() as usize)
+                .unwrap_or_default();
+        let field = |index: usize| record.get(index).unwrap_or_default();
+
+        let mut errors = Vec::new();
+        let datetime_value = match header.datetime {
+            (datetime, None) => field(datetime).to_string(),
+            (date, Some(time)) => format!("{} {}", field(date), field(time)),
+        };
+        let datetime = parse_datetime(&datetime_value);
+        if datetime.is_none() {
+            errors.push(TemperatureLogRowErrorKind::InvalidDatetime(datetime_value));
+        }
+        let temperature = parse_temperature(field(header.temperature), header.fahrenheit);
+        if temperature.is_none() {
+            errors.push(TemperatureLogRowErrorKind::InvalidTemperature(
+                field(header.temperature).to_string(),
+            ));
+        }
+        let location = match (&default_location, header.location_code) {
+            (_, Some(index)) if !field(index).is_empty() || default_location.is_none() => {
+                let code = field(index);
+                let location = get_location_by_code(connection, store_id, code, &mut locations)?;
+                if location.is_none() {
+                    errors.push(TemperatureLogRowErrorKind::LocationDoesNotExist(
+                        code.to_string(),
+                    ));
+                }
+                location
+            }
+            (location, _) => location.clone(),
+        };
+
+        match (location, temperature, datetime) {
+            (Some(location), Some(temperature), Some(datetime)) if errors.is_empty() => {
+                readings.push((location, temperature, datetime))
+            }
+            _ => row_errors.push(TemperatureLogRowError {
+                line_number,
+                errors,
+            }),
+        }
+    }
+
+    if !row_errors.is_empty() {
+        return Err(ImportTemperatureLogsError::RowErrors(row_errors));
+    }
+    if readings.is_empty() {
+        return Err(ImportTemperatureLogsError::NoRows);
+    }
+    Ok(readings)
+}
+
+fn get_location_by_code(
+    connection: &StorageConnection,
+    store_id: &str,
+    code: &str,
+    locations: &mut HashMap<String, Option<LocationRow>>,
+) -> Result<Option<LocationRow>, ImportTemperatureLogsError> {
+    if let Some(location) = locations.get(code) {
+        return Ok(location.clone());
+    }
+    let location = match LocationRepository::new(connection)
+        .query_by_filter(
+            LocationFilter::new()
+                .code(EqualFilter::equal_to(code))
+                .store_id(EqualFilter::equal_to(store_id)),
+        )?
+        .pop()
+    {
+        Some(location) => LocationRowRepository::new(connection).find_one_by_id(&location.id)?,
+        None => None,
+    };
+    locations.insert(code.to_string(), location.clone());
+    Ok(location)
+}
+
+impl From<RepositoryError> for ImportTemperatureLogsError {
+    fn from(error: RepositoryError) -> Self {
+        ImportTemperatureLogsError::DatabaseError(error)
+    }
+}
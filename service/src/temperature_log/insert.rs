@@ -0,0 +1,141 @@
+use std::collections::{HashMap, HashSet};
+
+use chrono::NaiveDateTime;
+use repository::{
+    schema::{LocationRow, TemperatureLogRow},
+    LocationRowRepository, RepositoryError, StorageConnection, TemperatureLogRowRepository,
+};
+use util::uuid::uuid;
+
+use crate::service_provider::ServiceContext;
+
+use super::{breach::update_location_breaches, IngestedTemperatureLogs};
+
+pub struct InsertTemperatureLog {
+    pub location_id: String,
+    /// Degrees Celsius
+    pub temperature: f64,
+    pub datetime: NaiveDateTime,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum InsertTemperatureLogsError {
+    DatabaseError(RepositoryError),
+    NoReadings,
+    /// Holds the id of the unknown location
+    LocationDoesNotExist(String),
+}
+
+/// Logs readings pushed by sensors and updates the breaches of their locations.
+///
+/// Readings of a location at a time that is already logged are skipped, so sensors can safely
+/// resend readings.
+pub fn insert_temperature_logs(
+    ctx: &ServiceContext,
+    store_id: &str,
+    input: Vec<InsertTemperatureLog>,
+) -> Result<IngestedTemperatureLogs, InsertTemperatureLogsError> {
+    let result = ctx
+        .connection
+        .transaction_sync(|connection| {
+            let readings = validate(connection, store_id, input)?;
+            Ok::<IngestedTemperatureLogs, InsertTemperatureLogsError>(ingest_readings(
+                connection, readings,
+            )?)
+        })
+        .map_err(|error| error.to_inner_error())?;
+    Ok(result)
+}
+
+fn validate(
+    connection: &StorageConnection,
+    store_id: &str,
+    input: Vec<InsertTemperatureLog>,
+) -> Result<Vec<(LocationRow, f64, NaiveDateTime)>, InsertTemperatureLogsError> {
+    if input.is_empty() {
+        return Err(InsertTemperatureLogsError::NoReadings);
+    }
+    let repository = LocationRowRepository::new(connection);
+    let mut locations: HashMap<String, LocationRow> = HashMap::new();
+    let mut readings = Vec::new();
+    for reading in input {
+        let location = match locations.get(&reading.location_id) {
+            Some(location) => location.clone(),
+            None => {
+                let location = repository
+                    .find_one_by_id(&reading.location_id)?
+                    .filter(|location| location.store_id == store_id)
+                    .ok_or_else(|| {
+                        InsertTemperatureLogsError::LocationDoesNotExist(
+                            reading.location_id.clone(),
+                        )
+                    })?;
+                locations.insert(location.id.clone(), location.clone());
+                location
+            }
+        };
+        readings.push((location, reading.temperature, reading.datetime));
+    }
+    Ok(readings)
+}
+
+/// Stores the readings that aren't logged yet and updates the breaches of their locations
+pub fn ingest_readings(
+    connection: &StorageConnection,
+    readings: Vec<(LocationRow, f64, NaiveDateTime)>,
+) -> Result<IngestedTemperatureLogs, RepositoryError> {
+    let mut by_location: HashMap<String, (LocationRow, Vec<(f64, NaiveDateTime)>)> = HashMap::new();
+    for (location, temperature, datetime) in readings {
+        by_location
+            .entry(location.id.clone())
+            .or_insert_with(|| (location, Vec::new()))
+            .1
+            .push((temperature, datetime));
+    }
+    let mut locations: Vec<(LocationRow, Vec<(f64, NaiveDateTime)>)> =
+        by_location.into_values().collect();
+    locations.sort_by(|(a, _), (b, _)| a.id.cmp(&b.id));
+
+    let repository = TemperatureLogRowRepository::new(connection);
+    let mut result = IngestedTemperatureLogs {
+        logs: Vec::new(),
+        number_of_duplicates: 0,
+        breaches: Vec::new(),
+    };
+    for (location, mut readings) in locations {
+        readings.sort_by(|(_, a), (_, b)| a.cmp(b));
+        let from_datetime = readings.first().map(|(_, datetime)| *datetime);
+        let to_datetime = readings.last().map(|(_, datetime)| *datetime);
+        let mut logged: HashSet<NaiveDateTime> = repository
+            .find_many_by_location_id(&location.store_id, &location.id, from_datetime, to_datetime)?
+            .into_iter()
+            .map(|log| log.datetime)
+            .collect();
+
+        for (temperature, datetime) in readings {
+            if !logged.insert(datetime) {
+                result.number_of_duplicates += 1;
+                continue;
+            }
+            let log = TemperatureLogRow {
+                id: uuid(),
+                store_id: location.store_id.clone(),
+                location_id: location.id.clone(),
+                temperature,
+                datetime,
+            };
+            repository.upsert_one(&log)?;
+            result.logs.push(log);
+        }
+        result
+            .breaches
+            .append(&mut update_location_breaches(connection, location)?);
+    }
+    Ok(result)
+}
+
+impl From<RepositoryError> for InsertTemperatureLogsError {
+    fn from(error: RepositoryError) -> Self {
+        InsertTemperatureLogsError::DatabaseError(error)
+    }
+}
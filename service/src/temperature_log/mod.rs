@@ -0,0 +1,169 @@
+use chrono::NaiveDateTime;
+use domain::stock_line::StockLine;
+use repository::{
+    schema::{TemperatureBreachConfigRow, TemperatureBreachRow, TemperatureLogRow},
+    RepositoryError, TemperatureBreachConfigRowRepository, TemperatureBreachRowRepository,
+    TemperatureLogRowRepository,
+};
+
+use crate::{service_provider::ServiceContext, SingleRecordError};
+
+use self::{
+    breach::get_breach_stock_lines,
+    breach_config::{
+        insert_temperature_breach_config, update_temperature_breach_config,
+        InsertTemperatureBreachConfig, InsertTemperatureBreachConfigError,
+        UpdateTemperatureBreachConfig, UpdateTemperatureBreachConfigError,
+    },
+    import::{import_temperature_logs, ImportTemperatureLogs, ImportTemperatureLogsError},
+    insert::{insert_temperature_logs, InsertTemperatureLog, InsertTemperatureLogsError},
+};
+
+pub mod breach;
+pub mod breach_config;
+pub mod import;
+pub mod insert;
+
+#[cfg(test)]
+mod tests;
+
+/// Result of ingesting readings
+#[derive(Debug, PartialEq)]
+pub struct IngestedTemperatureLogs {
+    /// Newly logged readings
+    pub logs: Vec<TemperatureLogRow>,
+    /// Readings skipped because the location already has a reading at the same time
+    pub number_of_duplicates: usize,
+    /// Breaches of the locations of the readings
+    pub breaches: Vec<TemperatureBreachRow>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct TemperatureBreachCounts {
+    /// Breaches that haven't ended yet
+    pub current: i64,
+    /// Breaches that started at or after the given time
+    pub recent: i64,
+}
+
+pub trait TemperatureLogServiceTrait: Sync + Send {
+    fn insert_temperature_logs(
+        &self,
+        ctx: &ServiceContext,
+        store_id: &str,
+        input: Vec<InsertTemperatureLog>,
+    ) -> Result<IngestedTemperatureLogs, InsertTemperatureLogsError> {
+        insert_temperature_logs(ctx, store_id, input)
+    }
+
+    fn import_temperature_logs(
+        &self,
+        ctx: &ServiceContext,
+        store_id: &str,
+        input: ImportTemperatureLogs,
+    ) -> Result<IngestedTemperatureLogs, ImportTemperatureLogsError> {
+        import_temperature_logs(ctx, store_id, input)
+    }
+
+    /// Readings of the location, optionally limited to a period, oldest first
+    fn get_temperature_logs(
+        &self,
+        ctx: &ServiceContext,
+        store_id: &str,
+        location_id: &str,
+        from_datetime: Option<NaiveDateTime>,
+        to_datetime: Option<NaiveDateTime>,
+    ) -> Result<Vec<TemperatureLogRow>, RepositoryError> {
+        TemperatureLogRowRepository::new(&ctx.connection).find_many_by_location_id(
+            store_id,
+            location_id,
+            from_datetime,
+            to_datetime,
+        )
+    }
+
+    /// Breaches of the store, most recent first
+    fn get_temperature_breaches(
+        &self,
+        ctx: &ServiceContext,
+        store_id: &str,
+        location_id: Option<&str>,
+        current_only: bool,
+    ) -> Result<Vec<TemperatureBreachRow>, RepositoryError> {
+        TemperatureBreachRowRepository::new(&ctx.connection).find_many(
+            store_id,
+            location_id,
+            current_only,
+        )
+    }
+
+    /// Stock lines that were in the location of the breach while it lasted
+    fn get_temperature_breach_stock_lines(
+        &self,
+        ctx: &ServiceContext,
+        breach: &TemperatureBreachRow,
+    ) -> Result<Vec<StockLine>, RepositoryError> {
+        get_breach_stock_lines(&ctx.connection, breach)
+    }
+
+    fn count_temperature_breaches(
+        &self,
+        ctx: &ServiceContext,
+        store_id: &str,
+        since: NaiveDateTime,
+    ) -> Result<TemperatureBreachCounts, RepositoryError> {
+        let breaches = TemperatureBreachRowRepository::new(&ctx.connection)
+            .find_many(store_id, None, false)?;
+        Ok(TemperatureBreachCounts {
+            current: breaches
+                .iter()
+                .filter(|breach| breach.end_datetime.is_none())
+                .count() as i64,
+            recent: breaches
+                .iter()
+                .filter(|breach| breach.start_datetime >= since)
+                .count() as i64,
+        })
+    }
+
+    fn get_temperature_breach_configs(
+        &self,
+        ctx: &ServiceContext,
+        location_type_id: Option<&str>,
+    ) -> Result<Vec<TemperatureBreachConfigRow>, RepositoryError> {
+        let repository = TemperatureBreachConfigRowRepository::new(&ctx.connection);
+        match location_type_id {
+            Some(location_type_id) => repository.find_many_by_location_type_id(location_type_id),
+            None => repository.find_all(),
+        }
+    }
+
+    fn get_temperature_breach_config(
+        &self,
+        ctx: &ServiceContext,
+        id: &str,
+    ) -> Result<TemperatureBreachConfigRow, SingleRecordError> {
+        TemperatureBreachConfigRowRepository::new(&ctx.connection)
+            .find_one_by_id(id)?
+            .ok_or_else(|| SingleRecordError::NotFound(id.to_string()))
+    }
+
+    fn insert_temperature_breach_config(
+        &self,
+        ctx: &ServiceContext,
+        input: InsertTemperatureBreachConfig,
+    ) -> Result<TemperatureBreachConfigRow, InsertTemperatureBreachConfigError> {
+        insert_temperature_breach_config(ctx, input)
+    }
+
+    fn update_temperature_breach_config(
+        &self,
+        ctx: &ServiceContext,
+        input: UpdateTemperatureBreachConfig,
+    ) -> Result<TemperatureBreachConfigRow, UpdateTemperatureBreachConfigError> {
+        update_temperature_breach_config(ctx, input)
+    }
+}
+
+pub struct TemperatureLogService {}
+impl TemperatureLogServiceTrait for TemperatureLogService {}
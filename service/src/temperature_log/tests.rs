@@ -0,0 +1,394 @@
+#[cfg(test)]
+mod temperature_log_test {
+    use chrono::{NaiveDate, NaiveDateTime};
+    use repository::{
+        mock::{mock_stock_line_a, mock_stock_line_b, mock_store_a, MockDataInserts},
+        schema::{LocationMovementRow, LocationTypeRow, StockLineRow},
+        test_db::setup_all,
+        LocationMovementRowRepository, LocationRowRepository, LocationTypeRowRepository,
+        StockLineRowRepository,
+    };
+
+    use crate::{
+        service_provider::ServiceProvider,
+        temperature_log::{
+            breach_config::{
+                InsertTemperatureBreachConfig, InsertTemperatureBreachConfigError,
+                UpdateTemperatureBreachConfig, UpdateTemperatureBreachConfigError,
+            },
+            import::{
+                ImportTemperatureLogs, ImportTemperatureLogsError, TemperatureLogRowError,
+                TemperatureLogRowErrorKind,
+            },
+            insert::{InsertTemperatureLog, InsertTemperatureLogsError},
+            TemperatureBreachCounts,
+        },
+    };
+
+    fn datetime(hour: u32, minute: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd(2021, 6, 1).and_hms(hour, minute, 0)
+    }
+
+    fn reading(
+        location_id: &str,
+        temperature: f64,
+        datetime: NaiveDateTime,
+    ) -> InsertTemperatureLog {
+        InsertTemperatureLog {
+            location_id: location_id.to_string(),
+            temperature,
+            datetime,
+        }
+    }
+
+    fn config_input(
+        id: &str,
+        minimum_temperature: Option<f64>,
+        maximum_temperature: Option<f64>,
+    ) -> InsertTemperatureBreachConfig {
+        InsertTemperatureBreachConfig {
+            id: id.to_string(),
+            location_type_id: "fridge".to_string(),
+            description: id.to_string(),
+            minimum_temperature,
+            maximum_temperature,
+            duration_minutes: 30,
+            is_active: None,
+        }
+    }
+
+    fn setup_fridge(connection: &repository::StorageConnection) {
+        LocationTypeRowRepository::new(connection)
+            .upsert_one(&LocationTypeRow {
+                id: "fridge".to_string(),
+                name: "Fridge".to_string(),
+                min_temperature: Some(2.0),
+                max_temperature: Some(8.0),
+            })
+            .unwrap();
+        let location_repository = LocationRowRepository::new(connection);
+        let mut location = location_repository
+            .find_one_by_id("location_1")
+            .unwrap()
+            .unwrap();
+        location.location_type_id = Some("fridge".to_string());
+        location_repository.upsert_one(&location).unwrap();
+    }
+
+    #[actix_rt::test]
+    async fn temperature_breach_config() {
+        let (_, connection, connection_manager, _) =
+            setup_all("temperature_breach_config", MockDataInserts::all()).await;
+        let service_provider = ServiceProvider::new(connection_manager);
+        let context = service_provider.context().unwrap();
+        let service = &service_provider.temperature_log_service;
+        setup_fridge(&connection);
+
+        // LocationTypeDoesNotExist
+        let mut input = config_input("hot", None, Some(8.0));
+        input.location_type_id = "invalid".to_string();
+        assert_eq!(
+            service.insert_temperature_breach_config(&context, input),
+            Err(InsertTemperatureBreachConfigError::LocationTypeDoesNotExist)
+        );
+        // NoTemperatureRange
+        assert_eq!(
+            service.insert_temperature_breach_config(&context, config_input("hot", None, None)),
+            Err(InsertTemperatureBreachConfigError::NoTemperatureRange)
+        );
+        // MinimumAboveMaximum
+        assert_eq!(
+            service.insert_temperature_breach_config(
+                &context,
+                config_input("hot", Some(8.0), Some(2.0))
+            ),
+            Err(InsertTemperatureBreachConfigError::MinimumAboveMaximum)
+        );
+
+        let config = service
+            .insert_temperature_breach_config(&context, config_input("hot", None, Some(8.0)))
+            .unwrap();
+        assert!(config.is_active);
+        // ConfigAlreadyExists
+        assert_eq!(
+            service
+                .insert_temperature_breach_config(&context, config_input("hot", None, Some(8.0))),
+            Err(InsertTemperatureBreachConfigError::ConfigAlreadyExists)
+        );
+
+        let update = |id: &str, minimum_temperature: Option<f64>| UpdateTemperatureBreachConfig {
+            id: id.to_string(),
+            description: None,
+            minimum_temperature,
+            maximum_temperature: None,
+            duration_minutes: Some(60),
+            is_active: Some(false),
+        };
+        // ConfigDoesNotExist
+        assert_eq!(
+            service.update_temperature_breach_config(&context, update("invalid", None)),
+            Err(UpdateTemperatureBreachConfigError::ConfigDoesNotExist)
+        );
+        // MinimumAboveMaximum
+        assert_eq!(
+            service.update_temperature_breach_config(&context, update("hot", Some(9.0))),
+            Err(UpdateTemperatureBreachConfigError::MinimumAboveMaximum)
+        );
+        let config = service
+            .update_temperature_breach_config(&context, update("hot", Some(2.0)))
+            .unwrap();
+        assert_eq!(config.minimum_temperature, Some(2.0));
+        assert_eq!(config.maximum_temperature, Some(8.0));
+        assert_eq!(config.duration_minutes, 60);
+        assert!(!config.is_active);
+        assert_eq!(
+            service
+                .get_temperature_breach_configs(&context, Some("fridge"))
+                .unwrap(),
+            vec![config]
+        );
+    }
+
+    #[actix_rt::test]
+    async fn insert_temperature_logs() {
+        let (_, connection, connection_manager, _) =
+            setup_all("insert_temperature_logs", MockDataInserts::all()).await;
+        let service_provider = ServiceProvider::new(connection_manager);
+        let context = service_provider.context().unwrap();
+        let service = &service_provider.temperature_log_service;
+        let store_id = &mock_store_a().id;
+        setup_fridge(&connection);
+        service
+            .insert_temperature_breach_config(
+                &context,
+                config_input("fridge", Some(2.0), Some(8.0)),
+            )
+            .unwrap();
+
+        // NoReadings
+        assert_eq!(
+            service.insert_temperature_logs(&context, store_id, vec![]),
+            Err(InsertTemperatureLogsError::NoReadings)
+        );
+        // LocationDoesNotExist
+        assert_eq!(
+            service.insert_temperature_logs(
+                &context,
+                store_id,
+                vec![reading("location_in_another_store", 5.0, datetime(0, 0))]
+            ),
+            Err(InsertTemperatureLogsError::LocationDoesNotExist(
+                "location_in_another_store".to_string()
+            ))
+        );
+
+        // out of range from 00:10 until 01:00
+        let result = service
+            .insert_temperature_logs(
+                &context,
+                store_id,
+                vec![
+                    reading("location_1", 5.0, datetime(0, 0)),
+                    reading("location_1", 9.0, datetime(0, 10)),
+                    reading("location_1", 10.5, datetime(0, 20)),
+                    reading("location_1", 9.0, datetime(0, 50)),
+                    reading("location_1", 5.0, datetime(1, 0)),
+                ],
+            )
+            .unwrap();
+        assert_eq!(result.logs.len(), 5);
+        assert_eq!(result.breaches.len(), 1);
+        let breach = result.breaches[0].clone();
+        assert_eq!(breach.start_datetime, datetime(0, 10));
+        assert_eq!(breach.end_datetime, Some(datetime(1, 0)));
+        assert_eq!(breach.duration_minutes, 50);
+
+        // out of range for less than the duration of the config, then out of range again below
+        // the minimum and not back in range yet
+        let result = service
+            .insert_temperature_logs(
+                &context,
+                store_id,
+                vec![
+                    reading("location_1", 5.0, datetime(0, 0)),
+                    reading("location_1", 9.0, datetime(2, 0)),
+                    reading("location_1", 5.0, datetime(2, 10)),
+                    reading("location_1", 1.0, datetime(3, 0)),
+                    reading("location_1", -1.0, datetime(3, 45)),
+                ],
+            )
+            .unwrap();
+        assert_eq!(result.logs.len(), 4);
+        assert_eq!(result.number_of_duplicates, 1);
+        assert_eq!(result.breaches.len(), 2);
+        let current = result.breaches[0].clone();
+        assert_eq!(current.start_datetime, datetime(3, 0));
+        assert_eq!(current.end_datetime, None);
+        assert_eq!(current.duration_minutes, 45);
+        // existing breach is kept
+        assert_eq!(result.breaches[1], breach);
+
+        assert_eq!(
+            service
+                .get_temperature_breaches(&context, store_id, None, true)
+                .unwrap(),
+            vec![current.clone()]
+        );
+        assert_eq!(
+            service
+                .count_temperature_breaches(&context, store_id, datetime(2, 0))
+                .unwrap(),
+            TemperatureBreachCounts {
+                current: 1,
+                recent: 1
+            }
+        );
+        assert_eq!(
+            service
+                .get_temperature_logs(
+                    &context,
+                    store_id,
+                    "location_1",
+                    Some(datetime(0, 10)),
+                    Some(datetime(0, 50))
+                )
+                .unwrap()
+                .len(),
+            3
+        );
+
+        // stock line a was moved into the location during the first breach, stock line b was
+        // moved out before it started
+        let stock_line_repository = StockLineRowRepository::new(&connection);
+        let movement_repository = LocationMovementRowRepository::new(&connection);
+        for (stock_line, from, to, moved_datetime) in [
+            (mock_stock_line_a(), None, "location_1", datetime(0, 30)),
+            (
+                mock_stock_line_b(),
+                Some("location_1"),
+                "location_2",
+                datetime(0, 5),
+            ),
+        ] {
+            stock_line_repository
+                .upsert_one(&StockLineRow {
+                    location_id: Some(to.to_string()),
+                    ..stock_line.clone()
+                })
+                .unwrap();
+            movement_repository
+                .upsert_one(&LocationMovementRow {
+                    id: format!("move_{}", stock_line.id),
+                    store_id: store_id.clone(),
+                    stock_line_id: stock_line.id.clone(),
+                    split_from_stock_line_id: None,
+                    from_location_id: from.map(str::to_string),
+                    to_location_id: to.to_string(),
+                    number_of_packs: stock_line.total_number_of_packs,
+                    moved_datetime,
+                })
+                .unwrap();
+        }
+        let stock_line_ids: Vec<String> = service
+            .get_temperature_breach_stock_lines(&context, &breach)
+            .unwrap()
+            .into_iter()
+            .map(|stock_line| stock_line.id)
+            .collect();
+        assert_eq!(stock_line_ids, vec![mock_stock_line_a().id]);
+    }
+
+    #[actix_rt::test]
+    async fn import_temperature_logs() {
+        let (_, connection, connection_manager, _) =
+            setup_all("import_temperature_logs", MockDataInserts::all()).await;
+        let service_provider = ServiceProvider::new(connection_manager);
+        let context = service_provider.context().unwrap();
+        let service = &service_provider.temperature_log_service;
+        let store_id = &mock_store_a().id;
+        setup_fridge(&connection);
+        service
+            .insert_temperature_breach_config(
+                &context,
+                config_input("fridge", Some(2.0), Some(8.0)),
+            )
+            .unwrap();
+
+        let import = |location_id: Option<&str>, csv: &str| {
+            service.import_temperature_logs(
+                &context,
+                store_id,
+                ImportTemperatureLogs {
+                    location_id: location_id.map(str::to_string),
+                    csv: csv.to_string(),
+                },
+            )
+        };
+
+        // MissingHeader
+        assert_eq!(
+            import(Some("location_1"), "a,b\n1,2\n"),
+            Err(ImportTemperatureLogsError::MissingHeader)
+        );
+        // MissingLocation
+        assert_eq!(
+            import(None, "Date/Time,Temperature\n2021-06-01 00:00:00,5\n"),
+            Err(ImportTemperatureLogsError::MissingLocation)
+        );
+        // LocationDoesNotExist
+        assert_eq!(
+            import(
+                Some("location_in_another_store"),
+                "Date/Time,Temperature\n2021-06-01 00:00:00,5\n"
+            ),
+            Err(ImportTemperatureLogsError::LocationDoesNotExist)
+        );
+        // RowErrors
+        assert_eq!(
+            import(
+                None,
+                "datetime,temperature,location_code\n\
+                2021-06-01 00:00:00,5,code_location_1\n\
+                yesterday,warm,unknown\n"
+            ),
+            Err(ImportTemperatureLogsError::RowErrors(vec![
+                TemperatureLogRowError {
+                    line_number: 3,
+                    errors: vec![
+                        TemperatureLogRowErrorKind::InvalidDatetime("yesterday".to_string()),
+                        TemperatureLogRowErrorKind::InvalidTemperature("warm".to_string()),
+                        TemperatureLogRowErrorKind::LocationDoesNotExist("unknown".to_string()),
+                    ]
+                }
+            ]))
+        );
+
+        // logger details before the header, semicolon separated with decimal commas
+        let result = import(
+            Some("location_1"),
+            "Logger;FT-72\nSerial;12345\n\n\
+            Date;Time;Temperature (°C)\n\
+            01/06/2021;00:00;5,5\n\
+            01/06/2021;00:15;9,0\n\
+            01/06/2021;00:45;8,5\n\
+            01/06/2021;01:00;4,0\n",
+        )
+        .unwrap();
+        assert_eq!(result.logs.len(), 4);
+        assert_eq!(result.logs[0].temperature, 5.5);
+        assert_eq!(result.breaches.len(), 1);
+        assert_eq!(result.breaches[0].duration_minutes, 45);
+
+        // overlapping export in Fahrenheit
+        let result = import(
+            Some("location_1"),
+            "Timestamp,Temp (°F)\n\
+            2021-06-01T01:00:00Z,39.2\n\
+            2021-06-01T01:15:00Z,41\n",
+        )
+        .unwrap();
+        assert_eq!(result.number_of_duplicates, 1);
+        assert_eq!(result.logs.len(), 1);
+        assert_eq!(result.logs[0].temperature, 5.0);
+    }
+}